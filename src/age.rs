@@ -0,0 +1,243 @@
+use std::cmp;
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+
+use clap;
+use tabwriter::TabWriter;
+
+use app::arg_to_str;
+use block::block;
+use codepoint::Codepoint;
+use display::ShortWriter;
+use error::Result;
+use list::find_canonical_property_value;
+use script::script;
+use tables::{range_value, ucd};
+
+/// The width, in columns, of the longest bar printed by `rucd timeline`.
+const HISTOGRAM_WIDTH: usize = 50;
+
+pub fn command_since(args: &clap::ArgMatches) -> Result<()> {
+    let given = arg_to_str("version", args.value_of_os("version"))?;
+    let version = find_canonical_property_value("Age", &given)?;
+    let mut wtr = ShortWriter::new(io::stdout());
+    wtr.pictures(args.is_present("pictures"));
+    for cp in introduced_in(version) {
+        wtr.write_codepoint(cp)?;
+    }
+    wtr.flush()?;
+    Ok(())
+}
+
+pub fn command_timeline(args: &clap::ArgMatches) -> Result<()> {
+    let block_name = match args.value_of("block") {
+        None => None,
+        Some(name) => Some(find_canonical_property_value("Block", name)?),
+    };
+    let script_name = match args.value_of("script") {
+        None => None,
+        Some(name) => Some(find_canonical_property_value("Script", name)?),
+    };
+
+    let by = args.value_of("by");
+    let versions = versions();
+    let groups = added_by_version(by, block_name, script_name);
+
+    let mut wtr = TabWriter::new(io::stdout());
+    if let Some(by) = by {
+        write!(wtr, "{}\t", by)?;
+    }
+    writeln!(wtr, "version\tadded\thistogram")?;
+    for (name, counts) in groups {
+        // Each block or script gets its own histogram, since otherwise the
+        // private use planes would dwarf everything else.
+        let max = counts.iter().cloned().max().unwrap_or(0);
+        for (&version, &count) in versions.iter().zip(&counts) {
+            // A block or script only lists the versions that added to it.
+            if by.is_some() && count == 0 {
+                continue;
+            }
+            let width = count * HISTOGRAM_WIDTH / cmp::max(max, 1);
+            // Never hide a version that added something behind an empty
+            // bar.
+            let width = if count > 0 { cmp::max(width, 1) } else { 0 };
+            if by.is_some() {
+                write!(wtr, "{}\t", name)?;
+            }
+            writeln!(
+                wtr,
+                "{}\t{}\t{}",
+                version_number(version),
+                count,
+                "#".repeat(width))?;
+        }
+    }
+    wtr.flush()?;
+    Ok(())
+}
+
+/// Count the codepoints added in each version returned by `versions`.
+///
+/// When `by` is `block` or `script`, there is one group of counts for each
+/// block or script, in the order they are first seen. Otherwise, there is a
+/// single unnamed group. The counts may be restricted to a single block or
+/// script by their canonical names.
+fn added_by_version(
+    by: Option<&str>,
+    block_name: Option<&str>,
+    script_name: Option<&str>,
+) -> Vec<(&'static str, Vec<usize>)> {
+    let versions = versions();
+    let mut groups: Vec<(&'static str, Vec<usize>)> = vec![];
+    let mut group_index: BTreeMap<&'static str, usize> = BTreeMap::new();
+    if by.is_none() {
+        groups.push(("", vec![0; versions.len()]));
+        group_index.insert("", 0);
+    }
+    for &(start, end, age) in ucd().age {
+        let v = versions.iter().position(|&v| v == age).unwrap();
+        for n in start..end + 1 {
+            let cp = Codepoint::from_u32(n).unwrap();
+            if block_name.is_some() && block_name != Some(block(cp)) {
+                continue;
+            }
+            if script_name.is_some() && script_name != Some(script(cp)) {
+                continue;
+            }
+            let name = match by {
+                Some("block") => block(cp),
+                Some("script") => script(cp),
+                _ => "",
+            };
+            let i = *group_index.entry(name).or_insert_with(|| {
+                groups.push((name, vec![0; versions.len()]));
+                groups.len() - 1
+            });
+            groups[i].1[v] += 1;
+        }
+    }
+    groups
+}
+
+/// Return the `Age` property value of the given codepoint, e.g., `V9_0`.
+pub fn age(cp: Codepoint) -> &'static str {
+    range_value(ucd().age, cp.value()).unwrap_or("Unassigned")
+}
+
+/// Return every codepoint whose `Age` property value is the one given, e.g.,
+/// `V10_0`, in ascending order.
+pub fn introduced_in(version: &str) -> Vec<Codepoint> {
+    let mut cps = vec![];
+    for &(start, end, age) in ucd().age {
+        if age != version {
+            continue;
+        }
+        for n in start..end + 1 {
+            cps.push(Codepoint::from_u32(n).unwrap());
+        }
+    }
+    cps
+}
+
+/// Convert an `Age` property value, such as `V9_0`, to a version number,
+/// such as `9.0`.
+pub fn version_number(age: &str) -> String {
+    if !age.starts_with('V') {
+        return age.to_string();
+    }
+    age[1..].replace('_', ".")
+}
+
+/// Return every `Age` property value that some codepoint has, from oldest
+/// to newest.
+fn versions() -> Vec<&'static str> {
+    let mut versions: Vec<&'static str> = vec![];
+    for &(_, _, age) in ucd().age {
+        if !versions.contains(&age) {
+            versions.push(age);
+        }
+    }
+    versions.sort_by_key(|v| {
+        let mut parts = v[1..].split('_').map(|n| n.parse().unwrap_or(0));
+        (parts.next().unwrap_or(0u32), parts.next().unwrap_or(0u32))
+    });
+    versions
+}
+
+#[cfg(test)]
+mod tests {
+    use codepoint::Codepoint;
+    use list::find_canonical_property_value;
+    use tables::select_unicode_version;
+
+    use super::{
+        added_by_version, age, introduced_in, version_number, versions,
+    };
+
+    #[test]
+    fn since() {
+        let version = find_canonical_property_value("Age", "10.0").unwrap();
+        assert_eq!("V10_0", version);
+        let cps = introduced_in(version);
+        assert_eq!(8518, cps.len());
+        assert!(cps.iter().all(|&cp| age(cp) == "V10_0"));
+        // BITCOIN SIGN was added in Unicode 10.0.
+        assert!(cps.contains(&Codepoint::from('\u{20BF}')));
+        assert_eq!(7500, introduced_in("V9_0").len());
+    }
+
+    #[test]
+    fn ages() {
+        assert_eq!("V1_1", age(Codepoint::from('a')));
+        assert_eq!("V10_0", age(Codepoint::from('\u{20BF}')));
+        assert_eq!("Unassigned", age(Codepoint::from('\u{378}')));
+        assert_eq!("10.0", version_number("V10_0"));
+        assert_eq!("Unassigned", version_number("Unassigned"));
+    }
+
+    #[test]
+    fn versions_are_ordered() {
+        let versions = versions();
+        assert_eq!(Some(&"V1_1"), versions.first());
+        assert_eq!(Some(&"V10_0"), versions.last());
+        let v9 = versions.iter().position(|&v| v == "V9_0").unwrap();
+        let v10 = versions.iter().position(|&v| v == "V10_0").unwrap();
+        assert_eq!(v9 + 1, v10);
+    }
+
+    #[test]
+    fn older_version() {
+        select_unicode_version("9.0");
+        assert!(introduced_in("V10_0").is_empty());
+        assert_eq!(7500, introduced_in("V9_0").len());
+        assert_eq!("Unassigned", age(Codepoint::from('\u{20BF}')));
+        assert_eq!(Some(&"V9_0"), versions().last());
+    }
+
+    #[test]
+    fn added_by_block_and_script() {
+        let versions = versions();
+        let v9 = versions.iter().position(|&v| v == "V9_0").unwrap();
+        let v10 = versions.iter().position(|&v| v == "V10_0").unwrap();
+
+        let all = added_by_version(None, None, None);
+        assert_eq!(1, all.len());
+        assert_eq!(8518, all[0].1[v10]);
+
+        let greek = added_by_version(Some("script"), None, Some("Greek"));
+        assert_eq!(1, greek.len());
+        assert_eq!("Greek", greek[0].0);
+        assert_eq!(2, greek[0].1[v9]);
+
+        let currency =
+            added_by_version(Some("block"), Some("Currency_Symbols"), None);
+        assert_eq!(1, currency.len());
+        assert_eq!("Currency_Symbols", currency[0].0);
+        assert_eq!(1, currency[0].1[v10]);
+
+        let blocks = added_by_version(Some("block"), None, None);
+        assert_eq!(Some("Basic_Latin"), blocks.first().map(|g| g.0));
+        let total: usize = blocks.iter().map(|g| g.1[v10]).sum();
+        assert_eq!(8518, total);
+    }
+}
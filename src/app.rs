@@ -1,8 +1,13 @@
 use std::ffi::OsStr;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
 
 use clap::{App, AppSettings, Arg, SubCommand};
 
+use codepoint::Codepoint;
 use error::Result;
+use tables::UNICODE_VERSIONS;
 
 const TEMPLATE: &'static str = "\
 {bin} {version}
@@ -36,6 +41,200 @@ Project home page: https://github.com/BurntSushi/rucd";
 
 const ABOUT_SEARCH: &'static str = "\
 This sub-command provides a convenient interface for searching codepoints.
+
+The emoji data used by search comes from Emoji 5.0, which goes with Unicode
+10.0, except for Extended_Pictographic, which was first published in Emoji
+11.0 and comes from Emoji 13.0.
+";
+
+const ABOUT_DECOMPOSE: &'static str = "\
+This sub-command prints the full decomposition tree of a codepoint. Every
+level of canonical and compatibility decomposition is shown, along with the
+formatting tag (e.g., <font>) of each compatibility decomposition.
+
+A codepoint may be given as a hexadecimal number, optionally prefixed with
+U+, or as a single literal character.
+";
+
+const ABOUT_COMPOSE: &'static str = "\
+This sub-command finds the precomposed character that a base character and
+a sequence of combining marks canonically compose to. If no such character
+exists, then an error is reported.
+
+Each argument is either a codepoint prefixed with U+ (e.g., U+0301) or
+literal text. All arguments are concatenated in order before composing.
+
+With --all, every precomposed character whose canonical decomposition begins
+with the given base character is listed instead.
+";
+
+const ABOUT_CHECK_NORMALIZATION: &'static str = "\
+This sub-command checks that the given files are in a Unicode normalization
+form. Every sequence of codepoints that isn't in the requested form is
+reported along with its line and column (counted in codepoints), followed by
+the offending codepoints.
+
+The NFC_Quick_Check and NFKC_Quick_Check properties are used to find
+offending sequences quickly. Sequences whose quick check result is Maybe are
+fully verified by normalizing them.
+
+If any sequence isn't in the requested form, then this exits with a non-zero
+status.
+";
+
+const ABOUT_GRAPHEMES: &'static str = "\
+This sub-command splits text into extended grapheme clusters, as described in
+UAX #29, and prints the codepoints of each cluster.
+
+Every codepoint is shown along with the rule that decided whether there is a
+boundary (÷) or no boundary (×) before it.
+";
+
+const ABOUT_SEGMENT: &'static str = "\
+This sub-command splits text into words or sentences, as described in
+UAX #29. Each segment is shown along with the rule that decided the boundary
+before it.
+";
+
+const ABOUT_LINEBREAK: &'static str = "\
+This sub-command finds every line break opportunity in text, as described in
+UAX #14. The text is split into segments that each end at an opportunity,
+which is either mandatory (!) or allowed (÷), and the rule that decided each
+opportunity is shown.
+
+With --width, the text is instead wrapped at the given number of terminal
+columns, breaking lines only at break opportunities. Wide characters, such as
+CJK ideographs and emoji, take two columns, as reported by the width
+sub-command.
+";
+
+const ABOUT_BIDI: &'static str = "\
+This sub-command resolves the embedding level of every codepoint in text
+using the Unicode Bidirectional Algorithm described in UAX #9. Each paragraph
+is shown with its direction, followed by the bidi class and resolved level of
+every codepoint and the visual order of the paragraph when displayed as a
+single line. Codepoints removed by rule X9 have no level and are marked with
+x.
+
+Unmatched isolates, embeddings and overrides are reported as warnings.
+";
+
+const ABOUT_WIDTH: &'static str = "\
+This sub-command computes how many terminal columns text occupies. The width
+of every codepoint is shown along with its East_Asian_Width, its
+General_Category and the reason for its width.
+
+Combining marks, format characters and controls take no columns. Wide and
+fullwidth codepoints, along with codepoints displayed as emoji by default,
+take two columns. Ambiguous codepoints take one column unless
+--ambiguous wide is given.
+
+Widths are computed one grapheme cluster at a time, so that a cluster shown
+as a single emoji, such as a flag, an emoji with a skin tone, an emoji ZWJ
+sequence or a codepoint followed by U+FE0F, takes two columns. The first
+codepoint of such a cluster is given its full width.
+
+No emoji data is embedded for Unicode 9.0, so with --unicode-version 9.0,
+only East_Asian_Width decides how wide an emoji is.
+";
+
+const ABOUT_SCRIPTS: &'static str = "\
+This sub-command reports the scripts used by the given text, as determined
+by the Script_Extensions property, along with the resolved script set of the
+text as defined by UTS #39 section 5.1.
+
+Text whose resolved script set is not empty is single-script. Text that mixes
+Latin with Han and the scripts customarily written with Han in Japanese,
+Chinese or Korean is highlightable; this corresponds to the Highly
+Restrictive level of UTS #39 section 5.2. All other text is mixed, in which
+case the runs of each script in the text are shown.
+
+If the argument names a file, then the contents of the file are analyzed.
+";
+
+const ABOUT_CONFUSABLES: &'static str = "\
+This sub-command detects visually confusable text, as described in UTS #39.
+
+When given a single codepoint, every codepoint that is visually confusable
+with it is printed. When given two strings, the skeleton of each is computed
+and the strings are reported as confusable if their skeletons are equal.
+";
+
+const ABOUT_IDENT: &'static str = "\
+This sub-command checks whether each of the given names is a valid default
+identifier, as defined by UAX #31. For invalid names, the first codepoint that
+isn't permitted is shown along with its properties. The restriction level of
+UTS #39 section 5.2 is also computed for each name.
+
+This exits with an error if any of the names are not valid identifiers.
+";
+
+const ABOUT_AUDIT: &'static str = "\
+This sub-command audits source files for codepoints that can make code
+display differently than it is parsed, such as in the Trojan Source attacks.
+Every suspicious codepoint is reported along with its line and column
+(counted in codepoints), followed by the codepoint itself.
+
+The following codepoints are reported: bidi embedding, override and isolate
+controls, Default_Ignorable_Code_Point codepoints (which includes zero width
+characters), whitespace other than the ASCII space, controls other than tab,
+line feed and carriage return, noncharacters, private use codepoints and
+unassigned codepoints.
+
+Codepoints that are expected in the audited files may be allowed with the
+--allow flag. If any codepoint is reported, then this exits with a non-zero
+status.
+";
+
+const ABOUT_EMOJI: &'static str = "\
+This sub-command builds emoji sequences from their parts, and decodes emoji
+sequences back into their parts. The resulting sequence is printed along with
+its name and whether it is recommended for general interchange (RGI).
+
+A flag is built from a region code, e.g., 'rucd emoji flag US', or from a
+subdivision code, e.g., 'rucd emoji flag gbsct'. A skin tone is applied to an
+emoji with, e.g., 'rucd emoji skin-tone U+1F44B medium'. Emoji are joined
+with zero width joiners with, e.g., 'rucd emoji zwj U+1F469 U+1F4BB'.
+
+When given an existing sequence instead of parts, e.g., 'rucd emoji flag 🇺🇸',
+the sequence is decoded.
+";
+
+const ABOUT_VARIANTS: &'static str = "\
+This sub-command lists the variation sequences that begin with the given
+codepoint, along with a description of each. Variation sequences come from
+StandardizedVariants.txt, such as the CJK compatibility ideograph variants,
+and from emoji-variation-sequences.txt, which select between text and emoji
+style. The Mongolian and Manichaean sequences of StandardizedVariants.txt
+aren't included yet.
+
+When given a variation selector, every sequence that uses it is listed.
+";
+
+const ABOUT_SINCE: &'static str = "\
+This sub-command prints every codepoint that was introduced in the given
+version of Unicode, according to the Age property. The version may be given
+as, e.g., 9.0 or V9_0.
+";
+
+const ABOUT_TIMELINE: &'static str = "\
+This sub-command prints the number of codepoints introduced in each version
+of Unicode, according to the Age property, along with a histogram. The counts
+may be broken down by block or by script, in which case each block or script
+lists the versions that added codepoints to it. The counts may also be
+restricted to a single block or to a single script, where a codepoint belongs
+to the script given by its Script property.
+";
+
+const ABOUT_BLOCK: &'static str = "\
+This sub-command prints every codepoint in a Unicode block. The block name is
+matched loosely against block names and their aliases, so that, e.g., greek
+matches the Greek_And_Coptic block.
+";
+
+const ABOUT_NORMALIZE: &'static str = "\
+This sub-command normalizes text to one of the four normalization forms
+described in UAX #15: NFC, NFD, NFKC or NFKD.
 ";
 
 /// Build a clap application.
@@ -56,9 +255,315 @@ pub fn app() -> App<'static, 'static> {
             .short("s")
             .help("Case sensitive search. By default, search is case \
                    insensitive."))
+        .arg(Arg::with_name("labels")
+            .long("labels")
+            .short("L")
+            .help("Also match the pattern against code point labels, such \
+                   as <control-0009> or <reserved-0378>, of codepoints \
+                   without names."))
+        .arg(Arg::with_name("long")
+            .long("long")
+            .short("l")
+            .help("Show every known property of each codepoint found. \
+                   Emoji properties come from Emoji 5.0, except for \
+                   Extended_Pictographic, which was first published in \
+                   Emoji 11.0 and comes from Emoji 13.0."))
+        .arg(Arg::with_name("mirrored")
+            .long("mirrored")
+            .short("m")
+            .help("Only show codepoints that have a Bidi_Mirroring_Glyph, \
+                   side by side with their mirror image."))
+        .arg(Arg::with_name("numeric-value")
+            .long("numeric-value")
+            .short("n")
+            .takes_value(true)
+            .allow_hyphen_values(true)
+            .value_name("VALUE")
+            .help("Only show codepoints whose Numeric_Value is the given \
+                   integer or fraction, e.g., 7 or 1/2."))
+        .arg(Arg::with_name("script-extension")
+            .long("script-extension")
+            .short("x")
+            .takes_value(true)
+            .value_name("SCRIPT")
+            .help("Only show codepoints whose Script_Extensions contain the \
+                   given script. Unlike Script, this includes codepoints \
+                   shared by several scripts, such as the Common digits \
+                   and punctuation used by Arabic."))
         .arg(Arg::with_name("pattern")
             .help("A regular expression to apply against all character \
-                   names."));
+                   names and the names of emoji sequences. Emoji sequences \
+                   aren't searched with --unicode-version 9.0, since no \
+                   emoji data is embedded for it."));
+    let cmd_normalize = SubCommand::with_name("normalize")
+        .author(crate_authors!())
+        .version(crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("Normalize text to NFC, NFD, NFKC or NFKD.")
+        .before_help(ABOUT_NORMALIZE)
+        .arg(Arg::with_name("form")
+            .long("form")
+            .short("f")
+            .takes_value(true)
+            .possible_values(&["nfc", "nfd", "nfkc", "nfkd"])
+            .default_value("nfc")
+            .help("The normalization form to apply."))
+        .arg(Arg::with_name("table")
+            .long("table")
+            .short("t")
+            .help("Show the codepoints of the text before and after \
+                   normalization."))
+        .arg(Arg::with_name("text")
+            .help("The text to normalize.")
+            .required(true));
+    let cmd_decompose = SubCommand::with_name("decompose")
+        .author(crate_authors!())
+        .version(crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("Show the decomposition tree of a codepoint.")
+        .before_help(ABOUT_DECOMPOSE)
+        .arg(Arg::with_name("codepoint")
+            .help("The codepoint to decompose.")
+            .required(true));
+    let cmd_compose = SubCommand::with_name("compose")
+        .author(crate_authors!())
+        .version(crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("Compose a base character and combining marks.")
+        .before_help(ABOUT_COMPOSE)
+        .arg(Arg::with_name("all")
+            .long("all")
+            .short("a")
+            .help("List all precomposed characters whose canonical \
+                   decomposition begins with the given base character."))
+        .arg(Arg::with_name("input")
+            .help("The codepoints or text to compose.")
+            .multiple(true)
+            .required(true));
+    let cmd_check_normalization =
+        SubCommand::with_name("check-normalization")
+        .author(crate_authors!())
+        .version(crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("Check that files are in a normalization form.")
+        .before_help(ABOUT_CHECK_NORMALIZATION)
+        .arg(Arg::with_name("form")
+            .long("form")
+            .short("f")
+            .takes_value(true)
+            .possible_values(&["nfc", "nfd", "nfkc", "nfkd"])
+            .default_value("nfc")
+            .help("The normalization form to check."))
+        .arg(Arg::with_name("file")
+            .help("The files to check.")
+            .multiple(true)
+            .required(true));
+    let cmd_graphemes = SubCommand::with_name("graphemes")
+        .author(crate_authors!())
+        .version(crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("Split text into grapheme clusters.")
+        .before_help(ABOUT_GRAPHEMES)
+        .arg(Arg::with_name("text")
+            .help("The text to segment.")
+            .required(true));
+    let cmd_segment = SubCommand::with_name("segment")
+        .author(crate_authors!())
+        .version(crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("Split text into words or sentences.")
+        .before_help(ABOUT_SEGMENT)
+        .arg(Arg::with_name("words")
+            .long("words")
+            .short("w")
+            .conflicts_with("sentences")
+            .help("Split text into words. This is the default."))
+        .arg(Arg::with_name("sentences")
+            .long("sentences")
+            .short("s")
+            .help("Split text into sentences."))
+        .arg(Arg::with_name("text")
+            .help("The text to segment.")
+            .required(true));
+    let cmd_linebreak = SubCommand::with_name("linebreak")
+        .author(crate_authors!())
+        .version(crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("Find line break opportunities in text.")
+        .before_help(ABOUT_LINEBREAK)
+        .arg(Arg::with_name("width")
+            .long("width")
+            .short("w")
+            .takes_value(true)
+            .help("Wrap the text at this many columns."))
+        .arg(Arg::with_name("text")
+            .help("The text to analyze.")
+            .required(true));
+    let cmd_bidi = SubCommand::with_name("bidi")
+        .author(crate_authors!())
+        .version(crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("Resolve the bidi levels and visual order of text.")
+        .before_help(ABOUT_BIDI)
+        .arg(Arg::with_name("direction")
+            .long("direction")
+            .short("d")
+            .takes_value(true)
+            .possible_values(&["auto", "ltr", "rtl"])
+            .default_value("auto")
+            .help("The paragraph direction. By default, it is determined \
+                   by the first strong character in each paragraph."))
+        .arg(Arg::with_name("text")
+            .help("The text to analyze.")
+            .required(true));
+    let cmd_width = SubCommand::with_name("width")
+        .author(crate_authors!())
+        .version(crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("Compute the display width of text.")
+        .before_help(ABOUT_WIDTH)
+        .arg(Arg::with_name("ambiguous")
+            .long("ambiguous")
+            .short("a")
+            .takes_value(true)
+            .possible_values(&["narrow", "wide"])
+            .default_value("narrow")
+            .help("The width of codepoints whose East_Asian_Width is \
+                   ambiguous."))
+        .arg(Arg::with_name("text")
+            .help("The text to measure.")
+            .required(true));
+    let cmd_scripts = SubCommand::with_name("scripts")
+        .author(crate_authors!())
+        .version(crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("Detect the scripts used by text.")
+        .before_help(ABOUT_SCRIPTS)
+        .arg(Arg::with_name("text")
+            .help("The text to analyze, or a file containing it.")
+            .required(true));
+    let cmd_confusables = SubCommand::with_name("confusables")
+        .author(crate_authors!())
+        .version(crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("Find visually confusable codepoints and strings.")
+        .before_help(ABOUT_CONFUSABLES)
+        .arg(Arg::with_name("input")
+            .help("A codepoint, or two strings to compare.")
+            .multiple(true)
+            .required(true));
+    let cmd_ident = SubCommand::with_name("ident")
+        .author(crate_authors!())
+        .version(crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("Validate identifiers.")
+        .before_help(ABOUT_IDENT)
+        .arg(Arg::with_name("name")
+            .help("The names to check.")
+            .multiple(true)
+            .required(true));
+    let cmd_audit = SubCommand::with_name("audit")
+        .author(crate_authors!())
+        .version(crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("Audit files for invisible and bidi control codepoints.")
+        .before_help(ABOUT_AUDIT)
+        .arg(Arg::with_name("allow")
+            .long("allow")
+            .short("a")
+            .takes_value(true)
+            .multiple(true)
+            .number_of_values(1)
+            .value_name("CODEPOINT")
+            .help("Do not report the given codepoint. This flag may be \
+                   given multiple times."))
+        .arg(Arg::with_name("file")
+            .help("The files to audit.")
+            .multiple(true)
+            .required(true));
+    let cmd_emoji = SubCommand::with_name("emoji")
+        .author(crate_authors!())
+        .version(crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("Build and decode emoji sequences.")
+        .before_help(ABOUT_EMOJI)
+        .arg(Arg::with_name("kind")
+            .help("The kind of sequence to build or decode.")
+            .possible_values(&["flag", "skin-tone", "zwj"])
+            .required(true))
+        .arg(Arg::with_name("input")
+            .help("The parts of the sequence, or a sequence to decode.")
+            .multiple(true)
+            .required(true));
+    let cmd_variants = SubCommand::with_name("variants")
+        .author(crate_authors!())
+        .version(crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("List the variation sequences of a codepoint.")
+        .before_help(ABOUT_VARIANTS)
+        .arg(Arg::with_name("codepoint")
+            .help("A codepoint or variation selector.")
+            .required(true));
+    let cmd_since = SubCommand::with_name("since")
+        .author(crate_authors!())
+        .version(crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("Print the codepoints introduced in a Unicode version.")
+        .before_help(ABOUT_SINCE)
+        .arg(Arg::with_name("version")
+            .help("The Unicode version, e.g., 9.0.")
+            .required(true));
+    let cmd_timeline = SubCommand::with_name("timeline")
+        .author(crate_authors!())
+        .version(crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("Print the number of codepoints added in each Unicode \
+                version.")
+        .before_help(ABOUT_TIMELINE)
+        .arg(Arg::with_name("block")
+            .long("block")
+            .short("b")
+            .takes_value(true)
+            .value_name("BLOCK")
+            .help("Only count codepoints in the given block."))
+        .arg(Arg::with_name("script")
+            .long("script")
+            .short("s")
+            .takes_value(true)
+            .value_name("SCRIPT")
+            .help("Only count codepoints with the given Script."))
+        .arg(Arg::with_name("by")
+            .long("by")
+            .takes_value(true)
+            .possible_values(&["block", "script"])
+            .help("Count the codepoints added to each block or script \
+                   separately."));
+    let cmd_block = SubCommand::with_name("block")
+        .author(crate_authors!())
+        .version(crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("Print the codepoints in a Unicode block.")
+        .before_help(ABOUT_BLOCK)
+        .arg(Arg::with_name("assigned")
+            .long("assigned")
+            .short("a")
+            .help("Only print assigned codepoints."))
+        .arg(Arg::with_name("block")
+            .help("The name of the block to print.")
+            .required(true));
+    let cmd_list_blocks = SubCommand::with_name("list-blocks")
+        .author(crate_authors!())
+        .version(crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("Print every Unicode block with its range, size, number of \
+                assigned codepoints and most common script.");
+    let cmd_list_scripts = SubCommand::with_name("list-scripts")
+        .author(crate_authors!())
+        .version(crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("Print every script with the number of codepoints whose \
+                Script (sc) is that script, and the number whose \
+                Script_Extensions (scx) contain it.");
     let cmd_list_properties = SubCommand::with_name("list-properties")
         .author(crate_authors!())
         .version(crate_version!())
@@ -82,7 +587,46 @@ pub fn app() -> App<'static, 'static> {
         .template(TEMPLATE)
         .max_term_width(100)
         .setting(AppSettings::UnifiedHelpMessage)
+        .arg(Arg::with_name("pictures")
+            .long("pictures")
+            .short("P")
+            .global(true)
+            .help("Show invisible characters in codepoint tables using \
+                   Control Pictures (e.g., \u{2409} for a tab) and labels \
+                   (e.g., <ZWJ>). Surrogates, noncharacters and private use \
+                   codepoints are also labeled."))
+        .arg(Arg::with_name("unicode-version")
+            .long("unicode-version")
+            .takes_value(true)
+            .value_name("VERSION")
+            .global(true)
+            .help("The version of Unicode to answer questions with, e.g., \
+                   9.0. Only the versions embedded in rucd are available, \
+                   which are currently 9.0 and 10.0. The confusable, \
+                   identifier status, emoji and variation sequence data of \
+                   UTS #39, UTS #51 and StandardizedVariants.txt is only \
+                   embedded for 10.0. [default: latest]"))
         .subcommand(cmd_search)
+        .subcommand(cmd_normalize)
+        .subcommand(cmd_decompose)
+        .subcommand(cmd_compose)
+        .subcommand(cmd_check_normalization)
+        .subcommand(cmd_graphemes)
+        .subcommand(cmd_segment)
+        .subcommand(cmd_linebreak)
+        .subcommand(cmd_bidi)
+        .subcommand(cmd_width)
+        .subcommand(cmd_scripts)
+        .subcommand(cmd_confusables)
+        .subcommand(cmd_ident)
+        .subcommand(cmd_audit)
+        .subcommand(cmd_emoji)
+        .subcommand(cmd_variants)
+        .subcommand(cmd_since)
+        .subcommand(cmd_timeline)
+        .subcommand(cmd_block)
+        .subcommand(cmd_list_blocks)
+        .subcommand(cmd_list_scripts)
         .subcommand(cmd_list_properties)
         .subcommand(cmd_list_property_values)
 }
@@ -97,3 +641,111 @@ pub fn arg_to_str(name: &str, value: Option<&OsStr>) -> Result<String> {
         Some(value) => Ok(value.to_string()),
     }
 }
+
+/// Convert an argument to one of the versions of Unicode embedded in rucd.
+///
+/// A version may be given as `latest`, which refers to the newest version,
+/// or as a version number with an optional update number, e.g., `10.0` or
+/// `10.0.0`. When no version is given, the newest version is used.
+pub fn arg_to_unicode_version(
+    value: Option<&OsStr>,
+) -> Result<&'static str> {
+    // This flag has no default value in clap, since clap would otherwise
+    // show it as a required flag in usage messages.
+    let given = match value {
+        None => "latest".to_string(),
+        Some(_) => arg_to_str("unicode-version", value)?,
+    };
+    if given == "latest" {
+        return Ok(UNICODE_VERSIONS[UNICODE_VERSIONS.len() - 1]);
+    }
+    let mut parts = given.split('.').map(|part| part.parse::<u32>().ok());
+    let (major, minor, update) = (parts.next(), parts.next(), parts.next());
+    let version = match (major, minor, update, parts.next()) {
+        (Some(Some(major)), Some(Some(minor)), None, None)
+        | (Some(Some(major)), Some(Some(minor)), Some(Some(0)), None) => {
+            format!("{}.{}", major, minor)
+        }
+        _ => return err!("invalid Unicode version: {}", given),
+    };
+    match UNICODE_VERSIONS.iter().find(|&&v| v == version) {
+        Some(&version) => Ok(version),
+        None => err!(
+            "Unicode {} is not available, rucd only embeds: {}",
+            given, UNICODE_VERSIONS.join(", ")),
+    }
+}
+
+/// Convert an argument to text.
+///
+/// If the argument names a file that exists, then the contents of the file
+/// are returned. Otherwise, the argument itself is the text.
+pub fn arg_to_text(name: &str, value: Option<&OsStr>) -> Result<String> {
+    if let Some(path) = value.map(Path::new) {
+        if path.is_file() {
+            let mut text = String::new();
+            let res = File::open(path)
+                .and_then(|mut f| f.read_to_string(&mut text));
+            if let Err(err) = res {
+                return err!("{}: {}", path.display(), err);
+            }
+            return Ok(text);
+        }
+    }
+    arg_to_str(name, value)
+}
+
+/// Convert an argument to a codepoint.
+///
+/// A codepoint may be given as a hexadecimal number, optionally prefixed
+/// with `U+`, or as a single literal character.
+pub fn arg_to_codepoint(
+    name: &str,
+    value: Option<&OsStr>,
+) -> Result<Codepoint> {
+    let value = arg_to_str(name, value)?;
+    let mut chars = value.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Ok(Codepoint::from(c));
+    }
+    value.parse()
+}
+
+/// Convert an argument to a sequence of codepoints.
+///
+/// An argument prefixed with `U+` is parsed as a hexadecimal codepoint.
+/// Otherwise, the argument is taken as literal text.
+pub fn arg_to_codepoints(
+    name: &str,
+    value: &OsStr,
+) -> Result<Vec<Codepoint>> {
+    let value = arg_to_str(name, Some(value))?;
+    if value.starts_with("U+") || value.starts_with("u+") {
+        return Ok(vec![value.parse()?]);
+    }
+    Ok(value.chars().map(Codepoint::from).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsStr;
+
+    use super::arg_to_unicode_version;
+
+    fn version(value: &str) -> Option<&'static str> {
+        arg_to_unicode_version(Some(OsStr::new(value))).ok()
+    }
+
+    #[test]
+    fn unicode_versions() {
+        assert_eq!("10.0", arg_to_unicode_version(None).unwrap());
+        assert_eq!(Some("10.0"), version("latest"));
+        assert_eq!(Some("9.0"), version("9.0"));
+        assert_eq!(Some("9.0"), version("9.0.0"));
+        assert_eq!(Some("10.0"), version("10.0"));
+        assert_eq!(None, version("9.0.1"));
+        assert_eq!(None, version("8.0"));
+        assert_eq!(None, version("15.1"));
+        assert_eq!(None, version("ten"));
+    }
+}
@@ -0,0 +1,181 @@
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
+
+use clap;
+
+use app::arg_to_codepoint;
+use bidi::bidi_class;
+use codepoint::Codepoint;
+use display::ShortWriter;
+use error::Result;
+use tables::{in_ranges, range_value, ucd};
+
+pub fn command(args: &clap::ArgMatches) -> Result<()> {
+    let mut allowed = vec![];
+    if let Some(values) = args.values_of_os("allow") {
+        for value in values {
+            allowed.push(arg_to_codepoint("allow", Some(value))?);
+        }
+    }
+    let pictures = args.is_present("pictures");
+    let mut count = 0;
+    for path in args.values_of_os("file").unwrap() {
+        count += audit_file(Path::new(path), &allowed, pictures)?;
+    }
+    if count > 0 {
+        return err!("found {} suspicious codepoint(s)", count);
+    }
+    Ok(())
+}
+
+/// Report every suspicious codepoint in the given file, except for those
+/// in `allowed`.
+///
+/// This returns the number of codepoints reported. If `pictures` is
+/// enabled, then each codepoint is shown using `picture_char`.
+fn audit_file(
+    path: &Path,
+    allowed: &[Codepoint],
+    pictures: bool,
+) -> Result<u64> {
+    let mut text = String::new();
+    let res = File::open(path).and_then(|mut f| f.read_to_string(&mut text));
+    if let Err(err) = res {
+        return err!("{}: {}", path.display(), err);
+    }
+
+    let found = find_suspicious(&text, allowed);
+    for &(line, column, cp, reason) in &found {
+        println!("{}:{}:{}: {}", path.display(), line, column, reason);
+        let mut wtr = ShortWriter::new(io::stdout());
+        wtr.pictures(pictures);
+        wtr.write_codepoint(cp)?;
+        wtr.flush()?;
+        println!();
+    }
+    Ok(found.len() as u64)
+}
+
+/// Return every suspicious codepoint in the given text, except for those in
+/// `allowed`, along with the reason it is suspicious.
+///
+/// Each codepoint is returned with its 1-based line number and its 1-based
+/// column, counted in codepoints.
+pub fn find_suspicious(
+    text: &str,
+    allowed: &[Codepoint],
+) -> Vec<(usize, usize, Codepoint, &'static str)> {
+    let mut found = vec![];
+    for (i, line) in text.lines().enumerate() {
+        for (column, c) in line.chars().enumerate() {
+            let cp = Codepoint::from(c);
+            if allowed.contains(&cp) {
+                continue;
+            }
+            if let Some(reason) = suspicious(cp) {
+                found.push((i + 1, column + 1, cp, reason));
+            }
+        }
+    }
+    found
+}
+
+/// Return a short description of why the given codepoint is suspicious in
+/// source code, or `None` if it isn't.
+///
+/// Suspicious codepoints are those that can make text display differently
+/// than it is parsed: bidi controls, invisible codepoints, whitespace other
+/// than the ASCII space and controls other than tab, line feed and carriage
+/// return. Noncharacters, private use and unassigned codepoints are also
+/// reported, since they have no agreed upon appearance.
+pub fn suspicious(cp: Codepoint) -> Option<&'static str> {
+    match bidi_class(cp) {
+        "LRE" | "RLE" | "LRO" | "RLO" | "PDF" => {
+            return Some("bidi embedding or override");
+        }
+        "LRI" | "RLI" | "FSI" | "PDI" => return Some("bidi isolate"),
+        _ => {}
+    }
+    if in_ranges(ucd().default_ignorable_code_point, cp.value()) {
+        return Some("zero width (Default_Ignorable_Code_Point)");
+    }
+    if cp.is_noncharacter() {
+        return Some("noncharacter");
+    }
+    match range_value(ucd().general_category, cp.value()).unwrap_or("Cn") {
+        "Cc" => match cp.value() {
+            0x09 | 0x0A | 0x0D => None,
+            _ => Some("control"),
+        },
+        "Zs" | "Zl" | "Zp" if cp.value() != 0x20 => {
+            Some("unusual whitespace")
+        }
+        "Co" => Some("private use"),
+        "Cn" => Some("unassigned"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use codepoint::Codepoint;
+
+    use super::{find_suspicious, suspicious};
+
+    fn cp(c: char) -> Codepoint {
+        Codepoint::from(c)
+    }
+
+    #[test]
+    fn suspicious_codepoints() {
+        assert_eq!(None, suspicious(cp('a')));
+        assert_eq!(None, suspicious(cp(' ')));
+        assert_eq!(None, suspicious(cp('\t')));
+        assert_eq!(None, suspicious(cp('é')));
+        let embedding = Some("bidi embedding or override");
+        assert_eq!(embedding, suspicious(cp('\u{202E}')));
+        assert_eq!(embedding, suspicious(cp('\u{202C}')));
+        assert_eq!(Some("bidi isolate"), suspicious(cp('\u{2066}')));
+        assert_eq!(Some("bidi isolate"), suspicious(cp('\u{2069}')));
+        let zero_width = Some("zero width (Default_Ignorable_Code_Point)");
+        assert_eq!(zero_width, suspicious(cp('\u{200B}')));
+        assert_eq!(Some("control"), suspicious(cp('\u{7}')));
+        assert_eq!(Some("unusual whitespace"), suspicious(cp('\u{A0}')));
+        assert_eq!(Some("noncharacter"), suspicious(cp('\u{FFFF}')));
+        assert_eq!(Some("private use"), suspicious(cp('\u{E000}')));
+    }
+
+    #[test]
+    fn trojan_source() {
+        // The "commenting-out" example from the Trojan Source paper, where
+        // an RLO and isolates hide the end of a comment.
+        let text = "let admin = false;\n\
+                    /*\u{202E} } \u{2066}if (admin)\u{2069} \u{2066} begin \
+                    admins only */\n";
+        let found: Vec<(usize, usize, u32)> = find_suspicious(text, &[])
+            .into_iter()
+            .map(|(line, column, cp, _)| (line, column, cp.value()))
+            .collect();
+        assert_eq!(
+            vec![
+                (2, 3, 0x202E),
+                (2, 7, 0x2066),
+                (2, 18, 0x2069),
+                (2, 20, 0x2066),
+            ],
+            found);
+    }
+
+    #[test]
+    fn allow_list() {
+        let text = "a\u{200B}b\u{202E}c\u{200B}";
+        assert_eq!(3, find_suspicious(text, &[]).len());
+        let found = find_suspicious(text, &[cp('\u{200B}')]);
+        assert_eq!(1, found.len());
+        let (line, column, found, _) = found[0];
+        assert_eq!((1, 4, cp('\u{202E}')), (line, column, found));
+        let all = [cp('\u{200B}'), cp('\u{202E}')];
+        assert!(find_suspicious(text, &all).is_empty());
+    }
+}
@@ -0,0 +1,929 @@
+use std::cmp;
+use std::io;
+
+use clap;
+
+use app::arg_to_str;
+use codepoint::Codepoint;
+use display::{ColumnWriter, cell_char, cell_text};
+use error::Result;
+use normalize::decomposition;
+use tables::{in_ranges, range_value, ucd};
+
+/// The maximum explicit embedding level, as given by BD2 in UAX #9.
+const MAX_DEPTH: u8 = 125;
+
+pub fn command(args: &clap::ArgMatches) -> Result<()> {
+    let text = arg_to_str("text", args.value_of_os("text"))?;
+    let level = match args.value_of("direction").unwrap_or("auto") {
+        "ltr" => Some(0),
+        "rtl" => Some(1),
+        _ => None,
+    };
+    let cps: Vec<Codepoint> = text.chars().map(Codepoint::from).collect();
+    let pictures = args.is_present("pictures");
+
+    let mut start = 0;
+    for (i, paragraph) in paragraphs(&cps).into_iter().enumerate() {
+        let bidi = Paragraph::new(paragraph, level);
+        if i > 0 {
+            println!();
+        }
+        println!(
+            "paragraph {}: {} (level {})",
+            i + 1,
+            if direction(bidi.level) == "L" {
+                "left-to-right"
+            } else {
+                "right-to-left"
+            },
+            bidi.level);
+        for &(index, ref problem) in &bidi.problems {
+            println!("warning: U+{} at {}: {}",
+                     paragraph[index], start + index, problem);
+        }
+
+        let mut wtr = ColumnWriter::new(io::stdout());
+        wtr.write_row(vec![
+            "index".to_string(),
+            "codepoint".to_string(),
+            "character".to_string(),
+            "class".to_string(),
+            "level".to_string(),
+        ]);
+        for (j, &cp) in paragraph.iter().enumerate() {
+            wtr.write_row(vec![
+                (start + j).to_string(),
+                format!("U+{}", cp),
+                cell_char(cp, pictures),
+                bidi.classes[j].to_string(),
+                bidi.levels[j].map_or("x".to_string(), |l| l.to_string()),
+            ]);
+        }
+        wtr.flush()?;
+
+        let order = bidi.visual_order();
+        let indices: Vec<String> =
+            order.iter().map(|&j| (start + j).to_string()).collect();
+        let visual: String = order
+            .iter()
+            .filter_map(|&j| paragraph[j].scalar())
+            .collect();
+        println!("visual order: {}", indices.join(" "));
+        println!("visual text: {}", cell_text(&visual, pictures));
+        start += paragraph.len();
+    }
+    Ok(())
+}
+
+/// Return the `Bidi_Class` property value of the given codepoint, using the
+/// abbreviated value names from UnicodeData.txt.
+pub fn bidi_class(cp: Codepoint) -> &'static str {
+    range_value(ucd().bidi_class, cp.value()).unwrap_or("L")
+}
+
+/// Returns true if the given codepoint has the `Bidi_Mirrored` property.
+pub fn bidi_mirrored(cp: Codepoint) -> bool {
+    in_ranges(ucd().bidi_mirrored, cp.value())
+}
+
+/// Return the `Bidi_Mirroring_Glyph` property value of the given codepoint,
+/// which is the codepoint whose glyph is typically a mirror image of the
+/// given codepoint's glyph.
+pub fn bidi_mirroring_glyph(cp: Codepoint) -> Option<Codepoint> {
+    let table = ucd().bidi_mirroring_glyph;
+    table
+        .binary_search_by_key(&cp.value(), |&(cp, _)| cp)
+        .ok()
+        .map(|i| Codepoint::from_u32(table[i].1).unwrap())
+}
+
+/// Return the `Bidi_Paired_Bracket` and `Bidi_Paired_Bracket_Type` property
+/// values of the given codepoint, if it is a paired bracket.
+pub fn bidi_paired_bracket(
+    cp: Codepoint,
+) -> Option<(Codepoint, &'static str)> {
+    let table = ucd().bidi_brackets;
+    table
+        .binary_search_by_key(&cp.value(), |&(cp, _, _)| cp)
+        .ok()
+        .map(|i| {
+            let (_, pair, kind) = table[i];
+            (Codepoint::from_u32(pair).unwrap(), kind)
+        })
+}
+
+/// Split the given codepoints into paragraphs, as described by rule P1 of
+/// UAX #9. Each paragraph separator is kept with the paragraph it ends.
+fn paragraphs(cps: &[Codepoint]) -> Vec<&[Codepoint]> {
+    let mut paragraphs = vec![];
+    let mut start = 0;
+    for (i, &cp) in cps.iter().enumerate() {
+        if bidi_class(cp) == "B" {
+            paragraphs.push(&cps[start..i + 1]);
+            start = i + 1;
+        }
+    }
+    if start < cps.len() || cps.is_empty() {
+        paragraphs.push(&cps[start..]);
+    }
+    paragraphs
+}
+
+/// A paragraph whose embedding levels have been resolved according to the
+/// Unicode Bidirectional Algorithm in UAX #9.
+#[derive(Clone, Debug)]
+pub struct Paragraph {
+    /// The paragraph embedding level.
+    pub level: u8,
+    /// The original bidi class of every codepoint in the paragraph.
+    pub classes: Vec<&'static str>,
+    /// The resolved embedding level of every codepoint, after applying the
+    /// rules for a single line. Codepoints removed by rule X9 have no level.
+    pub levels: Vec<Option<u8>>,
+    /// Unmatched or unterminated isolates and embeddings, given by the index
+    /// of the codepoint at fault and a description of the problem.
+    pub problems: Vec<(usize, &'static str)>,
+}
+
+impl Paragraph {
+    /// Resolve the embedding levels of the given paragraph, which is
+    /// displayed as a single line.
+    ///
+    /// If a paragraph embedding level is not given, then it is determined
+    /// from the first strong character in the paragraph.
+    pub fn new(cps: &[Codepoint], level: Option<u8>) -> Paragraph {
+        let classes: Vec<&str> =
+            cps.iter().map(|&cp| bidi_class(cp)).collect();
+        let brackets: Vec<Option<Bracket>> = cps
+            .iter()
+            .map(|&cp| Bracket::new(cp))
+            .collect();
+        Paragraph::with_classes(classes, &brackets, level)
+    }
+
+    /// Resolve the embedding levels of a paragraph with the given bidi
+    /// classes and paired brackets.
+    fn with_classes(
+        classes: Vec<&'static str>,
+        brackets: &[Option<Bracket>],
+        level: Option<u8>,
+    ) -> Paragraph {
+        let level = level.unwrap_or_else(|| {
+            first_strong(&classes).map_or(0, |c| if c == "L" { 0 } else { 1 })
+        });
+        let mut para = Paragraph {
+            level,
+            classes,
+            levels: vec![],
+            problems: vec![],
+        };
+        let (mut types, levels) = para.explicit_levels();
+        let mut levels: Vec<Option<u8>> = levels
+            .into_iter()
+            .zip(&para.classes)
+            .map(|(l, &c)| if is_removed(c) { None } else { Some(l) })
+            .collect();
+        for seq in para.isolating_run_sequences(&levels) {
+            seq.resolve(&mut types, &para.classes, brackets);
+            for &i in &seq.indices {
+                let level = levels[i].unwrap();
+                levels[i] = Some(implicit_level(level, types[i]));
+            }
+        }
+        para.levels = levels;
+        para.reset_whitespace_levels();
+        para
+    }
+
+    /// Return the indices of the codepoints in this paragraph in visual
+    /// order, as described by rule L2. Codepoints removed by rule X9 are
+    /// omitted.
+    pub fn visual_order(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.levels.len())
+            .filter(|&i| self.levels[i].is_some())
+            .collect();
+        let level_at = |order: &[usize], k: usize| {
+            self.levels[order[k]].unwrap()
+        };
+        let levels: Vec<u8> =
+            (0..order.len()).map(|k| level_at(&order, k)).collect();
+        let highest = levels.iter().cloned().max().unwrap_or(0);
+        let lowest_odd = levels
+            .iter()
+            .cloned()
+            .filter(|l| l % 2 == 1)
+            .min()
+            .unwrap_or(highest + 1);
+        let mut level = highest;
+        while level >= lowest_odd {
+            let mut k = 0;
+            while k < order.len() {
+                if level_at(&order, k) < level {
+                    k += 1;
+                    continue;
+                }
+                let start = k;
+                while k < order.len() && level_at(&order, k) >= level {
+                    k += 1;
+                }
+                order[start..k].reverse();
+            }
+            level -= 1;
+        }
+        order
+    }
+
+    /// Apply rules X1 through X8, returning the class of every codepoint
+    /// after directional overrides and the explicit embedding level of
+    /// every codepoint.
+    fn explicit_levels(&mut self) -> (Vec<&'static str>, Vec<u8>) {
+        struct Status {
+            level: u8,
+            overridden: Option<&'static str>,
+            isolate: bool,
+            // The index of the codepoint that pushed this entry.
+            start: usize,
+        }
+
+        let classes = &self.classes;
+        let mut types = classes.clone();
+        let mut levels = vec![self.level; classes.len()];
+        let mut stack = vec![Status {
+            level: self.level,
+            overridden: None,
+            isolate: false,
+            start: 0,
+        }];
+        let (mut overflow_isolates, mut overflow_embeddings) = (0, 0);
+        let mut valid_isolates = 0;
+        for i in 0..classes.len() {
+            let class = classes[i];
+            let (level, overridden) = {
+                let last = stack.last().unwrap();
+                (last.level, last.overridden)
+            };
+            match class {
+                "RLE" | "LRE" | "RLO" | "LRO" => {
+                    levels[i] = level;
+                    let rtl = class == "RLE" || class == "RLO";
+                    let next = next_level(level, rtl);
+                    if next <= MAX_DEPTH
+                        && overflow_isolates == 0
+                        && overflow_embeddings == 0
+                    {
+                        stack.push(Status {
+                            level: next,
+                            overridden: match class {
+                                "RLO" => Some("R"),
+                                "LRO" => Some("L"),
+                                _ => None,
+                            },
+                            isolate: false,
+                            start: i,
+                        });
+                    } else if overflow_isolates == 0 {
+                        overflow_embeddings += 1;
+                    }
+                }
+                "RLI" | "LRI" | "FSI" => {
+                    levels[i] = level;
+                    if let Some(o) = overridden {
+                        types[i] = o;
+                    }
+                    let pdi = matching_pdi(classes, i);
+                    let rtl = match class {
+                        "RLI" => true,
+                        "LRI" => false,
+                        _ => {
+                            let end = pdi.unwrap_or(classes.len());
+                            first_strong(&classes[i + 1..end])
+                                .unwrap_or("L") != "L"
+                        }
+                    };
+                    let next = next_level(level, rtl);
+                    if pdi.is_none() {
+                        self.problems.push(
+                            (i, "isolate initiator has no matching PDI"));
+                    }
+                    if next <= MAX_DEPTH
+                        && overflow_isolates == 0
+                        && overflow_embeddings == 0
+                    {
+                        valid_isolates += 1;
+                        stack.push(Status {
+                            level: next,
+                            overridden: None,
+                            isolate: true,
+                            start: i,
+                        });
+                    } else {
+                        overflow_isolates += 1;
+                    }
+                }
+                "PDI" => {
+                    if overflow_isolates > 0 {
+                        overflow_isolates -= 1;
+                    } else if valid_isolates == 0 {
+                        self.problems.push(
+                            (i, "PDI has no matching isolate initiator"));
+                    } else {
+                        overflow_embeddings = 0;
+                        while !stack.last().unwrap().isolate {
+                            let status = stack.pop().unwrap();
+                            self.problems.push((
+                                status.start,
+                                "embedding is terminated by PDI, not PDF",
+                            ));
+                        }
+                        stack.pop();
+                        valid_isolates -= 1;
+                    }
+                    let last = stack.last().unwrap();
+                    levels[i] = last.level;
+                    if let Some(o) = last.overridden {
+                        types[i] = o;
+                    }
+                }
+                "PDF" => {
+                    levels[i] = level;
+                    if overflow_isolates > 0 {
+                        // This PDF is inside an isolate that overflowed, so
+                        // it matches nothing and is ignored.
+                    } else if overflow_embeddings > 0 {
+                        overflow_embeddings -= 1;
+                    } else if stack.len() >= 2
+                        && !stack.last().unwrap().isolate
+                    {
+                        stack.pop();
+                    } else {
+                        self.problems.push(
+                            (i, "PDF has no matching embedding or override"));
+                    }
+                }
+                "B" => {
+                    levels[i] = self.level;
+                }
+                "BN" => {
+                    levels[i] = level;
+                }
+                _ => {
+                    levels[i] = level;
+                    if let Some(o) = overridden {
+                        types[i] = o;
+                    }
+                }
+            }
+        }
+        for status in &stack[1..] {
+            if !status.isolate {
+                self.problems.push((
+                    status.start,
+                    "embedding or override has no matching PDF",
+                ));
+            }
+        }
+        self.problems.sort();
+        (types, levels)
+    }
+
+    /// Compute the isolating run sequences of this paragraph, as described
+    /// by BD13 and rule X10.
+    fn isolating_run_sequences(
+        &self,
+        levels: &[Option<u8>],
+    ) -> Vec<Sequence> {
+        let classes = &self.classes;
+
+        // Compute the level runs, ignoring codepoints removed by X9.
+        let mut runs: Vec<Vec<usize>> = vec![];
+        let mut last_level = None;
+        for (i, &level) in levels.iter().enumerate() {
+            let level = match level {
+                None => continue,
+                Some(level) => level,
+            };
+            if last_level == Some(level) {
+                runs.last_mut().unwrap().push(i);
+            } else {
+                runs.push(vec![i]);
+            }
+            last_level = Some(level);
+        }
+
+        // Join level runs that are linked by matching isolate initiators
+        // and PDIs.
+        let mut sequences = vec![];
+        let mut used = vec![false; runs.len()];
+        for r in 0..runs.len() {
+            if used[r] {
+                continue;
+            }
+            let mut indices = vec![];
+            let mut run = r;
+            loop {
+                used[run] = true;
+                indices.extend_from_slice(&runs[run]);
+                let last = *indices.last().unwrap();
+                let pdi = if is_isolate_initiator(classes[last]) {
+                    matching_pdi(classes, last)
+                } else {
+                    None
+                };
+                let next =
+                    pdi.and_then(|p| runs.iter().position(|run| run[0] == p));
+                match next {
+                    None => break,
+                    Some(next) => run = next,
+                }
+            }
+            sequences.push(self.sequence(indices, levels));
+        }
+        sequences
+    }
+
+    /// Build an isolating run sequence from the given indices, computing
+    /// its `sos` and `eos` types.
+    fn sequence(
+        &self,
+        indices: Vec<usize>,
+        levels: &[Option<u8>],
+    ) -> Sequence {
+        let classes = &self.classes;
+        let (first, last) = (indices[0], *indices.last().unwrap());
+        let level = levels[first].unwrap();
+        let before = (0..first)
+            .rev()
+            .filter_map(|i| levels[i])
+            .next()
+            .unwrap_or(self.level);
+        let after =
+            if is_isolate_initiator(classes[last]) {
+                self.level
+            } else {
+                (last + 1..classes.len())
+                    .filter_map(|i| levels[i])
+                    .next()
+                    .unwrap_or(self.level)
+            };
+        Sequence {
+            indices,
+            level,
+            sos: direction(cmp::max(level, before)),
+            eos: direction(cmp::max(level, after)),
+        }
+    }
+
+    /// Apply rule L1, which resets separators and trailing whitespace to
+    /// the paragraph embedding level.
+    fn reset_whitespace_levels(&mut self) {
+        let mut trailing = true;
+        for i in (0..self.classes.len()).rev() {
+            match self.classes[i] {
+                "B" | "S" => {
+                    trailing = true;
+                    self.levels[i] = Some(self.level);
+                }
+                "WS" | "FSI" | "LRI" | "RLI" | "PDI" => {
+                    if trailing {
+                        self.levels[i] = Some(self.level);
+                    }
+                }
+                class if is_removed(class) => {}
+                _ => trailing = false,
+            }
+        }
+    }
+}
+
+/// A paired bracket, as given by BidiBrackets.txt.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Bracket {
+    /// The opening bracket of the pair, normalized so that canonically
+    /// equivalent brackets compare equal.
+    opening: Codepoint,
+    /// Whether this is the opening bracket.
+    is_open: bool,
+}
+
+impl Bracket {
+    fn new(cp: Codepoint) -> Option<Bracket> {
+        let (pair, kind) = match bidi_paired_bracket(cp) {
+            None => return None,
+            Some(bracket) => bracket,
+        };
+        let is_open = kind == "Open";
+        let opening = canonical_bracket(if is_open { cp } else { pair });
+        Some(Bracket { opening, is_open })
+    }
+}
+
+/// Return the canonical equivalent of a bracket, so that, e.g., U+2329 and
+/// U+3008 are treated as the same bracket.
+fn canonical_bracket(cp: Codepoint) -> Codepoint {
+    match decomposition(cp) {
+        Some(ref d) if d.is_canonical() && d.mapping.len() == 1 => {
+            d.mapping[0]
+        }
+        _ => cp,
+    }
+}
+
+/// An isolating run sequence, as described by BD13.
+#[derive(Clone, Debug)]
+struct Sequence {
+    /// The indices of the codepoints in this sequence, in logical order.
+    indices: Vec<usize>,
+    /// The embedding level shared by every codepoint in this sequence.
+    level: u8,
+    /// The type of the start of the sequence, `L` or `R`.
+    sos: &'static str,
+    /// The type of the end of the sequence, `L` or `R`.
+    eos: &'static str,
+}
+
+impl Sequence {
+    /// Resolve the types of every codepoint in this sequence by applying
+    /// the weak type rules (W1-W7), paired bracket rule (N0) and neutral
+    /// type rules (N1-N2).
+    fn resolve(
+        &self,
+        types: &mut [&'static str],
+        classes: &[&'static str],
+        brackets: &[Option<Bracket>],
+    ) {
+        let idx = &self.indices;
+        let n = idx.len();
+
+        // W1
+        let mut prev = self.sos;
+        for &i in idx {
+            if types[i] == "NSM" {
+                types[i] = prev;
+            }
+            prev = if is_isolate_initiator(types[i]) || types[i] == "PDI" {
+                "ON"
+            } else {
+                types[i]
+            };
+        }
+        // W2 and W3
+        let mut last_strong = self.sos;
+        for &i in idx {
+            match types[i] {
+                "L" | "R" | "AL" => last_strong = types[i],
+                "EN" if last_strong == "AL" => types[i] = "AN",
+                _ => {}
+            }
+        }
+        for &i in idx {
+            if types[i] == "AL" {
+                types[i] = "R";
+            }
+        }
+        // W4
+        for k in 1..n.saturating_sub(1) {
+            let (a, b, c) =
+                (types[idx[k - 1]], types[idx[k]], types[idx[k + 1]]);
+            if b == "ES" && a == "EN" && c == "EN" {
+                types[idx[k]] = "EN";
+            } else if b == "CS" && a == c && (a == "EN" || a == "AN") {
+                types[idx[k]] = a;
+            }
+        }
+        // W5
+        let mut k = 0;
+        while k < n {
+            if types[idx[k]] != "ET" {
+                k += 1;
+                continue;
+            }
+            let start = k;
+            while k < n && types[idx[k]] == "ET" {
+                k += 1;
+            }
+            let adjacent_en = (start > 0 && types[idx[start - 1]] == "EN")
+                || (k < n && types[idx[k]] == "EN");
+            if adjacent_en {
+                for j in start..k {
+                    types[idx[j]] = "EN";
+                }
+            }
+        }
+        // W6
+        for &i in idx {
+            match types[i] {
+                "ES" | "ET" | "CS" => types[i] = "ON",
+                _ => {}
+            }
+        }
+        // W7
+        let mut last_strong = self.sos;
+        for &i in idx {
+            match types[i] {
+                "L" | "R" => last_strong = types[i],
+                "EN" if last_strong == "L" => types[i] = "L",
+                _ => {}
+            }
+        }
+
+        self.resolve_brackets(types, classes, brackets);
+
+        // N1 and N2
+        let embedding = direction(self.level);
+        let strong = |t: &str| -> Option<&'static str> {
+            match t {
+                "L" => Some("L"),
+                "R" | "EN" | "AN" => Some("R"),
+                _ => None,
+            }
+        };
+        let mut k = 0;
+        while k < n {
+            if !is_neutral(types[idx[k]]) {
+                k += 1;
+                continue;
+            }
+            let start = k;
+            while k < n && is_neutral(types[idx[k]]) {
+                k += 1;
+            }
+            let before =
+                if start == 0 {
+                    self.sos
+                } else {
+                    strong(types[idx[start - 1]]).unwrap_or(embedding)
+                };
+            let after =
+                if k == n {
+                    self.eos
+                } else {
+                    strong(types[idx[k]]).unwrap_or(embedding)
+                };
+            let resolved = if before == after { before } else { embedding };
+            for j in start..k {
+                types[idx[j]] = resolved;
+            }
+        }
+    }
+
+    /// Apply rule N0, which resolves paired brackets to the direction of
+    /// the text they enclose or surround.
+    fn resolve_brackets(
+        &self,
+        types: &mut [&'static str],
+        classes: &[&'static str],
+        brackets: &[Option<Bracket>],
+    ) {
+        let idx = &self.indices;
+        let pairs = self.bracket_pairs(types, brackets);
+        let embedding = direction(self.level);
+        let opposite = if embedding == "L" { "R" } else { "L" };
+        let strong = |t: &str| -> Option<&'static str> {
+            match t {
+                "L" => Some("L"),
+                "R" | "EN" | "AN" => Some("R"),
+                _ => None,
+            }
+        };
+        for (open, close) in pairs {
+            let mut found_embedding = false;
+            let mut found_opposite = false;
+            for k in open + 1..close {
+                match strong(types[idx[k]]) {
+                    Some(d) if d == embedding => found_embedding = true,
+                    Some(_) => found_opposite = true,
+                    None => {}
+                }
+            }
+            let resolved =
+                if found_embedding {
+                    embedding
+                } else if found_opposite {
+                    let context = (0..open)
+                        .rev()
+                        .filter_map(|k| strong(types[idx[k]]))
+                        .next()
+                        .unwrap_or(self.sos);
+                    if context == opposite { opposite } else { embedding }
+                } else {
+                    continue;
+                };
+            for &k in &[open, close] {
+                types[idx[k]] = resolved;
+                // Any NSMs following a bracket take on its new type.
+                for j in k + 1..idx.len() {
+                    if classes[idx[j]] != "NSM" {
+                        break;
+                    }
+                    types[idx[j]] = resolved;
+                }
+            }
+        }
+    }
+
+    /// Identify the bracket pairs in this sequence, as described by BD16.
+    /// Each pair is given by the positions of its brackets within this
+    /// sequence, and pairs are sorted by the position of their opening
+    /// bracket.
+    fn bracket_pairs(
+        &self,
+        types: &[&'static str],
+        brackets: &[Option<Bracket>],
+    ) -> Vec<(usize, usize)> {
+        const MAX_STACK: usize = 63;
+
+        let mut stack: Vec<(Codepoint, usize)> = vec![];
+        let mut pairs = vec![];
+        for (k, &i) in self.indices.iter().enumerate() {
+            let bracket = match brackets.get(i).and_then(|b| *b) {
+                Some(bracket) if types[i] == "ON" => bracket,
+                _ => continue,
+            };
+            if bracket.is_open {
+                if stack.len() == MAX_STACK {
+                    break;
+                }
+                stack.push((bracket.opening, k));
+                continue;
+            }
+            if let Some(depth) =
+                stack.iter().rposition(|&(o, _)| o == bracket.opening)
+            {
+                pairs.push((stack[depth].1, k));
+                stack.truncate(depth);
+            }
+        }
+        pairs.sort();
+        pairs
+    }
+}
+
+/// Return the class of the first strong character (`L`, `R` or `AL`) in
+/// the given classes, as described by rule P2, skipping over characters
+/// between an isolate initiator and its matching PDI.
+fn first_strong(classes: &[&'static str]) -> Option<&'static str> {
+    let mut depth = 0;
+    for &class in classes {
+        match class {
+            "L" | "R" | "AL" if depth == 0 => return Some(class),
+            "RLI" | "LRI" | "FSI" => depth += 1,
+            "PDI" if depth > 0 => depth -= 1,
+            "B" => return None,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Return the index of the PDI matching the isolate initiator at index `i`,
+/// as described by BD9.
+fn matching_pdi(classes: &[&'static str], i: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (j, &class) in classes.iter().enumerate().skip(i + 1) {
+        match class {
+            "RLI" | "LRI" | "FSI" => depth += 1,
+            "PDI" if depth == 0 => return Some(j),
+            "PDI" => depth -= 1,
+            "B" => return None,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Return the least level greater than the given level with the given
+/// direction.
+fn next_level(level: u8, rtl: bool) -> u8 {
+    if rtl {
+        (level + 1) | 1
+    } else {
+        (level + 2) & !1
+    }
+}
+
+/// Return the direction, `L` or `R`, of the given embedding level.
+fn direction(level: u8) -> &'static str {
+    if level % 2 == 0 { "L" } else { "R" }
+}
+
+/// Apply rules I1 and I2 to compute the level of a codepoint from its
+/// embedding level and resolved type.
+fn implicit_level(level: u8, resolved: &str) -> u8 {
+    match (level % 2, resolved) {
+        (0, "R") => level + 1,
+        (0, "AN") | (0, "EN") => level + 2,
+        (1, "L") | (1, "AN") | (1, "EN") => level + 1,
+        _ => level,
+    }
+}
+
+/// Returns true if codepoints with the given class are removed by rule X9.
+fn is_removed(class: &str) -> bool {
+    match class {
+        "RLE" | "LRE" | "RLO" | "LRO" | "PDF" | "BN" => true,
+        _ => false,
+    }
+}
+
+fn is_isolate_initiator(class: &str) -> bool {
+    class == "RLI" || class == "LRI" || class == "FSI"
+}
+
+/// Returns true if the given resolved type is treated as neutral by rules
+/// N1 and N2.
+fn is_neutral(class: &str) -> bool {
+    match class {
+        "B" | "S" | "WS" | "ON" | "RLI" | "LRI" | "FSI" | "PDI" => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::char;
+
+    use codepoint::Codepoint;
+
+    use super::{Bracket, Paragraph};
+
+    // These are still the 8.0.0 versions of the conformance tests, while
+    // the tables under test are from 10.0.0. They need to be replaced with
+    // BidiTest-10.0.0.txt and BidiCharacterTest-10.0.0.txt from
+    // https://www.unicode.org/Public/10.0.0/ucd/.
+    const BIDI_TEST: &'static str =
+        include_str!("../tests/data/BidiTest.txt");
+    const BIDI_CHARACTER_TEST: &'static str =
+        include_str!("../tests/data/BidiCharacterTest.txt");
+
+    fn parse_levels(field: &str) -> Vec<Option<u8>> {
+        field
+            .split_whitespace()
+            .map(|l| if l == "x" { None } else { Some(l.parse().unwrap()) })
+            .collect()
+    }
+
+    fn parse_order(field: &str) -> Vec<usize> {
+        field.split_whitespace().map(|i| i.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn bidi_test() {
+        let (mut levels, mut order) = (vec![], vec![]);
+        for line in BIDI_TEST.lines() {
+            let line = line.split('#').next().unwrap().trim();
+            if line.is_empty() {
+                continue;
+            }
+            if line.starts_with("@Levels:") {
+                levels = parse_levels(line.splitn(2, ':').nth(1).unwrap());
+                continue;
+            }
+            if line.starts_with("@Reorder:") {
+                order = parse_order(line.splitn(2, ':').nth(1).unwrap());
+                continue;
+            }
+            let mut fields = line.split(';');
+            let classes: Vec<&'static str> =
+                fields.next().unwrap().split_whitespace().collect();
+            let bitset: u8 = fields.next().unwrap().trim().parse().unwrap();
+            let brackets: Vec<Option<Bracket>> = vec![None; classes.len()];
+            for &(bit, level) in &[(1, None), (2, Some(0)), (4, Some(1))] {
+                if bitset & bit == 0 {
+                    continue;
+                }
+                let para =
+                    Paragraph::with_classes(classes.clone(), &brackets, level);
+                assert_eq!(levels, para.levels, "{} ({})", line, bit);
+                assert_eq!(order, para.visual_order(), "{} ({})", line, bit);
+            }
+        }
+    }
+
+    #[test]
+    fn bidi_character_test() {
+        for line in BIDI_CHARACTER_TEST.lines() {
+            let line = line.split('#').next().unwrap().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let fields: Vec<&str> = line.split(';').collect();
+            let cps: Vec<Codepoint> = fields[0]
+                .split_whitespace()
+                .map(|cp| u32::from_str_radix(cp, 16).unwrap())
+                .map(|cp| Codepoint::from(char::from_u32(cp).unwrap()))
+                .collect();
+            let direction = match fields[1] {
+                "0" => Some(0),
+                "1" => Some(1),
+                _ => None,
+            };
+            let para = Paragraph::new(&cps, direction);
+            let level: u8 = fields[2].parse().unwrap();
+            assert_eq!(level, para.level, "{}", line);
+            assert_eq!(parse_levels(fields[3]), para.levels, "{}", line);
+            let order = parse_order(fields[4]);
+            assert_eq!(order, para.visual_order(), "{}", line);
+        }
+    }
+}
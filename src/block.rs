@@ -0,0 +1,126 @@
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+
+use clap;
+use tabwriter::TabWriter;
+
+use app::arg_to_str;
+use codepoint::Codepoint;
+use display::ShortWriter;
+use error::Result;
+use list::find_canonical_property_value;
+use name::is_assigned;
+use script::script;
+use tables::{range_value, ucd};
+
+pub fn command_list_blocks(_: &clap::ArgMatches) -> Result<()> {
+    let mut wtr = TabWriter::new(io::stdout());
+    writeln!(wtr, "block\trange\tsize\tassigned\tscript")?;
+    for &(start, end, name) in ucd().block {
+        let cps = block_codepoints(start, end);
+        let assigned: Vec<Codepoint> =
+            cps.iter().cloned().filter(|&cp| is_assigned(cp)).collect();
+        writeln!(
+            wtr,
+            "{}\tU+{:04X}..U+{:04X}\t{}\t{}\t{}",
+            name,
+            start,
+            end,
+            cps.len(),
+            assigned.len(),
+            dominant_script(&assigned).unwrap_or(""))?;
+    }
+    wtr.flush()?;
+    Ok(())
+}
+
+pub fn command_block(args: &clap::ArgMatches) -> Result<()> {
+    let given = arg_to_str("block", args.value_of_os("block"))?;
+    let name = find_canonical_property_value("Block", &given)?;
+    let (start, end) = match block_range(name) {
+        None => return err!("block {} has no codepoints", name),
+        Some(range) => range,
+    };
+    let mut wtr = ShortWriter::new(io::stdout());
+    wtr.pictures(args.is_present("pictures"));
+    for cp in block_codepoints(start, end) {
+        if args.is_present("assigned") && !is_assigned(cp) {
+            continue;
+        }
+        wtr.write_codepoint(cp)?;
+    }
+    wtr.flush()?;
+    Ok(())
+}
+
+/// Return the `Block` property value of the given codepoint.
+pub fn block(cp: Codepoint) -> &'static str {
+    range_value(ucd().block, cp.value()).unwrap_or("No_Block")
+}
+
+/// Return the range of the block with the given canonical name.
+fn block_range(name: &str) -> Option<(u32, u32)> {
+    ucd().block
+        .iter()
+        .find(|&&(_, _, block)| block == name)
+        .map(|&(start, end, _)| (start, end))
+}
+
+fn block_codepoints(start: u32, end: u32) -> Vec<Codepoint> {
+    (start..end + 1).map(|n| Codepoint::from_u32(n).unwrap()).collect()
+}
+
+/// Return the script used by the most codepoints given, if any are given.
+///
+/// Ties are broken in favor of the script whose name sorts first.
+fn dominant_script(cps: &[Codepoint]) -> Option<&'static str> {
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for &cp in cps {
+        *counts.entry(script(cp)).or_insert(0) += 1;
+    }
+    let mut best: Option<(&str, usize)> = None;
+    for (script, count) in counts {
+        // Scripts are visited in name order, so only a strictly greater
+        // count replaces an earlier script.
+        if best.map_or(true, |(_, most)| count > most) {
+            best = Some((script, count));
+        }
+    }
+    best.map(|(script, _)| script)
+}
+
+#[cfg(test)]
+mod tests {
+    use codepoint::Codepoint;
+    use list::find_canonical_property_value;
+    use tables::ucd;
+
+    use super::dominant_script;
+
+    fn cps(text: &str) -> Vec<Codepoint> {
+        text.chars().map(Codepoint::from).collect()
+    }
+
+    #[test]
+    fn dominant_script_counts() {
+        assert_eq!(None, dominant_script(&[]));
+        assert_eq!(Some("Greek"), dominant_script(&cps("aαβ")));
+        assert_eq!(Some("Latin"), dominant_script(&cps("abα")));
+    }
+
+    #[test]
+    fn dominant_script_ties() {
+        assert_eq!(Some("Greek"), dominant_script(&cps("aα")));
+        assert_eq!(Some("Greek"), dominant_script(&cps("αa")));
+        assert_eq!(Some("Cyrillic"), dominant_script(&cps("aαж")));
+    }
+
+    #[test]
+    fn block_names_match_list_blocks() {
+        // The example in the `block` help must name a block exactly as
+        // `list-blocks` prints it.
+        let name = find_canonical_property_value("Block", "greek").unwrap();
+        assert_eq!("Greek_And_Coptic", name);
+        assert!(ucd().block.iter().any(|&(_, _, block)| block == name));
+    }
+}
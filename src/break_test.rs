@@ -0,0 +1,38 @@
+use std::char;
+
+/// Parse a single test from a break test file, such as GraphemeBreakTest.txt
+/// or LineBreakTest.txt, into the full text and the expected segments of
+/// that text.
+///
+/// Each test is a sequence of hexadecimal codepoints separated by `÷`
+/// wherever there is a break and `×` wherever there isn't. Comments and
+/// surrounding whitespace must already be removed.
+pub fn parse_break_test(line: &str) -> (String, Vec<String>) {
+    let mut segments = vec![];
+    let mut segment = String::new();
+    for token in line.split_whitespace() {
+        match token {
+            "÷" => {
+                if !segment.is_empty() {
+                    segments.push(segment);
+                    segment = String::new();
+                }
+            }
+            "×" => {}
+            hex => {
+                let cp = u32::from_str_radix(hex, 16).unwrap();
+                segment.push(char::from_u32(cp).unwrap());
+            }
+        }
+    }
+    (segments.concat(), segments)
+}
+
+/// Return the tests in the given break test file, with comments and blank
+/// lines removed.
+pub fn break_tests(data: &'static str) -> Vec<&'static str> {
+    data.lines()
+        .map(|line| line.split('#').next().unwrap().trim())
+        .filter(|line| !line.is_empty())
+        .collect()
+}
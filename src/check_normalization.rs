@@ -0,0 +1,89 @@
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
+
+use clap;
+
+use codepoint::Codepoint;
+use display::ShortWriter;
+use error::Result;
+use normalize::{
+    Form, QuickCheck, canonical_combining_class, is_normalized, normalize,
+    quick_check_codepoint,
+};
+
+pub fn command(args: &clap::ArgMatches) -> Result<()> {
+    let form: Form = args.value_of("form").unwrap_or("nfc").parse()?;
+    let pictures = args.is_present("pictures");
+    let mut count = 0;
+    for path in args.values_of_os("file").unwrap() {
+        count += check_file(form, Path::new(path), pictures)?;
+    }
+    if count > 0 {
+        return err!("found {} sequence(s) not in {}", count, form);
+    }
+    Ok(())
+}
+
+/// Check that every line in the given file is in the given normalization
+/// form, and report every sequence that isn't.
+///
+/// This returns the number of sequences reported. If `pictures` is enabled,
+/// then the codepoints of each sequence are shown using `picture_char`.
+fn check_file(form: Form, path: &Path, pictures: bool) -> Result<u64> {
+    let mut text = String::new();
+    let res = File::open(path).and_then(|mut f| f.read_to_string(&mut text));
+    if let Err(err) = res {
+        return err!("{}: {}", path.display(), err);
+    }
+
+    let mut count = 0;
+    for (i, line) in text.lines().enumerate() {
+        for (column, segment) in segments(form, line) {
+            if is_normalized(form, segment) {
+                continue;
+            }
+            count += 1;
+            let expected: Vec<String> = normalize(form, segment)
+                .chars()
+                .map(|c| format!("U+{}", Codepoint::from(c)))
+                .collect();
+            println!(
+                "{}:{}:{}: not in {} (expected {})",
+                path.display(), i + 1, column, form, expected.join(" "));
+            let mut wtr = ShortWriter::new(io::stdout());
+            wtr.pictures(pictures);
+            for c in segment.chars() {
+                wtr.write_codepoint(Codepoint::from(c))?;
+            }
+            wtr.flush()?;
+            println!();
+        }
+    }
+    Ok(count)
+}
+
+/// Split a line into segments that can each be normalized independently,
+/// along with the one-based column (in codepoints) at which each begins.
+///
+/// A segment begins at every starter whose quick check value is `Yes`, since
+/// normalization never reorders or composes codepoints across one.
+fn segments(form: Form, line: &str) -> Vec<(usize, &str)> {
+    let mut segments = vec![];
+    let (mut start, mut start_column) = (0, 1);
+    for (column, (i, c)) in line.char_indices().enumerate() {
+        let cp = Codepoint::from(c);
+        let boundary = i > 0
+            && canonical_combining_class(cp) == 0
+            && quick_check_codepoint(form, cp) == QuickCheck::Yes;
+        if boundary {
+            segments.push((start_column, &line[start..i]));
+            start = i;
+            start_column = column + 1;
+        }
+    }
+    if start < line.len() {
+        segments.push((start_column, &line[start..]));
+    }
+    segments
+}
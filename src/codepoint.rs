@@ -39,13 +39,37 @@ impl Codepoint {
     ///
     /// If this is a surrogate codepoint, then this returns `None`.
     pub fn scalar(self) -> Option<char> { char::from_u32(self.0) }
+
+    /// Returns true if this is a surrogate codepoint.
+    pub fn is_surrogate(self) -> bool {
+        0xD800 <= self.0 && self.0 <= 0xDFFF
+    }
+
+    /// Returns true if this is a noncharacter, i.e., one of the 66
+    /// codepoints permanently reserved for internal use.
+    pub fn is_noncharacter(self) -> bool {
+        (0xFDD0 <= self.0 && self.0 <= 0xFDEF) || self.0 & 0xFFFE == 0xFFFE
+    }
+
+    /// Returns true if this codepoint is in one of the private use areas.
+    pub fn is_private_use(self) -> bool {
+        (0xE000 <= self.0 && self.0 <= 0xF8FF)
+            || (0xF0000 <= self.0 && self.0 <= 0xFFFFD)
+            || (0x100000 <= self.0 && self.0 <= 0x10FFFD)
+    }
 }
 
 impl FromStr for Codepoint {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Codepoint> {
-        match u32::from_str_radix(s, 16) {
+        let hex =
+            if s.starts_with("U+") || s.starts_with("u+") {
+                &s[2..]
+            } else {
+                s
+            };
+        match u32::from_str_radix(hex, 16) {
             Ok(n) => Codepoint::from_u32(n),
             Err(err) => {
                 return err!(
@@ -56,6 +80,12 @@ impl FromStr for Codepoint {
     }
 }
 
+impl From<char> for Codepoint {
+    fn from(c: char) -> Codepoint {
+        Codepoint(c as u32)
+    }
+}
+
 impl fmt::Display for Codepoint {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{:04X}", self.0)
@@ -0,0 +1,91 @@
+use std::io;
+
+use clap;
+
+use app::{arg_to_codepoint, arg_to_codepoints};
+use codepoint::Codepoint;
+use display::ShortWriter;
+use error::Result;
+use normalize::{Form, decomposition, normalize};
+
+pub fn command(args: &clap::ArgMatches) -> Result<()> {
+    if args.is_present("all") {
+        if args.occurrences_of("input") > 1 {
+            return err!("--all expects a single base character");
+        }
+        let base = arg_to_codepoint("input", args.value_of_os("input"))?;
+        let mut wtr = ShortWriter::new(io::stdout());
+        wtr.pictures(args.is_present("pictures"));
+        for cp in precomposed(base) {
+            wtr.write_codepoint(cp)?;
+        }
+        wtr.flush()?;
+        return Ok(());
+    }
+
+    let mut cps = vec![];
+    for value in args.values_of_os("input").unwrap() {
+        cps.extend(arg_to_codepoints("input", value)?);
+    }
+    let composed = match compose(&cps) {
+        None => {
+            let cps: Vec<String> =
+                cps.iter().map(|cp| format!("U+{}", cp)).collect();
+            return err!(
+                "no precomposed character exists for {}", cps.join(" "));
+        }
+        Some(composed) => composed,
+    };
+    let mut wtr = ShortWriter::new(io::stdout());
+    wtr.pictures(args.is_present("pictures"));
+    wtr.write_codepoint(composed)?;
+    wtr.flush()?;
+    Ok(())
+}
+
+/// Return the single precomposed codepoint that the given sequence of
+/// codepoints canonically composes to, if one exists.
+fn compose(cps: &[Codepoint]) -> Option<Codepoint> {
+    let mut text = String::new();
+    for cp in cps {
+        match cp.scalar() {
+            None => return None,
+            Some(c) => text.push(c),
+        }
+    }
+    let composed = normalize(Form::Nfc, &text);
+    let mut composed = composed.chars();
+    let cp = match (composed.next(), composed.next()) {
+        (Some(c), None) => Codepoint::from(c),
+        _ => return None,
+    };
+    match decomposition(cp) {
+        Some(ref d) if d.is_canonical() => Some(cp),
+        _ => None,
+    }
+}
+
+/// Return every precomposed codepoint whose full canonical decomposition
+/// begins with the given base codepoint, in codepoint order.
+///
+/// This includes codepoints that are excluded from composition, since they
+/// are still precomposed forms of the base.
+fn precomposed(base: Codepoint) -> Vec<Codepoint> {
+    let mut found = vec![];
+    for n in 0..0x110000 {
+        let cp = Codepoint::from_u32(n).unwrap();
+        match decomposition(cp) {
+            Some(ref d) if d.is_canonical() => {}
+            _ => continue,
+        }
+        let c = match cp.scalar() {
+            None => continue,
+            Some(c) => c,
+        };
+        let decomposed = normalize(Form::Nfd, &c.to_string());
+        if decomposed.chars().next().map(Codepoint::from) == Some(base) {
+            found.push(cp);
+        }
+    }
+    found
+}
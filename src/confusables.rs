@@ -0,0 +1,139 @@
+use std::io;
+
+use clap;
+
+use app::{arg_to_codepoint, arg_to_str};
+use codepoint::Codepoint;
+use display::ShortWriter;
+use error::Result;
+use normalize::{Form, normalize};
+use tables::{require, ucd};
+
+pub fn command(args: &clap::ArgMatches) -> Result<()> {
+    require(ucd().confusables, "confusable data")?;
+    let values: Vec<_> = args.values_of_os("input").unwrap().collect();
+    if values.len() > 2 {
+        return err!("expected a codepoint or two strings to compare");
+    }
+    let mut wtr = ShortWriter::new(io::stdout());
+    wtr.pictures(args.is_present("pictures"));
+    if values.len() == 1 {
+        let cp = arg_to_codepoint("input", Some(values[0]))?;
+        for confusable in confusables(cp) {
+            wtr.write_codepoint(confusable)?;
+        }
+        wtr.flush()?;
+        return Ok(());
+    }
+
+    let text1 = arg_to_str("input", Some(values[0]))?;
+    let text2 = arg_to_str("input", Some(values[1]))?;
+    let (skel1, skel2) = (skeleton(&text1), skeleton(&text2));
+    println!("skeleton of {:?}: {:?}", text1, skel1);
+    println!("skeleton of {:?}: {:?}", text2, skel2);
+    println!("confusable: {}", if skel1 == skel2 { "yes" } else { "no" });
+    println!();
+    // Show every codepoint that is mapped to something else by the
+    // skeleton, since these are the ones that make the strings confusable.
+    for c in text1.chars().chain(text2.chars()) {
+        let cp = Codepoint::from(c);
+        if confusable_prototype(cp).is_some() {
+            wtr.write_codepoint(cp)?;
+        }
+    }
+    wtr.flush()?;
+    Ok(())
+}
+
+/// Return the prototype of the given codepoint from confusables.txt, if the
+/// codepoint is confusable with a different sequence of codepoints.
+///
+/// If the selected version of Unicode has no confusable data, then no
+/// codepoint has a prototype.
+pub fn confusable_prototype(cp: Codepoint) -> Option<&'static [u32]> {
+    let table = ucd().confusables.unwrap_or(&[]);
+    table
+        .binary_search_by_key(&cp.value(), |&(cp, _)| cp)
+        .ok()
+        .map(|i| table[i].1)
+}
+
+/// Return the skeleton of the given string, as defined by UTS #39
+/// section 4.
+///
+/// Two strings are confusable if and only if their skeletons are equal.
+/// The skeleton is computed by converting the string to NFD, replacing each
+/// codepoint with its prototype, and then converting the result to NFD
+/// again.
+pub fn skeleton(text: &str) -> String {
+    let mut mapped = String::new();
+    for c in normalize(Form::Nfd, text).chars() {
+        match confusable_prototype(Codepoint::from(c)) {
+            None => mapped.push(c),
+            Some(proto) => {
+                mapped.extend(proto.iter().filter_map(|&n| {
+                    Codepoint::from_u32(n).ok().and_then(|cp| cp.scalar())
+                }));
+            }
+        }
+    }
+    normalize(Form::Nfd, &mapped)
+}
+
+/// Return every codepoint whose skeleton is the same as the skeleton of
+/// the given codepoint, including the codepoint itself, in ascending order.
+pub fn confusables(cp: Codepoint) -> Vec<Codepoint> {
+    let target = match cp.scalar() {
+        None => return vec![cp],
+        Some(c) => skeleton(&c.to_string()),
+    };
+    let mut candidates = vec![cp];
+    for &(n, proto) in ucd().confusables.unwrap_or(&[]) {
+        candidates.push(Codepoint::from_u32(n).unwrap());
+        if proto.len() == 1 {
+            candidates.push(Codepoint::from_u32(proto[0]).unwrap());
+        }
+    }
+    candidates.sort();
+    candidates.dedup();
+    candidates
+        .into_iter()
+        .filter(|cand| match cand.scalar() {
+            None => false,
+            Some(c) => skeleton(&c.to_string()) == target,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use codepoint::Codepoint;
+
+    use super::{confusable_prototype, confusables, skeleton};
+
+    #[test]
+    fn skeletons() {
+        // The second string uses U+0430 CYRILLIC SMALL LETTER A.
+        assert_eq!(skeleton("paypal"), skeleton("p\u{430}ypal"));
+        assert_eq!(skeleton("paypal"), skeleton("paypa1"));
+        assert_eq!(skeleton("m"), skeleton("rn"));
+        assert_eq!("rn", skeleton("m"));
+        assert_ne!(skeleton("paypal"), skeleton("payday"));
+        assert_eq!("", skeleton(""));
+    }
+
+    #[test]
+    fn prototypes() {
+        let cyrillic_a = Codepoint::from('\u{430}');
+        assert_eq!(Some(&[0x61][..]), confusable_prototype(cyrillic_a));
+        assert_eq!(None, confusable_prototype(Codepoint::from('a')));
+    }
+
+    #[test]
+    fn confusable_set() {
+        let set = confusables(Codepoint::from('a'));
+        assert!(set.contains(&Codepoint::from('a')));
+        assert!(set.contains(&Codepoint::from('\u{430}')));
+        assert!(!set.contains(&Codepoint::from('b')));
+    }
+}
@@ -0,0 +1,62 @@
+use std::io::{self, Write};
+
+use clap;
+use tabwriter::TabWriter;
+
+use app::arg_to_codepoint;
+use codepoint::Codepoint;
+use display::{ColumnWriter, cell_char};
+use error::Result;
+use name::codepoint_name_or_label;
+use normalize::{Form, decomposition, normalize};
+
+pub fn command(args: &clap::ArgMatches) -> Result<()> {
+    let cp = arg_to_codepoint("codepoint", args.value_of_os("codepoint"))?;
+    let mut wtr = ColumnWriter::new(io::stdout());
+    write_tree(&mut wtr, cp, 0, args.is_present("pictures"));
+    wtr.flush()?;
+
+    // Surrogates have no decomposition, so there is nothing to summarize.
+    let scalar = match cp.scalar() {
+        None => return Ok(()),
+        Some(scalar) => scalar,
+    };
+    println!();
+    let mut wtr = TabWriter::new(io::stdout());
+    for &form in &[Form::Nfd, Form::Nfkd] {
+        let cps: Vec<String> = normalize(form, &scalar.to_string())
+            .chars()
+            .map(|c| format!("U+{}", Codepoint::from(c)))
+            .collect();
+        writeln!(wtr, "{}:\t{}", form, cps.join(" "))?;
+    }
+    wtr.flush()?;
+    Ok(())
+}
+
+/// Write the full decomposition tree rooted at the given codepoint.
+///
+/// Each level of decomposition is indented beneath the codepoint it
+/// decomposes. Compatibility decompositions are marked with their formatting
+/// tag, e.g., `<font>`. If `pictures` is enabled, then each codepoint is
+/// shown using `picture_char`.
+fn write_tree<W: io::Write>(
+    wtr: &mut ColumnWriter<W>,
+    cp: Codepoint,
+    depth: usize,
+    pictures: bool,
+) {
+    let decomp = decomposition(cp);
+    let tag = decomp.as_ref().and_then(|d| d.tag()).unwrap_or("");
+    wtr.write_row(vec![
+        format!("{}U+{}", "    ".repeat(depth), cp),
+        cell_char(cp, pictures),
+        codepoint_name_or_label(cp),
+        tag.to_string(),
+    ]);
+    if let Some(decomp) = decomp {
+        for &cp in &decomp.mapping {
+            write_tree(wtr, cp, depth + 1, pictures);
+        }
+    }
+}
@@ -1,50 +1,328 @@
+use std::char;
+use std::fmt;
 use std::io::{self, Write};
 
-use tabwriter::TabWriter;
-
+use age::{age, version_number};
+use bidi::{
+    bidi_class, bidi_mirrored, bidi_mirroring_glyph, bidi_paired_bracket,
+};
 use codepoint::Codepoint;
+use emoji::{Sequence, emoji_properties};
 use error::Result;
-use name::codepoint_name;
+use ident::{identifier_properties, identifier_status, identifier_type};
+use name::{codepoint_abbreviation, codepoint_name_or_label};
+use numeric::{is_unihan_numeric, numeric_type, numeric_value};
+use script::{script, script_extensions};
+use variants::variation_sequences;
+use tables::{in_ranges, range_value, ucd, unicode_version};
+use width::{Ambiguous, text_width};
 
 pub struct ShortWriter<W: io::Write> {
-    wtr: TabWriter<io::BufWriter<W>>,
+    wtr: ColumnWriter<W>,
     wrote_header: bool,
+    pictures: bool,
 }
 
 impl<W: io::Write> ShortWriter<W> {
     pub fn new(wtr: W) -> ShortWriter<W> {
         ShortWriter {
-            wtr: TabWriter::new(io::BufWriter::new(wtr)),
+            wtr: ColumnWriter::new(wtr),
             wrote_header: false,
+            pictures: false,
         }
     }
 
-    fn write_header(&mut self) -> Result<()> {
-        writeln!(self.wtr, "codepoint\tcharacter\tutf8\tname")?;
+    /// When enabled, show invisible codepoints using Control Pictures and
+    /// labels. See `picture_char`.
+    pub fn pictures(&mut self, yes: bool) -> &mut ShortWriter<W> {
+        self.pictures = yes;
+        self
+    }
+
+    fn write_header(&mut self) {
+        self.wtr.write_row(vec![
+            "codepoint".to_string(),
+            "character".to_string(),
+            "utf8".to_string(),
+            "name".to_string(),
+        ]);
         self.wrote_header = true;
-        Ok(())
     }
 
     pub fn write_codepoint(&mut self, cp: Codepoint) -> Result<()> {
         if !self.wrote_header {
-            self.write_header()?;
+            self.write_header();
         }
-        let name = codepoint_name(cp).unwrap_or("".to_string());
-        let scalar = nice_char(cp).unwrap_or("".to_string());
+        let name = codepoint_name_or_label(cp);
+        let scalar =
+            if self.pictures {
+                picture_char(cp)
+            } else {
+                display_char(cp).unwrap_or("".to_string())
+            };
         let utf8 = utf8_hex(cp).unwrap_or("".to_string());
-        writeln!(
-            self.wtr,
-            "U+{:04X}\t{}\t{}\t{}",
-            cp.value(), scalar, utf8, name)?;
+        self.wtr.write_row(
+            vec![format!("U+{:04X}", cp.value()), scalar, utf8, name]);
         Ok(())
     }
 
+    /// Write a named emoji sequence as a single row.
+    pub fn write_sequence(&mut self, seq: &Sequence) -> Result<()> {
+        if !self.wrote_header {
+            self.write_header();
+        }
+        let cps = seq.codepoints();
+        self.wtr.write_row(vec![
+            sequence_codepoints(&cps),
+            seq.to_string(),
+            sequence_utf8_hex(&cps),
+            seq.name.to_string(),
+        ]);
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> Result<()> {
+        self.wtr.flush()
+    }
+}
+
+/// A writer that aligns columns of text by the number of terminal columns
+/// each cell occupies.
+///
+/// This is like `TabWriter`, except cell widths are computed by
+/// `width::text_width`, which agrees with the `width` sub-command.
+pub struct ColumnWriter<W: io::Write> {
+    wtr: io::BufWriter<W>,
+    rows: Vec<Vec<String>>,
+}
+
+impl<W: io::Write> ColumnWriter<W> {
+    pub fn new(wtr: W) -> ColumnWriter<W> {
+        ColumnWriter { wtr: io::BufWriter::new(wtr), rows: vec![] }
+    }
+
+    /// Add a row of cells. Nothing is written until `flush` is called.
+    pub fn write_row(&mut self, cells: Vec<String>) {
+        self.rows.push(cells);
+    }
+
+    /// Write every row added so far, padding each cell except the last in
+    /// each row to the width of the widest cell in its column.
     pub fn flush(&mut self) -> Result<()> {
+        let mut widths: Vec<usize> = vec![];
+        for row in &self.rows {
+            for (i, cell) in row.iter().enumerate() {
+                let width = text_width(cell, Ambiguous::Narrow);
+                if i >= widths.len() {
+                    widths.push(width);
+                } else if width > widths[i] {
+                    widths[i] = width;
+                }
+            }
+        }
+        for row in self.rows.drain(..) {
+            // Trailing empty cells would otherwise leave trailing padding.
+            let len = row
+                .iter()
+                .rposition(|c| !c.is_empty())
+                .map_or(0, |i| i + 1);
+            let mut line = String::new();
+            for (i, cell) in row[..len].iter().enumerate() {
+                line.push_str(cell);
+                if i + 1 < len {
+                    let width = text_width(cell, Ambiguous::Narrow);
+                    for _ in width..widths[i] + 2 {
+                        line.push(' ');
+                    }
+                }
+            }
+            writeln!(self.wtr, "{}", line)?;
+        }
         self.wtr.flush()?;
         Ok(())
     }
 }
 
+/// A writer that shows every property rucd knows about for each codepoint,
+/// with one property per line.
+pub struct LongWriter<W: io::Write> {
+    wtr: ColumnWriter<W>,
+    wrote_codepoint: bool,
+    pictures: bool,
+}
+
+impl<W: io::Write> LongWriter<W> {
+    pub fn new(wtr: W) -> LongWriter<W> {
+        LongWriter {
+            wtr: ColumnWriter::new(wtr),
+            wrote_codepoint: false,
+            pictures: false,
+        }
+    }
+
+    /// When enabled, show invisible codepoints using Control Pictures and
+    /// labels. See `picture_char`.
+    pub fn pictures(&mut self, yes: bool) -> &mut LongWriter<W> {
+        self.pictures = yes;
+        self
+    }
+
+    pub fn write_codepoint(&mut self, cp: Codepoint) -> Result<()> {
+        if self.wrote_codepoint {
+            self.wtr.write_row(vec![]);
+        }
+        self.wrote_codepoint = true;
+
+        let codepoint = format!("U+{:04X}", cp.value());
+        self.write_property("codepoint", Some(codepoint))?;
+        if self.pictures {
+            self.write_property("character", Some(picture_char(cp)))?;
+        } else {
+            self.write_property("character", display_char(cp))?;
+        }
+        self.write_property("utf8", utf8_hex(cp))?;
+        self.write_property("name", Some(codepoint_name_or_label(cp)))?;
+        self.write_property("age", Some(version_number(age(cp))))?;
+        let gc = range_value(ucd().general_category, cp.value());
+        self.write_property("general category", Some(gc.unwrap_or("Cn")))?;
+        if let Some(value) = numeric_value(cp) {
+            self.write_property("numeric type", Some(numeric_type(cp)))?;
+            if is_unihan_numeric(cp) {
+                self.write_property(
+                    "numeric value", Some(format!("{} (Unihan)", value)))?;
+            } else {
+                self.write_property("numeric value", Some(value))?;
+            }
+        }
+        self.write_property("script", Some(script(cp)))?;
+        self.write_property(
+            "script extensions", Some(script_extensions(cp).join(" ")))?;
+        let props = identifier_properties(cp);
+        if !props.is_empty() {
+            self.write_property(
+                "identifier properties", Some(props.join(" ")))?;
+        }
+        // Some data isn't embedded for every version of Unicode, so say so
+        // rather than leaving its properties out.
+        let unavailable =
+            format!("unavailable in Unicode {}", unicode_version());
+        let unavailable = unavailable.as_str();
+        self.write_property(
+            "identifier status",
+            Some(identifier_status(cp).unwrap_or(unavailable)))?;
+        self.write_property(
+            "identifier type",
+            Some(identifier_type(cp).unwrap_or(unavailable)))?;
+        self.write_property("bidi class", Some(bidi_class(cp)))?;
+        self.write_property(
+            "bidi mirrored",
+            Some(if bidi_mirrored(cp) { "yes" } else { "no" }))?;
+        let pictures = self.pictures;
+        self.write_property(
+            "bidi mirroring glyph",
+            bidi_mirroring_glyph(cp).map(|g| short_codepoint(g, pictures)))?;
+        if let Some((pair, kind)) = bidi_paired_bracket(cp) {
+            self.write_property(
+                "bidi paired bracket", Some(short_codepoint(pair, pictures)))?;
+            self.write_property("bidi paired bracket type", Some(kind))?;
+        }
+        match emoji_properties(cp) {
+            None => {
+                self.write_property("emoji properties", Some(unavailable))?;
+            }
+            Some(ref props) if props.is_empty() => {}
+            Some(props) => {
+                self.write_property(
+                    "emoji properties", Some(props.join(" ")))?;
+            }
+        }
+        match variation_sequences(cp) {
+            None => {
+                self.write_property(
+                    "variation sequences", Some(unavailable))?;
+            }
+            Some(sequences) => {
+                for (selector, description) in sequences {
+                    self.write_property(
+                        "variation sequence",
+                        Some(format!(
+                            "U+{:04X} U+{:04X} ({})",
+                            cp.value(), selector.value(), description)))?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Write a named emoji sequence, with one line for each of its
+    /// codepoints.
+    pub fn write_sequence(&mut self, seq: &Sequence) -> Result<()> {
+        if self.wrote_codepoint {
+            self.wtr.write_row(vec![]);
+        }
+        self.wrote_codepoint = true;
+
+        let cps = seq.codepoints();
+        self.write_property("sequence", Some(sequence_codepoints(&cps)))?;
+        self.write_property("character", Some(seq))?;
+        self.write_property("utf8", Some(sequence_utf8_hex(&cps)))?;
+        self.write_property("name", Some(seq.name))?;
+        self.write_property("type", Some(seq.kind))?;
+        for cp in cps {
+            let component = short_codepoint(cp, self.pictures);
+            self.write_property("component", Some(component))?;
+        }
+        Ok(())
+    }
+
+    /// Write a single property of a codepoint. If the codepoint doesn't have
+    /// a value for the property, then nothing is written.
+    fn write_property<T: fmt::Display>(
+        &mut self,
+        property: &str,
+        value: Option<T>,
+    ) -> Result<()> {
+        if let Some(value) = value {
+            self.wtr.write_row(
+                vec![format!("{}:", property), value.to_string()]);
+        }
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> Result<()> {
+        self.wtr.flush()
+    }
+}
+
+/// Format a codepoint on a single line along with its character and name,
+/// e.g., `U+0029 ) RIGHT PARENTHESIS`.
+///
+/// The character is shown using `cell_char`.
+pub fn short_codepoint(cp: Codepoint, pictures: bool) -> String {
+    let mut s = format!("U+{:04X}", cp.value());
+    let c = cell_char(cp, pictures);
+    if !c.is_empty() {
+        s.push(' ');
+        s.push_str(&c);
+    }
+    s.push(' ');
+    s.push_str(&codepoint_name_or_label(cp));
+    s
+}
+
+/// Format a sequence of codepoints, e.g., `U+0023 U+FE0F U+20E3`.
+pub fn sequence_codepoints(cps: &[Codepoint]) -> String {
+    let cps: Vec<String> =
+        cps.iter().map(|cp| format!("U+{:04X}", cp.value())).collect();
+    cps.join(" ")
+}
+
+/// Return the UTF-8 encoding of a sequence of codepoints, using `\xFF`
+/// escapes for every byte.
+pub fn sequence_utf8_hex(cps: &[Codepoint]) -> String {
+    cps.iter().filter_map(|&cp| utf8_hex(cp)).collect()
+}
+
 /// Converts the given codepoint to a human readable hexadecimal string
 /// corresponding to its UTF-8 encoding.
 ///
@@ -62,10 +340,109 @@ fn utf8_hex(cp: Codepoint) -> Option<String> {
     Some(hexstr)
 }
 
+/// Converts the given codepoint into a form that occupies at least one
+/// terminal column, so that it is visible in a table.
+///
+/// Combining marks are shown on U+25CC DOTTED CIRCLE and other zero-width
+/// codepoints are shown as U+2B1A DOTTED SQUARE. Otherwise, this is the same
+/// as `nice_char`.
+pub fn display_char(cp: Codepoint) -> Option<String> {
+    let nice = match nice_char(cp) {
+        None => return None,
+        Some(nice) => nice,
+    };
+    if text_width(&nice, Ambiguous::Narrow) > 0 {
+        return Some(nice);
+    }
+    Some(match range_value(ucd().general_category, cp.value()) {
+        Some("Mn") | Some("Me") => format!("\u{25CC}{}", nice),
+        _ => "\u{2B1A}".to_string(),
+    })
+}
+
+/// Converts the given codepoint into a form suitable for a cell in a table.
+///
+/// If `pictures` is enabled, then this is `picture_char`. Otherwise, this
+/// is `display_char`.
+pub fn cell_char(cp: Codepoint, pictures: bool) -> String {
+    if pictures {
+        picture_char(cp)
+    } else {
+        display_char(cp).unwrap_or("".to_string())
+    }
+}
+
+/// Converts the given text into a form suitable for a cell in a table.
+///
+/// If `pictures` is enabled, then every invisible codepoint in the text is
+/// replaced as described by `picture_char`, while visible codepoints are
+/// left as is so that combining marks still combine. Otherwise, the text is
+/// quoted and escaped.
+pub fn cell_text(text: &str, pictures: bool) -> String {
+    if !pictures {
+        return format!("{:?}", text);
+    }
+    text.chars()
+        .map(|c| {
+            let cp = Codepoint::from(c);
+            picture(cp).unwrap_or(c.to_string())
+        })
+        .collect()
+}
+
+/// Converts the given codepoint into a visible form, even if it is a
+/// control, space or default ignorable codepoint.
+///
+/// C0 controls are shown using the Control Pictures block, e.g., U+2409
+/// SYMBOL FOR HORIZONTAL TABULATION for a tab. Other controls, spaces and
+/// default ignorable codepoints are shown by their abbreviated alias, e.g.,
+/// `<ZWJ>` or `<NBSP>`, or by their codepoint if they have no abbreviation.
+/// Surrogates, noncharacters and private use codepoints are labeled as
+/// such.
+pub fn picture_char(cp: Codepoint) -> String {
+    match picture(cp) {
+        Some(picture) => picture,
+        None => display_char(cp).unwrap_or("".to_string()),
+    }
+}
+
+/// Return the picture or label that `picture_char` uses for the given
+/// codepoint, or `None` if the codepoint is visible on its own.
+fn picture(cp: Codepoint) -> Option<String> {
+    let n = cp.value();
+    if n <= 0x1F {
+        return Some(char::from_u32(0x2400 + n).unwrap().to_string());
+    }
+    if n == 0x7F {
+        return Some("\u{2421}".to_string());
+    }
+    if cp.is_surrogate() {
+        return Some("<surrogate>".to_string());
+    }
+    if cp.is_noncharacter() {
+        return Some("<noncharacter>".to_string());
+    }
+    if cp.is_private_use() {
+        return Some("<private-use>".to_string());
+    }
+    let invisible = in_ranges(ucd().default_ignorable_code_point, cp.value())
+        || match range_value(ucd().general_category, cp.value()) {
+            Some("Cc") | Some("Zs") | Some("Zl") | Some("Zp") => true,
+            _ => false,
+        };
+    if !invisible {
+        return None;
+    }
+    Some(match codepoint_abbreviation(cp) {
+        Some(abbrev) => format!("<{}>", abbrev),
+        None => format!("<U+{:04X}>", cp.value()),
+    })
+}
+
 /// Converts the given codepoint into a displayable form. If the codepoint is
 /// whitespace or control, then its escaped form is returned. If the codepoint
 /// is not a scalar value, then `None` is returned.
-fn nice_char(cp: Codepoint) -> Option<String> {
+pub fn nice_char(cp: Codepoint) -> Option<String> {
     let scalar = match cp.scalar() {
         None => return None,
         Some(scalar) => scalar,
@@ -76,3 +453,68 @@ fn nice_char(cp: Codepoint) -> Option<String> {
         scalar.to_string()
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use codepoint::Codepoint;
+    use tables::select_unicode_version;
+
+    use super::{ColumnWriter, LongWriter};
+
+    fn columns(rows: Vec<Vec<&str>>) -> String {
+        let mut wtr = ColumnWriter::new(vec![]);
+        for row in rows {
+            wtr.write_row(row.into_iter().map(|s| s.to_string()).collect());
+        }
+        wtr.flush().unwrap();
+        String::from_utf8(wtr.wtr.into_inner().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn columns_align_emoji_sequences() {
+        // Every emoji sequence occupies two columns, regardless of how many
+        // codepoints it contains.
+        let got = columns(vec![
+            vec!["ab", "x"],
+            vec!["\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}", "y"],
+            vec!["\u{1F1FA}\u{1F1F8}", "z"],
+            vec!["\u{1F44D}\u{1F3FD}", "w"],
+        ]);
+        let want = "\
+ab  x
+\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}  y
+\u{1F1FA}\u{1F1F8}  z
+\u{1F44D}\u{1F3FD}  w
+";
+        assert_eq!(want, got);
+    }
+
+    #[test]
+    fn columns_skip_trailing_empty_cells() {
+        let got = columns(vec![vec!["abc", "d", ""], vec!["a", "", ""]]);
+        assert_eq!("abc  d\na\n", got);
+    }
+
+    #[test]
+    fn long_reports_unavailable_properties() {
+        select_unicode_version("9.0");
+        let mut wtr = LongWriter::new(vec![]);
+        wtr.write_codepoint(Codepoint::from('\u{2764}')).unwrap();
+        wtr.flush().unwrap();
+        let got = String::from_utf8(wtr.wtr.wtr.into_inner().unwrap());
+        let unavailable: Vec<String> = got
+            .unwrap()
+            .lines()
+            .filter(|line| line.ends_with("unavailable in Unicode 9.0"))
+            .map(|line| line.split(':').next().unwrap().to_string())
+            .collect();
+        assert_eq!(
+            vec![
+                "identifier status",
+                "identifier type",
+                "emoji properties",
+                "variation sequences",
+            ],
+            unavailable);
+    }
+}
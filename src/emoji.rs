@@ -0,0 +1,419 @@
+use std::fmt;
+use std::io;
+
+use clap;
+
+use app::arg_to_codepoints;
+use codepoint::Codepoint;
+use display::{
+    ColumnWriter, sequence_codepoints, sequence_utf8_hex, short_codepoint,
+};
+use error::Result;
+use tables::{in_ranges, require, ucd};
+
+const ZWJ: u32 = 0x200D;
+const VS16: u32 = 0xFE0F;
+const BLACK_FLAG: u32 = 0x1F3F4;
+const CANCEL_TAG: u32 = 0xE007F;
+
+/// The names of the Fitzpatrick skin tone modifiers, starting at U+1F3FB.
+const SKIN_TONES: &'static [&'static str] = &[
+    "light", "medium-light", "medium", "medium-dark", "dark",
+];
+
+pub fn command(args: &clap::ArgMatches) -> Result<()> {
+    require(ucd().emoji_sequences, "emoji sequence data")?;
+    let mut inputs = vec![];
+    for value in args.values_of_os("input").unwrap() {
+        inputs.push(arg_to_codepoints("input", value)?);
+    }
+    let cps = match args.value_of("kind").unwrap() {
+        "flag" => {
+            if inputs.len() > 1 {
+                return err!("flag expects a single region code or flag");
+            }
+            flag(&inputs[0])?
+        }
+        "skin-tone" => skin_tone(&inputs)?,
+        _ => zwj(&inputs)?,
+    };
+    let cps: Vec<u32> = cps.iter().map(|cp| cp.value()).collect();
+    let rgi = rgi_sequence(&cps);
+    // Prefer the fully-qualified form of an RGI sequence, which may contain
+    // variation selectors that weren't given.
+    let cps = rgi.map(|seq| seq.codepoints.to_vec()).unwrap_or(cps);
+    let cps: Vec<Codepoint> =
+        cps.into_iter().map(|n| Codepoint::from_u32(n).unwrap()).collect();
+    let text: String = cps.iter().filter_map(|cp| cp.scalar()).collect();
+
+    let pictures = args.is_present("pictures");
+    let mut wtr = ColumnWriter::new(io::stdout());
+    {
+        let mut row = |name: &str, value: String| {
+            wtr.write_row(vec![format!("{}:", name), value]);
+        };
+        row("sequence", sequence_codepoints(&cps));
+        row("character", text);
+        row("utf8", sequence_utf8_hex(&cps));
+        if let Some(seq) = rgi {
+            row("name", seq.name.to_string());
+            row("type", seq.kind.to_string());
+        }
+        let is_rgi = if rgi.is_some() { "yes" } else { "no" };
+        row("rgi", is_rgi.to_string());
+        if let Some(region) = flag_region(&cps) {
+            row("region", region);
+        }
+        if let Some((base, tone)) = modifier_parts(&cps) {
+            row("base", short_codepoint(base, pictures));
+            row("skin tone", skin_tone_name(tone).unwrap().to_string());
+        }
+        for &cp in &cps {
+            if cp.value() != ZWJ && cp.value() != VS16 {
+                row("component", short_codepoint(cp, pictures));
+            }
+        }
+    }
+    wtr.flush()?;
+    Ok(())
+}
+
+/// Build a flag from a region code, or return the given codepoints if they
+/// are already a flag.
+///
+/// Two letter codes, such as `US`, produce a pair of regional indicators.
+/// Longer codes, such as `gbsct` or `GB-SCT`, produce a tag sequence for a
+/// subdivision.
+fn flag(cps: &[Codepoint]) -> Result<Vec<Codepoint>> {
+    if flag_region(cps).is_some() {
+        return Ok(cps.to_vec());
+    }
+    let given: String = cps.iter().filter_map(|cp| cp.scalar()).collect();
+    let code: String = given
+        .chars()
+        .filter(|&c| c != '-')
+        .flat_map(|c| c.to_lowercase())
+        .collect();
+    let is_lower = |c: char| 'a' <= c && c <= 'z';
+    let valid = code.chars().all(|c| is_lower(c) || ('0' <= c && c <= '9'));
+    if !valid || code.len() < 2 {
+        return err!("invalid region code: {}", given);
+    }
+    if code.len() == 2 {
+        if !code.chars().all(is_lower) {
+            return err!("invalid region code: {}", given);
+        }
+        return Ok(code
+            .bytes()
+            .map(|b| {
+                Codepoint::from_u32(0x1F1E6 + (b - b'a') as u32).unwrap()
+            })
+            .collect());
+    }
+    let mut cps = vec![Codepoint::from_u32(BLACK_FLAG).unwrap()];
+    for b in code.bytes() {
+        cps.push(Codepoint::from_u32(0xE0000 + b as u32).unwrap());
+    }
+    cps.push(Codepoint::from_u32(CANCEL_TAG).unwrap());
+    Ok(cps)
+}
+
+/// Return the region code of the given flag, e.g., `US` or `gbsct`.
+pub fn flag_region(cps: &[Codepoint]) -> Option<String> {
+    let is_ri = |cp: &Codepoint| {
+        0x1F1E6 <= cp.value() && cp.value() <= 0x1F1FF
+    };
+    if cps.len() == 2 && cps.iter().all(is_ri) {
+        let region = cps
+            .iter()
+            .map(|cp| (b'A' + (cp.value() - 0x1F1E6) as u8) as char)
+            .collect();
+        return Some(region);
+    }
+    let is_tag = |cp: &Codepoint| {
+        0xE0020 <= cp.value() && cp.value() < CANCEL_TAG
+    };
+    let tagged = cps.len() > 2
+        && cps[0].value() == BLACK_FLAG
+        && cps[cps.len() - 1].value() == CANCEL_TAG
+        && cps[1..cps.len() - 1].iter().all(is_tag);
+    if tagged {
+        let region = cps[1..cps.len() - 1]
+            .iter()
+            .map(|cp| (cp.value() - 0xE0000) as u8 as char)
+            .collect();
+        return Some(region);
+    }
+    None
+}
+
+/// Build an emoji modifier sequence from a base and the name of a skin
+/// tone, or return the given modifier sequence.
+fn skin_tone(inputs: &[Vec<Codepoint>]) -> Result<Vec<Codepoint>> {
+    if inputs.len() > 2 {
+        return err!("skin-tone expects a base emoji and a skin tone");
+    }
+    if inputs.len() == 1 && modifier_parts(&inputs[0]).is_some() {
+        return Ok(inputs[0].clone());
+    }
+    let base = match inputs.first() {
+        Some(cps) if cps.len() == 1 || cps.len() == 2 => cps[0],
+        _ => return err!("expected a base emoji and a skin tone"),
+    };
+    let bases = require(ucd().emoji_modifier_base, "emoji data")?;
+    if !in_ranges(bases, base.value()) {
+        return err!("U+{} is not an Emoji_Modifier_Base", base);
+    }
+    let name: String = match inputs.get(1) {
+        None => return err!("expected a skin tone, e.g., medium"),
+        Some(cps) => cps.iter().filter_map(|cp| cp.scalar()).collect(),
+    };
+    let tone = match SKIN_TONES.iter().position(|&tone| tone == name) {
+        None => {
+            return err!(
+                "unknown skin tone '{}', expected one of: {}",
+                name, SKIN_TONES.join(", "));
+        }
+        Some(i) => Codepoint::from_u32(0x1F3FB + i as u32).unwrap(),
+    };
+    Ok(vec![base, tone])
+}
+
+/// Return the base and modifier of an emoji modifier sequence.
+pub fn modifier_parts(cps: &[Codepoint]) -> Option<(Codepoint, Codepoint)> {
+    if cps.len() != 2 || skin_tone_name(cps[1]).is_none() {
+        return None;
+    }
+    Some((cps[0], cps[1]))
+}
+
+/// Return the name of the given skin tone modifier, e.g., `medium`.
+pub fn skin_tone_name(cp: Codepoint) -> Option<&'static str> {
+    let n = cp.value();
+    if n < 0x1F3FB || n > 0x1F3FF {
+        return None;
+    }
+    Some(SKIN_TONES[(n - 0x1F3FB) as usize])
+}
+
+/// Join the given emoji with zero width joiners, or return the given
+/// ZWJ sequence.
+fn zwj(inputs: &[Vec<Codepoint>]) -> Result<Vec<Codepoint>> {
+    if inputs.len() == 1 {
+        return Ok(inputs[0].clone());
+    }
+    let mut cps = vec![];
+    for (i, input) in inputs.iter().enumerate() {
+        if i > 0 {
+            cps.push(Codepoint::from_u32(ZWJ).unwrap());
+        }
+        cps.extend(input);
+    }
+    Ok(cps)
+}
+
+/// Return the RGI emoji sequence matching the given codepoints, if one
+/// exists.
+///
+/// Variation selectors are ignored when matching, so that a sequence
+/// matches even if it isn't fully-qualified. If the selected version of
+/// Unicode has no emoji sequence data, then `None` is returned.
+pub fn rgi_sequence(cps: &[u32]) -> Option<Sequence> {
+    let unqualified = |cps: &[u32]| -> Vec<u32> {
+        cps.iter().cloned().filter(|&n| n != VS16).collect()
+    };
+    let given = unqualified(cps);
+    emoji_sequences().and_then(|seqs| {
+        seqs.into_iter().find(|seq| unqualified(seq.codepoints) == given)
+    })
+}
+
+/// A named emoji sequence from emoji-sequences.txt or
+/// emoji-zwj-sequences.txt.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Sequence {
+    /// The codepoints of the sequence.
+    pub codepoints: &'static [u32],
+    /// The type of the sequence, e.g., `Emoji_ZWJ_Sequence`.
+    pub kind: &'static str,
+    /// The name of the sequence, e.g., `family: man, woman, boy`.
+    pub name: &'static str,
+}
+
+impl Sequence {
+    /// Return the codepoints of this sequence.
+    pub fn codepoints(&self) -> Vec<Codepoint> {
+        self.codepoints
+            .iter()
+            .map(|&n| Codepoint::from_u32(n).unwrap())
+            .collect()
+    }
+}
+
+impl fmt::Display for Sequence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for cp in self.codepoints() {
+            if let Some(c) = cp.scalar() {
+                write!(f, "{}", c)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Return the names of the emoji properties, such as `Emoji` or
+/// `Emoji_Presentation`, that the given codepoint has.
+///
+/// `Extended_Pictographic` is included, even though it comes from a newer
+/// version of the emoji data than the other properties. If the selected
+/// version of Unicode has no emoji data, then `None` is returned.
+pub fn emoji_properties(cp: Codepoint) -> Option<Vec<&'static str>> {
+    let ucd = ucd();
+    ucd.emoji_properties.map(|table| {
+        let mut props: Vec<&'static str> = table
+            .iter()
+            .filter(|&&(_, ranges)| in_ranges(ranges, cp.value()))
+            .map(|&(name, _)| name)
+            .collect();
+        let pictographic = ucd.extended_pictographic.unwrap_or(&[]);
+        if in_ranges(pictographic, cp.value()) {
+            props.push("Extended_Pictographic");
+        }
+        props
+    })
+}
+
+/// Return every named emoji sequence, in ascending order of codepoints.
+///
+/// If the selected version of Unicode has no emoji sequence data, then
+/// `None` is returned.
+pub fn emoji_sequences() -> Option<Vec<Sequence>> {
+    ucd().emoji_sequences.map(|table| {
+        table
+            .iter()
+            .map(|&(codepoints, kind, name)| Sequence {
+                codepoints,
+                kind,
+                name,
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use codepoint::Codepoint;
+    use tables::select_unicode_version;
+
+    use super::{
+        emoji_properties, emoji_sequences, flag, flag_region, modifier_parts,
+        rgi_sequence, skin_tone, skin_tone_name, zwj,
+    };
+
+    fn cps(values: &[u32]) -> Vec<Codepoint> {
+        values.iter().map(|&n| Codepoint::from_u32(n).unwrap()).collect()
+    }
+
+    fn text(text: &str) -> Vec<Codepoint> {
+        text.chars().map(Codepoint::from).collect()
+    }
+
+    fn values(cps: &[Codepoint]) -> Vec<u32> {
+        cps.iter().map(|cp| cp.value()).collect()
+    }
+
+    fn name(cps: &[Codepoint]) -> Option<&'static str> {
+        rgi_sequence(&values(cps)).map(|seq| seq.name)
+    }
+
+    #[test]
+    fn flags() {
+        let us = flag(&text("US")).unwrap();
+        assert_eq!(vec![0x1F1FA, 0x1F1F8], values(&us));
+        assert_eq!(Some("US".to_string()), flag_region(&us));
+        assert_eq!(Some("flag: United States"), name(&us));
+        assert_eq!(us, flag(&text("us")).unwrap());
+        // Decoding an existing flag returns it unchanged.
+        assert_eq!(us, flag(&text("\u{1F1FA}\u{1F1F8}")).unwrap());
+        assert_eq!(us, flag(&cps(&[0x1F1FA, 0x1F1F8])).unwrap());
+
+        let scotland = flag(&text("GB-SCT")).unwrap();
+        assert_eq!(
+            vec![
+                0x1F3F4, 0xE0067, 0xE0062, 0xE0073, 0xE0063, 0xE0074,
+                0xE007F,
+            ],
+            values(&scotland));
+        assert_eq!(Some("gbsct".to_string()), flag_region(&scotland));
+        assert_eq!(Some("flag: Scotland"), name(&scotland));
+        assert_eq!(scotland, flag(&text("gbsct")).unwrap());
+
+        assert!(flag(&text("U")).is_err());
+        assert!(flag(&text("U$")).is_err());
+        assert!(flag(&text("U1")).is_err());
+    }
+
+    #[test]
+    fn skin_tones() {
+        let base = cps(&[0x1F44D]);
+        let tone: Vec<Codepoint> =
+            "medium".chars().map(Codepoint::from).collect();
+        let seq = skin_tone(&[base.clone(), tone.clone()]).unwrap();
+        assert_eq!(vec![0x1F44D, 0x1F3FD], values(&seq));
+        assert_eq!(Some("thumbs up: medium skin tone"), name(&seq));
+        assert_eq!(Some((seq[0], seq[1])), modifier_parts(&seq));
+        assert_eq!(Some("medium"), skin_tone_name(seq[1]));
+        // Decoding an existing modifier sequence returns it unchanged.
+        assert_eq!(seq, skin_tone(&[seq.clone()]).unwrap());
+
+        assert_eq!(Some("light"), skin_tone_name(cps(&[0x1F3FB])[0]));
+        assert_eq!(Some("dark"), skin_tone_name(cps(&[0x1F3FF])[0]));
+        assert_eq!(None, skin_tone_name(cps(&[0x1F3FA])[0]));
+        assert_eq!(None, skin_tone_name(cps(&[0x1F400])[0]));
+
+        let letter = cps(&[0x61]);
+        assert!(skin_tone(&[letter, tone.clone()]).is_err());
+        let unknown: Vec<Codepoint> =
+            "purple".chars().map(Codepoint::from).collect();
+        assert!(skin_tone(&[base.clone(), unknown]).is_err());
+        assert!(skin_tone(&[base, tone, text("dark")]).is_err());
+    }
+
+    #[test]
+    fn zwj_sequences() {
+        let parts = vec![cps(&[0x1F468]), cps(&[0x1F469]), cps(&[0x1F467])];
+        let family = zwj(&parts).unwrap();
+        assert_eq!(
+            vec![0x1F468, 0x200D, 0x1F469, 0x200D, 0x1F467],
+            values(&family));
+        assert_eq!(Some("family: man, woman, girl"), name(&family));
+        // Decoding an existing ZWJ sequence returns it unchanged.
+        assert_eq!(family, zwj(&[family.clone()]).unwrap());
+
+        // Sequences match even when variation selectors are left out.
+        let rainbow = zwj(&[cps(&[0x1F3F3]), cps(&[0x1F308])]).unwrap();
+        assert_eq!(Some("rainbow flag"), name(&rainbow));
+    }
+
+    #[test]
+    fn properties() {
+        let props = |n| emoji_properties(cps(&[n])[0]).unwrap();
+        assert_eq!(vec!["Emoji", "Extended_Pictographic"], props(0x2764));
+        assert_eq!(
+            vec![
+                "Emoji", "Emoji_Modifier_Base", "Emoji_Presentation",
+                "Extended_Pictographic",
+            ],
+            props(0x1F44D));
+        assert!(props(0x61).is_empty());
+    }
+
+    #[test]
+    fn unavailable_in_unicode_9() {
+        select_unicode_version("9.0");
+        assert_eq!(None, emoji_properties(cps(&[0x1F44D])[0]));
+        assert_eq!(None, emoji_sequences());
+        assert_eq!(None, name(&flag(&text("US")).unwrap()));
+        assert!(skin_tone(&[cps(&[0x1F44D]), text("medium")]).is_err());
+    }
+}
@@ -0,0 +1,143 @@
+use std::io;
+
+use clap;
+
+use app::arg_to_str;
+use codepoint::Codepoint;
+use display::{ColumnWriter, cell_char};
+use error::Result;
+use name::codepoint_name_or_label;
+use tables::{range_value, ucd};
+
+pub fn command(args: &clap::ArgMatches) -> Result<()> {
+    let text = arg_to_str("text", args.value_of_os("text"))?;
+    let cps: Vec<Codepoint> = text.chars().map(Codepoint::from).collect();
+    let gcb: Vec<&str> =
+        cps.iter().map(|&cp| grapheme_cluster_break(cp)).collect();
+
+    let pictures = args.is_present("pictures");
+    let mut wtr = ColumnWriter::new(io::stdout());
+    wtr.write_row(vec![
+        "cluster".to_string(),
+        "rule".to_string(),
+        "codepoint".to_string(),
+        "character".to_string(),
+        "name".to_string(),
+    ]);
+    let mut cluster = 0;
+    for (i, &cp) in cps.iter().enumerate() {
+        let (is_break, rule) = boundary(&gcb, i);
+        let cluster_label =
+            if is_break {
+                cluster += 1;
+                cluster.to_string()
+            } else {
+                "".to_string()
+            };
+        wtr.write_row(vec![
+            cluster_label,
+            format!("{} {}", if is_break { "÷" } else { "×" }, rule),
+            format!("U+{}", cp),
+            cell_char(cp, pictures),
+            codepoint_name_or_label(cp),
+        ]);
+    }
+    wtr.flush()?;
+    Ok(())
+}
+
+/// Return the `Grapheme_Cluster_Break` property value of the given
+/// codepoint.
+pub fn grapheme_cluster_break(cp: Codepoint) -> &'static str {
+    range_value(ucd().grapheme_cluster_break, cp.value()).unwrap_or("Other")
+}
+
+/// Split the given text into extended grapheme clusters, as described in
+/// UAX #29.
+pub fn grapheme_clusters(text: &str) -> Vec<&str> {
+    let gcb: Vec<&str> = text
+        .chars()
+        .map(|c| grapheme_cluster_break(Codepoint::from(c)))
+        .collect();
+    let mut clusters = vec![];
+    let mut start = 0;
+    for (i, (offset, _)) in text.char_indices().enumerate() {
+        if i > 0 && boundary(&gcb, i).0 {
+            clusters.push(&text[start..offset]);
+            start = offset;
+        }
+    }
+    if start < text.len() {
+        clusters.push(&text[start..]);
+    }
+    clusters
+}
+
+/// Determine whether there is an extended grapheme cluster boundary before
+/// the codepoint at index `i`, given the `Grapheme_Cluster_Break` values of
+/// every codepoint in the text.
+///
+/// The rule from UAX #29 that decides the boundary is returned along with
+/// the decision.
+fn boundary(gcb: &[&'static str], i: usize) -> (bool, &'static str) {
+    if i == 0 {
+        return (true, "GB1");
+    }
+    let (before, after) = (gcb[i - 1], gcb[i]);
+    match (before, after) {
+        ("CR", "LF") => return (false, "GB3"),
+        ("Control", _) | ("CR", _) | ("LF", _) => return (true, "GB4"),
+        (_, "Control") | (_, "CR") | (_, "LF") => return (true, "GB5"),
+        ("L", "L") | ("L", "V") | ("L", "LV") | ("L", "LVT") => {
+            return (false, "GB6");
+        }
+        ("LV", "V") | ("LV", "T") | ("V", "V") | ("V", "T") => {
+            return (false, "GB7");
+        }
+        ("LVT", "T") | ("T", "T") => return (false, "GB8"),
+        (_, "Extend") | (_, "ZWJ") => return (false, "GB9"),
+        (_, "SpacingMark") => return (false, "GB9a"),
+        ("Prepend", _) => return (false, "GB9b"),
+        (_, "E_Modifier") => {
+            let base = gcb[..i].iter().rev().find(|&&v| v != "Extend");
+            if base.map_or(false, |b| *b == "E_Base" || *b == "E_Base_GAZ") {
+                return (false, "GB10");
+            }
+        }
+        ("ZWJ", "Glue_After_Zwj") | ("ZWJ", "E_Base_GAZ") => {
+            return (false, "GB11");
+        }
+        ("Regional_Indicator", "Regional_Indicator") => {
+            // Regional indicators pair up, so only break after an even
+            // number of them.
+            let count = gcb[..i]
+                .iter()
+                .rev()
+                .take_while(|&&v| v == "Regional_Indicator")
+                .count();
+            if count % 2 == 1 {
+                return (false, if count == i { "GB12" } else { "GB13" });
+            }
+        }
+        _ => {}
+    }
+    (true, "GB999")
+}
+
+#[cfg(test)]
+mod tests {
+    use break_test::{break_tests, parse_break_test};
+
+    use super::grapheme_clusters;
+
+    const GRAPHEME_BREAK_TEST: &'static str =
+        include_str!("../tests/data/GraphemeBreakTest.txt");
+
+    #[test]
+    fn grapheme_break_test() {
+        for line in break_tests(GRAPHEME_BREAK_TEST) {
+            let (text, expected) = parse_break_test(line);
+            assert_eq!(expected, grapheme_clusters(&text), "{}", line);
+        }
+    }
+}
@@ -0,0 +1,260 @@
+use std::fmt;
+use std::io;
+
+use clap;
+
+use app::arg_to_str;
+use codepoint::Codepoint;
+use display::LongWriter;
+use error::Result;
+use script::{Mixing, ScriptSet};
+use tables::{in_ranges, range_value, ucd, unicode_version};
+
+/// The scripts recommended for use in identifiers by UTS #39, in Table 5.
+const RECOMMENDED_SCRIPTS: &'static [&'static str] = &[
+    "Arabic", "Armenian", "Bengali", "Bopomofo", "Cyrillic", "Devanagari",
+    "Ethiopic", "Georgian", "Greek", "Gujarati", "Gurmukhi", "Han",
+    "Hangul", "Hebrew", "Hiragana", "Kannada", "Katakana", "Khmer", "Lao",
+    "Latin", "Malayalam", "Myanmar", "Oriya", "Sinhala", "Tamil", "Telugu",
+    "Thaana", "Thai", "Tibetan",
+];
+
+pub fn command(args: &clap::ArgMatches) -> Result<()> {
+    let mut invalid = 0;
+    for (i, value) in args.values_of_os("name").unwrap().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        let name = arg_to_str("name", Some(value))?;
+        println!("identifier: {:?}", name);
+        match restriction_level(&name) {
+            None => {
+                println!(
+                    "restriction level: unavailable in Unicode {}",
+                    unicode_version());
+            }
+            Some(level) => println!("restriction level: {}", level),
+        }
+        if name.is_empty() {
+            invalid += 1;
+            println!("valid: no (identifiers must not be empty)");
+            continue;
+        }
+        let (position, cp, reason) = match invalid_codepoint(&name) {
+            None => {
+                println!("valid: yes");
+                continue;
+            }
+            Some(invalid) => invalid,
+        };
+        invalid += 1;
+        println!(
+            "valid: no (U+{} at position {} is {})",
+            cp, position + 1, reason);
+        println!();
+        let mut wtr = LongWriter::new(io::stdout());
+        wtr.pictures(args.is_present("pictures"));
+        wtr.write_codepoint(cp)?;
+        wtr.flush()?;
+    }
+    if invalid > 0 {
+        return err!("found {} invalid identifier(s)", invalid);
+    }
+    Ok(())
+}
+
+/// A restriction level of UTS #39 section 5.2, which describes how the
+/// scripts of an identifier are mixed. Levels are ordered from most to
+/// least restrictive.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum RestrictionLevel {
+    /// Every codepoint is ASCII.
+    AsciiOnly,
+    /// The resolved script set is not empty.
+    SingleScript,
+    /// Latin mixed with Han and the scripts written with it in Japanese,
+    /// Chinese or Korean.
+    HighlyRestrictive,
+    /// Latin mixed with one other recommended script, except for Cyrillic
+    /// and Greek.
+    ModeratelyRestrictive,
+    /// Any mix of scripts, as long as every codepoint is allowed in
+    /// identifiers.
+    MinimallyRestrictive,
+    /// Some codepoint is not allowed in identifiers.
+    Unrestricted,
+}
+
+impl fmt::Display for RestrictionLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match *self {
+            RestrictionLevel::AsciiOnly => "ASCII-Only",
+            RestrictionLevel::SingleScript => "Single Script",
+            RestrictionLevel::HighlyRestrictive => "Highly Restrictive",
+            RestrictionLevel::ModeratelyRestrictive => {
+                "Moderately Restrictive"
+            }
+            RestrictionLevel::MinimallyRestrictive => {
+                "Minimally Restrictive"
+            }
+            RestrictionLevel::Unrestricted => "Unrestricted",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Return the most restrictive level of UTS #39 section 5.2 that the given
+/// identifier satisfies.
+///
+/// If the identifier isn't ASCII and the selected version of Unicode has no
+/// identifier status data, then `None` is returned.
+pub fn restriction_level(text: &str) -> Option<RestrictionLevel> {
+    if text.bytes().all(|b| b < 0x80) {
+        return Some(RestrictionLevel::AsciiOnly);
+    }
+    let allowed = ucd().identifier_status.map(|table| {
+        text.chars().all(|c| range_value(table, c as u32) == Some("Allowed"))
+    });
+    match allowed {
+        None => return None,
+        Some(false) => return Some(RestrictionLevel::Unrestricted),
+        Some(true) => {}
+    }
+    match Mixing::of(text) {
+        Mixing::SingleScript => return Some(RestrictionLevel::SingleScript),
+        Mixing::Highlightable => {
+            return Some(RestrictionLevel::HighlyRestrictive);
+        }
+        Mixing::Mixed => {}
+    }
+    // The text is moderately restrictive if everything that isn't Latin
+    // resolves to a single recommended script other than Cyrillic or
+    // Greek.
+    let other = text
+        .chars()
+        .map(|c| ScriptSet::augmented(Codepoint::from(c)))
+        .filter(|set| !set.contains("Latin"))
+        .fold(ScriptSet::All, |acc, set| acc.intersection(&set));
+    let moderate = RECOMMENDED_SCRIPTS
+        .iter()
+        .filter(|&&name| name != "Cyrillic" && name != "Greek")
+        .any(|&name| other.contains(name));
+    if moderate {
+        Some(RestrictionLevel::ModeratelyRestrictive)
+    } else {
+        Some(RestrictionLevel::MinimallyRestrictive)
+    }
+}
+
+/// Return the first codepoint that prevents the given text from being a
+/// default identifier, as defined by UAX #31 requirement R1.
+///
+/// The codepoint is returned along with its position in the text, counted
+/// in codepoints, and the reason it isn't permitted.
+pub fn invalid_codepoint(
+    text: &str,
+) -> Option<(usize, Codepoint, &'static str)> {
+    for (i, c) in text.chars().enumerate() {
+        let cp = Codepoint::from(c);
+        if i == 0 && !in_ranges(ucd().xid_start, cp.value()) {
+            return Some((i, cp, "not XID_Start"));
+        }
+        if !in_ranges(ucd().xid_continue, cp.value()) {
+            return Some((i, cp, "not XID_Continue"));
+        }
+    }
+    None
+}
+
+/// Return the names of the identifier properties, such as `XID_Start` or
+/// `Pattern_Syntax`, that the given codepoint has.
+pub fn identifier_properties(cp: Codepoint) -> Vec<&'static str> {
+    ucd().identifier_properties
+        .iter()
+        .filter(|&&(_, ranges)| in_ranges(ranges, cp.value()))
+        .map(|&(name, _)| name)
+        .collect()
+}
+
+/// Return the `Identifier_Status` of the given codepoint from UTS #39,
+/// which is either `Allowed` or `Restricted`.
+///
+/// If the selected version of Unicode has no identifier status data, then
+/// `None` is returned.
+pub fn identifier_status(cp: Codepoint) -> Option<&'static str> {
+    ucd().identifier_status.map(|table| {
+        range_value(table, cp.value()).unwrap_or("Restricted")
+    })
+}
+
+/// Return the `Identifier_Type` of the given codepoint from UTS #39.
+///
+/// If the selected version of Unicode has no identifier type data, then
+/// `None` is returned.
+pub fn identifier_type(cp: Codepoint) -> Option<&'static str> {
+    ucd().identifier_type.map(|table| {
+        range_value(table, cp.value()).unwrap_or("Not_Character")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use codepoint::Codepoint;
+
+    use tables::select_unicode_version;
+
+    use super::{
+        RestrictionLevel, identifier_status, invalid_codepoint,
+        restriction_level,
+    };
+
+    #[test]
+    fn restriction_levels() {
+        use super::RestrictionLevel::*;
+
+        let level = |text| restriction_level(text).unwrap();
+        assert_eq!(AsciiOnly, level("paypal"));
+        assert_eq!(SingleScript, level("αβγ"));
+        assert_eq!(SingleScript, level("café"));
+        assert_eq!(HighlyRestrictive, level("abc漢字ひらがな"));
+        assert_eq!(ModeratelyRestrictive, level("abcनमस्ते"));
+        assert_eq!(MinimallyRestrictive, level("p\u{430}ypal"));
+        assert_eq!(MinimallyRestrictive, level("abcαβγ"));
+        assert_eq!(Unrestricted, level("abc\u{16A0}"));
+    }
+
+    #[test]
+    fn restriction_levels_unavailable_in_unicode_9() {
+        select_unicode_version("9.0");
+        assert_eq!(Some(RestrictionLevel::AsciiOnly), restriction_level("ab"));
+        assert_eq!(None, restriction_level("αβγ"));
+        assert_eq!(None, identifier_status(Codepoint::from('a')));
+    }
+
+    #[test]
+    fn restriction_levels_are_ordered() {
+        assert!(RestrictionLevel::AsciiOnly < RestrictionLevel::SingleScript);
+        assert!(
+            RestrictionLevel::MinimallyRestrictive
+                < RestrictionLevel::Unrestricted);
+    }
+
+    #[test]
+    fn invalid_codepoints() {
+        assert_eq!(None, invalid_codepoint("foo_bar"));
+        assert_eq!(None, invalid_codepoint("αβγ"));
+        assert_eq!(None, invalid_codepoint(""));
+        assert_eq!(
+            Some((0, Codepoint::from('1'), "not XID_Start")),
+            invalid_codepoint("1abc"));
+        assert_eq!(
+            Some((0, Codepoint::from('_'), "not XID_Start")),
+            invalid_codepoint("_abc"));
+        assert_eq!(
+            Some((3, Codepoint::from('-'), "not XID_Continue")),
+            invalid_codepoint("foo-bar"));
+        assert_eq!(
+            Some((2, Codepoint::from('\u{200B}'), "not XID_Continue")),
+            invalid_codepoint("ab\u{200B}c"));
+    }
+}
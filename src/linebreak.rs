@@ -0,0 +1,402 @@
+use std::io::{self, Write};
+
+use clap;
+
+use app::arg_to_str;
+use codepoint::Codepoint;
+use display::{ColumnWriter, cell_text};
+use error::Result;
+use tables::{range_value, ucd};
+use width::{Ambiguous, text_width};
+
+pub fn command(args: &clap::ArgMatches) -> Result<()> {
+    let text = arg_to_str("text", args.value_of_os("text"))?;
+    let lines = line_segments(&text);
+    if let Some(width) = args.value_of("width") {
+        let width = match width.parse() {
+            Ok(width) if width > 0 => width,
+            _ => return err!("invalid width: '{}'", width),
+        };
+        let stdout = io::stdout();
+        let mut stdout = stdout.lock();
+        for line in wrap(&lines, width) {
+            writeln!(stdout, "{}", line)?;
+        }
+        return Ok(());
+    }
+
+    let pictures = args.is_present("pictures");
+    let mut wtr = ColumnWriter::new(io::stdout());
+    wtr.write_row(vec![
+        "segment".to_string(),
+        "break".to_string(),
+        "rule".to_string(),
+        "text".to_string(),
+    ]);
+    for (i, &(brk, rule, segment)) in lines.iter().enumerate() {
+        let brk = match brk {
+            Break::Mandatory => "!",
+            Break::Allowed => "÷",
+        };
+        wtr.write_row(vec![
+            (i + 1).to_string(),
+            brk.to_string(),
+            rule.to_string(),
+            cell_text(segment, pictures),
+        ]);
+    }
+    wtr.flush()?;
+    Ok(())
+}
+
+/// A line break opportunity.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Break {
+    /// A line must be broken here.
+    Mandatory,
+    /// A line may be broken here.
+    Allowed,
+}
+
+/// Return the `Line_Break` property value of the given codepoint, using the
+/// abbreviated value names from LineBreak.txt.
+pub fn line_break(cp: Codepoint) -> &'static str {
+    range_value(ucd().line_break, cp.value()).unwrap_or("XX")
+}
+
+/// Split the given text at every line break opportunity, as described in
+/// UAX #14.
+///
+/// Each segment is returned along with the kind of break opportunity that
+/// ends it and the rule that decided that opportunity.
+pub fn line_segments(text: &str) -> Vec<(Break, &'static str, &str)> {
+    let classes: Vec<&str> = text
+        .chars()
+        .map(|c| resolve_class(Codepoint::from(c)))
+        .collect();
+    let mut segments = vec![];
+    let mut start = 0;
+    for (i, (offset, _)) in text.char_indices().enumerate() {
+        if let (Some(brk), rule) = boundary(&classes, i) {
+            segments.push((brk, rule, &text[start..offset]));
+            start = offset;
+        }
+    }
+    if start < text.len() {
+        segments.push((Break::Mandatory, "LB3", &text[start..]));
+    }
+    segments
+}
+
+/// Wrap the given line segments into lines of at most `width` columns.
+///
+/// Lines are filled greedily. Trailing whitespace does not count toward
+/// a line's width, and a segment that is wider than `width` on its own is
+/// put on a line by itself. Widths are measured in terminal columns, so
+/// that wide characters, such as CJK ideographs and emoji, count as two
+/// columns.
+fn wrap(segments: &[(Break, &str, &str)], width: usize) -> Vec<String> {
+    let columns = |s: &str| text_width(trim_end(s), Ambiguous::Narrow);
+    let mut lines = vec![];
+    let mut line = String::new();
+    for &(brk, _, segment) in segments {
+        let candidate = format!("{}{}", line, segment);
+        if !line.is_empty() && columns(&candidate) > width {
+            lines.push(trim_end(&line).to_string());
+            line = segment.to_string();
+        } else {
+            line = candidate;
+        }
+        if brk == Break::Mandatory {
+            lines.push(trim_end(&line).to_string());
+            line.clear();
+        }
+    }
+    lines
+}
+
+/// Return the given text without any trailing whitespace.
+fn trim_end(text: &str) -> &str {
+    let end = text
+        .char_indices()
+        .rev()
+        .find(|&(_, c)| !c.is_whitespace())
+        .map_or(0, |(i, c)| i + c.len_utf8());
+    &text[..end]
+}
+
+/// Resolve the line breaking class of a codepoint according to rule LB1.
+fn resolve_class(cp: Codepoint) -> &'static str {
+    match line_break(cp) {
+        "AI" | "SG" | "XX" => "AL",
+        "SA" => {
+            match range_value(ucd().general_category, cp.value()) {
+                Some("Mn") | Some("Mc") => "CM",
+                _ => "AL",
+            }
+        }
+        "CJ" => "NS",
+        class => class,
+    }
+}
+
+/// Returns true if a combining mark following the given class attaches to
+/// it per rule LB9.
+fn takes_combining_marks(class: &str) -> bool {
+    !["BK", "CR", "LF", "NL", "SP", "ZW"].contains(&class)
+}
+
+/// Return the effective class of the codepoint at index `i` after applying
+/// rules LB9 and LB10, along with the index at which the combining sequence
+/// containing it begins.
+fn effective_class(
+    classes: &[&'static str],
+    i: usize,
+) -> (&'static str, usize) {
+    let mut j = i;
+    while classes[j] == "CM" || classes[j] == "ZWJ" {
+        if j == 0 || !takes_combining_marks(classes[j - 1]) {
+            return ("AL", j);
+        }
+        j -= 1;
+    }
+    (classes[j], j)
+}
+
+/// Determine the kind of line break opportunity, if any, before the
+/// codepoint at index `i`, given the resolved line breaking classes of
+/// every codepoint in the text.
+///
+/// The rule from UAX #14 that decides the opportunity is returned along
+/// with the decision. Numbers are handled by the tailoring of rule LB25 in
+/// Example 7 of UAX #14, which is what LineBreakTest.txt assumes.
+fn boundary(
+    classes: &[&'static str],
+    i: usize,
+) -> (Option<Break>, &'static str) {
+    const NO: Option<Break> = None;
+    const ALLOWED: Option<Break> = Some(Break::Allowed);
+    const MANDATORY: Option<Break> = Some(Break::Mandatory);
+
+    if i == 0 {
+        return (NO, "LB2");
+    }
+    let (raw_before, raw_after) = (classes[i - 1], classes[i]);
+    match (raw_before, raw_after) {
+        ("BK", _) => return (MANDATORY, "LB4"),
+        ("CR", "LF") => return (NO, "LB5"),
+        ("CR", _) | ("LF", _) | ("NL", _) => return (MANDATORY, "LB5"),
+        (_, "BK") | (_, "CR") | (_, "LF") | (_, "NL") => {
+            return (NO, "LB6");
+        }
+        (_, "SP") | (_, "ZW") => return (NO, "LB7"),
+        _ => {}
+    }
+    let mut j = i;
+    while j > 0 && classes[j - 1] == "SP" {
+        j -= 1;
+    }
+    if j > 0 && classes[j - 1] == "ZW" {
+        return (ALLOWED, "LB8");
+    }
+    match (raw_before, raw_after) {
+        ("ZWJ", "ID") | ("ZWJ", "EB") | ("ZWJ", "EM") => {
+            return (NO, "LB8a");
+        }
+        (_, "CM") | (_, "ZWJ") if takes_combining_marks(raw_before) => {
+            return (NO, "LB9");
+        }
+        _ => {}
+    }
+
+    let (before, start) = effective_class(classes, i - 1);
+    let after = effective_class(classes, i).0;
+    // The effective class preceding the one before this boundary, if any.
+    let before2 =
+        if start == 0 { "" } else { effective_class(classes, start - 1).0 };
+    // The effective class preceding any spaces before this boundary, if
+    // any.
+    let before_spaces = {
+        let mut k = i;
+        while k > 0 && classes[k - 1] == "SP" {
+            k -= 1;
+        }
+        if k == 0 { "" } else { effective_class(classes, k - 1).0 }
+    };
+
+    match (before, after) {
+        (_, "WJ") | ("WJ", _) => return (NO, "LB11"),
+        ("GL", _) => return (NO, "LB12"),
+        ("SP", "GL") | ("BA", "GL") | ("HY", "GL") => {}
+        (_, "GL") => return (NO, "LB12a"),
+        _ => {}
+    }
+    match after {
+        "CL" | "CP" | "EX" | "IS" | "SY" => return (NO, "LB13"),
+        _ => {}
+    }
+    match (before_spaces, after) {
+        ("OP", _) => return (NO, "LB14"),
+        ("QU", "OP") => return (NO, "LB15"),
+        ("CL", "NS") | ("CP", "NS") => return (NO, "LB16"),
+        ("B2", "B2") => return (NO, "LB17"),
+        _ => {}
+    }
+    match (before, after) {
+        ("SP", _) => return (ALLOWED, "LB18"),
+        (_, "QU") | ("QU", _) => return (NO, "LB19"),
+        (_, "CB") | ("CB", _) => return (ALLOWED, "LB20"),
+        (_, "BA") | (_, "HY") | (_, "NS") | ("BB", _) => {
+            return (NO, "LB21");
+        }
+        ("HY", _) | ("BA", _) if before2 == "HL" => return (NO, "LB21a"),
+        ("SY", "HL") => return (NO, "LB21b"),
+        ("AL", "IN") | ("HL", "IN") | ("EX", "IN") | ("ID", "IN")
+        | ("EB", "IN") | ("EM", "IN") | ("IN", "IN") | ("NU", "IN") => {
+            return (NO, "LB22");
+        }
+        ("AL", "NU") | ("HL", "NU") | ("NU", "AL") | ("NU", "HL") => {
+            return (NO, "LB23");
+        }
+        ("PR", "ID") | ("PR", "EB") | ("PR", "EM") | ("ID", "PO")
+        | ("EB", "PO") | ("EM", "PO") => {
+            return (NO, "LB23a");
+        }
+        ("PR", "AL") | ("PR", "HL") | ("PO", "AL") | ("PO", "HL")
+        | ("AL", "PR") | ("AL", "PO") | ("HL", "PR") | ("HL", "PO") => {
+            return (NO, "LB24");
+        }
+        _ => {}
+    }
+    if is_number_continuation(classes, i, before, after) {
+        return (NO, "LB25");
+    }
+    match (before, after) {
+        ("JL", "JL") | ("JL", "JV") | ("JL", "H2") | ("JL", "H3")
+        | ("JV", "JV") | ("JV", "JT") | ("H2", "JV") | ("H2", "JT")
+        | ("JT", "JT") | ("H3", "JT") => {
+            return (NO, "LB26");
+        }
+        ("JL", "IN") | ("JV", "IN") | ("JT", "IN") | ("H2", "IN")
+        | ("H3", "IN") | ("JL", "PO") | ("JV", "PO") | ("JT", "PO")
+        | ("H2", "PO") | ("H3", "PO") | ("PR", "JL") | ("PR", "JV")
+        | ("PR", "JT") | ("PR", "H2") | ("PR", "H3") => {
+            return (NO, "LB27");
+        }
+        ("AL", "AL") | ("AL", "HL") | ("HL", "AL") | ("HL", "HL") => {
+            return (NO, "LB28");
+        }
+        ("IS", "AL") | ("IS", "HL") => return (NO, "LB29"),
+        ("AL", "OP") | ("HL", "OP") | ("NU", "OP") | ("CP", "AL")
+        | ("CP", "HL") | ("CP", "NU") => {
+            return (NO, "LB30");
+        }
+        ("RI", "RI") => {
+            // Regional indicators pair up, so only allow a break after an
+            // even number of them.
+            let mut count = 0;
+            let mut k = Some(i - 1);
+            while let Some(end) = k {
+                let (class, start) = effective_class(classes, end);
+                if class != "RI" {
+                    break;
+                }
+                count += 1;
+                k = if start == 0 { None } else { Some(start - 1) };
+            }
+            if count % 2 == 1 {
+                return (NO, "LB30a");
+            }
+        }
+        ("EB", "EM") => return (NO, "LB30b"),
+        _ => {}
+    }
+    (ALLOWED, "LB31")
+}
+
+/// Returns true if the boundary before the codepoint at index `i` is within
+/// a number, according to the tailoring of rule LB25 given in Example 7 of
+/// UAX #14:
+///
+/// ```text
+/// (PR | PO) × (OP | HY)? NU
+/// (OP | HY) × NU
+/// NU × (NU | SY | IS)
+/// NU (NU | SY | IS)* × (NU | SY | IS | CL | CP)
+/// NU (NU | SY | IS)* (CL | CP)? × (PO | PR)
+/// ```
+fn is_number_continuation(
+    classes: &[&'static str],
+    i: usize,
+    before: &str,
+    after: &str,
+) -> bool {
+    // The effective class of the codepoint following the one after this
+    // boundary, if any.
+    let after2 = {
+        let mut k = i + 1;
+        while k < classes.len()
+            && (classes[k] == "CM" || classes[k] == "ZWJ")
+            && takes_combining_marks(after)
+        {
+            k += 1;
+        }
+        if k < classes.len() { effective_class(classes, k).0 } else { "" }
+    };
+    match (before, after) {
+        ("PR", "NU") | ("PO", "NU") | ("OP", "NU") | ("HY", "NU") => {
+            return true;
+        }
+        ("PR", "OP") | ("PR", "HY") | ("PO", "OP") | ("PO", "HY") => {
+            return after2 == "NU";
+        }
+        _ => {}
+    }
+
+    // Look backwards for a number, optionally followed by a closing
+    // punctuation mark.
+    let (class, start) = effective_class(classes, i - 1);
+    let closed = class == "CL" || class == "CP";
+    let mut next = Some(i - 1);
+    if closed {
+        next = if start == 0 { None } else { Some(start - 1) };
+    }
+    let mut number = false;
+    while let Some(end) = next {
+        let (class, start) = effective_class(classes, end);
+        match class {
+            "NU" => number = true,
+            "SY" | "IS" => {}
+            _ => break,
+        }
+        next = if start == 0 { None } else { Some(start - 1) };
+    }
+    if !number {
+        return false;
+    }
+    match after {
+        "PO" | "PR" => true,
+        "NU" | "SY" | "IS" | "CL" | "CP" => !closed,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use break_test::{break_tests, parse_break_test};
+
+    use super::line_segments;
+
+    const LINE_BREAK_TEST: &'static str =
+        include_str!("../tests/data/LineBreakTest.txt");
+
+    #[test]
+    fn line_break_test() {
+        for line in break_tests(LINE_BREAK_TEST) {
+            let (text, expected) = parse_break_test(line);
+            let got: Vec<&str> =
+                line_segments(&text).into_iter().map(|(_, _, s)| s).collect();
+            assert_eq!(expected, got, "{}", line);
+        }
+    }
+}
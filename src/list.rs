@@ -4,13 +4,14 @@ use std::io::{self, Write};
 use clap;
 use tabwriter::TabWriter;
 use ucd_util::{
-    canonical_property_name, property_values, symbolic_name_normalize,
+    canonical_property_name, canonical_property_value, property_values,
+    symbolic_name_normalize,
 };
 
 use app::arg_to_str;
 use error::Result;
 use tables::slice::property_names::PROPERTY_NAMES;
-use tables::slice::property_values::PROPERTY_VALUES;
+use tables::ucd;
 
 pub fn command_list_properties(_: &clap::ArgMatches) -> Result<()> {
     print_assoc_list(&by_canonical_symbolic_name(PROPERTY_NAMES))
@@ -42,6 +43,22 @@ fn print_assoc_list(
     Ok(())
 }
 
+/// Find the canonical value of the given property matching the given value,
+/// using the loose matching rules of UAX #44 (e.g., `greek` matches the
+/// `Greek_And_Coptic` block).
+pub fn find_canonical_property_value(
+    canonical_property_name: &str,
+    given: &str,
+) -> Result<&'static str> {
+    let values = find_property_values(canonical_property_name)?;
+    let mut norm = given.to_string();
+    symbolic_name_normalize(&mut norm);
+    canonical_property_value(values, &norm).map_or(
+        err!("could not find {} value matching '{}'",
+             canonical_property_name, given),
+        Ok)
+}
+
 fn find_canonical_property_name(given: &str) -> Result<&'static str> {
     let mut norm = given.to_string();
     symbolic_name_normalize(&mut norm);
@@ -53,7 +70,7 @@ fn find_property_values(
     canonical_property_name: &str,
 ) -> Result<&'static [(&'static str, &'static str)]> {
     let name = canonical_property_name;
-    property_values(PROPERTY_VALUES, name)
+    property_values(ucd().property_values, name)
         .map_or(err!("could not find property values for '{}'", name), Ok)
 }
 
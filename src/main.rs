@@ -27,14 +27,34 @@ macro_rules! err {
     }
 }
 
+mod age;
 mod app;
+mod audit;
+mod bidi;
+mod block;
+#[cfg(test)]
+mod break_test;
+mod check_normalization;
 mod codepoint;
+mod compose;
+mod confusables;
+mod decompose;
 mod display;
+mod emoji;
 mod error;
+mod graphemes;
+mod ident;
+mod linebreak;
 mod list;
 mod name;
+mod normalize;
+mod numeric;
+mod script;
 mod search;
+mod segment;
 mod tables;
+mod variants;
+mod width;
 
 fn main() {
     if let Err(err) = try_main() {
@@ -48,10 +68,73 @@ fn main() {
 
 fn try_main() -> Result<()> {
     let matches = app::app().get_matches();
+    let version =
+        app::arg_to_unicode_version(matches.value_of_os("unicode-version"))?;
+    tables::select_unicode_version(version);
     match matches.subcommand() {
         ("search", Some(m)) => {
             search::command(m)
         }
+        ("normalize", Some(m)) => {
+            normalize::command(m)
+        }
+        ("decompose", Some(m)) => {
+            decompose::command(m)
+        }
+        ("compose", Some(m)) => {
+            compose::command(m)
+        }
+        ("check-normalization", Some(m)) => {
+            check_normalization::command(m)
+        }
+        ("graphemes", Some(m)) => {
+            graphemes::command(m)
+        }
+        ("segment", Some(m)) => {
+            segment::command(m)
+        }
+        ("linebreak", Some(m)) => {
+            linebreak::command(m)
+        }
+        ("bidi", Some(m)) => {
+            bidi::command(m)
+        }
+        ("width", Some(m)) => {
+            width::command(m)
+        }
+        ("block", Some(m)) => {
+            block::command_block(m)
+        }
+        ("list-blocks", Some(m)) => {
+            block::command_list_blocks(m)
+        }
+        ("confusables", Some(m)) => {
+            confusables::command(m)
+        }
+        ("ident", Some(m)) => {
+            ident::command(m)
+        }
+        ("audit", Some(m)) => {
+            audit::command(m)
+        }
+        ("emoji", Some(m)) => {
+            emoji::command(m)
+        }
+        ("variants", Some(m)) => {
+            variants::command(m)
+        }
+        ("since", Some(m)) => {
+            age::command_since(m)
+        }
+        ("timeline", Some(m)) => {
+            age::command_timeline(m)
+        }
+        ("scripts", Some(m)) => {
+            script::command_scripts(m)
+        }
+        ("list-scripts", Some(m)) => {
+            script::command_list_scripts(m)
+        }
         ("list-properties", Some(m)) => {
             list::command_list_properties(m)
         }
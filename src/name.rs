@@ -5,6 +5,7 @@ use ucd_util;
 
 use codepoint::Codepoint;
 use tables::fst::names::NAMES;
+use tables::{range_value, ucd};
 
 lazy_static! {
     static ref NAMES_WITH_ALIASES: BTreeMap<Codepoint, Names> = {
@@ -43,10 +44,29 @@ impl Names {
         }
         self.aliases.get(0).map(|name| &**name)
     }
+
+    /// Pick the shortest abbreviation among the aliases, if one exists.
+    ///
+    /// Abbreviations, such as `ZWJ` or `NBSP`, are the only aliases that
+    /// never contain a space.
+    fn abbreviation(&self) -> Option<&str> {
+        self.aliases
+            .iter()
+            .filter(|name| !name.contains(' '))
+            .min_by_key(|name| name.len())
+            .map(|name| &**name)
+    }
 }
 
 /// Return the name of the given codepoint, if it exists.
+///
+/// Names never change once assigned, so the names are shared by every
+/// version of Unicode, but only codepoints assigned in the selected version
+/// have one.
 pub fn codepoint_name(cp: Codepoint) -> Option<String> {
+    if !is_assigned(cp) {
+        return None;
+    }
     if let Some(name) = ucd_util::hangul_name(cp.value()) {
         return Some(name);
     }
@@ -58,3 +78,76 @@ pub fn codepoint_name(cp: Codepoint) -> Option<String> {
         .and_then(|x| x.one_name())
         .map(|x| x.to_owned())
 }
+
+/// Return the code point label of the given codepoint, if it has one.
+///
+/// Code point labels, as described in section 4.8 of the Unicode Standard,
+/// identify codepoints that have no name, such as `<control-0009>` or
+/// `<reserved-0378>`. Only controls, private use codepoints, surrogates,
+/// noncharacters and reserved codepoints have labels.
+pub fn codepoint_label(cp: Codepoint) -> Option<String> {
+    let kind =
+        if cp.is_noncharacter() {
+            "noncharacter"
+        } else {
+            let gc = range_value(ucd().general_category, cp.value());
+            match gc.unwrap_or("Cn") {
+                "Cc" => "control",
+                "Co" => "private-use",
+                "Cs" => "surrogate",
+                "Cn" => "reserved",
+                _ => return None,
+            }
+        };
+    Some(format!("<{}-{:04X}>", kind, cp.value()))
+}
+
+/// Return the name of the given codepoint, or its code point label if it has
+/// no name. If it has neither, then an empty string is returned.
+pub fn codepoint_name_or_label(cp: Codepoint) -> String {
+    codepoint_name(cp)
+        .or_else(|| codepoint_label(cp))
+        .unwrap_or("".to_string())
+}
+
+/// Return the abbreviated alias of the given codepoint, such as `ZWJ` for
+/// U+200D ZERO WIDTH JOINER, if it exists.
+pub fn codepoint_abbreviation(cp: Codepoint) -> Option<String> {
+    if !is_assigned(cp) {
+        return None;
+    }
+    NAMES_WITH_ALIASES
+        .get(&cp)
+        .and_then(|x| x.abbreviation())
+        .map(|x| x.to_owned())
+}
+
+/// Returns true if the given codepoint is assigned in the selected version
+/// of Unicode, i.e., its general category is not `Cn`.
+pub fn is_assigned(cp: Codepoint) -> bool {
+    range_value(ucd().general_category, cp.value()).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use codepoint::Codepoint;
+    use tables::select_unicode_version;
+
+    use super::{codepoint_label, codepoint_name};
+
+    #[test]
+    fn names_by_version() {
+        let bitcoin = Codepoint::from('\u{20BF}');
+        assert_eq!(Some("BITCOIN SIGN".to_string()), codepoint_name(bitcoin));
+        assert_eq!(None, codepoint_label(bitcoin));
+
+        select_unicode_version("9.0");
+        assert_eq!(None, codepoint_name(bitcoin));
+        assert_eq!(
+            Some("<reserved-20BF>".to_string()),
+            codepoint_label(bitcoin));
+        assert_eq!(
+            Some("EURO SIGN".to_string()),
+            codepoint_name(Codepoint::from('\u{20AC}')));
+    }
+}
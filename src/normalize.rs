@@ -0,0 +1,498 @@
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+
+use clap;
+
+use app::arg_to_str;
+use codepoint::Codepoint;
+use display::ShortWriter;
+use error::{Error, Result};
+use tables::{range_value, ucd};
+
+// Constants for the algorithmic decomposition and composition of Hangul
+// syllables, as described in Unicode 3.12.
+const S_BASE: u32 = 0xAC00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11A7;
+const L_COUNT: u32 = 19;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = V_COUNT * T_COUNT;
+const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+pub fn command(args: &clap::ArgMatches) -> Result<()> {
+    let form: Form = args.value_of("form").unwrap_or("nfc").parse()?;
+    let text = arg_to_str("text", args.value_of_os("text"))?;
+    let normalized = normalize(form, &text);
+    if !args.is_present("table") {
+        println!("{}", normalized);
+        return Ok(());
+    }
+
+    println!("before:");
+    let mut wtr = ShortWriter::new(io::stdout());
+    wtr.pictures(args.is_present("pictures"));
+    for c in text.chars() {
+        wtr.write_codepoint(Codepoint::from(c))?;
+    }
+    wtr.flush()?;
+
+    println!("\nafter ({}):", form);
+    let mut wtr = ShortWriter::new(io::stdout());
+    wtr.pictures(args.is_present("pictures"));
+    for c in normalized.chars() {
+        wtr.write_codepoint(Codepoint::from(c))?;
+    }
+    wtr.flush()?;
+    Ok(())
+}
+
+/// One of the four Unicode normalization forms described in UAX #15.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Form {
+    /// Canonical decomposition followed by canonical composition.
+    Nfc,
+    /// Canonical decomposition.
+    Nfd,
+    /// Compatibility decomposition followed by canonical composition.
+    Nfkc,
+    /// Compatibility decomposition.
+    Nfkd,
+}
+
+impl Form {
+    /// Returns true if this form uses compatibility decompositions.
+    pub fn is_compatibility(self) -> bool {
+        self == Form::Nfkc || self == Form::Nfkd
+    }
+
+    /// Returns true if this form recomposes after decomposing.
+    pub fn is_composed(self) -> bool {
+        self == Form::Nfc || self == Form::Nfkc
+    }
+}
+
+impl FromStr for Form {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Form> {
+        match &*s.to_lowercase() {
+            "nfc" => Ok(Form::Nfc),
+            "nfd" => Ok(Form::Nfd),
+            "nfkc" => Ok(Form::Nfkc),
+            "nfkd" => Ok(Form::Nfkd),
+            _ => err!("unrecognized normalization form: '{}'", s),
+        }
+    }
+}
+
+impl fmt::Display for Form {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Form::Nfc => write!(f, "NFC"),
+            Form::Nfd => write!(f, "NFD"),
+            Form::Nfkc => write!(f, "NFKC"),
+            Form::Nfkd => write!(f, "NFKD"),
+        }
+    }
+}
+
+/// The result of a normalization quick check, as described in UAX #15.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum QuickCheck {
+    /// The text is definitely in the normalization form.
+    Yes,
+    /// The text is definitely not in the normalization form.
+    No,
+    /// The text may or may not be in the normalization form. Determining
+    /// which requires normalizing it.
+    Maybe,
+}
+
+/// A single level of decomposition for a codepoint.
+///
+/// This corresponds to the decomposition mapping field of UnicodeData.txt,
+/// except that Hangul syllables are decomposed algorithmically.
+#[derive(Clone, Debug)]
+pub struct Decomposition {
+    /// The `Decomposition_Type` of this mapping, e.g., `Canonical` or `Font`.
+    pub kind: &'static str,
+    /// The codepoints that the decomposed codepoint maps to.
+    pub mapping: Vec<Codepoint>,
+}
+
+impl Decomposition {
+    /// Returns true if and only if this is a canonical decomposition.
+    pub fn is_canonical(&self) -> bool {
+        self.kind == "Canonical"
+    }
+
+    /// Return the formatting tag used for this decomposition in
+    /// UnicodeData.txt, e.g., `<font>`.
+    ///
+    /// Canonical decompositions have no tag.
+    pub fn tag(&self) -> Option<&'static str> {
+        Some(match self.kind {
+            "Circle" => "<circle>",
+            "Compat" => "<compat>",
+            "Final" => "<final>",
+            "Font" => "<font>",
+            "Fraction" => "<fraction>",
+            "Initial" => "<initial>",
+            "Isolated" => "<isolated>",
+            "Medial" => "<medial>",
+            "Narrow" => "<narrow>",
+            "Nobreak" => "<noBreak>",
+            "Small" => "<small>",
+            "Square" => "<square>",
+            "Sub" => "<sub>",
+            "Super" => "<super>",
+            "Vertical" => "<vertical>",
+            "Wide" => "<wide>",
+            _ => return None,
+        })
+    }
+}
+
+/// Return the single level decomposition of the given codepoint, if one
+/// exists.
+pub fn decomposition(cp: Codepoint) -> Option<Decomposition> {
+    if let Some(mapping) = hangul_decomposition(cp) {
+        return Some(Decomposition { kind: "Canonical", mapping });
+    }
+    let table = ucd().decomposition;
+    table
+        .binary_search_by_key(&cp.value(), |&(cp, _, _)| cp)
+        .ok()
+        .map(|i| {
+            let (_, kind, mapping) = table[i];
+            Decomposition {
+                kind,
+                mapping: mapping
+                    .iter()
+                    .map(|&cp| Codepoint::from_u32(cp).unwrap())
+                    .collect(),
+            }
+        })
+}
+
+/// Return the `Canonical_Combining_Class` of the given codepoint.
+pub fn canonical_combining_class(cp: Codepoint) -> u8 {
+    range_value(ucd().canonical_combining_class, cp.value()).unwrap_or(0)
+}
+
+/// Return the primary composite of the given pair of codepoints, if one
+/// exists.
+///
+/// This never returns codepoints that are excluded from composition.
+pub fn compose_pair(
+    first: Codepoint,
+    second: Codepoint,
+) -> Option<Codepoint> {
+    if let Some(cp) = hangul_compose_pair(first, second) {
+        return Some(cp);
+    }
+    let table = ucd().composition;
+    table
+        .binary_search_by_key(&(first.value(), second.value()), |&(k, _)| k)
+        .ok()
+        .map(|i| Codepoint::from_u32(table[i].1).unwrap())
+}
+
+/// Return the quick check property value (e.g., `NFC_Quick_Check`) of the
+/// given codepoint for the given normalization form.
+pub fn quick_check_codepoint(form: Form, cp: Codepoint) -> QuickCheck {
+    let table = match form {
+        Form::Nfc => ucd().nfc_quick_check,
+        Form::Nfkc => ucd().nfkc_quick_check,
+        // The decomposed forms have no Maybe values, and a codepoint is
+        // excluded from them precisely when it has a decomposition.
+        Form::Nfd => {
+            return match decomposition(cp) {
+                Some(ref d) if d.is_canonical() => QuickCheck::No,
+                _ => QuickCheck::Yes,
+            };
+        }
+        Form::Nfkd => {
+            return match decomposition(cp) {
+                Some(_) => QuickCheck::No,
+                None => QuickCheck::Yes,
+            };
+        }
+    };
+    match range_value(table, cp.value()) {
+        Some("No") => QuickCheck::No,
+        Some("Maybe") => QuickCheck::Maybe,
+        _ => QuickCheck::Yes,
+    }
+}
+
+/// Run the quick check algorithm from UAX #15 on the given text.
+pub fn quick_check(form: Form, text: &str) -> QuickCheck {
+    let mut result = QuickCheck::Yes;
+    let mut last_class = 0;
+    for c in text.chars() {
+        let cp = Codepoint::from(c);
+        let class = canonical_combining_class(cp);
+        if class != 0 && last_class > class {
+            return QuickCheck::No;
+        }
+        match quick_check_codepoint(form, cp) {
+            QuickCheck::Yes => {}
+            QuickCheck::No => return QuickCheck::No,
+            QuickCheck::Maybe => result = QuickCheck::Maybe,
+        }
+        last_class = class;
+    }
+    result
+}
+
+/// Returns true if and only if the given text is in the given normalization
+/// form.
+///
+/// The text is only normalized when the quick check is inconclusive.
+pub fn is_normalized(form: Form, text: &str) -> bool {
+    match quick_check(form, text) {
+        QuickCheck::Yes => true,
+        QuickCheck::No => false,
+        QuickCheck::Maybe => normalize(form, text) == text,
+    }
+}
+
+/// Normalize the given text to the given normalization form.
+pub fn normalize(form: Form, text: &str) -> String {
+    let cps = text.chars().map(Codepoint::from);
+    let mut cps = decompose(cps, form.is_compatibility());
+    if form.is_composed() {
+        cps = compose(cps);
+    }
+    cps.into_iter().map(|cp| cp.scalar().unwrap()).collect()
+}
+
+/// Fully decompose the given codepoints and put the result into canonical
+/// order.
+///
+/// If `compat` is true, then compatibility decompositions are applied in
+/// addition to canonical decompositions.
+fn decompose<I>(cps: I, compat: bool) -> Vec<Codepoint>
+where I: IntoIterator<Item=Codepoint>
+{
+    let mut decomposed = vec![];
+    for cp in cps {
+        decompose_into(cp, compat, &mut decomposed);
+    }
+    canonical_order(&mut decomposed);
+    decomposed
+}
+
+fn decompose_into(cp: Codepoint, compat: bool, into: &mut Vec<Codepoint>) {
+    match decomposition(cp) {
+        Some(ref d) if compat || d.is_canonical() => {
+            for &cp in &d.mapping {
+                decompose_into(cp, compat, into);
+            }
+        }
+        _ => into.push(cp),
+    }
+}
+
+/// Apply the canonical ordering algorithm, which stably sorts every maximal
+/// run of non-starters by their canonical combining class.
+fn canonical_order(cps: &mut [Codepoint]) {
+    let mut i = 0;
+    while i < cps.len() {
+        if canonical_combining_class(cps[i]) == 0 {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < cps.len() && canonical_combining_class(cps[i]) != 0 {
+            i += 1;
+        }
+        cps[start..i].sort_by_key(|&cp| canonical_combining_class(cp));
+    }
+}
+
+/// Apply the canonical composition algorithm to a sequence of codepoints
+/// that is fully decomposed and in canonical order.
+fn compose(cps: Vec<Codepoint>) -> Vec<Codepoint> {
+    let mut composed: Vec<Codepoint> = Vec::with_capacity(cps.len());
+    // The index of the last starter in `composed`, if any.
+    let mut starter = None;
+    // The combining class of the last codepoint pushed after the starter.
+    // This is `None` when the last codepoint pushed is the starter itself.
+    let mut last_class = None;
+    for cp in cps {
+        let class = canonical_combining_class(cp);
+        if let Some(i) = starter {
+            let blocked = match last_class {
+                None => false,
+                Some(last) => last >= class,
+            };
+            if !blocked {
+                if let Some(primary) = compose_pair(composed[i], cp) {
+                    composed[i] = primary;
+                    continue;
+                }
+            }
+        }
+        if class == 0 {
+            starter = Some(composed.len());
+            last_class = None;
+        } else {
+            last_class = Some(class);
+        }
+        composed.push(cp);
+    }
+    composed
+}
+
+fn hangul_decomposition(cp: Codepoint) -> Option<Vec<Codepoint>> {
+    let cp = cp.value();
+    if cp < S_BASE || cp >= S_BASE + S_COUNT {
+        return None;
+    }
+    let s_index = cp - S_BASE;
+    let t_index = s_index % T_COUNT;
+    // An LV syllable decomposes to its leading consonant and vowel, while an
+    // LVT syllable decomposes to its LV syllable and trailing consonant.
+    let (first, second) =
+        if t_index == 0 {
+            let l_index = s_index / N_COUNT;
+            let v_index = (s_index % N_COUNT) / T_COUNT;
+            (L_BASE + l_index, V_BASE + v_index)
+        } else {
+            (cp - t_index, T_BASE + t_index)
+        };
+    Some(vec![
+        Codepoint::from_u32(first).unwrap(),
+        Codepoint::from_u32(second).unwrap(),
+    ])
+}
+
+fn hangul_compose_pair(
+    first: Codepoint,
+    second: Codepoint,
+) -> Option<Codepoint> {
+    let (first, second) = (first.value(), second.value());
+    let cp =
+        if L_BASE <= first && first < L_BASE + L_COUNT
+            && V_BASE <= second && second < V_BASE + V_COUNT
+        {
+            let l_index = first - L_BASE;
+            let v_index = second - V_BASE;
+            S_BASE + (l_index * V_COUNT + v_index) * T_COUNT
+        } else if S_BASE <= first && first < S_BASE + S_COUNT
+            && (first - S_BASE) % T_COUNT == 0
+            && T_BASE < second && second < T_BASE + T_COUNT
+        {
+            first + (second - T_BASE)
+        } else {
+            return None;
+        };
+    Some(Codepoint::from_u32(cp).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::char;
+    use std::collections::BTreeSet;
+
+    use super::{Form, QuickCheck, is_normalized, normalize, quick_check};
+
+    const FORMS: &'static [Form] = &[
+        Form::Nfc, Form::Nfd, Form::Nfkc, Form::Nfkd,
+    ];
+
+    const NORMALIZATION_TEST: &'static str =
+        include_str!("../tests/data/NormalizationTest.txt");
+
+    fn parse_column(column: &str) -> String {
+        column
+            .split_whitespace()
+            .map(|hex| u32::from_str_radix(hex, 16).unwrap())
+            .map(|cp| char::from_u32(cp).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn quick_check_test() {
+        for line in NORMALIZATION_TEST.lines() {
+            let line = line.split('#').next().unwrap().trim();
+            if line.is_empty() || line.starts_with('@') {
+                continue;
+            }
+            for c in line.split(';').take(5).map(parse_column) {
+                for &form in FORMS {
+                    let normalized = normalize(form, &c) == c;
+                    // A quick check may be inconclusive, but it must never
+                    // contradict the result of normalizing.
+                    let wrong =
+                        if normalized {
+                            QuickCheck::No
+                        } else {
+                            QuickCheck::Yes
+                        };
+                    assert!(
+                        quick_check(form, &c) != wrong,
+                        "{}: {}", form, line);
+                    assert_eq!(
+                        normalized, is_normalized(form, &c),
+                        "{}: {}", form, line);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn normalization_test() {
+        let mut part1 = BTreeSet::new();
+        let mut in_part1 = false;
+        for line in NORMALIZATION_TEST.lines() {
+            let line = line.split('#').next().unwrap().trim();
+            if line.is_empty() {
+                continue;
+            }
+            if line.starts_with('@') {
+                in_part1 = line == "@Part1";
+                continue;
+            }
+            let cols: Vec<String> =
+                line.split(';').take(5).map(parse_column).collect();
+            let (c1, c2, c3, c4, c5) =
+                (&cols[0], &cols[1], &cols[2], &cols[3], &cols[4]);
+
+            for c in &[c1, c2, c3] {
+                assert_eq!(c2, &normalize(Form::Nfc, c), "NFC: {}", line);
+                assert_eq!(c3, &normalize(Form::Nfd, c), "NFD: {}", line);
+            }
+            for c in &[c4, c5] {
+                assert_eq!(c4, &normalize(Form::Nfc, c), "NFC: {}", line);
+                assert_eq!(c5, &normalize(Form::Nfd, c), "NFD: {}", line);
+            }
+            for c in &[c1, c2, c3, c4, c5] {
+                assert_eq!(c4, &normalize(Form::Nfkc, c), "NFKC: {}", line);
+                assert_eq!(c5, &normalize(Form::Nfkd, c), "NFKD: {}", line);
+            }
+            if in_part1 {
+                part1.insert(c1.chars().next().unwrap());
+            }
+        }
+
+        for cp in 0..0x110000 {
+            let c = match char::from_u32(cp) {
+                None => continue,
+                Some(c) => c,
+            };
+            if part1.contains(&c) {
+                continue;
+            }
+            let s = c.to_string();
+            for &form in FORMS {
+                assert_eq!(s, normalize(form, &s), "{}: {:04X}", form, cp);
+            }
+        }
+    }
+}
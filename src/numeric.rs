@@ -0,0 +1,144 @@
+use std::cmp;
+use std::fmt;
+use std::str::FromStr;
+
+use codepoint::Codepoint;
+use error::{Error, Result};
+use tables::{in_ranges, range_value, ucd};
+
+/// A numeric value, represented as a rational number in lowest terms.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Rational {
+    numerator: i64,
+    denominator: u64,
+}
+
+impl Rational {
+    /// Create a new rational number. The given fraction is reduced to
+    /// lowest terms. The denominator must not be zero.
+    pub fn new(numerator: i64, denominator: u64) -> Rational {
+        assert!(denominator > 0);
+        let magnitude = if numerator < 0 {
+            (numerator as u64).wrapping_neg()
+        } else {
+            numerator as u64
+        };
+        let divisor = cmp::max(gcd(magnitude, denominator), 1);
+        Rational {
+            numerator: numerator / divisor as i64,
+            denominator: denominator / divisor,
+        }
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.denominator == 1 {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+impl FromStr for Rational {
+    type Err = Error;
+
+    /// Parse an integer, such as `7`, or a fraction, such as `1/2`.
+    fn from_str(s: &str) -> Result<Rational> {
+        let mut parts = s.splitn(2, '/');
+        let numerator = parts.next().unwrap_or("").trim().parse();
+        let denominator = match parts.next() {
+            None => Ok(1),
+            Some(denominator) => denominator.trim().parse(),
+        };
+        match (numerator, denominator) {
+            (Ok(n), Ok(d)) if d > 0 => Ok(Rational::new(n, d)),
+            _ => err!("invalid numeric value: {}", s),
+        }
+    }
+}
+
+/// Return the `Numeric_Value` property of the given codepoint, if it has
+/// one.
+pub fn numeric_value(cp: Codepoint) -> Option<Rational> {
+    range_value(ucd().numeric_value, cp.value())
+        .map(|(n, d)| Rational::new(n, d))
+}
+
+/// Return the `Numeric_Type` property of the given codepoint.
+pub fn numeric_type(cp: Codepoint) -> &'static str {
+    range_value(ucd().numeric_type, cp.value()).unwrap_or("None")
+}
+
+/// Returns true if the numeric value of the given codepoint comes from one
+/// of the `kPrimaryNumeric`, `kAccountingNumeric` or `kOtherNumeric` fields
+/// of the Unihan database, rather than from UnicodeData.txt.
+pub fn is_unihan_numeric(cp: Codepoint) -> bool {
+    in_ranges(ucd().unihan_numeric_value, cp.value())
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let t = a % b;
+        a = b;
+        b = t;
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use codepoint::Codepoint;
+
+    use super::{Rational, is_unihan_numeric, numeric_type, numeric_value};
+
+    fn value(c: char) -> Option<String> {
+        numeric_value(Codepoint::from(c)).map(|v| v.to_string())
+    }
+
+    #[test]
+    fn rationals() {
+        assert_eq!(Rational::new(1, 2), Rational::new(2, 4));
+        assert_eq!(Rational::new(-1, 2), Rational::new(-3, 6));
+        assert_eq!(Rational::new(0, 1), Rational::new(0, 5));
+        assert_eq!("7", Rational::new(14, 2).to_string());
+        assert_eq!("-1/2", Rational::new(-2, 4).to_string());
+        assert_eq!(
+            "-9223372036854775808",
+            Rational::new(::std::i64::MIN, 1).to_string());
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(Rational::new(7, 1), "7".parse().unwrap());
+        assert_eq!(Rational::new(1, 2), "2/4".parse().unwrap());
+        assert_eq!(Rational::new(-1, 2), " -1 / 2 ".parse().unwrap());
+        assert!("1/0".parse::<Rational>().is_err());
+        assert!("1/-2".parse::<Rational>().is_err());
+        assert!("seven".parse::<Rational>().is_err());
+        assert!("".parse::<Rational>().is_err());
+    }
+
+    #[test]
+    fn values() {
+        assert_eq!(Some("7".to_string()), value('7'));
+        assert_eq!(Some("1/2".to_string()), value('\u{BD}'));
+        assert_eq!(Some("-1/2".to_string()), value('\u{F33}'));
+        assert_eq!(Some("7".to_string()), value('\u{2166}'));
+        assert_eq!(None, value('a'));
+        assert_eq!("Decimal", numeric_type(Codepoint::from('7')));
+        assert_eq!("Numeric", numeric_type(Codepoint::from('\u{BD}')));
+        assert_eq!("None", numeric_type(Codepoint::from('a')));
+    }
+
+    #[test]
+    fn unihan_values() {
+        // U+4E03 is the common form of seven and U+67D2 its accounting form.
+        assert_eq!(Some("7".to_string()), value('\u{4E03}'));
+        assert_eq!(Some("7".to_string()), value('\u{67D2}'));
+        assert!(is_unihan_numeric(Codepoint::from('\u{4E03}')));
+        assert!(is_unihan_numeric(Codepoint::from('\u{67D2}')));
+        assert!(!is_unihan_numeric(Codepoint::from('7')));
+    }
+}
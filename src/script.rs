@@ -0,0 +1,307 @@
+use std::collections::BTreeSet;
+use std::fmt;
+use std::io::{self, Write};
+
+use clap;
+use tabwriter::TabWriter;
+
+use app::arg_to_text;
+use codepoint::Codepoint;
+use display::{ColumnWriter, cell_text};
+use error::Result;
+use tables::{in_ranges, range_value, ucd};
+
+pub fn command_scripts(args: &clap::ArgMatches) -> Result<()> {
+    let text = arg_to_text("text", args.value_of_os("text"))?;
+
+    let mut used = BTreeSet::new();
+    for c in text.chars() {
+        for name in script_extensions(Codepoint::from(c)) {
+            if name != "Common" && name != "Inherited" {
+                used.insert(name);
+            }
+        }
+    }
+    let used: Vec<&str> = used.into_iter().collect();
+    println!("scripts: {}", used.join(" "));
+    println!("resolved: {}", resolved_script_set(&text));
+    let mixed = match Mixing::of(&text) {
+        Mixing::SingleScript => {
+            println!("mixing: single-script");
+            return Ok(());
+        }
+        Mixing::Highlightable => "highlightable",
+        Mixing::Mixed => "mixed",
+    };
+    println!("mixing: {}", mixed);
+    println!();
+
+    let pictures = args.is_present("pictures");
+    let mut wtr = ColumnWriter::new(io::stdout());
+    wtr.write_row(
+        vec!["run".to_string(), "scripts".to_string(), "text".to_string()]);
+    for (i, (set, run)) in script_runs(&text).into_iter().enumerate() {
+        wtr.write_row(vec![
+            (i + 1).to_string(),
+            set.to_string(),
+            cell_text(run, pictures),
+        ]);
+    }
+    wtr.flush()?;
+    Ok(())
+}
+
+pub fn command_list_scripts(_: &clap::ArgMatches) -> Result<()> {
+    let mut wtr = TabWriter::new(io::stdout());
+    writeln!(wtr, "script\tsc\tscx")?;
+    for &(name, ranges) in ucd().script_extension {
+        let sc: u32 = ucd().script
+            .iter()
+            .filter(|&&(_, _, script)| script == name)
+            .map(|&(start, end, _)| end - start + 1)
+            .sum();
+        let scx: u32 = ranges
+            .iter()
+            .map(|&(start, end)| end - start + 1)
+            .sum();
+        writeln!(wtr, "{}\t{}\t{}", name, sc, scx)?;
+    }
+    wtr.flush()?;
+    Ok(())
+}
+
+/// Return the `Script` property value of the given codepoint.
+pub fn script(cp: Codepoint) -> &'static str {
+    range_value(ucd().script, cp.value()).unwrap_or("Unknown")
+}
+
+/// Return the `Script_Extensions` property value of the given codepoint,
+/// as a sorted list of scripts.
+///
+/// A codepoint that is not listed in ScriptExtensions.txt has the single
+/// script given by its `Script` property, so this is never empty.
+pub fn script_extensions(cp: Codepoint) -> Vec<&'static str> {
+    let scripts: Vec<&'static str> = ucd().script_extension
+        .iter()
+        .filter(|&&(_, ranges)| in_ranges(ranges, cp.value()))
+        .map(|&(name, _)| name)
+        .collect();
+    if scripts.is_empty() {
+        vec![script(cp)]
+    } else {
+        scripts
+    }
+}
+
+/// Returns true if the `Script_Extensions` of the given codepoint contain
+/// the script with the given canonical name.
+pub fn has_script_extension(cp: Codepoint, name: &str) -> bool {
+    if script_extension_ranges(name).is_empty() {
+        return script(cp) == name;
+    }
+    in_ranges(script_extension_ranges(name), cp.value())
+}
+
+fn script_extension_ranges(name: &str) -> &'static [(u32, u32)] {
+    ucd().script_extension
+        .iter()
+        .find(|&&(script, _)| script == name)
+        .map(|&(_, ranges)| ranges)
+        .unwrap_or(&[])
+}
+
+/// A set of scripts, as used by the mixed-script detection of UTS #39.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ScriptSet {
+    /// The set of all scripts. This is the script set of `Common` and
+    /// `Inherited` codepoints, which may be used with any script.
+    All,
+    /// A particular set of scripts, which may be empty.
+    Scripts(BTreeSet<&'static str>),
+}
+
+impl ScriptSet {
+    /// Return the augmented script set of the given codepoint, as defined
+    /// by UTS #39 section 5.1.
+    ///
+    /// This is the `Script_Extensions` of the codepoint, augmented with the
+    /// writing systems that combine several scripts. That is, Han also
+    /// counts as Hanb (Han with Bopomofo), Jpan (Japanese) and Kore
+    /// (Korean), Hiragana and Katakana count as Jpan, Hangul counts as Kore
+    /// and Bopomofo counts as Hanb.
+    pub fn augmented(cp: Codepoint) -> ScriptSet {
+        let mut set = BTreeSet::new();
+        for name in script_extensions(cp) {
+            match name {
+                "Common" | "Inherited" => return ScriptSet::All,
+                "Han" => set.extend(&["Hanb", "Jpan", "Kore"]),
+                "Hiragana" | "Katakana" => { set.insert("Jpan"); }
+                "Hangul" => { set.insert("Kore"); }
+                "Bopomofo" => { set.insert("Hanb"); }
+                _ => {}
+            }
+            set.insert(name);
+        }
+        ScriptSet::Scripts(set)
+    }
+
+    /// Return the scripts in both this set and the one given.
+    pub fn intersection(&self, other: &ScriptSet) -> ScriptSet {
+        let a = match *self {
+            ScriptSet::All => return other.clone(),
+            ScriptSet::Scripts(ref a) => a,
+        };
+        let b = match *other {
+            ScriptSet::All => return self.clone(),
+            ScriptSet::Scripts(ref b) => b,
+        };
+        ScriptSet::Scripts(a.intersection(b).cloned().collect())
+    }
+
+    /// Returns true if this set contains the script with the given name.
+    pub fn contains(&self, name: &str) -> bool {
+        match *self {
+            ScriptSet::All => true,
+            ScriptSet::Scripts(ref set) => set.contains(name),
+        }
+    }
+
+    /// Returns true if this set contains no scripts.
+    pub fn is_empty(&self) -> bool {
+        match *self {
+            ScriptSet::All => false,
+            ScriptSet::Scripts(ref set) => set.is_empty(),
+        }
+    }
+}
+
+impl fmt::Display for ScriptSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ScriptSet::All => write!(f, "ALL"),
+            ScriptSet::Scripts(ref set) if set.is_empty() => {
+                write!(f, "(empty)")
+            }
+            ScriptSet::Scripts(ref set) => {
+                let names: Vec<&str> = set.iter().cloned().collect();
+                write!(f, "{}", names.join(" "))
+            }
+        }
+    }
+}
+
+/// How the scripts of a string are mixed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Mixing {
+    /// The resolved script set of the string is not empty.
+    SingleScript,
+    /// The string mixes scripts, but only in one of the ways permitted by
+    /// the Highly Restrictive level of UTS #39 section 5.2, e.g., Latin with
+    /// Han, Hiragana and Katakana.
+    Highlightable,
+    /// The string mixes scripts in any other way.
+    Mixed,
+}
+
+impl Mixing {
+    /// Determine how the scripts of the given string are mixed.
+    pub fn of(text: &str) -> Mixing {
+        if !resolved_script_set(text).is_empty() {
+            return Mixing::SingleScript;
+        }
+        let covers = |scripts: &[&str]| {
+            text.chars().all(|c| {
+                let cp = Codepoint::from(c);
+                match ScriptSet::augmented(cp) {
+                    ScriptSet::All => true,
+                    ScriptSet::Scripts(set) => {
+                        scripts.iter().any(|name| set.contains(name))
+                    }
+                }
+            })
+        };
+        if covers(&["Latin", "Han", "Hiragana", "Katakana"])
+            || covers(&["Latin", "Han", "Bopomofo"])
+            || covers(&["Latin", "Han", "Hangul"])
+        {
+            return Mixing::Highlightable;
+        }
+        Mixing::Mixed
+    }
+}
+
+/// Return the resolved script set of the given string, as defined by
+/// UTS #39 section 5.1. This is the intersection of the augmented script
+/// sets of every codepoint in the string.
+pub fn resolved_script_set(text: &str) -> ScriptSet {
+    text.chars().fold(ScriptSet::All, |set, c| {
+        set.intersection(&ScriptSet::augmented(Codepoint::from(c)))
+    })
+}
+
+/// Split the given string into maximal runs whose resolved script sets are
+/// not empty. Each run is returned along with its resolved script set.
+pub fn script_runs(text: &str) -> Vec<(ScriptSet, &str)> {
+    let mut runs = vec![];
+    let (mut start, mut set) = (0, ScriptSet::All);
+    for (i, c) in text.char_indices() {
+        let cp_set = ScriptSet::augmented(Codepoint::from(c));
+        let next = set.intersection(&cp_set);
+        if next.is_empty() {
+            runs.push((set, &text[start..i]));
+            start = i;
+            set = cp_set;
+        } else {
+            set = next;
+        }
+    }
+    if start < text.len() {
+        runs.push((set, &text[start..]));
+    }
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Mixing, ScriptSet, resolved_script_set, script_runs};
+
+    #[test]
+    fn mixing_single_script() {
+        assert_eq!(Mixing::SingleScript, Mixing::of("paypal"));
+        assert_eq!(Mixing::SingleScript, Mixing::of("user_42"));
+        assert_eq!(Mixing::SingleScript, Mixing::of("αβγ"));
+        assert_eq!(Mixing::SingleScript, Mixing::of("123"));
+        // Han and Hiragana resolve to Jpan, so Japanese is a single script.
+        assert_eq!(Mixing::SingleScript, Mixing::of("漢字ひらがな"));
+    }
+
+    #[test]
+    fn mixing_highlightable() {
+        assert_eq!(Mixing::Highlightable, Mixing::of("abc漢字ひらがな"));
+        assert_eq!(Mixing::Highlightable, Mixing::of("abc漢字ㄅ"));
+        assert_eq!(Mixing::Highlightable, Mixing::of("abc한글"));
+    }
+
+    #[test]
+    fn mixing_mixed() {
+        assert_eq!(Mixing::Mixed, Mixing::of("p\u{430}ypal"));
+        assert_eq!(Mixing::Mixed, Mixing::of("abcαβγ"));
+        assert_eq!(Mixing::Mixed, Mixing::of("ひらがな한글"));
+    }
+
+    #[test]
+    fn resolved() {
+        assert_eq!(ScriptSet::All, resolved_script_set("123 !"));
+        assert!(resolved_script_set("abc").contains("Latin"));
+        assert!(resolved_script_set("abcα").is_empty());
+    }
+
+    #[test]
+    fn runs() {
+        let runs: Vec<String> = script_runs("abc αβγ")
+            .into_iter()
+            .map(|(set, run)| format!("{}:{}", set, run))
+            .collect();
+        assert_eq!(vec!["Latin:abc ", "Greek:αβγ"], runs);
+    }
+}
@@ -7,31 +7,117 @@ use clap;
 use fst::{self, Streamer};
 use regex::bytes::{Regex, RegexBuilder};
 
+use bidi::bidi_mirroring_glyph;
 use codepoint::Codepoint;
-use display::ShortWriter;
+use display::{ColumnWriter, LongWriter, ShortWriter, cell_char};
+use emoji::{Sequence, emoji_sequences};
 use error::Result;
+use list::find_canonical_property_value;
+use name::{codepoint_label, codepoint_name_or_label, is_assigned};
+use numeric::{Rational, numeric_value};
+use script::has_script_extension;
 use tables::fst::names::NAMES;
 
 pub fn command(args: &clap::ArgMatches) -> Result<()> {
     let mut search_builder = SearchBuilder::new();
     search_builder
-        .case_insensitive(!args.is_present("case-sensitive"));
+        .case_insensitive(!args.is_present("case-sensitive"))
+        .labels(args.is_present("labels"))
+        .mirrored(args.is_present("mirrored"));
+    if let Some(value) = args.value_of("numeric-value") {
+        search_builder.numeric_value(Some(value.parse()?));
+    }
+    if let Some(name) = args.value_of("script-extension") {
+        let script = find_canonical_property_value("Script", name)?;
+        search_builder.script_extension(Some(script));
+    }
 
     if let Some(os_pat) = args.value_of_os("pattern") {
         search_builder.pattern(Some(pattern_to_str(os_pat)?));
     }
 
     let searcher = search_builder.build()?;
-    let results = ResultSink::from_search(searcher);
+    let mut results = ResultSink::from_search(searcher);
+    // Emoji sequences are only matched by name, so they never satisfy any
+    // of the other filters.
+    let filtered = args.is_present("mirrored")
+        || args.is_present("numeric-value")
+        || args.is_present("script-extension");
+    if !filtered {
+        results.sequences = search_builder.sequences()?;
+    }
     if !args.is_present("allow-large") && results.len() > 10_000 {
         return err!("{} results found, which is too large to print. \
                      Pass the -A flag to forcefully print them.",
                      results.len());
     }
+    if args.is_present("mirrored") {
+        return write_mirrored_pairs(
+            &results.codepoints, args.is_present("pictures"));
+    }
+    if args.is_present("long") {
+        let mut wtr = LongWriter::new(io::stdout());
+        wtr.pictures(args.is_present("pictures"));
+        for cp in results.codepoints {
+            wtr.write_codepoint(cp)?;
+        }
+        for seq in results.sequences {
+            wtr.write_sequence(&seq)?;
+        }
+        wtr.flush()?;
+        return Ok(());
+    }
     let mut wtr = ShortWriter::new(io::stdout());
+    wtr.pictures(args.is_present("pictures"));
     for cp in results.codepoints {
         wtr.write_codepoint(cp)?;
     }
+    for seq in results.sequences {
+        wtr.write_sequence(&seq)?;
+    }
+    wtr.flush()?;
+    Ok(())
+}
+
+/// Print each of the given codepoints side by side with its
+/// `Bidi_Mirroring_Glyph`. A pair is only printed once, even if both of its
+/// codepoints are given.
+///
+/// If `pictures` is enabled, then each codepoint is shown using
+/// `picture_char`.
+fn write_mirrored_pairs(
+    codepoints: &BTreeSet<Codepoint>,
+    pictures: bool,
+) -> Result<()> {
+    let mut wtr = ColumnWriter::new(io::stdout());
+    wtr.write_row(vec![
+        "codepoint".to_string(),
+        "character".to_string(),
+        "name".to_string(),
+        "mirror".to_string(),
+        "character".to_string(),
+        "name".to_string(),
+    ]);
+    for &cp in codepoints {
+        let mirror = match bidi_mirroring_glyph(cp) {
+            None => continue,
+            Some(mirror) => mirror,
+        };
+        if mirror < cp
+            && codepoints.contains(&mirror)
+            && bidi_mirroring_glyph(mirror) == Some(cp)
+        {
+            continue;
+        }
+        wtr.write_row(vec![
+            format!("U+{:04X}", cp.value()),
+            cell_char(cp, pictures),
+            codepoint_name_or_label(cp),
+            format!("U+{:04X}", mirror.value()),
+            cell_char(mirror, pictures),
+            codepoint_name_or_label(mirror),
+        ]);
+    }
     wtr.flush()?;
     Ok(())
 }
@@ -39,30 +125,64 @@ pub fn command(args: &clap::ArgMatches) -> Result<()> {
 #[derive(Debug)]
 struct ResultSink {
     codepoints: BTreeSet<Codepoint>,
+    sequences: Vec<Sequence>,
 }
 
 impl ResultSink {
     fn from_search(search: Search) -> ResultSink {
         ResultSink {
             codepoints: search.collect(),
+            sequences: vec![],
         }
     }
 
     fn len(&self) -> usize {
-        self.codepoints.len()
+        self.codepoints.len() + self.sequences.len()
     }
 }
 
 #[derive(Debug)]
 struct Search {
     source: SearchSource,
+    mirrored: bool,
+    numeric_value: Option<Rational>,
+    script_extension: Option<&'static str>,
+}
+
+impl Search {
+    /// Returns true if the given codepoint satisfies every filter of this
+    /// search.
+    fn is_match(&self, cp: Codepoint) -> bool {
+        if self.mirrored && bidi_mirroring_glyph(cp).is_none() {
+            return false;
+        }
+        if let Some(value) = self.numeric_value {
+            if numeric_value(cp) != Some(value) {
+                return false;
+            }
+        }
+        if let Some(script) = self.script_extension {
+            if !has_script_extension(cp, script) {
+                return false;
+            }
+        }
+        true
+    }
 }
 
 impl Iterator for Search {
     type Item = Codepoint;
 
     fn next(&mut self) -> Option<Codepoint> {
-        self.source.next()
+        loop {
+            let cp = match self.source.next() {
+                None => return None,
+                Some(cp) => cp,
+            };
+            if self.is_match(cp) {
+                return Some(cp);
+            }
+        }
     }
 }
 
@@ -71,6 +191,9 @@ enum SearchSource {
     ByPatternMatch {
         re: Regex,
         stream: fst::map::Stream<'static>,
+        /// When set, the next codepoint whose label should be searched once
+        /// every name has been searched.
+        labels: Option<u32>,
     },
 }
 
@@ -87,17 +210,32 @@ impl Iterator for SearchSource {
                 *cp += 1;
                 Some(next)
             }
-            SearchSource::ByPatternMatch { ref re, ref mut stream } => {
-                loop {
-                    let (name, tagged) = match stream.next() {
-                        None => return None,
-                        Some(x) => x,
+            SearchSource::ByPatternMatch {
+                ref re, ref mut stream, ref mut labels,
+            } => {
+                // The names are shared by every version of Unicode, so skip
+                // the codepoints that aren't assigned in the selected one.
+                while let Some((name, tagged)) = stream.next() {
+                    let cp = Codepoint::from_u64(tagged).unwrap();
+                    if re.is_match(name) && is_assigned(cp) {
+                        return Some(cp);
+                    }
+                }
+                let next = match *labels {
+                    None => return None,
+                    Some(ref mut next) => next,
+                };
+                while let Ok(cp) = Codepoint::from_u32(*next) {
+                    *next += 1;
+                    let label = match codepoint_label(cp) {
+                        None => continue,
+                        Some(label) => label,
                     };
-                    if !re.is_match(name) {
-                        continue;
+                    if re.is_match(label.as_bytes()) {
+                        return Some(cp);
                     }
-                    return Some(Codepoint::from_u64(tagged).unwrap());
                 }
+                None
             }
         }
     }
@@ -107,6 +245,10 @@ impl Iterator for SearchSource {
 struct SearchBuilder {
     pattern: Option<String>,
     case_insensitive: bool,
+    labels: bool,
+    mirrored: bool,
+    numeric_value: Option<Rational>,
+    script_extension: Option<&'static str>,
 }
 
 impl SearchBuilder {
@@ -115,23 +257,49 @@ impl SearchBuilder {
     }
 
     fn build(&self) -> Result<Search> {
-        let source = match self.pattern {
+        let source = match self.regex()? {
             None => SearchSource::AllCodepoints(0),
-            Some(ref pattern) => {
-                let re = RegexBuilder::new(pattern)
-                    .case_insensitive(self.case_insensitive)
-                    .build()?;
+            Some(re) => {
                 SearchSource::ByPatternMatch {
                     re: re,
                     stream: NAMES.stream(),
+                    labels: if self.labels { Some(0) } else { None },
                 }
             }
         };
         Ok(Search {
             source: source,
+            mirrored: self.mirrored,
+            numeric_value: self.numeric_value,
+            script_extension: self.script_extension,
         })
     }
 
+    /// Return every named emoji sequence whose name matches the pattern.
+    /// If there is no pattern, then no sequences are returned.
+    fn sequences(&self) -> Result<Vec<Sequence>> {
+        let re = match self.regex()? {
+            None => return Ok(vec![]),
+            Some(re) => re,
+        };
+        Ok(emoji_sequences()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|seq| re.is_match(seq.name.as_bytes()))
+            .collect())
+    }
+
+    fn regex(&self) -> Result<Option<Regex>> {
+        let pattern = match self.pattern {
+            None => return Ok(None),
+            Some(ref pattern) => pattern,
+        };
+        let re = RegexBuilder::new(pattern)
+            .case_insensitive(self.case_insensitive)
+            .build()?;
+        Ok(Some(re))
+    }
+
     fn pattern<S: AsRef<str>>(
         &mut self,
         pattern: Option<S>,
@@ -144,6 +312,38 @@ impl SearchBuilder {
         self.case_insensitive = yes;
         self
     }
+
+    /// Match the pattern against code point labels, such as
+    /// `<control-0009>`, in addition to names.
+    fn labels(&mut self, yes: bool) -> &mut SearchBuilder {
+        self.labels = yes;
+        self
+    }
+
+    /// Only match codepoints that have a `Bidi_Mirroring_Glyph`.
+    fn mirrored(&mut self, yes: bool) -> &mut SearchBuilder {
+        self.mirrored = yes;
+        self
+    }
+
+    /// Only match codepoints whose `Numeric_Value` is the given value.
+    fn numeric_value(
+        &mut self,
+        value: Option<Rational>,
+    ) -> &mut SearchBuilder {
+        self.numeric_value = value;
+        self
+    }
+
+    /// Only match codepoints whose `Script_Extensions` contain the script
+    /// with the given canonical name.
+    fn script_extension(
+        &mut self,
+        script: Option<&'static str>,
+    ) -> &mut SearchBuilder {
+        self.script_extension = script;
+        self
+    }
 }
 
 impl fmt::Debug for SearchSource {
@@ -164,6 +364,10 @@ impl Default for SearchBuilder {
         SearchBuilder {
             pattern: None,
             case_insensitive: true,
+            labels: false,
+            mirrored: false,
+            numeric_value: None,
+            script_extension: None,
         }
     }
 }
@@ -178,3 +382,33 @@ fn pattern_to_str(s: &OsStr) -> Result<&str> {
              s.to_string_lossy()),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::SearchBuilder;
+
+    fn numeric(value: &str) -> Vec<u32> {
+        let mut builder = SearchBuilder::new();
+        builder.numeric_value(Some(value.parse().unwrap()));
+        builder.build().unwrap().map(|cp| cp.value()).collect()
+    }
+
+    #[test]
+    fn numeric_value_seven() {
+        let found = numeric("7");
+        // DIGIT SEVEN, ROMAN NUMERAL SEVEN and the Unihan numerals.
+        for &n in &[0x37, 0x2166, 0x4E03, 0x67D2] {
+            assert!(found.contains(&n), "missing U+{:04X}", n);
+        }
+        assert!(!found.contains(&0x38));
+    }
+
+    #[test]
+    fn numeric_value_fractions() {
+        let half = numeric("1/2");
+        assert!(half.contains(&0xBD));
+        assert_eq!(half, numeric("2/4"));
+        assert!(!half.contains(&0xBC));
+        assert_eq!(vec![0xF33], numeric("-1/2"));
+    }
+}
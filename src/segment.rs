@@ -0,0 +1,392 @@
+use std::io;
+
+use clap;
+
+use app::arg_to_str;
+use codepoint::Codepoint;
+use display::{ColumnWriter, cell_text};
+use error::Result;
+use tables::{range_value, ucd};
+
+pub fn command(args: &clap::ArgMatches) -> Result<()> {
+    let text = arg_to_str("text", args.value_of_os("text"))?;
+    let segments =
+        if args.is_present("sentences") {
+            sentences(&text)
+        } else {
+            words(&text)
+        };
+
+    let pictures = args.is_present("pictures");
+    let mut wtr = ColumnWriter::new(io::stdout());
+    wtr.write_row(
+        vec!["segment".to_string(), "rule".to_string(), "text".to_string()]);
+    for (i, &(rule, segment)) in segments.iter().enumerate() {
+        wtr.write_row(vec![
+            (i + 1).to_string(),
+            rule.to_string(),
+            cell_text(segment, pictures),
+        ]);
+    }
+    wtr.flush()?;
+    Ok(())
+}
+
+/// Return the `Word_Break` property value of the given codepoint.
+pub fn word_break(cp: Codepoint) -> &'static str {
+    range_value(ucd().word_break, cp.value()).unwrap_or("Other")
+}
+
+/// Return the `Sentence_Break` property value of the given codepoint.
+pub fn sentence_break(cp: Codepoint) -> &'static str {
+    range_value(ucd().sentence_break, cp.value()).unwrap_or("Other")
+}
+
+/// Split the given text into words, as described in UAX #29.
+///
+/// Each word is returned along with the rule that decided the boundary
+/// before it.
+pub fn words(text: &str) -> Vec<(&'static str, &str)> {
+    split(text, word_break, word_boundary)
+}
+
+/// Split the given text into sentences, as described in UAX #29.
+///
+/// Each sentence is returned along with the rule that decided the boundary
+/// before it.
+pub fn sentences(text: &str) -> Vec<(&'static str, &str)> {
+    split(text, sentence_break, sentence_boundary)
+}
+
+/// Split text at every boundary found by the given boundary function, which
+/// is given the property values of every codepoint in the text.
+fn split<P, B>(
+    text: &str,
+    property: P,
+    boundary: B,
+) -> Vec<(&'static str, &str)>
+where P: Fn(Codepoint) -> &'static str,
+      B: Fn(&[&'static str], usize) -> (bool, &'static str)
+{
+    let values: Vec<&str> =
+        text.chars().map(|c| property(Codepoint::from(c))).collect();
+    let mut segments = vec![];
+    let (mut start, mut start_rule) = (0, "");
+    for (i, (offset, _)) in text.char_indices().enumerate() {
+        let (is_break, rule) = boundary(&values, i);
+        if !is_break {
+            continue;
+        }
+        if i > 0 {
+            segments.push((start_rule, &text[start..offset]));
+        }
+        start = offset;
+        start_rule = rule;
+    }
+    if start < text.len() {
+        segments.push((start_rule, &text[start..]));
+    }
+    segments
+}
+
+fn is_ahletter(v: &str) -> bool {
+    v == "ALetter" || v == "Hebrew_Letter"
+}
+
+fn is_midnumletq(v: &str) -> bool {
+    v == "MidNumLet" || v == "Single_Quote"
+}
+
+/// Returns true if the given `Word_Break` value is ignored by rule WB4.
+fn is_word_ignorable(v: &str) -> bool {
+    v == "Extend" || v == "Format" || v == "ZWJ"
+}
+
+/// Determine whether there is a word boundary before the codepoint at index
+/// `i`, given the `Word_Break` values of every codepoint in the text.
+///
+/// The rule from UAX #29 that decides the boundary is returned along with
+/// the decision.
+fn word_boundary(wb: &[&'static str], i: usize) -> (bool, &'static str) {
+    if i == 0 {
+        return (true, "WB1");
+    }
+    let is_newline = |v: &str| v == "Newline" || v == "CR" || v == "LF";
+    let (before, after) = (wb[i - 1], wb[i]);
+    if before == "CR" && after == "LF" {
+        return (false, "WB3");
+    }
+    if is_newline(before) {
+        return (true, "WB3a");
+    }
+    if is_newline(after) {
+        return (true, "WB3b");
+    }
+    if before == "ZWJ"
+        && (after == "Glue_After_Zwj" || after == "E_Base_GAZ")
+    {
+        return (false, "WB3c");
+    }
+    if is_word_ignorable(after) {
+        return (false, "WB4");
+    }
+
+    // Per WB4, ignorable codepoints are treated as if they were the
+    // codepoint preceding them, so find the codepoints on either side of
+    // this boundary while skipping over them. Ignorable codepoints do not
+    // attach to a preceding newline, which always has a boundary after it.
+    let prev = |j: usize| -> Option<usize> {
+        let mut j = j;
+        while j > 0 {
+            j -= 1;
+            if !is_word_ignorable(wb[j]) {
+                return Some(j);
+            }
+        }
+        None
+    };
+    let next = |j: usize| -> Option<usize> {
+        (j + 1..wb.len()).find(|&k| !is_word_ignorable(wb[k]))
+    };
+    let p = match prev(i) {
+        Some(p) if !is_newline(wb[p]) => p,
+        _ => i - 1,
+    };
+    let before = wb[p];
+    let before2 = prev(p).map_or("", |j| wb[j]);
+    let after2 = next(i).map_or("", |j| wb[j]);
+
+    if is_ahletter(before) && is_ahletter(after) {
+        return (false, "WB5");
+    }
+    if is_ahletter(before)
+        && (after == "MidLetter" || is_midnumletq(after))
+        && is_ahletter(after2)
+    {
+        return (false, "WB6");
+    }
+    if is_ahletter(before2)
+        && (before == "MidLetter" || is_midnumletq(before))
+        && is_ahletter(after)
+    {
+        return (false, "WB7");
+    }
+    if before == "Hebrew_Letter" && after == "Single_Quote" {
+        return (false, "WB7a");
+    }
+    if before == "Hebrew_Letter"
+        && after == "Double_Quote"
+        && after2 == "Hebrew_Letter"
+    {
+        return (false, "WB7b");
+    }
+    if before2 == "Hebrew_Letter"
+        && before == "Double_Quote"
+        && after == "Hebrew_Letter"
+    {
+        return (false, "WB7c");
+    }
+    if before == "Numeric" && after == "Numeric" {
+        return (false, "WB8");
+    }
+    if is_ahletter(before) && after == "Numeric" {
+        return (false, "WB9");
+    }
+    if before == "Numeric" && is_ahletter(after) {
+        return (false, "WB10");
+    }
+    if before2 == "Numeric"
+        && (before == "MidNum" || is_midnumletq(before))
+        && after == "Numeric"
+    {
+        return (false, "WB11");
+    }
+    if before == "Numeric"
+        && (after == "MidNum" || is_midnumletq(after))
+        && after2 == "Numeric"
+    {
+        return (false, "WB12");
+    }
+    if before == "Katakana" && after == "Katakana" {
+        return (false, "WB13");
+    }
+    if after == "ExtendNumLet"
+        && (is_ahletter(before)
+            || before == "Numeric"
+            || before == "Katakana"
+            || before == "ExtendNumLet")
+    {
+        return (false, "WB13a");
+    }
+    if before == "ExtendNumLet"
+        && (is_ahletter(after) || after == "Numeric" || after == "Katakana")
+    {
+        return (false, "WB13b");
+    }
+    if (before == "E_Base" || before == "E_Base_GAZ")
+        && after == "E_Modifier"
+    {
+        return (false, "WB14");
+    }
+    if before == "Regional_Indicator" && after == "Regional_Indicator" {
+        // Regional indicators pair up, so only break after an even number
+        // of them.
+        let mut count = 0;
+        let mut j = Some(p);
+        while let Some(k) = j {
+            if wb[k] != "Regional_Indicator" {
+                break;
+            }
+            count += 1;
+            j = prev(k);
+        }
+        if count % 2 == 1 {
+            return (false, if j.is_none() { "WB15" } else { "WB16" });
+        }
+    }
+    (true, "WB999")
+}
+
+fn is_parasep(v: &str) -> bool {
+    v == "Sep" || v == "CR" || v == "LF"
+}
+
+fn is_saterm(v: &str) -> bool {
+    v == "STerm" || v == "ATerm"
+}
+
+/// Returns true if the given `Sentence_Break` value is ignored by rule SB5.
+fn is_sentence_ignorable(v: &str) -> bool {
+    v == "Extend" || v == "Format"
+}
+
+/// Determine whether there is a sentence boundary before the codepoint at
+/// index `i`, given the `Sentence_Break` values of every codepoint in the
+/// text.
+///
+/// The rule from UAX #29 that decides the boundary is returned along with
+/// the decision.
+fn sentence_boundary(sb: &[&'static str], i: usize) -> (bool, &'static str) {
+    if i == 0 {
+        return (true, "SB1");
+    }
+    let (before, after) = (sb[i - 1], sb[i]);
+    if before == "CR" && after == "LF" {
+        return (false, "SB3");
+    }
+    if is_parasep(before) {
+        return (true, "SB4");
+    }
+    if is_sentence_ignorable(after) {
+        return (false, "SB5");
+    }
+
+    // Per SB5, ignorable codepoints are treated as if they were the
+    // codepoint preceding them. Walk backwards over them to find the
+    // effective values preceding this boundary. Ignorable codepoints do not
+    // attach to a preceding paragraph separator.
+    //
+    // The rules below only need the two values preceding the boundary and
+    // any spaces and closing punctuation before a terminator, so the walk
+    // stops at the first value after those.
+    let mut prevs = vec![];
+    let mut j = i;
+    while j > 0 {
+        j -= 1;
+        if is_sentence_ignorable(sb[j]) && j > 0 && !is_parasep(sb[j - 1]) {
+            continue;
+        }
+        prevs.push(sb[j]);
+        if prevs.len() >= 2 && sb[j] != "Sp" && sb[j] != "Close" {
+            break;
+        }
+    }
+    let before = prevs[0];
+    let before2 = prevs.get(1).cloned().unwrap_or("");
+
+    if before == "ATerm" && after == "Numeric" {
+        return (false, "SB6");
+    }
+    if (before2 == "Upper" || before2 == "Lower")
+        && before == "ATerm"
+        && after == "Upper"
+    {
+        return (false, "SB7");
+    }
+
+    // The remaining rules all look for a sentence terminator followed by
+    // any number of closing punctuation and then any number of spaces.
+    let mut k = 0;
+    while k < prevs.len() && prevs[k] == "Sp" {
+        k += 1;
+    }
+    let has_sp = k > 0;
+    while k < prevs.len() && prevs[k] == "Close" {
+        k += 1;
+    }
+    let term = prevs.get(k).cloned().unwrap_or("");
+    if !is_saterm(term) {
+        return (false, "SB998");
+    }
+    if term == "ATerm" {
+        // SB8 skips over everything that isn't a letter, a separator or a
+        // terminator, so the first codepoint that is decides the rule.
+        for &v in &sb[i..] {
+            if v == "Lower" {
+                return (false, "SB8");
+            }
+            if v == "OLetter" || v == "Upper" || is_parasep(v) || is_saterm(v)
+            {
+                break;
+            }
+        }
+    }
+    if after == "SContinue" || is_saterm(after) {
+        return (false, "SB8a");
+    }
+    if !has_sp && (after == "Close" || after == "Sp" || is_parasep(after)) {
+        return (false, "SB9");
+    }
+    if after == "Sp" || is_parasep(after) {
+        return (false, "SB10");
+    }
+    (true, "SB11")
+}
+
+#[cfg(test)]
+mod tests {
+    use break_test::{break_tests, parse_break_test};
+    use tables::select_unicode_version;
+
+    use super::{sentences, words};
+
+    const WORD_BREAK_TEST: &'static str =
+        include_str!("../tests/data/WordBreakTest.txt");
+    // This is still the 9.0.0 version of the sentence break test, so it is
+    // checked against the Unicode 9.0 tables. It needs to be replaced with
+    // SentenceBreakTest-10.0.0.txt from
+    // https://www.unicode.org/Public/10.0.0/ucd/auxiliary/.
+    const SENTENCE_BREAK_TEST: &'static str =
+        include_str!("../tests/data/SentenceBreakTest.txt");
+
+    #[test]
+    fn word_break_test() {
+        for line in break_tests(WORD_BREAK_TEST) {
+            let (text, expected) = parse_break_test(line);
+            let got: Vec<&str> =
+                words(&text).into_iter().map(|(_, w)| w).collect();
+            assert_eq!(expected, got, "{}", line);
+        }
+    }
+
+    #[test]
+    fn sentence_break_test() {
+        select_unicode_version("9.0");
+        for line in break_tests(SENTENCE_BREAK_TEST) {
+            let (text, expected) = parse_break_test(line);
+            let got: Vec<&str> =
+                sentences(&text).into_iter().map(|(_, s)| s).collect();
+            assert_eq!(expected, got, "{}", line);
+        }
+    }
+}
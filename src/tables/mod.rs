@@ -1,2 +1,291 @@
+use std::cell::Cell;
+use std::cmp::Ordering;
+
+use error::Result;
+
 pub mod fst;
 pub mod slice;
+pub mod v9_0;
+
+/// The versions of Unicode whose data is embedded in rucd, from oldest to
+/// newest. The newest version is used when `latest` is requested.
+pub const UNICODE_VERSIONS: &'static [&'static str] = &["9.0", "10.0"];
+
+/// A table of the codepoint ranges of each value of a property, or of each
+/// boolean property in a group, such as the `BY_NAME` tables.
+pub type ByName = &'static [(&'static str, &'static [(u32, u32)])];
+
+/// The tables generated from a single version of the UCD.
+///
+/// The tables for the latest version live in `slice`, while the tables for
+/// each older version live in a module named after that version, e.g.,
+/// `v9_0`. Every module has the same layout, so adding a version means
+/// generating its tables with ucd-generate, as done by
+/// `scripts/generate-ucd-tables`, and adding them to `UCDS` and
+/// `UNICODE_VERSIONS`.
+///
+/// Character names never change once assigned, so the names in `fst` and
+/// the property names in `slice` are shared by every version.
+///
+/// The security data of UTS #39, the emoji data of UTS #51 and
+/// StandardizedVariants.txt are optional. A version without them has `None`
+/// for their tables, and commands that need them report that they aren't
+/// available for that version.
+///
+/// The emoji data of the latest version comes from Emoji 5.0, except for
+/// `Extended_Pictographic`, which was first published in Emoji 11.0 and so
+/// comes from Emoji 13.0.
+pub struct Ucd {
+    pub age: &'static [(u32, u32, &'static str)],
+    pub bidi_brackets: &'static [(u32, u32, &'static str)],
+    pub bidi_class: &'static [(u32, u32, &'static str)],
+    pub bidi_mirrored: &'static [(u32, u32)],
+    pub bidi_mirroring_glyph: &'static [(u32, u32)],
+    pub block: &'static [(u32, u32, &'static str)],
+    pub canonical_combining_class: &'static [(u32, u32, u8)],
+    pub composition: &'static [((u32, u32), u32)],
+    pub confusables: Option<&'static [(u32, &'static [u32])]>,
+    pub decomposition: &'static [(u32, &'static str, &'static [u32])],
+    pub default_ignorable_code_point: &'static [(u32, u32)],
+    pub east_asian_width: &'static [(u32, u32, &'static str)],
+    pub emoji_properties: Option<ByName>,
+    pub emoji: Option<&'static [(u32, u32)]>,
+    pub emoji_modifier: Option<&'static [(u32, u32)]>,
+    pub emoji_modifier_base: Option<&'static [(u32, u32)]>,
+    pub emoji_presentation: Option<&'static [(u32, u32)]>,
+    pub extended_pictographic: Option<&'static [(u32, u32)]>,
+    pub emoji_sequences:
+        Option<&'static [(&'static [u32], &'static str, &'static str)]>,
+    pub emoji_variation_sequences:
+        Option<&'static [(u32, u32, &'static str)]>,
+    pub general_category: &'static [(u32, u32, &'static str)],
+    pub grapheme_cluster_break: &'static [(u32, u32, &'static str)],
+    pub identifier_properties: ByName,
+    pub xid_start: &'static [(u32, u32)],
+    pub xid_continue: &'static [(u32, u32)],
+    pub identifier_status: Option<&'static [(u32, u32, &'static str)]>,
+    pub identifier_type: Option<&'static [(u32, u32, &'static str)]>,
+    pub line_break: &'static [(u32, u32, &'static str)],
+    pub nfc_quick_check: &'static [(u32, u32, &'static str)],
+    pub nfkc_quick_check: &'static [(u32, u32, &'static str)],
+    pub numeric_type: &'static [(u32, u32, &'static str)],
+    pub numeric_value: &'static [(u32, u32, (i64, u64))],
+    pub unihan_numeric_value: &'static [(u32, u32)],
+    pub property_values: &'static [
+        (&'static str, &'static [(&'static str, &'static str)])
+    ],
+    pub script: &'static [(u32, u32, &'static str)],
+    pub script_extension: ByName,
+    pub sentence_break: &'static [(u32, u32, &'static str)],
+    pub standardized_variants: Option<&'static [(u32, u32, &'static str)]>,
+    pub word_break: &'static [(u32, u32, &'static str)],
+}
+
+macro_rules! ucd {
+    ($tables:ident { $($optional:ident: $table:expr,)* }) => {
+        Ucd {
+            age: $tables::age::AGE,
+            bidi_brackets: $tables::bidi_brackets::BIDI_BRACKETS,
+            bidi_class: $tables::bidi_class::BIDI_CLASS,
+            bidi_mirrored: $tables::bidi_mirrored::BIDI_MIRRORED,
+            bidi_mirroring_glyph:
+                $tables::bidi_mirroring_glyph::BIDI_MIRRORING_GLYPH,
+            block: $tables::block::BLOCK,
+            canonical_combining_class:
+                $tables::canonical_combining_class::CANONICAL_COMBINING_CLASS,
+            composition: $tables::composition::COMPOSITION,
+            decomposition: $tables::decomposition::DECOMPOSITION,
+            default_ignorable_code_point:
+                $tables::default_ignorable_code_point
+                    ::DEFAULT_IGNORABLE_CODE_POINT,
+            east_asian_width: $tables::east_asian_width::EAST_ASIAN_WIDTH,
+            general_category: $tables::general_category::GENERAL_CATEGORY,
+            grapheme_cluster_break:
+                $tables::grapheme_cluster_break::GRAPHEME_CLUSTER_BREAK,
+            identifier_properties: $tables::identifier_properties::BY_NAME,
+            xid_start: $tables::identifier_properties::XID_START,
+            xid_continue: $tables::identifier_properties::XID_CONTINUE,
+            line_break: $tables::line_break::LINE_BREAK,
+            nfc_quick_check:
+                $tables::normalization_quick_check::NFC_QUICK_CHECK,
+            nfkc_quick_check:
+                $tables::normalization_quick_check::NFKC_QUICK_CHECK,
+            numeric_type: $tables::numeric_type::NUMERIC_TYPE,
+            numeric_value: $tables::numeric_value::NUMERIC_VALUE,
+            unihan_numeric_value:
+                $tables::numeric_value::UNIHAN_NUMERIC_VALUE,
+            property_values: $tables::property_values::PROPERTY_VALUES,
+            script: $tables::script::SCRIPT,
+            script_extension: $tables::script_extension::BY_NAME,
+            sentence_break: $tables::sentence_break::SENTENCE_BREAK,
+            word_break: $tables::word_break::WORD_BREAK,
+            $($optional: $table,)*
+        }
+    }
+}
+
+/// The tables for each version in `UNICODE_VERSIONS`, in the same order.
+const UCDS: &'static [Ucd] = &[
+    // The security and emoji data released alongside Unicode 9.0 (security
+    // 9.0.0 and emoji 4.0) isn't embedded, and neither is its
+    // StandardizedVariants.txt.
+    ucd!(v9_0 {
+        confusables: None,
+        emoji_properties: None,
+        emoji: None,
+        emoji_modifier: None,
+        emoji_modifier_base: None,
+        emoji_presentation: None,
+        extended_pictographic: None,
+        emoji_sequences: None,
+        emoji_variation_sequences: None,
+        identifier_status: None,
+        identifier_type: None,
+        standardized_variants: None,
+    }),
+    ucd!(slice {
+        confusables: Some(slice::confusables::CONFUSABLES),
+        emoji_properties: Some(slice::emoji_properties::BY_NAME),
+        emoji: Some(slice::emoji_properties::EMOJI),
+        emoji_modifier: Some(slice::emoji_properties::EMOJI_MODIFIER),
+        emoji_modifier_base:
+            Some(slice::emoji_properties::EMOJI_MODIFIER_BASE),
+        emoji_presentation: Some(slice::emoji_properties::EMOJI_PRESENTATION),
+        extended_pictographic:
+            Some(slice::extended_pictographic::EXTENDED_PICTOGRAPHIC),
+        emoji_sequences: Some(slice::emoji_sequences::EMOJI_SEQUENCES),
+        emoji_variation_sequences:
+            Some(slice::emoji_variation_sequences::EMOJI_VARIATION_SEQUENCES),
+        identifier_status: Some(slice::identifier_status::IDENTIFIER_STATUS),
+        identifier_type: Some(slice::identifier_type::IDENTIFIER_TYPE),
+        standardized_variants:
+            Some(slice::standardized_variants::STANDARDIZED_VARIANTS),
+    }),
+];
+
+thread_local! {
+    static SELECTED: Cell<usize> = Cell::new(UCDS.len() - 1);
+}
+
+/// Select the version of Unicode whose tables are returned by `ucd`.
+///
+/// The version given must be one of `UNICODE_VERSIONS`. Until a version is
+/// selected, the latest version is used.
+pub fn select_unicode_version(version: &str) {
+    let i = UNICODE_VERSIONS
+        .iter()
+        .position(|&v| v == version)
+        .expect("an embedded Unicode version");
+    SELECTED.with(|selected| selected.set(i));
+}
+
+/// Return the tables for the selected version of Unicode.
+pub fn ucd() -> &'static Ucd {
+    SELECTED.with(|selected| &UCDS[selected.get()])
+}
+
+/// Return the selected version of Unicode, e.g., `9.0`.
+pub fn unicode_version() -> &'static str {
+    SELECTED.with(|selected| UNICODE_VERSIONS[selected.get()])
+}
+
+/// Return the given optional table, or an error saying that the data it
+/// holds, e.g., `emoji data`, isn't available for the selected version of
+/// Unicode.
+pub fn require<T>(table: Option<T>, data: &str) -> Result<T> {
+    match table {
+        None => {
+            err!("{} is not available for Unicode {}", data, unicode_version())
+        }
+        Some(table) => Ok(table),
+    }
+}
+
+/// Return the value associated with the range containing the given
+/// codepoint.
+///
+/// The table given must be sorted by range and its ranges must not overlap,
+/// which is true of every range table generated by ucd-generate. If no range
+/// contains the codepoint, then `None` is returned.
+pub fn range_value<T: Copy>(table: &[(u32, u32, T)], cp: u32) -> Option<T> {
+    table
+        .binary_search_by(|&(start, end, _)| {
+            if end < cp {
+                Ordering::Less
+            } else if start > cp {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .ok()
+        .map(|i| table[i].2)
+}
+
+/// Returns true if and only if the given codepoint is in one of the ranges
+/// of the given table.
+///
+/// The table given must be sorted by range and its ranges must not overlap.
+pub fn in_ranges(table: &[(u32, u32)], cp: u32) -> bool {
+    table
+        .binary_search_by(|&(start, end)| {
+            if end < cp {
+                Ordering::Less
+            } else if start > cp {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        UNICODE_VERSIONS, range_value, require, select_unicode_version, ucd,
+    };
+
+    #[test]
+    fn latest_by_default() {
+        assert_eq!(Some(&"10.0"), UNICODE_VERSIONS.last());
+        // BITCOIN SIGN was added in Unicode 10.0.
+        assert_eq!(Some("Sc"), range_value(ucd().general_category, 0x20BF));
+    }
+
+    #[test]
+    fn select_versions() {
+        select_unicode_version("9.0");
+        assert_eq!(None, range_value(ucd().general_category, 0x20BF));
+        assert_eq!(Some("V9_0"), range_value(ucd().age, 0x1F6F6));
+        assert_eq!(None, range_value(ucd().age, 0x1F6F7));
+        // SNOWBOARDER became an emoji modifier base in Unicode 10.0.
+        assert_eq!(Some("ID"), range_value(ucd().line_break, 0x1F3C2));
+        assert_eq!(None, range_value(ucd().grapheme_cluster_break, 0x1F3C2));
+        // MODIFIER LETTER MINUS SIGN became an ALetter in Unicode 10.0.
+        assert_eq!(Some("MidLetter"), range_value(ucd().word_break, 0x2D7));
+
+        select_unicode_version("10.0");
+        assert_eq!(Some("Sc"), range_value(ucd().general_category, 0x20BF));
+        assert_eq!(Some("V10_0"), range_value(ucd().age, 0x1F6F7));
+        assert_eq!(Some("EB"), range_value(ucd().line_break, 0x1F3C2));
+        assert_eq!(
+            Some("E_Base"),
+            range_value(ucd().grapheme_cluster_break, 0x1F3C2));
+        assert_eq!(Some("ALetter"), range_value(ucd().word_break, 0x2D7));
+    }
+
+    #[test]
+    fn optional_tables() {
+        assert!(require(ucd().confusables, "confusable data").is_ok());
+        assert!(require(ucd().emoji, "emoji data").is_ok());
+
+        select_unicode_version("9.0");
+        assert!(ucd().identifier_status.is_none());
+        assert!(ucd().standardized_variants.is_none());
+        let err = require(ucd().emoji, "emoji data").unwrap_err();
+        assert_eq!(
+            "emoji data is not available for Unicode 9.0",
+            err.to_string());
+    }
+}
@@ -0,0 +1,213 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//  ucd-generate bidi-class /home/andrew/tmp/ucd-10.0.0/
+//
+// ucd-generate is available on crates.io.
+
+pub const BIDI_CLASS: &'static [(u32, u32, &'static str)] = &[
+  (0, 8, "BN"), (9, 9, "S"), (10, 10, "B"), (11, 11, "S"), (12, 12, "WS"),
+  (13, 13, "B"), (14, 27, "BN"), (28, 30, "B"), (31, 31, "S"), (32, 32, "WS"),
+  (33, 34, "ON"), (35, 37, "ET"), (38, 42, "ON"), (43, 43, "ES"),
+  (44, 44, "CS"), (45, 45, "ES"), (46, 47, "CS"), (48, 57, "EN"),
+  (58, 58, "CS"), (59, 64, "ON"), (91, 96, "ON"), (123, 126, "ON"),
+  (127, 132, "BN"), (133, 133, "B"), (134, 159, "BN"), (160, 160, "CS"),
+  (161, 161, "ON"), (162, 165, "ET"), (166, 169, "ON"), (171, 172, "ON"),
+  (173, 173, "BN"), (174, 175, "ON"), (176, 177, "ET"), (178, 179, "EN"),
+  (180, 180, "ON"), (182, 184, "ON"), (185, 185, "EN"), (187, 191, "ON"),
+  (215, 215, "ON"), (247, 247, "ON"), (697, 698, "ON"), (706, 719, "ON"),
+  (722, 735, "ON"), (741, 749, "ON"), (751, 767, "ON"), (768, 879, "NSM"),
+  (884, 885, "ON"), (894, 894, "ON"), (900, 901, "ON"), (903, 903, "ON"),
+  (1014, 1014, "ON"), (1155, 1161, "NSM"), (1418, 1418, "ON"),
+  (1421, 1422, "ON"), (1423, 1423, "ET"), (1424, 1424, "R"),
+  (1425, 1469, "NSM"), (1470, 1470, "R"), (1471, 1471, "NSM"),
+  (1472, 1472, "R"), (1473, 1474, "NSM"), (1475, 1475, "R"),
+  (1476, 1477, "NSM"), (1478, 1478, "R"), (1479, 1479, "NSM"),
+  (1480, 1535, "R"), (1536, 1541, "AN"), (1542, 1543, "ON"),
+  (1544, 1544, "AL"), (1545, 1546, "ET"), (1547, 1547, "AL"),
+  (1548, 1548, "CS"), (1549, 1549, "AL"), (1550, 1551, "ON"),
+  (1552, 1562, "NSM"), (1563, 1610, "AL"), (1611, 1631, "NSM"),
+  (1632, 1641, "AN"), (1642, 1642, "ET"), (1643, 1644, "AN"),
+  (1645, 1647, "AL"), (1648, 1648, "NSM"), (1649, 1749, "AL"),
+  (1750, 1756, "NSM"), (1757, 1757, "AN"), (1758, 1758, "ON"),
+  (1759, 1764, "NSM"), (1765, 1766, "AL"), (1767, 1768, "NSM"),
+  (1769, 1769, "ON"), (1770, 1773, "NSM"), (1774, 1775, "AL"),
+  (1776, 1785, "EN"), (1786, 1808, "AL"), (1809, 1809, "NSM"),
+  (1810, 1839, "AL"), (1840, 1866, "NSM"), (1867, 1957, "AL"),
+  (1958, 1968, "NSM"), (1969, 1983, "AL"), (1984, 2026, "R"),
+  (2027, 2035, "NSM"), (2036, 2037, "R"), (2038, 2041, "ON"),
+  (2042, 2069, "R"), (2070, 2073, "NSM"), (2074, 2074, "R"),
+  (2075, 2083, "NSM"), (2084, 2084, "R"), (2085, 2087, "NSM"),
+  (2088, 2088, "R"), (2089, 2093, "NSM"), (2094, 2136, "R"),
+  (2137, 2139, "NSM"), (2140, 2143, "R"), (2144, 2154, "AL"),
+  (2155, 2207, "R"), (2208, 2259, "AL"), (2260, 2273, "NSM"),
+  (2274, 2274, "AN"), (2275, 2306, "NSM"), (2362, 2362, "NSM"),
+  (2364, 2364, "NSM"), (2369, 2376, "NSM"), (2381, 2381, "NSM"),
+  (2385, 2391, "NSM"), (2402, 2403, "NSM"), (2433, 2433, "NSM"),
+  (2492, 2492, "NSM"), (2497, 2500, "NSM"), (2509, 2509, "NSM"),
+  (2530, 2531, "NSM"), (2546, 2547, "ET"), (2555, 2555, "ET"),
+  (2561, 2562, "NSM"), (2620, 2620, "NSM"), (2625, 2626, "NSM"),
+  (2631, 2632, "NSM"), (2635, 2637, "NSM"), (2641, 2641, "NSM"),
+  (2672, 2673, "NSM"), (2677, 2677, "NSM"), (2689, 2690, "NSM"),
+  (2748, 2748, "NSM"), (2753, 2757, "NSM"), (2759, 2760, "NSM"),
+  (2765, 2765, "NSM"), (2786, 2787, "NSM"), (2801, 2801, "ET"),
+  (2810, 2815, "NSM"), (2817, 2817, "NSM"), (2876, 2876, "NSM"),
+  (2879, 2879, "NSM"), (2881, 2884, "NSM"), (2893, 2893, "NSM"),
+  (2902, 2902, "NSM"), (2914, 2915, "NSM"), (2946, 2946, "NSM"),
+  (3008, 3008, "NSM"), (3021, 3021, "NSM"), (3059, 3064, "ON"),
+  (3065, 3065, "ET"), (3066, 3066, "ON"), (3072, 3072, "NSM"),
+  (3134, 3136, "NSM"), (3142, 3144, "NSM"), (3146, 3149, "NSM"),
+  (3157, 3158, "NSM"), (3170, 3171, "NSM"), (3192, 3198, "ON"),
+  (3201, 3201, "NSM"), (3260, 3260, "NSM"), (3276, 3277, "NSM"),
+  (3298, 3299, "NSM"), (3328, 3329, "NSM"), (3387, 3388, "NSM"),
+  (3393, 3396, "NSM"), (3405, 3405, "NSM"), (3426, 3427, "NSM"),
+  (3530, 3530, "NSM"), (3538, 3540, "NSM"), (3542, 3542, "NSM"),
+  (3633, 3633, "NSM"), (3636, 3642, "NSM"), (3647, 3647, "ET"),
+  (3655, 3662, "NSM"), (3761, 3761, "NSM"), (3764, 3769, "NSM"),
+  (3771, 3772, "NSM"), (3784, 3789, "NSM"), (3864, 3865, "NSM"),
+  (3893, 3893, "NSM"), (3895, 3895, "NSM"), (3897, 3897, "NSM"),
+  (3898, 3901, "ON"), (3953, 3966, "NSM"), (3968, 3972, "NSM"),
+  (3974, 3975, "NSM"), (3981, 3991, "NSM"), (3993, 4028, "NSM"),
+  (4038, 4038, "NSM"), (4141, 4144, "NSM"), (4146, 4151, "NSM"),
+  (4153, 4154, "NSM"), (4157, 4158, "NSM"), (4184, 4185, "NSM"),
+  (4190, 4192, "NSM"), (4209, 4212, "NSM"), (4226, 4226, "NSM"),
+  (4229, 4230, "NSM"), (4237, 4237, "NSM"), (4253, 4253, "NSM"),
+  (4957, 4959, "NSM"), (5008, 5017, "ON"), (5120, 5120, "ON"),
+  (5760, 5760, "WS"), (5787, 5788, "ON"), (5906, 5908, "NSM"),
+  (5938, 5940, "NSM"), (5970, 5971, "NSM"), (6002, 6003, "NSM"),
+  (6068, 6069, "NSM"), (6071, 6077, "NSM"), (6086, 6086, "NSM"),
+  (6089, 6099, "NSM"), (6107, 6107, "ET"), (6109, 6109, "NSM"),
+  (6128, 6137, "ON"), (6144, 6154, "ON"), (6155, 6157, "NSM"),
+  (6158, 6158, "BN"), (6277, 6278, "NSM"), (6313, 6313, "NSM"),
+  (6432, 6434, "NSM"), (6439, 6440, "NSM"), (6450, 6450, "NSM"),
+  (6457, 6459, "NSM"), (6464, 6464, "ON"), (6468, 6469, "ON"),
+  (6622, 6655, "ON"), (6679, 6680, "NSM"), (6683, 6683, "NSM"),
+  (6742, 6742, "NSM"), (6744, 6750, "NSM"), (6752, 6752, "NSM"),
+  (6754, 6754, "NSM"), (6757, 6764, "NSM"), (6771, 6780, "NSM"),
+  (6783, 6783, "NSM"), (6832, 6846, "NSM"), (6912, 6915, "NSM"),
+  (6964, 6964, "NSM"), (6966, 6970, "NSM"), (6972, 6972, "NSM"),
+  (6978, 6978, "NSM"), (7019, 7027, "NSM"), (7040, 7041, "NSM"),
+  (7074, 7077, "NSM"), (7080, 7081, "NSM"), (7083, 7085, "NSM"),
+  (7142, 7142, "NSM"), (7144, 7145, "NSM"), (7149, 7149, "NSM"),
+  (7151, 7153, "NSM"), (7212, 7219, "NSM"), (7222, 7223, "NSM"),
+  (7376, 7378, "NSM"), (7380, 7392, "NSM"), (7394, 7400, "NSM"),
+  (7405, 7405, "NSM"), (7412, 7412, "NSM"), (7416, 7417, "NSM"),
+  (7616, 7673, "NSM"), (7675, 7679, "NSM"), (8125, 8125, "ON"),
+  (8127, 8129, "ON"), (8141, 8143, "ON"), (8157, 8159, "ON"),
+  (8173, 8175, "ON"), (8189, 8190, "ON"), (8192, 8202, "WS"),
+  (8203, 8205, "BN"), (8207, 8207, "R"), (8208, 8231, "ON"),
+  (8232, 8232, "WS"), (8233, 8233, "B"), (8234, 8234, "LRE"),
+  (8235, 8235, "RLE"), (8236, 8236, "PDF"), (8237, 8237, "LRO"),
+  (8238, 8238, "RLO"), (8239, 8239, "CS"), (8240, 8244, "ET"),
+  (8245, 8259, "ON"), (8260, 8260, "CS"), (8261, 8286, "ON"),
+  (8287, 8287, "WS"), (8288, 8292, "BN"), (8294, 8294, "LRI"),
+  (8295, 8295, "RLI"), (8296, 8296, "FSI"), (8297, 8297, "PDI"),
+  (8298, 8303, "BN"), (8304, 8304, "EN"), (8308, 8313, "EN"),
+  (8314, 8315, "ES"), (8316, 8318, "ON"), (8320, 8329, "EN"),
+  (8330, 8331, "ES"), (8332, 8334, "ON"), (8352, 8399, "ET"),
+  (8400, 8432, "NSM"), (8448, 8449, "ON"), (8451, 8454, "ON"),
+  (8456, 8457, "ON"), (8468, 8468, "ON"), (8470, 8472, "ON"),
+  (8478, 8483, "ON"), (8485, 8485, "ON"), (8487, 8487, "ON"),
+  (8489, 8489, "ON"), (8494, 8494, "ET"), (8506, 8507, "ON"),
+  (8512, 8516, "ON"), (8522, 8525, "ON"), (8528, 8543, "ON"),
+  (8585, 8587, "ON"), (8592, 8721, "ON"), (8722, 8722, "ES"),
+  (8723, 8723, "ET"), (8724, 9013, "ON"), (9083, 9108, "ON"),
+  (9110, 9254, "ON"), (9280, 9290, "ON"), (9312, 9351, "ON"),
+  (9352, 9371, "EN"), (9450, 9899, "ON"), (9901, 10239, "ON"),
+  (10496, 11123, "ON"), (11126, 11157, "ON"), (11160, 11193, "ON"),
+  (11197, 11208, "ON"), (11210, 11218, "ON"), (11244, 11247, "ON"),
+  (11493, 11498, "ON"), (11503, 11505, "NSM"), (11513, 11519, "ON"),
+  (11647, 11647, "NSM"), (11744, 11775, "NSM"), (11776, 11849, "ON"),
+  (11904, 11929, "ON"), (11931, 12019, "ON"), (12032, 12245, "ON"),
+  (12272, 12283, "ON"), (12288, 12288, "WS"), (12289, 12292, "ON"),
+  (12296, 12320, "ON"), (12330, 12333, "NSM"), (12336, 12336, "ON"),
+  (12342, 12343, "ON"), (12349, 12351, "ON"), (12441, 12442, "NSM"),
+  (12443, 12444, "ON"), (12448, 12448, "ON"), (12539, 12539, "ON"),
+  (12736, 12771, "ON"), (12829, 12830, "ON"), (12880, 12895, "ON"),
+  (12924, 12926, "ON"), (12977, 12991, "ON"), (13004, 13007, "ON"),
+  (13175, 13178, "ON"), (13278, 13279, "ON"), (13311, 13311, "ON"),
+  (19904, 19967, "ON"), (42128, 42182, "ON"), (42509, 42511, "ON"),
+  (42607, 42610, "NSM"), (42611, 42611, "ON"), (42612, 42621, "NSM"),
+  (42622, 42623, "ON"), (42654, 42655, "NSM"), (42736, 42737, "NSM"),
+  (42752, 42785, "ON"), (42888, 42888, "ON"), (43010, 43010, "NSM"),
+  (43014, 43014, "NSM"), (43019, 43019, "NSM"), (43045, 43046, "NSM"),
+  (43048, 43051, "ON"), (43064, 43065, "ET"), (43124, 43127, "ON"),
+  (43204, 43205, "NSM"), (43232, 43249, "NSM"), (43302, 43309, "NSM"),
+  (43335, 43345, "NSM"), (43392, 43394, "NSM"), (43443, 43443, "NSM"),
+  (43446, 43449, "NSM"), (43452, 43452, "NSM"), (43493, 43493, "NSM"),
+  (43561, 43566, "NSM"), (43569, 43570, "NSM"), (43573, 43574, "NSM"),
+  (43587, 43587, "NSM"), (43596, 43596, "NSM"), (43644, 43644, "NSM"),
+  (43696, 43696, "NSM"), (43698, 43700, "NSM"), (43703, 43704, "NSM"),
+  (43710, 43711, "NSM"), (43713, 43713, "NSM"), (43756, 43757, "NSM"),
+  (43766, 43766, "NSM"), (44005, 44005, "NSM"), (44008, 44008, "NSM"),
+  (44013, 44013, "NSM"), (64285, 64285, "R"), (64286, 64286, "NSM"),
+  (64287, 64296, "R"), (64297, 64297, "ES"), (64298, 64335, "R"),
+  (64336, 64829, "AL"), (64830, 64831, "ON"), (64832, 64975, "AL"),
+  (65008, 65020, "AL"), (65021, 65021, "ON"), (65022, 65023, "AL"),
+  (65024, 65039, "NSM"), (65040, 65049, "ON"), (65056, 65071, "NSM"),
+  (65072, 65103, "ON"), (65104, 65104, "CS"), (65105, 65105, "ON"),
+  (65106, 65106, "CS"), (65108, 65108, "ON"), (65109, 65109, "CS"),
+  (65110, 65118, "ON"), (65119, 65119, "ET"), (65120, 65121, "ON"),
+  (65122, 65123, "ES"), (65124, 65126, "ON"), (65128, 65128, "ON"),
+  (65129, 65130, "ET"), (65131, 65131, "ON"), (65136, 65278, "AL"),
+  (65279, 65279, "BN"), (65281, 65282, "ON"), (65283, 65285, "ET"),
+  (65286, 65290, "ON"), (65291, 65291, "ES"), (65292, 65292, "CS"),
+  (65293, 65293, "ES"), (65294, 65295, "CS"), (65296, 65305, "EN"),
+  (65306, 65306, "CS"), (65307, 65312, "ON"), (65339, 65344, "ON"),
+  (65371, 65381, "ON"), (65504, 65505, "ET"), (65506, 65508, "ON"),
+  (65509, 65510, "ET"), (65512, 65518, "ON"), (65529, 65533, "ON"),
+  (65793, 65793, "ON"), (65856, 65932, "ON"), (65936, 65947, "ON"),
+  (65952, 65952, "ON"), (66045, 66045, "NSM"), (66272, 66272, "NSM"),
+  (66273, 66299, "EN"), (66422, 66426, "NSM"), (67584, 67870, "R"),
+  (67871, 67871, "ON"), (67872, 68096, "R"), (68097, 68099, "NSM"),
+  (68100, 68100, "R"), (68101, 68102, "NSM"), (68103, 68107, "R"),
+  (68108, 68111, "NSM"), (68112, 68151, "R"), (68152, 68154, "NSM"),
+  (68155, 68158, "R"), (68159, 68159, "NSM"), (68160, 68324, "R"),
+  (68325, 68326, "NSM"), (68327, 68408, "R"), (68409, 68415, "ON"),
+  (68416, 69215, "R"), (69216, 69246, "AN"), (69247, 69631, "R"),
+  (69633, 69633, "NSM"), (69688, 69702, "NSM"), (69714, 69733, "ON"),
+  (69759, 69761, "NSM"), (69811, 69814, "NSM"), (69817, 69818, "NSM"),
+  (69888, 69890, "NSM"), (69927, 69931, "NSM"), (69933, 69940, "NSM"),
+  (70003, 70003, "NSM"), (70016, 70017, "NSM"), (70070, 70078, "NSM"),
+  (70090, 70092, "NSM"), (70191, 70193, "NSM"), (70196, 70196, "NSM"),
+  (70198, 70199, "NSM"), (70206, 70206, "NSM"), (70367, 70367, "NSM"),
+  (70371, 70378, "NSM"), (70400, 70401, "NSM"), (70460, 70460, "NSM"),
+  (70464, 70464, "NSM"), (70502, 70508, "NSM"), (70512, 70516, "NSM"),
+  (70712, 70719, "NSM"), (70722, 70724, "NSM"), (70726, 70726, "NSM"),
+  (70835, 70840, "NSM"), (70842, 70842, "NSM"), (70847, 70848, "NSM"),
+  (70850, 70851, "NSM"), (71090, 71093, "NSM"), (71100, 71101, "NSM"),
+  (71103, 71104, "NSM"), (71132, 71133, "NSM"), (71219, 71226, "NSM"),
+  (71229, 71229, "NSM"), (71231, 71232, "NSM"), (71264, 71276, "ON"),
+  (71339, 71339, "NSM"), (71341, 71341, "NSM"), (71344, 71349, "NSM"),
+  (71351, 71351, "NSM"), (71453, 71455, "NSM"), (71458, 71461, "NSM"),
+  (71463, 71467, "NSM"), (72193, 72198, "NSM"), (72201, 72202, "NSM"),
+  (72243, 72248, "NSM"), (72251, 72254, "NSM"), (72263, 72263, "NSM"),
+  (72273, 72278, "NSM"), (72281, 72283, "NSM"), (72330, 72342, "NSM"),
+  (72344, 72345, "NSM"), (72752, 72758, "NSM"), (72760, 72765, "NSM"),
+  (72850, 72871, "NSM"), (72874, 72880, "NSM"), (72882, 72883, "NSM"),
+  (72885, 72886, "NSM"), (73009, 73014, "NSM"), (73018, 73018, "NSM"),
+  (73020, 73021, "NSM"), (73023, 73029, "NSM"), (73031, 73031, "NSM"),
+  (92912, 92916, "NSM"), (92976, 92982, "NSM"), (94095, 94098, "NSM"),
+  (113821, 113822, "NSM"), (113824, 113827, "BN"), (119143, 119145, "NSM"),
+  (119155, 119162, "BN"), (119163, 119170, "NSM"), (119173, 119179, "NSM"),
+  (119210, 119213, "NSM"), (119296, 119361, "ON"), (119362, 119364, "NSM"),
+  (119365, 119365, "ON"), (119552, 119638, "ON"), (120539, 120539, "ON"),
+  (120597, 120597, "ON"), (120655, 120655, "ON"), (120713, 120713, "ON"),
+  (120771, 120771, "ON"), (120782, 120831, "EN"), (121344, 121398, "NSM"),
+  (121403, 121452, "NSM"), (121461, 121461, "NSM"), (121476, 121476, "NSM"),
+  (121499, 121503, "NSM"), (121505, 121519, "NSM"), (122880, 122886, "NSM"),
+  (122888, 122904, "NSM"), (122907, 122913, "NSM"), (122915, 122916, "NSM"),
+  (122918, 122922, "NSM"), (124928, 125135, "R"), (125136, 125142, "NSM"),
+  (125143, 125251, "R"), (125252, 125258, "NSM"), (125259, 126463, "R"),
+  (126464, 126703, "AL"), (126704, 126705, "ON"), (126706, 126719, "AL"),
+  (126720, 126975, "R"), (126976, 127019, "ON"), (127024, 127123, "ON"),
+  (127136, 127150, "ON"), (127153, 127167, "ON"), (127169, 127183, "ON"),
+  (127185, 127221, "ON"), (127232, 127242, "EN"), (127243, 127244, "ON"),
+  (127338, 127339, "ON"), (127584, 127589, "ON"), (127744, 128724, "ON"),
+  (128736, 128748, "ON"), (128752, 128760, "ON"), (128768, 128883, "ON"),
+  (128896, 128980, "ON"), (129024, 129035, "ON"), (129040, 129095, "ON"),
+  (129104, 129113, "ON"), (129120, 129159, "ON"), (129168, 129197, "ON"),
+  (129280, 129291, "ON"), (129296, 129342, "ON"), (129344, 129356, "ON"),
+  (129360, 129387, "ON"), (129408, 129431, "ON"), (129472, 129472, "ON"),
+  (129488, 129510, "ON"), (917505, 917505, "BN"), (917536, 917631, "BN"),
+  (917760, 917999, "NSM"),
+];
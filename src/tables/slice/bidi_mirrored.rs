@@ -0,0 +1,33 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//  ucd-generate property-bool /home/andrew/tmp/ucd-10.0.0/ --include Bidi_Mirrored
+//
+// ucd-generate is available on crates.io.
+
+pub const BIDI_MIRRORED: &'static [(u32, u32)] = &[
+  (40, 41), (60, 60), (62, 62), (91, 91), (93, 93), (123, 123), (125, 125),
+  (171, 171), (187, 187), (3898, 3901), (5787, 5788), (8249, 8250),
+  (8261, 8262), (8317, 8318), (8333, 8334), (8512, 8512), (8705, 8708),
+  (8712, 8717), (8721, 8721), (8725, 8726), (8730, 8733), (8735, 8738),
+  (8740, 8740), (8742, 8742), (8747, 8755), (8761, 8761), (8763, 8780),
+  (8786, 8789), (8799, 8800), (8802, 8802), (8804, 8811), (8814, 8844),
+  (8847, 8850), (8856, 8856), (8866, 8867), (8870, 8888), (8894, 8895),
+  (8905, 8909), (8912, 8913), (8918, 8941), (8944, 8959), (8968, 8971),
+  (8992, 8993), (9001, 9002), (10088, 10101), (10176, 10176), (10179, 10182),
+  (10184, 10185), (10187, 10189), (10195, 10198), (10204, 10206),
+  (10210, 10223), (10627, 10648), (10651, 10671), (10680, 10680),
+  (10688, 10693), (10697, 10697), (10702, 10706), (10708, 10709),
+  (10712, 10716), (10721, 10721), (10723, 10725), (10728, 10729),
+  (10740, 10745), (10748, 10749), (10762, 10780), (10782, 10785),
+  (10788, 10788), (10790, 10790), (10793, 10793), (10795, 10798),
+  (10804, 10805), (10812, 10814), (10839, 10840), (10852, 10853),
+  (10858, 10861), (10863, 10864), (10867, 10868), (10873, 10915),
+  (10918, 10925), (10927, 10966), (10972, 10972), (10974, 10974),
+  (10978, 10982), (10988, 10990), (10995, 10995), (10999, 11003),
+  (11005, 11005), (11778, 11781), (11785, 11786), (11788, 11789),
+  (11804, 11805), (11808, 11817), (12296, 12305), (12308, 12315),
+  (65113, 65118), (65124, 65125), (65288, 65289), (65308, 65308),
+  (65310, 65310), (65339, 65339), (65341, 65341), (65371, 65371),
+  (65373, 65373), (65375, 65376), (65378, 65379), (120539, 120539),
+  (120597, 120597), (120655, 120655), (120713, 120713), (120771, 120771),
+];
@@ -0,0 +1,174 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//  ucd-generate block /home/andrew/tmp/ucd-10.0.0/
+//
+// ucd-generate is available on crates.io.
+
+pub const BLOCK: &'static [(u32, u32, &'static str)] = &[
+  (0, 127, "Basic_Latin"), (128, 255, "Latin_1_Supplement"),
+  (256, 383, "Latin_Extended_A"), (384, 591, "Latin_Extended_B"),
+  (592, 687, "IPA_Extensions"), (688, 767, "Spacing_Modifier_Letters"),
+  (768, 879, "Combining_Diacritical_Marks"), (880, 1023, "Greek_And_Coptic"),
+  (1024, 1279, "Cyrillic"), (1280, 1327, "Cyrillic_Supplement"),
+  (1328, 1423, "Armenian"), (1424, 1535, "Hebrew"), (1536, 1791, "Arabic"),
+  (1792, 1871, "Syriac"), (1872, 1919, "Arabic_Supplement"),
+  (1920, 1983, "Thaana"), (1984, 2047, "NKo"), (2048, 2111, "Samaritan"),
+  (2112, 2143, "Mandaic"), (2144, 2159, "Syriac_Supplement"),
+  (2208, 2303, "Arabic_Extended_A"), (2304, 2431, "Devanagari"),
+  (2432, 2559, "Bengali"), (2560, 2687, "Gurmukhi"), (2688, 2815, "Gujarati"),
+  (2816, 2943, "Oriya"), (2944, 3071, "Tamil"), (3072, 3199, "Telugu"),
+  (3200, 3327, "Kannada"), (3328, 3455, "Malayalam"), (3456, 3583, "Sinhala"),
+  (3584, 3711, "Thai"), (3712, 3839, "Lao"), (3840, 4095, "Tibetan"),
+  (4096, 4255, "Myanmar"), (4256, 4351, "Georgian"),
+  (4352, 4607, "Hangul_Jamo"), (4608, 4991, "Ethiopic"),
+  (4992, 5023, "Ethiopic_Supplement"), (5024, 5119, "Cherokee"),
+  (5120, 5759, "Unified_Canadian_Aboriginal_Syllabics"), (5760, 5791, "Ogham"),
+  (5792, 5887, "Runic"), (5888, 5919, "Tagalog"), (5920, 5951, "Hanunoo"),
+  (5952, 5983, "Buhid"), (5984, 6015, "Tagbanwa"), (6016, 6143, "Khmer"),
+  (6144, 6319, "Mongolian"),
+  (6320, 6399, "Unified_Canadian_Aboriginal_Syllabics_Extended"),
+  (6400, 6479, "Limbu"), (6480, 6527, "Tai_Le"), (6528, 6623, "New_Tai_Lue"),
+  (6624, 6655, "Khmer_Symbols"), (6656, 6687, "Buginese"),
+  (6688, 6831, "Tai_Tham"),
+  (6832, 6911, "Combining_Diacritical_Marks_Extended"),
+  (6912, 7039, "Balinese"), (7040, 7103, "Sundanese"), (7104, 7167, "Batak"),
+  (7168, 7247, "Lepcha"), (7248, 7295, "Ol_Chiki"),
+  (7296, 7311, "Cyrillic_Extended_C"), (7360, 7375, "Sundanese_Supplement"),
+  (7376, 7423, "Vedic_Extensions"), (7424, 7551, "Phonetic_Extensions"),
+  (7552, 7615, "Phonetic_Extensions_Supplement"),
+  (7616, 7679, "Combining_Diacritical_Marks_Supplement"),
+  (7680, 7935, "Latin_Extended_Additional"), (7936, 8191, "Greek_Extended"),
+  (8192, 8303, "General_Punctuation"),
+  (8304, 8351, "Superscripts_And_Subscripts"),
+  (8352, 8399, "Currency_Symbols"),
+  (8400, 8447, "Combining_Diacritical_Marks_For_Symbols"),
+  (8448, 8527, "Letterlike_Symbols"), (8528, 8591, "Number_Forms"),
+  (8592, 8703, "Arrows"), (8704, 8959, "Mathematical_Operators"),
+  (8960, 9215, "Miscellaneous_Technical"), (9216, 9279, "Control_Pictures"),
+  (9280, 9311, "Optical_Character_Recognition"),
+  (9312, 9471, "Enclosed_Alphanumerics"), (9472, 9599, "Box_Drawing"),
+  (9600, 9631, "Block_Elements"), (9632, 9727, "Geometric_Shapes"),
+  (9728, 9983, "Miscellaneous_Symbols"), (9984, 10175, "Dingbats"),
+  (10176, 10223, "Miscellaneous_Mathematical_Symbols_A"),
+  (10224, 10239, "Supplemental_Arrows_A"), (10240, 10495, "Braille_Patterns"),
+  (10496, 10623, "Supplemental_Arrows_B"),
+  (10624, 10751, "Miscellaneous_Mathematical_Symbols_B"),
+  (10752, 11007, "Supplemental_Mathematical_Operators"),
+  (11008, 11263, "Miscellaneous_Symbols_And_Arrows"),
+  (11264, 11359, "Glagolitic"), (11360, 11391, "Latin_Extended_C"),
+  (11392, 11519, "Coptic"), (11520, 11567, "Georgian_Supplement"),
+  (11568, 11647, "Tifinagh"), (11648, 11743, "Ethiopic_Extended"),
+  (11744, 11775, "Cyrillic_Extended_A"),
+  (11776, 11903, "Supplemental_Punctuation"),
+  (11904, 12031, "CJK_Radicals_Supplement"), (12032, 12255, "Kangxi_Radicals"),
+  (12272, 12287, "Ideographic_Description_Characters"),
+  (12288, 12351, "CJK_Symbols_And_Punctuation"), (12352, 12447, "Hiragana"),
+  (12448, 12543, "Katakana"), (12544, 12591, "Bopomofo"),
+  (12592, 12687, "Hangul_Compatibility_Jamo"), (12688, 12703, "Kanbun"),
+  (12704, 12735, "Bopomofo_Extended"), (12736, 12783, "CJK_Strokes"),
+  (12784, 12799, "Katakana_Phonetic_Extensions"),
+  (12800, 13055, "Enclosed_CJK_Letters_And_Months"),
+  (13056, 13311, "CJK_Compatibility"),
+  (13312, 19903, "CJK_Unified_Ideographs_Extension_A"),
+  (19904, 19967, "Yijing_Hexagram_Symbols"),
+  (19968, 40959, "CJK_Unified_Ideographs"), (40960, 42127, "Yi_Syllables"),
+  (42128, 42191, "Yi_Radicals"), (42192, 42239, "Lisu"), (42240, 42559, "Vai"),
+  (42560, 42655, "Cyrillic_Extended_B"), (42656, 42751, "Bamum"),
+  (42752, 42783, "Modifier_Tone_Letters"), (42784, 43007, "Latin_Extended_D"),
+  (43008, 43055, "Syloti_Nagri"), (43056, 43071, "Common_Indic_Number_Forms"),
+  (43072, 43135, "Phags_Pa"), (43136, 43231, "Saurashtra"),
+  (43232, 43263, "Devanagari_Extended"), (43264, 43311, "Kayah_Li"),
+  (43312, 43359, "Rejang"), (43360, 43391, "Hangul_Jamo_Extended_A"),
+  (43392, 43487, "Javanese"), (43488, 43519, "Myanmar_Extended_B"),
+  (43520, 43615, "Cham"), (43616, 43647, "Myanmar_Extended_A"),
+  (43648, 43743, "Tai_Viet"), (43744, 43775, "Meetei_Mayek_Extensions"),
+  (43776, 43823, "Ethiopic_Extended_A"), (43824, 43887, "Latin_Extended_E"),
+  (43888, 43967, "Cherokee_Supplement"), (43968, 44031, "Meetei_Mayek"),
+  (44032, 55215, "Hangul_Syllables"), (55216, 55295, "Hangul_Jamo_Extended_B"),
+  (55296, 56191, "High_Surrogates"),
+  (56192, 56319, "High_Private_Use_Surrogates"),
+  (56320, 57343, "Low_Surrogates"), (57344, 63743, "Private_Use_Area"),
+  (63744, 64255, "CJK_Compatibility_Ideographs"),
+  (64256, 64335, "Alphabetic_Presentation_Forms"),
+  (64336, 65023, "Arabic_Presentation_Forms_A"),
+  (65024, 65039, "Variation_Selectors"), (65040, 65055, "Vertical_Forms"),
+  (65056, 65071, "Combining_Half_Marks"),
+  (65072, 65103, "CJK_Compatibility_Forms"),
+  (65104, 65135, "Small_Form_Variants"),
+  (65136, 65279, "Arabic_Presentation_Forms_B"),
+  (65280, 65519, "Halfwidth_And_Fullwidth_Forms"), (65520, 65535, "Specials"),
+  (65536, 65663, "Linear_B_Syllabary"), (65664, 65791, "Linear_B_Ideograms"),
+  (65792, 65855, "Aegean_Numbers"), (65856, 65935, "Ancient_Greek_Numbers"),
+  (65936, 65999, "Ancient_Symbols"), (66000, 66047, "Phaistos_Disc"),
+  (66176, 66207, "Lycian"), (66208, 66271, "Carian"),
+  (66272, 66303, "Coptic_Epact_Numbers"), (66304, 66351, "Old_Italic"),
+  (66352, 66383, "Gothic"), (66384, 66431, "Old_Permic"),
+  (66432, 66463, "Ugaritic"), (66464, 66527, "Old_Persian"),
+  (66560, 66639, "Deseret"), (66640, 66687, "Shavian"),
+  (66688, 66735, "Osmanya"), (66736, 66815, "Osage"),
+  (66816, 66863, "Elbasan"), (66864, 66927, "Caucasian_Albanian"),
+  (67072, 67455, "Linear_A"), (67584, 67647, "Cypriot_Syllabary"),
+  (67648, 67679, "Imperial_Aramaic"), (67680, 67711, "Palmyrene"),
+  (67712, 67759, "Nabataean"), (67808, 67839, "Hatran"),
+  (67840, 67871, "Phoenician"), (67872, 67903, "Lydian"),
+  (67968, 67999, "Meroitic_Hieroglyphs"), (68000, 68095, "Meroitic_Cursive"),
+  (68096, 68191, "Kharoshthi"), (68192, 68223, "Old_South_Arabian"),
+  (68224, 68255, "Old_North_Arabian"), (68288, 68351, "Manichaean"),
+  (68352, 68415, "Avestan"), (68416, 68447, "Inscriptional_Parthian"),
+  (68448, 68479, "Inscriptional_Pahlavi"), (68480, 68527, "Psalter_Pahlavi"),
+  (68608, 68687, "Old_Turkic"), (68736, 68863, "Old_Hungarian"),
+  (69216, 69247, "Rumi_Numeral_Symbols"), (69632, 69759, "Brahmi"),
+  (69760, 69839, "Kaithi"), (69840, 69887, "Sora_Sompeng"),
+  (69888, 69967, "Chakma"), (69968, 70015, "Mahajani"),
+  (70016, 70111, "Sharada"), (70112, 70143, "Sinhala_Archaic_Numbers"),
+  (70144, 70223, "Khojki"), (70272, 70319, "Multani"),
+  (70320, 70399, "Khudawadi"), (70400, 70527, "Grantha"),
+  (70656, 70783, "Newa"), (70784, 70879, "Tirhuta"), (71040, 71167, "Siddham"),
+  (71168, 71263, "Modi"), (71264, 71295, "Mongolian_Supplement"),
+  (71296, 71375, "Takri"), (71424, 71503, "Ahom"),
+  (71840, 71935, "Warang_Citi"), (72192, 72271, "Zanabazar_Square"),
+  (72272, 72367, "Soyombo"), (72384, 72447, "Pau_Cin_Hau"),
+  (72704, 72815, "Bhaiksuki"), (72816, 72895, "Marchen"),
+  (72960, 73055, "Masaram_Gondi"), (73728, 74751, "Cuneiform"),
+  (74752, 74879, "Cuneiform_Numbers_And_Punctuation"),
+  (74880, 75087, "Early_Dynastic_Cuneiform"),
+  (77824, 78895, "Egyptian_Hieroglyphs"),
+  (82944, 83583, "Anatolian_Hieroglyphs"), (92160, 92735, "Bamum_Supplement"),
+  (92736, 92783, "Mro"), (92880, 92927, "Bassa_Vah"),
+  (92928, 93071, "Pahawh_Hmong"), (93952, 94111, "Miao"),
+  (94176, 94207, "Ideographic_Symbols_And_Punctuation"),
+  (94208, 100351, "Tangut"), (100352, 101119, "Tangut_Components"),
+  (110592, 110847, "Kana_Supplement"), (110848, 110895, "Kana_Extended_A"),
+  (110960, 111359, "Nushu"), (113664, 113823, "Duployan"),
+  (113824, 113839, "Shorthand_Format_Controls"),
+  (118784, 119039, "Byzantine_Musical_Symbols"),
+  (119040, 119295, "Musical_Symbols"),
+  (119296, 119375, "Ancient_Greek_Musical_Notation"),
+  (119552, 119647, "Tai_Xuan_Jing_Symbols"),
+  (119648, 119679, "Counting_Rod_Numerals"),
+  (119808, 120831, "Mathematical_Alphanumeric_Symbols"),
+  (120832, 121519, "Sutton_SignWriting"),
+  (122880, 122927, "Glagolitic_Supplement"), (124928, 125151, "Mende_Kikakui"),
+  (125184, 125279, "Adlam"),
+  (126464, 126719, "Arabic_Mathematical_Alphabetic_Symbols"),
+  (126976, 127023, "Mahjong_Tiles"), (127024, 127135, "Domino_Tiles"),
+  (127136, 127231, "Playing_Cards"),
+  (127232, 127487, "Enclosed_Alphanumeric_Supplement"),
+  (127488, 127743, "Enclosed_Ideographic_Supplement"),
+  (127744, 128511, "Miscellaneous_Symbols_And_Pictographs"),
+  (128512, 128591, "Emoticons"), (128592, 128639, "Ornamental_Dingbats"),
+  (128640, 128767, "Transport_And_Map_Symbols"),
+  (128768, 128895, "Alchemical_Symbols"),
+  (128896, 129023, "Geometric_Shapes_Extended"),
+  (129024, 129279, "Supplemental_Arrows_C"),
+  (129280, 129535, "Supplemental_Symbols_And_Pictographs"),
+  (131072, 173791, "CJK_Unified_Ideographs_Extension_B"),
+  (173824, 177983, "CJK_Unified_Ideographs_Extension_C"),
+  (177984, 178207, "CJK_Unified_Ideographs_Extension_D"),
+  (178208, 183983, "CJK_Unified_Ideographs_Extension_E"),
+  (183984, 191471, "CJK_Unified_Ideographs_Extension_F"),
+  (194560, 195103, "CJK_Compatibility_Ideographs_Supplement"),
+  (917504, 917631, "Tags"), (917760, 917999, "Variation_Selectors_Supplement"),
+  (983040, 1048575, "Supplementary_Private_Use_Area_A"),
+  (1048576, 1114111, "Supplementary_Private_Use_Area_B"),
+];
@@ -0,0 +1,1846 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//  ucd-generate confusables /home/andrew/tmp/security-10.0.0/
+//
+// ucd-generate is available on crates.io.
+
+pub const CONFUSABLES: &'static [(u32, &'static [u32])] = &[
+  (34, &[39, 39]), (37, &[186, 47, 8320]), (48, &[79]), (49, &[108]),
+  (73, &[108]), (96, &[39]), (109, &[114, 110]), (124, &[108]), (160, &[32]),
+  (162, &[99, 824]), (165, &[89, 821]), (175, &[713]), (180, &[39]),
+  (181, &[956]), (184, &[44]), (198, &[65, 69]), (199, &[67, 806]),
+  (208, &[68, 821]), (215, &[120]), (216, &[79, 824]), (230, &[97, 101]),
+  (231, &[99, 806]), (240, &[8706, 821]), (246, &[1577]), (248, &[111, 824]),
+  (272, &[68, 821]), (273, &[100, 821]), (282, &[276]), (283, &[277]),
+  (294, &[72, 821]), (295, &[104, 821]), (305, &[105]), (306, &[108, 74]),
+  (307, &[105, 106]), (319, &[108, 183]), (320, &[108, 183]),
+  (321, &[76, 824]), (322, &[108, 824]), (326, &[626]), (329, &[39, 110]),
+  (336, &[214]), (338, &[79, 69]), (339, &[111, 101]), (355, &[427]),
+  (358, &[84, 821]), (359, &[116, 821]), (383, &[102]), (384, &[98, 821]),
+  (385, &[39, 66]), (386, &[98, 772]), (387, &[98, 772]), (388, &[98]),
+  (391, &[67, 39]), (393, &[68, 821]), (394, &[39, 68]), (396, &[100, 772]),
+  (397, &[103]), (401, &[70, 806]), (402, &[102, 806]), (403, &[71, 39]),
+  (406, &[108]), (407, &[108, 821]), (408, &[75, 39]), (409, &[107, 788]),
+  (410, &[108, 821]), (411, &[955, 824]), (413, &[78, 806]),
+  (414, &[110, 809]), (415, &[79, 821]), (416, &[79, 39]), (417, &[111, 39]),
+  (420, &[39, 80]), (421, &[112, 788]), (422, &[82]), (423, &[50]),
+  (428, &[39, 84]), (429, &[116, 788]), (430, &[84, 808]), (435, &[39, 89]),
+  (436, &[121, 788]), (437, &[90, 821]), (438, &[122, 821]), (439, &[51]),
+  (443, &[50, 821]), (444, &[53]), (445, &[115]), (447, &[254]), (448, &[108]),
+  (449, &[108, 108]), (451, &[33]), (452, &[68, 381]), (453, &[68, 382]),
+  (454, &[100, 382]), (455, &[76, 74]), (456, &[76, 106]), (457, &[108, 106]),
+  (458, &[78, 74]), (459, &[78, 106]), (460, &[110, 106]), (461, &[258]),
+  (462, &[259]), (463, &[300]), (464, &[301]), (465, &[334]), (466, &[335]),
+  (467, &[364]), (468, &[365]), (484, &[71, 821]), (485, &[103, 821]),
+  (486, &[286]), (487, &[287]), (497, &[68, 90]), (498, &[68, 122]),
+  (499, &[100, 122]), (501, &[291]), (510, &[79, 824, 769]), (538, &[354]),
+  (539, &[427]), (540, &[51]), (546, &[56]), (547, &[56]), (548, &[90, 806]),
+  (549, &[122, 806]), (550, &[197]), (551, &[229]), (572, &[99, 824]),
+  (574, &[84, 824]), (577, &[63]), (580, &[85, 821]), (582, &[69, 824]),
+  (583, &[101, 824]), (584, &[74, 821]), (585, &[106, 821]),
+  (589, &[114, 821]), (590, &[89, 821]), (591, &[121, 821]), (593, &[97]),
+  (595, &[98, 788]), (598, &[100, 808]), (599, &[100, 788]), (601, &[477]),
+  (602, &[477, 734]), (603, &[42899]), (608, &[103, 788]), (609, &[103]),
+  (611, &[121]), (614, &[104, 788]), (616, &[105, 821]), (617, &[105]),
+  (618, &[105]), (619, &[108, 820]), (621, &[108, 808]), (622, &[108, 541]),
+  (623, &[119]), (625, &[114, 110, 806]), (627, &[110, 808]),
+  (629, &[111, 821]), (630, &[111, 7431]), (636, &[114, 809]),
+  (637, &[114, 808]), (642, &[115, 808]), (651, &[117]), (655, &[121]),
+  (656, &[122, 808]), (658, &[541]), (660, &[63]), (672, &[113, 788]),
+  (675, &[100, 122]), (676, &[100, 541]), (677, &[100, 657]),
+  (678, &[116, 115]), (679, &[116, 643]), (680, &[116, 597]),
+  (681, &[102, 331]), (682, &[108, 115]), (683, &[108, 122]), (691, &[6388]),
+  (697, &[39]), (698, &[39, 39]), (699, &[39]), (700, &[39]), (701, &[39]),
+  (702, &[39]), (703, &[1369]), (706, &[60]), (707, &[62]), (708, &[94]),
+  (710, &[94]), (712, &[39]), (714, &[39]), (715, &[39]), (720, &[58]),
+  (723, &[1369]), (727, &[45]), (728, &[711]), (729, &[2417]), (730, &[176]),
+  (731, &[105]), (732, &[126]), (733, &[39, 39]), (737, &[6387]),
+  (738, &[6389]), (740, &[705]), (750, &[39, 39]), (756, &[39]),
+  (758, &[39, 39]), (760, &[58]), (763, &[746]), (773, &[772]), (780, &[774]),
+  (781, &[1648]), (784, &[774, 775]), (785, &[770]), (789, &[787]),
+  (791, &[1616]), (800, &[817]), (801, &[806]), (802, &[808]), (807, &[806]),
+  (822, &[821]), (823, &[824]), (825, &[806]), (832, &[768]), (833, &[769]),
+  (834, &[771]), (835, &[787]), (837, &[808]), (839, &[819]), (855, &[848]),
+  (856, &[775]), (870, &[778]), (878, &[774]), (880, &[11381]), (884, &[39]),
+  (885, &[719]), (886, &[1048]), (887, &[7438]), (890, &[105]), (891, &[596]),
+  (893, &[42815]), (894, &[59]), (895, &[74]), (900, &[39]), (903, &[183]),
+  (913, &[65]), (914, &[66]), (917, &[69]), (918, &[90]), (919, &[72]),
+  (920, &[79, 821]), (921, &[108]), (922, &[75]), (923, &[581]), (924, &[77]),
+  (925, &[78]), (927, &[79]), (929, &[80]), (931, &[425]), (932, &[84]),
+  (933, &[89]), (935, &[88]), (945, &[97]), (946, &[223]), (947, &[121]),
+  (948, &[7839]), (949, &[42899]), (951, &[110, 809]), (952, &[79, 821]),
+  (953, &[105]), (954, &[312]), (957, &[118]), (959, &[111]), (961, &[112]),
+  (963, &[111]), (964, &[7451]), (965, &[117]), (966, &[632]), (976, &[223]),
+  (977, &[79, 821]), (978, &[89]), (981, &[632]), (982, &[960]), (987, &[962]),
+  (988, &[70]), (1000, &[50]), (1001, &[424]), (1008, &[312]), (1009, &[112]),
+  (1010, &[99]), (1011, &[106]), (1012, &[79, 821]), (1013, &[42899]),
+  (1015, &[222]), (1016, &[254]), (1017, &[67]), (1018, &[77]), (1021, &[390]),
+  (1023, &[42814]), (1028, &[42898]), (1029, &[83]), (1030, &[108]),
+  (1032, &[74]), (1040, &[65]), (1041, &[98, 772]), (1042, &[66]),
+  (1043, &[915]), (1045, &[69]), (1047, &[51]), (1049, &[1037]), (1050, &[75]),
+  (1051, &[581]), (1052, &[77]), (1053, &[72]), (1054, &[79]), (1055, &[928]),
+  (1056, &[80]), (1057, &[67]), (1058, &[84]), (1059, &[89]), (1060, &[934]),
+  (1061, &[88]), (1067, &[98, 108]), (1068, &[98]), (1070, &[108, 79]),
+  (1072, &[97]), (1073, &[54]), (1074, &[665]), (1075, &[114]), (1077, &[101]),
+  (1079, &[604]), (1080, &[7438]), (1082, &[312]), (1084, &[653]),
+  (1085, &[668]), (1086, &[111]), (1087, &[960]), (1088, &[112]),
+  (1089, &[99]), (1090, &[7451]), (1091, &[121]), (1092, &[632]),
+  (1093, &[120]), (1098, &[713, 98]), (1099, &[389, 105]), (1100, &[389]),
+  (1103, &[7449]), (1108, &[42899]), (1109, &[115]), (1110, &[105]),
+  (1112, &[106]), (1115, &[104, 821]), (1117, &[1081]), (1121, &[119]),
+  (1122, &[98, 821]), (1123, &[98, 821]), (1136, &[936]), (1137, &[968]),
+  (1138, &[79, 821]), (1139, &[111, 821]), (1140, &[86]), (1141, &[118]),
+  (1148, &[1120, 1158, 1159]), (1149, &[119, 1158, 1159]),
+  (1162, &[1037, 806]), (1163, &[1081, 806]), (1164, &[98, 821]),
+  (1165, &[98, 821]), (1168, &[915, 39]), (1169, &[114, 39]),
+  (1170, &[915, 821]), (1171, &[114, 821]), (1174, &[1046, 809]),
+  (1175, &[1078, 809]), (1176, &[51, 806]), (1177, &[604, 806]),
+  (1178, &[75, 809]), (1179, &[312, 809]), (1182, &[75, 821]),
+  (1183, &[312, 821]), (1186, &[72, 809]), (1187, &[668, 809]),
+  (1194, &[67, 806]), (1195, &[99, 806]), (1196, &[84, 809]),
+  (1197, &[7451, 809]), (1198, &[89]), (1199, &[121]), (1200, &[89, 821]),
+  (1201, &[121, 821]), (1202, &[88, 809]), (1211, &[104]), (1213, &[101]),
+  (1214, &[1212, 808]), (1215, &[101, 808]), (1216, &[108]),
+  (1221, &[581, 806]), (1222, &[1083, 806]), (1223, &[72, 806]),
+  (1224, &[668, 806]), (1225, &[72, 806]), (1226, &[668, 806]),
+  (1227, &[1206]), (1228, &[1207]), (1229, &[77, 806]), (1230, &[653, 806]),
+  (1231, &[105]), (1236, &[65, 69]), (1237, &[97, 101]), (1240, &[399]),
+  (1241, &[477]), (1248, &[51]), (1249, &[541]), (1256, &[79, 821]),
+  (1257, &[111, 821]), (1281, &[100]), (1290, &[502]), (1292, &[71]),
+  (1293, &[610]), (1296, &[400]), (1297, &[42899]), (1307, &[113]),
+  (1308, &[87]), (1309, &[119]), (1339, &[4782]), (1348, &[4614]),
+  (1354, &[4899]), (1356, &[4705]), (1357, &[85]), (1359, &[83]),
+  (1363, &[934]), (1365, &[79]), (1370, &[39]), (1373, &[39]), (1377, &[119]),
+  (1379, &[113]), (1382, &[113]), (1390, &[7839]), (1392, &[104]),
+  (1397, &[567]), (1400, &[110]), (1402, &[624]), (1404, &[110]),
+  (1405, &[117]), (1409, &[103]), (1412, &[102]), (1413, &[111]),
+  (1415, &[1381, 1410]), (1417, &[58]), (1436, &[769]), (1437, &[769]),
+  (1444, &[1434]), (1448, &[1433]), (1453, &[1430]), (1454, &[1432]),
+  (1455, &[778]), (1460, &[803]), (1465, &[775]), (1466, &[775]),
+  (1472, &[108]), (1473, &[775]), (1474, &[775]), (1475, &[58]),
+  (1476, &[775]), (1477, &[803]), (1493, &[108]), (1496, &[118]),
+  (1497, &[39]), (1503, &[108]), (1505, &[111]), (1520, &[108, 108]),
+  (1521, &[108, 39]), (1522, &[39, 39]), (1523, &[39]), (1524, &[39, 39]),
+  (1545, &[186, 47, 8320, 8320]), (1546, &[186, 47, 8320, 8320, 8320]),
+  (1549, &[44]), (1551, &[1593]), (1560, &[769]), (1561, &[787]),
+  (1562, &[1616]), (1571, &[108, 1652]), (1572, &[1608, 1652]),
+  (1573, &[108, 1621]), (1574, &[1609, 1652]), (1575, &[108]),
+  (1579, &[1609, 1755]), (1588, &[1587, 1755]), (1597, &[1609, 770]),
+  (1599, &[1609, 1755]), (1607, &[111]), (1610, &[1609]), (1611, &[779]),
+  (1614, &[769]), (1615, &[787]), (1618, &[778]), (1619, &[771]),
+  (1622, &[809]), (1623, &[786]), (1624, &[774]), (1625, &[772]),
+  (1626, &[774]), (1627, &[770]), (1628, &[803]), (1629, &[788]),
+  (1631, &[1621]), (1632, &[46]), (1633, &[108]), (1637, &[111]),
+  (1639, &[86]), (1640, &[581]), (1642, &[186, 47, 8320]), (1643, &[44]),
+  (1644, &[1548]), (1645, &[42]), (1646, &[1609]), (1647, &[1697]),
+  (1650, &[108, 1652]), (1651, &[108, 1621]), (1653, &[108, 1652]),
+  (1654, &[1608, 1652]), (1655, &[1608, 787, 1652]), (1656, &[1609, 1652]),
+  (1657, &[1609, 1557]), (1662, &[1609, 1755]), (1665, &[1581, 1620]),
+  (1669, &[1581, 1755]), (1672, &[1583, 1557]), (1675, &[1674, 1557]),
+  (1678, &[1583, 1755]), (1681, &[1585, 1557]), (1682, &[1585, 774]),
+  (1688, &[1585, 1755]), (1694, &[1589, 1755]), (1695, &[1591, 1755]),
+  (1700, &[1697, 1755]), (1703, &[1601]), (1704, &[1697, 1755]),
+  (1705, &[1603]), (1706, &[1603]), (1709, &[1603, 1755]),
+  (1716, &[1711, 1755]), (1717, &[1604, 774]), (1719, &[1604, 1755]),
+  (1722, &[1609]), (1723, &[1609, 1557]), (1725, &[1609, 1755]),
+  (1726, &[111]), (1729, &[111]), (1730, &[1728]), (1731, &[1577]),
+  (1734, &[1608, 774]), (1735, &[1608, 787]), (1736, &[1608, 1648]),
+  (1737, &[1608, 770]), (1739, &[1608, 1755]), (1740, &[1609]),
+  (1742, &[1609, 774]), (1744, &[1659]), (1745, &[1609, 1755]),
+  (1746, &[1609]), (1748, &[45]), (1749, &[111]), (1759, &[778]),
+  (1768, &[774, 775]), (1772, &[775]), (1774, &[1583, 770]),
+  (1775, &[1585, 770]), (1776, &[46]), (1777, &[108]), (1778, &[1634]),
+  (1779, &[1635]), (1780, &[1636]), (1781, &[111]), (1782, &[1638]),
+  (1783, &[86]), (1784, &[581]), (1785, &[1641]), (1789, &[1569, 840]),
+  (1790, &[1605, 840]), (1791, &[111, 770]), (1793, &[46]), (1794, &[46]),
+  (1795, &[58]), (1796, &[58]), (1856, &[775]), (1857, &[775]),
+  (1858, &[1852]), (1863, &[769]), (1873, &[1576, 1755]), (1878, &[1609, 774]),
+  (1890, &[1708]), (1891, &[1603, 1755]), (1895, &[1876]),
+  (1896, &[1606, 1557]), (1897, &[1606, 774]), (1900, &[1585, 1620]),
+  (1905, &[1687, 1557]), (1906, &[1581, 1620]), (1918, &[1587, 770]),
+  (1984, &[79]), (1994, &[108]), (2027, &[772]), (2029, &[775]),
+  (2030, &[770]), (2035, &[776]), (2036, &[39]), (2037, &[39]), (2042, &[95]),
+  (2209, &[1576, 1620]), (2212, &[1698, 1755]), (2215, &[1605, 1755]),
+  (2216, &[1609, 1620]), (2217, &[1876]), (2222, &[1583, 804, 803]),
+  (2223, &[1589, 804, 803]), (2224, &[1711]), (2225, &[1608]),
+  (2226, &[1586, 770]), (2230, &[1576, 1762]), (2231, &[1609, 1755, 1762]),
+  (2233, &[1585, 774, 775]), (2234, &[1609, 774, 775]), (2235, &[1697]),
+  (2236, &[1697]), (2237, &[1609]), (2277, &[1612]), (2280, &[1612]),
+  (2282, &[775]), (2283, &[776]), (2285, &[803]), (2286, &[804]),
+  (2288, &[779]), (2289, &[1612]), (2290, &[1613]), (2291, &[787]),
+  (2296, &[848]), (2297, &[852]), (2298, &[853]), (2303, &[848]),
+  (2304, &[850]), (2305, &[774, 775]), (2306, &[775]), (2307, &[58]),
+  (2308, &[2309, 2374]), (2310, &[2309, 2366]), (2312, &[2352, 2381, 2311]),
+  (2317, &[2319, 2373]), (2318, &[2319, 2374]), (2320, &[2319, 2375]),
+  (2321, &[2309, 2377]), (2322, &[2309, 2366, 2374]),
+  (2323, &[2309, 2366, 2375]), (2324, &[2309, 2366, 2376]), (2364, &[803]),
+  (2386, &[817]), (2387, &[768]), (2388, &[769]), (2405, &[2404, 2404]),
+  (2406, &[111]), (2407, &[1641]), (2429, &[63]), (2433, &[774, 775]),
+  (2438, &[2437, 2494]), (2492, &[803]), (2528, &[2443, 2499]),
+  (2529, &[2443, 2499]), (2534, &[79]), (2538, &[56]), (2541, &[57]),
+  (2562, &[775]), (2563, &[2435]), (2566, &[2565, 2622]),
+  (2567, &[2674, 2623]), (2568, &[2674, 2624]), (2569, &[2675, 2625]),
+  (2570, &[2675, 2626]), (2575, &[2674, 2631]), (2576, &[2565, 2632]),
+  (2580, &[2565, 2636]), (2620, &[803]), (2635, &[2374]), (2637, &[2381]),
+  (2662, &[111]), (2663, &[57]), (2666, &[56]), (2689, &[774, 775]),
+  (2690, &[775]), (2691, &[58]), (2694, &[2693, 2750]), (2701, &[2693, 2757]),
+  (2703, &[2693, 2759]), (2704, &[2693, 2760]), (2705, &[2693, 2750, 2757]),
+  (2707, &[2693, 2750, 2759]), (2708, &[2693, 2750, 2760]), (2748, &[803]),
+  (2749, &[2365]), (2753, &[2369]), (2754, &[2370]), (2765, &[2381]),
+  (2790, &[111]), (2792, &[2408]), (2793, &[2409]), (2794, &[2410]),
+  (2798, &[2414]), (2800, &[2416]), (2817, &[774, 775]), (2819, &[56]),
+  (2822, &[2821, 2878]), (2848, &[79]), (2876, &[803]), (2918, &[79]),
+  (2920, &[57]), (2946, &[778]), (2954, &[2953, 2995]), (2972, &[2960]),
+  (2992, &[2952]), (3006, &[2952]), (3016, &[2985]), (3018, &[3014, 2952]),
+  (3019, &[3015, 2952]), (3020, &[3014, 2995]), (3021, &[775]),
+  (3031, &[2995]), (3046, &[111]), (3047, &[2965]), (3048, &[2953]),
+  (3050, &[2970]), (3051, &[2952, 3009]), (3052, &[2970, 3009]),
+  (3053, &[2958]), (3054, &[2949]), (3056, &[2991]), (3058, &[2970, 3010]),
+  (3060, &[2990, 3008]), (3061, &[3059]), (3063, &[2958, 2997]),
+  (3064, &[2999]), (3066, &[2984, 3008]), (3072, &[774, 775]), (3074, &[111]),
+  (3075, &[2435]), (3091, &[3090, 3157]), (3092, &[3090, 3148]),
+  (3104, &[3120, 1468]), (3106, &[3105, 803]), (3109, &[3111, 1468]),
+  (3117, &[3116, 803]), (3118, &[3125, 3137]), (3127, &[3125, 803]),
+  (3129, &[3125, 3134]), (3138, &[3137, 3134]), (3140, &[3139, 3134]),
+  (3168, &[3083, 3134]), (3169, &[3084, 3134]), (3174, &[111]),
+  (3201, &[774, 775]), (3202, &[111]), (3203, &[2435]), (3205, &[3077]),
+  (3206, &[3078]), (3207, &[3079]), (3218, &[3090]), (3219, &[3090, 3157]),
+  (3220, &[3090, 3148]), (3228, &[3100]), (3230, &[3102]), (3235, &[3107]),
+  (3247, &[3119]), (3249, &[3121]), (3250, &[3122]), (3297, &[3212, 3262]),
+  (3302, &[111]), (3303, &[3175]), (3304, &[3176]), (3311, &[3183]),
+  (3329, &[774, 775]), (3330, &[111]), (3331, &[2435]), (3336, &[3335, 3415]),
+  (3337, &[2953]), (3338, &[2953, 3415]), (3340, &[3368, 3393]),
+  (3344, &[3342, 3398]), (3347, &[3346, 3390]), (3348, &[3346, 3415]),
+  (3353, &[3368, 3393]), (3356, &[2960]), (3360, &[111]), (3363, &[2979]),
+  (3377, &[3376]), (3380, &[2996]), (3382, &[2998]), (3386, &[2975, 3007]),
+  (3391, &[3007]), (3392, &[3007]), (3394, &[3393]), (3395, &[3393]),
+  (3400, &[3398, 3398]), (3406, &[2417]), (3418, &[3368, 3405, 3374]),
+  (3423, &[111, 3376, 111]), (3425, &[3358]), (3430, &[111]),
+  (3434, &[3376, 3405]), (3435, &[3366, 3405, 3376]),
+  (3436, &[3368, 3405, 3368]), (3437, &[57]), (3438, &[3381, 3405, 3376]),
+  (3439, &[3368, 3405]), (3446, &[3385, 3405, 3374]), (3449, &[3368, 3393]),
+  (3451, &[3368, 3405]), (3452, &[3376, 3405]), (3458, &[111]),
+  (3459, &[2435]), (3561, &[3560, 3535]), (3562, &[3490]), (3563, &[3503]),
+  (3567, &[3560, 3539]), (3587, &[3586]), (3595, &[3594]), (3599, &[3598]),
+  (3604, &[3588]), (3605, &[3588]), (3607, &[3601]), (3617, &[3590]),
+  (3622, &[3616]), (3635, &[778, 3634]), (3649, &[3648, 3648]),
+  (3653, &[3634]), (3661, &[778]), (3664, &[111]), (3720, &[3592]),
+  (3725, &[3618]), (3738, &[3610]), (3739, &[3611]), (3741, &[3613]),
+  (3742, &[3614]), (3743, &[3615]), (3763, &[778, 3762]), (3768, &[3640]),
+  (3769, &[3641]), (3784, &[3656]), (3785, &[3657]), (3786, &[3658]),
+  (3787, &[3659]), (3789, &[778]), (3792, &[111]), (3804, &[3755, 3737]),
+  (3805, &[3755, 3745]), (3840, &[3944, 3964, 3966]),
+  (3842, &[3936, 3956, 3970, 3967]), (3843, &[3936, 3956, 3970, 3860]),
+  (3852, &[3851]), (3854, &[3853, 3853]), (3867, &[3866, 3866]),
+  (3870, &[3869, 3869]), (3871, &[3866, 3869]), (3895, &[805]),
+  (3946, &[3938]), (3959, &[4018, 3953, 3968]), (3961, &[4019, 3953, 3968]),
+  (4046, &[3869, 3866]), (4053, &[21328]), (4054, &[21325]),
+  (4096, &[4098, 4140]), (4112, &[111, 4140]), (4125, &[111]),
+  (4127, &[4117, 4140]), (4137, &[4126, 4156]),
+  (4138, &[4126, 4156, 4145, 4140, 4154]), (4150, &[778]), (4152, &[2435]),
+  (4160, &[111]), (4171, &[4170, 4170]), (4197, &[4161]),
+  (4198, &[4117, 4158]), (4207, &[4117, 4140, 4158]), (4208, &[4099, 4158]),
+  (4222, &[4221, 4158]), (4225, &[4098, 4158]), (4254, &[4227, 778]),
+  (4256, &[42886]), (4327, &[121]), (4339, &[541]), (4351, &[111]),
+  (4353, &[4352, 4352]), (4356, &[4355, 4355]), (4360, &[4359, 4359]),
+  (4362, &[4361, 4361]), (4365, &[4364, 4364]), (4371, &[4354, 4352]),
+  (4372, &[4354, 4354]), (4373, &[4354, 4355]), (4374, &[4354, 4359]),
+  (4375, &[4355, 4352]), (4376, &[4357, 4354]), (4377, &[4357, 4357]),
+  (4378, &[4357, 4370]), (4379, &[4357, 4363]), (4380, &[4358, 4359]),
+  (4381, &[4358, 4363]), (4382, &[4359, 4352]), (4383, &[4359, 4354]),
+  (4384, &[4359, 4355]), (4385, &[4359, 4361]), (4386, &[4359, 4361, 4352]),
+  (4387, &[4359, 4361, 4355]), (4388, &[4359, 4361, 4359]),
+  (4389, &[4359, 4361, 4361]), (4390, &[4359, 4361, 4364]),
+  (4391, &[4359, 4364]), (4392, &[4359, 4366]), (4393, &[4359, 4368]),
+  (4394, &[4359, 4369]), (4395, &[4359, 4363]), (4396, &[4359, 4359, 4363]),
+  (4397, &[4361, 4352]), (4398, &[4361, 4354]), (4399, &[4361, 4355]),
+  (4400, &[4361, 4357]), (4401, &[4361, 4358]), (4402, &[4361, 4359]),
+  (4403, &[4361, 4359, 4352]), (4404, &[4361, 4361, 4361]),
+  (4405, &[4361, 4363]), (4406, &[4361, 4364]), (4407, &[4361, 4366]),
+  (4408, &[4361, 4367]), (4409, &[4361, 4368]), (4410, &[4361, 4369]),
+  (4411, &[4357, 4370]), (4413, &[4412, 4412]), (4415, &[4414, 4414]),
+  (4417, &[4363, 4352]), (4418, &[4363, 4355]), (4419, &[4363, 4358]),
+  (4420, &[4363, 4359]), (4421, &[4363, 4361]), (4422, &[4363, 4416]),
+  (4423, &[4363, 4363]), (4424, &[4363, 4364]), (4425, &[4363, 4366]),
+  (4426, &[4363, 4368]), (4427, &[4363, 4369]), (4429, &[4364, 4363]),
+  (4431, &[4430, 4430]), (4433, &[4432, 4432]), (4434, &[4366, 4367]),
+  (4435, &[4366, 4370]), (4438, &[4369, 4359]), (4439, &[4369, 4363]),
+  (4440, &[4370, 4370]), (4442, &[4352, 4355]), (4443, &[4354, 4361]),
+  (4444, &[4354, 4364]), (4445, &[4354, 4370]), (4446, &[4355, 4357]),
+  (4450, &[4449, 20008]), (4452, &[4451, 20008]), (4454, &[4453, 20008]),
+  (4456, &[4455, 20008]), (4458, &[4457, 4449]), (4459, &[4457, 4449, 20008]),
+  (4460, &[4457, 20008]), (4463, &[4462, 4453]), (4464, &[4462, 4453, 20008]),
+  (4465, &[4462, 20008]), (4467, &[12540]), (4468, &[12540, 20008]),
+  (4469, &[20008]), (4470, &[4449, 4457]), (4471, &[4449, 4462]),
+  (4472, &[4451, 4457]), (4473, &[4451, 4461]), (4474, &[4453, 4457]),
+  (4475, &[4453, 4462]), (4476, &[4453, 12540]), (4477, &[4455, 4457]),
+  (4478, &[4455, 4462]), (4479, &[4457, 4453]), (4480, &[4457, 4453, 20008]),
+  (4481, &[4457, 4455, 20008]), (4482, &[4457, 4457]), (4483, &[4457, 4462]),
+  (4484, &[4461, 4451]), (4485, &[4461, 4451, 20008]), (4486, &[4461, 4451]),
+  (4487, &[4461, 4457]), (4488, &[4461, 20008]), (4489, &[4462, 4449]),
+  (4490, &[4462, 4449, 20008]), (4491, &[4462, 4453, 12540]),
+  (4492, &[4462, 4455, 20008]), (4493, &[4462, 4462]), (4494, &[4466, 4449]),
+  (4495, &[4466, 4453]), (4496, &[4466, 4453, 20008]), (4497, &[4466, 4455]),
+  (4498, &[4466, 4455, 20008]), (4499, &[4466, 4462]), (4500, &[4466, 20008]),
+  (4501, &[12540, 4462]), (4502, &[12540, 12540]),
+  (4503, &[12540, 20008, 4462]), (4504, &[20008, 4449]),
+  (4505, &[20008, 4451]), (4506, &[20008, 4457]), (4507, &[20008, 4462]),
+  (4508, &[20008, 12540]), (4509, &[20008, 4510]), (4511, &[4510, 4453]),
+  (4512, &[4510, 4462]), (4513, &[4510, 20008]), (4514, &[4510, 4510]),
+  (4515, &[4449, 12540]), (4516, &[4451, 4462]), (4517, &[4455, 4451]),
+  (4518, &[4457, 4451]), (4519, &[4457, 4451, 20008]), (4520, &[4352]),
+  (4521, &[4352, 4352]), (4522, &[4352, 4361]), (4523, &[4354]),
+  (4524, &[4354, 4364]), (4525, &[4354, 4370]), (4526, &[4355]),
+  (4527, &[4357]), (4528, &[4357, 4352]), (4529, &[4357, 4358]),
+  (4530, &[4357, 4359]), (4531, &[4357, 4361]), (4532, &[4357, 4368]),
+  (4533, &[4357, 4369]), (4534, &[4357, 4370]), (4535, &[4358]),
+  (4536, &[4359]), (4537, &[4359, 4361]), (4538, &[4361]),
+  (4539, &[4361, 4361]), (4540, &[4363]), (4541, &[4364]), (4542, &[4366]),
+  (4543, &[4367]), (4544, &[4368]), (4545, &[4369]), (4546, &[4370]),
+  (4547, &[4352, 4357]), (4548, &[4352, 4361, 4352]), (4549, &[4354, 4352]),
+  (4550, &[4354, 4355]), (4551, &[4354, 4361]), (4552, &[4354, 4416]),
+  (4553, &[4354, 4368]), (4554, &[4355, 4352]), (4555, &[4355, 4357]),
+  (4556, &[4357, 4352, 4361]), (4557, &[4357, 4354]), (4558, &[4357, 4355]),
+  (4559, &[4357, 4355, 4370]), (4560, &[4357, 4357]),
+  (4561, &[4357, 4358, 4352]), (4562, &[4357, 4358, 4361]),
+  (4563, &[4357, 4359, 4361]), (4564, &[4357, 4359, 4370]),
+  (4565, &[4357, 4359, 4363]), (4566, &[4357, 4361, 4361]),
+  (4567, &[4357, 4416]), (4568, &[4357, 4367]), (4569, &[4357, 4441]),
+  (4570, &[4358, 4352]), (4571, &[4358, 4357]), (4572, &[4358, 4359]),
+  (4573, &[4358, 4361]), (4574, &[4358, 4361, 4361]), (4575, &[4358, 4416]),
+  (4576, &[4358, 4366]), (4577, &[4358, 4370]), (4578, &[4358, 4363]),
+  (4579, &[4359, 4357]), (4580, &[4359, 4369]), (4581, &[4359, 4370]),
+  (4582, &[4359, 4363]), (4583, &[4361, 4352]), (4584, &[4361, 4355]),
+  (4585, &[4361, 4357]), (4586, &[4361, 4359]), (4587, &[4416]),
+  (4588, &[4363, 4352]), (4589, &[4363, 4352, 4352]), (4590, &[4363, 4363]),
+  (4591, &[4363, 4367]), (4592, &[4428]), (4593, &[4363, 4361]),
+  (4594, &[4363, 4416]), (4595, &[4369, 4359]), (4596, &[4369, 4363]),
+  (4597, &[4370, 4354]), (4598, &[4370, 4357]), (4599, &[4370, 4358]),
+  (4600, &[4370, 4359]), (4601, &[4441]), (4602, &[4352, 4354]),
+  (4603, &[4352, 4359]), (4604, &[4352, 4366]), (4605, &[4352, 4367]),
+  (4606, &[4352, 4370]), (4607, &[4354, 4354]), (4608, &[85]), (4643, &[624]),
+  (4672, &[934]), (4704, &[1352]), (4756, &[1393]), (4816, &[79]),
+  (5024, &[68]), (5025, &[82]), (5026, &[84]), (5028, &[79, 39]),
+  (5029, &[105]), (5032, &[11381]), (5033, &[89]), (5034, &[65]),
+  (5035, &[74]), (5036, &[69]), (5038, &[63]), (5040, &[11381]),
+  (5041, &[915]), (5043, &[87]), (5047, &[77]), (5051, &[72]), (5053, &[89]),
+  (5054, &[79, 821]), (5055, &[427]), (5056, &[71]), (5058, &[104]),
+  (5059, &[90]), (5063, &[1120]), (5067, &[400]), (5068, &[85, 821]),
+  (5070, &[52]), (5071, &[98]), (5074, &[82]), (5076, &[87]), (5077, &[83]),
+  (5081, &[86]), (5082, &[83]), (5086, &[76]), (5087, &[67]), (5090, &[80]),
+  (5094, &[75]), (5095, &[100]), (5099, &[79, 821]), (5102, &[54]),
+  (5104, &[223]), (5106, &[104, 788]), (5107, &[71]), (5108, &[66]),
+  (5115, &[610]), (5116, &[665]), (5120, &[61]), (5123, &[916]),
+  (5132, &[183, 5121]), (5133, &[5121, 183]), (5134, &[183, 916]),
+  (5135, &[916, 183]), (5136, &[183, 5124]), (5137, &[5124, 183]),
+  (5138, &[183, 5125]), (5139, &[5125, 183]), (5140, &[183, 5126]),
+  (5141, &[5126, 183]), (5143, &[183, 5130]), (5144, &[5130, 183]),
+  (5145, &[183, 5131]), (5146, &[5131, 183]), (5159, &[183]),
+  (5163, &[5121, 5152]), (5164, &[916, 5152]), (5165, &[5125, 5152]),
+  (5166, &[5130, 5152]), (5167, &[86]), (5169, &[581]), (5171, &[62]),
+  (5175, &[183, 62]), (5176, &[60]), (5178, &[183, 86]), (5179, &[86, 183]),
+  (5180, &[183, 581]), (5181, &[581, 183]), (5182, &[183, 5170]),
+  (5183, &[5170, 183]), (5184, &[183, 62]), (5185, &[62, 183]),
+  (5186, &[183, 5172]), (5187, &[5172, 183]), (5188, &[183, 60]),
+  (5189, &[60, 183]), (5190, &[183, 5177]), (5191, &[5177, 183]),
+  (5194, &[39]), (5196, &[85]), (5198, &[1352]), (5204, &[183, 5200]),
+  (5207, &[183, 85]), (5208, &[85, 183]), (5209, &[183, 1352]),
+  (5210, &[1352, 183]), (5211, &[183, 5199]), (5212, &[5199, 183]),
+  (5213, &[183, 5200]), (5214, &[5200, 183]), (5215, &[183, 5201]),
+  (5216, &[5201, 183]), (5217, &[183, 5205]), (5218, &[5205, 183]),
+  (5219, &[183, 5206]), (5220, &[5206, 183]), (5223, &[85, 39]),
+  (5224, &[1352, 39]), (5225, &[5200, 39]), (5226, &[5205, 39]), (5229, &[80]),
+  (5231, &[100]), (5234, &[98]), (5235, &[98, 775]), (5236, &[183, 5227]),
+  (5237, &[5227, 183]), (5238, &[183, 80]), (5239, &[112, 183]),
+  (5240, &[183, 5230]), (5241, &[5230, 183]), (5242, &[183, 100]),
+  (5243, &[100, 183]), (5244, &[183, 5232]), (5245, &[5232, 183]),
+  (5246, &[183, 98]), (5247, &[98, 183]), (5248, &[183, 98, 775]),
+  (5249, &[98, 775, 183]), (5253, &[5227, 39]), (5254, &[80, 39]),
+  (5255, &[100, 39]), (5256, &[98, 39]), (5261, &[74]), (5266, &[183, 5257]),
+  (5267, &[5257, 183]), (5268, &[183, 5259]), (5269, &[5259, 183]),
+  (5270, &[183, 5260]), (5271, &[5260, 183]), (5272, &[183, 74]),
+  (5273, &[74, 183]), (5274, &[183, 5262]), (5275, &[5262, 183]),
+  (5276, &[183, 5264]), (5277, &[5264, 183]), (5278, &[183, 5265]),
+  (5279, &[5265, 183]), (5285, &[915]), (5290, &[76]), (5292, &[183, 5283]),
+  (5293, &[5283, 183]), (5294, &[183, 915]), (5295, &[915, 183]),
+  (5296, &[183, 5286]), (5297, &[5286, 183]), (5298, &[183, 5287]),
+  (5299, &[5287, 183]), (5300, &[183, 5288]), (5301, &[5288, 183]),
+  (5302, &[183, 76]), (5303, &[108, 183]), (5304, &[183, 5291]),
+  (5305, &[5291, 183]), (5311, &[50]), (5321, &[183, 5312]),
+  (5322, &[5312, 183]), (5323, &[183, 5319]), (5324, &[5319, 183]),
+  (5325, &[183, 5320]), (5326, &[5320, 183]), (5329, &[5153]),
+  (5340, &[183, 5331]), (5341, &[5331, 183]), (5342, &[183, 5333]),
+  (5343, &[5333, 183]), (5344, &[183, 5334]), (5345, &[5334, 183]),
+  (5346, &[183, 5335]), (5347, &[5335, 183]), (5348, &[183, 5336]),
+  (5349, &[5336, 183]), (5350, &[183, 5338]), (5351, &[5338, 183]),
+  (5352, &[183, 5339]), (5353, &[5339, 183]), (5366, &[183, 5357]),
+  (5367, &[5357, 183]), (5368, &[183, 5359]), (5369, &[5359, 183]),
+  (5370, &[183, 5360]), (5371, &[5360, 183]), (5372, &[183, 5361]),
+  (5373, &[5361, 183]), (5374, &[183, 5362]), (5375, &[5362, 183]),
+  (5376, &[183, 5364]), (5377, &[5364, 183]), (5378, &[183, 5365]),
+  (5379, &[5365, 183]), (5388, &[5387, 60]), (5389, &[5387, 5205]),
+  (5390, &[5387, 98]), (5391, &[5387, 5264]), (5399, &[183, 5392]),
+  (5400, &[5392, 183]), (5401, &[183, 5393]), (5402, &[5393, 183]),
+  (5403, &[183, 5394]), (5404, &[5394, 183]), (5405, &[183, 5395]),
+  (5406, &[5395, 183]), (5407, &[183, 5396]), (5408, &[5396, 183]),
+  (5409, &[183, 5397]), (5410, &[5397, 183]), (5411, &[183, 5398]),
+  (5412, &[5398, 183]), (5423, &[183, 52]), (5424, &[52, 183]),
+  (5425, &[183, 5416]), (5426, &[5416, 183]), (5427, &[183, 5417]),
+  (5428, &[5417, 183]), (5429, &[183, 5418]), (5430, &[5418, 183]),
+  (5431, &[183, 5419]), (5432, &[5419, 183]), (5433, &[183, 5421]),
+  (5434, &[5421, 183]), (5435, &[183, 5422]), (5436, &[5422, 183]),
+  (5440, &[5161]), (5441, &[120]), (5454, &[183, 5452]), (5455, &[5452, 183]),
+  (5467, &[183, 5466]), (5468, &[5466, 183]), (5480, &[183, 5479]),
+  (5481, &[5479, 183]), (5495, &[7839]), (5500, &[72]), (5501, &[120]),
+  (5502, &[5456, 5228]), (5503, &[5456, 80]), (5504, &[5456, 5230]),
+  (5505, &[5456, 100]), (5506, &[5456, 5232]), (5507, &[5456, 98]),
+  (5508, &[5456, 98, 775]), (5509, &[5456, 5251]), (5511, &[82]),
+  (5518, &[5525, 5258]), (5519, &[5525, 5259]), (5520, &[5525, 5260]),
+  (5521, &[5525, 74]), (5522, &[5525, 5262]), (5523, &[5525, 5264]),
+  (5524, &[5525, 5265]), (5551, &[98]), (5556, &[70]), (5557, &[8498]),
+  (5559, &[43003]), (5572, &[11375]), (5573, &[65]), (5598, &[68]),
+  (5610, &[68]), (5615, &[1120]), (5616, &[77]), (5623, &[66]),
+  (5634, &[5264]), (5635, &[5257]), (5636, &[5331]), (5639, &[5338]),
+  (5666, &[5443]), (5667, &[5446]), (5668, &[5450]), (5678, &[433]),
+  (5679, &[937]), (5684, &[433]), (5685, &[937]), (5741, &[88]),
+  (5742, &[120]), (5743, &[5456, 5227]), (5744, &[5525, 5257]),
+  (5745, &[5526, 5259]), (5746, &[5526, 5260]), (5747, &[5526, 74]),
+  (5748, &[5526, 5262]), (5749, &[5526, 5264]), (5750, &[5526, 5265]),
+  (5751, &[5543, 183]), (5752, &[5544, 183]), (5753, &[5545, 183]),
+  (5754, &[5546, 183]), (5755, &[5547, 183]), (5756, &[5548, 183]),
+  (5757, &[5549, 183]), (5760, &[32]), (5810, &[60]), (5815, &[88]),
+  (5825, &[108]), (5826, &[5821]), (5836, &[39]), (5845, &[75]), (5846, &[77]),
+  (5848, &[936]), (5857, &[5820]), (5867, &[183]), (5868, &[58]),
+  (5869, &[43]), (5872, &[934]), (5941, &[47]), (6051, &[6050]),
+  (6071, &[3636]), (6072, &[3637]), (6073, &[3638]), (6074, &[3639]),
+  (6086, &[778]), (6091, &[3656]), (6099, &[778]), (6100, &[3631]),
+  (6101, &[3674]), (6105, &[3663]), (6106, &[3675]), (6147, &[58]),
+  (6153, &[58]), (6229, &[6197]), (6294, &[6236]), (6323, &[183, 6321]),
+  (6326, &[183, 6324]), (6329, &[183, 6328]), (6338, &[183, 6336]),
+  (6342, &[183, 5314]), (6343, &[5314, 183]), (6344, &[183, 5315]),
+  (6345, &[5315, 183]), (6346, &[183, 5316]), (6347, &[5316, 183]),
+  (6348, &[183, 5317]), (6349, &[5317, 183]), (6350, &[183, 5443]),
+  (6351, &[183, 5446]), (6352, &[183, 5447]), (6353, &[183, 5448]),
+  (6354, &[183, 5449]), (6355, &[183, 5451]), (6363, &[6389]),
+  (6364, &[6367, 5150]), (6365, &[5150, 6367]), (6368, &[5443, 183]),
+  (6371, &[5470, 183]), (6372, &[5478, 183]), (6373, &[5483, 183]),
+  (6376, &[5510, 183]), (6378, &[5527, 183]), (6381, &[1120, 183]),
+  (6384, &[5620, 183]), (6386, &[5659, 183]), (6608, &[6558]), (6609, &[6577]),
+  (6784, &[6725]), (6800, &[6725]), (6825, &[6824, 6824]),
+  (6827, &[6826, 6824]), (6836, &[1755]), (6839, &[808]), (6994, &[6925]),
+  (6995, &[6929]), (7000, &[6952]), (7004, &[6992]), (7007, &[7006, 7006]),
+  (7228, &[7227, 7227]), (7295, &[7294, 7294]), (7376, &[770]), (7378, &[772]),
+  (7379, &[39, 39]), (7381, &[811]), (7384, &[814]), (7385, &[813]),
+  (7386, &[782]), (7388, &[809]), (7389, &[803]), (7390, &[804]),
+  (7405, &[790]), (7428, &[99]), (7432, &[604]), (7435, &[312]),
+  (7437, &[653]), (7439, &[111]), (7440, &[596]), (7441, &[111]),
+  (7444, &[477, 111]), (7452, &[117]), (7456, &[118]), (7457, &[119]),
+  (7458, &[122]), (7460, &[424]), (7462, &[114]), (7463, &[652]),
+  (7464, &[960]), (7465, &[7448]), (7467, &[1083]), (7486, &[6358]),
+  (7506, &[186]), (7531, &[117, 101]), (7534, &[102, 820]),
+  (7535, &[114, 110, 820]), (7536, &[110, 820]), (7538, &[114, 820]),
+  (7539, &[638, 820]), (7540, &[115, 820]), (7541, &[116, 820]),
+  (7542, &[122, 820]), (7544, &[7476]), (7547, &[105, 821]),
+  (7548, &[105, 821]), (7549, &[112, 821]), (7550, &[117, 821]),
+  (7551, &[650, 821]), (7555, &[103]), (7564, &[121]), (7568, &[587]),
+  (7583, &[7499]), (7586, &[7501]), (7610, &[6356]), (7611, &[5702]),
+  (7662, &[11756]), (7747, &[43857]), (7834, &[7843]), (7837, &[102]),
+  (7838, &[223]), (7935, &[121]), (8061, &[8180]), (8125, &[39]),
+  (8126, &[105]), (8127, &[39]), (8128, &[126]), (8175, &[39]),
+  (8182, &[5103]), (8189, &[39]), (8190, &[39]), (8192, &[32]), (8193, &[32]),
+  (8194, &[32]), (8195, &[32]), (8196, &[32]), (8197, &[32]), (8198, &[32]),
+  (8199, &[32]), (8200, &[32]), (8201, &[32]), (8202, &[32]), (8208, &[45]),
+  (8209, &[45]), (8210, &[45]), (8211, &[45]), (8212, &[12540]),
+  (8213, &[12540]), (8214, &[108, 108]), (8216, &[39]), (8217, &[39]),
+  (8218, &[44]), (8219, &[39]), (8220, &[39, 39]), (8221, &[39, 39]),
+  (8223, &[39, 39]), (8226, &[183]), (8228, &[46]), (8229, &[46, 46]),
+  (8230, &[46, 46, 46]), (8231, &[183]), (8232, &[32]), (8233, &[32]),
+  (8239, &[32]), (8240, &[186, 47, 8320, 8320]),
+  (8241, &[186, 47, 8320, 8320, 8320]), (8242, &[39]), (8243, &[39, 39]),
+  (8244, &[39, 39, 39]), (8245, &[39]), (8246, &[39, 39]),
+  (8247, &[39, 39, 39]), (8249, &[60]), (8250, &[62]), (8252, &[33, 33]),
+  (8254, &[713]), (8257, &[47]), (8259, &[45]), (8260, &[47]),
+  (8263, &[63, 63]), (8264, &[63, 33]), (8265, &[33, 63]), (8270, &[42]),
+  (8274, &[186, 47, 8320]), (8275, &[126]), (8279, &[39, 39, 39, 39]),
+  (8282, &[58]), (8285, &[11607]), (8286, &[11586]), (8287, &[32]),
+  (8304, &[186]), (8313, &[42864]), (8353, &[67, 8427]), (8356, &[163]),
+  (8357, &[114, 110, 824]), (8360, &[82, 115]), (8361, &[87, 821]),
+  (8363, &[100, 821, 817]), (8364, &[42898]), (8365, &[75, 821]),
+  (8366, &[84, 8427]), (8374, &[108, 116]), (8381, &[1364]), (8411, &[1755]),
+  (8448, &[97, 47, 99]), (8449, &[97, 47, 115]), (8450, &[67]),
+  (8451, &[176, 67]), (8453, &[99, 47, 111]), (8454, &[99, 47, 117]),
+  (8455, &[400]), (8456, &[1069]), (8457, &[176, 70]), (8458, &[103]),
+  (8459, &[72]), (8460, &[72]), (8461, &[72]), (8462, &[104]),
+  (8463, &[104, 821]), (8464, &[108]), (8465, &[108]), (8466, &[76]),
+  (8467, &[108]), (8469, &[78]), (8470, &[78, 111]), (8473, &[80]),
+  (8474, &[81]), (8475, &[82]), (8476, &[82]), (8477, &[82]),
+  (8481, &[84, 69, 76]), (8484, &[90]), (8486, &[937]), (8487, &[433]),
+  (8488, &[90]), (8489, &[639]), (8490, &[75]), (8492, &[66]), (8493, &[67]),
+  (8494, &[101]), (8495, &[101]), (8496, &[69]), (8497, &[70]), (8499, &[77]),
+  (8500, &[111]), (8501, &[1488]), (8502, &[1489]), (8503, &[1490]),
+  (8504, &[1491]), (8505, &[105]), (8507, &[70, 65, 88]), (8508, &[960]),
+  (8509, &[121]), (8510, &[915]), (8511, &[928]), (8512, &[425]),
+  (8513, &[42216]), (8514, &[42230]), (8515, &[93952]), (8517, &[68]),
+  (8518, &[100]), (8519, &[101]), (8520, &[105]), (8521, &[106]),
+  (8544, &[108]), (8545, &[108, 108]), (8546, &[108, 108, 108]),
+  (8547, &[108, 86]), (8548, &[86]), (8549, &[86, 108]),
+  (8550, &[86, 108, 108]), (8551, &[86, 108, 108, 108]), (8552, &[108, 88]),
+  (8553, &[88]), (8554, &[88, 108]), (8555, &[88, 108, 108]), (8556, &[76]),
+  (8557, &[67]), (8558, &[68]), (8559, &[77]), (8560, &[105]),
+  (8561, &[105, 105]), (8562, &[105, 105, 105]), (8563, &[105, 118]),
+  (8564, &[118]), (8565, &[118, 105]), (8566, &[118, 105, 105]),
+  (8567, &[118, 105, 105, 105]), (8568, &[105, 120]), (8569, &[120]),
+  (8570, &[120, 105]), (8571, &[120, 105, 105]), (8572, &[108]), (8573, &[99]),
+  (8574, &[100]), (8575, &[114, 110]), (8579, &[390]), (8580, &[596]),
+  (8593, &[5839]), (8597, &[5864]), (8629, &[8626]), (8638, &[5850]),
+  (8639, &[5840]), (8704, &[11375]), (8707, &[398]), (8710, &[916]),
+  (8719, &[928]), (8721, &[425]), (8722, &[45]), (8724, &[43, 775]),
+  (8725, &[47]), (8726, &[92]), (8727, &[42]), (8728, &[176]), (8729, &[183]),
+  (8734, &[111, 111]), (8739, &[108]), (8741, &[108, 108]), (8744, &[118]),
+  (8745, &[1352]), (8746, &[85]), (8747, &[643]), (8748, &[643, 643]),
+  (8749, &[643, 643, 643]), (8751, &[8750, 8750]), (8752, &[8750, 8750, 8750]),
+  (8758, &[58]), (8760, &[45, 775]), (8764, &[126]), (8784, &[61, 775]),
+  (8785, &[61, 775, 803]), (8791, &[61, 778]), (8793, &[61, 770]),
+  (8794, &[61, 774]), (8798, &[61, 875]), (8803, &[8801]), (8810, &[60, 60]),
+  (8811, &[62, 62]), (8834, &[5205]), (8835, &[5200]), (8853, &[66216]),
+  (8854, &[79, 821]), (8857, &[664]), (8861, &[79, 821]), (8868, &[84]),
+  (8869, &[42197]), (8896, &[8743]), (8897, &[118]), (8898, &[1352]),
+  (8899, &[85]), (8900, &[5852]), (8901, &[183]), (8904, &[5854]),
+  (8918, &[60, 183]), (8919, &[183, 62]), (8920, &[60, 60, 60]),
+  (8921, &[62, 62, 62]), (8942, &[11607]), (8943, &[183, 183, 183]),
+  (8948, &[42899]), (8959, &[69]), (8960, &[8709]), (8997, &[8996]),
+  (9001, &[10092]), (9002, &[10093]), (9025, &[12348]), (9049, &[916, 818]),
+  (9050, &[5852, 818]), (9052, &[176, 818]), (9055, &[8859]),
+  (9057, &[84, 776]), (9058, &[8711, 776]), (9059, &[8902, 776]),
+  (9060, &[176, 776]), (9061, &[1577]), (9064, &[126, 776]), (9065, &[5173]),
+  (9067, &[8711, 820]), (9068, &[79, 821]), (9075, &[105]), (9076, &[112]),
+  (9077, &[969]), (9078, &[97, 818]), (9079, &[42899, 818]),
+  (9080, &[105, 818]), (9081, &[969, 818]), (9082, &[97]), (9087, &[5821]),
+  (9116, &[20008]), (9119, &[20008]), (9122, &[20008]), (9125, &[20008]),
+  (9130, &[20008]), (9134, &[20008]), (9153, &[9045]), (9154, &[9038]),
+  (9155, &[9035]), (9158, &[9069]), (9192, &[8321, 8320]), (9212, &[9211]),
+  (9213, &[108]), (9214, &[9790]), (9290, &[92, 92]), (9312, &[10112]),
+  (9313, &[10113]), (9314, &[10114]), (9315, &[10115]), (9316, &[10116]),
+  (9317, &[10117]), (9318, &[10118]), (9319, &[10119]), (9320, &[10120]),
+  (9321, &[10121]), (9332, &[40, 108, 41]), (9333, &[40, 50, 41]),
+  (9334, &[40, 51, 41]), (9335, &[40, 52, 41]), (9336, &[40, 53, 41]),
+  (9337, &[40, 54, 41]), (9338, &[40, 55, 41]), (9339, &[40, 56, 41]),
+  (9340, &[40, 57, 41]), (9341, &[40, 108, 79, 41]),
+  (9342, &[40, 108, 108, 41]), (9343, &[40, 108, 50, 41]),
+  (9344, &[40, 108, 51, 41]), (9345, &[40, 108, 52, 41]),
+  (9346, &[40, 108, 53, 41]), (9347, &[40, 108, 54, 41]),
+  (9348, &[40, 108, 55, 41]), (9349, &[40, 108, 56, 41]),
+  (9350, &[40, 108, 57, 41]), (9351, &[40, 50, 79, 41]), (9352, &[108, 46]),
+  (9353, &[50, 46]), (9354, &[51, 46]), (9355, &[52, 46]), (9356, &[53, 46]),
+  (9357, &[54, 46]), (9358, &[55, 46]), (9359, &[56, 46]), (9360, &[57, 46]),
+  (9361, &[108, 79, 46]), (9362, &[108, 108, 46]), (9363, &[108, 50, 46]),
+  (9364, &[108, 51, 46]), (9365, &[108, 52, 46]), (9366, &[108, 53, 46]),
+  (9367, &[108, 54, 46]), (9368, &[108, 55, 46]), (9369, &[108, 56, 46]),
+  (9370, &[108, 57, 46]), (9371, &[50, 79, 46]), (9372, &[40, 97, 41]),
+  (9373, &[40, 98, 41]), (9374, &[40, 99, 41]), (9375, &[40, 100, 41]),
+  (9376, &[40, 101, 41]), (9377, &[40, 102, 41]), (9378, &[40, 103, 41]),
+  (9379, &[40, 104, 41]), (9380, &[40, 105, 41]), (9381, &[40, 106, 41]),
+  (9382, &[40, 107, 41]), (9383, &[40, 108, 41]), (9384, &[40, 114, 110, 41]),
+  (9385, &[40, 110, 41]), (9386, &[40, 111, 41]), (9387, &[40, 112, 41]),
+  (9388, &[40, 113, 41]), (9389, &[40, 114, 41]), (9390, &[40, 115, 41]),
+  (9391, &[40, 116, 41]), (9392, &[40, 117, 41]), (9393, &[40, 118, 41]),
+  (9394, &[40, 119, 41]), (9395, &[40, 120, 41]), (9396, &[40, 121, 41]),
+  (9397, &[40, 122, 41]), (9400, &[169]), (9413, &[8471]), (9415, &[174]),
+  (9435, &[9406]), (9472, &[12540]), (9473, &[12540]), (9475, &[9474]),
+  (9487, &[9484]), (9507, &[9500]), (9585, &[47]), (9587, &[88]),
+  (9608, &[8718]), (9616, &[9612]), (9620, &[713]), (9623, &[9622]),
+  (9629, &[9624]), (9632, &[8718]), (9649, &[9189]), (9651, &[916]),
+  (9655, &[8883]), (9656, &[9654]), (9658, &[9654]), (9661, &[66236]),
+  (9665, &[8882]), (9671, &[5852]), (9674, &[5852]), (9675, &[176]),
+  (9678, &[9022]), (9696, &[8978]), (9702, &[176]), (9737, &[664]),
+  (9744, &[9633]), (9765, &[67998]), (9776, &[11446]), (9784, &[9096]),
+  (9806, &[8783]), (9826, &[5852]), (9833, &[119128, 119141]),
+  (9834, &[119128, 119141, 119150]), (9900, &[2416]), (10088, &[40]),
+  (10089, &[41]), (10094, &[60]), (10095, &[62]), (10098, &[40]),
+  (10099, &[41]), (10100, &[123]), (10101, &[125]), (10133, &[43]),
+  (10134, &[45]), (10135, &[247]), (10178, &[42197]), (10184, &[92, 5205]),
+  (10185, &[5200, 47]), (10187, &[47]), (10189, &[92]), (10201, &[84]),
+  (10216, &[10092]), (10217, &[10093]), (10539, &[120]), (10540, &[120]),
+  (10595, &[5840, 5850]), (10597, &[8643, 8642]), (10606, &[5840, 8642]),
+  (10607, &[8643, 5850]), (10649, &[11586]), (10672, &[9033]),
+  (10686, &[9022]), (10692, &[12348]), (10693, &[9026]), (10695, &[9019]),
+  (10710, &[66240]), (10713, &[10650]), (10740, &[58, 8594]), (10741, &[92]),
+  (10742, &[47, 772]), (10744, &[47]), (10745, &[92]), (10752, &[664]),
+  (10753, &[66216]), (10754, &[8855]), (10755, &[8845]), (10756, &[8846]),
+  (10757, &[8851]), (10758, &[8852]), (10764, &[643, 643, 643, 643]),
+  (10781, &[5854]), (10784, &[62, 62]), (10785, &[5850]), (10786, &[43, 778]),
+  (10787, &[43, 770]), (10788, &[43, 771]), (10789, &[43, 803]),
+  (10790, &[43, 816]), (10791, &[43, 8322]), (10793, &[45, 787]),
+  (10794, &[45, 803]), (10799, &[120]), (10800, &[120, 775]), (10813, &[8985]),
+  (10814, &[10783]), (10815, &[8720]), (10858, &[126, 775]),
+  (10862, &[61, 8432]), (10868, &[58, 58, 61]), (10869, &[61, 61]),
+  (10870, &[61, 61, 61]), (10917, &[62, 60]), (10922, &[5589]),
+  (10923, &[5586]), (10967, &[5200, 5205]), (11003, &[47, 47, 47]),
+  (11005, &[47, 47]), (11244, &[8606]), (11245, &[8607]), (11246, &[8608]),
+  (11247, &[8609]), (11367, &[72, 809]), (11369, &[75, 809]), (11396, &[915]),
+  (11397, &[114]), (11398, &[916]), (11400, &[42898]), (11401, &[42899]),
+  (11406, &[72]), (11410, &[108]), (11412, &[75]), (11413, &[312]),
+  (11414, &[955]), (11416, &[77]), (11418, &[78]), (11422, &[79]),
+  (11423, &[111]), (11424, &[928]), (11426, &[80]), (11427, &[112]),
+  (11428, &[67]), (11429, &[99]), (11430, &[84]), (11432, &[89]),
+  (11434, &[934]), (11435, &[632]), (11436, &[88]), (11437, &[967]),
+  (11438, &[936]), (11441, &[969]), (11444, &[60, 183]), (11450, &[45]),
+  (11452, &[1064]), (11453, &[1096]), (11462, &[47]), (11466, &[57]),
+  (11468, &[51]), (11469, &[541]), (11472, &[76]), (11473, &[671]),
+  (11474, &[54]), (11484, &[1004]), (11492, &[983]), (11497, &[9767]),
+  (11513, &[92, 92]), (11569, &[79, 821]), (11575, &[581]), (11576, &[86]),
+  (11577, &[69]), (11578, &[398]), (11585, &[79, 824]),
+  (11592, &[183, 183, 183]), (11593, &[425]), (11599, &[108]), (11601, &[33]),
+  (11604, &[79]), (11605, &[81]), (11609, &[664]), (11613, &[88]),
+  (11616, &[916]), (11619, &[5871]), (11752, &[7647]), (11754, &[778]),
+  (11757, &[872]), (11759, &[879]), (11766, &[867]), (11767, &[868]),
+  (11802, &[45, 776]), (11806, &[126, 775]), (11807, &[126, 803]),
+  (11814, &[5205]), (11815, &[5200]), (11816, &[40, 40]), (11817, &[41, 41]),
+  (11818, &[8757]), (11819, &[8756]), (11820, &[8759]), (11822, &[1567]),
+  (11824, &[176]), (11825, &[183]), (11826, &[1548]), (11829, &[1563]),
+  (11833, &[7839]), (11837, &[11586]), (11839, &[182]), (11840, &[61]),
+  (11906, &[20059]), (11907, &[20058]), (11909, &[20155]), (11913, &[20994]),
+  (11915, &[13630]), (11918, &[20800]), (11919, &[23587]), (11920, &[23586]),
+  (11922, &[24051]), (11923, &[24186]), (11924, &[24401]), (11926, &[24516]),
+  (11927, &[14586]), (11928, &[25164]), (11929, &[25909]), (11931, &[26081]),
+  (11934, &[27514]), (11935, &[27597]), (11936, &[27665]), (11937, &[27701]),
+  (11938, &[27706]), (11939, &[28780]), (11940, &[29227]), (11942, &[20012]),
+  (11944, &[29357]), (11947, &[32594]), (11949, &[31035]), (11951, &[31993]),
+  (11953, &[32595]), (11954, &[32594]), (11961, &[32770]), (11962, &[32896]),
+  (11966, &[33401]), (11967, &[33401]), (11968, &[33401]), (11969, &[34382]),
+  (11970, &[34916]), (11971, &[35200]), (11972, &[35199]), (11973, &[35265]),
+  (11976, &[35744]), (11977, &[36125]), (11979, &[36710]), (11980, &[36790]),
+  (11981, &[36790]), (11983, &[38429]), (11984, &[38021]), (11985, &[38263]),
+  (11986, &[38264]), (11987, &[38271]), (11988, &[38376]), (11990, &[38429]),
+  (11992, &[38738]), (11993, &[38886]), (11994, &[39029]), (11995, &[39118]),
+  (11996, &[39134]), (11997, &[39135]), (11999, &[39136]), (12000, &[39267]),
+  (12002, &[39532]), (12004, &[39740]), (12005, &[40060]), (12008, &[40614]),
+  (12009, &[40644]), (12011, &[25993]), (12012, &[40784]), (12013, &[27503]),
+  (12014, &[40831]), (12015, &[31452]), (12016, &[40857]), (12018, &[20096]),
+  (12019, &[40863]), (12032, &[12540]), (12033, &[20008]), (12034, &[92]),
+  (12035, &[47]), (12036, &[20057]), (12037, &[20101]), (12038, &[20108]),
+  (12039, &[20128]), (12040, &[20154]), (12041, &[20799]), (12042, &[20837]),
+  (12043, &[20843]), (12044, &[20866]), (12045, &[20886]), (12046, &[20907]),
+  (12047, &[20960]), (12048, &[20981]), (12049, &[20992]), (12050, &[21147]),
+  (12051, &[21241]), (12052, &[21269]), (12053, &[21274]), (12054, &[21304]),
+  (12055, &[21313]), (12056, &[21340]), (12057, &[21353]), (12058, &[21378]),
+  (12059, &[21430]), (12060, &[21448]), (12061, &[21475]), (12062, &[21475]),
+  (12063, &[22303]), (12064, &[22303]), (12065, &[22786]), (12066, &[22794]),
+  (12067, &[22805]), (12068, &[22823]), (12069, &[22899]), (12070, &[23376]),
+  (12071, &[23424]), (12072, &[23544]), (12073, &[23567]), (12074, &[23586]),
+  (12075, &[23608]), (12076, &[23662]), (12077, &[23665]), (12078, &[24027]),
+  (12079, &[24037]), (12080, &[24049]), (12081, &[24062]), (12082, &[24178]),
+  (12083, &[24186]), (12084, &[24191]), (12085, &[24308]), (12086, &[24318]),
+  (12087, &[24331]), (12088, &[24339]), (12089, &[24400]), (12090, &[24417]),
+  (12091, &[24435]), (12092, &[24515]), (12093, &[25096]), (12094, &[25142]),
+  (12095, &[25163]), (12096, &[25903]), (12097, &[25908]), (12098, &[25991]),
+  (12099, &[26007]), (12100, &[26020]), (12101, &[26041]), (12102, &[26080]),
+  (12103, &[26085]), (12104, &[26352]), (12105, &[26376]), (12106, &[26408]),
+  (12107, &[27424]), (12108, &[27490]), (12109, &[27513]), (12110, &[27571]),
+  (12111, &[27595]), (12112, &[27604]), (12113, &[27611]), (12114, &[27663]),
+  (12115, &[27668]), (12116, &[27700]), (12117, &[28779]), (12118, &[29226]),
+  (12119, &[29238]), (12120, &[29243]), (12121, &[29247]), (12122, &[29255]),
+  (12123, &[29273]), (12124, &[29275]), (12125, &[29356]), (12126, &[29572]),
+  (12127, &[29577]), (12128, &[29916]), (12129, &[29926]), (12130, &[29976]),
+  (12131, &[29983]), (12132, &[29992]), (12133, &[30000]), (12134, &[30091]),
+  (12135, &[30098]), (12136, &[30326]), (12137, &[30333]), (12138, &[30382]),
+  (12139, &[30399]), (12140, &[30446]), (12141, &[30683]), (12142, &[30690]),
+  (12143, &[30707]), (12144, &[31034]), (12145, &[31160]), (12146, &[31166]),
+  (12147, &[31348]), (12148, &[31435]), (12149, &[31481]), (12150, &[31859]),
+  (12151, &[31992]), (12152, &[32566]), (12153, &[32593]), (12154, &[32650]),
+  (12155, &[32701]), (12156, &[32769]), (12157, &[32780]), (12158, &[32786]),
+  (12159, &[32819]), (12160, &[32895]), (12161, &[32905]), (12162, &[33251]),
+  (12163, &[33258]), (12164, &[33267]), (12165, &[33276]), (12166, &[33292]),
+  (12167, &[33307]), (12168, &[33311]), (12169, &[33390]), (12170, &[33394]),
+  (12171, &[33400]), (12172, &[34381]), (12173, &[34411]), (12174, &[34880]),
+  (12175, &[34892]), (12176, &[34915]), (12177, &[35198]), (12178, &[35211]),
+  (12179, &[35282]), (12180, &[35328]), (12181, &[35895]), (12182, &[35910]),
+  (12183, &[35925]), (12184, &[35960]), (12185, &[35997]), (12186, &[36196]),
+  (12187, &[36208]), (12188, &[36275]), (12189, &[36523]), (12190, &[36554]),
+  (12191, &[36763]), (12192, &[36784]), (12193, &[36789]), (12194, &[37009]),
+  (12195, &[37193]), (12196, &[37318]), (12197, &[37324]), (12198, &[37329]),
+  (12199, &[38263]), (12200, &[38272]), (12201, &[38428]), (12202, &[38582]),
+  (12203, &[38585]), (12204, &[38632]), (12205, &[38737]), (12206, &[38750]),
+  (12207, &[38754]), (12208, &[38761]), (12209, &[38859]), (12210, &[38893]),
+  (12211, &[38899]), (12212, &[38913]), (12213, &[39080]), (12214, &[39131]),
+  (12215, &[39135]), (12216, &[39318]), (12217, &[39321]), (12218, &[39340]),
+  (12219, &[39592]), (12220, &[39640]), (12221, &[39647]), (12222, &[39717]),
+  (12223, &[39727]), (12224, &[39730]), (12225, &[39740]), (12226, &[39770]),
+  (12227, &[40165]), (12228, &[40565]), (12229, &[40575]), (12230, &[40613]),
+  (12231, &[40635]), (12232, &[40643]), (12233, &[40653]), (12234, &[40657]),
+  (12235, &[40697]), (12236, &[40701]), (12237, &[40718]), (12238, &[40723]),
+  (12239, &[40736]), (12240, &[40763]), (12241, &[40778]), (12242, &[40786]),
+  (12243, &[40845]), (12244, &[40860]), (12245, &[40864]), (12290, &[755]),
+  (12291, &[39, 39]), (12295, &[79]), (12296, &[10092]), (12297, &[10093]),
+  (12306, &[8376]), (12308, &[40]), (12309, &[41]), (12314, &[10214]),
+  (12315, &[10215]), (12332, &[777]), (12333, &[805]), (12339, &[47]),
+  (12342, &[8376]), (12344, &[21313]), (12345, &[21316]), (12346, &[21317]),
+  (12367, &[10092]), (12442, &[778]), (12443, &[65438]), (12444, &[65439]),
+  (12448, &[61]), (12452, &[20155]), (12456, &[24037]), (12459, &[21147]),
+  (12479, &[22805]), (12488, &[21340]), (12491, &[20108]), (12494, &[47]),
+  (12495, &[20843]), (12504, &[12408]), (12525, &[21475]), (12539, &[183]),
+  (12582, &[20799]), (12593, &[4352]), (12594, &[4352, 4352]),
+  (12595, &[4352, 4361]), (12596, &[4354]), (12597, &[4354, 4364]),
+  (12598, &[4354, 4370]), (12599, &[4355]), (12600, &[4355, 4355]),
+  (12601, &[4357]), (12602, &[4357, 4352]), (12603, &[4357, 4358]),
+  (12604, &[4357, 4359]), (12605, &[4357, 4361]), (12606, &[4357, 4368]),
+  (12607, &[4357, 4369]), (12608, &[4357, 4370]), (12609, &[4358]),
+  (12610, &[4359]), (12611, &[4359, 4359]), (12612, &[4359, 4361]),
+  (12613, &[4361]), (12614, &[4361, 4361]), (12615, &[4363]), (12616, &[4364]),
+  (12617, &[4364, 4364]), (12618, &[4366]), (12619, &[4367]), (12620, &[4368]),
+  (12621, &[4369]), (12622, &[4370]), (12623, &[4449]),
+  (12624, &[4449, 20008]), (12625, &[4451]), (12626, &[4451, 20008]),
+  (12627, &[4453]), (12628, &[4453, 20008]), (12629, &[4455]),
+  (12630, &[4455, 20008]), (12631, &[4457]), (12632, &[4457, 4449]),
+  (12633, &[4457, 4449, 20008]), (12634, &[4457, 20008]), (12635, &[4461]),
+  (12636, &[4462]), (12637, &[4462, 4453]), (12638, &[4462, 4453, 20008]),
+  (12639, &[4462, 20008]), (12640, &[4466]), (12641, &[12540]),
+  (12642, &[12540, 20008]), (12643, &[20008]), (12644, &[4448]),
+  (12645, &[4354, 4354]), (12646, &[4354, 4355]), (12647, &[4354, 4361]),
+  (12648, &[4354, 4416]), (12649, &[4357, 4352, 4361]), (12650, &[4357, 4355]),
+  (12651, &[4357, 4359, 4361]), (12652, &[4357, 4416]), (12653, &[4357, 4441]),
+  (12654, &[4358, 4359]), (12655, &[4358, 4361]), (12656, &[4358, 4416]),
+  (12657, &[4358, 4363]), (12658, &[4359, 4352]), (12659, &[4359, 4355]),
+  (12660, &[4359, 4361, 4352]), (12661, &[4359, 4361, 4355]),
+  (12662, &[4359, 4364]), (12663, &[4359, 4368]), (12664, &[4359, 4363]),
+  (12665, &[4359, 4359, 4363]), (12666, &[4361, 4352]), (12667, &[4361, 4354]),
+  (12668, &[4361, 4355]), (12669, &[4361, 4359]), (12670, &[4361, 4364]),
+  (12671, &[4416]), (12672, &[4363, 4363]), (12673, &[4428]),
+  (12674, &[4363, 4361]), (12675, &[4363, 4416]), (12676, &[4369, 4363]),
+  (12677, &[4370, 4370]), (12678, &[4441]), (12679, &[4461, 4451]),
+  (12680, &[4461, 4451, 20008]), (12681, &[4461, 20008]),
+  (12682, &[4466, 4455]), (12683, &[4466, 4455, 20008]),
+  (12684, &[4466, 20008]), (12685, &[4510]), (12686, &[4510, 20008]),
+  (12752, &[12540]), (12753, &[20008]), (12755, &[47]), (12756, &[92]),
+  (12758, &[20059]), (12762, &[20101]), (12763, &[10092]), (12767, &[20058]),
+  (12768, &[20057]), (12800, &[40, 4352, 41]), (12801, &[40, 4354, 41]),
+  (12802, &[40, 4355, 41]), (12803, &[40, 4357, 41]), (12804, &[40, 4358, 41]),
+  (12805, &[40, 4359, 41]), (12806, &[40, 4361, 41]), (12807, &[40, 4363, 41]),
+  (12808, &[40, 4364, 41]), (12809, &[40, 4366, 41]), (12810, &[40, 4367, 41]),
+  (12811, &[40, 4368, 41]), (12812, &[40, 4369, 41]), (12813, &[40, 4370, 41]),
+  (12814, &[40, 44032, 41]), (12815, &[40, 45208, 41]),
+  (12816, &[40, 45796, 41]), (12817, &[40, 46972, 41]),
+  (12818, &[40, 47560, 41]), (12819, &[40, 48148, 41]),
+  (12820, &[40, 49324, 41]), (12821, &[40, 50500, 41]),
+  (12822, &[40, 51088, 41]), (12823, &[40, 52264, 41]),
+  (12824, &[40, 52852, 41]), (12825, &[40, 53440, 41]),
+  (12826, &[40, 54028, 41]), (12827, &[40, 54616, 41]),
+  (12828, &[40, 51452, 41]), (12829, &[40, 50724, 51204, 41]),
+  (12830, &[40, 50724, 54980, 41]), (12832, &[40, 12540, 41]),
+  (12833, &[40, 20108, 41]), (12834, &[40, 19977, 41]),
+  (12835, &[40, 22235, 41]), (12836, &[40, 20116, 41]),
+  (12837, &[40, 20845, 41]), (12838, &[40, 19971, 41]),
+  (12839, &[40, 20843, 41]), (12840, &[40, 20061, 41]),
+  (12841, &[40, 21313, 41]), (12842, &[40, 26376, 41]),
+  (12843, &[40, 28779, 41]), (12844, &[40, 27700, 41]),
+  (12845, &[40, 26408, 41]), (12846, &[40, 37329, 41]),
+  (12847, &[40, 22303, 41]), (12848, &[40, 26085, 41]),
+  (12849, &[40, 26666, 41]), (12850, &[40, 26377, 41]),
+  (12851, &[40, 31038, 41]), (12852, &[40, 21517, 41]),
+  (12853, &[40, 29305, 41]), (12854, &[40, 36001, 41]),
+  (12855, &[40, 31069, 41]), (12856, &[40, 21172, 41]),
+  (12857, &[40, 20195, 41]), (12858, &[40, 21628, 41]),
+  (12859, &[40, 23398, 41]), (12860, &[40, 30435, 41]),
+  (12861, &[40, 20225, 41]), (12862, &[40, 36039, 41]),
+  (12863, &[40, 21332, 41]), (12864, &[40, 31085, 41]),
+  (12865, &[40, 20241, 41]), (12866, &[40, 33258, 41]),
+  (12867, &[40, 33267, 41]), (12992, &[108, 26376]), (12993, &[50, 26376]),
+  (12994, &[51, 26376]), (12995, &[52, 26376]), (12996, &[53, 26376]),
+  (12997, &[54, 26376]), (12998, &[55, 26376]), (12999, &[56, 26376]),
+  (13000, &[57, 26376]), (13001, &[108, 79, 26376]),
+  (13002, &[108, 108, 26376]), (13003, &[108, 50, 26376]),
+  (13144, &[79, 28857]), (13145, &[108, 28857]), (13146, &[50, 28857]),
+  (13147, &[51, 28857]), (13148, &[52, 28857]), (13149, &[53, 28857]),
+  (13150, &[54, 28857]), (13151, &[55, 28857]), (13152, &[56, 28857]),
+  (13153, &[57, 28857]), (13154, &[108, 79, 28857]),
+  (13155, &[108, 108, 28857]), (13156, &[108, 50, 28857]),
+  (13157, &[108, 51, 28857]), (13158, &[108, 52, 28857]),
+  (13159, &[108, 53, 28857]), (13160, &[108, 54, 28857]),
+  (13161, &[108, 55, 28857]), (13162, &[108, 56, 28857]),
+  (13163, &[108, 57, 28857]), (13164, &[50, 79, 28857]),
+  (13165, &[50, 108, 28857]), (13166, &[50, 50, 28857]),
+  (13167, &[50, 51, 28857]), (13168, &[50, 52, 28857]), (13280, &[108, 26085]),
+  (13281, &[50, 26085]), (13282, &[51, 26085]), (13283, &[52, 26085]),
+  (13284, &[53, 26085]), (13285, &[54, 26085]), (13286, &[55, 26085]),
+  (13287, &[56, 26085]), (13288, &[57, 26085]), (13289, &[108, 79, 26085]),
+  (13290, &[108, 108, 26085]), (13291, &[108, 50, 26085]),
+  (13292, &[108, 51, 26085]), (13293, &[108, 52, 26085]),
+  (13294, &[108, 53, 26085]), (13295, &[108, 54, 26085]),
+  (13296, &[108, 55, 26085]), (13297, &[108, 56, 26085]),
+  (13298, &[108, 57, 26085]), (13299, &[50, 79, 26085]),
+  (13300, &[50, 108, 26085]), (13301, &[50, 50, 26085]),
+  (13302, &[50, 51, 26085]), (13303, &[50, 52, 26085]),
+  (13304, &[50, 53, 26085]), (13305, &[50, 54, 26085]),
+  (13306, &[50, 55, 26085]), (13307, &[50, 56, 26085]),
+  (13308, &[50, 57, 26085]), (13309, &[51, 79, 26085]),
+  (13310, &[51, 108, 26085]), (14771, &[13885]), (17307, &[13704]),
+  (17440, &[15163]), (19968, &[12540]), (20022, &[92]), (20031, &[47]),
+  (20482, &[20341]), (20540, &[20516]), (21855, &[21843]), (22231, &[21475]),
+  (22635, &[22625]), (22763, &[22303]), (22783, &[22699]), (23296, &[23215]),
+  (24114, &[24097]), (24144, &[15162]), (25144, &[25142]), (25609, &[14913]),
+  (26211, &[16447]), (26217, &[26202]), (26358, &[15066]), (26406, &[17475]),
+  (26623, &[26478]), (27113, &[15267]), (27175, &[27037]), (28505, &[28296]),
+  (30799, &[30740]), (32118, &[32085]), (32934, &[26380]), (32970, &[26384]),
+  (32976, &[26383]), (33014, &[15157]), (33025, &[26387]), (33063, &[26392]),
+  (33089, &[33020]), (33191, &[26403]), (34111, &[33933]), (34369, &[34359]),
+  (35358, &[18102]), (35453, &[35374]), (35727, &[35718]), (35939, &[35932]),
+  (36230, &[36223]), (36346, &[36325]), (36507, &[36503]), (36647, &[36607]),
+  (37086, &[37070]), (37806, &[37805]), (38584, &[38583]), (40515, &[40514]),
+  (40658, &[40657]), (40899, &[16441]), (42132, &[41677]), (42140, &[41152]),
+  (42142, &[41034]), (42151, &[42072]), (42152, &[41266]), (42156, &[41040]),
+  (42160, &[41922]), (42170, &[41919]), (42174, &[41649]), (42175, &[41561]),
+  (42176, &[41899]), (42178, &[41909]), (42192, &[66]), (42193, &[80]),
+  (42194, &[100]), (42195, &[68]), (42196, &[84]), (42198, &[71]),
+  (42199, &[75]), (42201, &[74]), (42202, &[67]), (42203, &[390]),
+  (42204, &[90]), (42205, &[70]), (42206, &[8498]), (42207, &[77]),
+  (42208, &[78]), (42209, &[76]), (42210, &[83]), (42211, &[82]),
+  (42213, &[581]), (42214, &[86]), (42215, &[72]), (42218, &[87]),
+  (42219, &[88]), (42220, &[89]), (42221, &[5728]), (42222, &[65]),
+  (42223, &[11375]), (42224, &[69]), (42225, &[398]), (42226, &[108]),
+  (42227, &[79]), (42228, &[85]), (42229, &[1352]), (42231, &[5601]),
+  (42232, &[46]), (42233, &[44]), (42234, &[46, 46]), (42235, &[46, 44]),
+  (42237, &[58]), (42238, &[45, 46]), (42239, &[61]), (42510, &[46]),
+  (42564, &[50]), (42565, &[424]), (42567, &[105]), (42573, &[969]),
+  (42576, &[1066, 108]), (42577, &[713, 98, 105]), (42600, &[664]),
+  (42607, &[8425]), (42620, &[774]), (42622, &[711]), (42645, &[104, 788]),
+  (42648, &[79, 79]), (42649, &[111, 111]), (42650, &[66216]),
+  (42657, &[1048]), (42672, &[5817]), (42673, &[11381]), (42701, &[673]),
+  (42702, &[581]), (42715, &[928]), (42719, &[86]), (42731, &[63]),
+  (42735, &[50]), (42736, &[770]), (42737, &[772]), (42740, &[42739, 42739]),
+  (42772, &[747]), (42774, &[746]), (42792, &[84, 51]), (42793, &[116, 541]),
+  (42801, &[115]), (42802, &[65, 65]), (42803, &[97, 97]), (42804, &[65, 79]),
+  (42805, &[97, 111]), (42806, &[65, 85]), (42807, &[97, 117]),
+  (42808, &[65, 86]), (42809, &[97, 118]), (42810, &[65, 86]),
+  (42811, &[97, 118]), (42812, &[65, 89]), (42813, &[97, 121]),
+  (42816, &[75, 821]), (42826, &[79, 821]), (42827, &[111, 821]),
+  (42830, &[79, 79]), (42831, &[111, 111]), (42842, &[50]),
+  (42849, &[119, 806]), (42858, &[51]), (42859, &[541]), (42862, &[57]),
+  (42871, &[116, 102]), (42872, &[38]), (42874, &[42873]), (42889, &[58]),
+  (42892, &[39]), (42895, &[183]), (42901, &[42791]), (42904, &[70]),
+  (42905, &[102]), (42906, &[66578]), (42907, &[66618]), (42909, &[666]),
+  (42910, &[42212]), (42911, &[117]), (42923, &[51]), (42929, &[42197]),
+  (42930, &[74]), (42931, &[88]), (42932, &[66]), (42933, &[223]),
+  (42934, &[42572]), (42935, &[969]), (42999, &[12540]), (43056, &[2404]),
+  (43360, &[4355, 4358]), (43361, &[4355, 4359]), (43362, &[4355, 4361]),
+  (43363, &[4355, 4364]), (43364, &[4357, 4352]), (43365, &[4357, 4352, 4352]),
+  (43366, &[4357, 4355]), (43367, &[4357, 4355, 4355]), (43368, &[4357, 4358]),
+  (43369, &[4357, 4359]), (43370, &[4357, 4359, 4359]),
+  (43371, &[4357, 4359, 4363]), (43372, &[4357, 4361]), (43373, &[4357, 4364]),
+  (43374, &[4357, 4367]), (43375, &[4358, 4352]), (43376, &[4358, 4355]),
+  (43377, &[4358, 4361]), (43378, &[4359, 4361, 4368]), (43379, &[4359, 4367]),
+  (43380, &[4359, 4370]), (43381, &[4361, 4361, 4359]), (43382, &[4363, 4357]),
+  (43383, &[4363, 4370]), (43384, &[4364, 4364, 4370]), (43385, &[4368, 4368]),
+  (43386, &[4369, 4370]), (43387, &[4370, 4361]), (43388, &[4441, 4441]),
+  (43410, &[11327]), (43427, &[43421]), (43462, &[43472]), (43471, &[1634]),
+  (43603, &[43521]), (43606, &[43555]), (43826, &[101]), (43829, &[102]),
+  (43837, &[111]), (43838, &[111, 824]), (43839, &[596, 824]),
+  (43841, &[477, 111, 824]), (43842, &[477, 111, 821]), (43847, &[114]),
+  (43848, &[114]), (43853, &[643]), (43854, &[117]), (43858, &[117]),
+  (43859, &[967]), (43861, &[967]), (43866, &[121]), (43872, &[1113]),
+  (43874, &[596, 101]), (43875, &[117, 111]), (43888, &[7429]),
+  (43889, &[640]), (43890, &[7451]), (43892, &[111, 795]), (43893, &[105]),
+  (43898, &[7424]), (43899, &[7434]), (43900, &[7431]), (43902, &[578]),
+  (43904, &[11382]), (43905, &[114]), (43907, &[119]), (43911, &[653]),
+  (43915, &[668]), (43918, &[111, 821]), (43920, &[610]), (43923, &[122]),
+  (43931, &[42899]), (43932, &[117, 821]), (43935, &[389]), (43938, &[640]),
+  (43945, &[118]), (43946, &[115]), (43950, &[671]), (43951, &[99]),
+  (43954, &[7448]), (43958, &[312]), (43963, &[111, 821]),
+  (55216, &[4457, 4455]), (55217, &[4457, 4457, 20008]),
+  (55218, &[4461, 4449]), (55219, &[4461, 4449, 20008]),
+  (55220, &[4461, 4453]), (55221, &[4462, 4455]),
+  (55222, &[4462, 20008, 20008]), (55223, &[4466, 4449, 20008]),
+  (55224, &[4466, 4457]), (55225, &[12540, 4449]), (55226, &[12540, 4453]),
+  (55227, &[12540, 4453, 20008]), (55228, &[12540, 4457]),
+  (55229, &[20008, 4451, 4457]), (55230, &[20008, 4451, 20008]),
+  (55231, &[20008, 4455]), (55232, &[20008, 4455, 20008]),
+  (55233, &[20008, 4457, 20008]), (55234, &[20008, 4461]),
+  (55235, &[20008, 4466]), (55236, &[20008, 20008]), (55237, &[4510, 4449]),
+  (55238, &[4510, 4453, 20008]), (55243, &[4354, 4357]),
+  (55244, &[4354, 4366]), (55245, &[4355, 4355]), (55246, &[4355, 4355, 4359]),
+  (55247, &[4355, 4359]), (55248, &[4355, 4361]), (55249, &[4355, 4361, 4352]),
+  (55250, &[4355, 4364]), (55251, &[4355, 4366]), (55252, &[4355, 4368]),
+  (55253, &[4357, 4352, 4352]), (55254, &[4357, 4352, 4370]),
+  (55255, &[4357, 4357, 4367]), (55256, &[4357, 4358, 4370]),
+  (55257, &[4357, 4359, 4355]), (55258, &[4357, 4359, 4369]),
+  (55259, &[4357, 4428]), (55260, &[4357, 4441, 4370]), (55261, &[4357, 4363]),
+  (55262, &[4358, 4354]), (55263, &[4358, 4354, 4354]), (55264, &[4358, 4358]),
+  (55265, &[4358, 4359, 4361]), (55266, &[4358, 4364]), (55267, &[4359, 4355]),
+  (55268, &[4359, 4357, 4369]), (55269, &[4359, 4358]), (55270, &[4359, 4359]),
+  (55271, &[4359, 4361, 4355]), (55272, &[4359, 4364]), (55273, &[4359, 4366]),
+  (55274, &[4361, 4358]), (55275, &[4361, 4359, 4363]),
+  (55276, &[4361, 4361, 4352]), (55277, &[4361, 4361, 4355]),
+  (55278, &[4361, 4416]), (55279, &[4361, 4364]), (55280, &[4361, 4366]),
+  (55281, &[4361, 4368]), (55282, &[4357, 4370]), (55283, &[4416, 4359]),
+  (55284, &[4416, 4359, 4363]), (55285, &[4428, 4358]), (55286, &[4428, 4370]),
+  (55287, &[4364, 4359]), (55288, &[4364, 4359, 4359]), (55289, &[4364, 4364]),
+  (55290, &[4369, 4361]), (55291, &[4369, 4368]), (63744, &[35912]),
+  (63745, &[26356]), (63746, &[36554]), (63747, &[36040]), (63748, &[28369]),
+  (63749, &[20018]), (63750, &[21477]), (63751, &[40860]), (63752, &[40860]),
+  (63753, &[22865]), (63754, &[37329]), (63755, &[21895]), (63756, &[22856]),
+  (63757, &[25078]), (63758, &[30313]), (63759, &[32645]), (63760, &[34367]),
+  (63761, &[34746]), (63762, &[35064]), (63763, &[37007]), (63764, &[27138]),
+  (63765, &[27931]), (63766, &[28889]), (63767, &[29662]), (63768, &[33853]),
+  (63769, &[37226]), (63770, &[39409]), (63771, &[20098]), (63772, &[21365]),
+  (63773, &[27396]), (63774, &[29211]), (63775, &[34349]), (63776, &[40478]),
+  (63777, &[23888]), (63778, &[28651]), (63779, &[34253]), (63780, &[35172]),
+  (63781, &[25289]), (63782, &[33240]), (63783, &[34847]), (63784, &[24266]),
+  (63785, &[26391]), (63786, &[28010]), (63787, &[29436]), (63788, &[37070]),
+  (63789, &[20358]), (63790, &[20919]), (63791, &[21214]), (63792, &[25796]),
+  (63793, &[27347]), (63794, &[29200]), (63795, &[30439]), (63796, &[32769]),
+  (63797, &[34310]), (63798, &[34396]), (63799, &[36335]), (63800, &[38706]),
+  (63801, &[39791]), (63802, &[40442]), (63803, &[30860]), (63804, &[31103]),
+  (63805, &[32160]), (63806, &[33737]), (63807, &[37636]), (63808, &[40575]),
+  (63809, &[35542]), (63810, &[22751]), (63811, &[24324]), (63812, &[31840]),
+  (63813, &[32894]), (63814, &[29282]), (63815, &[30922]), (63816, &[36034]),
+  (63817, &[38647]), (63818, &[22744]), (63819, &[23650]), (63820, &[27155]),
+  (63821, &[28122]), (63822, &[28431]), (63823, &[32047]), (63824, &[32311]),
+  (63825, &[38475]), (63826, &[21202]), (63827, &[32907]), (63828, &[20956]),
+  (63829, &[20940]), (63830, &[31260]), (63831, &[32190]), (63832, &[33777]),
+  (63833, &[38517]), (63834, &[35712]), (63835, &[25295]), (63836, &[27138]),
+  (63837, &[35582]), (63838, &[20025]), (63839, &[23527]), (63840, &[24594]),
+  (63841, &[29575]), (63842, &[30064]), (63843, &[21271]), (63844, &[30971]),
+  (63845, &[20415]), (63846, &[24489]), (63847, &[19981]), (63848, &[27852]),
+  (63849, &[25976]), (63850, &[32034]), (63851, &[21443]), (63852, &[22622]),
+  (63853, &[30465]), (63854, &[33865]), (63855, &[35498]), (63856, &[27578]),
+  (63857, &[36784]), (63858, &[27784]), (63859, &[25342]), (63860, &[33509]),
+  (63861, &[25504]), (63862, &[30053]), (63863, &[20142]), (63864, &[20841]),
+  (63865, &[20937]), (63866, &[26753]), (63867, &[31975]), (63868, &[33391]),
+  (63869, &[35538]), (63870, &[37327]), (63871, &[21237]), (63872, &[21570]),
+  (63873, &[22899]), (63874, &[24300]), (63875, &[26053]), (63876, &[28670]),
+  (63877, &[31018]), (63878, &[38317]), (63879, &[39530]), (63880, &[40599]),
+  (63881, &[40654]), (63882, &[21147]), (63883, &[26310]), (63884, &[27511]),
+  (63885, &[36706]), (63886, &[24180]), (63887, &[24976]), (63888, &[25088]),
+  (63889, &[25754]), (63890, &[28451]), (63891, &[29001]), (63892, &[29833]),
+  (63893, &[31178]), (63894, &[32244]), (63895, &[32879]), (63896, &[36646]),
+  (63897, &[34030]), (63898, &[36899]), (63899, &[37706]), (63900, &[21015]),
+  (63901, &[21155]), (63902, &[21693]), (63903, &[28872]), (63904, &[35010]),
+  (63905, &[35498]), (63906, &[24265]), (63907, &[24565]), (63908, &[25467]),
+  (63909, &[27566]), (63910, &[31806]), (63911, &[29557]), (63912, &[20196]),
+  (63913, &[22265]), (63914, &[23527]), (63915, &[23994]), (63916, &[24604]),
+  (63917, &[29618]), (63918, &[29801]), (63919, &[32666]), (63920, &[32838]),
+  (63921, &[37428]), (63922, &[38646]), (63923, &[38728]), (63924, &[38936]),
+  (63925, &[20363]), (63926, &[31150]), (63927, &[37300]), (63928, &[38583]),
+  (63929, &[24801]), (63930, &[20102]), (63931, &[20698]), (63932, &[23534]),
+  (63933, &[23615]), (63934, &[26009]), (63935, &[27138]), (63936, &[29134]),
+  (63937, &[30274]), (63938, &[34044]), (63939, &[36988]), (63940, &[40845]),
+  (63941, &[26248]), (63942, &[38446]), (63943, &[21129]), (63944, &[26491]),
+  (63945, &[26611]), (63946, &[27969]), (63947, &[28316]), (63948, &[29705]),
+  (63949, &[30041]), (63950, &[30827]), (63951, &[32016]), (63952, &[39006]),
+  (63953, &[20845]), (63954, &[25134]), (63955, &[38520]), (63956, &[20523]),
+  (63957, &[23833]), (63958, &[28138]), (63959, &[36650]), (63960, &[24459]),
+  (63961, &[24900]), (63962, &[26647]), (63963, &[29575]), (63964, &[38534]),
+  (63965, &[21033]), (63966, &[21519]), (63967, &[23653]), (63968, &[26131]),
+  (63969, &[26446]), (63970, &[26792]), (63971, &[27877]), (63972, &[29702]),
+  (63973, &[30178]), (63974, &[32633]), (63975, &[35023]), (63976, &[35041]),
+  (63977, &[37324]), (63978, &[38626]), (63979, &[21311]), (63980, &[28346]),
+  (63981, &[21533]), (63982, &[29136]), (63983, &[29848]), (63984, &[34298]),
+  (63985, &[38563]), (63986, &[40023]), (63987, &[40607]), (63988, &[26519]),
+  (63989, &[28107]), (63990, &[33256]), (63991, &[31435]), (63992, &[31520]),
+  (63993, &[31890]), (63994, &[29376]), (63995, &[28825]), (63996, &[35672]),
+  (63997, &[20160]), (63998, &[33590]), (63999, &[21050]), (64000, &[20999]),
+  (64001, &[24230]), (64002, &[25299]), (64003, &[31958]), (64004, &[23429]),
+  (64005, &[27934]), (64006, &[26292]), (64007, &[36667]), (64008, &[34892]),
+  (64009, &[38477]), (64010, &[35211]), (64011, &[24275]), (64012, &[20800]),
+  (64013, &[21952]), (64016, &[22618]), (64018, &[26228]), (64021, &[20958]),
+  (64022, &[29482]), (64023, &[30410]), (64024, &[31036]), (64025, &[31070]),
+  (64026, &[31077]), (64027, &[31119]), (64028, &[38742]), (64029, &[31934]),
+  (64030, &[32701]), (64032, &[34322]), (64034, &[35576]), (64037, &[36920]),
+  (64038, &[37117]), (64042, &[39151]), (64043, &[39164]), (64044, &[39208]),
+  (64045, &[40372]), (64046, &[37070]), (64047, &[38583]), (64048, &[20398]),
+  (64049, &[20711]), (64050, &[20813]), (64051, &[21193]), (64052, &[21220]),
+  (64053, &[21329]), (64054, &[21917]), (64055, &[22022]), (64056, &[22120]),
+  (64057, &[22592]), (64058, &[22696]), (64059, &[23652]), (64060, &[23662]),
+  (64061, &[24724]), (64062, &[24936]), (64063, &[24974]), (64064, &[25074]),
+  (64065, &[25935]), (64066, &[26082]), (64067, &[26257]), (64068, &[26757]),
+  (64069, &[28023]), (64070, &[28186]), (64071, &[28450]), (64072, &[29038]),
+  (64073, &[29227]), (64074, &[29730]), (64075, &[30865]), (64076, &[31038]),
+  (64077, &[31049]), (64078, &[31048]), (64079, &[31056]), (64080, &[31062]),
+  (64081, &[31069]), (64082, &[31117]), (64083, &[31118]), (64084, &[31296]),
+  (64085, &[31361]), (64086, &[31680]), (64087, &[32244]), (64088, &[32265]),
+  (64089, &[32321]), (64090, &[32626]), (64091, &[32773]), (64092, &[33261]),
+  (64093, &[33401]), (64094, &[33401]), (64095, &[33879]), (64096, &[35088]),
+  (64097, &[35222]), (64098, &[35585]), (64099, &[35641]), (64100, &[36051]),
+  (64101, &[36104]), (64102, &[36790]), (64103, &[36920]), (64104, &[38627]),
+  (64105, &[38911]), (64106, &[38971]), (64107, &[24693]), (64108, &[148206]),
+  (64109, &[33304]), (64112, &[20006]), (64113, &[20917]), (64114, &[20840]),
+  (64115, &[20352]), (64116, &[20805]), (64117, &[20864]), (64118, &[21191]),
+  (64119, &[21242]), (64120, &[21917]), (64121, &[21845]), (64122, &[21913]),
+  (64123, &[21986]), (64124, &[22618]), (64125, &[22707]), (64126, &[22852]),
+  (64127, &[22868]), (64128, &[23138]), (64129, &[23336]), (64130, &[24274]),
+  (64131, &[24281]), (64132, &[24425]), (64133, &[24493]), (64134, &[24792]),
+  (64135, &[24910]), (64136, &[24840]), (64137, &[24974]), (64138, &[24928]),
+  (64139, &[25074]), (64140, &[25140]), (64141, &[25540]), (64142, &[25628]),
+  (64143, &[25682]), (64144, &[25942]), (64145, &[26228]), (64146, &[26391]),
+  (64147, &[26395]), (64148, &[26454]), (64149, &[27513]), (64150, &[27578]),
+  (64151, &[27969]), (64152, &[28379]), (64153, &[28363]), (64154, &[28450]),
+  (64155, &[28702]), (64156, &[29038]), (64157, &[30631]), (64158, &[29237]),
+  (64159, &[29359]), (64160, &[29482]), (64161, &[29809]), (64162, &[29958]),
+  (64163, &[30011]), (64164, &[30237]), (64165, &[30239]), (64166, &[30410]),
+  (64167, &[30427]), (64168, &[30452]), (64169, &[30538]), (64170, &[30528]),
+  (64171, &[30924]), (64172, &[31409]), (64173, &[31680]), (64174, &[31867]),
+  (64175, &[32091]), (64176, &[32244]), (64177, &[32574]), (64178, &[32773]),
+  (64179, &[33618]), (64180, &[33775]), (64181, &[34681]), (64182, &[35137]),
+  (64183, &[35206]), (64184, &[35222]), (64185, &[35519]), (64186, &[35576]),
+  (64187, &[35531]), (64188, &[35585]), (64189, &[35582]), (64190, &[35565]),
+  (64191, &[35641]), (64192, &[35722]), (64193, &[36104]), (64194, &[36664]),
+  (64195, &[36978]), (64196, &[37273]), (64197, &[37494]), (64198, &[38524]),
+  (64199, &[38627]), (64200, &[38742]), (64201, &[38875]), (64202, &[38911]),
+  (64203, &[38923]), (64204, &[38971]), (64205, &[39698]), (64206, &[40860]),
+  (64207, &[141386]), (64208, &[141380]), (64209, &[144341]),
+  (64210, &[15261]), (64211, &[16408]), (64212, &[16441]), (64213, &[152137]),
+  (64214, &[154832]), (64215, &[163539]), (64216, &[40771]), (64217, &[40846]),
+  (64256, &[102, 102]), (64257, &[102, 105]), (64258, &[102, 108]),
+  (64259, &[102, 102, 105]), (64260, &[102, 102, 108]), (64262, &[115, 116]),
+  (64275, &[1396, 1398]), (64276, &[1396, 1381]), (64277, &[1396, 1387]),
+  (64278, &[1406, 1398]), (64279, &[1396, 1389]), (64288, &[1506]),
+  (64289, &[1488]), (64290, &[1491]), (64291, &[1492]), (64292, &[1499]),
+  (64293, &[1500]), (64294, &[1501]), (64295, &[1512]), (64296, &[1514]),
+  (64297, &[45, 775]), (64299, &[64298]), (64301, &[64300]), (64303, &[64302]),
+  (64304, &[64302]), (64313, &[64285]), (64329, &[64298]),
+  (64335, &[1488, 1500]), (64336, &[1649]), (64337, &[1649]), (64338, &[1659]),
+  (64339, &[1659]), (64340, &[1659]), (64341, &[1659]), (64342, &[1609, 1755]),
+  (64343, &[1609, 1755]), (64344, &[1609, 1755]), (64345, &[1609, 1755]),
+  (64346, &[1664]), (64347, &[1664]), (64348, &[1664]), (64349, &[1664]),
+  (64350, &[1658]), (64351, &[1658]), (64352, &[1658]), (64353, &[1658]),
+  (64354, &[1663]), (64355, &[1663]), (64356, &[1663]), (64357, &[1663]),
+  (64358, &[1609, 1557]), (64359, &[1609, 1557]), (64360, &[1609, 1557]),
+  (64361, &[1609, 1557]), (64362, &[1697, 1755]), (64363, &[1697, 1755]),
+  (64364, &[1697, 1755]), (64365, &[1697, 1755]), (64366, &[1702]),
+  (64367, &[1702]), (64368, &[1702]), (64369, &[1702]), (64370, &[1668]),
+  (64371, &[1668]), (64372, &[1668]), (64373, &[1668]), (64374, &[1667]),
+  (64375, &[1667]), (64376, &[1667]), (64377, &[1667]), (64378, &[1670]),
+  (64379, &[1670]), (64380, &[1670]), (64381, &[1670]), (64382, &[1671]),
+  (64383, &[1671]), (64384, &[1671]), (64385, &[1671]), (64386, &[1677]),
+  (64387, &[1677]), (64388, &[1676]), (64389, &[1676]), (64390, &[1583, 1755]),
+  (64391, &[1583, 1755]), (64392, &[1583, 1557]), (64393, &[1583, 1557]),
+  (64394, &[1585, 1755]), (64395, &[1585, 1755]), (64396, &[1585, 1557]),
+  (64397, &[1585, 1557]), (64398, &[1603]), (64399, &[1603]), (64400, &[1603]),
+  (64401, &[1603]), (64402, &[1711]), (64403, &[1711]), (64404, &[1711]),
+  (64405, &[1711]), (64406, &[1715]), (64407, &[1715]), (64408, &[1715]),
+  (64409, &[1715]), (64410, &[1713]), (64411, &[1713]), (64412, &[1713]),
+  (64413, &[1713]), (64414, &[1609]), (64415, &[1609]), (64416, &[1609, 1557]),
+  (64417, &[1609, 1557]), (64418, &[1609, 1557]), (64419, &[1609, 1557]),
+  (64420, &[1728]), (64421, &[1728]), (64422, &[111]), (64423, &[111]),
+  (64424, &[111]), (64425, &[111]), (64426, &[111]), (64427, &[111]),
+  (64428, &[111]), (64429, &[111]), (64430, &[1609]), (64431, &[1609]),
+  (64432, &[1747]), (64433, &[1747]), (64467, &[1603, 1755]),
+  (64468, &[1603, 1755]), (64469, &[1603, 1755]), (64470, &[1603, 1755]),
+  (64471, &[1608, 787]), (64472, &[1608, 787]), (64473, &[1608, 774]),
+  (64474, &[1608, 774]), (64475, &[1608, 1648]), (64476, &[1608, 1648]),
+  (64477, &[1608, 787, 1652]), (64478, &[1608, 1755]), (64479, &[1608, 1755]),
+  (64480, &[1733]), (64481, &[1733]), (64482, &[1608, 770]),
+  (64483, &[1608, 770]), (64484, &[1659]), (64485, &[1659]), (64486, &[1659]),
+  (64487, &[1659]), (64488, &[1609]), (64489, &[1609]),
+  (64490, &[1609, 1652, 108]), (64491, &[1609, 1652, 108]),
+  (64492, &[1609, 1652, 111]), (64493, &[1609, 1652, 111]),
+  (64494, &[1609, 1652, 1608]), (64495, &[1609, 1652, 1608]),
+  (64496, &[1609, 1652, 1608, 787]), (64497, &[1609, 1652, 1608, 787]),
+  (64498, &[1609, 1652, 1608, 774]), (64499, &[1609, 1652, 1608, 774]),
+  (64500, &[1609, 1652, 1608, 1648]), (64501, &[1609, 1652, 1608, 1648]),
+  (64502, &[1609, 1652, 1659]), (64503, &[1609, 1652, 1659]),
+  (64504, &[1609, 1652, 1659]), (64505, &[1609, 1652, 1609]),
+  (64506, &[1609, 1652, 1609]), (64507, &[1609, 1652, 1609]), (64508, &[1609]),
+  (64509, &[1609]), (64510, &[1609]), (64511, &[1609]),
+  (64512, &[1609, 1652, 1580]), (64513, &[1609, 1652, 1581]),
+  (64514, &[1609, 1652, 1605]), (64515, &[1609, 1652, 1609]),
+  (64516, &[1609, 1652, 1609]), (64517, &[1576, 1580]), (64518, &[1576, 1581]),
+  (64519, &[1576, 1582]), (64520, &[1576, 1605]), (64521, &[1576, 1609]),
+  (64522, &[1576, 1609]), (64523, &[1578, 1580]), (64524, &[1578, 1581]),
+  (64525, &[1578, 1582]), (64526, &[1578, 1605]), (64527, &[1578, 1609]),
+  (64528, &[1578, 1609]), (64529, &[1609, 1755, 1580]),
+  (64530, &[1609, 1755, 1605]), (64531, &[1609, 1755, 1609]),
+  (64532, &[1609, 1755, 1609]), (64533, &[1580, 1581]), (64534, &[1580, 1605]),
+  (64535, &[1581, 1580]), (64536, &[1581, 1605]), (64537, &[1582, 1580]),
+  (64538, &[1582, 1581]), (64539, &[1582, 1605]), (64540, &[1587, 1580]),
+  (64541, &[1587, 1581]), (64542, &[1587, 1582]), (64543, &[1587, 1605]),
+  (64544, &[1589, 1581]), (64545, &[1589, 1605]), (64546, &[1590, 1580]),
+  (64547, &[1590, 1581]), (64548, &[1590, 1582]), (64549, &[1590, 1605]),
+  (64550, &[1591, 1581]), (64551, &[1591, 1605]), (64552, &[1592, 1605]),
+  (64553, &[1593, 1580]), (64554, &[1593, 1605]), (64555, &[1594, 1580]),
+  (64556, &[1594, 1605]), (64557, &[1601, 1580]), (64558, &[1601, 1581]),
+  (64559, &[1601, 1582]), (64560, &[1601, 1605]), (64561, &[1601, 1609]),
+  (64562, &[1601, 1609]), (64563, &[1602, 1581]), (64564, &[1602, 1605]),
+  (64565, &[1602, 1609]), (64566, &[1602, 1609]), (64567, &[1603, 108]),
+  (64568, &[1603, 1580]), (64569, &[1603, 1581]), (64570, &[1603, 1582]),
+  (64571, &[1603, 1604]), (64572, &[1603, 1605]), (64573, &[1603, 1609]),
+  (64574, &[1603, 1609]), (64575, &[1604, 1580]), (64576, &[1604, 1581]),
+  (64577, &[1604, 1582]), (64578, &[1604, 1605]), (64579, &[1604, 1609]),
+  (64580, &[1604, 1609]), (64581, &[1605, 1580]), (64582, &[1605, 1581]),
+  (64583, &[1605, 1582]), (64584, &[1605, 1605]), (64585, &[1605, 1609]),
+  (64586, &[1605, 1609]), (64587, &[1576, 1582]), (64588, &[1606, 1581]),
+  (64589, &[1606, 1582]), (64590, &[1606, 1605]), (64591, &[1606, 1609]),
+  (64592, &[1606, 1609]), (64593, &[111, 1580]), (64594, &[111, 1605]),
+  (64595, &[111, 1609]), (64596, &[111, 1609]), (64597, &[1609, 1580]),
+  (64598, &[1609, 1581]), (64599, &[1609, 1582]), (64600, &[1609, 1605]),
+  (64601, &[1609, 1609]), (64602, &[1609, 1609]), (64603, &[1584, 1648]),
+  (64604, &[1585, 1648]), (64605, &[1609, 1648]), (64606, &[65138, 1617]),
+  (64607, &[65140, 1617]), (64608, &[65142, 1617]), (64609, &[65144, 1617]),
+  (64610, &[65146, 1617]), (64611, &[65148, 1648]),
+  (64612, &[1609, 1652, 1585]), (64613, &[1609, 1652, 1586]),
+  (64614, &[1609, 1652, 1605]), (64615, &[1609, 1652, 1606]),
+  (64616, &[1609, 1652, 1609]), (64617, &[1609, 1652, 1609]),
+  (64618, &[1576, 1585]), (64619, &[1576, 1586]), (64620, &[1576, 1605]),
+  (64621, &[1576, 1606]), (64622, &[1576, 1609]), (64623, &[1576, 1609]),
+  (64624, &[1578, 1585]), (64625, &[1578, 1586]), (64626, &[1578, 1605]),
+  (64627, &[1578, 1606]), (64628, &[1578, 1609]), (64629, &[1578, 1609]),
+  (64630, &[1609, 1755, 1585]), (64631, &[1609, 1755, 1586]),
+  (64632, &[1609, 1755, 1605]), (64633, &[1609, 1755, 1606]),
+  (64634, &[1609, 1755, 1609]), (64635, &[1609, 1755, 1609]),
+  (64636, &[1601, 1609]), (64637, &[1601, 1609]), (64638, &[1602, 1609]),
+  (64639, &[1602, 1609]), (64640, &[1603, 108]), (64641, &[1603, 1604]),
+  (64642, &[1603, 1605]), (64643, &[1603, 1609]), (64644, &[1603, 1609]),
+  (64645, &[1604, 1605]), (64646, &[1604, 1609]), (64647, &[1604, 1609]),
+  (64648, &[1605, 108]), (64649, &[1605, 1605]), (64650, &[1606, 1585]),
+  (64651, &[1606, 1586]), (64652, &[1606, 1605]), (64653, &[1606, 1606]),
+  (64654, &[1606, 1609]), (64655, &[1606, 1609]), (64656, &[1609, 1648]),
+  (64657, &[1609, 1585]), (64658, &[1609, 1586]), (64659, &[1609, 1605]),
+  (64660, &[1609, 1606]), (64661, &[1609, 1609]), (64662, &[1609, 1609]),
+  (64663, &[1609, 1652, 1580]), (64664, &[1609, 1652, 1581]),
+  (64665, &[1609, 1652, 1582]), (64666, &[1609, 1652, 1605]),
+  (64667, &[1609, 1652, 111]), (64668, &[1576, 1580]), (64669, &[1576, 1581]),
+  (64670, &[1576, 1582]), (64671, &[1576, 1605]), (64672, &[1576, 111]),
+  (64673, &[1578, 1580]), (64674, &[1578, 1581]), (64675, &[1578, 1582]),
+  (64676, &[1578, 1605]), (64677, &[1578, 111]), (64678, &[1609, 1755, 1605]),
+  (64679, &[1580, 1581]), (64680, &[1580, 1605]), (64681, &[1581, 1580]),
+  (64682, &[1581, 1605]), (64683, &[1582, 1580]), (64684, &[1582, 1605]),
+  (64685, &[1587, 1580]), (64686, &[1587, 1581]), (64687, &[1587, 1582]),
+  (64688, &[1587, 1605]), (64689, &[1589, 1581]), (64690, &[1589, 1582]),
+  (64691, &[1589, 1605]), (64692, &[1590, 1580]), (64693, &[1590, 1581]),
+  (64694, &[1590, 1582]), (64695, &[1590, 1605]), (64696, &[1591, 1581]),
+  (64697, &[1592, 1605]), (64698, &[1593, 1580]), (64699, &[1593, 1605]),
+  (64700, &[1594, 1580]), (64701, &[1594, 1605]), (64702, &[1601, 1580]),
+  (64703, &[1601, 1581]), (64704, &[1601, 1582]), (64705, &[1601, 1605]),
+  (64706, &[1602, 1581]), (64707, &[1602, 1605]), (64708, &[1603, 1580]),
+  (64709, &[1603, 1581]), (64710, &[1603, 1582]), (64711, &[1603, 1604]),
+  (64712, &[1603, 1605]), (64713, &[1604, 1580]), (64714, &[1604, 1581]),
+  (64715, &[1604, 1582]), (64716, &[1604, 1605]), (64717, &[1604, 111]),
+  (64718, &[1605, 1580]), (64719, &[1605, 1581]), (64720, &[1605, 1582]),
+  (64721, &[1605, 1605]), (64722, &[1576, 1582]), (64723, &[1606, 1581]),
+  (64724, &[1606, 1582]), (64725, &[1606, 1605]), (64726, &[1606, 111]),
+  (64727, &[111, 1580]), (64728, &[111, 1605]), (64729, &[111, 1648]),
+  (64730, &[1609, 1580]), (64731, &[1609, 1581]), (64732, &[1609, 1582]),
+  (64733, &[1609, 1605]), (64734, &[1609, 111]), (64735, &[1609, 1652, 1605]),
+  (64736, &[1609, 1652, 111]), (64737, &[1576, 1605]), (64738, &[1576, 111]),
+  (64739, &[1578, 1605]), (64740, &[1578, 111]), (64741, &[1609, 1755, 1605]),
+  (64742, &[1609, 1755, 111]), (64743, &[1587, 1605]), (64744, &[1587, 111]),
+  (64745, &[1587, 1755, 1605]), (64746, &[1587, 1755, 111]),
+  (64747, &[1603, 1604]), (64748, &[1603, 1605]), (64749, &[1604, 1605]),
+  (64750, &[1606, 1605]), (64751, &[1606, 111]), (64752, &[1609, 1605]),
+  (64753, &[1609, 111]), (64754, &[65143, 1617]), (64755, &[65145, 1617]),
+  (64756, &[65147, 1617]), (64757, &[1591, 1609]), (64758, &[1591, 1609]),
+  (64759, &[1593, 1609]), (64760, &[1593, 1609]), (64761, &[1594, 1609]),
+  (64762, &[1594, 1609]), (64763, &[1587, 1609]), (64764, &[1587, 1609]),
+  (64765, &[1587, 1755, 1609]), (64766, &[1587, 1755, 1609]),
+  (64767, &[1581, 1609]), (64768, &[1581, 1609]), (64769, &[1580, 1609]),
+  (64770, &[1580, 1609]), (64771, &[1582, 1609]), (64772, &[1582, 1609]),
+  (64773, &[1589, 1609]), (64774, &[1589, 1609]), (64775, &[1590, 1609]),
+  (64776, &[1590, 1609]), (64777, &[1587, 1755, 1580]),
+  (64778, &[1587, 1755, 1581]), (64779, &[1587, 1755, 1582]),
+  (64780, &[1587, 1755, 1605]), (64781, &[1587, 1755, 1585]),
+  (64782, &[1587, 1585]), (64783, &[1589, 1585]), (64784, &[1590, 1585]),
+  (64785, &[1591, 1609]), (64786, &[1591, 1609]), (64787, &[1593, 1609]),
+  (64788, &[1593, 1609]), (64789, &[1594, 1609]), (64790, &[1594, 1609]),
+  (64791, &[1587, 1609]), (64792, &[1587, 1609]), (64793, &[1587, 1755, 1609]),
+  (64794, &[1587, 1755, 1609]), (64795, &[1581, 1609]), (64796, &[1581, 1609]),
+  (64797, &[1580, 1609]), (64798, &[1580, 1609]), (64799, &[1582, 1609]),
+  (64800, &[1582, 1609]), (64801, &[1589, 1609]), (64802, &[1589, 1609]),
+  (64803, &[1590, 1609]), (64804, &[1590, 1609]), (64805, &[1587, 1755, 1580]),
+  (64806, &[1587, 1755, 1581]), (64807, &[1587, 1755, 1582]),
+  (64808, &[1587, 1755, 1605]), (64809, &[1587, 1755, 1585]),
+  (64810, &[1587, 1585]), (64811, &[1589, 1585]), (64812, &[1590, 1585]),
+  (64813, &[1587, 1755, 1580]), (64814, &[1587, 1755, 1581]),
+  (64815, &[1587, 1755, 1582]), (64816, &[1587, 1755, 1605]),
+  (64817, &[1587, 111]), (64818, &[1587, 1755, 111]), (64819, &[1591, 1605]),
+  (64820, &[1587, 1580]), (64821, &[1587, 1581]), (64822, &[1587, 1582]),
+  (64823, &[1587, 1755, 1580]), (64824, &[1587, 1755, 1581]),
+  (64825, &[1587, 1755, 1582]), (64826, &[1591, 1605]), (64827, &[1592, 1605]),
+  (64828, &[108, 779]), (64829, &[108, 779]), (64830, &[40]), (64831, &[41]),
+  (64848, &[1578, 1580, 1605]), (64849, &[1578, 1581, 1580]),
+  (64850, &[1578, 1581, 1580]), (64851, &[1578, 1581, 1605]),
+  (64852, &[1578, 1582, 1605]), (64853, &[1578, 1605, 1580]),
+  (64854, &[1578, 1605, 1581]), (64855, &[1578, 1605, 1582]),
+  (64856, &[1580, 1605, 1581]), (64857, &[1580, 1605, 1581]),
+  (64858, &[1581, 1605, 1609]), (64859, &[1581, 1605, 1609]),
+  (64860, &[1587, 1581, 1580]), (64861, &[1587, 1580, 1581]),
+  (64862, &[1587, 1580, 1609]), (64863, &[1587, 1605, 1581]),
+  (64864, &[1587, 1605, 1581]), (64865, &[1587, 1605, 1580]),
+  (64866, &[1587, 1605, 1605]), (64867, &[1587, 1605, 1605]),
+  (64868, &[1589, 1581, 1581]), (64869, &[1589, 1581, 1581]),
+  (64870, &[1589, 1605, 1605]), (64871, &[1587, 1755, 1581, 1605]),
+  (64872, &[1587, 1755, 1581, 1605]), (64873, &[1587, 1755, 1580, 1609]),
+  (64874, &[1587, 1755, 1605, 1582]), (64875, &[1587, 1755, 1605, 1582]),
+  (64876, &[1587, 1755, 1605, 1605]), (64877, &[1587, 1755, 1605, 1605]),
+  (64878, &[1590, 1581, 1609]), (64879, &[1590, 1582, 1605]),
+  (64880, &[1590, 1582, 1605]), (64881, &[1591, 1605, 1581]),
+  (64882, &[1591, 1605, 1581]), (64883, &[1591, 1605, 1605]),
+  (64884, &[1591, 1605, 1609]), (64885, &[1593, 1580, 1605]),
+  (64886, &[1593, 1605, 1605]), (64887, &[1593, 1605, 1605]),
+  (64888, &[1593, 1605, 1609]), (64889, &[1594, 1605, 1605]),
+  (64890, &[1594, 1605, 1609]), (64891, &[1594, 1605, 1609]),
+  (64892, &[1601, 1582, 1605]), (64893, &[1601, 1582, 1605]),
+  (64894, &[1602, 1605, 1581]), (64895, &[1602, 1605, 1605]),
+  (64896, &[1604, 1581, 1605]), (64897, &[1604, 1581, 1609]),
+  (64898, &[1604, 1581, 1609]), (64899, &[1604, 1580, 1580]),
+  (64900, &[1604, 1580, 1580]), (64901, &[1604, 1582, 1605]),
+  (64902, &[1604, 1582, 1605]), (64903, &[1604, 1605, 1581]),
+  (64904, &[1604, 1605, 1581]), (64905, &[1605, 1581, 1580]),
+  (64906, &[1605, 1581, 1605]), (64907, &[1605, 1581, 1609]),
+  (64908, &[1605, 1580, 1581]), (64909, &[1605, 1580, 1605]),
+  (64910, &[1605, 1582, 1580]), (64911, &[1605, 1582, 1605]),
+  (64914, &[1605, 1580, 1582]), (64915, &[111, 1605, 1580]),
+  (64916, &[111, 1605, 1605]), (64917, &[1606, 1581, 1605]),
+  (64918, &[1606, 1581, 1609]), (64919, &[1606, 1580, 1605]),
+  (64920, &[1606, 1580, 1605]), (64921, &[1606, 1580, 1609]),
+  (64922, &[1606, 1605, 1609]), (64923, &[1606, 1605, 1609]),
+  (64924, &[1609, 1605, 1605]), (64925, &[1609, 1605, 1605]),
+  (64926, &[1576, 1582, 1609]), (64927, &[1578, 1580, 1609]),
+  (64928, &[1578, 1580, 1609]), (64929, &[1578, 1582, 1609]),
+  (64930, &[1578, 1582, 1609]), (64931, &[1578, 1605, 1609]),
+  (64932, &[1578, 1605, 1609]), (64933, &[1580, 1605, 1609]),
+  (64934, &[1580, 1581, 1609]), (64935, &[1580, 1605, 1609]),
+  (64936, &[1587, 1582, 1609]), (64937, &[1589, 1581, 1609]),
+  (64938, &[1587, 1755, 1581, 1609]), (64939, &[1590, 1581, 1609]),
+  (64940, &[1604, 1580, 1609]), (64941, &[1604, 1605, 1609]),
+  (64942, &[1609, 1581, 1609]), (64943, &[1609, 1580, 1609]),
+  (64944, &[1609, 1605, 1609]), (64945, &[1605, 1605, 1609]),
+  (64946, &[1602, 1605, 1609]), (64947, &[1606, 1581, 1609]),
+  (64948, &[1602, 1605, 1581]), (64949, &[1604, 1581, 1605]),
+  (64950, &[1593, 1605, 1609]), (64951, &[1603, 1605, 1609]),
+  (64952, &[1606, 1580, 1581]), (64953, &[1605, 1582, 1609]),
+  (64954, &[1604, 1580, 1605]), (64955, &[1603, 1605, 1605]),
+  (64956, &[1604, 1580, 1605]), (64957, &[1606, 1580, 1581]),
+  (64958, &[1580, 1581, 1609]), (64959, &[1581, 1580, 1609]),
+  (64960, &[1605, 1580, 1609]), (64961, &[1601, 1605, 1609]),
+  (64962, &[1576, 1581, 1609]), (64963, &[1603, 1605, 1605]),
+  (64964, &[1593, 1580, 1605]), (64965, &[1589, 1605, 1605]),
+  (64966, &[1587, 1582, 1609]), (64967, &[1606, 1580, 1609]),
+  (65008, &[1589, 1604, 1609]), (65009, &[1602, 1604, 1609]),
+  (65010, &[108, 1604, 1604, 1617, 1648, 111]),
+  (65011, &[108, 1603, 1576, 1585]), (65012, &[1605, 1581, 1605, 1583]),
+  (65013, &[1589, 1604, 1593, 1605]), (65014, &[1585, 1587, 1608, 1604]),
+  (65015, &[1593, 1604, 1609, 111]), (65016, &[1608, 1587, 1604, 1605]),
+  (65017, &[1589, 1604, 1609]),
+  (65018, &[1589, 1604, 1609, 32, 108, 1604, 1604, 111, 32, 1593, 1604, 1609, 111, 32, 1608, 1587, 1604, 1605]),
+  (65019, &[1580, 1604, 32, 1580, 1604, 108, 1604, 111]),
+  (65020, &[1585, 1609, 108, 1604]), (65049, &[11607]), (65072, &[58]),
+  (65073, &[9474]), (65076, &[8967]), (65077, &[9180]), (65078, &[9181]),
+  (65079, &[9182]), (65080, &[9183]), (65081, &[9184]), (65082, &[9185]),
+  (65097, &[713]), (65098, &[713]), (65099, &[713]), (65100, &[713]),
+  (65101, &[95]), (65102, &[95]), (65103, &[95]), (65112, &[45]),
+  (65128, &[92]), (65152, &[1569]), (65153, &[1570]), (65154, &[1570]),
+  (65155, &[108, 1652]), (65156, &[108, 1652]), (65157, &[1608, 1652]),
+  (65158, &[1608, 1652]), (65159, &[108, 1621]), (65160, &[108, 1621]),
+  (65161, &[1609, 1652]), (65162, &[1609, 1652]), (65163, &[1609, 1652]),
+  (65164, &[1609, 1652]), (65165, &[108]), (65166, &[108]), (65167, &[1576]),
+  (65168, &[1576]), (65169, &[1576]), (65170, &[1576]), (65171, &[1577]),
+  (65172, &[1577]), (65173, &[1578]), (65174, &[1578]), (65175, &[1578]),
+  (65176, &[1578]), (65177, &[1609, 1755]), (65178, &[1609, 1755]),
+  (65179, &[1609, 1755]), (65180, &[1609, 1755]), (65181, &[1580]),
+  (65182, &[1580]), (65183, &[1580]), (65184, &[1580]), (65185, &[1581]),
+  (65186, &[1581]), (65187, &[1581]), (65188, &[1581]), (65189, &[1582]),
+  (65190, &[1582]), (65191, &[1582]), (65192, &[1582]), (65193, &[1583]),
+  (65194, &[1583]), (65195, &[1584]), (65196, &[1584]), (65197, &[1585]),
+  (65198, &[1585]), (65199, &[1586]), (65200, &[1586]), (65201, &[1587]),
+  (65202, &[1587]), (65203, &[1587]), (65204, &[1587]), (65205, &[1587, 1755]),
+  (65206, &[1587, 1755]), (65207, &[1587, 1755]), (65208, &[1587, 1755]),
+  (65209, &[1589]), (65210, &[1589]), (65211, &[1589]), (65212, &[1589]),
+  (65213, &[1590]), (65214, &[1590]), (65215, &[1590]), (65216, &[1590]),
+  (65217, &[1591]), (65218, &[1591]), (65219, &[1591]), (65220, &[1591]),
+  (65221, &[1592]), (65222, &[1592]), (65223, &[1592]), (65224, &[1592]),
+  (65225, &[1593]), (65226, &[1593]), (65227, &[1593]), (65228, &[1593]),
+  (65229, &[1594]), (65230, &[1594]), (65231, &[1594]), (65232, &[1594]),
+  (65233, &[1601]), (65234, &[1601]), (65235, &[1601]), (65236, &[1601]),
+  (65237, &[1602]), (65238, &[1602]), (65239, &[1602]), (65240, &[1602]),
+  (65241, &[1603]), (65242, &[1603]), (65243, &[1603]), (65244, &[1603]),
+  (65245, &[1604]), (65246, &[1604]), (65247, &[1604]), (65248, &[1604]),
+  (65249, &[1605]), (65250, &[1605]), (65251, &[1605]), (65252, &[1605]),
+  (65253, &[1606]), (65254, &[1606]), (65255, &[1606]), (65256, &[1606]),
+  (65257, &[111]), (65258, &[111]), (65259, &[111]), (65260, &[111]),
+  (65261, &[1608]), (65262, &[1608]), (65263, &[1609]), (65264, &[1609]),
+  (65265, &[1609]), (65266, &[1609]), (65267, &[1609]), (65268, &[1609]),
+  (65269, &[1604, 1570]), (65270, &[1604, 1570]), (65271, &[1604, 108, 1652]),
+  (65272, &[1604, 108, 1652]), (65273, &[1604, 108, 1621]),
+  (65274, &[1604, 108, 1621]), (65275, &[1604, 108]), (65276, &[1604, 108]),
+  (65281, &[33]), (65282, &[39, 39]), (65287, &[39]), (65293, &[12540]),
+  (65306, &[58]), (65313, &[65]), (65314, &[66]), (65315, &[67]),
+  (65317, &[69]), (65320, &[72]), (65321, &[108]), (65322, &[74]),
+  (65323, &[75]), (65325, &[77]), (65326, &[78]), (65327, &[79]),
+  (65328, &[80]), (65331, &[83]), (65332, &[84]), (65336, &[88]),
+  (65337, &[89]), (65338, &[90]), (65339, &[40]), (65340, &[92]),
+  (65341, &[41]), (65342, &[65087]), (65344, &[39]), (65345, &[97]),
+  (65347, &[99]), (65349, &[101]), (65351, &[103]), (65352, &[104]),
+  (65353, &[105]), (65354, &[106]), (65356, &[108]), (65359, &[111]),
+  (65360, &[112]), (65363, &[115]), (65366, &[118]), (65368, &[120]),
+  (65369, &[121]), (65372, &[9474]), (65374, &[12316]), (65381, &[183]),
+  (65507, &[713]), (65512, &[108]), (65517, &[9642]), (65793, &[183]),
+  (65934, &[78, 778]), (65942, &[88, 821]), (65943, &[86, 821]),
+  (65944, &[108, 821, 108, 821, 83, 821]), (65945, &[108, 821, 108, 821]),
+  (65952, &[11496]), (66178, &[66]), (66181, &[916]), (66182, &[69]),
+  (66183, &[70]), (66186, &[108]), (66189, &[581]), (66192, &[88]),
+  (66194, &[79]), (66196, &[5852]), (66197, &[80]), (66198, &[83]),
+  (66199, &[84]), (66203, &[43]), (66208, &[65]), (66209, &[66]),
+  (66210, &[67]), (66211, &[916]), (66213, &[70]), (66219, &[79]),
+  (66221, &[984]), (66224, &[77]), (66225, &[84]), (66226, &[89]),
+  (66227, &[934]), (66228, &[88]), (66229, &[936]), (66230, &[937]),
+  (66232, &[11584]), (66255, &[72]), (66273, &[1583]), (66276, &[1608]),
+  (66280, &[1591]), (66290, &[1589]), (66293, &[90]), (66305, &[66]),
+  (66306, &[67]), (66313, &[108]), (66321, &[77]), (66322, &[984]),
+  (66325, &[84]), (66327, &[88]), (66330, &[56]), (66335, &[42]),
+  (66336, &[108]), (66338, &[88]), (66513, &[66434]), (66515, &[66451]),
+  (66561, &[400]), (66564, &[79]), (66577, &[42230]), (66581, &[67]),
+  (66587, &[76]), (66591, &[11376]), (66592, &[83]), (66595, &[390]),
+  (66597, &[1048]), (66601, &[42899]), (66602, &[666]), (66604, &[111]),
+  (66621, &[99]), (66623, &[631]), (66626, &[606]), (66627, &[671]),
+  (66632, &[115]), (66635, &[596]), (66637, &[7438]), (66720, &[66694]),
+  (66736, &[581]), (66740, &[82]), (66748, &[1219]), (66754, &[79]),
+  (66755, &[664]), (66756, &[222]), (66765, &[1035]), (66766, &[85]),
+  (66768, &[5862]), (66769, &[936]), (66770, &[55]), (66776, &[652]),
+  (66779, &[955]), (66794, &[111]), (66795, &[42601]), (66806, &[117]),
+  (66809, &[968]), (66835, &[78]), (66838, &[79]), (66840, &[75]),
+  (66844, &[67]), (66845, &[86]), (66853, &[70]), (66854, &[76]),
+  (66855, &[88]), (68154, &[803]), (68176, &[46]), (68183, &[68182, 68182]),
+  (68858, &[68773]), (68860, &[68738]), (69819, &[2416]), (70087, &[2416]),
+  (70090, &[803]), (70091, &[2362]), (70107, &[43260]), (70108, &[43259]),
+  (70110, &[8776]), (70400, &[778]), (70675, &[70708, 70722, 70674]),
+  (70681, &[70708, 70722, 70680]), (70692, &[70708, 70722, 70691]),
+  (70698, &[70708, 70722, 70697]), (70701, &[70708, 70722, 70700]),
+  (70703, &[70708, 70722, 70702]), (70732, &[70731, 70731]), (70802, &[2456]),
+  (70804, &[2458]), (70806, &[2460]), (70808, &[2462]), (70809, &[2463]),
+  (70811, &[2465]), (70813, &[2482]), (70814, &[2468]), (70815, &[2469]),
+  (70816, &[2470]), (70817, &[2471]), (70818, &[2472]), (70819, &[2474]),
+  (70823, &[2478]), (70824, &[2479]), (70825, &[2476]), (70826, &[2467]),
+  (70827, &[2480]), (70829, &[2487]), (70830, &[2488]), (70832, &[2494]),
+  (70833, &[2495]), (70841, &[2503]), (70844, &[2507]), (70845, &[2519]),
+  (70846, &[2508]), (70847, &[774, 775]), (70849, &[2435]), (70850, &[2509]),
+  (70851, &[803]), (70852, &[2493]), (70853, &[119, 775]), (70864, &[79]),
+  (70865, &[2535]), (70866, &[2536]), (70870, &[2540]), (71128, &[71042]),
+  (71129, &[71042]), (71130, &[71043]), (71131, &[71044]), (71132, &[71090]),
+  (71133, &[71091]), (71234, &[71233, 71233]), (71424, &[114, 110]),
+  (71430, &[118]), (71434, &[119]), (71438, &[119]), (71439, &[119]),
+  (71840, &[86]), (71842, &[70]), (71843, &[76]), (71844, &[89]),
+  (71846, &[69]), (71848, &[8711]), (71849, &[90]), (71852, &[57]),
+  (71854, &[69]), (71855, &[52]), (71858, &[76]), (71861, &[79]),
+  (71863, &[5852]), (71864, &[85]), (71867, &[53]), (71868, &[84]),
+  (71872, &[118]), (71873, &[115]), (71874, &[70]), (71875, &[105]),
+  (71876, &[122]), (71878, &[55]), (71880, &[111]), (71882, &[51]),
+  (71884, &[57]), (71886, &[42899]), (71893, &[54]), (71894, &[57]),
+  (71895, &[111]), (71896, &[117]), (71900, &[121]), (71904, &[79]),
+  (71907, &[114, 110]), (71908, &[1641]), (71909, &[90]), (71910, &[87]),
+  (71913, &[67]), (71916, &[88]), (71919, &[87]), (71922, &[67]),
+  (72422, &[72421, 72431]), (72423, &[72421, 72432]), (72424, &[72421, 72421]),
+  (72425, &[72421, 72421, 72431]), (72426, &[72421, 72421, 72432]),
+  (72428, &[72427, 72431]), (72429, &[72427, 72427]),
+  (72430, &[72427, 72427, 72431]), (72436, &[72435, 72431]),
+  (72437, &[72435, 72432]), (72438, &[72435, 72435]),
+  (72439, &[72435, 72435, 72431]), (72440, &[72435, 72435, 72432]),
+  (72770, &[72769, 72769]), (72882, &[72874]), (73784, &[66458]),
+  (78585, &[67998]), (93959, &[915]), (93960, &[86]), (93962, &[84]),
+  (93974, &[76]), (93978, &[916]), (93980, &[42584]), (93990, &[42230]),
+  (93992, &[108]), (93997, &[400]), (94005, &[82]), (94010, &[83]),
+  (94011, &[51]), (94013, &[581]), (94015, &[62]), (94016, &[65]),
+  (94018, &[85]), (94019, &[89]), (94033, &[39]), (94034, &[39]),
+  (119060, &[123]), (119149, &[46]), (119298, &[1278]), (119302, &[51]),
+  (119307, &[1048]), (119309, &[86]), (119311, &[92]), (119314, &[55]),
+  (119315, &[70]), (119316, &[66236]), (119317, &[42230]), (119318, &[82]),
+  (119319, &[11375]), (119322, &[79, 821]), (119323, &[8516]),
+  (119324, &[42197]), (119329, &[400]), (119330, &[1120]), (119338, &[76]),
+  (119339, &[42230]), (119344, &[43003]), (119350, &[60]), (119351, &[62]),
+  (119352, &[8847]), (119353, &[8848]), (119354, &[47]), (119355, &[92]),
+  (119359, &[5835]), (119365, &[1352]), (119808, &[65]), (119809, &[66]),
+  (119810, &[67]), (119811, &[68]), (119812, &[69]), (119813, &[70]),
+  (119814, &[71]), (119815, &[72]), (119816, &[108]), (119817, &[74]),
+  (119818, &[75]), (119819, &[76]), (119820, &[77]), (119821, &[78]),
+  (119822, &[79]), (119823, &[80]), (119824, &[81]), (119825, &[82]),
+  (119826, &[83]), (119827, &[84]), (119828, &[85]), (119829, &[86]),
+  (119830, &[87]), (119831, &[88]), (119832, &[89]), (119833, &[90]),
+  (119834, &[97]), (119835, &[98]), (119836, &[99]), (119837, &[100]),
+  (119838, &[101]), (119839, &[102]), (119840, &[103]), (119841, &[104]),
+  (119842, &[105]), (119843, &[106]), (119844, &[107]), (119845, &[108]),
+  (119846, &[114, 110]), (119847, &[110]), (119848, &[111]), (119849, &[112]),
+  (119850, &[113]), (119851, &[114]), (119852, &[115]), (119853, &[116]),
+  (119854, &[117]), (119855, &[118]), (119856, &[119]), (119857, &[120]),
+  (119858, &[121]), (119859, &[122]), (119860, &[65]), (119861, &[66]),
+  (119862, &[67]), (119863, &[68]), (119864, &[69]), (119865, &[70]),
+  (119866, &[71]), (119867, &[72]), (119868, &[108]), (119869, &[74]),
+  (119870, &[75]), (119871, &[76]), (119872, &[77]), (119873, &[78]),
+  (119874, &[79]), (119875, &[80]), (119876, &[81]), (119877, &[82]),
+  (119878, &[83]), (119879, &[84]), (119880, &[85]), (119881, &[86]),
+  (119882, &[87]), (119883, &[88]), (119884, &[89]), (119885, &[90]),
+  (119886, &[97]), (119887, &[98]), (119888, &[99]), (119889, &[100]),
+  (119890, &[101]), (119891, &[102]), (119892, &[103]), (119894, &[105]),
+  (119895, &[106]), (119896, &[107]), (119897, &[108]), (119898, &[114, 110]),
+  (119899, &[110]), (119900, &[111]), (119901, &[112]), (119902, &[113]),
+  (119903, &[114]), (119904, &[115]), (119905, &[116]), (119906, &[117]),
+  (119907, &[118]), (119908, &[119]), (119909, &[120]), (119910, &[121]),
+  (119911, &[122]), (119912, &[65]), (119913, &[66]), (119914, &[67]),
+  (119915, &[68]), (119916, &[69]), (119917, &[70]), (119918, &[71]),
+  (119919, &[72]), (119920, &[108]), (119921, &[74]), (119922, &[75]),
+  (119923, &[76]), (119924, &[77]), (119925, &[78]), (119926, &[79]),
+  (119927, &[80]), (119928, &[81]), (119929, &[82]), (119930, &[83]),
+  (119931, &[84]), (119932, &[85]), (119933, &[86]), (119934, &[87]),
+  (119935, &[88]), (119936, &[89]), (119937, &[90]), (119938, &[97]),
+  (119939, &[98]), (119940, &[99]), (119941, &[100]), (119942, &[101]),
+  (119943, &[102]), (119944, &[103]), (119945, &[104]), (119946, &[105]),
+  (119947, &[106]), (119948, &[107]), (119949, &[108]), (119950, &[114, 110]),
+  (119951, &[110]), (119952, &[111]), (119953, &[112]), (119954, &[113]),
+  (119955, &[114]), (119956, &[115]), (119957, &[116]), (119958, &[117]),
+  (119959, &[118]), (119960, &[119]), (119961, &[120]), (119962, &[121]),
+  (119963, &[122]), (119964, &[65]), (119966, &[67]), (119967, &[68]),
+  (119970, &[71]), (119973, &[74]), (119974, &[75]), (119977, &[78]),
+  (119978, &[79]), (119979, &[80]), (119980, &[81]), (119982, &[83]),
+  (119983, &[84]), (119984, &[85]), (119985, &[86]), (119986, &[87]),
+  (119987, &[88]), (119988, &[89]), (119989, &[90]), (119990, &[97]),
+  (119991, &[98]), (119992, &[99]), (119993, &[100]), (119995, &[102]),
+  (119997, &[104]), (119998, &[105]), (119999, &[106]), (120000, &[107]),
+  (120001, &[108]), (120002, &[114, 110]), (120003, &[110]), (120005, &[112]),
+  (120006, &[113]), (120007, &[114]), (120008, &[115]), (120009, &[116]),
+  (120010, &[117]), (120011, &[118]), (120012, &[119]), (120013, &[120]),
+  (120014, &[121]), (120015, &[122]), (120016, &[65]), (120017, &[66]),
+  (120018, &[67]), (120019, &[68]), (120020, &[69]), (120021, &[70]),
+  (120022, &[71]), (120023, &[72]), (120024, &[108]), (120025, &[74]),
+  (120026, &[75]), (120027, &[76]), (120028, &[77]), (120029, &[78]),
+  (120030, &[79]), (120031, &[80]), (120032, &[81]), (120033, &[82]),
+  (120034, &[83]), (120035, &[84]), (120036, &[85]), (120037, &[86]),
+  (120038, &[87]), (120039, &[88]), (120040, &[89]), (120041, &[90]),
+  (120042, &[97]), (120043, &[98]), (120044, &[99]), (120045, &[100]),
+  (120046, &[101]), (120047, &[102]), (120048, &[103]), (120049, &[104]),
+  (120050, &[105]), (120051, &[106]), (120052, &[107]), (120053, &[108]),
+  (120054, &[114, 110]), (120055, &[110]), (120056, &[111]), (120057, &[112]),
+  (120058, &[113]), (120059, &[114]), (120060, &[115]), (120061, &[116]),
+  (120062, &[117]), (120063, &[118]), (120064, &[119]), (120065, &[120]),
+  (120066, &[121]), (120067, &[122]), (120068, &[65]), (120069, &[66]),
+  (120071, &[68]), (120072, &[69]), (120073, &[70]), (120074, &[71]),
+  (120077, &[74]), (120078, &[75]), (120079, &[76]), (120080, &[77]),
+  (120081, &[78]), (120082, &[79]), (120083, &[80]), (120084, &[81]),
+  (120086, &[83]), (120087, &[84]), (120088, &[85]), (120089, &[86]),
+  (120090, &[87]), (120091, &[88]), (120092, &[89]), (120094, &[97]),
+  (120095, &[98]), (120096, &[99]), (120097, &[100]), (120098, &[101]),
+  (120099, &[102]), (120100, &[103]), (120101, &[104]), (120102, &[105]),
+  (120103, &[106]), (120104, &[107]), (120105, &[108]), (120106, &[114, 110]),
+  (120107, &[110]), (120108, &[111]), (120109, &[112]), (120110, &[113]),
+  (120111, &[114]), (120112, &[115]), (120113, &[116]), (120114, &[117]),
+  (120115, &[118]), (120116, &[119]), (120117, &[120]), (120118, &[121]),
+  (120119, &[122]), (120120, &[65]), (120121, &[66]), (120123, &[68]),
+  (120124, &[69]), (120125, &[70]), (120126, &[71]), (120128, &[108]),
+  (120129, &[74]), (120130, &[75]), (120131, &[76]), (120132, &[77]),
+  (120134, &[79]), (120138, &[83]), (120139, &[84]), (120140, &[85]),
+  (120141, &[86]), (120142, &[87]), (120143, &[88]), (120144, &[89]),
+  (120146, &[97]), (120147, &[98]), (120148, &[99]), (120149, &[100]),
+  (120150, &[101]), (120151, &[102]), (120152, &[103]), (120153, &[104]),
+  (120154, &[105]), (120155, &[106]), (120156, &[107]), (120157, &[108]),
+  (120158, &[114, 110]), (120159, &[110]), (120160, &[111]), (120161, &[112]),
+  (120162, &[113]), (120163, &[114]), (120164, &[115]), (120165, &[116]),
+  (120166, &[117]), (120167, &[118]), (120168, &[119]), (120169, &[120]),
+  (120170, &[121]), (120171, &[122]), (120172, &[65]), (120173, &[66]),
+  (120174, &[67]), (120175, &[68]), (120176, &[69]), (120177, &[70]),
+  (120178, &[71]), (120179, &[72]), (120180, &[108]), (120181, &[74]),
+  (120182, &[75]), (120183, &[76]), (120184, &[77]), (120185, &[78]),
+  (120186, &[79]), (120187, &[80]), (120188, &[81]), (120189, &[82]),
+  (120190, &[83]), (120191, &[84]), (120192, &[85]), (120193, &[86]),
+  (120194, &[87]), (120195, &[88]), (120196, &[89]), (120197, &[90]),
+  (120198, &[97]), (120199, &[98]), (120200, &[99]), (120201, &[100]),
+  (120202, &[101]), (120203, &[102]), (120204, &[103]), (120205, &[104]),
+  (120206, &[105]), (120207, &[106]), (120208, &[107]), (120209, &[108]),
+  (120210, &[114, 110]), (120211, &[110]), (120212, &[111]), (120213, &[112]),
+  (120214, &[113]), (120215, &[114]), (120216, &[115]), (120217, &[116]),
+  (120218, &[117]), (120219, &[118]), (120220, &[119]), (120221, &[120]),
+  (120222, &[121]), (120223, &[122]), (120224, &[65]), (120225, &[66]),
+  (120226, &[67]), (120227, &[68]), (120228, &[69]), (120229, &[70]),
+  (120230, &[71]), (120231, &[72]), (120232, &[108]), (120233, &[74]),
+  (120234, &[75]), (120235, &[76]), (120236, &[77]), (120237, &[78]),
+  (120238, &[79]), (120239, &[80]), (120240, &[81]), (120241, &[82]),
+  (120242, &[83]), (120243, &[84]), (120244, &[85]), (120245, &[86]),
+  (120246, &[87]), (120247, &[88]), (120248, &[89]), (120249, &[90]),
+  (120250, &[97]), (120251, &[98]), (120252, &[99]), (120253, &[100]),
+  (120254, &[101]), (120255, &[102]), (120256, &[103]), (120257, &[104]),
+  (120258, &[105]), (120259, &[106]), (120260, &[107]), (120261, &[108]),
+  (120262, &[114, 110]), (120263, &[110]), (120264, &[111]), (120265, &[112]),
+  (120266, &[113]), (120267, &[114]), (120268, &[115]), (120269, &[116]),
+  (120270, &[117]), (120271, &[118]), (120272, &[119]), (120273, &[120]),
+  (120274, &[121]), (120275, &[122]), (120276, &[65]), (120277, &[66]),
+  (120278, &[67]), (120279, &[68]), (120280, &[69]), (120281, &[70]),
+  (120282, &[71]), (120283, &[72]), (120284, &[108]), (120285, &[74]),
+  (120286, &[75]), (120287, &[76]), (120288, &[77]), (120289, &[78]),
+  (120290, &[79]), (120291, &[80]), (120292, &[81]), (120293, &[82]),
+  (120294, &[83]), (120295, &[84]), (120296, &[85]), (120297, &[86]),
+  (120298, &[87]), (120299, &[88]), (120300, &[89]), (120301, &[90]),
+  (120302, &[97]), (120303, &[98]), (120304, &[99]), (120305, &[100]),
+  (120306, &[101]), (120307, &[102]), (120308, &[103]), (120309, &[104]),
+  (120310, &[105]), (120311, &[106]), (120312, &[107]), (120313, &[108]),
+  (120314, &[114, 110]), (120315, &[110]), (120316, &[111]), (120317, &[112]),
+  (120318, &[113]), (120319, &[114]), (120320, &[115]), (120321, &[116]),
+  (120322, &[117]), (120323, &[118]), (120324, &[119]), (120325, &[120]),
+  (120326, &[121]), (120327, &[122]), (120328, &[65]), (120329, &[66]),
+  (120330, &[67]), (120331, &[68]), (120332, &[69]), (120333, &[70]),
+  (120334, &[71]), (120335, &[72]), (120336, &[108]), (120337, &[74]),
+  (120338, &[75]), (120339, &[76]), (120340, &[77]), (120341, &[78]),
+  (120342, &[79]), (120343, &[80]), (120344, &[81]), (120345, &[82]),
+  (120346, &[83]), (120347, &[84]), (120348, &[85]), (120349, &[86]),
+  (120350, &[87]), (120351, &[88]), (120352, &[89]), (120353, &[90]),
+  (120354, &[97]), (120355, &[98]), (120356, &[99]), (120357, &[100]),
+  (120358, &[101]), (120359, &[102]), (120360, &[103]), (120361, &[104]),
+  (120362, &[105]), (120363, &[106]), (120364, &[107]), (120365, &[108]),
+  (120366, &[114, 110]), (120367, &[110]), (120368, &[111]), (120369, &[112]),
+  (120370, &[113]), (120371, &[114]), (120372, &[115]), (120373, &[116]),
+  (120374, &[117]), (120375, &[118]), (120376, &[119]), (120377, &[120]),
+  (120378, &[121]), (120379, &[122]), (120380, &[65]), (120381, &[66]),
+  (120382, &[67]), (120383, &[68]), (120384, &[69]), (120385, &[70]),
+  (120386, &[71]), (120387, &[72]), (120388, &[108]), (120389, &[74]),
+  (120390, &[75]), (120391, &[76]), (120392, &[77]), (120393, &[78]),
+  (120394, &[79]), (120395, &[80]), (120396, &[81]), (120397, &[82]),
+  (120398, &[83]), (120399, &[84]), (120400, &[85]), (120401, &[86]),
+  (120402, &[87]), (120403, &[88]), (120404, &[89]), (120405, &[90]),
+  (120406, &[97]), (120407, &[98]), (120408, &[99]), (120409, &[100]),
+  (120410, &[101]), (120411, &[102]), (120412, &[103]), (120413, &[104]),
+  (120414, &[105]), (120415, &[106]), (120416, &[107]), (120417, &[108]),
+  (120418, &[114, 110]), (120419, &[110]), (120420, &[111]), (120421, &[112]),
+  (120422, &[113]), (120423, &[114]), (120424, &[115]), (120425, &[116]),
+  (120426, &[117]), (120427, &[118]), (120428, &[119]), (120429, &[120]),
+  (120430, &[121]), (120431, &[122]), (120432, &[65]), (120433, &[66]),
+  (120434, &[67]), (120435, &[68]), (120436, &[69]), (120437, &[70]),
+  (120438, &[71]), (120439, &[72]), (120440, &[108]), (120441, &[74]),
+  (120442, &[75]), (120443, &[76]), (120444, &[77]), (120445, &[78]),
+  (120446, &[79]), (120447, &[80]), (120448, &[81]), (120449, &[82]),
+  (120450, &[83]), (120451, &[84]), (120452, &[85]), (120453, &[86]),
+  (120454, &[87]), (120455, &[88]), (120456, &[89]), (120457, &[90]),
+  (120458, &[97]), (120459, &[98]), (120460, &[99]), (120461, &[100]),
+  (120462, &[101]), (120463, &[102]), (120464, &[103]), (120465, &[104]),
+  (120466, &[105]), (120467, &[106]), (120468, &[107]), (120469, &[108]),
+  (120470, &[114, 110]), (120471, &[110]), (120472, &[111]), (120473, &[112]),
+  (120474, &[113]), (120475, &[114]), (120476, &[115]), (120477, &[116]),
+  (120478, &[117]), (120479, &[118]), (120480, &[119]), (120481, &[120]),
+  (120482, &[121]), (120483, &[122]), (120484, &[105]), (120485, &[567]),
+  (120488, &[65]), (120489, &[66]), (120490, &[915]), (120491, &[916]),
+  (120492, &[69]), (120493, &[90]), (120494, &[72]), (120495, &[79, 821]),
+  (120496, &[108]), (120497, &[75]), (120498, &[581]), (120499, &[77]),
+  (120500, &[78]), (120501, &[926]), (120502, &[79]), (120503, &[928]),
+  (120504, &[80]), (120505, &[79, 821]), (120506, &[425]), (120507, &[84]),
+  (120508, &[89]), (120509, &[934]), (120510, &[88]), (120511, &[936]),
+  (120512, &[937]), (120513, &[8711]), (120514, &[97]), (120515, &[223]),
+  (120516, &[121]), (120517, &[7839]), (120518, &[42899]), (120519, &[950]),
+  (120520, &[110, 809]), (120521, &[79, 821]), (120522, &[105]),
+  (120523, &[312]), (120524, &[955]), (120525, &[956]), (120526, &[118]),
+  (120527, &[958]), (120528, &[111]), (120529, &[960]), (120530, &[112]),
+  (120531, &[962]), (120532, &[111]), (120533, &[7451]), (120534, &[117]),
+  (120535, &[632]), (120536, &[967]), (120537, &[968]), (120538, &[969]),
+  (120539, &[8706]), (120540, &[42899]), (120541, &[79, 821]),
+  (120542, &[312]), (120543, &[632]), (120544, &[112]), (120545, &[960]),
+  (120546, &[65]), (120547, &[66]), (120548, &[915]), (120549, &[916]),
+  (120550, &[69]), (120551, &[90]), (120552, &[72]), (120553, &[79, 821]),
+  (120554, &[108]), (120555, &[75]), (120556, &[581]), (120557, &[77]),
+  (120558, &[78]), (120559, &[926]), (120560, &[79]), (120561, &[928]),
+  (120562, &[80]), (120563, &[79, 821]), (120564, &[425]), (120565, &[84]),
+  (120566, &[89]), (120567, &[934]), (120568, &[88]), (120569, &[936]),
+  (120570, &[937]), (120571, &[8711]), (120572, &[97]), (120573, &[223]),
+  (120574, &[121]), (120575, &[7839]), (120576, &[42899]), (120577, &[950]),
+  (120578, &[110, 809]), (120579, &[79, 821]), (120580, &[105]),
+  (120581, &[312]), (120582, &[955]), (120583, &[956]), (120584, &[118]),
+  (120585, &[958]), (120586, &[111]), (120587, &[960]), (120588, &[112]),
+  (120589, &[962]), (120590, &[111]), (120591, &[7451]), (120592, &[117]),
+  (120593, &[632]), (120594, &[967]), (120595, &[968]), (120596, &[969]),
+  (120597, &[8706]), (120598, &[42899]), (120599, &[79, 821]),
+  (120600, &[312]), (120601, &[632]), (120602, &[112]), (120603, &[960]),
+  (120604, &[65]), (120605, &[66]), (120606, &[915]), (120607, &[916]),
+  (120608, &[69]), (120609, &[90]), (120610, &[72]), (120611, &[79, 821]),
+  (120612, &[108]), (120613, &[75]), (120614, &[581]), (120615, &[77]),
+  (120616, &[78]), (120617, &[926]), (120618, &[79]), (120619, &[928]),
+  (120620, &[80]), (120621, &[79, 821]), (120622, &[425]), (120623, &[84]),
+  (120624, &[89]), (120625, &[934]), (120626, &[88]), (120627, &[936]),
+  (120628, &[937]), (120629, &[8711]), (120630, &[97]), (120631, &[223]),
+  (120632, &[121]), (120633, &[7839]), (120634, &[42899]), (120635, &[950]),
+  (120636, &[110, 809]), (120637, &[79, 821]), (120638, &[105]),
+  (120639, &[312]), (120640, &[955]), (120641, &[956]), (120642, &[118]),
+  (120643, &[958]), (120644, &[111]), (120645, &[960]), (120646, &[112]),
+  (120647, &[962]), (120648, &[111]), (120649, &[7451]), (120650, &[117]),
+  (120651, &[632]), (120652, &[967]), (120653, &[968]), (120654, &[969]),
+  (120655, &[8706]), (120656, &[42899]), (120657, &[79, 821]),
+  (120658, &[312]), (120659, &[632]), (120660, &[112]), (120661, &[960]),
+  (120662, &[65]), (120663, &[66]), (120664, &[915]), (120665, &[916]),
+  (120666, &[69]), (120667, &[90]), (120668, &[72]), (120669, &[79, 821]),
+  (120670, &[108]), (120671, &[75]), (120672, &[581]), (120673, &[77]),
+  (120674, &[78]), (120675, &[926]), (120676, &[79]), (120677, &[928]),
+  (120678, &[80]), (120679, &[79, 821]), (120680, &[425]), (120681, &[84]),
+  (120682, &[89]), (120683, &[934]), (120684, &[88]), (120685, &[936]),
+  (120686, &[937]), (120687, &[8711]), (120688, &[97]), (120689, &[223]),
+  (120690, &[121]), (120691, &[7839]), (120692, &[42899]), (120693, &[950]),
+  (120694, &[110, 809]), (120695, &[79, 821]), (120696, &[105]),
+  (120697, &[312]), (120698, &[955]), (120699, &[956]), (120700, &[118]),
+  (120701, &[958]), (120702, &[111]), (120703, &[960]), (120704, &[112]),
+  (120705, &[962]), (120706, &[111]), (120707, &[7451]), (120708, &[117]),
+  (120709, &[632]), (120710, &[967]), (120711, &[968]), (120712, &[969]),
+  (120713, &[8706]), (120714, &[42899]), (120715, &[79, 821]),
+  (120716, &[312]), (120717, &[632]), (120718, &[112]), (120719, &[960]),
+  (120720, &[65]), (120721, &[66]), (120722, &[915]), (120723, &[916]),
+  (120724, &[69]), (120725, &[90]), (120726, &[72]), (120727, &[79, 821]),
+  (120728, &[108]), (120729, &[75]), (120730, &[581]), (120731, &[77]),
+  (120732, &[78]), (120733, &[926]), (120734, &[79]), (120735, &[928]),
+  (120736, &[80]), (120737, &[79, 821]), (120738, &[425]), (120739, &[84]),
+  (120740, &[89]), (120741, &[934]), (120742, &[88]), (120743, &[936]),
+  (120744, &[937]), (120745, &[8711]), (120746, &[97]), (120747, &[223]),
+  (120748, &[121]), (120749, &[7839]), (120750, &[42899]), (120751, &[950]),
+  (120752, &[110, 809]), (120753, &[79, 821]), (120754, &[105]),
+  (120755, &[312]), (120756, &[955]), (120757, &[956]), (120758, &[118]),
+  (120759, &[958]), (120760, &[111]), (120761, &[960]), (120762, &[112]),
+  (120763, &[962]), (120764, &[111]), (120765, &[7451]), (120766, &[117]),
+  (120767, &[632]), (120768, &[967]), (120769, &[968]), (120770, &[969]),
+  (120771, &[8706]), (120772, &[42899]), (120773, &[79, 821]),
+  (120774, &[312]), (120775, &[632]), (120776, &[112]), (120777, &[960]),
+  (120778, &[70]), (120779, &[989]), (120782, &[79]), (120783, &[108]),
+  (120784, &[50]), (120785, &[51]), (120786, &[52]), (120787, &[53]),
+  (120788, &[54]), (120789, &[55]), (120790, &[56]), (120791, &[57]),
+  (120792, &[79]), (120793, &[108]), (120794, &[50]), (120795, &[51]),
+  (120796, &[52]), (120797, &[53]), (120798, &[54]), (120799, &[55]),
+  (120800, &[56]), (120801, &[57]), (120802, &[79]), (120803, &[108]),
+  (120804, &[50]), (120805, &[51]), (120806, &[52]), (120807, &[53]),
+  (120808, &[54]), (120809, &[55]), (120810, &[56]), (120811, &[57]),
+  (120812, &[79]), (120813, &[108]), (120814, &[50]), (120815, &[51]),
+  (120816, &[52]), (120817, &[53]), (120818, &[54]), (120819, &[55]),
+  (120820, &[56]), (120821, &[57]), (120822, &[79]), (120823, &[108]),
+  (120824, &[50]), (120825, &[51]), (120826, &[52]), (120827, &[53]),
+  (120828, &[54]), (120829, &[55]), (120830, &[56]), (120831, &[57]),
+  (125127, &[108]), (125128, &[8736]), (125129, &[1635]), (125131, &[56]),
+  (125132, &[8706]), (125133, &[8706, 821]), (126464, &[108]),
+  (126465, &[1576]), (126466, &[1580]), (126467, &[1583]), (126469, &[1608]),
+  (126470, &[1586]), (126471, &[1581]), (126472, &[1591]), (126473, &[1609]),
+  (126474, &[1603]), (126475, &[1604]), (126476, &[1605]), (126477, &[1606]),
+  (126478, &[1587]), (126479, &[1593]), (126480, &[1601]), (126481, &[1589]),
+  (126482, &[1602]), (126483, &[1585]), (126484, &[1587, 1755]),
+  (126485, &[1578]), (126486, &[1609, 1755]), (126487, &[1582]),
+  (126488, &[1584]), (126489, &[1590]), (126490, &[1592]), (126491, &[1594]),
+  (126492, &[1609]), (126493, &[1609]), (126494, &[1697]), (126495, &[1697]),
+  (126497, &[1576]), (126498, &[1580]), (126500, &[111]), (126503, &[1581]),
+  (126505, &[1609]), (126506, &[1603]), (126507, &[1604]), (126508, &[1605]),
+  (126509, &[1606]), (126510, &[1587]), (126511, &[1593]), (126512, &[1601]),
+  (126513, &[1589]), (126514, &[1602]), (126516, &[1587, 1755]),
+  (126517, &[1578]), (126518, &[1609, 1755]), (126519, &[1582]),
+  (126521, &[1590]), (126523, &[1594]), (126530, &[1580]), (126535, &[1581]),
+  (126537, &[1609]), (126539, &[1604]), (126541, &[1606]), (126542, &[1587]),
+  (126543, &[1593]), (126545, &[1589]), (126546, &[1602]),
+  (126548, &[1587, 1755]), (126551, &[1582]), (126553, &[1590]),
+  (126555, &[1594]), (126557, &[1609]), (126559, &[1697]), (126561, &[1576]),
+  (126562, &[1580]), (126564, &[111]), (126567, &[1581]), (126568, &[1591]),
+  (126569, &[1609]), (126570, &[1603]), (126572, &[1605]), (126573, &[1606]),
+  (126574, &[1587]), (126575, &[1593]), (126576, &[1601]), (126577, &[1589]),
+  (126578, &[1602]), (126580, &[1587, 1755]), (126581, &[1578]),
+  (126582, &[1609, 1755]), (126583, &[1582]), (126585, &[1590]),
+  (126586, &[1592]), (126587, &[1594]), (126588, &[1609]), (126590, &[1697]),
+  (126592, &[108]), (126593, &[1576]), (126594, &[1580]), (126595, &[1583]),
+  (126596, &[111]), (126597, &[1608]), (126598, &[1586]), (126599, &[1581]),
+  (126600, &[1591]), (126601, &[1609]), (126603, &[1604]), (126604, &[1605]),
+  (126605, &[1606]), (126606, &[1587]), (126607, &[1593]), (126608, &[1601]),
+  (126609, &[1589]), (126610, &[1602]), (126611, &[1585]),
+  (126612, &[1587, 1755]), (126613, &[1578]), (126614, &[1609, 1755]),
+  (126615, &[1582]), (126616, &[1584]), (126617, &[1590]), (126618, &[1592]),
+  (126619, &[1594]), (126625, &[1576]), (126626, &[1580]), (126627, &[1583]),
+  (126629, &[1608]), (126630, &[1586]), (126631, &[1581]), (126632, &[1591]),
+  (126633, &[1609]), (126635, &[1604]), (126636, &[1605]), (126637, &[1606]),
+  (126638, &[1587]), (126639, &[1593]), (126640, &[1601]), (126641, &[1589]),
+  (126642, &[1602]), (126643, &[1585]), (126644, &[1587, 1755]),
+  (126645, &[1578]), (126646, &[1609, 1755]), (126647, &[1582]),
+  (126648, &[1584]), (126649, &[1590]), (126650, &[1592]), (126651, &[1594]),
+  (127232, &[79, 46]), (127233, &[79, 44]), (127234, &[108, 44]),
+  (127235, &[50, 44]), (127236, &[51, 44]), (127237, &[52, 44]),
+  (127238, &[53, 44]), (127239, &[54, 44]), (127240, &[55, 44]),
+  (127241, &[56, 44]), (127242, &[57, 44]), (127248, &[40, 65, 41]),
+  (127249, &[40, 66, 41]), (127250, &[40, 67, 41]), (127251, &[40, 68, 41]),
+  (127252, &[40, 69, 41]), (127253, &[40, 70, 41]), (127254, &[40, 71, 41]),
+  (127255, &[40, 72, 41]), (127256, &[40, 108, 41]), (127257, &[40, 74, 41]),
+  (127258, &[40, 75, 41]), (127259, &[40, 76, 41]), (127260, &[40, 77, 41]),
+  (127261, &[40, 78, 41]), (127262, &[40, 79, 41]), (127263, &[40, 80, 41]),
+  (127264, &[40, 81, 41]), (127265, &[40, 82, 41]), (127266, &[40, 83, 41]),
+  (127267, &[40, 84, 41]), (127268, &[40, 85, 41]), (127269, &[40, 86, 41]),
+  (127270, &[40, 87, 41]), (127271, &[40, 88, 41]), (127272, &[40, 89, 41]),
+  (127273, &[40, 90, 41]), (127274, &[40, 83, 41]), (127552, &[40, 26412, 41]),
+  (127553, &[40, 19977, 41]), (127554, &[40, 20108, 41]),
+  (127555, &[40, 23433, 41]), (127556, &[40, 28857, 41]),
+  (127557, &[40, 25171, 41]), (127558, &[40, 30423, 41]),
+  (127559, &[40, 21213, 41]), (127560, &[40, 25943, 41]), (127762, &[9789]),
+  (127768, &[9790]), (127769, &[9789]), (128768, &[81, 69]),
+  (128769, &[42584]), (128770, &[916]), (128772, &[66236]),
+  (128775, &[65, 82]), (128776, &[86, 7652]), (128778, &[9769]),
+  (128788, &[79, 821]), (128808, &[66216]), (128826, &[10719]),
+  (128844, &[67]), (128852, &[5852]), (128853, &[8865]),
+  (128860, &[115, 115, 115]), (128862, &[8783]), (128872, &[84]),
+  (128875, &[77, 66]), (128876, &[86, 66]), (128881, &[8864]),
+  (139240, &[10092]), (194560, &[20029]), (194561, &[20024]),
+  (194562, &[20033]), (194563, &[131362]), (194564, &[20320]),
+  (194565, &[20398]), (194566, &[20411]), (194567, &[20341]),
+  (194568, &[20602]), (194569, &[20633]), (194570, &[20711]),
+  (194571, &[20687]), (194572, &[13470]), (194573, &[132666]),
+  (194574, &[20813]), (194575, &[20820]), (194576, &[20836]),
+  (194577, &[20855]), (194578, &[132380]), (194579, &[13497]),
+  (194580, &[20839]), (194581, &[20877]), (194582, &[132427]),
+  (194583, &[20887]), (194584, &[20900]), (194585, &[20172]),
+  (194586, &[20908]), (194587, &[20917]), (194588, &[168415]),
+  (194589, &[20981]), (194590, &[20995]), (194591, &[13535]),
+  (194592, &[21051]), (194593, &[21062]), (194594, &[21106]),
+  (194595, &[21111]), (194596, &[13589]), (194597, &[21191]),
+  (194598, &[21193]), (194599, &[21220]), (194600, &[21242]),
+  (194601, &[21253]), (194602, &[21254]), (194603, &[21271]),
+  (194604, &[21321]), (194605, &[21329]), (194606, &[21338]),
+  (194607, &[21363]), (194608, &[21373]), (194609, &[21375]),
+  (194610, &[21375]), (194611, &[21375]), (194612, &[133676]),
+  (194613, &[28784]), (194614, &[21450]), (194615, &[21471]),
+  (194616, &[133987]), (194617, &[21483]), (194618, &[21489]),
+  (194619, &[21510]), (194620, &[21662]), (194621, &[21560]),
+  (194622, &[21576]), (194623, &[21608]), (194624, &[21666]),
+  (194625, &[21750]), (194626, &[21776]), (194627, &[21843]),
+  (194628, &[21859]), (194629, &[21892]), (194630, &[21892]),
+  (194631, &[21913]), (194632, &[21931]), (194633, &[21939]),
+  (194634, &[21954]), (194635, &[22294]), (194636, &[22022]),
+  (194637, &[22295]), (194638, &[22097]), (194639, &[22132]),
+  (194640, &[20999]), (194641, &[22766]), (194642, &[22478]),
+  (194643, &[22516]), (194644, &[22541]), (194645, &[22411]),
+  (194646, &[22578]), (194647, &[22577]), (194648, &[22700]),
+  (194649, &[136420]), (194650, &[22770]), (194651, &[22775]),
+  (194652, &[22790]), (194653, &[22810]), (194654, &[22818]),
+  (194655, &[22882]), (194656, &[136872]), (194657, &[136938]),
+  (194658, &[23020]), (194659, &[23067]), (194660, &[23079]),
+  (194661, &[23000]), (194662, &[23142]), (194663, &[14062]),
+  (194664, &[14076]), (194665, &[23304]), (194666, &[23358]),
+  (194667, &[23358]), (194668, &[137672]), (194669, &[23491]),
+  (194670, &[23512]), (194671, &[23527]), (194672, &[23539]),
+  (194673, &[138008]), (194674, &[23551]), (194675, &[23558]),
+  (194676, &[24403]), (194677, &[23586]), (194678, &[14209]),
+  (194679, &[23648]), (194680, &[23662]), (194681, &[23744]),
+  (194682, &[23693]), (194683, &[138724]), (194684, &[23875]),
+  (194685, &[138726]), (194686, &[23918]), (194687, &[23915]),
+  (194688, &[23932]), (194689, &[24033]), (194690, &[24034]),
+  (194691, &[14383]), (194692, &[24061]), (194693, &[24104]),
+  (194694, &[24125]), (194695, &[24169]), (194696, &[14434]),
+  (194697, &[139651]), (194698, &[14460]), (194699, &[24240]),
+  (194700, &[24243]), (194701, &[24246]), (194702, &[24266]),
+  (194703, &[172946]), (194704, &[24318]), (194705, &[140081]),
+  (194706, &[140081]), (194707, &[33281]), (194708, &[24354]),
+  (194709, &[24354]), (194710, &[14535]), (194711, &[144056]),
+  (194712, &[156122]), (194713, &[24418]), (194714, &[24427]),
+  (194715, &[14563]), (194716, &[24474]), (194717, &[24525]),
+  (194718, &[24535]), (194719, &[24569]), (194720, &[24705]),
+  (194721, &[14650]), (194722, &[14620]), (194723, &[24724]),
+  (194724, &[141012]), (194725, &[24775]), (194726, &[24904]),
+  (194727, &[24908]), (194728, &[24910]), (194729, &[24908]),
+  (194730, &[24954]), (194731, &[24974]), (194732, &[25010]),
+  (194733, &[24996]), (194734, &[25007]), (194735, &[25054]),
+  (194736, &[25074]), (194737, &[25078]), (194738, &[25104]),
+  (194739, &[25115]), (194740, &[25181]), (194741, &[25265]),
+  (194742, &[25300]), (194743, &[25424]), (194744, &[142092]),
+  (194745, &[25405]), (194746, &[25340]), (194747, &[25448]),
+  (194748, &[25475]), (194749, &[25572]), (194750, &[142321]),
+  (194751, &[25634]), (194752, &[25541]), (194753, &[25513]),
+  (194754, &[14894]), (194755, &[25705]), (194756, &[25726]),
+  (194757, &[25757]), (194758, &[25719]), (194759, &[14956]),
+  (194760, &[25935]), (194761, &[25964]), (194762, &[143370]),
+  (194763, &[26083]), (194764, &[26360]), (194765, &[26185]),
+  (194766, &[15129]), (194767, &[26257]), (194768, &[15112]),
+  (194769, &[15076]), (194770, &[20882]), (194771, &[20885]),
+  (194772, &[26368]), (194773, &[26268]), (194774, &[32941]),
+  (194775, &[17369]), (194776, &[26391]), (194777, &[26395]),
+  (194778, &[26401]), (194779, &[26462]), (194780, &[26451]),
+  (194781, &[144323]), (194782, &[15177]), (194783, &[26618]),
+  (194784, &[26501]), (194785, &[26706]), (194786, &[26757]),
+  (194787, &[144493]), (194788, &[26766]), (194789, &[26655]),
+  (194790, &[26900]), (194791, &[15261]), (194792, &[26946]),
+  (194793, &[27043]), (194794, &[27114]), (194795, &[27304]),
+  (194796, &[145059]), (194797, &[27355]), (194798, &[15384]),
+  (194799, &[27425]), (194800, &[145575]), (194801, &[27476]),
+  (194802, &[15438]), (194803, &[27506]), (194804, &[27551]),
+  (194805, &[27578]), (194806, &[27579]), (194807, &[146061]),
+  (194808, &[138507]), (194809, &[146170]), (194810, &[27726]),
+  (194811, &[146620]), (194812, &[27839]), (194813, &[27853]),
+  (194814, &[27751]), (194815, &[27926]), (194816, &[27966]),
+  (194817, &[28023]), (194818, &[27969]), (194819, &[28009]),
+  (194820, &[28024]), (194821, &[28037]), (194822, &[146718]),
+  (194823, &[27956]), (194824, &[28207]), (194825, &[28270]),
+  (194826, &[15667]), (194827, &[28363]), (194828, &[28359]),
+  (194829, &[147153]), (194830, &[28153]), (194831, &[28526]),
+  (194832, &[147294]), (194833, &[147342]), (194834, &[28614]),
+  (194835, &[28729]), (194836, &[28702]), (194837, &[28699]),
+  (194838, &[15766]), (194839, &[28746]), (194840, &[28797]),
+  (194841, &[28791]), (194842, &[28845]), (194843, &[132389]),
+  (194844, &[28997]), (194845, &[148067]), (194846, &[29084]),
+  (194847, &[148395]), (194848, &[29224]), (194849, &[29237]),
+  (194850, &[29264]), (194851, &[149000]), (194852, &[29312]),
+  (194853, &[29333]), (194854, &[149301]), (194855, &[149524]),
+  (194856, &[29562]), (194857, &[29579]), (194858, &[16044]),
+  (194859, &[29605]), (194860, &[16056]), (194861, &[16056]),
+  (194862, &[29767]), (194863, &[29788]), (194864, &[29809]),
+  (194865, &[29829]), (194866, &[29898]), (194867, &[16155]),
+  (194868, &[29988]), (194869, &[150582]), (194870, &[30014]),
+  (194871, &[150674]), (194872, &[30064]), (194873, &[139679]),
+  (194874, &[30224]), (194875, &[151457]), (194876, &[151480]),
+  (194877, &[151620]), (194878, &[16380]), (194879, &[16392]),
+  (194880, &[30452]), (194881, &[151795]), (194882, &[151794]),
+  (194883, &[151833]), (194884, &[151859]), (194885, &[30494]),
+  (194886, &[30495]), (194887, &[30495]), (194888, &[30538]),
+  (194889, &[16441]), (194890, &[30603]), (194891, &[16454]),
+  (194892, &[16534]), (194893, &[152605]), (194894, &[30798]),
+  (194895, &[30860]), (194896, &[30924]), (194897, &[16611]),
+  (194898, &[153126]), (194899, &[31062]), (194900, &[153242]),
+  (194901, &[153285]), (194902, &[31119]), (194903, &[31211]),
+  (194904, &[16687]), (194905, &[31296]), (194906, &[31306]),
+  (194907, &[31311]), (194908, &[153980]), (194909, &[154279]),
+  (194910, &[154279]), (194911, &[31470]), (194912, &[16898]),
+  (194913, &[154539]), (194914, &[31686]), (194915, &[31689]),
+  (194916, &[16935]), (194917, &[154752]), (194918, &[31954]),
+  (194919, &[17056]), (194920, &[31976]), (194921, &[31971]),
+  (194922, &[32000]), (194923, &[155526]), (194924, &[32099]),
+  (194925, &[17153]), (194926, &[32199]), (194927, &[32258]),
+  (194928, &[32325]), (194929, &[17204]), (194930, &[156200]),
+  (194931, &[156231]), (194932, &[17241]), (194933, &[156377]),
+  (194934, &[32634]), (194935, &[156478]), (194936, &[32661]),
+  (194937, &[32762]), (194938, &[32773]), (194939, &[156890]),
+  (194940, &[156963]), (194941, &[32864]), (194942, &[157096]),
+  (194943, &[32880]), (194944, &[144223]), (194945, &[17365]),
+  (194946, &[32946]), (194947, &[33027]), (194948, &[17419]),
+  (194949, &[33086]), (194950, &[23221]), (194951, &[157607]),
+  (194952, &[157621]), (194953, &[144275]), (194954, &[144284]),
+  (194955, &[33281]), (194956, &[33284]), (194957, &[36766]),
+  (194958, &[17515]), (194959, &[33425]), (194960, &[33419]),
+  (194961, &[33437]), (194962, &[21171]), (194963, &[33457]),
+  (194964, &[33459]), (194965, &[33469]), (194966, &[33510]),
+  (194967, &[158524]), (194968, &[33509]), (194969, &[33565]),
+  (194970, &[33635]), (194971, &[33709]), (194972, &[33571]),
+  (194973, &[33725]), (194974, &[33767]), (194975, &[33879]),
+  (194976, &[33619]), (194977, &[33738]), (194978, &[33740]),
+  (194979, &[33756]), (194980, &[158774]), (194981, &[159083]),
+  (194982, &[158933]), (194983, &[17707]), (194984, &[34033]),
+  (194985, &[34035]), (194986, &[34070]), (194987, &[160714]),
+  (194988, &[34148]), (194989, &[159532]), (194990, &[17757]),
+  (194991, &[17761]), (194992, &[159665]), (194993, &[159954]),
+  (194994, &[17771]), (194995, &[34384]), (194996, &[34396]),
+  (194997, &[34407]), (194998, &[34409]), (194999, &[34473]),
+  (195000, &[34440]), (195001, &[34574]), (195002, &[34530]),
+  (195003, &[34681]), (195004, &[34600]), (195005, &[34667]),
+  (195006, &[34694]), (195007, &[17879]), (195008, &[34785]),
+  (195009, &[34817]), (195010, &[17913]), (195011, &[34912]),
+  (195012, &[34915]), (195013, &[161383]), (195014, &[35031]),
+  (195015, &[35038]), (195016, &[17973]), (195017, &[35066]),
+  (195018, &[13499]), (195019, &[161966]), (195020, &[162150]),
+  (195021, &[18110]), (195022, &[18119]), (195023, &[35488]),
+  (195024, &[35565]), (195025, &[35722]), (195026, &[35925]),
+  (195027, &[162984]), (195028, &[36011]), (195029, &[36033]),
+  (195030, &[36123]), (195031, &[36215]), (195032, &[163631]),
+  (195033, &[133124]), (195034, &[36299]), (195035, &[36284]),
+  (195036, &[36336]), (195037, &[133342]), (195038, &[36564]),
+  (195039, &[36664]), (195040, &[165330]), (195041, &[165357]),
+  (195042, &[37012]), (195043, &[37105]), (195044, &[37137]),
+  (195045, &[165678]), (195046, &[37147]), (195047, &[37432]),
+  (195048, &[37591]), (195049, &[37592]), (195050, &[37500]),
+  (195051, &[37881]), (195052, &[37909]), (195053, &[166906]),
+  (195054, &[38283]), (195055, &[18837]), (195056, &[38327]),
+  (195057, &[167287]), (195058, &[18918]), (195059, &[38595]),
+  (195060, &[23986]), (195061, &[38691]), (195062, &[168261]),
+  (195063, &[168474]), (195064, &[19054]), (195065, &[19062]),
+  (195066, &[38880]), (195067, &[168970]), (195068, &[19122]),
+  (195069, &[169110]), (195070, &[38923]), (195071, &[38923]),
+  (195072, &[38953]), (195073, &[169398]), (195074, &[39138]),
+  (195075, &[19251]), (195076, &[39209]), (195077, &[39335]),
+  (195078, &[39362]), (195079, &[39422]), (195080, &[19406]),
+  (195081, &[170800]), (195082, &[39698]), (195083, &[40000]),
+  (195084, &[40189]), (195085, &[19662]), (195086, &[19693]),
+  (195087, &[40295]), (195088, &[172238]), (195089, &[19704]),
+  (195090, &[172293]), (195091, &[172558]), (195092, &[172689]),
+  (195093, &[40635]), (195094, &[19798]), (195095, &[40697]),
+  (195096, &[40702]), (195097, &[40709]), (195098, &[40719]),
+  (195099, &[40726]), (195100, &[40763]), (195101, &[173568]),
+];
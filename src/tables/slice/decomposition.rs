@@ -0,0 +1,2741 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//  ucd-generate decomposition /home/andrew/tmp/ucd-10.0.0/
+//
+// ucd-generate is available on crates.io.
+
+pub const DECOMPOSITION: &'static [(u32, &'static str, &'static [u32])] = &[
+  (160, "Nobreak", &[32]), (168, "Compat", &[32, 776]), (170, "Super", &[97]),
+  (175, "Compat", &[32, 772]), (178, "Super", &[50]), (179, "Super", &[51]),
+  (180, "Compat", &[32, 769]), (181, "Compat", &[956]),
+  (184, "Compat", &[32, 807]), (185, "Super", &[49]), (186, "Super", &[111]),
+  (188, "Fraction", &[49, 8260, 52]), (189, "Fraction", &[49, 8260, 50]),
+  (190, "Fraction", &[51, 8260, 52]), (192, "Canonical", &[65, 768]),
+  (193, "Canonical", &[65, 769]), (194, "Canonical", &[65, 770]),
+  (195, "Canonical", &[65, 771]), (196, "Canonical", &[65, 776]),
+  (197, "Canonical", &[65, 778]), (199, "Canonical", &[67, 807]),
+  (200, "Canonical", &[69, 768]), (201, "Canonical", &[69, 769]),
+  (202, "Canonical", &[69, 770]), (203, "Canonical", &[69, 776]),
+  (204, "Canonical", &[73, 768]), (205, "Canonical", &[73, 769]),
+  (206, "Canonical", &[73, 770]), (207, "Canonical", &[73, 776]),
+  (209, "Canonical", &[78, 771]), (210, "Canonical", &[79, 768]),
+  (211, "Canonical", &[79, 769]), (212, "Canonical", &[79, 770]),
+  (213, "Canonical", &[79, 771]), (214, "Canonical", &[79, 776]),
+  (217, "Canonical", &[85, 768]), (218, "Canonical", &[85, 769]),
+  (219, "Canonical", &[85, 770]), (220, "Canonical", &[85, 776]),
+  (221, "Canonical", &[89, 769]), (224, "Canonical", &[97, 768]),
+  (225, "Canonical", &[97, 769]), (226, "Canonical", &[97, 770]),
+  (227, "Canonical", &[97, 771]), (228, "Canonical", &[97, 776]),
+  (229, "Canonical", &[97, 778]), (231, "Canonical", &[99, 807]),
+  (232, "Canonical", &[101, 768]), (233, "Canonical", &[101, 769]),
+  (234, "Canonical", &[101, 770]), (235, "Canonical", &[101, 776]),
+  (236, "Canonical", &[105, 768]), (237, "Canonical", &[105, 769]),
+  (238, "Canonical", &[105, 770]), (239, "Canonical", &[105, 776]),
+  (241, "Canonical", &[110, 771]), (242, "Canonical", &[111, 768]),
+  (243, "Canonical", &[111, 769]), (244, "Canonical", &[111, 770]),
+  (245, "Canonical", &[111, 771]), (246, "Canonical", &[111, 776]),
+  (249, "Canonical", &[117, 768]), (250, "Canonical", &[117, 769]),
+  (251, "Canonical", &[117, 770]), (252, "Canonical", &[117, 776]),
+  (253, "Canonical", &[121, 769]), (255, "Canonical", &[121, 776]),
+  (256, "Canonical", &[65, 772]), (257, "Canonical", &[97, 772]),
+  (258, "Canonical", &[65, 774]), (259, "Canonical", &[97, 774]),
+  (260, "Canonical", &[65, 808]), (261, "Canonical", &[97, 808]),
+  (262, "Canonical", &[67, 769]), (263, "Canonical", &[99, 769]),
+  (264, "Canonical", &[67, 770]), (265, "Canonical", &[99, 770]),
+  (266, "Canonical", &[67, 775]), (267, "Canonical", &[99, 775]),
+  (268, "Canonical", &[67, 780]), (269, "Canonical", &[99, 780]),
+  (270, "Canonical", &[68, 780]), (271, "Canonical", &[100, 780]),
+  (274, "Canonical", &[69, 772]), (275, "Canonical", &[101, 772]),
+  (276, "Canonical", &[69, 774]), (277, "Canonical", &[101, 774]),
+  (278, "Canonical", &[69, 775]), (279, "Canonical", &[101, 775]),
+  (280, "Canonical", &[69, 808]), (281, "Canonical", &[101, 808]),
+  (282, "Canonical", &[69, 780]), (283, "Canonical", &[101, 780]),
+  (284, "Canonical", &[71, 770]), (285, "Canonical", &[103, 770]),
+  (286, "Canonical", &[71, 774]), (287, "Canonical", &[103, 774]),
+  (288, "Canonical", &[71, 775]), (289, "Canonical", &[103, 775]),
+  (290, "Canonical", &[71, 807]), (291, "Canonical", &[103, 807]),
+  (292, "Canonical", &[72, 770]), (293, "Canonical", &[104, 770]),
+  (296, "Canonical", &[73, 771]), (297, "Canonical", &[105, 771]),
+  (298, "Canonical", &[73, 772]), (299, "Canonical", &[105, 772]),
+  (300, "Canonical", &[73, 774]), (301, "Canonical", &[105, 774]),
+  (302, "Canonical", &[73, 808]), (303, "Canonical", &[105, 808]),
+  (304, "Canonical", &[73, 775]), (306, "Compat", &[73, 74]),
+  (307, "Compat", &[105, 106]), (308, "Canonical", &[74, 770]),
+  (309, "Canonical", &[106, 770]), (310, "Canonical", &[75, 807]),
+  (311, "Canonical", &[107, 807]), (313, "Canonical", &[76, 769]),
+  (314, "Canonical", &[108, 769]), (315, "Canonical", &[76, 807]),
+  (316, "Canonical", &[108, 807]), (317, "Canonical", &[76, 780]),
+  (318, "Canonical", &[108, 780]), (319, "Compat", &[76, 183]),
+  (320, "Compat", &[108, 183]), (323, "Canonical", &[78, 769]),
+  (324, "Canonical", &[110, 769]), (325, "Canonical", &[78, 807]),
+  (326, "Canonical", &[110, 807]), (327, "Canonical", &[78, 780]),
+  (328, "Canonical", &[110, 780]), (329, "Compat", &[700, 110]),
+  (332, "Canonical", &[79, 772]), (333, "Canonical", &[111, 772]),
+  (334, "Canonical", &[79, 774]), (335, "Canonical", &[111, 774]),
+  (336, "Canonical", &[79, 779]), (337, "Canonical", &[111, 779]),
+  (340, "Canonical", &[82, 769]), (341, "Canonical", &[114, 769]),
+  (342, "Canonical", &[82, 807]), (343, "Canonical", &[114, 807]),
+  (344, "Canonical", &[82, 780]), (345, "Canonical", &[114, 780]),
+  (346, "Canonical", &[83, 769]), (347, "Canonical", &[115, 769]),
+  (348, "Canonical", &[83, 770]), (349, "Canonical", &[115, 770]),
+  (350, "Canonical", &[83, 807]), (351, "Canonical", &[115, 807]),
+  (352, "Canonical", &[83, 780]), (353, "Canonical", &[115, 780]),
+  (354, "Canonical", &[84, 807]), (355, "Canonical", &[116, 807]),
+  (356, "Canonical", &[84, 780]), (357, "Canonical", &[116, 780]),
+  (360, "Canonical", &[85, 771]), (361, "Canonical", &[117, 771]),
+  (362, "Canonical", &[85, 772]), (363, "Canonical", &[117, 772]),
+  (364, "Canonical", &[85, 774]), (365, "Canonical", &[117, 774]),
+  (366, "Canonical", &[85, 778]), (367, "Canonical", &[117, 778]),
+  (368, "Canonical", &[85, 779]), (369, "Canonical", &[117, 779]),
+  (370, "Canonical", &[85, 808]), (371, "Canonical", &[117, 808]),
+  (372, "Canonical", &[87, 770]), (373, "Canonical", &[119, 770]),
+  (374, "Canonical", &[89, 770]), (375, "Canonical", &[121, 770]),
+  (376, "Canonical", &[89, 776]), (377, "Canonical", &[90, 769]),
+  (378, "Canonical", &[122, 769]), (379, "Canonical", &[90, 775]),
+  (380, "Canonical", &[122, 775]), (381, "Canonical", &[90, 780]),
+  (382, "Canonical", &[122, 780]), (383, "Compat", &[115]),
+  (416, "Canonical", &[79, 795]), (417, "Canonical", &[111, 795]),
+  (431, "Canonical", &[85, 795]), (432, "Canonical", &[117, 795]),
+  (452, "Compat", &[68, 381]), (453, "Compat", &[68, 382]),
+  (454, "Compat", &[100, 382]), (455, "Compat", &[76, 74]),
+  (456, "Compat", &[76, 106]), (457, "Compat", &[108, 106]),
+  (458, "Compat", &[78, 74]), (459, "Compat", &[78, 106]),
+  (460, "Compat", &[110, 106]), (461, "Canonical", &[65, 780]),
+  (462, "Canonical", &[97, 780]), (463, "Canonical", &[73, 780]),
+  (464, "Canonical", &[105, 780]), (465, "Canonical", &[79, 780]),
+  (466, "Canonical", &[111, 780]), (467, "Canonical", &[85, 780]),
+  (468, "Canonical", &[117, 780]), (469, "Canonical", &[220, 772]),
+  (470, "Canonical", &[252, 772]), (471, "Canonical", &[220, 769]),
+  (472, "Canonical", &[252, 769]), (473, "Canonical", &[220, 780]),
+  (474, "Canonical", &[252, 780]), (475, "Canonical", &[220, 768]),
+  (476, "Canonical", &[252, 768]), (478, "Canonical", &[196, 772]),
+  (479, "Canonical", &[228, 772]), (480, "Canonical", &[550, 772]),
+  (481, "Canonical", &[551, 772]), (482, "Canonical", &[198, 772]),
+  (483, "Canonical", &[230, 772]), (486, "Canonical", &[71, 780]),
+  (487, "Canonical", &[103, 780]), (488, "Canonical", &[75, 780]),
+  (489, "Canonical", &[107, 780]), (490, "Canonical", &[79, 808]),
+  (491, "Canonical", &[111, 808]), (492, "Canonical", &[490, 772]),
+  (493, "Canonical", &[491, 772]), (494, "Canonical", &[439, 780]),
+  (495, "Canonical", &[658, 780]), (496, "Canonical", &[106, 780]),
+  (497, "Compat", &[68, 90]), (498, "Compat", &[68, 122]),
+  (499, "Compat", &[100, 122]), (500, "Canonical", &[71, 769]),
+  (501, "Canonical", &[103, 769]), (504, "Canonical", &[78, 768]),
+  (505, "Canonical", &[110, 768]), (506, "Canonical", &[197, 769]),
+  (507, "Canonical", &[229, 769]), (508, "Canonical", &[198, 769]),
+  (509, "Canonical", &[230, 769]), (510, "Canonical", &[216, 769]),
+  (511, "Canonical", &[248, 769]), (512, "Canonical", &[65, 783]),
+  (513, "Canonical", &[97, 783]), (514, "Canonical", &[65, 785]),
+  (515, "Canonical", &[97, 785]), (516, "Canonical", &[69, 783]),
+  (517, "Canonical", &[101, 783]), (518, "Canonical", &[69, 785]),
+  (519, "Canonical", &[101, 785]), (520, "Canonical", &[73, 783]),
+  (521, "Canonical", &[105, 783]), (522, "Canonical", &[73, 785]),
+  (523, "Canonical", &[105, 785]), (524, "Canonical", &[79, 783]),
+  (525, "Canonical", &[111, 783]), (526, "Canonical", &[79, 785]),
+  (527, "Canonical", &[111, 785]), (528, "Canonical", &[82, 783]),
+  (529, "Canonical", &[114, 783]), (530, "Canonical", &[82, 785]),
+  (531, "Canonical", &[114, 785]), (532, "Canonical", &[85, 783]),
+  (533, "Canonical", &[117, 783]), (534, "Canonical", &[85, 785]),
+  (535, "Canonical", &[117, 785]), (536, "Canonical", &[83, 806]),
+  (537, "Canonical", &[115, 806]), (538, "Canonical", &[84, 806]),
+  (539, "Canonical", &[116, 806]), (542, "Canonical", &[72, 780]),
+  (543, "Canonical", &[104, 780]), (550, "Canonical", &[65, 775]),
+  (551, "Canonical", &[97, 775]), (552, "Canonical", &[69, 807]),
+  (553, "Canonical", &[101, 807]), (554, "Canonical", &[214, 772]),
+  (555, "Canonical", &[246, 772]), (556, "Canonical", &[213, 772]),
+  (557, "Canonical", &[245, 772]), (558, "Canonical", &[79, 775]),
+  (559, "Canonical", &[111, 775]), (560, "Canonical", &[558, 772]),
+  (561, "Canonical", &[559, 772]), (562, "Canonical", &[89, 772]),
+  (563, "Canonical", &[121, 772]), (688, "Super", &[104]),
+  (689, "Super", &[614]), (690, "Super", &[106]), (691, "Super", &[114]),
+  (692, "Super", &[633]), (693, "Super", &[635]), (694, "Super", &[641]),
+  (695, "Super", &[119]), (696, "Super", &[121]), (728, "Compat", &[32, 774]),
+  (729, "Compat", &[32, 775]), (730, "Compat", &[32, 778]),
+  (731, "Compat", &[32, 808]), (732, "Compat", &[32, 771]),
+  (733, "Compat", &[32, 779]), (736, "Super", &[611]), (737, "Super", &[108]),
+  (738, "Super", &[115]), (739, "Super", &[120]), (740, "Super", &[661]),
+  (832, "Canonical", &[768]), (833, "Canonical", &[769]),
+  (835, "Canonical", &[787]), (836, "Canonical", &[776, 769]),
+  (884, "Canonical", &[697]), (890, "Compat", &[32, 837]),
+  (894, "Canonical", &[59]), (900, "Compat", &[32, 769]),
+  (901, "Canonical", &[168, 769]), (902, "Canonical", &[913, 769]),
+  (903, "Canonical", &[183]), (904, "Canonical", &[917, 769]),
+  (905, "Canonical", &[919, 769]), (906, "Canonical", &[921, 769]),
+  (908, "Canonical", &[927, 769]), (910, "Canonical", &[933, 769]),
+  (911, "Canonical", &[937, 769]), (912, "Canonical", &[970, 769]),
+  (938, "Canonical", &[921, 776]), (939, "Canonical", &[933, 776]),
+  (940, "Canonical", &[945, 769]), (941, "Canonical", &[949, 769]),
+  (942, "Canonical", &[951, 769]), (943, "Canonical", &[953, 769]),
+  (944, "Canonical", &[971, 769]), (970, "Canonical", &[953, 776]),
+  (971, "Canonical", &[965, 776]), (972, "Canonical", &[959, 769]),
+  (973, "Canonical", &[965, 769]), (974, "Canonical", &[969, 769]),
+  (976, "Compat", &[946]), (977, "Compat", &[952]), (978, "Compat", &[933]),
+  (979, "Canonical", &[978, 769]), (980, "Canonical", &[978, 776]),
+  (981, "Compat", &[966]), (982, "Compat", &[960]), (1008, "Compat", &[954]),
+  (1009, "Compat", &[961]), (1010, "Compat", &[962]), (1012, "Compat", &[920]),
+  (1013, "Compat", &[949]), (1017, "Compat", &[931]),
+  (1024, "Canonical", &[1045, 768]), (1025, "Canonical", &[1045, 776]),
+  (1027, "Canonical", &[1043, 769]), (1031, "Canonical", &[1030, 776]),
+  (1036, "Canonical", &[1050, 769]), (1037, "Canonical", &[1048, 768]),
+  (1038, "Canonical", &[1059, 774]), (1049, "Canonical", &[1048, 774]),
+  (1081, "Canonical", &[1080, 774]), (1104, "Canonical", &[1077, 768]),
+  (1105, "Canonical", &[1077, 776]), (1107, "Canonical", &[1075, 769]),
+  (1111, "Canonical", &[1110, 776]), (1116, "Canonical", &[1082, 769]),
+  (1117, "Canonical", &[1080, 768]), (1118, "Canonical", &[1091, 774]),
+  (1142, "Canonical", &[1140, 783]), (1143, "Canonical", &[1141, 783]),
+  (1217, "Canonical", &[1046, 774]), (1218, "Canonical", &[1078, 774]),
+  (1232, "Canonical", &[1040, 774]), (1233, "Canonical", &[1072, 774]),
+  (1234, "Canonical", &[1040, 776]), (1235, "Canonical", &[1072, 776]),
+  (1238, "Canonical", &[1045, 774]), (1239, "Canonical", &[1077, 774]),
+  (1242, "Canonical", &[1240, 776]), (1243, "Canonical", &[1241, 776]),
+  (1244, "Canonical", &[1046, 776]), (1245, "Canonical", &[1078, 776]),
+  (1246, "Canonical", &[1047, 776]), (1247, "Canonical", &[1079, 776]),
+  (1250, "Canonical", &[1048, 772]), (1251, "Canonical", &[1080, 772]),
+  (1252, "Canonical", &[1048, 776]), (1253, "Canonical", &[1080, 776]),
+  (1254, "Canonical", &[1054, 776]), (1255, "Canonical", &[1086, 776]),
+  (1258, "Canonical", &[1256, 776]), (1259, "Canonical", &[1257, 776]),
+  (1260, "Canonical", &[1069, 776]), (1261, "Canonical", &[1101, 776]),
+  (1262, "Canonical", &[1059, 772]), (1263, "Canonical", &[1091, 772]),
+  (1264, "Canonical", &[1059, 776]), (1265, "Canonical", &[1091, 776]),
+  (1266, "Canonical", &[1059, 779]), (1267, "Canonical", &[1091, 779]),
+  (1268, "Canonical", &[1063, 776]), (1269, "Canonical", &[1095, 776]),
+  (1272, "Canonical", &[1067, 776]), (1273, "Canonical", &[1099, 776]),
+  (1415, "Compat", &[1381, 1410]), (1570, "Canonical", &[1575, 1619]),
+  (1571, "Canonical", &[1575, 1620]), (1572, "Canonical", &[1608, 1620]),
+  (1573, "Canonical", &[1575, 1621]), (1574, "Canonical", &[1610, 1620]),
+  (1653, "Compat", &[1575, 1652]), (1654, "Compat", &[1608, 1652]),
+  (1655, "Compat", &[1735, 1652]), (1656, "Compat", &[1610, 1652]),
+  (1728, "Canonical", &[1749, 1620]), (1730, "Canonical", &[1729, 1620]),
+  (1747, "Canonical", &[1746, 1620]), (2345, "Canonical", &[2344, 2364]),
+  (2353, "Canonical", &[2352, 2364]), (2356, "Canonical", &[2355, 2364]),
+  (2392, "Canonical", &[2325, 2364]), (2393, "Canonical", &[2326, 2364]),
+  (2394, "Canonical", &[2327, 2364]), (2395, "Canonical", &[2332, 2364]),
+  (2396, "Canonical", &[2337, 2364]), (2397, "Canonical", &[2338, 2364]),
+  (2398, "Canonical", &[2347, 2364]), (2399, "Canonical", &[2351, 2364]),
+  (2507, "Canonical", &[2503, 2494]), (2508, "Canonical", &[2503, 2519]),
+  (2524, "Canonical", &[2465, 2492]), (2525, "Canonical", &[2466, 2492]),
+  (2527, "Canonical", &[2479, 2492]), (2611, "Canonical", &[2610, 2620]),
+  (2614, "Canonical", &[2616, 2620]), (2649, "Canonical", &[2582, 2620]),
+  (2650, "Canonical", &[2583, 2620]), (2651, "Canonical", &[2588, 2620]),
+  (2654, "Canonical", &[2603, 2620]), (2888, "Canonical", &[2887, 2902]),
+  (2891, "Canonical", &[2887, 2878]), (2892, "Canonical", &[2887, 2903]),
+  (2908, "Canonical", &[2849, 2876]), (2909, "Canonical", &[2850, 2876]),
+  (2964, "Canonical", &[2962, 3031]), (3018, "Canonical", &[3014, 3006]),
+  (3019, "Canonical", &[3015, 3006]), (3020, "Canonical", &[3014, 3031]),
+  (3144, "Canonical", &[3142, 3158]), (3264, "Canonical", &[3263, 3285]),
+  (3271, "Canonical", &[3270, 3285]), (3272, "Canonical", &[3270, 3286]),
+  (3274, "Canonical", &[3270, 3266]), (3275, "Canonical", &[3274, 3285]),
+  (3402, "Canonical", &[3398, 3390]), (3403, "Canonical", &[3399, 3390]),
+  (3404, "Canonical", &[3398, 3415]), (3546, "Canonical", &[3545, 3530]),
+  (3548, "Canonical", &[3545, 3535]), (3549, "Canonical", &[3548, 3530]),
+  (3550, "Canonical", &[3545, 3551]), (3635, "Compat", &[3661, 3634]),
+  (3763, "Compat", &[3789, 3762]), (3804, "Compat", &[3755, 3737]),
+  (3805, "Compat", &[3755, 3745]), (3852, "Nobreak", &[3851]),
+  (3907, "Canonical", &[3906, 4023]), (3917, "Canonical", &[3916, 4023]),
+  (3922, "Canonical", &[3921, 4023]), (3927, "Canonical", &[3926, 4023]),
+  (3932, "Canonical", &[3931, 4023]), (3945, "Canonical", &[3904, 4021]),
+  (3955, "Canonical", &[3953, 3954]), (3957, "Canonical", &[3953, 3956]),
+  (3958, "Canonical", &[4018, 3968]), (3959, "Compat", &[4018, 3969]),
+  (3960, "Canonical", &[4019, 3968]), (3961, "Compat", &[4019, 3969]),
+  (3969, "Canonical", &[3953, 3968]), (3987, "Canonical", &[3986, 4023]),
+  (3997, "Canonical", &[3996, 4023]), (4002, "Canonical", &[4001, 4023]),
+  (4007, "Canonical", &[4006, 4023]), (4012, "Canonical", &[4011, 4023]),
+  (4025, "Canonical", &[3984, 4021]), (4134, "Canonical", &[4133, 4142]),
+  (4348, "Super", &[4316]), (6918, "Canonical", &[6917, 6965]),
+  (6920, "Canonical", &[6919, 6965]), (6922, "Canonical", &[6921, 6965]),
+  (6924, "Canonical", &[6923, 6965]), (6926, "Canonical", &[6925, 6965]),
+  (6930, "Canonical", &[6929, 6965]), (6971, "Canonical", &[6970, 6965]),
+  (6973, "Canonical", &[6972, 6965]), (6976, "Canonical", &[6974, 6965]),
+  (6977, "Canonical", &[6975, 6965]), (6979, "Canonical", &[6978, 6965]),
+  (7468, "Super", &[65]), (7469, "Super", &[198]), (7470, "Super", &[66]),
+  (7472, "Super", &[68]), (7473, "Super", &[69]), (7474, "Super", &[398]),
+  (7475, "Super", &[71]), (7476, "Super", &[72]), (7477, "Super", &[73]),
+  (7478, "Super", &[74]), (7479, "Super", &[75]), (7480, "Super", &[76]),
+  (7481, "Super", &[77]), (7482, "Super", &[78]), (7484, "Super", &[79]),
+  (7485, "Super", &[546]), (7486, "Super", &[80]), (7487, "Super", &[82]),
+  (7488, "Super", &[84]), (7489, "Super", &[85]), (7490, "Super", &[87]),
+  (7491, "Super", &[97]), (7492, "Super", &[592]), (7493, "Super", &[593]),
+  (7494, "Super", &[7426]), (7495, "Super", &[98]), (7496, "Super", &[100]),
+  (7497, "Super", &[101]), (7498, "Super", &[601]), (7499, "Super", &[603]),
+  (7500, "Super", &[604]), (7501, "Super", &[103]), (7503, "Super", &[107]),
+  (7504, "Super", &[109]), (7505, "Super", &[331]), (7506, "Super", &[111]),
+  (7507, "Super", &[596]), (7508, "Super", &[7446]), (7509, "Super", &[7447]),
+  (7510, "Super", &[112]), (7511, "Super", &[116]), (7512, "Super", &[117]),
+  (7513, "Super", &[7453]), (7514, "Super", &[623]), (7515, "Super", &[118]),
+  (7516, "Super", &[7461]), (7517, "Super", &[946]), (7518, "Super", &[947]),
+  (7519, "Super", &[948]), (7520, "Super", &[966]), (7521, "Super", &[967]),
+  (7522, "Sub", &[105]), (7523, "Sub", &[114]), (7524, "Sub", &[117]),
+  (7525, "Sub", &[118]), (7526, "Sub", &[946]), (7527, "Sub", &[947]),
+  (7528, "Sub", &[961]), (7529, "Sub", &[966]), (7530, "Sub", &[967]),
+  (7544, "Super", &[1085]), (7579, "Super", &[594]), (7580, "Super", &[99]),
+  (7581, "Super", &[597]), (7582, "Super", &[240]), (7583, "Super", &[604]),
+  (7584, "Super", &[102]), (7585, "Super", &[607]), (7586, "Super", &[609]),
+  (7587, "Super", &[613]), (7588, "Super", &[616]), (7589, "Super", &[617]),
+  (7590, "Super", &[618]), (7591, "Super", &[7547]), (7592, "Super", &[669]),
+  (7593, "Super", &[621]), (7594, "Super", &[7557]), (7595, "Super", &[671]),
+  (7596, "Super", &[625]), (7597, "Super", &[624]), (7598, "Super", &[626]),
+  (7599, "Super", &[627]), (7600, "Super", &[628]), (7601, "Super", &[629]),
+  (7602, "Super", &[632]), (7603, "Super", &[642]), (7604, "Super", &[643]),
+  (7605, "Super", &[427]), (7606, "Super", &[649]), (7607, "Super", &[650]),
+  (7608, "Super", &[7452]), (7609, "Super", &[651]), (7610, "Super", &[652]),
+  (7611, "Super", &[122]), (7612, "Super", &[656]), (7613, "Super", &[657]),
+  (7614, "Super", &[658]), (7615, "Super", &[952]),
+  (7680, "Canonical", &[65, 805]), (7681, "Canonical", &[97, 805]),
+  (7682, "Canonical", &[66, 775]), (7683, "Canonical", &[98, 775]),
+  (7684, "Canonical", &[66, 803]), (7685, "Canonical", &[98, 803]),
+  (7686, "Canonical", &[66, 817]), (7687, "Canonical", &[98, 817]),
+  (7688, "Canonical", &[199, 769]), (7689, "Canonical", &[231, 769]),
+  (7690, "Canonical", &[68, 775]), (7691, "Canonical", &[100, 775]),
+  (7692, "Canonical", &[68, 803]), (7693, "Canonical", &[100, 803]),
+  (7694, "Canonical", &[68, 817]), (7695, "Canonical", &[100, 817]),
+  (7696, "Canonical", &[68, 807]), (7697, "Canonical", &[100, 807]),
+  (7698, "Canonical", &[68, 813]), (7699, "Canonical", &[100, 813]),
+  (7700, "Canonical", &[274, 768]), (7701, "Canonical", &[275, 768]),
+  (7702, "Canonical", &[274, 769]), (7703, "Canonical", &[275, 769]),
+  (7704, "Canonical", &[69, 813]), (7705, "Canonical", &[101, 813]),
+  (7706, "Canonical", &[69, 816]), (7707, "Canonical", &[101, 816]),
+  (7708, "Canonical", &[552, 774]), (7709, "Canonical", &[553, 774]),
+  (7710, "Canonical", &[70, 775]), (7711, "Canonical", &[102, 775]),
+  (7712, "Canonical", &[71, 772]), (7713, "Canonical", &[103, 772]),
+  (7714, "Canonical", &[72, 775]), (7715, "Canonical", &[104, 775]),
+  (7716, "Canonical", &[72, 803]), (7717, "Canonical", &[104, 803]),
+  (7718, "Canonical", &[72, 776]), (7719, "Canonical", &[104, 776]),
+  (7720, "Canonical", &[72, 807]), (7721, "Canonical", &[104, 807]),
+  (7722, "Canonical", &[72, 814]), (7723, "Canonical", &[104, 814]),
+  (7724, "Canonical", &[73, 816]), (7725, "Canonical", &[105, 816]),
+  (7726, "Canonical", &[207, 769]), (7727, "Canonical", &[239, 769]),
+  (7728, "Canonical", &[75, 769]), (7729, "Canonical", &[107, 769]),
+  (7730, "Canonical", &[75, 803]), (7731, "Canonical", &[107, 803]),
+  (7732, "Canonical", &[75, 817]), (7733, "Canonical", &[107, 817]),
+  (7734, "Canonical", &[76, 803]), (7735, "Canonical", &[108, 803]),
+  (7736, "Canonical", &[7734, 772]), (7737, "Canonical", &[7735, 772]),
+  (7738, "Canonical", &[76, 817]), (7739, "Canonical", &[108, 817]),
+  (7740, "Canonical", &[76, 813]), (7741, "Canonical", &[108, 813]),
+  (7742, "Canonical", &[77, 769]), (7743, "Canonical", &[109, 769]),
+  (7744, "Canonical", &[77, 775]), (7745, "Canonical", &[109, 775]),
+  (7746, "Canonical", &[77, 803]), (7747, "Canonical", &[109, 803]),
+  (7748, "Canonical", &[78, 775]), (7749, "Canonical", &[110, 775]),
+  (7750, "Canonical", &[78, 803]), (7751, "Canonical", &[110, 803]),
+  (7752, "Canonical", &[78, 817]), (7753, "Canonical", &[110, 817]),
+  (7754, "Canonical", &[78, 813]), (7755, "Canonical", &[110, 813]),
+  (7756, "Canonical", &[213, 769]), (7757, "Canonical", &[245, 769]),
+  (7758, "Canonical", &[213, 776]), (7759, "Canonical", &[245, 776]),
+  (7760, "Canonical", &[332, 768]), (7761, "Canonical", &[333, 768]),
+  (7762, "Canonical", &[332, 769]), (7763, "Canonical", &[333, 769]),
+  (7764, "Canonical", &[80, 769]), (7765, "Canonical", &[112, 769]),
+  (7766, "Canonical", &[80, 775]), (7767, "Canonical", &[112, 775]),
+  (7768, "Canonical", &[82, 775]), (7769, "Canonical", &[114, 775]),
+  (7770, "Canonical", &[82, 803]), (7771, "Canonical", &[114, 803]),
+  (7772, "Canonical", &[7770, 772]), (7773, "Canonical", &[7771, 772]),
+  (7774, "Canonical", &[82, 817]), (7775, "Canonical", &[114, 817]),
+  (7776, "Canonical", &[83, 775]), (7777, "Canonical", &[115, 775]),
+  (7778, "Canonical", &[83, 803]), (7779, "Canonical", &[115, 803]),
+  (7780, "Canonical", &[346, 775]), (7781, "Canonical", &[347, 775]),
+  (7782, "Canonical", &[352, 775]), (7783, "Canonical", &[353, 775]),
+  (7784, "Canonical", &[7778, 775]), (7785, "Canonical", &[7779, 775]),
+  (7786, "Canonical", &[84, 775]), (7787, "Canonical", &[116, 775]),
+  (7788, "Canonical", &[84, 803]), (7789, "Canonical", &[116, 803]),
+  (7790, "Canonical", &[84, 817]), (7791, "Canonical", &[116, 817]),
+  (7792, "Canonical", &[84, 813]), (7793, "Canonical", &[116, 813]),
+  (7794, "Canonical", &[85, 804]), (7795, "Canonical", &[117, 804]),
+  (7796, "Canonical", &[85, 816]), (7797, "Canonical", &[117, 816]),
+  (7798, "Canonical", &[85, 813]), (7799, "Canonical", &[117, 813]),
+  (7800, "Canonical", &[360, 769]), (7801, "Canonical", &[361, 769]),
+  (7802, "Canonical", &[362, 776]), (7803, "Canonical", &[363, 776]),
+  (7804, "Canonical", &[86, 771]), (7805, "Canonical", &[118, 771]),
+  (7806, "Canonical", &[86, 803]), (7807, "Canonical", &[118, 803]),
+  (7808, "Canonical", &[87, 768]), (7809, "Canonical", &[119, 768]),
+  (7810, "Canonical", &[87, 769]), (7811, "Canonical", &[119, 769]),
+  (7812, "Canonical", &[87, 776]), (7813, "Canonical", &[119, 776]),
+  (7814, "Canonical", &[87, 775]), (7815, "Canonical", &[119, 775]),
+  (7816, "Canonical", &[87, 803]), (7817, "Canonical", &[119, 803]),
+  (7818, "Canonical", &[88, 775]), (7819, "Canonical", &[120, 775]),
+  (7820, "Canonical", &[88, 776]), (7821, "Canonical", &[120, 776]),
+  (7822, "Canonical", &[89, 775]), (7823, "Canonical", &[121, 775]),
+  (7824, "Canonical", &[90, 770]), (7825, "Canonical", &[122, 770]),
+  (7826, "Canonical", &[90, 803]), (7827, "Canonical", &[122, 803]),
+  (7828, "Canonical", &[90, 817]), (7829, "Canonical", &[122, 817]),
+  (7830, "Canonical", &[104, 817]), (7831, "Canonical", &[116, 776]),
+  (7832, "Canonical", &[119, 778]), (7833, "Canonical", &[121, 778]),
+  (7834, "Compat", &[97, 702]), (7835, "Canonical", &[383, 775]),
+  (7840, "Canonical", &[65, 803]), (7841, "Canonical", &[97, 803]),
+  (7842, "Canonical", &[65, 777]), (7843, "Canonical", &[97, 777]),
+  (7844, "Canonical", &[194, 769]), (7845, "Canonical", &[226, 769]),
+  (7846, "Canonical", &[194, 768]), (7847, "Canonical", &[226, 768]),
+  (7848, "Canonical", &[194, 777]), (7849, "Canonical", &[226, 777]),
+  (7850, "Canonical", &[194, 771]), (7851, "Canonical", &[226, 771]),
+  (7852, "Canonical", &[7840, 770]), (7853, "Canonical", &[7841, 770]),
+  (7854, "Canonical", &[258, 769]), (7855, "Canonical", &[259, 769]),
+  (7856, "Canonical", &[258, 768]), (7857, "Canonical", &[259, 768]),
+  (7858, "Canonical", &[258, 777]), (7859, "Canonical", &[259, 777]),
+  (7860, "Canonical", &[258, 771]), (7861, "Canonical", &[259, 771]),
+  (7862, "Canonical", &[7840, 774]), (7863, "Canonical", &[7841, 774]),
+  (7864, "Canonical", &[69, 803]), (7865, "Canonical", &[101, 803]),
+  (7866, "Canonical", &[69, 777]), (7867, "Canonical", &[101, 777]),
+  (7868, "Canonical", &[69, 771]), (7869, "Canonical", &[101, 771]),
+  (7870, "Canonical", &[202, 769]), (7871, "Canonical", &[234, 769]),
+  (7872, "Canonical", &[202, 768]), (7873, "Canonical", &[234, 768]),
+  (7874, "Canonical", &[202, 777]), (7875, "Canonical", &[234, 777]),
+  (7876, "Canonical", &[202, 771]), (7877, "Canonical", &[234, 771]),
+  (7878, "Canonical", &[7864, 770]), (7879, "Canonical", &[7865, 770]),
+  (7880, "Canonical", &[73, 777]), (7881, "Canonical", &[105, 777]),
+  (7882, "Canonical", &[73, 803]), (7883, "Canonical", &[105, 803]),
+  (7884, "Canonical", &[79, 803]), (7885, "Canonical", &[111, 803]),
+  (7886, "Canonical", &[79, 777]), (7887, "Canonical", &[111, 777]),
+  (7888, "Canonical", &[212, 769]), (7889, "Canonical", &[244, 769]),
+  (7890, "Canonical", &[212, 768]), (7891, "Canonical", &[244, 768]),
+  (7892, "Canonical", &[212, 777]), (7893, "Canonical", &[244, 777]),
+  (7894, "Canonical", &[212, 771]), (7895, "Canonical", &[244, 771]),
+  (7896, "Canonical", &[7884, 770]), (7897, "Canonical", &[7885, 770]),
+  (7898, "Canonical", &[416, 769]), (7899, "Canonical", &[417, 769]),
+  (7900, "Canonical", &[416, 768]), (7901, "Canonical", &[417, 768]),
+  (7902, "Canonical", &[416, 777]), (7903, "Canonical", &[417, 777]),
+  (7904, "Canonical", &[416, 771]), (7905, "Canonical", &[417, 771]),
+  (7906, "Canonical", &[416, 803]), (7907, "Canonical", &[417, 803]),
+  (7908, "Canonical", &[85, 803]), (7909, "Canonical", &[117, 803]),
+  (7910, "Canonical", &[85, 777]), (7911, "Canonical", &[117, 777]),
+  (7912, "Canonical", &[431, 769]), (7913, "Canonical", &[432, 769]),
+  (7914, "Canonical", &[431, 768]), (7915, "Canonical", &[432, 768]),
+  (7916, "Canonical", &[431, 777]), (7917, "Canonical", &[432, 777]),
+  (7918, "Canonical", &[431, 771]), (7919, "Canonical", &[432, 771]),
+  (7920, "Canonical", &[431, 803]), (7921, "Canonical", &[432, 803]),
+  (7922, "Canonical", &[89, 768]), (7923, "Canonical", &[121, 768]),
+  (7924, "Canonical", &[89, 803]), (7925, "Canonical", &[121, 803]),
+  (7926, "Canonical", &[89, 777]), (7927, "Canonical", &[121, 777]),
+  (7928, "Canonical", &[89, 771]), (7929, "Canonical", &[121, 771]),
+  (7936, "Canonical", &[945, 787]), (7937, "Canonical", &[945, 788]),
+  (7938, "Canonical", &[7936, 768]), (7939, "Canonical", &[7937, 768]),
+  (7940, "Canonical", &[7936, 769]), (7941, "Canonical", &[7937, 769]),
+  (7942, "Canonical", &[7936, 834]), (7943, "Canonical", &[7937, 834]),
+  (7944, "Canonical", &[913, 787]), (7945, "Canonical", &[913, 788]),
+  (7946, "Canonical", &[7944, 768]), (7947, "Canonical", &[7945, 768]),
+  (7948, "Canonical", &[7944, 769]), (7949, "Canonical", &[7945, 769]),
+  (7950, "Canonical", &[7944, 834]), (7951, "Canonical", &[7945, 834]),
+  (7952, "Canonical", &[949, 787]), (7953, "Canonical", &[949, 788]),
+  (7954, "Canonical", &[7952, 768]), (7955, "Canonical", &[7953, 768]),
+  (7956, "Canonical", &[7952, 769]), (7957, "Canonical", &[7953, 769]),
+  (7960, "Canonical", &[917, 787]), (7961, "Canonical", &[917, 788]),
+  (7962, "Canonical", &[7960, 768]), (7963, "Canonical", &[7961, 768]),
+  (7964, "Canonical", &[7960, 769]), (7965, "Canonical", &[7961, 769]),
+  (7968, "Canonical", &[951, 787]), (7969, "Canonical", &[951, 788]),
+  (7970, "Canonical", &[7968, 768]), (7971, "Canonical", &[7969, 768]),
+  (7972, "Canonical", &[7968, 769]), (7973, "Canonical", &[7969, 769]),
+  (7974, "Canonical", &[7968, 834]), (7975, "Canonical", &[7969, 834]),
+  (7976, "Canonical", &[919, 787]), (7977, "Canonical", &[919, 788]),
+  (7978, "Canonical", &[7976, 768]), (7979, "Canonical", &[7977, 768]),
+  (7980, "Canonical", &[7976, 769]), (7981, "Canonical", &[7977, 769]),
+  (7982, "Canonical", &[7976, 834]), (7983, "Canonical", &[7977, 834]),
+  (7984, "Canonical", &[953, 787]), (7985, "Canonical", &[953, 788]),
+  (7986, "Canonical", &[7984, 768]), (7987, "Canonical", &[7985, 768]),
+  (7988, "Canonical", &[7984, 769]), (7989, "Canonical", &[7985, 769]),
+  (7990, "Canonical", &[7984, 834]), (7991, "Canonical", &[7985, 834]),
+  (7992, "Canonical", &[921, 787]), (7993, "Canonical", &[921, 788]),
+  (7994, "Canonical", &[7992, 768]), (7995, "Canonical", &[7993, 768]),
+  (7996, "Canonical", &[7992, 769]), (7997, "Canonical", &[7993, 769]),
+  (7998, "Canonical", &[7992, 834]), (7999, "Canonical", &[7993, 834]),
+  (8000, "Canonical", &[959, 787]), (8001, "Canonical", &[959, 788]),
+  (8002, "Canonical", &[8000, 768]), (8003, "Canonical", &[8001, 768]),
+  (8004, "Canonical", &[8000, 769]), (8005, "Canonical", &[8001, 769]),
+  (8008, "Canonical", &[927, 787]), (8009, "Canonical", &[927, 788]),
+  (8010, "Canonical", &[8008, 768]), (8011, "Canonical", &[8009, 768]),
+  (8012, "Canonical", &[8008, 769]), (8013, "Canonical", &[8009, 769]),
+  (8016, "Canonical", &[965, 787]), (8017, "Canonical", &[965, 788]),
+  (8018, "Canonical", &[8016, 768]), (8019, "Canonical", &[8017, 768]),
+  (8020, "Canonical", &[8016, 769]), (8021, "Canonical", &[8017, 769]),
+  (8022, "Canonical", &[8016, 834]), (8023, "Canonical", &[8017, 834]),
+  (8025, "Canonical", &[933, 788]), (8027, "Canonical", &[8025, 768]),
+  (8029, "Canonical", &[8025, 769]), (8031, "Canonical", &[8025, 834]),
+  (8032, "Canonical", &[969, 787]), (8033, "Canonical", &[969, 788]),
+  (8034, "Canonical", &[8032, 768]), (8035, "Canonical", &[8033, 768]),
+  (8036, "Canonical", &[8032, 769]), (8037, "Canonical", &[8033, 769]),
+  (8038, "Canonical", &[8032, 834]), (8039, "Canonical", &[8033, 834]),
+  (8040, "Canonical", &[937, 787]), (8041, "Canonical", &[937, 788]),
+  (8042, "Canonical", &[8040, 768]), (8043, "Canonical", &[8041, 768]),
+  (8044, "Canonical", &[8040, 769]), (8045, "Canonical", &[8041, 769]),
+  (8046, "Canonical", &[8040, 834]), (8047, "Canonical", &[8041, 834]),
+  (8048, "Canonical", &[945, 768]), (8049, "Canonical", &[940]),
+  (8050, "Canonical", &[949, 768]), (8051, "Canonical", &[941]),
+  (8052, "Canonical", &[951, 768]), (8053, "Canonical", &[942]),
+  (8054, "Canonical", &[953, 768]), (8055, "Canonical", &[943]),
+  (8056, "Canonical", &[959, 768]), (8057, "Canonical", &[972]),
+  (8058, "Canonical", &[965, 768]), (8059, "Canonical", &[973]),
+  (8060, "Canonical", &[969, 768]), (8061, "Canonical", &[974]),
+  (8064, "Canonical", &[7936, 837]), (8065, "Canonical", &[7937, 837]),
+  (8066, "Canonical", &[7938, 837]), (8067, "Canonical", &[7939, 837]),
+  (8068, "Canonical", &[7940, 837]), (8069, "Canonical", &[7941, 837]),
+  (8070, "Canonical", &[7942, 837]), (8071, "Canonical", &[7943, 837]),
+  (8072, "Canonical", &[7944, 837]), (8073, "Canonical", &[7945, 837]),
+  (8074, "Canonical", &[7946, 837]), (8075, "Canonical", &[7947, 837]),
+  (8076, "Canonical", &[7948, 837]), (8077, "Canonical", &[7949, 837]),
+  (8078, "Canonical", &[7950, 837]), (8079, "Canonical", &[7951, 837]),
+  (8080, "Canonical", &[7968, 837]), (8081, "Canonical", &[7969, 837]),
+  (8082, "Canonical", &[7970, 837]), (8083, "Canonical", &[7971, 837]),
+  (8084, "Canonical", &[7972, 837]), (8085, "Canonical", &[7973, 837]),
+  (8086, "Canonical", &[7974, 837]), (8087, "Canonical", &[7975, 837]),
+  (8088, "Canonical", &[7976, 837]), (8089, "Canonical", &[7977, 837]),
+  (8090, "Canonical", &[7978, 837]), (8091, "Canonical", &[7979, 837]),
+  (8092, "Canonical", &[7980, 837]), (8093, "Canonical", &[7981, 837]),
+  (8094, "Canonical", &[7982, 837]), (8095, "Canonical", &[7983, 837]),
+  (8096, "Canonical", &[8032, 837]), (8097, "Canonical", &[8033, 837]),
+  (8098, "Canonical", &[8034, 837]), (8099, "Canonical", &[8035, 837]),
+  (8100, "Canonical", &[8036, 837]), (8101, "Canonical", &[8037, 837]),
+  (8102, "Canonical", &[8038, 837]), (8103, "Canonical", &[8039, 837]),
+  (8104, "Canonical", &[8040, 837]), (8105, "Canonical", &[8041, 837]),
+  (8106, "Canonical", &[8042, 837]), (8107, "Canonical", &[8043, 837]),
+  (8108, "Canonical", &[8044, 837]), (8109, "Canonical", &[8045, 837]),
+  (8110, "Canonical", &[8046, 837]), (8111, "Canonical", &[8047, 837]),
+  (8112, "Canonical", &[945, 774]), (8113, "Canonical", &[945, 772]),
+  (8114, "Canonical", &[8048, 837]), (8115, "Canonical", &[945, 837]),
+  (8116, "Canonical", &[940, 837]), (8118, "Canonical", &[945, 834]),
+  (8119, "Canonical", &[8118, 837]), (8120, "Canonical", &[913, 774]),
+  (8121, "Canonical", &[913, 772]), (8122, "Canonical", &[913, 768]),
+  (8123, "Canonical", &[902]), (8124, "Canonical", &[913, 837]),
+  (8125, "Compat", &[32, 787]), (8126, "Canonical", &[953]),
+  (8127, "Compat", &[32, 787]), (8128, "Compat", &[32, 834]),
+  (8129, "Canonical", &[168, 834]), (8130, "Canonical", &[8052, 837]),
+  (8131, "Canonical", &[951, 837]), (8132, "Canonical", &[942, 837]),
+  (8134, "Canonical", &[951, 834]), (8135, "Canonical", &[8134, 837]),
+  (8136, "Canonical", &[917, 768]), (8137, "Canonical", &[904]),
+  (8138, "Canonical", &[919, 768]), (8139, "Canonical", &[905]),
+  (8140, "Canonical", &[919, 837]), (8141, "Canonical", &[8127, 768]),
+  (8142, "Canonical", &[8127, 769]), (8143, "Canonical", &[8127, 834]),
+  (8144, "Canonical", &[953, 774]), (8145, "Canonical", &[953, 772]),
+  (8146, "Canonical", &[970, 768]), (8147, "Canonical", &[912]),
+  (8150, "Canonical", &[953, 834]), (8151, "Canonical", &[970, 834]),
+  (8152, "Canonical", &[921, 774]), (8153, "Canonical", &[921, 772]),
+  (8154, "Canonical", &[921, 768]), (8155, "Canonical", &[906]),
+  (8157, "Canonical", &[8190, 768]), (8158, "Canonical", &[8190, 769]),
+  (8159, "Canonical", &[8190, 834]), (8160, "Canonical", &[965, 774]),
+  (8161, "Canonical", &[965, 772]), (8162, "Canonical", &[971, 768]),
+  (8163, "Canonical", &[944]), (8164, "Canonical", &[961, 787]),
+  (8165, "Canonical", &[961, 788]), (8166, "Canonical", &[965, 834]),
+  (8167, "Canonical", &[971, 834]), (8168, "Canonical", &[933, 774]),
+  (8169, "Canonical", &[933, 772]), (8170, "Canonical", &[933, 768]),
+  (8171, "Canonical", &[910]), (8172, "Canonical", &[929, 788]),
+  (8173, "Canonical", &[168, 768]), (8174, "Canonical", &[901]),
+  (8175, "Canonical", &[96]), (8178, "Canonical", &[8060, 837]),
+  (8179, "Canonical", &[969, 837]), (8180, "Canonical", &[974, 837]),
+  (8182, "Canonical", &[969, 834]), (8183, "Canonical", &[8182, 837]),
+  (8184, "Canonical", &[927, 768]), (8185, "Canonical", &[908]),
+  (8186, "Canonical", &[937, 768]), (8187, "Canonical", &[911]),
+  (8188, "Canonical", &[937, 837]), (8189, "Canonical", &[180]),
+  (8190, "Compat", &[32, 788]), (8192, "Canonical", &[8194]),
+  (8193, "Canonical", &[8195]), (8194, "Compat", &[32]),
+  (8195, "Compat", &[32]), (8196, "Compat", &[32]), (8197, "Compat", &[32]),
+  (8198, "Compat", &[32]), (8199, "Nobreak", &[32]), (8200, "Compat", &[32]),
+  (8201, "Compat", &[32]), (8202, "Compat", &[32]), (8209, "Nobreak", &[8208]),
+  (8215, "Compat", &[32, 819]), (8228, "Compat", &[46]),
+  (8229, "Compat", &[46, 46]), (8230, "Compat", &[46, 46, 46]),
+  (8239, "Nobreak", &[32]), (8243, "Compat", &[8242, 8242]),
+  (8244, "Compat", &[8242, 8242, 8242]), (8246, "Compat", &[8245, 8245]),
+  (8247, "Compat", &[8245, 8245, 8245]), (8252, "Compat", &[33, 33]),
+  (8254, "Compat", &[32, 773]), (8263, "Compat", &[63, 63]),
+  (8264, "Compat", &[63, 33]), (8265, "Compat", &[33, 63]),
+  (8279, "Compat", &[8242, 8242, 8242, 8242]), (8287, "Compat", &[32]),
+  (8304, "Super", &[48]), (8305, "Super", &[105]), (8308, "Super", &[52]),
+  (8309, "Super", &[53]), (8310, "Super", &[54]), (8311, "Super", &[55]),
+  (8312, "Super", &[56]), (8313, "Super", &[57]), (8314, "Super", &[43]),
+  (8315, "Super", &[8722]), (8316, "Super", &[61]), (8317, "Super", &[40]),
+  (8318, "Super", &[41]), (8319, "Super", &[110]), (8320, "Sub", &[48]),
+  (8321, "Sub", &[49]), (8322, "Sub", &[50]), (8323, "Sub", &[51]),
+  (8324, "Sub", &[52]), (8325, "Sub", &[53]), (8326, "Sub", &[54]),
+  (8327, "Sub", &[55]), (8328, "Sub", &[56]), (8329, "Sub", &[57]),
+  (8330, "Sub", &[43]), (8331, "Sub", &[8722]), (8332, "Sub", &[61]),
+  (8333, "Sub", &[40]), (8334, "Sub", &[41]), (8336, "Sub", &[97]),
+  (8337, "Sub", &[101]), (8338, "Sub", &[111]), (8339, "Sub", &[120]),
+  (8340, "Sub", &[601]), (8341, "Sub", &[104]), (8342, "Sub", &[107]),
+  (8343, "Sub", &[108]), (8344, "Sub", &[109]), (8345, "Sub", &[110]),
+  (8346, "Sub", &[112]), (8347, "Sub", &[115]), (8348, "Sub", &[116]),
+  (8360, "Compat", &[82, 115]), (8448, "Compat", &[97, 47, 99]),
+  (8449, "Compat", &[97, 47, 115]), (8450, "Font", &[67]),
+  (8451, "Compat", &[176, 67]), (8453, "Compat", &[99, 47, 111]),
+  (8454, "Compat", &[99, 47, 117]), (8455, "Compat", &[400]),
+  (8457, "Compat", &[176, 70]), (8458, "Font", &[103]), (8459, "Font", &[72]),
+  (8460, "Font", &[72]), (8461, "Font", &[72]), (8462, "Font", &[104]),
+  (8463, "Font", &[295]), (8464, "Font", &[73]), (8465, "Font", &[73]),
+  (8466, "Font", &[76]), (8467, "Font", &[108]), (8469, "Font", &[78]),
+  (8470, "Compat", &[78, 111]), (8473, "Font", &[80]), (8474, "Font", &[81]),
+  (8475, "Font", &[82]), (8476, "Font", &[82]), (8477, "Font", &[82]),
+  (8480, "Super", &[83, 77]), (8481, "Compat", &[84, 69, 76]),
+  (8482, "Super", &[84, 77]), (8484, "Font", &[90]),
+  (8486, "Canonical", &[937]), (8488, "Font", &[90]),
+  (8490, "Canonical", &[75]), (8491, "Canonical", &[197]),
+  (8492, "Font", &[66]), (8493, "Font", &[67]), (8495, "Font", &[101]),
+  (8496, "Font", &[69]), (8497, "Font", &[70]), (8499, "Font", &[77]),
+  (8500, "Font", &[111]), (8501, "Compat", &[1488]), (8502, "Compat", &[1489]),
+  (8503, "Compat", &[1490]), (8504, "Compat", &[1491]), (8505, "Font", &[105]),
+  (8507, "Compat", &[70, 65, 88]), (8508, "Font", &[960]),
+  (8509, "Font", &[947]), (8510, "Font", &[915]), (8511, "Font", &[928]),
+  (8512, "Font", &[8721]), (8517, "Font", &[68]), (8518, "Font", &[100]),
+  (8519, "Font", &[101]), (8520, "Font", &[105]), (8521, "Font", &[106]),
+  (8528, "Fraction", &[49, 8260, 55]), (8529, "Fraction", &[49, 8260, 57]),
+  (8530, "Fraction", &[49, 8260, 49, 48]), (8531, "Fraction", &[49, 8260, 51]),
+  (8532, "Fraction", &[50, 8260, 51]), (8533, "Fraction", &[49, 8260, 53]),
+  (8534, "Fraction", &[50, 8260, 53]), (8535, "Fraction", &[51, 8260, 53]),
+  (8536, "Fraction", &[52, 8260, 53]), (8537, "Fraction", &[49, 8260, 54]),
+  (8538, "Fraction", &[53, 8260, 54]), (8539, "Fraction", &[49, 8260, 56]),
+  (8540, "Fraction", &[51, 8260, 56]), (8541, "Fraction", &[53, 8260, 56]),
+  (8542, "Fraction", &[55, 8260, 56]), (8543, "Fraction", &[49, 8260]),
+  (8544, "Compat", &[73]), (8545, "Compat", &[73, 73]),
+  (8546, "Compat", &[73, 73, 73]), (8547, "Compat", &[73, 86]),
+  (8548, "Compat", &[86]), (8549, "Compat", &[86, 73]),
+  (8550, "Compat", &[86, 73, 73]), (8551, "Compat", &[86, 73, 73, 73]),
+  (8552, "Compat", &[73, 88]), (8553, "Compat", &[88]),
+  (8554, "Compat", &[88, 73]), (8555, "Compat", &[88, 73, 73]),
+  (8556, "Compat", &[76]), (8557, "Compat", &[67]), (8558, "Compat", &[68]),
+  (8559, "Compat", &[77]), (8560, "Compat", &[105]),
+  (8561, "Compat", &[105, 105]), (8562, "Compat", &[105, 105, 105]),
+  (8563, "Compat", &[105, 118]), (8564, "Compat", &[118]),
+  (8565, "Compat", &[118, 105]), (8566, "Compat", &[118, 105, 105]),
+  (8567, "Compat", &[118, 105, 105, 105]), (8568, "Compat", &[105, 120]),
+  (8569, "Compat", &[120]), (8570, "Compat", &[120, 105]),
+  (8571, "Compat", &[120, 105, 105]), (8572, "Compat", &[108]),
+  (8573, "Compat", &[99]), (8574, "Compat", &[100]), (8575, "Compat", &[109]),
+  (8585, "Fraction", &[48, 8260, 51]), (8602, "Canonical", &[8592, 824]),
+  (8603, "Canonical", &[8594, 824]), (8622, "Canonical", &[8596, 824]),
+  (8653, "Canonical", &[8656, 824]), (8654, "Canonical", &[8660, 824]),
+  (8655, "Canonical", &[8658, 824]), (8708, "Canonical", &[8707, 824]),
+  (8713, "Canonical", &[8712, 824]), (8716, "Canonical", &[8715, 824]),
+  (8740, "Canonical", &[8739, 824]), (8742, "Canonical", &[8741, 824]),
+  (8748, "Compat", &[8747, 8747]), (8749, "Compat", &[8747, 8747, 8747]),
+  (8751, "Compat", &[8750, 8750]), (8752, "Compat", &[8750, 8750, 8750]),
+  (8769, "Canonical", &[8764, 824]), (8772, "Canonical", &[8771, 824]),
+  (8775, "Canonical", &[8773, 824]), (8777, "Canonical", &[8776, 824]),
+  (8800, "Canonical", &[61, 824]), (8802, "Canonical", &[8801, 824]),
+  (8813, "Canonical", &[8781, 824]), (8814, "Canonical", &[60, 824]),
+  (8815, "Canonical", &[62, 824]), (8816, "Canonical", &[8804, 824]),
+  (8817, "Canonical", &[8805, 824]), (8820, "Canonical", &[8818, 824]),
+  (8821, "Canonical", &[8819, 824]), (8824, "Canonical", &[8822, 824]),
+  (8825, "Canonical", &[8823, 824]), (8832, "Canonical", &[8826, 824]),
+  (8833, "Canonical", &[8827, 824]), (8836, "Canonical", &[8834, 824]),
+  (8837, "Canonical", &[8835, 824]), (8840, "Canonical", &[8838, 824]),
+  (8841, "Canonical", &[8839, 824]), (8876, "Canonical", &[8866, 824]),
+  (8877, "Canonical", &[8872, 824]), (8878, "Canonical", &[8873, 824]),
+  (8879, "Canonical", &[8875, 824]), (8928, "Canonical", &[8828, 824]),
+  (8929, "Canonical", &[8829, 824]), (8930, "Canonical", &[8849, 824]),
+  (8931, "Canonical", &[8850, 824]), (8938, "Canonical", &[8882, 824]),
+  (8939, "Canonical", &[8883, 824]), (8940, "Canonical", &[8884, 824]),
+  (8941, "Canonical", &[8885, 824]), (9001, "Canonical", &[12296]),
+  (9002, "Canonical", &[12297]), (9312, "Circle", &[49]),
+  (9313, "Circle", &[50]), (9314, "Circle", &[51]), (9315, "Circle", &[52]),
+  (9316, "Circle", &[53]), (9317, "Circle", &[54]), (9318, "Circle", &[55]),
+  (9319, "Circle", &[56]), (9320, "Circle", &[57]),
+  (9321, "Circle", &[49, 48]), (9322, "Circle", &[49, 49]),
+  (9323, "Circle", &[49, 50]), (9324, "Circle", &[49, 51]),
+  (9325, "Circle", &[49, 52]), (9326, "Circle", &[49, 53]),
+  (9327, "Circle", &[49, 54]), (9328, "Circle", &[49, 55]),
+  (9329, "Circle", &[49, 56]), (9330, "Circle", &[49, 57]),
+  (9331, "Circle", &[50, 48]), (9332, "Compat", &[40, 49, 41]),
+  (9333, "Compat", &[40, 50, 41]), (9334, "Compat", &[40, 51, 41]),
+  (9335, "Compat", &[40, 52, 41]), (9336, "Compat", &[40, 53, 41]),
+  (9337, "Compat", &[40, 54, 41]), (9338, "Compat", &[40, 55, 41]),
+  (9339, "Compat", &[40, 56, 41]), (9340, "Compat", &[40, 57, 41]),
+  (9341, "Compat", &[40, 49, 48, 41]), (9342, "Compat", &[40, 49, 49, 41]),
+  (9343, "Compat", &[40, 49, 50, 41]), (9344, "Compat", &[40, 49, 51, 41]),
+  (9345, "Compat", &[40, 49, 52, 41]), (9346, "Compat", &[40, 49, 53, 41]),
+  (9347, "Compat", &[40, 49, 54, 41]), (9348, "Compat", &[40, 49, 55, 41]),
+  (9349, "Compat", &[40, 49, 56, 41]), (9350, "Compat", &[40, 49, 57, 41]),
+  (9351, "Compat", &[40, 50, 48, 41]), (9352, "Compat", &[49, 46]),
+  (9353, "Compat", &[50, 46]), (9354, "Compat", &[51, 46]),
+  (9355, "Compat", &[52, 46]), (9356, "Compat", &[53, 46]),
+  (9357, "Compat", &[54, 46]), (9358, "Compat", &[55, 46]),
+  (9359, "Compat", &[56, 46]), (9360, "Compat", &[57, 46]),
+  (9361, "Compat", &[49, 48, 46]), (9362, "Compat", &[49, 49, 46]),
+  (9363, "Compat", &[49, 50, 46]), (9364, "Compat", &[49, 51, 46]),
+  (9365, "Compat", &[49, 52, 46]), (9366, "Compat", &[49, 53, 46]),
+  (9367, "Compat", &[49, 54, 46]), (9368, "Compat", &[49, 55, 46]),
+  (9369, "Compat", &[49, 56, 46]), (9370, "Compat", &[49, 57, 46]),
+  (9371, "Compat", &[50, 48, 46]), (9372, "Compat", &[40, 97, 41]),
+  (9373, "Compat", &[40, 98, 41]), (9374, "Compat", &[40, 99, 41]),
+  (9375, "Compat", &[40, 100, 41]), (9376, "Compat", &[40, 101, 41]),
+  (9377, "Compat", &[40, 102, 41]), (9378, "Compat", &[40, 103, 41]),
+  (9379, "Compat", &[40, 104, 41]), (9380, "Compat", &[40, 105, 41]),
+  (9381, "Compat", &[40, 106, 41]), (9382, "Compat", &[40, 107, 41]),
+  (9383, "Compat", &[40, 108, 41]), (9384, "Compat", &[40, 109, 41]),
+  (9385, "Compat", &[40, 110, 41]), (9386, "Compat", &[40, 111, 41]),
+  (9387, "Compat", &[40, 112, 41]), (9388, "Compat", &[40, 113, 41]),
+  (9389, "Compat", &[40, 114, 41]), (9390, "Compat", &[40, 115, 41]),
+  (9391, "Compat", &[40, 116, 41]), (9392, "Compat", &[40, 117, 41]),
+  (9393, "Compat", &[40, 118, 41]), (9394, "Compat", &[40, 119, 41]),
+  (9395, "Compat", &[40, 120, 41]), (9396, "Compat", &[40, 121, 41]),
+  (9397, "Compat", &[40, 122, 41]), (9398, "Circle", &[65]),
+  (9399, "Circle", &[66]), (9400, "Circle", &[67]), (9401, "Circle", &[68]),
+  (9402, "Circle", &[69]), (9403, "Circle", &[70]), (9404, "Circle", &[71]),
+  (9405, "Circle", &[72]), (9406, "Circle", &[73]), (9407, "Circle", &[74]),
+  (9408, "Circle", &[75]), (9409, "Circle", &[76]), (9410, "Circle", &[77]),
+  (9411, "Circle", &[78]), (9412, "Circle", &[79]), (9413, "Circle", &[80]),
+  (9414, "Circle", &[81]), (9415, "Circle", &[82]), (9416, "Circle", &[83]),
+  (9417, "Circle", &[84]), (9418, "Circle", &[85]), (9419, "Circle", &[86]),
+  (9420, "Circle", &[87]), (9421, "Circle", &[88]), (9422, "Circle", &[89]),
+  (9423, "Circle", &[90]), (9424, "Circle", &[97]), (9425, "Circle", &[98]),
+  (9426, "Circle", &[99]), (9427, "Circle", &[100]), (9428, "Circle", &[101]),
+  (9429, "Circle", &[102]), (9430, "Circle", &[103]), (9431, "Circle", &[104]),
+  (9432, "Circle", &[105]), (9433, "Circle", &[106]), (9434, "Circle", &[107]),
+  (9435, "Circle", &[108]), (9436, "Circle", &[109]), (9437, "Circle", &[110]),
+  (9438, "Circle", &[111]), (9439, "Circle", &[112]), (9440, "Circle", &[113]),
+  (9441, "Circle", &[114]), (9442, "Circle", &[115]), (9443, "Circle", &[116]),
+  (9444, "Circle", &[117]), (9445, "Circle", &[118]), (9446, "Circle", &[119]),
+  (9447, "Circle", &[120]), (9448, "Circle", &[121]), (9449, "Circle", &[122]),
+  (9450, "Circle", &[48]), (10764, "Compat", &[8747, 8747, 8747, 8747]),
+  (10868, "Compat", &[58, 58, 61]), (10869, "Compat", &[61, 61]),
+  (10870, "Compat", &[61, 61, 61]), (10972, "Canonical", &[10973, 824]),
+  (11388, "Sub", &[106]), (11389, "Super", &[86]), (11631, "Super", &[11617]),
+  (11935, "Compat", &[27597]), (12019, "Compat", &[40863]),
+  (12032, "Compat", &[19968]), (12033, "Compat", &[20008]),
+  (12034, "Compat", &[20022]), (12035, "Compat", &[20031]),
+  (12036, "Compat", &[20057]), (12037, "Compat", &[20101]),
+  (12038, "Compat", &[20108]), (12039, "Compat", &[20128]),
+  (12040, "Compat", &[20154]), (12041, "Compat", &[20799]),
+  (12042, "Compat", &[20837]), (12043, "Compat", &[20843]),
+  (12044, "Compat", &[20866]), (12045, "Compat", &[20886]),
+  (12046, "Compat", &[20907]), (12047, "Compat", &[20960]),
+  (12048, "Compat", &[20981]), (12049, "Compat", &[20992]),
+  (12050, "Compat", &[21147]), (12051, "Compat", &[21241]),
+  (12052, "Compat", &[21269]), (12053, "Compat", &[21274]),
+  (12054, "Compat", &[21304]), (12055, "Compat", &[21313]),
+  (12056, "Compat", &[21340]), (12057, "Compat", &[21353]),
+  (12058, "Compat", &[21378]), (12059, "Compat", &[21430]),
+  (12060, "Compat", &[21448]), (12061, "Compat", &[21475]),
+  (12062, "Compat", &[22231]), (12063, "Compat", &[22303]),
+  (12064, "Compat", &[22763]), (12065, "Compat", &[22786]),
+  (12066, "Compat", &[22794]), (12067, "Compat", &[22805]),
+  (12068, "Compat", &[22823]), (12069, "Compat", &[22899]),
+  (12070, "Compat", &[23376]), (12071, "Compat", &[23424]),
+  (12072, "Compat", &[23544]), (12073, "Compat", &[23567]),
+  (12074, "Compat", &[23586]), (12075, "Compat", &[23608]),
+  (12076, "Compat", &[23662]), (12077, "Compat", &[23665]),
+  (12078, "Compat", &[24027]), (12079, "Compat", &[24037]),
+  (12080, "Compat", &[24049]), (12081, "Compat", &[24062]),
+  (12082, "Compat", &[24178]), (12083, "Compat", &[24186]),
+  (12084, "Compat", &[24191]), (12085, "Compat", &[24308]),
+  (12086, "Compat", &[24318]), (12087, "Compat", &[24331]),
+  (12088, "Compat", &[24339]), (12089, "Compat", &[24400]),
+  (12090, "Compat", &[24417]), (12091, "Compat", &[24435]),
+  (12092, "Compat", &[24515]), (12093, "Compat", &[25096]),
+  (12094, "Compat", &[25142]), (12095, "Compat", &[25163]),
+  (12096, "Compat", &[25903]), (12097, "Compat", &[25908]),
+  (12098, "Compat", &[25991]), (12099, "Compat", &[26007]),
+  (12100, "Compat", &[26020]), (12101, "Compat", &[26041]),
+  (12102, "Compat", &[26080]), (12103, "Compat", &[26085]),
+  (12104, "Compat", &[26352]), (12105, "Compat", &[26376]),
+  (12106, "Compat", &[26408]), (12107, "Compat", &[27424]),
+  (12108, "Compat", &[27490]), (12109, "Compat", &[27513]),
+  (12110, "Compat", &[27571]), (12111, "Compat", &[27595]),
+  (12112, "Compat", &[27604]), (12113, "Compat", &[27611]),
+  (12114, "Compat", &[27663]), (12115, "Compat", &[27668]),
+  (12116, "Compat", &[27700]), (12117, "Compat", &[28779]),
+  (12118, "Compat", &[29226]), (12119, "Compat", &[29238]),
+  (12120, "Compat", &[29243]), (12121, "Compat", &[29247]),
+  (12122, "Compat", &[29255]), (12123, "Compat", &[29273]),
+  (12124, "Compat", &[29275]), (12125, "Compat", &[29356]),
+  (12126, "Compat", &[29572]), (12127, "Compat", &[29577]),
+  (12128, "Compat", &[29916]), (12129, "Compat", &[29926]),
+  (12130, "Compat", &[29976]), (12131, "Compat", &[29983]),
+  (12132, "Compat", &[29992]), (12133, "Compat", &[30000]),
+  (12134, "Compat", &[30091]), (12135, "Compat", &[30098]),
+  (12136, "Compat", &[30326]), (12137, "Compat", &[30333]),
+  (12138, "Compat", &[30382]), (12139, "Compat", &[30399]),
+  (12140, "Compat", &[30446]), (12141, "Compat", &[30683]),
+  (12142, "Compat", &[30690]), (12143, "Compat", &[30707]),
+  (12144, "Compat", &[31034]), (12145, "Compat", &[31160]),
+  (12146, "Compat", &[31166]), (12147, "Compat", &[31348]),
+  (12148, "Compat", &[31435]), (12149, "Compat", &[31481]),
+  (12150, "Compat", &[31859]), (12151, "Compat", &[31992]),
+  (12152, "Compat", &[32566]), (12153, "Compat", &[32593]),
+  (12154, "Compat", &[32650]), (12155, "Compat", &[32701]),
+  (12156, "Compat", &[32769]), (12157, "Compat", &[32780]),
+  (12158, "Compat", &[32786]), (12159, "Compat", &[32819]),
+  (12160, "Compat", &[32895]), (12161, "Compat", &[32905]),
+  (12162, "Compat", &[33251]), (12163, "Compat", &[33258]),
+  (12164, "Compat", &[33267]), (12165, "Compat", &[33276]),
+  (12166, "Compat", &[33292]), (12167, "Compat", &[33307]),
+  (12168, "Compat", &[33311]), (12169, "Compat", &[33390]),
+  (12170, "Compat", &[33394]), (12171, "Compat", &[33400]),
+  (12172, "Compat", &[34381]), (12173, "Compat", &[34411]),
+  (12174, "Compat", &[34880]), (12175, "Compat", &[34892]),
+  (12176, "Compat", &[34915]), (12177, "Compat", &[35198]),
+  (12178, "Compat", &[35211]), (12179, "Compat", &[35282]),
+  (12180, "Compat", &[35328]), (12181, "Compat", &[35895]),
+  (12182, "Compat", &[35910]), (12183, "Compat", &[35925]),
+  (12184, "Compat", &[35960]), (12185, "Compat", &[35997]),
+  (12186, "Compat", &[36196]), (12187, "Compat", &[36208]),
+  (12188, "Compat", &[36275]), (12189, "Compat", &[36523]),
+  (12190, "Compat", &[36554]), (12191, "Compat", &[36763]),
+  (12192, "Compat", &[36784]), (12193, "Compat", &[36789]),
+  (12194, "Compat", &[37009]), (12195, "Compat", &[37193]),
+  (12196, "Compat", &[37318]), (12197, "Compat", &[37324]),
+  (12198, "Compat", &[37329]), (12199, "Compat", &[38263]),
+  (12200, "Compat", &[38272]), (12201, "Compat", &[38428]),
+  (12202, "Compat", &[38582]), (12203, "Compat", &[38585]),
+  (12204, "Compat", &[38632]), (12205, "Compat", &[38737]),
+  (12206, "Compat", &[38750]), (12207, "Compat", &[38754]),
+  (12208, "Compat", &[38761]), (12209, "Compat", &[38859]),
+  (12210, "Compat", &[38893]), (12211, "Compat", &[38899]),
+  (12212, "Compat", &[38913]), (12213, "Compat", &[39080]),
+  (12214, "Compat", &[39131]), (12215, "Compat", &[39135]),
+  (12216, "Compat", &[39318]), (12217, "Compat", &[39321]),
+  (12218, "Compat", &[39340]), (12219, "Compat", &[39592]),
+  (12220, "Compat", &[39640]), (12221, "Compat", &[39647]),
+  (12222, "Compat", &[39717]), (12223, "Compat", &[39727]),
+  (12224, "Compat", &[39730]), (12225, "Compat", &[39740]),
+  (12226, "Compat", &[39770]), (12227, "Compat", &[40165]),
+  (12228, "Compat", &[40565]), (12229, "Compat", &[40575]),
+  (12230, "Compat", &[40613]), (12231, "Compat", &[40635]),
+  (12232, "Compat", &[40643]), (12233, "Compat", &[40653]),
+  (12234, "Compat", &[40657]), (12235, "Compat", &[40697]),
+  (12236, "Compat", &[40701]), (12237, "Compat", &[40718]),
+  (12238, "Compat", &[40723]), (12239, "Compat", &[40736]),
+  (12240, "Compat", &[40763]), (12241, "Compat", &[40778]),
+  (12242, "Compat", &[40786]), (12243, "Compat", &[40845]),
+  (12244, "Compat", &[40860]), (12245, "Compat", &[40864]),
+  (12288, "Wide", &[32]), (12342, "Compat", &[12306]),
+  (12344, "Compat", &[21313]), (12345, "Compat", &[21316]),
+  (12346, "Compat", &[21317]), (12364, "Canonical", &[12363, 12441]),
+  (12366, "Canonical", &[12365, 12441]), (12368, "Canonical", &[12367, 12441]),
+  (12370, "Canonical", &[12369, 12441]), (12372, "Canonical", &[12371, 12441]),
+  (12374, "Canonical", &[12373, 12441]), (12376, "Canonical", &[12375, 12441]),
+  (12378, "Canonical", &[12377, 12441]), (12380, "Canonical", &[12379, 12441]),
+  (12382, "Canonical", &[12381, 12441]), (12384, "Canonical", &[12383, 12441]),
+  (12386, "Canonical", &[12385, 12441]), (12389, "Canonical", &[12388, 12441]),
+  (12391, "Canonical", &[12390, 12441]), (12393, "Canonical", &[12392, 12441]),
+  (12400, "Canonical", &[12399, 12441]), (12401, "Canonical", &[12399, 12442]),
+  (12403, "Canonical", &[12402, 12441]), (12404, "Canonical", &[12402, 12442]),
+  (12406, "Canonical", &[12405, 12441]), (12407, "Canonical", &[12405, 12442]),
+  (12409, "Canonical", &[12408, 12441]), (12410, "Canonical", &[12408, 12442]),
+  (12412, "Canonical", &[12411, 12441]), (12413, "Canonical", &[12411, 12442]),
+  (12436, "Canonical", &[12358, 12441]), (12443, "Compat", &[32, 12441]),
+  (12444, "Compat", &[32, 12442]), (12446, "Canonical", &[12445, 12441]),
+  (12447, "Vertical", &[12424, 12426]), (12460, "Canonical", &[12459, 12441]),
+  (12462, "Canonical", &[12461, 12441]), (12464, "Canonical", &[12463, 12441]),
+  (12466, "Canonical", &[12465, 12441]), (12468, "Canonical", &[12467, 12441]),
+  (12470, "Canonical", &[12469, 12441]), (12472, "Canonical", &[12471, 12441]),
+  (12474, "Canonical", &[12473, 12441]), (12476, "Canonical", &[12475, 12441]),
+  (12478, "Canonical", &[12477, 12441]), (12480, "Canonical", &[12479, 12441]),
+  (12482, "Canonical", &[12481, 12441]), (12485, "Canonical", &[12484, 12441]),
+  (12487, "Canonical", &[12486, 12441]), (12489, "Canonical", &[12488, 12441]),
+  (12496, "Canonical", &[12495, 12441]), (12497, "Canonical", &[12495, 12442]),
+  (12499, "Canonical", &[12498, 12441]), (12500, "Canonical", &[12498, 12442]),
+  (12502, "Canonical", &[12501, 12441]), (12503, "Canonical", &[12501, 12442]),
+  (12505, "Canonical", &[12504, 12441]), (12506, "Canonical", &[12504, 12442]),
+  (12508, "Canonical", &[12507, 12441]), (12509, "Canonical", &[12507, 12442]),
+  (12532, "Canonical", &[12454, 12441]), (12535, "Canonical", &[12527, 12441]),
+  (12536, "Canonical", &[12528, 12441]), (12537, "Canonical", &[12529, 12441]),
+  (12538, "Canonical", &[12530, 12441]), (12542, "Canonical", &[12541, 12441]),
+  (12543, "Vertical", &[12467, 12488]), (12593, "Compat", &[4352]),
+  (12594, "Compat", &[4353]), (12595, "Compat", &[4522]),
+  (12596, "Compat", &[4354]), (12597, "Compat", &[4524]),
+  (12598, "Compat", &[4525]), (12599, "Compat", &[4355]),
+  (12600, "Compat", &[4356]), (12601, "Compat", &[4357]),
+  (12602, "Compat", &[4528]), (12603, "Compat", &[4529]),
+  (12604, "Compat", &[4530]), (12605, "Compat", &[4531]),
+  (12606, "Compat", &[4532]), (12607, "Compat", &[4533]),
+  (12608, "Compat", &[4378]), (12609, "Compat", &[4358]),
+  (12610, "Compat", &[4359]), (12611, "Compat", &[4360]),
+  (12612, "Compat", &[4385]), (12613, "Compat", &[4361]),
+  (12614, "Compat", &[4362]), (12615, "Compat", &[4363]),
+  (12616, "Compat", &[4364]), (12617, "Compat", &[4365]),
+  (12618, "Compat", &[4366]), (12619, "Compat", &[4367]),
+  (12620, "Compat", &[4368]), (12621, "Compat", &[4369]),
+  (12622, "Compat", &[4370]), (12623, "Compat", &[4449]),
+  (12624, "Compat", &[4450]), (12625, "Compat", &[4451]),
+  (12626, "Compat", &[4452]), (12627, "Compat", &[4453]),
+  (12628, "Compat", &[4454]), (12629, "Compat", &[4455]),
+  (12630, "Compat", &[4456]), (12631, "Compat", &[4457]),
+  (12632, "Compat", &[4458]), (12633, "Compat", &[4459]),
+  (12634, "Compat", &[4460]), (12635, "Compat", &[4461]),
+  (12636, "Compat", &[4462]), (12637, "Compat", &[4463]),
+  (12638, "Compat", &[4464]), (12639, "Compat", &[4465]),
+  (12640, "Compat", &[4466]), (12641, "Compat", &[4467]),
+  (12642, "Compat", &[4468]), (12643, "Compat", &[4469]),
+  (12644, "Compat", &[4448]), (12645, "Compat", &[4372]),
+  (12646, "Compat", &[4373]), (12647, "Compat", &[4551]),
+  (12648, "Compat", &[4552]), (12649, "Compat", &[4556]),
+  (12650, "Compat", &[4558]), (12651, "Compat", &[4563]),
+  (12652, "Compat", &[4567]), (12653, "Compat", &[4569]),
+  (12654, "Compat", &[4380]), (12655, "Compat", &[4573]),
+  (12656, "Compat", &[4575]), (12657, "Compat", &[4381]),
+  (12658, "Compat", &[4382]), (12659, "Compat", &[4384]),
+  (12660, "Compat", &[4386]), (12661, "Compat", &[4387]),
+  (12662, "Compat", &[4391]), (12663, "Compat", &[4393]),
+  (12664, "Compat", &[4395]), (12665, "Compat", &[4396]),
+  (12666, "Compat", &[4397]), (12667, "Compat", &[4398]),
+  (12668, "Compat", &[4399]), (12669, "Compat", &[4402]),
+  (12670, "Compat", &[4406]), (12671, "Compat", &[4416]),
+  (12672, "Compat", &[4423]), (12673, "Compat", &[4428]),
+  (12674, "Compat", &[4593]), (12675, "Compat", &[4594]),
+  (12676, "Compat", &[4439]), (12677, "Compat", &[4440]),
+  (12678, "Compat", &[4441]), (12679, "Compat", &[4484]),
+  (12680, "Compat", &[4485]), (12681, "Compat", &[4488]),
+  (12682, "Compat", &[4497]), (12683, "Compat", &[4498]),
+  (12684, "Compat", &[4500]), (12685, "Compat", &[4510]),
+  (12686, "Compat", &[4513]), (12690, "Super", &[19968]),
+  (12691, "Super", &[20108]), (12692, "Super", &[19977]),
+  (12693, "Super", &[22235]), (12694, "Super", &[19978]),
+  (12695, "Super", &[20013]), (12696, "Super", &[19979]),
+  (12697, "Super", &[30002]), (12698, "Super", &[20057]),
+  (12699, "Super", &[19993]), (12700, "Super", &[19969]),
+  (12701, "Super", &[22825]), (12702, "Super", &[22320]),
+  (12703, "Super", &[20154]), (12800, "Compat", &[40, 4352, 41]),
+  (12801, "Compat", &[40, 4354, 41]), (12802, "Compat", &[40, 4355, 41]),
+  (12803, "Compat", &[40, 4357, 41]), (12804, "Compat", &[40, 4358, 41]),
+  (12805, "Compat", &[40, 4359, 41]), (12806, "Compat", &[40, 4361, 41]),
+  (12807, "Compat", &[40, 4363, 41]), (12808, "Compat", &[40, 4364, 41]),
+  (12809, "Compat", &[40, 4366, 41]), (12810, "Compat", &[40, 4367, 41]),
+  (12811, "Compat", &[40, 4368, 41]), (12812, "Compat", &[40, 4369, 41]),
+  (12813, "Compat", &[40, 4370, 41]), (12814, "Compat", &[40, 4352, 4449, 41]),
+  (12815, "Compat", &[40, 4354, 4449, 41]),
+  (12816, "Compat", &[40, 4355, 4449, 41]),
+  (12817, "Compat", &[40, 4357, 4449, 41]),
+  (12818, "Compat", &[40, 4358, 4449, 41]),
+  (12819, "Compat", &[40, 4359, 4449, 41]),
+  (12820, "Compat", &[40, 4361, 4449, 41]),
+  (12821, "Compat", &[40, 4363, 4449, 41]),
+  (12822, "Compat", &[40, 4364, 4449, 41]),
+  (12823, "Compat", &[40, 4366, 4449, 41]),
+  (12824, "Compat", &[40, 4367, 4449, 41]),
+  (12825, "Compat", &[40, 4368, 4449, 41]),
+  (12826, "Compat", &[40, 4369, 4449, 41]),
+  (12827, "Compat", &[40, 4370, 4449, 41]),
+  (12828, "Compat", &[40, 4364, 4462, 41]),
+  (12829, "Compat", &[40, 4363, 4457, 4364, 4453, 4523, 41]),
+  (12830, "Compat", &[40, 4363, 4457, 4370, 4462, 41]),
+  (12832, "Compat", &[40, 19968, 41]), (12833, "Compat", &[40, 20108, 41]),
+  (12834, "Compat", &[40, 19977, 41]), (12835, "Compat", &[40, 22235, 41]),
+  (12836, "Compat", &[40, 20116, 41]), (12837, "Compat", &[40, 20845, 41]),
+  (12838, "Compat", &[40, 19971, 41]), (12839, "Compat", &[40, 20843, 41]),
+  (12840, "Compat", &[40, 20061, 41]), (12841, "Compat", &[40, 21313, 41]),
+  (12842, "Compat", &[40, 26376, 41]), (12843, "Compat", &[40, 28779, 41]),
+  (12844, "Compat", &[40, 27700, 41]), (12845, "Compat", &[40, 26408, 41]),
+  (12846, "Compat", &[40, 37329, 41]), (12847, "Compat", &[40, 22303, 41]),
+  (12848, "Compat", &[40, 26085, 41]), (12849, "Compat", &[40, 26666, 41]),
+  (12850, "Compat", &[40, 26377, 41]), (12851, "Compat", &[40, 31038, 41]),
+  (12852, "Compat", &[40, 21517, 41]), (12853, "Compat", &[40, 29305, 41]),
+  (12854, "Compat", &[40, 36001, 41]), (12855, "Compat", &[40, 31069, 41]),
+  (12856, "Compat", &[40, 21172, 41]), (12857, "Compat", &[40, 20195, 41]),
+  (12858, "Compat", &[40, 21628, 41]), (12859, "Compat", &[40, 23398, 41]),
+  (12860, "Compat", &[40, 30435, 41]), (12861, "Compat", &[40, 20225, 41]),
+  (12862, "Compat", &[40, 36039, 41]), (12863, "Compat", &[40, 21332, 41]),
+  (12864, "Compat", &[40, 31085, 41]), (12865, "Compat", &[40, 20241, 41]),
+  (12866, "Compat", &[40, 33258, 41]), (12867, "Compat", &[40, 33267, 41]),
+  (12868, "Circle", &[21839]), (12869, "Circle", &[24188]),
+  (12870, "Circle", &[25991]), (12871, "Circle", &[31631]),
+  (12880, "Square", &[80, 84, 69]), (12881, "Circle", &[50, 49]),
+  (12882, "Circle", &[50, 50]), (12883, "Circle", &[50, 51]),
+  (12884, "Circle", &[50, 52]), (12885, "Circle", &[50, 53]),
+  (12886, "Circle", &[50, 54]), (12887, "Circle", &[50, 55]),
+  (12888, "Circle", &[50, 56]), (12889, "Circle", &[50, 57]),
+  (12890, "Circle", &[51, 48]), (12891, "Circle", &[51, 49]),
+  (12892, "Circle", &[51, 50]), (12893, "Circle", &[51, 51]),
+  (12894, "Circle", &[51, 52]), (12895, "Circle", &[51, 53]),
+  (12896, "Circle", &[4352]), (12897, "Circle", &[4354]),
+  (12898, "Circle", &[4355]), (12899, "Circle", &[4357]),
+  (12900, "Circle", &[4358]), (12901, "Circle", &[4359]),
+  (12902, "Circle", &[4361]), (12903, "Circle", &[4363]),
+  (12904, "Circle", &[4364]), (12905, "Circle", &[4366]),
+  (12906, "Circle", &[4367]), (12907, "Circle", &[4368]),
+  (12908, "Circle", &[4369]), (12909, "Circle", &[4370]),
+  (12910, "Circle", &[4352, 4449]), (12911, "Circle", &[4354, 4449]),
+  (12912, "Circle", &[4355, 4449]), (12913, "Circle", &[4357, 4449]),
+  (12914, "Circle", &[4358, 4449]), (12915, "Circle", &[4359, 4449]),
+  (12916, "Circle", &[4361, 4449]), (12917, "Circle", &[4363, 4449]),
+  (12918, "Circle", &[4364, 4449]), (12919, "Circle", &[4366, 4449]),
+  (12920, "Circle", &[4367, 4449]), (12921, "Circle", &[4368, 4449]),
+  (12922, "Circle", &[4369, 4449]), (12923, "Circle", &[4370, 4449]),
+  (12924, "Circle", &[4366, 4449, 4535, 4352, 4457]),
+  (12925, "Circle", &[4364, 4462, 4363, 4468]),
+  (12926, "Circle", &[4363, 4462]), (12928, "Circle", &[19968]),
+  (12929, "Circle", &[20108]), (12930, "Circle", &[19977]),
+  (12931, "Circle", &[22235]), (12932, "Circle", &[20116]),
+  (12933, "Circle", &[20845]), (12934, "Circle", &[19971]),
+  (12935, "Circle", &[20843]), (12936, "Circle", &[20061]),
+  (12937, "Circle", &[21313]), (12938, "Circle", &[26376]),
+  (12939, "Circle", &[28779]), (12940, "Circle", &[27700]),
+  (12941, "Circle", &[26408]), (12942, "Circle", &[37329]),
+  (12943, "Circle", &[22303]), (12944, "Circle", &[26085]),
+  (12945, "Circle", &[26666]), (12946, "Circle", &[26377]),
+  (12947, "Circle", &[31038]), (12948, "Circle", &[21517]),
+  (12949, "Circle", &[29305]), (12950, "Circle", &[36001]),
+  (12951, "Circle", &[31069]), (12952, "Circle", &[21172]),
+  (12953, "Circle", &[31192]), (12954, "Circle", &[30007]),
+  (12955, "Circle", &[22899]), (12956, "Circle", &[36969]),
+  (12957, "Circle", &[20778]), (12958, "Circle", &[21360]),
+  (12959, "Circle", &[27880]), (12960, "Circle", &[38917]),
+  (12961, "Circle", &[20241]), (12962, "Circle", &[20889]),
+  (12963, "Circle", &[27491]), (12964, "Circle", &[19978]),
+  (12965, "Circle", &[20013]), (12966, "Circle", &[19979]),
+  (12967, "Circle", &[24038]), (12968, "Circle", &[21491]),
+  (12969, "Circle", &[21307]), (12970, "Circle", &[23447]),
+  (12971, "Circle", &[23398]), (12972, "Circle", &[30435]),
+  (12973, "Circle", &[20225]), (12974, "Circle", &[36039]),
+  (12975, "Circle", &[21332]), (12976, "Circle", &[22812]),
+  (12977, "Circle", &[51, 54]), (12978, "Circle", &[51, 55]),
+  (12979, "Circle", &[51, 56]), (12980, "Circle", &[51, 57]),
+  (12981, "Circle", &[52, 48]), (12982, "Circle", &[52, 49]),
+  (12983, "Circle", &[52, 50]), (12984, "Circle", &[52, 51]),
+  (12985, "Circle", &[52, 52]), (12986, "Circle", &[52, 53]),
+  (12987, "Circle", &[52, 54]), (12988, "Circle", &[52, 55]),
+  (12989, "Circle", &[52, 56]), (12990, "Circle", &[52, 57]),
+  (12991, "Circle", &[53, 48]), (12992, "Compat", &[49, 26376]),
+  (12993, "Compat", &[50, 26376]), (12994, "Compat", &[51, 26376]),
+  (12995, "Compat", &[52, 26376]), (12996, "Compat", &[53, 26376]),
+  (12997, "Compat", &[54, 26376]), (12998, "Compat", &[55, 26376]),
+  (12999, "Compat", &[56, 26376]), (13000, "Compat", &[57, 26376]),
+  (13001, "Compat", &[49, 48, 26376]), (13002, "Compat", &[49, 49, 26376]),
+  (13003, "Compat", &[49, 50, 26376]), (13004, "Square", &[72, 103]),
+  (13005, "Square", &[101, 114, 103]), (13006, "Square", &[101, 86]),
+  (13007, "Square", &[76, 84, 68]), (13008, "Circle", &[12450]),
+  (13009, "Circle", &[12452]), (13010, "Circle", &[12454]),
+  (13011, "Circle", &[12456]), (13012, "Circle", &[12458]),
+  (13013, "Circle", &[12459]), (13014, "Circle", &[12461]),
+  (13015, "Circle", &[12463]), (13016, "Circle", &[12465]),
+  (13017, "Circle", &[12467]), (13018, "Circle", &[12469]),
+  (13019, "Circle", &[12471]), (13020, "Circle", &[12473]),
+  (13021, "Circle", &[12475]), (13022, "Circle", &[12477]),
+  (13023, "Circle", &[12479]), (13024, "Circle", &[12481]),
+  (13025, "Circle", &[12484]), (13026, "Circle", &[12486]),
+  (13027, "Circle", &[12488]), (13028, "Circle", &[12490]),
+  (13029, "Circle", &[12491]), (13030, "Circle", &[12492]),
+  (13031, "Circle", &[12493]), (13032, "Circle", &[12494]),
+  (13033, "Circle", &[12495]), (13034, "Circle", &[12498]),
+  (13035, "Circle", &[12501]), (13036, "Circle", &[12504]),
+  (13037, "Circle", &[12507]), (13038, "Circle", &[12510]),
+  (13039, "Circle", &[12511]), (13040, "Circle", &[12512]),
+  (13041, "Circle", &[12513]), (13042, "Circle", &[12514]),
+  (13043, "Circle", &[12516]), (13044, "Circle", &[12518]),
+  (13045, "Circle", &[12520]), (13046, "Circle", &[12521]),
+  (13047, "Circle", &[12522]), (13048, "Circle", &[12523]),
+  (13049, "Circle", &[12524]), (13050, "Circle", &[12525]),
+  (13051, "Circle", &[12527]), (13052, "Circle", &[12528]),
+  (13053, "Circle", &[12529]), (13054, "Circle", &[12530]),
+  (13056, "Square", &[12450, 12497, 12540, 12488]),
+  (13057, "Square", &[12450, 12523, 12501, 12449]),
+  (13058, "Square", &[12450, 12531, 12506, 12450]),
+  (13059, "Square", &[12450, 12540, 12523]),
+  (13060, "Square", &[12452, 12491, 12531, 12464]),
+  (13061, "Square", &[12452, 12531, 12481]),
+  (13062, "Square", &[12454, 12457, 12531]),
+  (13063, "Square", &[12456, 12473, 12463, 12540, 12489]),
+  (13064, "Square", &[12456, 12540, 12459, 12540]),
+  (13065, "Square", &[12458, 12531, 12473]),
+  (13066, "Square", &[12458, 12540, 12512]),
+  (13067, "Square", &[12459, 12452, 12522]),
+  (13068, "Square", &[12459, 12521, 12483, 12488]),
+  (13069, "Square", &[12459, 12525, 12522, 12540]),
+  (13070, "Square", &[12460, 12525, 12531]),
+  (13071, "Square", &[12460, 12531, 12510]),
+  (13072, "Square", &[12462, 12460]),
+  (13073, "Square", &[12462, 12491, 12540]),
+  (13074, "Square", &[12461, 12517, 12522, 12540]),
+  (13075, "Square", &[12462, 12523, 12480, 12540]),
+  (13076, "Square", &[12461, 12525]),
+  (13077, "Square", &[12461, 12525, 12464, 12521, 12512]),
+  (13078, "Square", &[12461, 12525, 12513, 12540, 12488, 12523]),
+  (13079, "Square", &[12461, 12525, 12527, 12483, 12488]),
+  (13080, "Square", &[12464, 12521, 12512]),
+  (13081, "Square", &[12464, 12521, 12512, 12488, 12531]),
+  (13082, "Square", &[12463, 12523, 12476, 12452, 12525]),
+  (13083, "Square", &[12463, 12525, 12540, 12493]),
+  (13084, "Square", &[12465, 12540, 12473]),
+  (13085, "Square", &[12467, 12523, 12490]),
+  (13086, "Square", &[12467, 12540, 12509]),
+  (13087, "Square", &[12469, 12452, 12463, 12523]),
+  (13088, "Square", &[12469, 12531, 12481, 12540, 12512]),
+  (13089, "Square", &[12471, 12522, 12531, 12464]),
+  (13090, "Square", &[12475, 12531, 12481]),
+  (13091, "Square", &[12475, 12531, 12488]),
+  (13092, "Square", &[12480, 12540, 12473]),
+  (13093, "Square", &[12487, 12471]), (13094, "Square", &[12489, 12523]),
+  (13095, "Square", &[12488, 12531]), (13096, "Square", &[12490, 12494]),
+  (13097, "Square", &[12494, 12483, 12488]),
+  (13098, "Square", &[12495, 12452, 12484]),
+  (13099, "Square", &[12497, 12540, 12475, 12531, 12488]),
+  (13100, "Square", &[12497, 12540, 12484]),
+  (13101, "Square", &[12496, 12540, 12524, 12523]),
+  (13102, "Square", &[12500, 12450, 12473, 12488, 12523]),
+  (13103, "Square", &[12500, 12463, 12523]),
+  (13104, "Square", &[12500, 12467]), (13105, "Square", &[12499, 12523]),
+  (13106, "Square", &[12501, 12449, 12521, 12483, 12489]),
+  (13107, "Square", &[12501, 12451, 12540, 12488]),
+  (13108, "Square", &[12502, 12483, 12471, 12455, 12523]),
+  (13109, "Square", &[12501, 12521, 12531]),
+  (13110, "Square", &[12504, 12463, 12479, 12540, 12523]),
+  (13111, "Square", &[12506, 12477]),
+  (13112, "Square", &[12506, 12491, 12498]),
+  (13113, "Square", &[12504, 12523, 12484]),
+  (13114, "Square", &[12506, 12531, 12473]),
+  (13115, "Square", &[12506, 12540, 12472]),
+  (13116, "Square", &[12505, 12540, 12479]),
+  (13117, "Square", &[12509, 12452, 12531, 12488]),
+  (13118, "Square", &[12508, 12523, 12488]),
+  (13119, "Square", &[12507, 12531]),
+  (13120, "Square", &[12509, 12531, 12489]),
+  (13121, "Square", &[12507, 12540, 12523]),
+  (13122, "Square", &[12507, 12540, 12531]),
+  (13123, "Square", &[12510, 12452, 12463, 12525]),
+  (13124, "Square", &[12510, 12452, 12523]),
+  (13125, "Square", &[12510, 12483, 12495]),
+  (13126, "Square", &[12510, 12523, 12463]),
+  (13127, "Square", &[12510, 12531, 12471, 12519, 12531]),
+  (13128, "Square", &[12511, 12463, 12525, 12531]),
+  (13129, "Square", &[12511, 12522]),
+  (13130, "Square", &[12511, 12522, 12496, 12540, 12523]),
+  (13131, "Square", &[12513, 12460]),
+  (13132, "Square", &[12513, 12460, 12488, 12531]),
+  (13133, "Square", &[12513, 12540, 12488, 12523]),
+  (13134, "Square", &[12516, 12540, 12489]),
+  (13135, "Square", &[12516, 12540, 12523]),
+  (13136, "Square", &[12518, 12450, 12531]),
+  (13137, "Square", &[12522, 12483, 12488, 12523]),
+  (13138, "Square", &[12522, 12521]),
+  (13139, "Square", &[12523, 12500, 12540]),
+  (13140, "Square", &[12523, 12540, 12502, 12523]),
+  (13141, "Square", &[12524, 12512]),
+  (13142, "Square", &[12524, 12531, 12488, 12466, 12531]),
+  (13143, "Square", &[12527, 12483, 12488]), (13144, "Compat", &[48, 28857]),
+  (13145, "Compat", &[49, 28857]), (13146, "Compat", &[50, 28857]),
+  (13147, "Compat", &[51, 28857]), (13148, "Compat", &[52, 28857]),
+  (13149, "Compat", &[53, 28857]), (13150, "Compat", &[54, 28857]),
+  (13151, "Compat", &[55, 28857]), (13152, "Compat", &[56, 28857]),
+  (13153, "Compat", &[57, 28857]), (13154, "Compat", &[49, 48, 28857]),
+  (13155, "Compat", &[49, 49, 28857]), (13156, "Compat", &[49, 50, 28857]),
+  (13157, "Compat", &[49, 51, 28857]), (13158, "Compat", &[49, 52, 28857]),
+  (13159, "Compat", &[49, 53, 28857]), (13160, "Compat", &[49, 54, 28857]),
+  (13161, "Compat", &[49, 55, 28857]), (13162, "Compat", &[49, 56, 28857]),
+  (13163, "Compat", &[49, 57, 28857]), (13164, "Compat", &[50, 48, 28857]),
+  (13165, "Compat", &[50, 49, 28857]), (13166, "Compat", &[50, 50, 28857]),
+  (13167, "Compat", &[50, 51, 28857]), (13168, "Compat", &[50, 52, 28857]),
+  (13169, "Square", &[104, 80, 97]), (13170, "Square", &[100, 97]),
+  (13171, "Square", &[65, 85]), (13172, "Square", &[98, 97, 114]),
+  (13173, "Square", &[111, 86]), (13174, "Square", &[112, 99]),
+  (13175, "Square", &[100, 109]), (13176, "Square", &[100, 109, 178]),
+  (13177, "Square", &[100, 109, 179]), (13178, "Square", &[73, 85]),
+  (13179, "Square", &[24179, 25104]), (13180, "Square", &[26157, 21644]),
+  (13181, "Square", &[22823, 27491]), (13182, "Square", &[26126, 27835]),
+  (13183, "Square", &[26666, 24335, 20250, 31038]),
+  (13184, "Square", &[112, 65]), (13185, "Square", &[110, 65]),
+  (13186, "Square", &[956, 65]), (13187, "Square", &[109, 65]),
+  (13188, "Square", &[107, 65]), (13189, "Square", &[75, 66]),
+  (13190, "Square", &[77, 66]), (13191, "Square", &[71, 66]),
+  (13192, "Square", &[99, 97, 108]), (13193, "Square", &[107, 99, 97, 108]),
+  (13194, "Square", &[112, 70]), (13195, "Square", &[110, 70]),
+  (13196, "Square", &[956, 70]), (13197, "Square", &[956, 103]),
+  (13198, "Square", &[109, 103]), (13199, "Square", &[107, 103]),
+  (13200, "Square", &[72, 122]), (13201, "Square", &[107, 72, 122]),
+  (13202, "Square", &[77, 72, 122]), (13203, "Square", &[71, 72, 122]),
+  (13204, "Square", &[84, 72, 122]), (13205, "Square", &[956, 8467]),
+  (13206, "Square", &[109, 8467]), (13207, "Square", &[100, 8467]),
+  (13208, "Square", &[107, 8467]), (13209, "Square", &[102, 109]),
+  (13210, "Square", &[110, 109]), (13211, "Square", &[956, 109]),
+  (13212, "Square", &[109, 109]), (13213, "Square", &[99, 109]),
+  (13214, "Square", &[107, 109]), (13215, "Square", &[109, 109, 178]),
+  (13216, "Square", &[99, 109, 178]), (13217, "Square", &[109, 178]),
+  (13218, "Square", &[107, 109, 178]), (13219, "Square", &[109, 109, 179]),
+  (13220, "Square", &[99, 109, 179]), (13221, "Square", &[109, 179]),
+  (13222, "Square", &[107, 109, 179]), (13223, "Square", &[109, 8725, 115]),
+  (13224, "Square", &[109, 8725, 115, 178]), (13225, "Square", &[80, 97]),
+  (13226, "Square", &[107, 80, 97]), (13227, "Square", &[77, 80, 97]),
+  (13228, "Square", &[71, 80, 97]), (13229, "Square", &[114, 97, 100]),
+  (13230, "Square", &[114, 97, 100, 8725, 115]),
+  (13231, "Square", &[114, 97, 100, 8725, 115, 178]),
+  (13232, "Square", &[112, 115]), (13233, "Square", &[110, 115]),
+  (13234, "Square", &[956, 115]), (13235, "Square", &[109, 115]),
+  (13236, "Square", &[112, 86]), (13237, "Square", &[110, 86]),
+  (13238, "Square", &[956, 86]), (13239, "Square", &[109, 86]),
+  (13240, "Square", &[107, 86]), (13241, "Square", &[77, 86]),
+  (13242, "Square", &[112, 87]), (13243, "Square", &[110, 87]),
+  (13244, "Square", &[956, 87]), (13245, "Square", &[109, 87]),
+  (13246, "Square", &[107, 87]), (13247, "Square", &[77, 87]),
+  (13248, "Square", &[107, 937]), (13249, "Square", &[77, 937]),
+  (13250, "Square", &[97, 46, 109, 46]), (13251, "Square", &[66, 113]),
+  (13252, "Square", &[99, 99]), (13253, "Square", &[99, 100]),
+  (13254, "Square", &[67, 8725, 107, 103]), (13255, "Square", &[67, 111, 46]),
+  (13256, "Square", &[100, 66]), (13257, "Square", &[71, 121]),
+  (13258, "Square", &[104, 97]), (13259, "Square", &[72, 80]),
+  (13260, "Square", &[105, 110]), (13261, "Square", &[75, 75]),
+  (13262, "Square", &[75, 77]), (13263, "Square", &[107, 116]),
+  (13264, "Square", &[108, 109]), (13265, "Square", &[108, 110]),
+  (13266, "Square", &[108, 111, 103]), (13267, "Square", &[108, 120]),
+  (13268, "Square", &[109, 98]), (13269, "Square", &[109, 105, 108]),
+  (13270, "Square", &[109, 111, 108]), (13271, "Square", &[80, 72]),
+  (13272, "Square", &[112, 46, 109, 46]), (13273, "Square", &[80, 80, 77]),
+  (13274, "Square", &[80, 82]), (13275, "Square", &[115, 114]),
+  (13276, "Square", &[83, 118]), (13277, "Square", &[87, 98]),
+  (13278, "Square", &[86, 8725, 109]), (13279, "Square", &[65, 8725, 109]),
+  (13280, "Compat", &[49, 26085]), (13281, "Compat", &[50, 26085]),
+  (13282, "Compat", &[51, 26085]), (13283, "Compat", &[52, 26085]),
+  (13284, "Compat", &[53, 26085]), (13285, "Compat", &[54, 26085]),
+  (13286, "Compat", &[55, 26085]), (13287, "Compat", &[56, 26085]),
+  (13288, "Compat", &[57, 26085]), (13289, "Compat", &[49, 48, 26085]),
+  (13290, "Compat", &[49, 49, 26085]), (13291, "Compat", &[49, 50, 26085]),
+  (13292, "Compat", &[49, 51, 26085]), (13293, "Compat", &[49, 52, 26085]),
+  (13294, "Compat", &[49, 53, 26085]), (13295, "Compat", &[49, 54, 26085]),
+  (13296, "Compat", &[49, 55, 26085]), (13297, "Compat", &[49, 56, 26085]),
+  (13298, "Compat", &[49, 57, 26085]), (13299, "Compat", &[50, 48, 26085]),
+  (13300, "Compat", &[50, 49, 26085]), (13301, "Compat", &[50, 50, 26085]),
+  (13302, "Compat", &[50, 51, 26085]), (13303, "Compat", &[50, 52, 26085]),
+  (13304, "Compat", &[50, 53, 26085]), (13305, "Compat", &[50, 54, 26085]),
+  (13306, "Compat", &[50, 55, 26085]), (13307, "Compat", &[50, 56, 26085]),
+  (13308, "Compat", &[50, 57, 26085]), (13309, "Compat", &[51, 48, 26085]),
+  (13310, "Compat", &[51, 49, 26085]), (13311, "Square", &[103, 97, 108]),
+  (42652, "Super", &[1098]), (42653, "Super", &[1100]),
+  (42864, "Super", &[42863]), (43000, "Super", &[294]),
+  (43001, "Super", &[339]), (43868, "Super", &[42791]),
+  (43869, "Super", &[43831]), (43870, "Super", &[619]),
+  (43871, "Super", &[43858]), (63744, "Canonical", &[35912]),
+  (63745, "Canonical", &[26356]), (63746, "Canonical", &[36554]),
+  (63747, "Canonical", &[36040]), (63748, "Canonical", &[28369]),
+  (63749, "Canonical", &[20018]), (63750, "Canonical", &[21477]),
+  (63751, "Canonical", &[40860]), (63752, "Canonical", &[40860]),
+  (63753, "Canonical", &[22865]), (63754, "Canonical", &[37329]),
+  (63755, "Canonical", &[21895]), (63756, "Canonical", &[22856]),
+  (63757, "Canonical", &[25078]), (63758, "Canonical", &[30313]),
+  (63759, "Canonical", &[32645]), (63760, "Canonical", &[34367]),
+  (63761, "Canonical", &[34746]), (63762, "Canonical", &[35064]),
+  (63763, "Canonical", &[37007]), (63764, "Canonical", &[27138]),
+  (63765, "Canonical", &[27931]), (63766, "Canonical", &[28889]),
+  (63767, "Canonical", &[29662]), (63768, "Canonical", &[33853]),
+  (63769, "Canonical", &[37226]), (63770, "Canonical", &[39409]),
+  (63771, "Canonical", &[20098]), (63772, "Canonical", &[21365]),
+  (63773, "Canonical", &[27396]), (63774, "Canonical", &[29211]),
+  (63775, "Canonical", &[34349]), (63776, "Canonical", &[40478]),
+  (63777, "Canonical", &[23888]), (63778, "Canonical", &[28651]),
+  (63779, "Canonical", &[34253]), (63780, "Canonical", &[35172]),
+  (63781, "Canonical", &[25289]), (63782, "Canonical", &[33240]),
+  (63783, "Canonical", &[34847]), (63784, "Canonical", &[24266]),
+  (63785, "Canonical", &[26391]), (63786, "Canonical", &[28010]),
+  (63787, "Canonical", &[29436]), (63788, "Canonical", &[37070]),
+  (63789, "Canonical", &[20358]), (63790, "Canonical", &[20919]),
+  (63791, "Canonical", &[21214]), (63792, "Canonical", &[25796]),
+  (63793, "Canonical", &[27347]), (63794, "Canonical", &[29200]),
+  (63795, "Canonical", &[30439]), (63796, "Canonical", &[32769]),
+  (63797, "Canonical", &[34310]), (63798, "Canonical", &[34396]),
+  (63799, "Canonical", &[36335]), (63800, "Canonical", &[38706]),
+  (63801, "Canonical", &[39791]), (63802, "Canonical", &[40442]),
+  (63803, "Canonical", &[30860]), (63804, "Canonical", &[31103]),
+  (63805, "Canonical", &[32160]), (63806, "Canonical", &[33737]),
+  (63807, "Canonical", &[37636]), (63808, "Canonical", &[40575]),
+  (63809, "Canonical", &[35542]), (63810, "Canonical", &[22751]),
+  (63811, "Canonical", &[24324]), (63812, "Canonical", &[31840]),
+  (63813, "Canonical", &[32894]), (63814, "Canonical", &[29282]),
+  (63815, "Canonical", &[30922]), (63816, "Canonical", &[36034]),
+  (63817, "Canonical", &[38647]), (63818, "Canonical", &[22744]),
+  (63819, "Canonical", &[23650]), (63820, "Canonical", &[27155]),
+  (63821, "Canonical", &[28122]), (63822, "Canonical", &[28431]),
+  (63823, "Canonical", &[32047]), (63824, "Canonical", &[32311]),
+  (63825, "Canonical", &[38475]), (63826, "Canonical", &[21202]),
+  (63827, "Canonical", &[32907]), (63828, "Canonical", &[20956]),
+  (63829, "Canonical", &[20940]), (63830, "Canonical", &[31260]),
+  (63831, "Canonical", &[32190]), (63832, "Canonical", &[33777]),
+  (63833, "Canonical", &[38517]), (63834, "Canonical", &[35712]),
+  (63835, "Canonical", &[25295]), (63836, "Canonical", &[27138]),
+  (63837, "Canonical", &[35582]), (63838, "Canonical", &[20025]),
+  (63839, "Canonical", &[23527]), (63840, "Canonical", &[24594]),
+  (63841, "Canonical", &[29575]), (63842, "Canonical", &[30064]),
+  (63843, "Canonical", &[21271]), (63844, "Canonical", &[30971]),
+  (63845, "Canonical", &[20415]), (63846, "Canonical", &[24489]),
+  (63847, "Canonical", &[19981]), (63848, "Canonical", &[27852]),
+  (63849, "Canonical", &[25976]), (63850, "Canonical", &[32034]),
+  (63851, "Canonical", &[21443]), (63852, "Canonical", &[22622]),
+  (63853, "Canonical", &[30465]), (63854, "Canonical", &[33865]),
+  (63855, "Canonical", &[35498]), (63856, "Canonical", &[27578]),
+  (63857, "Canonical", &[36784]), (63858, "Canonical", &[27784]),
+  (63859, "Canonical", &[25342]), (63860, "Canonical", &[33509]),
+  (63861, "Canonical", &[25504]), (63862, "Canonical", &[30053]),
+  (63863, "Canonical", &[20142]), (63864, "Canonical", &[20841]),
+  (63865, "Canonical", &[20937]), (63866, "Canonical", &[26753]),
+  (63867, "Canonical", &[31975]), (63868, "Canonical", &[33391]),
+  (63869, "Canonical", &[35538]), (63870, "Canonical", &[37327]),
+  (63871, "Canonical", &[21237]), (63872, "Canonical", &[21570]),
+  (63873, "Canonical", &[22899]), (63874, "Canonical", &[24300]),
+  (63875, "Canonical", &[26053]), (63876, "Canonical", &[28670]),
+  (63877, "Canonical", &[31018]), (63878, "Canonical", &[38317]),
+  (63879, "Canonical", &[39530]), (63880, "Canonical", &[40599]),
+  (63881, "Canonical", &[40654]), (63882, "Canonical", &[21147]),
+  (63883, "Canonical", &[26310]), (63884, "Canonical", &[27511]),
+  (63885, "Canonical", &[36706]), (63886, "Canonical", &[24180]),
+  (63887, "Canonical", &[24976]), (63888, "Canonical", &[25088]),
+  (63889, "Canonical", &[25754]), (63890, "Canonical", &[28451]),
+  (63891, "Canonical", &[29001]), (63892, "Canonical", &[29833]),
+  (63893, "Canonical", &[31178]), (63894, "Canonical", &[32244]),
+  (63895, "Canonical", &[32879]), (63896, "Canonical", &[36646]),
+  (63897, "Canonical", &[34030]), (63898, "Canonical", &[36899]),
+  (63899, "Canonical", &[37706]), (63900, "Canonical", &[21015]),
+  (63901, "Canonical", &[21155]), (63902, "Canonical", &[21693]),
+  (63903, "Canonical", &[28872]), (63904, "Canonical", &[35010]),
+  (63905, "Canonical", &[35498]), (63906, "Canonical", &[24265]),
+  (63907, "Canonical", &[24565]), (63908, "Canonical", &[25467]),
+  (63909, "Canonical", &[27566]), (63910, "Canonical", &[31806]),
+  (63911, "Canonical", &[29557]), (63912, "Canonical", &[20196]),
+  (63913, "Canonical", &[22265]), (63914, "Canonical", &[23527]),
+  (63915, "Canonical", &[23994]), (63916, "Canonical", &[24604]),
+  (63917, "Canonical", &[29618]), (63918, "Canonical", &[29801]),
+  (63919, "Canonical", &[32666]), (63920, "Canonical", &[32838]),
+  (63921, "Canonical", &[37428]), (63922, "Canonical", &[38646]),
+  (63923, "Canonical", &[38728]), (63924, "Canonical", &[38936]),
+  (63925, "Canonical", &[20363]), (63926, "Canonical", &[31150]),
+  (63927, "Canonical", &[37300]), (63928, "Canonical", &[38584]),
+  (63929, "Canonical", &[24801]), (63930, "Canonical", &[20102]),
+  (63931, "Canonical", &[20698]), (63932, "Canonical", &[23534]),
+  (63933, "Canonical", &[23615]), (63934, "Canonical", &[26009]),
+  (63935, "Canonical", &[27138]), (63936, "Canonical", &[29134]),
+  (63937, "Canonical", &[30274]), (63938, "Canonical", &[34044]),
+  (63939, "Canonical", &[36988]), (63940, "Canonical", &[40845]),
+  (63941, "Canonical", &[26248]), (63942, "Canonical", &[38446]),
+  (63943, "Canonical", &[21129]), (63944, "Canonical", &[26491]),
+  (63945, "Canonical", &[26611]), (63946, "Canonical", &[27969]),
+  (63947, "Canonical", &[28316]), (63948, "Canonical", &[29705]),
+  (63949, "Canonical", &[30041]), (63950, "Canonical", &[30827]),
+  (63951, "Canonical", &[32016]), (63952, "Canonical", &[39006]),
+  (63953, "Canonical", &[20845]), (63954, "Canonical", &[25134]),
+  (63955, "Canonical", &[38520]), (63956, "Canonical", &[20523]),
+  (63957, "Canonical", &[23833]), (63958, "Canonical", &[28138]),
+  (63959, "Canonical", &[36650]), (63960, "Canonical", &[24459]),
+  (63961, "Canonical", &[24900]), (63962, "Canonical", &[26647]),
+  (63963, "Canonical", &[29575]), (63964, "Canonical", &[38534]),
+  (63965, "Canonical", &[21033]), (63966, "Canonical", &[21519]),
+  (63967, "Canonical", &[23653]), (63968, "Canonical", &[26131]),
+  (63969, "Canonical", &[26446]), (63970, "Canonical", &[26792]),
+  (63971, "Canonical", &[27877]), (63972, "Canonical", &[29702]),
+  (63973, "Canonical", &[30178]), (63974, "Canonical", &[32633]),
+  (63975, "Canonical", &[35023]), (63976, "Canonical", &[35041]),
+  (63977, "Canonical", &[37324]), (63978, "Canonical", &[38626]),
+  (63979, "Canonical", &[21311]), (63980, "Canonical", &[28346]),
+  (63981, "Canonical", &[21533]), (63982, "Canonical", &[29136]),
+  (63983, "Canonical", &[29848]), (63984, "Canonical", &[34298]),
+  (63985, "Canonical", &[38563]), (63986, "Canonical", &[40023]),
+  (63987, "Canonical", &[40607]), (63988, "Canonical", &[26519]),
+  (63989, "Canonical", &[28107]), (63990, "Canonical", &[33256]),
+  (63991, "Canonical", &[31435]), (63992, "Canonical", &[31520]),
+  (63993, "Canonical", &[31890]), (63994, "Canonical", &[29376]),
+  (63995, "Canonical", &[28825]), (63996, "Canonical", &[35672]),
+  (63997, "Canonical", &[20160]), (63998, "Canonical", &[33590]),
+  (63999, "Canonical", &[21050]), (64000, "Canonical", &[20999]),
+  (64001, "Canonical", &[24230]), (64002, "Canonical", &[25299]),
+  (64003, "Canonical", &[31958]), (64004, "Canonical", &[23429]),
+  (64005, "Canonical", &[27934]), (64006, "Canonical", &[26292]),
+  (64007, "Canonical", &[36667]), (64008, "Canonical", &[34892]),
+  (64009, "Canonical", &[38477]), (64010, "Canonical", &[35211]),
+  (64011, "Canonical", &[24275]), (64012, "Canonical", &[20800]),
+  (64013, "Canonical", &[21952]), (64016, "Canonical", &[22618]),
+  (64018, "Canonical", &[26228]), (64021, "Canonical", &[20958]),
+  (64022, "Canonical", &[29482]), (64023, "Canonical", &[30410]),
+  (64024, "Canonical", &[31036]), (64025, "Canonical", &[31070]),
+  (64026, "Canonical", &[31077]), (64027, "Canonical", &[31119]),
+  (64028, "Canonical", &[38742]), (64029, "Canonical", &[31934]),
+  (64030, "Canonical", &[32701]), (64032, "Canonical", &[34322]),
+  (64034, "Canonical", &[35576]), (64037, "Canonical", &[36920]),
+  (64038, "Canonical", &[37117]), (64042, "Canonical", &[39151]),
+  (64043, "Canonical", &[39164]), (64044, "Canonical", &[39208]),
+  (64045, "Canonical", &[40372]), (64046, "Canonical", &[37086]),
+  (64047, "Canonical", &[38583]), (64048, "Canonical", &[20398]),
+  (64049, "Canonical", &[20711]), (64050, "Canonical", &[20813]),
+  (64051, "Canonical", &[21193]), (64052, "Canonical", &[21220]),
+  (64053, "Canonical", &[21329]), (64054, "Canonical", &[21917]),
+  (64055, "Canonical", &[22022]), (64056, "Canonical", &[22120]),
+  (64057, "Canonical", &[22592]), (64058, "Canonical", &[22696]),
+  (64059, "Canonical", &[23652]), (64060, "Canonical", &[23662]),
+  (64061, "Canonical", &[24724]), (64062, "Canonical", &[24936]),
+  (64063, "Canonical", &[24974]), (64064, "Canonical", &[25074]),
+  (64065, "Canonical", &[25935]), (64066, "Canonical", &[26082]),
+  (64067, "Canonical", &[26257]), (64068, "Canonical", &[26757]),
+  (64069, "Canonical", &[28023]), (64070, "Canonical", &[28186]),
+  (64071, "Canonical", &[28450]), (64072, "Canonical", &[29038]),
+  (64073, "Canonical", &[29227]), (64074, "Canonical", &[29730]),
+  (64075, "Canonical", &[30865]), (64076, "Canonical", &[31038]),
+  (64077, "Canonical", &[31049]), (64078, "Canonical", &[31048]),
+  (64079, "Canonical", &[31056]), (64080, "Canonical", &[31062]),
+  (64081, "Canonical", &[31069]), (64082, "Canonical", &[31117]),
+  (64083, "Canonical", &[31118]), (64084, "Canonical", &[31296]),
+  (64085, "Canonical", &[31361]), (64086, "Canonical", &[31680]),
+  (64087, "Canonical", &[32244]), (64088, "Canonical", &[32265]),
+  (64089, "Canonical", &[32321]), (64090, "Canonical", &[32626]),
+  (64091, "Canonical", &[32773]), (64092, "Canonical", &[33261]),
+  (64093, "Canonical", &[33401]), (64094, "Canonical", &[33401]),
+  (64095, "Canonical", &[33879]), (64096, "Canonical", &[35088]),
+  (64097, "Canonical", &[35222]), (64098, "Canonical", &[35585]),
+  (64099, "Canonical", &[35641]), (64100, "Canonical", &[36051]),
+  (64101, "Canonical", &[36104]), (64102, "Canonical", &[36790]),
+  (64103, "Canonical", &[36920]), (64104, "Canonical", &[38627]),
+  (64105, "Canonical", &[38911]), (64106, "Canonical", &[38971]),
+  (64107, "Canonical", &[24693]), (64108, "Canonical", &[148206]),
+  (64109, "Canonical", &[33304]), (64112, "Canonical", &[20006]),
+  (64113, "Canonical", &[20917]), (64114, "Canonical", &[20840]),
+  (64115, "Canonical", &[20352]), (64116, "Canonical", &[20805]),
+  (64117, "Canonical", &[20864]), (64118, "Canonical", &[21191]),
+  (64119, "Canonical", &[21242]), (64120, "Canonical", &[21917]),
+  (64121, "Canonical", &[21845]), (64122, "Canonical", &[21913]),
+  (64123, "Canonical", &[21986]), (64124, "Canonical", &[22618]),
+  (64125, "Canonical", &[22707]), (64126, "Canonical", &[22852]),
+  (64127, "Canonical", &[22868]), (64128, "Canonical", &[23138]),
+  (64129, "Canonical", &[23336]), (64130, "Canonical", &[24274]),
+  (64131, "Canonical", &[24281]), (64132, "Canonical", &[24425]),
+  (64133, "Canonical", &[24493]), (64134, "Canonical", &[24792]),
+  (64135, "Canonical", &[24910]), (64136, "Canonical", &[24840]),
+  (64137, "Canonical", &[24974]), (64138, "Canonical", &[24928]),
+  (64139, "Canonical", &[25074]), (64140, "Canonical", &[25140]),
+  (64141, "Canonical", &[25540]), (64142, "Canonical", &[25628]),
+  (64143, "Canonical", &[25682]), (64144, "Canonical", &[25942]),
+  (64145, "Canonical", &[26228]), (64146, "Canonical", &[26391]),
+  (64147, "Canonical", &[26395]), (64148, "Canonical", &[26454]),
+  (64149, "Canonical", &[27513]), (64150, "Canonical", &[27578]),
+  (64151, "Canonical", &[27969]), (64152, "Canonical", &[28379]),
+  (64153, "Canonical", &[28363]), (64154, "Canonical", &[28450]),
+  (64155, "Canonical", &[28702]), (64156, "Canonical", &[29038]),
+  (64157, "Canonical", &[30631]), (64158, "Canonical", &[29237]),
+  (64159, "Canonical", &[29359]), (64160, "Canonical", &[29482]),
+  (64161, "Canonical", &[29809]), (64162, "Canonical", &[29958]),
+  (64163, "Canonical", &[30011]), (64164, "Canonical", &[30237]),
+  (64165, "Canonical", &[30239]), (64166, "Canonical", &[30410]),
+  (64167, "Canonical", &[30427]), (64168, "Canonical", &[30452]),
+  (64169, "Canonical", &[30538]), (64170, "Canonical", &[30528]),
+  (64171, "Canonical", &[30924]), (64172, "Canonical", &[31409]),
+  (64173, "Canonical", &[31680]), (64174, "Canonical", &[31867]),
+  (64175, "Canonical", &[32091]), (64176, "Canonical", &[32244]),
+  (64177, "Canonical", &[32574]), (64178, "Canonical", &[32773]),
+  (64179, "Canonical", &[33618]), (64180, "Canonical", &[33775]),
+  (64181, "Canonical", &[34681]), (64182, "Canonical", &[35137]),
+  (64183, "Canonical", &[35206]), (64184, "Canonical", &[35222]),
+  (64185, "Canonical", &[35519]), (64186, "Canonical", &[35576]),
+  (64187, "Canonical", &[35531]), (64188, "Canonical", &[35585]),
+  (64189, "Canonical", &[35582]), (64190, "Canonical", &[35565]),
+  (64191, "Canonical", &[35641]), (64192, "Canonical", &[35722]),
+  (64193, "Canonical", &[36104]), (64194, "Canonical", &[36664]),
+  (64195, "Canonical", &[36978]), (64196, "Canonical", &[37273]),
+  (64197, "Canonical", &[37494]), (64198, "Canonical", &[38524]),
+  (64199, "Canonical", &[38627]), (64200, "Canonical", &[38742]),
+  (64201, "Canonical", &[38875]), (64202, "Canonical", &[38911]),
+  (64203, "Canonical", &[38923]), (64204, "Canonical", &[38971]),
+  (64205, "Canonical", &[39698]), (64206, "Canonical", &[40860]),
+  (64207, "Canonical", &[141386]), (64208, "Canonical", &[141380]),
+  (64209, "Canonical", &[144341]), (64210, "Canonical", &[15261]),
+  (64211, "Canonical", &[16408]), (64212, "Canonical", &[16441]),
+  (64213, "Canonical", &[152137]), (64214, "Canonical", &[154832]),
+  (64215, "Canonical", &[163539]), (64216, "Canonical", &[40771]),
+  (64217, "Canonical", &[40846]), (64256, "Compat", &[102, 102]),
+  (64257, "Compat", &[102, 105]), (64258, "Compat", &[102, 108]),
+  (64259, "Compat", &[102, 102, 105]), (64260, "Compat", &[102, 102, 108]),
+  (64261, "Compat", &[383, 116]), (64262, "Compat", &[115, 116]),
+  (64275, "Compat", &[1396, 1398]), (64276, "Compat", &[1396, 1381]),
+  (64277, "Compat", &[1396, 1387]), (64278, "Compat", &[1406, 1398]),
+  (64279, "Compat", &[1396, 1389]), (64285, "Canonical", &[1497, 1460]),
+  (64287, "Canonical", &[1522, 1463]), (64288, "Font", &[1506]),
+  (64289, "Font", &[1488]), (64290, "Font", &[1491]), (64291, "Font", &[1492]),
+  (64292, "Font", &[1499]), (64293, "Font", &[1500]), (64294, "Font", &[1501]),
+  (64295, "Font", &[1512]), (64296, "Font", &[1514]), (64297, "Font", &[43]),
+  (64298, "Canonical", &[1513, 1473]), (64299, "Canonical", &[1513, 1474]),
+  (64300, "Canonical", &[64329, 1473]), (64301, "Canonical", &[64329, 1474]),
+  (64302, "Canonical", &[1488, 1463]), (64303, "Canonical", &[1488, 1464]),
+  (64304, "Canonical", &[1488, 1468]), (64305, "Canonical", &[1489, 1468]),
+  (64306, "Canonical", &[1490, 1468]), (64307, "Canonical", &[1491, 1468]),
+  (64308, "Canonical", &[1492, 1468]), (64309, "Canonical", &[1493, 1468]),
+  (64310, "Canonical", &[1494, 1468]), (64312, "Canonical", &[1496, 1468]),
+  (64313, "Canonical", &[1497, 1468]), (64314, "Canonical", &[1498, 1468]),
+  (64315, "Canonical", &[1499, 1468]), (64316, "Canonical", &[1500, 1468]),
+  (64318, "Canonical", &[1502, 1468]), (64320, "Canonical", &[1504, 1468]),
+  (64321, "Canonical", &[1505, 1468]), (64323, "Canonical", &[1507, 1468]),
+  (64324, "Canonical", &[1508, 1468]), (64326, "Canonical", &[1510, 1468]),
+  (64327, "Canonical", &[1511, 1468]), (64328, "Canonical", &[1512, 1468]),
+  (64329, "Canonical", &[1513, 1468]), (64330, "Canonical", &[1514, 1468]),
+  (64331, "Canonical", &[1493, 1465]), (64332, "Canonical", &[1489, 1471]),
+  (64333, "Canonical", &[1499, 1471]), (64334, "Canonical", &[1508, 1471]),
+  (64335, "Compat", &[1488, 1500]), (64336, "Isolated", &[1649]),
+  (64337, "Final", &[1649]), (64338, "Isolated", &[1659]),
+  (64339, "Final", &[1659]), (64340, "Initial", &[1659]),
+  (64341, "Medial", &[1659]), (64342, "Isolated", &[1662]),
+  (64343, "Final", &[1662]), (64344, "Initial", &[1662]),
+  (64345, "Medial", &[1662]), (64346, "Isolated", &[1664]),
+  (64347, "Final", &[1664]), (64348, "Initial", &[1664]),
+  (64349, "Medial", &[1664]), (64350, "Isolated", &[1658]),
+  (64351, "Final", &[1658]), (64352, "Initial", &[1658]),
+  (64353, "Medial", &[1658]), (64354, "Isolated", &[1663]),
+  (64355, "Final", &[1663]), (64356, "Initial", &[1663]),
+  (64357, "Medial", &[1663]), (64358, "Isolated", &[1657]),
+  (64359, "Final", &[1657]), (64360, "Initial", &[1657]),
+  (64361, "Medial", &[1657]), (64362, "Isolated", &[1700]),
+  (64363, "Final", &[1700]), (64364, "Initial", &[1700]),
+  (64365, "Medial", &[1700]), (64366, "Isolated", &[1702]),
+  (64367, "Final", &[1702]), (64368, "Initial", &[1702]),
+  (64369, "Medial", &[1702]), (64370, "Isolated", &[1668]),
+  (64371, "Final", &[1668]), (64372, "Initial", &[1668]),
+  (64373, "Medial", &[1668]), (64374, "Isolated", &[1667]),
+  (64375, "Final", &[1667]), (64376, "Initial", &[1667]),
+  (64377, "Medial", &[1667]), (64378, "Isolated", &[1670]),
+  (64379, "Final", &[1670]), (64380, "Initial", &[1670]),
+  (64381, "Medial", &[1670]), (64382, "Isolated", &[1671]),
+  (64383, "Final", &[1671]), (64384, "Initial", &[1671]),
+  (64385, "Medial", &[1671]), (64386, "Isolated", &[1677]),
+  (64387, "Final", &[1677]), (64388, "Isolated", &[1676]),
+  (64389, "Final", &[1676]), (64390, "Isolated", &[1678]),
+  (64391, "Final", &[1678]), (64392, "Isolated", &[1672]),
+  (64393, "Final", &[1672]), (64394, "Isolated", &[1688]),
+  (64395, "Final", &[1688]), (64396, "Isolated", &[1681]),
+  (64397, "Final", &[1681]), (64398, "Isolated", &[1705]),
+  (64399, "Final", &[1705]), (64400, "Initial", &[1705]),
+  (64401, "Medial", &[1705]), (64402, "Isolated", &[1711]),
+  (64403, "Final", &[1711]), (64404, "Initial", &[1711]),
+  (64405, "Medial", &[1711]), (64406, "Isolated", &[1715]),
+  (64407, "Final", &[1715]), (64408, "Initial", &[1715]),
+  (64409, "Medial", &[1715]), (64410, "Isolated", &[1713]),
+  (64411, "Final", &[1713]), (64412, "Initial", &[1713]),
+  (64413, "Medial", &[1713]), (64414, "Isolated", &[1722]),
+  (64415, "Final", &[1722]), (64416, "Isolated", &[1723]),
+  (64417, "Final", &[1723]), (64418, "Initial", &[1723]),
+  (64419, "Medial", &[1723]), (64420, "Isolated", &[1728]),
+  (64421, "Final", &[1728]), (64422, "Isolated", &[1729]),
+  (64423, "Final", &[1729]), (64424, "Initial", &[1729]),
+  (64425, "Medial", &[1729]), (64426, "Isolated", &[1726]),
+  (64427, "Final", &[1726]), (64428, "Initial", &[1726]),
+  (64429, "Medial", &[1726]), (64430, "Isolated", &[1746]),
+  (64431, "Final", &[1746]), (64432, "Isolated", &[1747]),
+  (64433, "Final", &[1747]), (64467, "Isolated", &[1709]),
+  (64468, "Final", &[1709]), (64469, "Initial", &[1709]),
+  (64470, "Medial", &[1709]), (64471, "Isolated", &[1735]),
+  (64472, "Final", &[1735]), (64473, "Isolated", &[1734]),
+  (64474, "Final", &[1734]), (64475, "Isolated", &[1736]),
+  (64476, "Final", &[1736]), (64477, "Isolated", &[1655]),
+  (64478, "Isolated", &[1739]), (64479, "Final", &[1739]),
+  (64480, "Isolated", &[1733]), (64481, "Final", &[1733]),
+  (64482, "Isolated", &[1737]), (64483, "Final", &[1737]),
+  (64484, "Isolated", &[1744]), (64485, "Final", &[1744]),
+  (64486, "Initial", &[1744]), (64487, "Medial", &[1744]),
+  (64488, "Initial", &[1609]), (64489, "Medial", &[1609]),
+  (64490, "Isolated", &[1574, 1575]), (64491, "Final", &[1574, 1575]),
+  (64492, "Isolated", &[1574, 1749]), (64493, "Final", &[1574, 1749]),
+  (64494, "Isolated", &[1574, 1608]), (64495, "Final", &[1574, 1608]),
+  (64496, "Isolated", &[1574, 1735]), (64497, "Final", &[1574, 1735]),
+  (64498, "Isolated", &[1574, 1734]), (64499, "Final", &[1574, 1734]),
+  (64500, "Isolated", &[1574, 1736]), (64501, "Final", &[1574, 1736]),
+  (64502, "Isolated", &[1574, 1744]), (64503, "Final", &[1574, 1744]),
+  (64504, "Initial", &[1574, 1744]), (64505, "Isolated", &[1574, 1609]),
+  (64506, "Final", &[1574, 1609]), (64507, "Initial", &[1574, 1609]),
+  (64508, "Isolated", &[1740]), (64509, "Final", &[1740]),
+  (64510, "Initial", &[1740]), (64511, "Medial", &[1740]),
+  (64512, "Isolated", &[1574, 1580]), (64513, "Isolated", &[1574, 1581]),
+  (64514, "Isolated", &[1574, 1605]), (64515, "Isolated", &[1574, 1609]),
+  (64516, "Isolated", &[1574, 1610]), (64517, "Isolated", &[1576, 1580]),
+  (64518, "Isolated", &[1576, 1581]), (64519, "Isolated", &[1576, 1582]),
+  (64520, "Isolated", &[1576, 1605]), (64521, "Isolated", &[1576, 1609]),
+  (64522, "Isolated", &[1576, 1610]), (64523, "Isolated", &[1578, 1580]),
+  (64524, "Isolated", &[1578, 1581]), (64525, "Isolated", &[1578, 1582]),
+  (64526, "Isolated", &[1578, 1605]), (64527, "Isolated", &[1578, 1609]),
+  (64528, "Isolated", &[1578, 1610]), (64529, "Isolated", &[1579, 1580]),
+  (64530, "Isolated", &[1579, 1605]), (64531, "Isolated", &[1579, 1609]),
+  (64532, "Isolated", &[1579, 1610]), (64533, "Isolated", &[1580, 1581]),
+  (64534, "Isolated", &[1580, 1605]), (64535, "Isolated", &[1581, 1580]),
+  (64536, "Isolated", &[1581, 1605]), (64537, "Isolated", &[1582, 1580]),
+  (64538, "Isolated", &[1582, 1581]), (64539, "Isolated", &[1582, 1605]),
+  (64540, "Isolated", &[1587, 1580]), (64541, "Isolated", &[1587, 1581]),
+  (64542, "Isolated", &[1587, 1582]), (64543, "Isolated", &[1587, 1605]),
+  (64544, "Isolated", &[1589, 1581]), (64545, "Isolated", &[1589, 1605]),
+  (64546, "Isolated", &[1590, 1580]), (64547, "Isolated", &[1590, 1581]),
+  (64548, "Isolated", &[1590, 1582]), (64549, "Isolated", &[1590, 1605]),
+  (64550, "Isolated", &[1591, 1581]), (64551, "Isolated", &[1591, 1605]),
+  (64552, "Isolated", &[1592, 1605]), (64553, "Isolated", &[1593, 1580]),
+  (64554, "Isolated", &[1593, 1605]), (64555, "Isolated", &[1594, 1580]),
+  (64556, "Isolated", &[1594, 1605]), (64557, "Isolated", &[1601, 1580]),
+  (64558, "Isolated", &[1601, 1581]), (64559, "Isolated", &[1601, 1582]),
+  (64560, "Isolated", &[1601, 1605]), (64561, "Isolated", &[1601, 1609]),
+  (64562, "Isolated", &[1601, 1610]), (64563, "Isolated", &[1602, 1581]),
+  (64564, "Isolated", &[1602, 1605]), (64565, "Isolated", &[1602, 1609]),
+  (64566, "Isolated", &[1602, 1610]), (64567, "Isolated", &[1603, 1575]),
+  (64568, "Isolated", &[1603, 1580]), (64569, "Isolated", &[1603, 1581]),
+  (64570, "Isolated", &[1603, 1582]), (64571, "Isolated", &[1603, 1604]),
+  (64572, "Isolated", &[1603, 1605]), (64573, "Isolated", &[1603, 1609]),
+  (64574, "Isolated", &[1603, 1610]), (64575, "Isolated", &[1604, 1580]),
+  (64576, "Isolated", &[1604, 1581]), (64577, "Isolated", &[1604, 1582]),
+  (64578, "Isolated", &[1604, 1605]), (64579, "Isolated", &[1604, 1609]),
+  (64580, "Isolated", &[1604, 1610]), (64581, "Isolated", &[1605, 1580]),
+  (64582, "Isolated", &[1605, 1581]), (64583, "Isolated", &[1605, 1582]),
+  (64584, "Isolated", &[1605, 1605]), (64585, "Isolated", &[1605, 1609]),
+  (64586, "Isolated", &[1605, 1610]), (64587, "Isolated", &[1606, 1580]),
+  (64588, "Isolated", &[1606, 1581]), (64589, "Isolated", &[1606, 1582]),
+  (64590, "Isolated", &[1606, 1605]), (64591, "Isolated", &[1606, 1609]),
+  (64592, "Isolated", &[1606, 1610]), (64593, "Isolated", &[1607, 1580]),
+  (64594, "Isolated", &[1607, 1605]), (64595, "Isolated", &[1607, 1609]),
+  (64596, "Isolated", &[1607, 1610]), (64597, "Isolated", &[1610, 1580]),
+  (64598, "Isolated", &[1610, 1581]), (64599, "Isolated", &[1610, 1582]),
+  (64600, "Isolated", &[1610, 1605]), (64601, "Isolated", &[1610, 1609]),
+  (64602, "Isolated", &[1610, 1610]), (64603, "Isolated", &[1584, 1648]),
+  (64604, "Isolated", &[1585, 1648]), (64605, "Isolated", &[1609, 1648]),
+  (64606, "Isolated", &[32, 1612, 1617]),
+  (64607, "Isolated", &[32, 1613, 1617]),
+  (64608, "Isolated", &[32, 1614, 1617]),
+  (64609, "Isolated", &[32, 1615, 1617]),
+  (64610, "Isolated", &[32, 1616, 1617]),
+  (64611, "Isolated", &[32, 1617, 1648]), (64612, "Final", &[1574, 1585]),
+  (64613, "Final", &[1574, 1586]), (64614, "Final", &[1574, 1605]),
+  (64615, "Final", &[1574, 1606]), (64616, "Final", &[1574, 1609]),
+  (64617, "Final", &[1574, 1610]), (64618, "Final", &[1576, 1585]),
+  (64619, "Final", &[1576, 1586]), (64620, "Final", &[1576, 1605]),
+  (64621, "Final", &[1576, 1606]), (64622, "Final", &[1576, 1609]),
+  (64623, "Final", &[1576, 1610]), (64624, "Final", &[1578, 1585]),
+  (64625, "Final", &[1578, 1586]), (64626, "Final", &[1578, 1605]),
+  (64627, "Final", &[1578, 1606]), (64628, "Final", &[1578, 1609]),
+  (64629, "Final", &[1578, 1610]), (64630, "Final", &[1579, 1585]),
+  (64631, "Final", &[1579, 1586]), (64632, "Final", &[1579, 1605]),
+  (64633, "Final", &[1579, 1606]), (64634, "Final", &[1579, 1609]),
+  (64635, "Final", &[1579, 1610]), (64636, "Final", &[1601, 1609]),
+  (64637, "Final", &[1601, 1610]), (64638, "Final", &[1602, 1609]),
+  (64639, "Final", &[1602, 1610]), (64640, "Final", &[1603, 1575]),
+  (64641, "Final", &[1603, 1604]), (64642, "Final", &[1603, 1605]),
+  (64643, "Final", &[1603, 1609]), (64644, "Final", &[1603, 1610]),
+  (64645, "Final", &[1604, 1605]), (64646, "Final", &[1604, 1609]),
+  (64647, "Final", &[1604, 1610]), (64648, "Final", &[1605, 1575]),
+  (64649, "Final", &[1605, 1605]), (64650, "Final", &[1606, 1585]),
+  (64651, "Final", &[1606, 1586]), (64652, "Final", &[1606, 1605]),
+  (64653, "Final", &[1606, 1606]), (64654, "Final", &[1606, 1609]),
+  (64655, "Final", &[1606, 1610]), (64656, "Final", &[1609, 1648]),
+  (64657, "Final", &[1610, 1585]), (64658, "Final", &[1610, 1586]),
+  (64659, "Final", &[1610, 1605]), (64660, "Final", &[1610, 1606]),
+  (64661, "Final", &[1610, 1609]), (64662, "Final", &[1610, 1610]),
+  (64663, "Initial", &[1574, 1580]), (64664, "Initial", &[1574, 1581]),
+  (64665, "Initial", &[1574, 1582]), (64666, "Initial", &[1574, 1605]),
+  (64667, "Initial", &[1574, 1607]), (64668, "Initial", &[1576, 1580]),
+  (64669, "Initial", &[1576, 1581]), (64670, "Initial", &[1576, 1582]),
+  (64671, "Initial", &[1576, 1605]), (64672, "Initial", &[1576, 1607]),
+  (64673, "Initial", &[1578, 1580]), (64674, "Initial", &[1578, 1581]),
+  (64675, "Initial", &[1578, 1582]), (64676, "Initial", &[1578, 1605]),
+  (64677, "Initial", &[1578, 1607]), (64678, "Initial", &[1579, 1605]),
+  (64679, "Initial", &[1580, 1581]), (64680, "Initial", &[1580, 1605]),
+  (64681, "Initial", &[1581, 1580]), (64682, "Initial", &[1581, 1605]),
+  (64683, "Initial", &[1582, 1580]), (64684, "Initial", &[1582, 1605]),
+  (64685, "Initial", &[1587, 1580]), (64686, "Initial", &[1587, 1581]),
+  (64687, "Initial", &[1587, 1582]), (64688, "Initial", &[1587, 1605]),
+  (64689, "Initial", &[1589, 1581]), (64690, "Initial", &[1589, 1582]),
+  (64691, "Initial", &[1589, 1605]), (64692, "Initial", &[1590, 1580]),
+  (64693, "Initial", &[1590, 1581]), (64694, "Initial", &[1590, 1582]),
+  (64695, "Initial", &[1590, 1605]), (64696, "Initial", &[1591, 1581]),
+  (64697, "Initial", &[1592, 1605]), (64698, "Initial", &[1593, 1580]),
+  (64699, "Initial", &[1593, 1605]), (64700, "Initial", &[1594, 1580]),
+  (64701, "Initial", &[1594, 1605]), (64702, "Initial", &[1601, 1580]),
+  (64703, "Initial", &[1601, 1581]), (64704, "Initial", &[1601, 1582]),
+  (64705, "Initial", &[1601, 1605]), (64706, "Initial", &[1602, 1581]),
+  (64707, "Initial", &[1602, 1605]), (64708, "Initial", &[1603, 1580]),
+  (64709, "Initial", &[1603, 1581]), (64710, "Initial", &[1603, 1582]),
+  (64711, "Initial", &[1603, 1604]), (64712, "Initial", &[1603, 1605]),
+  (64713, "Initial", &[1604, 1580]), (64714, "Initial", &[1604, 1581]),
+  (64715, "Initial", &[1604, 1582]), (64716, "Initial", &[1604, 1605]),
+  (64717, "Initial", &[1604, 1607]), (64718, "Initial", &[1605, 1580]),
+  (64719, "Initial", &[1605, 1581]), (64720, "Initial", &[1605, 1582]),
+  (64721, "Initial", &[1605, 1605]), (64722, "Initial", &[1606, 1580]),
+  (64723, "Initial", &[1606, 1581]), (64724, "Initial", &[1606, 1582]),
+  (64725, "Initial", &[1606, 1605]), (64726, "Initial", &[1606, 1607]),
+  (64727, "Initial", &[1607, 1580]), (64728, "Initial", &[1607, 1605]),
+  (64729, "Initial", &[1607, 1648]), (64730, "Initial", &[1610, 1580]),
+  (64731, "Initial", &[1610, 1581]), (64732, "Initial", &[1610, 1582]),
+  (64733, "Initial", &[1610, 1605]), (64734, "Initial", &[1610, 1607]),
+  (64735, "Medial", &[1574, 1605]), (64736, "Medial", &[1574, 1607]),
+  (64737, "Medial", &[1576, 1605]), (64738, "Medial", &[1576, 1607]),
+  (64739, "Medial", &[1578, 1605]), (64740, "Medial", &[1578, 1607]),
+  (64741, "Medial", &[1579, 1605]), (64742, "Medial", &[1579, 1607]),
+  (64743, "Medial", &[1587, 1605]), (64744, "Medial", &[1587, 1607]),
+  (64745, "Medial", &[1588, 1605]), (64746, "Medial", &[1588, 1607]),
+  (64747, "Medial", &[1603, 1604]), (64748, "Medial", &[1603, 1605]),
+  (64749, "Medial", &[1604, 1605]), (64750, "Medial", &[1606, 1605]),
+  (64751, "Medial", &[1606, 1607]), (64752, "Medial", &[1610, 1605]),
+  (64753, "Medial", &[1610, 1607]), (64754, "Medial", &[1600, 1614, 1617]),
+  (64755, "Medial", &[1600, 1615, 1617]),
+  (64756, "Medial", &[1600, 1616, 1617]), (64757, "Isolated", &[1591, 1609]),
+  (64758, "Isolated", &[1591, 1610]), (64759, "Isolated", &[1593, 1609]),
+  (64760, "Isolated", &[1593, 1610]), (64761, "Isolated", &[1594, 1609]),
+  (64762, "Isolated", &[1594, 1610]), (64763, "Isolated", &[1587, 1609]),
+  (64764, "Isolated", &[1587, 1610]), (64765, "Isolated", &[1588, 1609]),
+  (64766, "Isolated", &[1588, 1610]), (64767, "Isolated", &[1581, 1609]),
+  (64768, "Isolated", &[1581, 1610]), (64769, "Isolated", &[1580, 1609]),
+  (64770, "Isolated", &[1580, 1610]), (64771, "Isolated", &[1582, 1609]),
+  (64772, "Isolated", &[1582, 1610]), (64773, "Isolated", &[1589, 1609]),
+  (64774, "Isolated", &[1589, 1610]), (64775, "Isolated", &[1590, 1609]),
+  (64776, "Isolated", &[1590, 1610]), (64777, "Isolated", &[1588, 1580]),
+  (64778, "Isolated", &[1588, 1581]), (64779, "Isolated", &[1588, 1582]),
+  (64780, "Isolated", &[1588, 1605]), (64781, "Isolated", &[1588, 1585]),
+  (64782, "Isolated", &[1587, 1585]), (64783, "Isolated", &[1589, 1585]),
+  (64784, "Isolated", &[1590, 1585]), (64785, "Final", &[1591, 1609]),
+  (64786, "Final", &[1591, 1610]), (64787, "Final", &[1593, 1609]),
+  (64788, "Final", &[1593, 1610]), (64789, "Final", &[1594, 1609]),
+  (64790, "Final", &[1594, 1610]), (64791, "Final", &[1587, 1609]),
+  (64792, "Final", &[1587, 1610]), (64793, "Final", &[1588, 1609]),
+  (64794, "Final", &[1588, 1610]), (64795, "Final", &[1581, 1609]),
+  (64796, "Final", &[1581, 1610]), (64797, "Final", &[1580, 1609]),
+  (64798, "Final", &[1580, 1610]), (64799, "Final", &[1582, 1609]),
+  (64800, "Final", &[1582, 1610]), (64801, "Final", &[1589, 1609]),
+  (64802, "Final", &[1589, 1610]), (64803, "Final", &[1590, 1609]),
+  (64804, "Final", &[1590, 1610]), (64805, "Final", &[1588, 1580]),
+  (64806, "Final", &[1588, 1581]), (64807, "Final", &[1588, 1582]),
+  (64808, "Final", &[1588, 1605]), (64809, "Final", &[1588, 1585]),
+  (64810, "Final", &[1587, 1585]), (64811, "Final", &[1589, 1585]),
+  (64812, "Final", &[1590, 1585]), (64813, "Initial", &[1588, 1580]),
+  (64814, "Initial", &[1588, 1581]), (64815, "Initial", &[1588, 1582]),
+  (64816, "Initial", &[1588, 1605]), (64817, "Initial", &[1587, 1607]),
+  (64818, "Initial", &[1588, 1607]), (64819, "Initial", &[1591, 1605]),
+  (64820, "Medial", &[1587, 1580]), (64821, "Medial", &[1587, 1581]),
+  (64822, "Medial", &[1587, 1582]), (64823, "Medial", &[1588, 1580]),
+  (64824, "Medial", &[1588, 1581]), (64825, "Medial", &[1588, 1582]),
+  (64826, "Medial", &[1591, 1605]), (64827, "Medial", &[1592, 1605]),
+  (64828, "Final", &[1575, 1611]), (64829, "Isolated", &[1575, 1611]),
+  (64848, "Initial", &[1578, 1580, 1605]),
+  (64849, "Final", &[1578, 1581, 1580]),
+  (64850, "Initial", &[1578, 1581, 1580]),
+  (64851, "Initial", &[1578, 1581, 1605]),
+  (64852, "Initial", &[1578, 1582, 1605]),
+  (64853, "Initial", &[1578, 1605, 1580]),
+  (64854, "Initial", &[1578, 1605, 1581]),
+  (64855, "Initial", &[1578, 1605, 1582]),
+  (64856, "Final", &[1580, 1605, 1581]),
+  (64857, "Initial", &[1580, 1605, 1581]),
+  (64858, "Final", &[1581, 1605, 1610]), (64859, "Final", &[1581, 1605, 1609]),
+  (64860, "Initial", &[1587, 1581, 1580]),
+  (64861, "Initial", &[1587, 1580, 1581]),
+  (64862, "Final", &[1587, 1580, 1609]), (64863, "Final", &[1587, 1605, 1581]),
+  (64864, "Initial", &[1587, 1605, 1581]),
+  (64865, "Initial", &[1587, 1605, 1580]),
+  (64866, "Final", &[1587, 1605, 1605]),
+  (64867, "Initial", &[1587, 1605, 1605]),
+  (64868, "Final", &[1589, 1581, 1581]),
+  (64869, "Initial", &[1589, 1581, 1581]),
+  (64870, "Final", &[1589, 1605, 1605]), (64871, "Final", &[1588, 1581, 1605]),
+  (64872, "Initial", &[1588, 1581, 1605]),
+  (64873, "Final", &[1588, 1580, 1610]), (64874, "Final", &[1588, 1605, 1582]),
+  (64875, "Initial", &[1588, 1605, 1582]),
+  (64876, "Final", &[1588, 1605, 1605]),
+  (64877, "Initial", &[1588, 1605, 1605]),
+  (64878, "Final", &[1590, 1581, 1609]), (64879, "Final", &[1590, 1582, 1605]),
+  (64880, "Initial", &[1590, 1582, 1605]),
+  (64881, "Final", &[1591, 1605, 1581]),
+  (64882, "Initial", &[1591, 1605, 1581]),
+  (64883, "Initial", &[1591, 1605, 1605]),
+  (64884, "Final", &[1591, 1605, 1610]), (64885, "Final", &[1593, 1580, 1605]),
+  (64886, "Final", &[1593, 1605, 1605]),
+  (64887, "Initial", &[1593, 1605, 1605]),
+  (64888, "Final", &[1593, 1605, 1609]), (64889, "Final", &[1594, 1605, 1605]),
+  (64890, "Final", &[1594, 1605, 1610]), (64891, "Final", &[1594, 1605, 1609]),
+  (64892, "Final", &[1601, 1582, 1605]),
+  (64893, "Initial", &[1601, 1582, 1605]),
+  (64894, "Final", &[1602, 1605, 1581]), (64895, "Final", &[1602, 1605, 1605]),
+  (64896, "Final", &[1604, 1581, 1605]), (64897, "Final", &[1604, 1581, 1610]),
+  (64898, "Final", &[1604, 1581, 1609]),
+  (64899, "Initial", &[1604, 1580, 1580]),
+  (64900, "Final", &[1604, 1580, 1580]), (64901, "Final", &[1604, 1582, 1605]),
+  (64902, "Initial", &[1604, 1582, 1605]),
+  (64903, "Final", &[1604, 1605, 1581]),
+  (64904, "Initial", &[1604, 1605, 1581]),
+  (64905, "Initial", &[1605, 1581, 1580]),
+  (64906, "Initial", &[1605, 1581, 1605]),
+  (64907, "Final", &[1605, 1581, 1610]),
+  (64908, "Initial", &[1605, 1580, 1581]),
+  (64909, "Initial", &[1605, 1580, 1605]),
+  (64910, "Initial", &[1605, 1582, 1580]),
+  (64911, "Initial", &[1605, 1582, 1605]),
+  (64914, "Initial", &[1605, 1580, 1582]),
+  (64915, "Initial", &[1607, 1605, 1580]),
+  (64916, "Initial", &[1607, 1605, 1605]),
+  (64917, "Initial", &[1606, 1581, 1605]),
+  (64918, "Final", &[1606, 1581, 1609]), (64919, "Final", &[1606, 1580, 1605]),
+  (64920, "Initial", &[1606, 1580, 1605]),
+  (64921, "Final", &[1606, 1580, 1609]), (64922, "Final", &[1606, 1605, 1610]),
+  (64923, "Final", &[1606, 1605, 1609]), (64924, "Final", &[1610, 1605, 1605]),
+  (64925, "Initial", &[1610, 1605, 1605]),
+  (64926, "Final", &[1576, 1582, 1610]), (64927, "Final", &[1578, 1580, 1610]),
+  (64928, "Final", &[1578, 1580, 1609]), (64929, "Final", &[1578, 1582, 1610]),
+  (64930, "Final", &[1578, 1582, 1609]), (64931, "Final", &[1578, 1605, 1610]),
+  (64932, "Final", &[1578, 1605, 1609]), (64933, "Final", &[1580, 1605, 1610]),
+  (64934, "Final", &[1580, 1581, 1609]), (64935, "Final", &[1580, 1605, 1609]),
+  (64936, "Final", &[1587, 1582, 1609]), (64937, "Final", &[1589, 1581, 1610]),
+  (64938, "Final", &[1588, 1581, 1610]), (64939, "Final", &[1590, 1581, 1610]),
+  (64940, "Final", &[1604, 1580, 1610]), (64941, "Final", &[1604, 1605, 1610]),
+  (64942, "Final", &[1610, 1581, 1610]), (64943, "Final", &[1610, 1580, 1610]),
+  (64944, "Final", &[1610, 1605, 1610]), (64945, "Final", &[1605, 1605, 1610]),
+  (64946, "Final", &[1602, 1605, 1610]), (64947, "Final", &[1606, 1581, 1610]),
+  (64948, "Initial", &[1602, 1605, 1581]),
+  (64949, "Initial", &[1604, 1581, 1605]),
+  (64950, "Final", &[1593, 1605, 1610]), (64951, "Final", &[1603, 1605, 1610]),
+  (64952, "Initial", &[1606, 1580, 1581]),
+  (64953, "Final", &[1605, 1582, 1610]),
+  (64954, "Initial", &[1604, 1580, 1605]),
+  (64955, "Final", &[1603, 1605, 1605]), (64956, "Final", &[1604, 1580, 1605]),
+  (64957, "Final", &[1606, 1580, 1581]), (64958, "Final", &[1580, 1581, 1610]),
+  (64959, "Final", &[1581, 1580, 1610]), (64960, "Final", &[1605, 1580, 1610]),
+  (64961, "Final", &[1601, 1605, 1610]), (64962, "Final", &[1576, 1581, 1610]),
+  (64963, "Initial", &[1603, 1605, 1605]),
+  (64964, "Initial", &[1593, 1580, 1605]),
+  (64965, "Initial", &[1589, 1605, 1605]),
+  (64966, "Final", &[1587, 1582, 1610]), (64967, "Final", &[1606, 1580, 1610]),
+  (65008, "Isolated", &[1589, 1604, 1746]),
+  (65009, "Isolated", &[1602, 1604, 1746]),
+  (65010, "Isolated", &[1575, 1604, 1604, 1607]),
+  (65011, "Isolated", &[1575, 1603, 1576, 1585]),
+  (65012, "Isolated", &[1605, 1581, 1605, 1583]),
+  (65013, "Isolated", &[1589, 1604, 1593, 1605]),
+  (65014, "Isolated", &[1585, 1587, 1608, 1604]),
+  (65015, "Isolated", &[1593, 1604, 1610, 1607]),
+  (65016, "Isolated", &[1608, 1587, 1604, 1605]),
+  (65017, "Isolated", &[1589, 1604, 1609]),
+  (65018, "Isolated", &[1589, 1604, 1609, 32, 1575, 1604, 1604, 1607, 32, 1593, 1604, 1610, 1607, 32, 1608, 1587, 1604, 1605]),
+  (65019, "Isolated", &[1580, 1604, 32, 1580, 1604, 1575, 1604, 1607]),
+  (65020, "Isolated", &[1585, 1740, 1575, 1604]), (65040, "Vertical", &[44]),
+  (65041, "Vertical", &[12289]), (65042, "Vertical", &[12290]),
+  (65043, "Vertical", &[58]), (65044, "Vertical", &[59]),
+  (65045, "Vertical", &[33]), (65046, "Vertical", &[63]),
+  (65047, "Vertical", &[12310]), (65048, "Vertical", &[12311]),
+  (65049, "Vertical", &[8230]), (65072, "Vertical", &[8229]),
+  (65073, "Vertical", &[8212]), (65074, "Vertical", &[8211]),
+  (65075, "Vertical", &[95]), (65076, "Vertical", &[95]),
+  (65077, "Vertical", &[40]), (65078, "Vertical", &[41]),
+  (65079, "Vertical", &[123]), (65080, "Vertical", &[125]),
+  (65081, "Vertical", &[12308]), (65082, "Vertical", &[12309]),
+  (65083, "Vertical", &[12304]), (65084, "Vertical", &[12305]),
+  (65085, "Vertical", &[12298]), (65086, "Vertical", &[12299]),
+  (65087, "Vertical", &[12296]), (65088, "Vertical", &[12297]),
+  (65089, "Vertical", &[12300]), (65090, "Vertical", &[12301]),
+  (65091, "Vertical", &[12302]), (65092, "Vertical", &[12303]),
+  (65095, "Vertical", &[91]), (65096, "Vertical", &[93]),
+  (65097, "Compat", &[8254]), (65098, "Compat", &[8254]),
+  (65099, "Compat", &[8254]), (65100, "Compat", &[8254]),
+  (65101, "Compat", &[95]), (65102, "Compat", &[95]), (65103, "Compat", &[95]),
+  (65104, "Small", &[44]), (65105, "Small", &[12289]), (65106, "Small", &[46]),
+  (65108, "Small", &[59]), (65109, "Small", &[58]), (65110, "Small", &[63]),
+  (65111, "Small", &[33]), (65112, "Small", &[8212]), (65113, "Small", &[40]),
+  (65114, "Small", &[41]), (65115, "Small", &[123]), (65116, "Small", &[125]),
+  (65117, "Small", &[12308]), (65118, "Small", &[12309]),
+  (65119, "Small", &[35]), (65120, "Small", &[38]), (65121, "Small", &[42]),
+  (65122, "Small", &[43]), (65123, "Small", &[45]), (65124, "Small", &[60]),
+  (65125, "Small", &[62]), (65126, "Small", &[61]), (65128, "Small", &[92]),
+  (65129, "Small", &[36]), (65130, "Small", &[37]), (65131, "Small", &[64]),
+  (65136, "Isolated", &[32, 1611]), (65137, "Medial", &[1600, 1611]),
+  (65138, "Isolated", &[32, 1612]), (65140, "Isolated", &[32, 1613]),
+  (65142, "Isolated", &[32, 1614]), (65143, "Medial", &[1600, 1614]),
+  (65144, "Isolated", &[32, 1615]), (65145, "Medial", &[1600, 1615]),
+  (65146, "Isolated", &[32, 1616]), (65147, "Medial", &[1600, 1616]),
+  (65148, "Isolated", &[32, 1617]), (65149, "Medial", &[1600, 1617]),
+  (65150, "Isolated", &[32, 1618]), (65151, "Medial", &[1600, 1618]),
+  (65152, "Isolated", &[1569]), (65153, "Isolated", &[1570]),
+  (65154, "Final", &[1570]), (65155, "Isolated", &[1571]),
+  (65156, "Final", &[1571]), (65157, "Isolated", &[1572]),
+  (65158, "Final", &[1572]), (65159, "Isolated", &[1573]),
+  (65160, "Final", &[1573]), (65161, "Isolated", &[1574]),
+  (65162, "Final", &[1574]), (65163, "Initial", &[1574]),
+  (65164, "Medial", &[1574]), (65165, "Isolated", &[1575]),
+  (65166, "Final", &[1575]), (65167, "Isolated", &[1576]),
+  (65168, "Final", &[1576]), (65169, "Initial", &[1576]),
+  (65170, "Medial", &[1576]), (65171, "Isolated", &[1577]),
+  (65172, "Final", &[1577]), (65173, "Isolated", &[1578]),
+  (65174, "Final", &[1578]), (65175, "Initial", &[1578]),
+  (65176, "Medial", &[1578]), (65177, "Isolated", &[1579]),
+  (65178, "Final", &[1579]), (65179, "Initial", &[1579]),
+  (65180, "Medial", &[1579]), (65181, "Isolated", &[1580]),
+  (65182, "Final", &[1580]), (65183, "Initial", &[1580]),
+  (65184, "Medial", &[1580]), (65185, "Isolated", &[1581]),
+  (65186, "Final", &[1581]), (65187, "Initial", &[1581]),
+  (65188, "Medial", &[1581]), (65189, "Isolated", &[1582]),
+  (65190, "Final", &[1582]), (65191, "Initial", &[1582]),
+  (65192, "Medial", &[1582]), (65193, "Isolated", &[1583]),
+  (65194, "Final", &[1583]), (65195, "Isolated", &[1584]),
+  (65196, "Final", &[1584]), (65197, "Isolated", &[1585]),
+  (65198, "Final", &[1585]), (65199, "Isolated", &[1586]),
+  (65200, "Final", &[1586]), (65201, "Isolated", &[1587]),
+  (65202, "Final", &[1587]), (65203, "Initial", &[1587]),
+  (65204, "Medial", &[1587]), (65205, "Isolated", &[1588]),
+  (65206, "Final", &[1588]), (65207, "Initial", &[1588]),
+  (65208, "Medial", &[1588]), (65209, "Isolated", &[1589]),
+  (65210, "Final", &[1589]), (65211, "Initial", &[1589]),
+  (65212, "Medial", &[1589]), (65213, "Isolated", &[1590]),
+  (65214, "Final", &[1590]), (65215, "Initial", &[1590]),
+  (65216, "Medial", &[1590]), (65217, "Isolated", &[1591]),
+  (65218, "Final", &[1591]), (65219, "Initial", &[1591]),
+  (65220, "Medial", &[1591]), (65221, "Isolated", &[1592]),
+  (65222, "Final", &[1592]), (65223, "Initial", &[1592]),
+  (65224, "Medial", &[1592]), (65225, "Isolated", &[1593]),
+  (65226, "Final", &[1593]), (65227, "Initial", &[1593]),
+  (65228, "Medial", &[1593]), (65229, "Isolated", &[1594]),
+  (65230, "Final", &[1594]), (65231, "Initial", &[1594]),
+  (65232, "Medial", &[1594]), (65233, "Isolated", &[1601]),
+  (65234, "Final", &[1601]), (65235, "Initial", &[1601]),
+  (65236, "Medial", &[1601]), (65237, "Isolated", &[1602]),
+  (65238, "Final", &[1602]), (65239, "Initial", &[1602]),
+  (65240, "Medial", &[1602]), (65241, "Isolated", &[1603]),
+  (65242, "Final", &[1603]), (65243, "Initial", &[1603]),
+  (65244, "Medial", &[1603]), (65245, "Isolated", &[1604]),
+  (65246, "Final", &[1604]), (65247, "Initial", &[1604]),
+  (65248, "Medial", &[1604]), (65249, "Isolated", &[1605]),
+  (65250, "Final", &[1605]), (65251, "Initial", &[1605]),
+  (65252, "Medial", &[1605]), (65253, "Isolated", &[1606]),
+  (65254, "Final", &[1606]), (65255, "Initial", &[1606]),
+  (65256, "Medial", &[1606]), (65257, "Isolated", &[1607]),
+  (65258, "Final", &[1607]), (65259, "Initial", &[1607]),
+  (65260, "Medial", &[1607]), (65261, "Isolated", &[1608]),
+  (65262, "Final", &[1608]), (65263, "Isolated", &[1609]),
+  (65264, "Final", &[1609]), (65265, "Isolated", &[1610]),
+  (65266, "Final", &[1610]), (65267, "Initial", &[1610]),
+  (65268, "Medial", &[1610]), (65269, "Isolated", &[1604, 1570]),
+  (65270, "Final", &[1604, 1570]), (65271, "Isolated", &[1604, 1571]),
+  (65272, "Final", &[1604, 1571]), (65273, "Isolated", &[1604, 1573]),
+  (65274, "Final", &[1604, 1573]), (65275, "Isolated", &[1604, 1575]),
+  (65276, "Final", &[1604, 1575]), (65281, "Wide", &[33]),
+  (65282, "Wide", &[34]), (65283, "Wide", &[35]), (65284, "Wide", &[36]),
+  (65285, "Wide", &[37]), (65286, "Wide", &[38]), (65287, "Wide", &[39]),
+  (65288, "Wide", &[40]), (65289, "Wide", &[41]), (65290, "Wide", &[42]),
+  (65291, "Wide", &[43]), (65292, "Wide", &[44]), (65293, "Wide", &[45]),
+  (65294, "Wide", &[46]), (65295, "Wide", &[47]), (65296, "Wide", &[48]),
+  (65297, "Wide", &[49]), (65298, "Wide", &[50]), (65299, "Wide", &[51]),
+  (65300, "Wide", &[52]), (65301, "Wide", &[53]), (65302, "Wide", &[54]),
+  (65303, "Wide", &[55]), (65304, "Wide", &[56]), (65305, "Wide", &[57]),
+  (65306, "Wide", &[58]), (65307, "Wide", &[59]), (65308, "Wide", &[60]),
+  (65309, "Wide", &[61]), (65310, "Wide", &[62]), (65311, "Wide", &[63]),
+  (65312, "Wide", &[64]), (65313, "Wide", &[65]), (65314, "Wide", &[66]),
+  (65315, "Wide", &[67]), (65316, "Wide", &[68]), (65317, "Wide", &[69]),
+  (65318, "Wide", &[70]), (65319, "Wide", &[71]), (65320, "Wide", &[72]),
+  (65321, "Wide", &[73]), (65322, "Wide", &[74]), (65323, "Wide", &[75]),
+  (65324, "Wide", &[76]), (65325, "Wide", &[77]), (65326, "Wide", &[78]),
+  (65327, "Wide", &[79]), (65328, "Wide", &[80]), (65329, "Wide", &[81]),
+  (65330, "Wide", &[82]), (65331, "Wide", &[83]), (65332, "Wide", &[84]),
+  (65333, "Wide", &[85]), (65334, "Wide", &[86]), (65335, "Wide", &[87]),
+  (65336, "Wide", &[88]), (65337, "Wide", &[89]), (65338, "Wide", &[90]),
+  (65339, "Wide", &[91]), (65340, "Wide", &[92]), (65341, "Wide", &[93]),
+  (65342, "Wide", &[94]), (65343, "Wide", &[95]), (65344, "Wide", &[96]),
+  (65345, "Wide", &[97]), (65346, "Wide", &[98]), (65347, "Wide", &[99]),
+  (65348, "Wide", &[100]), (65349, "Wide", &[101]), (65350, "Wide", &[102]),
+  (65351, "Wide", &[103]), (65352, "Wide", &[104]), (65353, "Wide", &[105]),
+  (65354, "Wide", &[106]), (65355, "Wide", &[107]), (65356, "Wide", &[108]),
+  (65357, "Wide", &[109]), (65358, "Wide", &[110]), (65359, "Wide", &[111]),
+  (65360, "Wide", &[112]), (65361, "Wide", &[113]), (65362, "Wide", &[114]),
+  (65363, "Wide", &[115]), (65364, "Wide", &[116]), (65365, "Wide", &[117]),
+  (65366, "Wide", &[118]), (65367, "Wide", &[119]), (65368, "Wide", &[120]),
+  (65369, "Wide", &[121]), (65370, "Wide", &[122]), (65371, "Wide", &[123]),
+  (65372, "Wide", &[124]), (65373, "Wide", &[125]), (65374, "Wide", &[126]),
+  (65375, "Wide", &[10629]), (65376, "Wide", &[10630]),
+  (65377, "Narrow", &[12290]), (65378, "Narrow", &[12300]),
+  (65379, "Narrow", &[12301]), (65380, "Narrow", &[12289]),
+  (65381, "Narrow", &[12539]), (65382, "Narrow", &[12530]),
+  (65383, "Narrow", &[12449]), (65384, "Narrow", &[12451]),
+  (65385, "Narrow", &[12453]), (65386, "Narrow", &[12455]),
+  (65387, "Narrow", &[12457]), (65388, "Narrow", &[12515]),
+  (65389, "Narrow", &[12517]), (65390, "Narrow", &[12519]),
+  (65391, "Narrow", &[12483]), (65392, "Narrow", &[12540]),
+  (65393, "Narrow", &[12450]), (65394, "Narrow", &[12452]),
+  (65395, "Narrow", &[12454]), (65396, "Narrow", &[12456]),
+  (65397, "Narrow", &[12458]), (65398, "Narrow", &[12459]),
+  (65399, "Narrow", &[12461]), (65400, "Narrow", &[12463]),
+  (65401, "Narrow", &[12465]), (65402, "Narrow", &[12467]),
+  (65403, "Narrow", &[12469]), (65404, "Narrow", &[12471]),
+  (65405, "Narrow", &[12473]), (65406, "Narrow", &[12475]),
+  (65407, "Narrow", &[12477]), (65408, "Narrow", &[12479]),
+  (65409, "Narrow", &[12481]), (65410, "Narrow", &[12484]),
+  (65411, "Narrow", &[12486]), (65412, "Narrow", &[12488]),
+  (65413, "Narrow", &[12490]), (65414, "Narrow", &[12491]),
+  (65415, "Narrow", &[12492]), (65416, "Narrow", &[12493]),
+  (65417, "Narrow", &[12494]), (65418, "Narrow", &[12495]),
+  (65419, "Narrow", &[12498]), (65420, "Narrow", &[12501]),
+  (65421, "Narrow", &[12504]), (65422, "Narrow", &[12507]),
+  (65423, "Narrow", &[12510]), (65424, "Narrow", &[12511]),
+  (65425, "Narrow", &[12512]), (65426, "Narrow", &[12513]),
+  (65427, "Narrow", &[12514]), (65428, "Narrow", &[12516]),
+  (65429, "Narrow", &[12518]), (65430, "Narrow", &[12520]),
+  (65431, "Narrow", &[12521]), (65432, "Narrow", &[12522]),
+  (65433, "Narrow", &[12523]), (65434, "Narrow", &[12524]),
+  (65435, "Narrow", &[12525]), (65436, "Narrow", &[12527]),
+  (65437, "Narrow", &[12531]), (65438, "Narrow", &[12441]),
+  (65439, "Narrow", &[12442]), (65440, "Narrow", &[12644]),
+  (65441, "Narrow", &[12593]), (65442, "Narrow", &[12594]),
+  (65443, "Narrow", &[12595]), (65444, "Narrow", &[12596]),
+  (65445, "Narrow", &[12597]), (65446, "Narrow", &[12598]),
+  (65447, "Narrow", &[12599]), (65448, "Narrow", &[12600]),
+  (65449, "Narrow", &[12601]), (65450, "Narrow", &[12602]),
+  (65451, "Narrow", &[12603]), (65452, "Narrow", &[12604]),
+  (65453, "Narrow", &[12605]), (65454, "Narrow", &[12606]),
+  (65455, "Narrow", &[12607]), (65456, "Narrow", &[12608]),
+  (65457, "Narrow", &[12609]), (65458, "Narrow", &[12610]),
+  (65459, "Narrow", &[12611]), (65460, "Narrow", &[12612]),
+  (65461, "Narrow", &[12613]), (65462, "Narrow", &[12614]),
+  (65463, "Narrow", &[12615]), (65464, "Narrow", &[12616]),
+  (65465, "Narrow", &[12617]), (65466, "Narrow", &[12618]),
+  (65467, "Narrow", &[12619]), (65468, "Narrow", &[12620]),
+  (65469, "Narrow", &[12621]), (65470, "Narrow", &[12622]),
+  (65474, "Narrow", &[12623]), (65475, "Narrow", &[12624]),
+  (65476, "Narrow", &[12625]), (65477, "Narrow", &[12626]),
+  (65478, "Narrow", &[12627]), (65479, "Narrow", &[12628]),
+  (65482, "Narrow", &[12629]), (65483, "Narrow", &[12630]),
+  (65484, "Narrow", &[12631]), (65485, "Narrow", &[12632]),
+  (65486, "Narrow", &[12633]), (65487, "Narrow", &[12634]),
+  (65490, "Narrow", &[12635]), (65491, "Narrow", &[12636]),
+  (65492, "Narrow", &[12637]), (65493, "Narrow", &[12638]),
+  (65494, "Narrow", &[12639]), (65495, "Narrow", &[12640]),
+  (65498, "Narrow", &[12641]), (65499, "Narrow", &[12642]),
+  (65500, "Narrow", &[12643]), (65504, "Wide", &[162]),
+  (65505, "Wide", &[163]), (65506, "Wide", &[172]), (65507, "Wide", &[175]),
+  (65508, "Wide", &[166]), (65509, "Wide", &[165]), (65510, "Wide", &[8361]),
+  (65512, "Narrow", &[9474]), (65513, "Narrow", &[8592]),
+  (65514, "Narrow", &[8593]), (65515, "Narrow", &[8594]),
+  (65516, "Narrow", &[8595]), (65517, "Narrow", &[9632]),
+  (65518, "Narrow", &[9675]), (69786, "Canonical", &[69785, 69818]),
+  (69788, "Canonical", &[69787, 69818]), (69803, "Canonical", &[69797, 69818]),
+  (69934, "Canonical", &[69937, 69927]), (69935, "Canonical", &[69938, 69927]),
+  (70475, "Canonical", &[70471, 70462]), (70476, "Canonical", &[70471, 70487]),
+  (70843, "Canonical", &[70841, 70842]), (70844, "Canonical", &[70841, 70832]),
+  (70846, "Canonical", &[70841, 70845]), (71098, "Canonical", &[71096, 71087]),
+  (71099, "Canonical", &[71097, 71087]),
+  (119134, "Canonical", &[119127, 119141]),
+  (119135, "Canonical", &[119128, 119141]),
+  (119136, "Canonical", &[119135, 119150]),
+  (119137, "Canonical", &[119135, 119151]),
+  (119138, "Canonical", &[119135, 119152]),
+  (119139, "Canonical", &[119135, 119153]),
+  (119140, "Canonical", &[119135, 119154]),
+  (119227, "Canonical", &[119225, 119141]),
+  (119228, "Canonical", &[119226, 119141]),
+  (119229, "Canonical", &[119227, 119150]),
+  (119230, "Canonical", &[119228, 119150]),
+  (119231, "Canonical", &[119227, 119151]),
+  (119232, "Canonical", &[119228, 119151]), (119808, "Font", &[65]),
+  (119809, "Font", &[66]), (119810, "Font", &[67]), (119811, "Font", &[68]),
+  (119812, "Font", &[69]), (119813, "Font", &[70]), (119814, "Font", &[71]),
+  (119815, "Font", &[72]), (119816, "Font", &[73]), (119817, "Font", &[74]),
+  (119818, "Font", &[75]), (119819, "Font", &[76]), (119820, "Font", &[77]),
+  (119821, "Font", &[78]), (119822, "Font", &[79]), (119823, "Font", &[80]),
+  (119824, "Font", &[81]), (119825, "Font", &[82]), (119826, "Font", &[83]),
+  (119827, "Font", &[84]), (119828, "Font", &[85]), (119829, "Font", &[86]),
+  (119830, "Font", &[87]), (119831, "Font", &[88]), (119832, "Font", &[89]),
+  (119833, "Font", &[90]), (119834, "Font", &[97]), (119835, "Font", &[98]),
+  (119836, "Font", &[99]), (119837, "Font", &[100]), (119838, "Font", &[101]),
+  (119839, "Font", &[102]), (119840, "Font", &[103]), (119841, "Font", &[104]),
+  (119842, "Font", &[105]), (119843, "Font", &[106]), (119844, "Font", &[107]),
+  (119845, "Font", &[108]), (119846, "Font", &[109]), (119847, "Font", &[110]),
+  (119848, "Font", &[111]), (119849, "Font", &[112]), (119850, "Font", &[113]),
+  (119851, "Font", &[114]), (119852, "Font", &[115]), (119853, "Font", &[116]),
+  (119854, "Font", &[117]), (119855, "Font", &[118]), (119856, "Font", &[119]),
+  (119857, "Font", &[120]), (119858, "Font", &[121]), (119859, "Font", &[122]),
+  (119860, "Font", &[65]), (119861, "Font", &[66]), (119862, "Font", &[67]),
+  (119863, "Font", &[68]), (119864, "Font", &[69]), (119865, "Font", &[70]),
+  (119866, "Font", &[71]), (119867, "Font", &[72]), (119868, "Font", &[73]),
+  (119869, "Font", &[74]), (119870, "Font", &[75]), (119871, "Font", &[76]),
+  (119872, "Font", &[77]), (119873, "Font", &[78]), (119874, "Font", &[79]),
+  (119875, "Font", &[80]), (119876, "Font", &[81]), (119877, "Font", &[82]),
+  (119878, "Font", &[83]), (119879, "Font", &[84]), (119880, "Font", &[85]),
+  (119881, "Font", &[86]), (119882, "Font", &[87]), (119883, "Font", &[88]),
+  (119884, "Font", &[89]), (119885, "Font", &[90]), (119886, "Font", &[97]),
+  (119887, "Font", &[98]), (119888, "Font", &[99]), (119889, "Font", &[100]),
+  (119890, "Font", &[101]), (119891, "Font", &[102]), (119892, "Font", &[103]),
+  (119894, "Font", &[105]), (119895, "Font", &[106]), (119896, "Font", &[107]),
+  (119897, "Font", &[108]), (119898, "Font", &[109]), (119899, "Font", &[110]),
+  (119900, "Font", &[111]), (119901, "Font", &[112]), (119902, "Font", &[113]),
+  (119903, "Font", &[114]), (119904, "Font", &[115]), (119905, "Font", &[116]),
+  (119906, "Font", &[117]), (119907, "Font", &[118]), (119908, "Font", &[119]),
+  (119909, "Font", &[120]), (119910, "Font", &[121]), (119911, "Font", &[122]),
+  (119912, "Font", &[65]), (119913, "Font", &[66]), (119914, "Font", &[67]),
+  (119915, "Font", &[68]), (119916, "Font", &[69]), (119917, "Font", &[70]),
+  (119918, "Font", &[71]), (119919, "Font", &[72]), (119920, "Font", &[73]),
+  (119921, "Font", &[74]), (119922, "Font", &[75]), (119923, "Font", &[76]),
+  (119924, "Font", &[77]), (119925, "Font", &[78]), (119926, "Font", &[79]),
+  (119927, "Font", &[80]), (119928, "Font", &[81]), (119929, "Font", &[82]),
+  (119930, "Font", &[83]), (119931, "Font", &[84]), (119932, "Font", &[85]),
+  (119933, "Font", &[86]), (119934, "Font", &[87]), (119935, "Font", &[88]),
+  (119936, "Font", &[89]), (119937, "Font", &[90]), (119938, "Font", &[97]),
+  (119939, "Font", &[98]), (119940, "Font", &[99]), (119941, "Font", &[100]),
+  (119942, "Font", &[101]), (119943, "Font", &[102]), (119944, "Font", &[103]),
+  (119945, "Font", &[104]), (119946, "Font", &[105]), (119947, "Font", &[106]),
+  (119948, "Font", &[107]), (119949, "Font", &[108]), (119950, "Font", &[109]),
+  (119951, "Font", &[110]), (119952, "Font", &[111]), (119953, "Font", &[112]),
+  (119954, "Font", &[113]), (119955, "Font", &[114]), (119956, "Font", &[115]),
+  (119957, "Font", &[116]), (119958, "Font", &[117]), (119959, "Font", &[118]),
+  (119960, "Font", &[119]), (119961, "Font", &[120]), (119962, "Font", &[121]),
+  (119963, "Font", &[122]), (119964, "Font", &[65]), (119966, "Font", &[67]),
+  (119967, "Font", &[68]), (119970, "Font", &[71]), (119973, "Font", &[74]),
+  (119974, "Font", &[75]), (119977, "Font", &[78]), (119978, "Font", &[79]),
+  (119979, "Font", &[80]), (119980, "Font", &[81]), (119982, "Font", &[83]),
+  (119983, "Font", &[84]), (119984, "Font", &[85]), (119985, "Font", &[86]),
+  (119986, "Font", &[87]), (119987, "Font", &[88]), (119988, "Font", &[89]),
+  (119989, "Font", &[90]), (119990, "Font", &[97]), (119991, "Font", &[98]),
+  (119992, "Font", &[99]), (119993, "Font", &[100]), (119995, "Font", &[102]),
+  (119997, "Font", &[104]), (119998, "Font", &[105]), (119999, "Font", &[106]),
+  (120000, "Font", &[107]), (120001, "Font", &[108]), (120002, "Font", &[109]),
+  (120003, "Font", &[110]), (120005, "Font", &[112]), (120006, "Font", &[113]),
+  (120007, "Font", &[114]), (120008, "Font", &[115]), (120009, "Font", &[116]),
+  (120010, "Font", &[117]), (120011, "Font", &[118]), (120012, "Font", &[119]),
+  (120013, "Font", &[120]), (120014, "Font", &[121]), (120015, "Font", &[122]),
+  (120016, "Font", &[65]), (120017, "Font", &[66]), (120018, "Font", &[67]),
+  (120019, "Font", &[68]), (120020, "Font", &[69]), (120021, "Font", &[70]),
+  (120022, "Font", &[71]), (120023, "Font", &[72]), (120024, "Font", &[73]),
+  (120025, "Font", &[74]), (120026, "Font", &[75]), (120027, "Font", &[76]),
+  (120028, "Font", &[77]), (120029, "Font", &[78]), (120030, "Font", &[79]),
+  (120031, "Font", &[80]), (120032, "Font", &[81]), (120033, "Font", &[82]),
+  (120034, "Font", &[83]), (120035, "Font", &[84]), (120036, "Font", &[85]),
+  (120037, "Font", &[86]), (120038, "Font", &[87]), (120039, "Font", &[88]),
+  (120040, "Font", &[89]), (120041, "Font", &[90]), (120042, "Font", &[97]),
+  (120043, "Font", &[98]), (120044, "Font", &[99]), (120045, "Font", &[100]),
+  (120046, "Font", &[101]), (120047, "Font", &[102]), (120048, "Font", &[103]),
+  (120049, "Font", &[104]), (120050, "Font", &[105]), (120051, "Font", &[106]),
+  (120052, "Font", &[107]), (120053, "Font", &[108]), (120054, "Font", &[109]),
+  (120055, "Font", &[110]), (120056, "Font", &[111]), (120057, "Font", &[112]),
+  (120058, "Font", &[113]), (120059, "Font", &[114]), (120060, "Font", &[115]),
+  (120061, "Font", &[116]), (120062, "Font", &[117]), (120063, "Font", &[118]),
+  (120064, "Font", &[119]), (120065, "Font", &[120]), (120066, "Font", &[121]),
+  (120067, "Font", &[122]), (120068, "Font", &[65]), (120069, "Font", &[66]),
+  (120071, "Font", &[68]), (120072, "Font", &[69]), (120073, "Font", &[70]),
+  (120074, "Font", &[71]), (120077, "Font", &[74]), (120078, "Font", &[75]),
+  (120079, "Font", &[76]), (120080, "Font", &[77]), (120081, "Font", &[78]),
+  (120082, "Font", &[79]), (120083, "Font", &[80]), (120084, "Font", &[81]),
+  (120086, "Font", &[83]), (120087, "Font", &[84]), (120088, "Font", &[85]),
+  (120089, "Font", &[86]), (120090, "Font", &[87]), (120091, "Font", &[88]),
+  (120092, "Font", &[89]), (120094, "Font", &[97]), (120095, "Font", &[98]),
+  (120096, "Font", &[99]), (120097, "Font", &[100]), (120098, "Font", &[101]),
+  (120099, "Font", &[102]), (120100, "Font", &[103]), (120101, "Font", &[104]),
+  (120102, "Font", &[105]), (120103, "Font", &[106]), (120104, "Font", &[107]),
+  (120105, "Font", &[108]), (120106, "Font", &[109]), (120107, "Font", &[110]),
+  (120108, "Font", &[111]), (120109, "Font", &[112]), (120110, "Font", &[113]),
+  (120111, "Font", &[114]), (120112, "Font", &[115]), (120113, "Font", &[116]),
+  (120114, "Font", &[117]), (120115, "Font", &[118]), (120116, "Font", &[119]),
+  (120117, "Font", &[120]), (120118, "Font", &[121]), (120119, "Font", &[122]),
+  (120120, "Font", &[65]), (120121, "Font", &[66]), (120123, "Font", &[68]),
+  (120124, "Font", &[69]), (120125, "Font", &[70]), (120126, "Font", &[71]),
+  (120128, "Font", &[73]), (120129, "Font", &[74]), (120130, "Font", &[75]),
+  (120131, "Font", &[76]), (120132, "Font", &[77]), (120134, "Font", &[79]),
+  (120138, "Font", &[83]), (120139, "Font", &[84]), (120140, "Font", &[85]),
+  (120141, "Font", &[86]), (120142, "Font", &[87]), (120143, "Font", &[88]),
+  (120144, "Font", &[89]), (120146, "Font", &[97]), (120147, "Font", &[98]),
+  (120148, "Font", &[99]), (120149, "Font", &[100]), (120150, "Font", &[101]),
+  (120151, "Font", &[102]), (120152, "Font", &[103]), (120153, "Font", &[104]),
+  (120154, "Font", &[105]), (120155, "Font", &[106]), (120156, "Font", &[107]),
+  (120157, "Font", &[108]), (120158, "Font", &[109]), (120159, "Font", &[110]),
+  (120160, "Font", &[111]), (120161, "Font", &[112]), (120162, "Font", &[113]),
+  (120163, "Font", &[114]), (120164, "Font", &[115]), (120165, "Font", &[116]),
+  (120166, "Font", &[117]), (120167, "Font", &[118]), (120168, "Font", &[119]),
+  (120169, "Font", &[120]), (120170, "Font", &[121]), (120171, "Font", &[122]),
+  (120172, "Font", &[65]), (120173, "Font", &[66]), (120174, "Font", &[67]),
+  (120175, "Font", &[68]), (120176, "Font", &[69]), (120177, "Font", &[70]),
+  (120178, "Font", &[71]), (120179, "Font", &[72]), (120180, "Font", &[73]),
+  (120181, "Font", &[74]), (120182, "Font", &[75]), (120183, "Font", &[76]),
+  (120184, "Font", &[77]), (120185, "Font", &[78]), (120186, "Font", &[79]),
+  (120187, "Font", &[80]), (120188, "Font", &[81]), (120189, "Font", &[82]),
+  (120190, "Font", &[83]), (120191, "Font", &[84]), (120192, "Font", &[85]),
+  (120193, "Font", &[86]), (120194, "Font", &[87]), (120195, "Font", &[88]),
+  (120196, "Font", &[89]), (120197, "Font", &[90]), (120198, "Font", &[97]),
+  (120199, "Font", &[98]), (120200, "Font", &[99]), (120201, "Font", &[100]),
+  (120202, "Font", &[101]), (120203, "Font", &[102]), (120204, "Font", &[103]),
+  (120205, "Font", &[104]), (120206, "Font", &[105]), (120207, "Font", &[106]),
+  (120208, "Font", &[107]), (120209, "Font", &[108]), (120210, "Font", &[109]),
+  (120211, "Font", &[110]), (120212, "Font", &[111]), (120213, "Font", &[112]),
+  (120214, "Font", &[113]), (120215, "Font", &[114]), (120216, "Font", &[115]),
+  (120217, "Font", &[116]), (120218, "Font", &[117]), (120219, "Font", &[118]),
+  (120220, "Font", &[119]), (120221, "Font", &[120]), (120222, "Font", &[121]),
+  (120223, "Font", &[122]), (120224, "Font", &[65]), (120225, "Font", &[66]),
+  (120226, "Font", &[67]), (120227, "Font", &[68]), (120228, "Font", &[69]),
+  (120229, "Font", &[70]), (120230, "Font", &[71]), (120231, "Font", &[72]),
+  (120232, "Font", &[73]), (120233, "Font", &[74]), (120234, "Font", &[75]),
+  (120235, "Font", &[76]), (120236, "Font", &[77]), (120237, "Font", &[78]),
+  (120238, "Font", &[79]), (120239, "Font", &[80]), (120240, "Font", &[81]),
+  (120241, "Font", &[82]), (120242, "Font", &[83]), (120243, "Font", &[84]),
+  (120244, "Font", &[85]), (120245, "Font", &[86]), (120246, "Font", &[87]),
+  (120247, "Font", &[88]), (120248, "Font", &[89]), (120249, "Font", &[90]),
+  (120250, "Font", &[97]), (120251, "Font", &[98]), (120252, "Font", &[99]),
+  (120253, "Font", &[100]), (120254, "Font", &[101]), (120255, "Font", &[102]),
+  (120256, "Font", &[103]), (120257, "Font", &[104]), (120258, "Font", &[105]),
+  (120259, "Font", &[106]), (120260, "Font", &[107]), (120261, "Font", &[108]),
+  (120262, "Font", &[109]), (120263, "Font", &[110]), (120264, "Font", &[111]),
+  (120265, "Font", &[112]), (120266, "Font", &[113]), (120267, "Font", &[114]),
+  (120268, "Font", &[115]), (120269, "Font", &[116]), (120270, "Font", &[117]),
+  (120271, "Font", &[118]), (120272, "Font", &[119]), (120273, "Font", &[120]),
+  (120274, "Font", &[121]), (120275, "Font", &[122]), (120276, "Font", &[65]),
+  (120277, "Font", &[66]), (120278, "Font", &[67]), (120279, "Font", &[68]),
+  (120280, "Font", &[69]), (120281, "Font", &[70]), (120282, "Font", &[71]),
+  (120283, "Font", &[72]), (120284, "Font", &[73]), (120285, "Font", &[74]),
+  (120286, "Font", &[75]), (120287, "Font", &[76]), (120288, "Font", &[77]),
+  (120289, "Font", &[78]), (120290, "Font", &[79]), (120291, "Font", &[80]),
+  (120292, "Font", &[81]), (120293, "Font", &[82]), (120294, "Font", &[83]),
+  (120295, "Font", &[84]), (120296, "Font", &[85]), (120297, "Font", &[86]),
+  (120298, "Font", &[87]), (120299, "Font", &[88]), (120300, "Font", &[89]),
+  (120301, "Font", &[90]), (120302, "Font", &[97]), (120303, "Font", &[98]),
+  (120304, "Font", &[99]), (120305, "Font", &[100]), (120306, "Font", &[101]),
+  (120307, "Font", &[102]), (120308, "Font", &[103]), (120309, "Font", &[104]),
+  (120310, "Font", &[105]), (120311, "Font", &[106]), (120312, "Font", &[107]),
+  (120313, "Font", &[108]), (120314, "Font", &[109]), (120315, "Font", &[110]),
+  (120316, "Font", &[111]), (120317, "Font", &[112]), (120318, "Font", &[113]),
+  (120319, "Font", &[114]), (120320, "Font", &[115]), (120321, "Font", &[116]),
+  (120322, "Font", &[117]), (120323, "Font", &[118]), (120324, "Font", &[119]),
+  (120325, "Font", &[120]), (120326, "Font", &[121]), (120327, "Font", &[122]),
+  (120328, "Font", &[65]), (120329, "Font", &[66]), (120330, "Font", &[67]),
+  (120331, "Font", &[68]), (120332, "Font", &[69]), (120333, "Font", &[70]),
+  (120334, "Font", &[71]), (120335, "Font", &[72]), (120336, "Font", &[73]),
+  (120337, "Font", &[74]), (120338, "Font", &[75]), (120339, "Font", &[76]),
+  (120340, "Font", &[77]), (120341, "Font", &[78]), (120342, "Font", &[79]),
+  (120343, "Font", &[80]), (120344, "Font", &[81]), (120345, "Font", &[82]),
+  (120346, "Font", &[83]), (120347, "Font", &[84]), (120348, "Font", &[85]),
+  (120349, "Font", &[86]), (120350, "Font", &[87]), (120351, "Font", &[88]),
+  (120352, "Font", &[89]), (120353, "Font", &[90]), (120354, "Font", &[97]),
+  (120355, "Font", &[98]), (120356, "Font", &[99]), (120357, "Font", &[100]),
+  (120358, "Font", &[101]), (120359, "Font", &[102]), (120360, "Font", &[103]),
+  (120361, "Font", &[104]), (120362, "Font", &[105]), (120363, "Font", &[106]),
+  (120364, "Font", &[107]), (120365, "Font", &[108]), (120366, "Font", &[109]),
+  (120367, "Font", &[110]), (120368, "Font", &[111]), (120369, "Font", &[112]),
+  (120370, "Font", &[113]), (120371, "Font", &[114]), (120372, "Font", &[115]),
+  (120373, "Font", &[116]), (120374, "Font", &[117]), (120375, "Font", &[118]),
+  (120376, "Font", &[119]), (120377, "Font", &[120]), (120378, "Font", &[121]),
+  (120379, "Font", &[122]), (120380, "Font", &[65]), (120381, "Font", &[66]),
+  (120382, "Font", &[67]), (120383, "Font", &[68]), (120384, "Font", &[69]),
+  (120385, "Font", &[70]), (120386, "Font", &[71]), (120387, "Font", &[72]),
+  (120388, "Font", &[73]), (120389, "Font", &[74]), (120390, "Font", &[75]),
+  (120391, "Font", &[76]), (120392, "Font", &[77]), (120393, "Font", &[78]),
+  (120394, "Font", &[79]), (120395, "Font", &[80]), (120396, "Font", &[81]),
+  (120397, "Font", &[82]), (120398, "Font", &[83]), (120399, "Font", &[84]),
+  (120400, "Font", &[85]), (120401, "Font", &[86]), (120402, "Font", &[87]),
+  (120403, "Font", &[88]), (120404, "Font", &[89]), (120405, "Font", &[90]),
+  (120406, "Font", &[97]), (120407, "Font", &[98]), (120408, "Font", &[99]),
+  (120409, "Font", &[100]), (120410, "Font", &[101]), (120411, "Font", &[102]),
+  (120412, "Font", &[103]), (120413, "Font", &[104]), (120414, "Font", &[105]),
+  (120415, "Font", &[106]), (120416, "Font", &[107]), (120417, "Font", &[108]),
+  (120418, "Font", &[109]), (120419, "Font", &[110]), (120420, "Font", &[111]),
+  (120421, "Font", &[112]), (120422, "Font", &[113]), (120423, "Font", &[114]),
+  (120424, "Font", &[115]), (120425, "Font", &[116]), (120426, "Font", &[117]),
+  (120427, "Font", &[118]), (120428, "Font", &[119]), (120429, "Font", &[120]),
+  (120430, "Font", &[121]), (120431, "Font", &[122]), (120432, "Font", &[65]),
+  (120433, "Font", &[66]), (120434, "Font", &[67]), (120435, "Font", &[68]),
+  (120436, "Font", &[69]), (120437, "Font", &[70]), (120438, "Font", &[71]),
+  (120439, "Font", &[72]), (120440, "Font", &[73]), (120441, "Font", &[74]),
+  (120442, "Font", &[75]), (120443, "Font", &[76]), (120444, "Font", &[77]),
+  (120445, "Font", &[78]), (120446, "Font", &[79]), (120447, "Font", &[80]),
+  (120448, "Font", &[81]), (120449, "Font", &[82]), (120450, "Font", &[83]),
+  (120451, "Font", &[84]), (120452, "Font", &[85]), (120453, "Font", &[86]),
+  (120454, "Font", &[87]), (120455, "Font", &[88]), (120456, "Font", &[89]),
+  (120457, "Font", &[90]), (120458, "Font", &[97]), (120459, "Font", &[98]),
+  (120460, "Font", &[99]), (120461, "Font", &[100]), (120462, "Font", &[101]),
+  (120463, "Font", &[102]), (120464, "Font", &[103]), (120465, "Font", &[104]),
+  (120466, "Font", &[105]), (120467, "Font", &[106]), (120468, "Font", &[107]),
+  (120469, "Font", &[108]), (120470, "Font", &[109]), (120471, "Font", &[110]),
+  (120472, "Font", &[111]), (120473, "Font", &[112]), (120474, "Font", &[113]),
+  (120475, "Font", &[114]), (120476, "Font", &[115]), (120477, "Font", &[116]),
+  (120478, "Font", &[117]), (120479, "Font", &[118]), (120480, "Font", &[119]),
+  (120481, "Font", &[120]), (120482, "Font", &[121]), (120483, "Font", &[122]),
+  (120484, "Font", &[305]), (120485, "Font", &[567]), (120488, "Font", &[913]),
+  (120489, "Font", &[914]), (120490, "Font", &[915]), (120491, "Font", &[916]),
+  (120492, "Font", &[917]), (120493, "Font", &[918]), (120494, "Font", &[919]),
+  (120495, "Font", &[920]), (120496, "Font", &[921]), (120497, "Font", &[922]),
+  (120498, "Font", &[923]), (120499, "Font", &[924]), (120500, "Font", &[925]),
+  (120501, "Font", &[926]), (120502, "Font", &[927]), (120503, "Font", &[928]),
+  (120504, "Font", &[929]), (120505, "Font", &[1012]),
+  (120506, "Font", &[931]), (120507, "Font", &[932]), (120508, "Font", &[933]),
+  (120509, "Font", &[934]), (120510, "Font", &[935]), (120511, "Font", &[936]),
+  (120512, "Font", &[937]), (120513, "Font", &[8711]),
+  (120514, "Font", &[945]), (120515, "Font", &[946]), (120516, "Font", &[947]),
+  (120517, "Font", &[948]), (120518, "Font", &[949]), (120519, "Font", &[950]),
+  (120520, "Font", &[951]), (120521, "Font", &[952]), (120522, "Font", &[953]),
+  (120523, "Font", &[954]), (120524, "Font", &[955]), (120525, "Font", &[956]),
+  (120526, "Font", &[957]), (120527, "Font", &[958]), (120528, "Font", &[959]),
+  (120529, "Font", &[960]), (120530, "Font", &[961]), (120531, "Font", &[962]),
+  (120532, "Font", &[963]), (120533, "Font", &[964]), (120534, "Font", &[965]),
+  (120535, "Font", &[966]), (120536, "Font", &[967]), (120537, "Font", &[968]),
+  (120538, "Font", &[969]), (120539, "Font", &[8706]),
+  (120540, "Font", &[1013]), (120541, "Font", &[977]),
+  (120542, "Font", &[1008]), (120543, "Font", &[981]),
+  (120544, "Font", &[1009]), (120545, "Font", &[982]),
+  (120546, "Font", &[913]), (120547, "Font", &[914]), (120548, "Font", &[915]),
+  (120549, "Font", &[916]), (120550, "Font", &[917]), (120551, "Font", &[918]),
+  (120552, "Font", &[919]), (120553, "Font", &[920]), (120554, "Font", &[921]),
+  (120555, "Font", &[922]), (120556, "Font", &[923]), (120557, "Font", &[924]),
+  (120558, "Font", &[925]), (120559, "Font", &[926]), (120560, "Font", &[927]),
+  (120561, "Font", &[928]), (120562, "Font", &[929]),
+  (120563, "Font", &[1012]), (120564, "Font", &[931]),
+  (120565, "Font", &[932]), (120566, "Font", &[933]), (120567, "Font", &[934]),
+  (120568, "Font", &[935]), (120569, "Font", &[936]), (120570, "Font", &[937]),
+  (120571, "Font", &[8711]), (120572, "Font", &[945]),
+  (120573, "Font", &[946]), (120574, "Font", &[947]), (120575, "Font", &[948]),
+  (120576, "Font", &[949]), (120577, "Font", &[950]), (120578, "Font", &[951]),
+  (120579, "Font", &[952]), (120580, "Font", &[953]), (120581, "Font", &[954]),
+  (120582, "Font", &[955]), (120583, "Font", &[956]), (120584, "Font", &[957]),
+  (120585, "Font", &[958]), (120586, "Font", &[959]), (120587, "Font", &[960]),
+  (120588, "Font", &[961]), (120589, "Font", &[962]), (120590, "Font", &[963]),
+  (120591, "Font", &[964]), (120592, "Font", &[965]), (120593, "Font", &[966]),
+  (120594, "Font", &[967]), (120595, "Font", &[968]), (120596, "Font", &[969]),
+  (120597, "Font", &[8706]), (120598, "Font", &[1013]),
+  (120599, "Font", &[977]), (120600, "Font", &[1008]),
+  (120601, "Font", &[981]), (120602, "Font", &[1009]),
+  (120603, "Font", &[982]), (120604, "Font", &[913]), (120605, "Font", &[914]),
+  (120606, "Font", &[915]), (120607, "Font", &[916]), (120608, "Font", &[917]),
+  (120609, "Font", &[918]), (120610, "Font", &[919]), (120611, "Font", &[920]),
+  (120612, "Font", &[921]), (120613, "Font", &[922]), (120614, "Font", &[923]),
+  (120615, "Font", &[924]), (120616, "Font", &[925]), (120617, "Font", &[926]),
+  (120618, "Font", &[927]), (120619, "Font", &[928]), (120620, "Font", &[929]),
+  (120621, "Font", &[1012]), (120622, "Font", &[931]),
+  (120623, "Font", &[932]), (120624, "Font", &[933]), (120625, "Font", &[934]),
+  (120626, "Font", &[935]), (120627, "Font", &[936]), (120628, "Font", &[937]),
+  (120629, "Font", &[8711]), (120630, "Font", &[945]),
+  (120631, "Font", &[946]), (120632, "Font", &[947]), (120633, "Font", &[948]),
+  (120634, "Font", &[949]), (120635, "Font", &[950]), (120636, "Font", &[951]),
+  (120637, "Font", &[952]), (120638, "Font", &[953]), (120639, "Font", &[954]),
+  (120640, "Font", &[955]), (120641, "Font", &[956]), (120642, "Font", &[957]),
+  (120643, "Font", &[958]), (120644, "Font", &[959]), (120645, "Font", &[960]),
+  (120646, "Font", &[961]), (120647, "Font", &[962]), (120648, "Font", &[963]),
+  (120649, "Font", &[964]), (120650, "Font", &[965]), (120651, "Font", &[966]),
+  (120652, "Font", &[967]), (120653, "Font", &[968]), (120654, "Font", &[969]),
+  (120655, "Font", &[8706]), (120656, "Font", &[1013]),
+  (120657, "Font", &[977]), (120658, "Font", &[1008]),
+  (120659, "Font", &[981]), (120660, "Font", &[1009]),
+  (120661, "Font", &[982]), (120662, "Font", &[913]), (120663, "Font", &[914]),
+  (120664, "Font", &[915]), (120665, "Font", &[916]), (120666, "Font", &[917]),
+  (120667, "Font", &[918]), (120668, "Font", &[919]), (120669, "Font", &[920]),
+  (120670, "Font", &[921]), (120671, "Font", &[922]), (120672, "Font", &[923]),
+  (120673, "Font", &[924]), (120674, "Font", &[925]), (120675, "Font", &[926]),
+  (120676, "Font", &[927]), (120677, "Font", &[928]), (120678, "Font", &[929]),
+  (120679, "Font", &[1012]), (120680, "Font", &[931]),
+  (120681, "Font", &[932]), (120682, "Font", &[933]), (120683, "Font", &[934]),
+  (120684, "Font", &[935]), (120685, "Font", &[936]), (120686, "Font", &[937]),
+  (120687, "Font", &[8711]), (120688, "Font", &[945]),
+  (120689, "Font", &[946]), (120690, "Font", &[947]), (120691, "Font", &[948]),
+  (120692, "Font", &[949]), (120693, "Font", &[950]), (120694, "Font", &[951]),
+  (120695, "Font", &[952]), (120696, "Font", &[953]), (120697, "Font", &[954]),
+  (120698, "Font", &[955]), (120699, "Font", &[956]), (120700, "Font", &[957]),
+  (120701, "Font", &[958]), (120702, "Font", &[959]), (120703, "Font", &[960]),
+  (120704, "Font", &[961]), (120705, "Font", &[962]), (120706, "Font", &[963]),
+  (120707, "Font", &[964]), (120708, "Font", &[965]), (120709, "Font", &[966]),
+  (120710, "Font", &[967]), (120711, "Font", &[968]), (120712, "Font", &[969]),
+  (120713, "Font", &[8706]), (120714, "Font", &[1013]),
+  (120715, "Font", &[977]), (120716, "Font", &[1008]),
+  (120717, "Font", &[981]), (120718, "Font", &[1009]),
+  (120719, "Font", &[982]), (120720, "Font", &[913]), (120721, "Font", &[914]),
+  (120722, "Font", &[915]), (120723, "Font", &[916]), (120724, "Font", &[917]),
+  (120725, "Font", &[918]), (120726, "Font", &[919]), (120727, "Font", &[920]),
+  (120728, "Font", &[921]), (120729, "Font", &[922]), (120730, "Font", &[923]),
+  (120731, "Font", &[924]), (120732, "Font", &[925]), (120733, "Font", &[926]),
+  (120734, "Font", &[927]), (120735, "Font", &[928]), (120736, "Font", &[929]),
+  (120737, "Font", &[1012]), (120738, "Font", &[931]),
+  (120739, "Font", &[932]), (120740, "Font", &[933]), (120741, "Font", &[934]),
+  (120742, "Font", &[935]), (120743, "Font", &[936]), (120744, "Font", &[937]),
+  (120745, "Font", &[8711]), (120746, "Font", &[945]),
+  (120747, "Font", &[946]), (120748, "Font", &[947]), (120749, "Font", &[948]),
+  (120750, "Font", &[949]), (120751, "Font", &[950]), (120752, "Font", &[951]),
+  (120753, "Font", &[952]), (120754, "Font", &[953]), (120755, "Font", &[954]),
+  (120756, "Font", &[955]), (120757, "Font", &[956]), (120758, "Font", &[957]),
+  (120759, "Font", &[958]), (120760, "Font", &[959]), (120761, "Font", &[960]),
+  (120762, "Font", &[961]), (120763, "Font", &[962]), (120764, "Font", &[963]),
+  (120765, "Font", &[964]), (120766, "Font", &[965]), (120767, "Font", &[966]),
+  (120768, "Font", &[967]), (120769, "Font", &[968]), (120770, "Font", &[969]),
+  (120771, "Font", &[8706]), (120772, "Font", &[1013]),
+  (120773, "Font", &[977]), (120774, "Font", &[1008]),
+  (120775, "Font", &[981]), (120776, "Font", &[1009]),
+  (120777, "Font", &[982]), (120778, "Font", &[988]), (120779, "Font", &[989]),
+  (120782, "Font", &[48]), (120783, "Font", &[49]), (120784, "Font", &[50]),
+  (120785, "Font", &[51]), (120786, "Font", &[52]), (120787, "Font", &[53]),
+  (120788, "Font", &[54]), (120789, "Font", &[55]), (120790, "Font", &[56]),
+  (120791, "Font", &[57]), (120792, "Font", &[48]), (120793, "Font", &[49]),
+  (120794, "Font", &[50]), (120795, "Font", &[51]), (120796, "Font", &[52]),
+  (120797, "Font", &[53]), (120798, "Font", &[54]), (120799, "Font", &[55]),
+  (120800, "Font", &[56]), (120801, "Font", &[57]), (120802, "Font", &[48]),
+  (120803, "Font", &[49]), (120804, "Font", &[50]), (120805, "Font", &[51]),
+  (120806, "Font", &[52]), (120807, "Font", &[53]), (120808, "Font", &[54]),
+  (120809, "Font", &[55]), (120810, "Font", &[56]), (120811, "Font", &[57]),
+  (120812, "Font", &[48]), (120813, "Font", &[49]), (120814, "Font", &[50]),
+  (120815, "Font", &[51]), (120816, "Font", &[52]), (120817, "Font", &[53]),
+  (120818, "Font", &[54]), (120819, "Font", &[55]), (120820, "Font", &[56]),
+  (120821, "Font", &[57]), (120822, "Font", &[48]), (120823, "Font", &[49]),
+  (120824, "Font", &[50]), (120825, "Font", &[51]), (120826, "Font", &[52]),
+  (120827, "Font", &[53]), (120828, "Font", &[54]), (120829, "Font", &[55]),
+  (120830, "Font", &[56]), (120831, "Font", &[57]), (126464, "Font", &[1575]),
+  (126465, "Font", &[1576]), (126466, "Font", &[1580]),
+  (126467, "Font", &[1583]), (126469, "Font", &[1608]),
+  (126470, "Font", &[1586]), (126471, "Font", &[1581]),
+  (126472, "Font", &[1591]), (126473, "Font", &[1610]),
+  (126474, "Font", &[1603]), (126475, "Font", &[1604]),
+  (126476, "Font", &[1605]), (126477, "Font", &[1606]),
+  (126478, "Font", &[1587]), (126479, "Font", &[1593]),
+  (126480, "Font", &[1601]), (126481, "Font", &[1589]),
+  (126482, "Font", &[1602]), (126483, "Font", &[1585]),
+  (126484, "Font", &[1588]), (126485, "Font", &[1578]),
+  (126486, "Font", &[1579]), (126487, "Font", &[1582]),
+  (126488, "Font", &[1584]), (126489, "Font", &[1590]),
+  (126490, "Font", &[1592]), (126491, "Font", &[1594]),
+  (126492, "Font", &[1646]), (126493, "Font", &[1722]),
+  (126494, "Font", &[1697]), (126495, "Font", &[1647]),
+  (126497, "Font", &[1576]), (126498, "Font", &[1580]),
+  (126500, "Font", &[1607]), (126503, "Font", &[1581]),
+  (126505, "Font", &[1610]), (126506, "Font", &[1603]),
+  (126507, "Font", &[1604]), (126508, "Font", &[1605]),
+  (126509, "Font", &[1606]), (126510, "Font", &[1587]),
+  (126511, "Font", &[1593]), (126512, "Font", &[1601]),
+  (126513, "Font", &[1589]), (126514, "Font", &[1602]),
+  (126516, "Font", &[1588]), (126517, "Font", &[1578]),
+  (126518, "Font", &[1579]), (126519, "Font", &[1582]),
+  (126521, "Font", &[1590]), (126523, "Font", &[1594]),
+  (126530, "Font", &[1580]), (126535, "Font", &[1581]),
+  (126537, "Font", &[1610]), (126539, "Font", &[1604]),
+  (126541, "Font", &[1606]), (126542, "Font", &[1587]),
+  (126543, "Font", &[1593]), (126545, "Font", &[1589]),
+  (126546, "Font", &[1602]), (126548, "Font", &[1588]),
+  (126551, "Font", &[1582]), (126553, "Font", &[1590]),
+  (126555, "Font", &[1594]), (126557, "Font", &[1722]),
+  (126559, "Font", &[1647]), (126561, "Font", &[1576]),
+  (126562, "Font", &[1580]), (126564, "Font", &[1607]),
+  (126567, "Font", &[1581]), (126568, "Font", &[1591]),
+  (126569, "Font", &[1610]), (126570, "Font", &[1603]),
+  (126572, "Font", &[1605]), (126573, "Font", &[1606]),
+  (126574, "Font", &[1587]), (126575, "Font", &[1593]),
+  (126576, "Font", &[1601]), (126577, "Font", &[1589]),
+  (126578, "Font", &[1602]), (126580, "Font", &[1588]),
+  (126581, "Font", &[1578]), (126582, "Font", &[1579]),
+  (126583, "Font", &[1582]), (126585, "Font", &[1590]),
+  (126586, "Font", &[1592]), (126587, "Font", &[1594]),
+  (126588, "Font", &[1646]), (126590, "Font", &[1697]),
+  (126592, "Font", &[1575]), (126593, "Font", &[1576]),
+  (126594, "Font", &[1580]), (126595, "Font", &[1583]),
+  (126596, "Font", &[1607]), (126597, "Font", &[1608]),
+  (126598, "Font", &[1586]), (126599, "Font", &[1581]),
+  (126600, "Font", &[1591]), (126601, "Font", &[1610]),
+  (126603, "Font", &[1604]), (126604, "Font", &[1605]),
+  (126605, "Font", &[1606]), (126606, "Font", &[1587]),
+  (126607, "Font", &[1593]), (126608, "Font", &[1601]),
+  (126609, "Font", &[1589]), (126610, "Font", &[1602]),
+  (126611, "Font", &[1585]), (126612, "Font", &[1588]),
+  (126613, "Font", &[1578]), (126614, "Font", &[1579]),
+  (126615, "Font", &[1582]), (126616, "Font", &[1584]),
+  (126617, "Font", &[1590]), (126618, "Font", &[1592]),
+  (126619, "Font", &[1594]), (126625, "Font", &[1576]),
+  (126626, "Font", &[1580]), (126627, "Font", &[1583]),
+  (126629, "Font", &[1608]), (126630, "Font", &[1586]),
+  (126631, "Font", &[1581]), (126632, "Font", &[1591]),
+  (126633, "Font", &[1610]), (126635, "Font", &[1604]),
+  (126636, "Font", &[1605]), (126637, "Font", &[1606]),
+  (126638, "Font", &[1587]), (126639, "Font", &[1593]),
+  (126640, "Font", &[1601]), (126641, "Font", &[1589]),
+  (126642, "Font", &[1602]), (126643, "Font", &[1585]),
+  (126644, "Font", &[1588]), (126645, "Font", &[1578]),
+  (126646, "Font", &[1579]), (126647, "Font", &[1582]),
+  (126648, "Font", &[1584]), (126649, "Font", &[1590]),
+  (126650, "Font", &[1592]), (126651, "Font", &[1594]),
+  (127232, "Compat", &[48, 46]), (127233, "Compat", &[48, 44]),
+  (127234, "Compat", &[49, 44]), (127235, "Compat", &[50, 44]),
+  (127236, "Compat", &[51, 44]), (127237, "Compat", &[52, 44]),
+  (127238, "Compat", &[53, 44]), (127239, "Compat", &[54, 44]),
+  (127240, "Compat", &[55, 44]), (127241, "Compat", &[56, 44]),
+  (127242, "Compat", &[57, 44]), (127248, "Compat", &[40, 65, 41]),
+  (127249, "Compat", &[40, 66, 41]), (127250, "Compat", &[40, 67, 41]),
+  (127251, "Compat", &[40, 68, 41]), (127252, "Compat", &[40, 69, 41]),
+  (127253, "Compat", &[40, 70, 41]), (127254, "Compat", &[40, 71, 41]),
+  (127255, "Compat", &[40, 72, 41]), (127256, "Compat", &[40, 73, 41]),
+  (127257, "Compat", &[40, 74, 41]), (127258, "Compat", &[40, 75, 41]),
+  (127259, "Compat", &[40, 76, 41]), (127260, "Compat", &[40, 77, 41]),
+  (127261, "Compat", &[40, 78, 41]), (127262, "Compat", &[40, 79, 41]),
+  (127263, "Compat", &[40, 80, 41]), (127264, "Compat", &[40, 81, 41]),
+  (127265, "Compat", &[40, 82, 41]), (127266, "Compat", &[40, 83, 41]),
+  (127267, "Compat", &[40, 84, 41]), (127268, "Compat", &[40, 85, 41]),
+  (127269, "Compat", &[40, 86, 41]), (127270, "Compat", &[40, 87, 41]),
+  (127271, "Compat", &[40, 88, 41]), (127272, "Compat", &[40, 89, 41]),
+  (127273, "Compat", &[40, 90, 41]), (127274, "Compat", &[12308, 83, 12309]),
+  (127275, "Circle", &[67]), (127276, "Circle", &[82]),
+  (127277, "Circle", &[67, 68]), (127278, "Circle", &[87, 90]),
+  (127280, "Square", &[65]), (127281, "Square", &[66]),
+  (127282, "Square", &[67]), (127283, "Square", &[68]),
+  (127284, "Square", &[69]), (127285, "Square", &[70]),
+  (127286, "Square", &[71]), (127287, "Square", &[72]),
+  (127288, "Square", &[73]), (127289, "Square", &[74]),
+  (127290, "Square", &[75]), (127291, "Square", &[76]),
+  (127292, "Square", &[77]), (127293, "Square", &[78]),
+  (127294, "Square", &[79]), (127295, "Square", &[80]),
+  (127296, "Square", &[81]), (127297, "Square", &[82]),
+  (127298, "Square", &[83]), (127299, "Square", &[84]),
+  (127300, "Square", &[85]), (127301, "Square", &[86]),
+  (127302, "Square", &[87]), (127303, "Square", &[88]),
+  (127304, "Square", &[89]), (127305, "Square", &[90]),
+  (127306, "Square", &[72, 86]), (127307, "Square", &[77, 86]),
+  (127308, "Square", &[83, 68]), (127309, "Square", &[83, 83]),
+  (127310, "Square", &[80, 80, 86]), (127311, "Square", &[87, 67]),
+  (127338, "Super", &[77, 67]), (127339, "Super", &[77, 68]),
+  (127376, "Square", &[68, 74]), (127488, "Square", &[12411, 12363]),
+  (127489, "Square", &[12467, 12467]), (127490, "Square", &[12469]),
+  (127504, "Square", &[25163]), (127505, "Square", &[23383]),
+  (127506, "Square", &[21452]), (127507, "Square", &[12487]),
+  (127508, "Square", &[20108]), (127509, "Square", &[22810]),
+  (127510, "Square", &[35299]), (127511, "Square", &[22825]),
+  (127512, "Square", &[20132]), (127513, "Square", &[26144]),
+  (127514, "Square", &[28961]), (127515, "Square", &[26009]),
+  (127516, "Square", &[21069]), (127517, "Square", &[24460]),
+  (127518, "Square", &[20877]), (127519, "Square", &[26032]),
+  (127520, "Square", &[21021]), (127521, "Square", &[32066]),
+  (127522, "Square", &[29983]), (127523, "Square", &[36009]),
+  (127524, "Square", &[22768]), (127525, "Square", &[21561]),
+  (127526, "Square", &[28436]), (127527, "Square", &[25237]),
+  (127528, "Square", &[25429]), (127529, "Square", &[19968]),
+  (127530, "Square", &[19977]), (127531, "Square", &[36938]),
+  (127532, "Square", &[24038]), (127533, "Square", &[20013]),
+  (127534, "Square", &[21491]), (127535, "Square", &[25351]),
+  (127536, "Square", &[36208]), (127537, "Square", &[25171]),
+  (127538, "Square", &[31105]), (127539, "Square", &[31354]),
+  (127540, "Square", &[21512]), (127541, "Square", &[28288]),
+  (127542, "Square", &[26377]), (127543, "Square", &[26376]),
+  (127544, "Square", &[30003]), (127545, "Square", &[21106]),
+  (127546, "Square", &[21942]), (127547, "Square", &[37197]),
+  (127552, "Compat", &[12308, 26412, 12309]),
+  (127553, "Compat", &[12308, 19977, 12309]),
+  (127554, "Compat", &[12308, 20108, 12309]),
+  (127555, "Compat", &[12308, 23433, 12309]),
+  (127556, "Compat", &[12308, 28857, 12309]),
+  (127557, "Compat", &[12308, 25171, 12309]),
+  (127558, "Compat", &[12308, 30423, 12309]),
+  (127559, "Compat", &[12308, 21213, 12309]),
+  (127560, "Compat", &[12308, 25943, 12309]), (127568, "Circle", &[24471]),
+  (127569, "Circle", &[21487]), (194560, "Canonical", &[20029]),
+  (194561, "Canonical", &[20024]), (194562, "Canonical", &[20033]),
+  (194563, "Canonical", &[131362]), (194564, "Canonical", &[20320]),
+  (194565, "Canonical", &[20398]), (194566, "Canonical", &[20411]),
+  (194567, "Canonical", &[20482]), (194568, "Canonical", &[20602]),
+  (194569, "Canonical", &[20633]), (194570, "Canonical", &[20711]),
+  (194571, "Canonical", &[20687]), (194572, "Canonical", &[13470]),
+  (194573, "Canonical", &[132666]), (194574, "Canonical", &[20813]),
+  (194575, "Canonical", &[20820]), (194576, "Canonical", &[20836]),
+  (194577, "Canonical", &[20855]), (194578, "Canonical", &[132380]),
+  (194579, "Canonical", &[13497]), (194580, "Canonical", &[20839]),
+  (194581, "Canonical", &[20877]), (194582, "Canonical", &[132427]),
+  (194583, "Canonical", &[20887]), (194584, "Canonical", &[20900]),
+  (194585, "Canonical", &[20172]), (194586, "Canonical", &[20908]),
+  (194587, "Canonical", &[20917]), (194588, "Canonical", &[168415]),
+  (194589, "Canonical", &[20981]), (194590, "Canonical", &[20995]),
+  (194591, "Canonical", &[13535]), (194592, "Canonical", &[21051]),
+  (194593, "Canonical", &[21062]), (194594, "Canonical", &[21106]),
+  (194595, "Canonical", &[21111]), (194596, "Canonical", &[13589]),
+  (194597, "Canonical", &[21191]), (194598, "Canonical", &[21193]),
+  (194599, "Canonical", &[21220]), (194600, "Canonical", &[21242]),
+  (194601, "Canonical", &[21253]), (194602, "Canonical", &[21254]),
+  (194603, "Canonical", &[21271]), (194604, "Canonical", &[21321]),
+  (194605, "Canonical", &[21329]), (194606, "Canonical", &[21338]),
+  (194607, "Canonical", &[21363]), (194608, "Canonical", &[21373]),
+  (194609, "Canonical", &[21375]), (194610, "Canonical", &[21375]),
+  (194611, "Canonical", &[21375]), (194612, "Canonical", &[133676]),
+  (194613, "Canonical", &[28784]), (194614, "Canonical", &[21450]),
+  (194615, "Canonical", &[21471]), (194616, "Canonical", &[133987]),
+  (194617, "Canonical", &[21483]), (194618, "Canonical", &[21489]),
+  (194619, "Canonical", &[21510]), (194620, "Canonical", &[21662]),
+  (194621, "Canonical", &[21560]), (194622, "Canonical", &[21576]),
+  (194623, "Canonical", &[21608]), (194624, "Canonical", &[21666]),
+  (194625, "Canonical", &[21750]), (194626, "Canonical", &[21776]),
+  (194627, "Canonical", &[21843]), (194628, "Canonical", &[21859]),
+  (194629, "Canonical", &[21892]), (194630, "Canonical", &[21892]),
+  (194631, "Canonical", &[21913]), (194632, "Canonical", &[21931]),
+  (194633, "Canonical", &[21939]), (194634, "Canonical", &[21954]),
+  (194635, "Canonical", &[22294]), (194636, "Canonical", &[22022]),
+  (194637, "Canonical", &[22295]), (194638, "Canonical", &[22097]),
+  (194639, "Canonical", &[22132]), (194640, "Canonical", &[20999]),
+  (194641, "Canonical", &[22766]), (194642, "Canonical", &[22478]),
+  (194643, "Canonical", &[22516]), (194644, "Canonical", &[22541]),
+  (194645, "Canonical", &[22411]), (194646, "Canonical", &[22578]),
+  (194647, "Canonical", &[22577]), (194648, "Canonical", &[22700]),
+  (194649, "Canonical", &[136420]), (194650, "Canonical", &[22770]),
+  (194651, "Canonical", &[22775]), (194652, "Canonical", &[22790]),
+  (194653, "Canonical", &[22810]), (194654, "Canonical", &[22818]),
+  (194655, "Canonical", &[22882]), (194656, "Canonical", &[136872]),
+  (194657, "Canonical", &[136938]), (194658, "Canonical", &[23020]),
+  (194659, "Canonical", &[23067]), (194660, "Canonical", &[23079]),
+  (194661, "Canonical", &[23000]), (194662, "Canonical", &[23142]),
+  (194663, "Canonical", &[14062]), (194664, "Canonical", &[14076]),
+  (194665, "Canonical", &[23304]), (194666, "Canonical", &[23358]),
+  (194667, "Canonical", &[23358]), (194668, "Canonical", &[137672]),
+  (194669, "Canonical", &[23491]), (194670, "Canonical", &[23512]),
+  (194671, "Canonical", &[23527]), (194672, "Canonical", &[23539]),
+  (194673, "Canonical", &[138008]), (194674, "Canonical", &[23551]),
+  (194675, "Canonical", &[23558]), (194676, "Canonical", &[24403]),
+  (194677, "Canonical", &[23586]), (194678, "Canonical", &[14209]),
+  (194679, "Canonical", &[23648]), (194680, "Canonical", &[23662]),
+  (194681, "Canonical", &[23744]), (194682, "Canonical", &[23693]),
+  (194683, "Canonical", &[138724]), (194684, "Canonical", &[23875]),
+  (194685, "Canonical", &[138726]), (194686, "Canonical", &[23918]),
+  (194687, "Canonical", &[23915]), (194688, "Canonical", &[23932]),
+  (194689, "Canonical", &[24033]), (194690, "Canonical", &[24034]),
+  (194691, "Canonical", &[14383]), (194692, "Canonical", &[24061]),
+  (194693, "Canonical", &[24104]), (194694, "Canonical", &[24125]),
+  (194695, "Canonical", &[24169]), (194696, "Canonical", &[14434]),
+  (194697, "Canonical", &[139651]), (194698, "Canonical", &[14460]),
+  (194699, "Canonical", &[24240]), (194700, "Canonical", &[24243]),
+  (194701, "Canonical", &[24246]), (194702, "Canonical", &[24266]),
+  (194703, "Canonical", &[172946]), (194704, "Canonical", &[24318]),
+  (194705, "Canonical", &[140081]), (194706, "Canonical", &[140081]),
+  (194707, "Canonical", &[33281]), (194708, "Canonical", &[24354]),
+  (194709, "Canonical", &[24354]), (194710, "Canonical", &[14535]),
+  (194711, "Canonical", &[144056]), (194712, "Canonical", &[156122]),
+  (194713, "Canonical", &[24418]), (194714, "Canonical", &[24427]),
+  (194715, "Canonical", &[14563]), (194716, "Canonical", &[24474]),
+  (194717, "Canonical", &[24525]), (194718, "Canonical", &[24535]),
+  (194719, "Canonical", &[24569]), (194720, "Canonical", &[24705]),
+  (194721, "Canonical", &[14650]), (194722, "Canonical", &[14620]),
+  (194723, "Canonical", &[24724]), (194724, "Canonical", &[141012]),
+  (194725, "Canonical", &[24775]), (194726, "Canonical", &[24904]),
+  (194727, "Canonical", &[24908]), (194728, "Canonical", &[24910]),
+  (194729, "Canonical", &[24908]), (194730, "Canonical", &[24954]),
+  (194731, "Canonical", &[24974]), (194732, "Canonical", &[25010]),
+  (194733, "Canonical", &[24996]), (194734, "Canonical", &[25007]),
+  (194735, "Canonical", &[25054]), (194736, "Canonical", &[25074]),
+  (194737, "Canonical", &[25078]), (194738, "Canonical", &[25104]),
+  (194739, "Canonical", &[25115]), (194740, "Canonical", &[25181]),
+  (194741, "Canonical", &[25265]), (194742, "Canonical", &[25300]),
+  (194743, "Canonical", &[25424]), (194744, "Canonical", &[142092]),
+  (194745, "Canonical", &[25405]), (194746, "Canonical", &[25340]),
+  (194747, "Canonical", &[25448]), (194748, "Canonical", &[25475]),
+  (194749, "Canonical", &[25572]), (194750, "Canonical", &[142321]),
+  (194751, "Canonical", &[25634]), (194752, "Canonical", &[25541]),
+  (194753, "Canonical", &[25513]), (194754, "Canonical", &[14894]),
+  (194755, "Canonical", &[25705]), (194756, "Canonical", &[25726]),
+  (194757, "Canonical", &[25757]), (194758, "Canonical", &[25719]),
+  (194759, "Canonical", &[14956]), (194760, "Canonical", &[25935]),
+  (194761, "Canonical", &[25964]), (194762, "Canonical", &[143370]),
+  (194763, "Canonical", &[26083]), (194764, "Canonical", &[26360]),
+  (194765, "Canonical", &[26185]), (194766, "Canonical", &[15129]),
+  (194767, "Canonical", &[26257]), (194768, "Canonical", &[15112]),
+  (194769, "Canonical", &[15076]), (194770, "Canonical", &[20882]),
+  (194771, "Canonical", &[20885]), (194772, "Canonical", &[26368]),
+  (194773, "Canonical", &[26268]), (194774, "Canonical", &[32941]),
+  (194775, "Canonical", &[17369]), (194776, "Canonical", &[26391]),
+  (194777, "Canonical", &[26395]), (194778, "Canonical", &[26401]),
+  (194779, "Canonical", &[26462]), (194780, "Canonical", &[26451]),
+  (194781, "Canonical", &[144323]), (194782, "Canonical", &[15177]),
+  (194783, "Canonical", &[26618]), (194784, "Canonical", &[26501]),
+  (194785, "Canonical", &[26706]), (194786, "Canonical", &[26757]),
+  (194787, "Canonical", &[144493]), (194788, "Canonical", &[26766]),
+  (194789, "Canonical", &[26655]), (194790, "Canonical", &[26900]),
+  (194791, "Canonical", &[15261]), (194792, "Canonical", &[26946]),
+  (194793, "Canonical", &[27043]), (194794, "Canonical", &[27114]),
+  (194795, "Canonical", &[27304]), (194796, "Canonical", &[145059]),
+  (194797, "Canonical", &[27355]), (194798, "Canonical", &[15384]),
+  (194799, "Canonical", &[27425]), (194800, "Canonical", &[145575]),
+  (194801, "Canonical", &[27476]), (194802, "Canonical", &[15438]),
+  (194803, "Canonical", &[27506]), (194804, "Canonical", &[27551]),
+  (194805, "Canonical", &[27578]), (194806, "Canonical", &[27579]),
+  (194807, "Canonical", &[146061]), (194808, "Canonical", &[138507]),
+  (194809, "Canonical", &[146170]), (194810, "Canonical", &[27726]),
+  (194811, "Canonical", &[146620]), (194812, "Canonical", &[27839]),
+  (194813, "Canonical", &[27853]), (194814, "Canonical", &[27751]),
+  (194815, "Canonical", &[27926]), (194816, "Canonical", &[27966]),
+  (194817, "Canonical", &[28023]), (194818, "Canonical", &[27969]),
+  (194819, "Canonical", &[28009]), (194820, "Canonical", &[28024]),
+  (194821, "Canonical", &[28037]), (194822, "Canonical", &[146718]),
+  (194823, "Canonical", &[27956]), (194824, "Canonical", &[28207]),
+  (194825, "Canonical", &[28270]), (194826, "Canonical", &[15667]),
+  (194827, "Canonical", &[28363]), (194828, "Canonical", &[28359]),
+  (194829, "Canonical", &[147153]), (194830, "Canonical", &[28153]),
+  (194831, "Canonical", &[28526]), (194832, "Canonical", &[147294]),
+  (194833, "Canonical", &[147342]), (194834, "Canonical", &[28614]),
+  (194835, "Canonical", &[28729]), (194836, "Canonical", &[28702]),
+  (194837, "Canonical", &[28699]), (194838, "Canonical", &[15766]),
+  (194839, "Canonical", &[28746]), (194840, "Canonical", &[28797]),
+  (194841, "Canonical", &[28791]), (194842, "Canonical", &[28845]),
+  (194843, "Canonical", &[132389]), (194844, "Canonical", &[28997]),
+  (194845, "Canonical", &[148067]), (194846, "Canonical", &[29084]),
+  (194847, "Canonical", &[148395]), (194848, "Canonical", &[29224]),
+  (194849, "Canonical", &[29237]), (194850, "Canonical", &[29264]),
+  (194851, "Canonical", &[149000]), (194852, "Canonical", &[29312]),
+  (194853, "Canonical", &[29333]), (194854, "Canonical", &[149301]),
+  (194855, "Canonical", &[149524]), (194856, "Canonical", &[29562]),
+  (194857, "Canonical", &[29579]), (194858, "Canonical", &[16044]),
+  (194859, "Canonical", &[29605]), (194860, "Canonical", &[16056]),
+  (194861, "Canonical", &[16056]), (194862, "Canonical", &[29767]),
+  (194863, "Canonical", &[29788]), (194864, "Canonical", &[29809]),
+  (194865, "Canonical", &[29829]), (194866, "Canonical", &[29898]),
+  (194867, "Canonical", &[16155]), (194868, "Canonical", &[29988]),
+  (194869, "Canonical", &[150582]), (194870, "Canonical", &[30014]),
+  (194871, "Canonical", &[150674]), (194872, "Canonical", &[30064]),
+  (194873, "Canonical", &[139679]), (194874, "Canonical", &[30224]),
+  (194875, "Canonical", &[151457]), (194876, "Canonical", &[151480]),
+  (194877, "Canonical", &[151620]), (194878, "Canonical", &[16380]),
+  (194879, "Canonical", &[16392]), (194880, "Canonical", &[30452]),
+  (194881, "Canonical", &[151795]), (194882, "Canonical", &[151794]),
+  (194883, "Canonical", &[151833]), (194884, "Canonical", &[151859]),
+  (194885, "Canonical", &[30494]), (194886, "Canonical", &[30495]),
+  (194887, "Canonical", &[30495]), (194888, "Canonical", &[30538]),
+  (194889, "Canonical", &[16441]), (194890, "Canonical", &[30603]),
+  (194891, "Canonical", &[16454]), (194892, "Canonical", &[16534]),
+  (194893, "Canonical", &[152605]), (194894, "Canonical", &[30798]),
+  (194895, "Canonical", &[30860]), (194896, "Canonical", &[30924]),
+  (194897, "Canonical", &[16611]), (194898, "Canonical", &[153126]),
+  (194899, "Canonical", &[31062]), (194900, "Canonical", &[153242]),
+  (194901, "Canonical", &[153285]), (194902, "Canonical", &[31119]),
+  (194903, "Canonical", &[31211]), (194904, "Canonical", &[16687]),
+  (194905, "Canonical", &[31296]), (194906, "Canonical", &[31306]),
+  (194907, "Canonical", &[31311]), (194908, "Canonical", &[153980]),
+  (194909, "Canonical", &[154279]), (194910, "Canonical", &[154279]),
+  (194911, "Canonical", &[31470]), (194912, "Canonical", &[16898]),
+  (194913, "Canonical", &[154539]), (194914, "Canonical", &[31686]),
+  (194915, "Canonical", &[31689]), (194916, "Canonical", &[16935]),
+  (194917, "Canonical", &[154752]), (194918, "Canonical", &[31954]),
+  (194919, "Canonical", &[17056]), (194920, "Canonical", &[31976]),
+  (194921, "Canonical", &[31971]), (194922, "Canonical", &[32000]),
+  (194923, "Canonical", &[155526]), (194924, "Canonical", &[32099]),
+  (194925, "Canonical", &[17153]), (194926, "Canonical", &[32199]),
+  (194927, "Canonical", &[32258]), (194928, "Canonical", &[32325]),
+  (194929, "Canonical", &[17204]), (194930, "Canonical", &[156200]),
+  (194931, "Canonical", &[156231]), (194932, "Canonical", &[17241]),
+  (194933, "Canonical", &[156377]), (194934, "Canonical", &[32634]),
+  (194935, "Canonical", &[156478]), (194936, "Canonical", &[32661]),
+  (194937, "Canonical", &[32762]), (194938, "Canonical", &[32773]),
+  (194939, "Canonical", &[156890]), (194940, "Canonical", &[156963]),
+  (194941, "Canonical", &[32864]), (194942, "Canonical", &[157096]),
+  (194943, "Canonical", &[32880]), (194944, "Canonical", &[144223]),
+  (194945, "Canonical", &[17365]), (194946, "Canonical", &[32946]),
+  (194947, "Canonical", &[33027]), (194948, "Canonical", &[17419]),
+  (194949, "Canonical", &[33086]), (194950, "Canonical", &[23221]),
+  (194951, "Canonical", &[157607]), (194952, "Canonical", &[157621]),
+  (194953, "Canonical", &[144275]), (194954, "Canonical", &[144284]),
+  (194955, "Canonical", &[33281]), (194956, "Canonical", &[33284]),
+  (194957, "Canonical", &[36766]), (194958, "Canonical", &[17515]),
+  (194959, "Canonical", &[33425]), (194960, "Canonical", &[33419]),
+  (194961, "Canonical", &[33437]), (194962, "Canonical", &[21171]),
+  (194963, "Canonical", &[33457]), (194964, "Canonical", &[33459]),
+  (194965, "Canonical", &[33469]), (194966, "Canonical", &[33510]),
+  (194967, "Canonical", &[158524]), (194968, "Canonical", &[33509]),
+  (194969, "Canonical", &[33565]), (194970, "Canonical", &[33635]),
+  (194971, "Canonical", &[33709]), (194972, "Canonical", &[33571]),
+  (194973, "Canonical", &[33725]), (194974, "Canonical", &[33767]),
+  (194975, "Canonical", &[33879]), (194976, "Canonical", &[33619]),
+  (194977, "Canonical", &[33738]), (194978, "Canonical", &[33740]),
+  (194979, "Canonical", &[33756]), (194980, "Canonical", &[158774]),
+  (194981, "Canonical", &[159083]), (194982, "Canonical", &[158933]),
+  (194983, "Canonical", &[17707]), (194984, "Canonical", &[34033]),
+  (194985, "Canonical", &[34035]), (194986, "Canonical", &[34070]),
+  (194987, "Canonical", &[160714]), (194988, "Canonical", &[34148]),
+  (194989, "Canonical", &[159532]), (194990, "Canonical", &[17757]),
+  (194991, "Canonical", &[17761]), (194992, "Canonical", &[159665]),
+  (194993, "Canonical", &[159954]), (194994, "Canonical", &[17771]),
+  (194995, "Canonical", &[34384]), (194996, "Canonical", &[34396]),
+  (194997, "Canonical", &[34407]), (194998, "Canonical", &[34409]),
+  (194999, "Canonical", &[34473]), (195000, "Canonical", &[34440]),
+  (195001, "Canonical", &[34574]), (195002, "Canonical", &[34530]),
+  (195003, "Canonical", &[34681]), (195004, "Canonical", &[34600]),
+  (195005, "Canonical", &[34667]), (195006, "Canonical", &[34694]),
+  (195007, "Canonical", &[17879]), (195008, "Canonical", &[34785]),
+  (195009, "Canonical", &[34817]), (195010, "Canonical", &[17913]),
+  (195011, "Canonical", &[34912]), (195012, "Canonical", &[34915]),
+  (195013, "Canonical", &[161383]), (195014, "Canonical", &[35031]),
+  (195015, "Canonical", &[35038]), (195016, "Canonical", &[17973]),
+  (195017, "Canonical", &[35066]), (195018, "Canonical", &[13499]),
+  (195019, "Canonical", &[161966]), (195020, "Canonical", &[162150]),
+  (195021, "Canonical", &[18110]), (195022, "Canonical", &[18119]),
+  (195023, "Canonical", &[35488]), (195024, "Canonical", &[35565]),
+  (195025, "Canonical", &[35722]), (195026, "Canonical", &[35925]),
+  (195027, "Canonical", &[162984]), (195028, "Canonical", &[36011]),
+  (195029, "Canonical", &[36033]), (195030, "Canonical", &[36123]),
+  (195031, "Canonical", &[36215]), (195032, "Canonical", &[163631]),
+  (195033, "Canonical", &[133124]), (195034, "Canonical", &[36299]),
+  (195035, "Canonical", &[36284]), (195036, "Canonical", &[36336]),
+  (195037, "Canonical", &[133342]), (195038, "Canonical", &[36564]),
+  (195039, "Canonical", &[36664]), (195040, "Canonical", &[165330]),
+  (195041, "Canonical", &[165357]), (195042, "Canonical", &[37012]),
+  (195043, "Canonical", &[37105]), (195044, "Canonical", &[37137]),
+  (195045, "Canonical", &[165678]), (195046, "Canonical", &[37147]),
+  (195047, "Canonical", &[37432]), (195048, "Canonical", &[37591]),
+  (195049, "Canonical", &[37592]), (195050, "Canonical", &[37500]),
+  (195051, "Canonical", &[37881]), (195052, "Canonical", &[37909]),
+  (195053, "Canonical", &[166906]), (195054, "Canonical", &[38283]),
+  (195055, "Canonical", &[18837]), (195056, "Canonical", &[38327]),
+  (195057, "Canonical", &[167287]), (195058, "Canonical", &[18918]),
+  (195059, "Canonical", &[38595]), (195060, "Canonical", &[23986]),
+  (195061, "Canonical", &[38691]), (195062, "Canonical", &[168261]),
+  (195063, "Canonical", &[168474]), (195064, "Canonical", &[19054]),
+  (195065, "Canonical", &[19062]), (195066, "Canonical", &[38880]),
+  (195067, "Canonical", &[168970]), (195068, "Canonical", &[19122]),
+  (195069, "Canonical", &[169110]), (195070, "Canonical", &[38923]),
+  (195071, "Canonical", &[38923]), (195072, "Canonical", &[38953]),
+  (195073, "Canonical", &[169398]), (195074, "Canonical", &[39138]),
+  (195075, "Canonical", &[19251]), (195076, "Canonical", &[39209]),
+  (195077, "Canonical", &[39335]), (195078, "Canonical", &[39362]),
+  (195079, "Canonical", &[39422]), (195080, "Canonical", &[19406]),
+  (195081, "Canonical", &[170800]), (195082, "Canonical", &[39698]),
+  (195083, "Canonical", &[40000]), (195084, "Canonical", &[40189]),
+  (195085, "Canonical", &[19662]), (195086, "Canonical", &[19693]),
+  (195087, "Canonical", &[40295]), (195088, "Canonical", &[172238]),
+  (195089, "Canonical", &[19704]), (195090, "Canonical", &[172293]),
+  (195091, "Canonical", &[172558]), (195092, "Canonical", &[172689]),
+  (195093, "Canonical", &[40635]), (195094, "Canonical", &[19798]),
+  (195095, "Canonical", &[40697]), (195096, "Canonical", &[40702]),
+  (195097, "Canonical", &[40709]), (195098, "Canonical", &[40719]),
+  (195099, "Canonical", &[40726]), (195100, "Canonical", &[40763]),
+  (195101, "Canonical", &[173568]),
+];
@@ -0,0 +1,12 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//  ucd-generate property-bool /home/andrew/tmp/ucd-10.0.0/ --include Default_Ignorable_Code_Point
+//
+// ucd-generate is available on crates.io.
+
+pub const DEFAULT_IGNORABLE_CODE_POINT: &'static [(u32, u32)] = &[
+  (173, 173), (847, 847), (1564, 1564), (4447, 4448), (6068, 6069),
+  (6155, 6158), (8203, 8207), (8234, 8238), (8288, 8303), (12644, 12644),
+  (65024, 65039), (65279, 65279), (65440, 65440), (65520, 65528),
+  (113824, 113827), (119155, 119162), (917504, 921599),
+];
@@ -0,0 +1,93 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//  ucd-generate east-asian-width /home/andrew/tmp/ucd-10.0.0/
+//
+// ucd-generate is available on crates.io.
+
+pub const EAST_ASIAN_WIDTH: &'static [(u32, u32, &'static str)] = &[
+  (32, 126, "Na"), (161, 161, "A"), (162, 163, "Na"), (164, 164, "A"),
+  (165, 166, "Na"), (167, 168, "A"), (170, 170, "A"), (172, 172, "Na"),
+  (173, 174, "A"), (175, 175, "Na"), (176, 180, "A"), (182, 186, "A"),
+  (188, 191, "A"), (198, 198, "A"), (208, 208, "A"), (215, 216, "A"),
+  (222, 225, "A"), (230, 230, "A"), (232, 234, "A"), (236, 237, "A"),
+  (240, 240, "A"), (242, 243, "A"), (247, 250, "A"), (252, 252, "A"),
+  (254, 254, "A"), (257, 257, "A"), (273, 273, "A"), (275, 275, "A"),
+  (283, 283, "A"), (294, 295, "A"), (299, 299, "A"), (305, 307, "A"),
+  (312, 312, "A"), (319, 322, "A"), (324, 324, "A"), (328, 331, "A"),
+  (333, 333, "A"), (338, 339, "A"), (358, 359, "A"), (363, 363, "A"),
+  (462, 462, "A"), (464, 464, "A"), (466, 466, "A"), (468, 468, "A"),
+  (470, 470, "A"), (472, 472, "A"), (474, 474, "A"), (476, 476, "A"),
+  (593, 593, "A"), (609, 609, "A"), (708, 708, "A"), (711, 711, "A"),
+  (713, 715, "A"), (717, 717, "A"), (720, 720, "A"), (728, 731, "A"),
+  (733, 733, "A"), (735, 735, "A"), (768, 879, "A"), (913, 929, "A"),
+  (931, 937, "A"), (945, 961, "A"), (963, 969, "A"), (1025, 1025, "A"),
+  (1040, 1103, "A"), (1105, 1105, "A"), (4352, 4447, "W"), (8208, 8208, "A"),
+  (8211, 8214, "A"), (8216, 8217, "A"), (8220, 8221, "A"), (8224, 8226, "A"),
+  (8228, 8231, "A"), (8240, 8240, "A"), (8242, 8243, "A"), (8245, 8245, "A"),
+  (8251, 8251, "A"), (8254, 8254, "A"), (8308, 8308, "A"), (8319, 8319, "A"),
+  (8321, 8324, "A"), (8361, 8361, "H"), (8364, 8364, "A"), (8451, 8451, "A"),
+  (8453, 8453, "A"), (8457, 8457, "A"), (8467, 8467, "A"), (8470, 8470, "A"),
+  (8481, 8482, "A"), (8486, 8486, "A"), (8491, 8491, "A"), (8531, 8532, "A"),
+  (8539, 8542, "A"), (8544, 8555, "A"), (8560, 8569, "A"), (8585, 8585, "A"),
+  (8592, 8601, "A"), (8632, 8633, "A"), (8658, 8658, "A"), (8660, 8660, "A"),
+  (8679, 8679, "A"), (8704, 8704, "A"), (8706, 8707, "A"), (8711, 8712, "A"),
+  (8715, 8715, "A"), (8719, 8719, "A"), (8721, 8721, "A"), (8725, 8725, "A"),
+  (8730, 8730, "A"), (8733, 8736, "A"), (8739, 8739, "A"), (8741, 8741, "A"),
+  (8743, 8748, "A"), (8750, 8750, "A"), (8756, 8759, "A"), (8764, 8765, "A"),
+  (8776, 8776, "A"), (8780, 8780, "A"), (8786, 8786, "A"), (8800, 8801, "A"),
+  (8804, 8807, "A"), (8810, 8811, "A"), (8814, 8815, "A"), (8834, 8835, "A"),
+  (8838, 8839, "A"), (8853, 8853, "A"), (8857, 8857, "A"), (8869, 8869, "A"),
+  (8895, 8895, "A"), (8978, 8978, "A"), (8986, 8987, "W"), (9001, 9002, "W"),
+  (9193, 9196, "W"), (9200, 9200, "W"), (9203, 9203, "W"), (9312, 9449, "A"),
+  (9451, 9547, "A"), (9552, 9587, "A"), (9600, 9615, "A"), (9618, 9621, "A"),
+  (9632, 9633, "A"), (9635, 9641, "A"), (9650, 9651, "A"), (9654, 9655, "A"),
+  (9660, 9661, "A"), (9664, 9665, "A"), (9670, 9672, "A"), (9675, 9675, "A"),
+  (9678, 9681, "A"), (9698, 9701, "A"), (9711, 9711, "A"), (9725, 9726, "W"),
+  (9733, 9734, "A"), (9737, 9737, "A"), (9742, 9743, "A"), (9748, 9749, "W"),
+  (9756, 9756, "A"), (9758, 9758, "A"), (9792, 9792, "A"), (9794, 9794, "A"),
+  (9800, 9811, "W"), (9824, 9825, "A"), (9827, 9829, "A"), (9831, 9834, "A"),
+  (9836, 9837, "A"), (9839, 9839, "A"), (9855, 9855, "W"), (9875, 9875, "W"),
+  (9886, 9887, "A"), (9889, 9889, "W"), (9898, 9899, "W"), (9917, 9918, "W"),
+  (9919, 9919, "A"), (9924, 9925, "W"), (9926, 9933, "A"), (9934, 9934, "W"),
+  (9935, 9939, "A"), (9940, 9940, "W"), (9941, 9953, "A"), (9955, 9955, "A"),
+  (9960, 9961, "A"), (9962, 9962, "W"), (9963, 9969, "A"), (9970, 9971, "W"),
+  (9972, 9972, "A"), (9973, 9973, "W"), (9974, 9977, "A"), (9978, 9978, "W"),
+  (9979, 9980, "A"), (9981, 9981, "W"), (9982, 9983, "A"), (9989, 9989, "W"),
+  (9994, 9995, "W"), (10024, 10024, "W"), (10045, 10045, "A"),
+  (10060, 10060, "W"), (10062, 10062, "W"), (10067, 10069, "W"),
+  (10071, 10071, "W"), (10102, 10111, "A"), (10133, 10135, "W"),
+  (10160, 10160, "W"), (10175, 10175, "W"), (10214, 10221, "Na"),
+  (10629, 10630, "Na"), (11035, 11036, "W"), (11088, 11088, "W"),
+  (11093, 11093, "W"), (11094, 11097, "A"), (11904, 11929, "W"),
+  (11931, 12019, "W"), (12032, 12245, "W"), (12272, 12283, "W"),
+  (12288, 12288, "F"), (12289, 12350, "W"), (12353, 12438, "W"),
+  (12441, 12543, "W"), (12549, 12589, "W"), (12593, 12686, "W"),
+  (12688, 12730, "W"), (12736, 12771, "W"), (12784, 12830, "W"),
+  (12832, 12871, "W"), (12872, 12879, "A"), (12880, 13054, "W"),
+  (13056, 19903, "W"), (19968, 42124, "W"), (42128, 42182, "W"),
+  (43360, 43388, "W"), (44032, 55203, "W"), (57344, 63743, "A"),
+  (63744, 64255, "W"), (65024, 65039, "A"), (65040, 65049, "W"),
+  (65072, 65106, "W"), (65108, 65126, "W"), (65128, 65131, "W"),
+  (65281, 65376, "F"), (65377, 65470, "H"), (65474, 65479, "H"),
+  (65482, 65487, "H"), (65490, 65495, "H"), (65498, 65500, "H"),
+  (65504, 65510, "F"), (65512, 65518, "H"), (65533, 65533, "A"),
+  (94176, 94177, "W"), (94208, 100332, "W"), (100352, 101106, "W"),
+  (110592, 110593, "W"), (110960, 111355, "W"), (126980, 126980, "W"),
+  (127183, 127183, "W"), (127232, 127242, "A"), (127248, 127277, "A"),
+  (127280, 127337, "A"), (127344, 127373, "A"), (127374, 127374, "W"),
+  (127375, 127376, "A"), (127377, 127386, "W"), (127387, 127404, "A"),
+  (127488, 127490, "W"), (127504, 127547, "W"), (127552, 127560, "W"),
+  (127568, 127569, "W"), (127584, 127589, "W"), (127744, 127776, "W"),
+  (127789, 127797, "W"), (127799, 127868, "W"), (127870, 127891, "W"),
+  (127904, 127946, "W"), (127951, 127955, "W"), (127968, 127984, "W"),
+  (127988, 127988, "W"), (127992, 128062, "W"), (128064, 128064, "W"),
+  (128066, 128252, "W"), (128255, 128317, "W"), (128331, 128334, "W"),
+  (128336, 128359, "W"), (128378, 128378, "W"), (128405, 128406, "W"),
+  (128420, 128420, "W"), (128507, 128591, "W"), (128640, 128709, "W"),
+  (128716, 128716, "W"), (128720, 128722, "W"), (128747, 128748, "W"),
+  (128756, 128760, "W"), (129296, 129310, "W"), (129312, 129319, "W"),
+  (129328, 129328, "W"), (129331, 129342, "W"), (129344, 129356, "W"),
+  (129360, 129387, "W"), (129408, 129431, "W"), (129472, 129472, "W"),
+  (131072, 196605, "W"), (196608, 262141, "W"), (917760, 917999, "A"),
+  (983040, 1048573, "A"), (1048576, 1114109, "A"),
+];
@@ -0,0 +1,1492 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//  ucd-generate emoji-sequences /home/andrew/tmp/emoji-5.0/
+//
+// ucd-generate is available on crates.io.
+
+pub const EMOJI_SEQUENCES: &'static [(&'static [u32], &'static str, &'static str)] = &[
+  (&[35, 65039, 8419], "Emoji_Keycap_Sequence", "keycap: #"),
+  (&[42, 65039, 8419], "Emoji_Keycap_Sequence", "keycap: *"),
+  (&[48, 65039, 8419], "Emoji_Keycap_Sequence", "keycap: 0"),
+  (&[49, 65039, 8419], "Emoji_Keycap_Sequence", "keycap: 1"),
+  (&[50, 65039, 8419], "Emoji_Keycap_Sequence", "keycap: 2"),
+  (&[51, 65039, 8419], "Emoji_Keycap_Sequence", "keycap: 3"),
+  (&[52, 65039, 8419], "Emoji_Keycap_Sequence", "keycap: 4"),
+  (&[53, 65039, 8419], "Emoji_Keycap_Sequence", "keycap: 5"),
+  (&[54, 65039, 8419], "Emoji_Keycap_Sequence", "keycap: 6"),
+  (&[55, 65039, 8419], "Emoji_Keycap_Sequence", "keycap: 7"),
+  (&[56, 65039, 8419], "Emoji_Keycap_Sequence", "keycap: 8"),
+  (&[57, 65039, 8419], "Emoji_Keycap_Sequence", "keycap: 9"),
+  (&[9757, 127995], "Emoji_Modifier_Sequence", "index pointing up: light skin tone"),
+  (&[9757, 127996], "Emoji_Modifier_Sequence", "index pointing up: medium-light skin tone"),
+  (&[9757, 127997], "Emoji_Modifier_Sequence", "index pointing up: medium skin tone"),
+  (&[9757, 127998], "Emoji_Modifier_Sequence", "index pointing up: medium-dark skin tone"),
+  (&[9757, 127999], "Emoji_Modifier_Sequence", "index pointing up: dark skin tone"),
+  (&[9977, 65039, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman bouncing ball"),
+  (&[9977, 65039, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man bouncing ball"),
+  (&[9977, 127995], "Emoji_Modifier_Sequence", "person bouncing ball: light skin tone"),
+  (&[9977, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman bouncing ball: light skin tone"),
+  (&[9977, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man bouncing ball: light skin tone"),
+  (&[9977, 127996], "Emoji_Modifier_Sequence", "person bouncing ball: medium-light skin tone"),
+  (&[9977, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman bouncing ball: medium-light skin tone"),
+  (&[9977, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man bouncing ball: medium-light skin tone"),
+  (&[9977, 127997], "Emoji_Modifier_Sequence", "person bouncing ball: medium skin tone"),
+  (&[9977, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman bouncing ball: medium skin tone"),
+  (&[9977, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man bouncing ball: medium skin tone"),
+  (&[9977, 127998], "Emoji_Modifier_Sequence", "person bouncing ball: medium-dark skin tone"),
+  (&[9977, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman bouncing ball: medium-dark skin tone"),
+  (&[9977, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man bouncing ball: medium-dark skin tone"),
+  (&[9977, 127999], "Emoji_Modifier_Sequence", "person bouncing ball: dark skin tone"),
+  (&[9977, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman bouncing ball: dark skin tone"),
+  (&[9977, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man bouncing ball: dark skin tone"),
+  (&[9994, 127995], "Emoji_Modifier_Sequence", "raised fist: light skin tone"),
+  (&[9994, 127996], "Emoji_Modifier_Sequence", "raised fist: medium-light skin tone"),
+  (&[9994, 127997], "Emoji_Modifier_Sequence", "raised fist: medium skin tone"),
+  (&[9994, 127998], "Emoji_Modifier_Sequence", "raised fist: medium-dark skin tone"),
+  (&[9994, 127999], "Emoji_Modifier_Sequence", "raised fist: dark skin tone"),
+  (&[9995, 127995], "Emoji_Modifier_Sequence", "raised hand: light skin tone"),
+  (&[9995, 127996], "Emoji_Modifier_Sequence", "raised hand: medium-light skin tone"),
+  (&[9995, 127997], "Emoji_Modifier_Sequence", "raised hand: medium skin tone"),
+  (&[9995, 127998], "Emoji_Modifier_Sequence", "raised hand: medium-dark skin tone"),
+  (&[9995, 127999], "Emoji_Modifier_Sequence", "raised hand: dark skin tone"),
+  (&[9996, 127995], "Emoji_Modifier_Sequence", "victory hand: light skin tone"),
+  (&[9996, 127996], "Emoji_Modifier_Sequence", "victory hand: medium-light skin tone"),
+  (&[9996, 127997], "Emoji_Modifier_Sequence", "victory hand: medium skin tone"),
+  (&[9996, 127998], "Emoji_Modifier_Sequence", "victory hand: medium-dark skin tone"),
+  (&[9996, 127999], "Emoji_Modifier_Sequence", "victory hand: dark skin tone"),
+  (&[9997, 127995], "Emoji_Modifier_Sequence", "writing hand: light skin tone"),
+  (&[9997, 127996], "Emoji_Modifier_Sequence", "writing hand: medium-light skin tone"),
+  (&[9997, 127997], "Emoji_Modifier_Sequence", "writing hand: medium skin tone"),
+  (&[9997, 127998], "Emoji_Modifier_Sequence", "writing hand: medium-dark skin tone"),
+  (&[9997, 127999], "Emoji_Modifier_Sequence", "writing hand: dark skin tone"),
+  (&[127462, 127464], "Emoji_Flag_Sequence", "flag: Ascension Island"),
+  (&[127462, 127465], "Emoji_Flag_Sequence", "flag: Andorra"),
+  (&[127462, 127466], "Emoji_Flag_Sequence", "flag: United Arab Emirates"),
+  (&[127462, 127467], "Emoji_Flag_Sequence", "flag: Afghanistan"),
+  (&[127462, 127468], "Emoji_Flag_Sequence", "flag: Antigua & Barbuda"),
+  (&[127462, 127470], "Emoji_Flag_Sequence", "flag: Anguilla"),
+  (&[127462, 127473], "Emoji_Flag_Sequence", "flag: Albania"),
+  (&[127462, 127474], "Emoji_Flag_Sequence", "flag: Armenia"),
+  (&[127462, 127476], "Emoji_Flag_Sequence", "flag: Angola"),
+  (&[127462, 127478], "Emoji_Flag_Sequence", "flag: Antarctica"),
+  (&[127462, 127479], "Emoji_Flag_Sequence", "flag: Argentina"),
+  (&[127462, 127480], "Emoji_Flag_Sequence", "flag: American Samoa"),
+  (&[127462, 127481], "Emoji_Flag_Sequence", "flag: Austria"),
+  (&[127462, 127482], "Emoji_Flag_Sequence", "flag: Australia"),
+  (&[127462, 127484], "Emoji_Flag_Sequence", "flag: Aruba"),
+  (&[127462, 127485], "Emoji_Flag_Sequence", "flag: Åland Islands"),
+  (&[127462, 127487], "Emoji_Flag_Sequence", "flag: Azerbaijan"),
+  (&[127463, 127462], "Emoji_Flag_Sequence", "flag: Bosnia & Herzegovina"),
+  (&[127463, 127463], "Emoji_Flag_Sequence", "flag: Barbados"),
+  (&[127463, 127465], "Emoji_Flag_Sequence", "flag: Bangladesh"),
+  (&[127463, 127466], "Emoji_Flag_Sequence", "flag: Belgium"),
+  (&[127463, 127467], "Emoji_Flag_Sequence", "flag: Burkina Faso"),
+  (&[127463, 127468], "Emoji_Flag_Sequence", "flag: Bulgaria"),
+  (&[127463, 127469], "Emoji_Flag_Sequence", "flag: Bahrain"),
+  (&[127463, 127470], "Emoji_Flag_Sequence", "flag: Burundi"),
+  (&[127463, 127471], "Emoji_Flag_Sequence", "flag: Benin"),
+  (&[127463, 127473], "Emoji_Flag_Sequence", "flag: St. Barthélemy"),
+  (&[127463, 127474], "Emoji_Flag_Sequence", "flag: Bermuda"),
+  (&[127463, 127475], "Emoji_Flag_Sequence", "flag: Brunei"),
+  (&[127463, 127476], "Emoji_Flag_Sequence", "flag: Bolivia"),
+  (&[127463, 127478], "Emoji_Flag_Sequence", "flag: Caribbean Netherlands"),
+  (&[127463, 127479], "Emoji_Flag_Sequence", "flag: Brazil"),
+  (&[127463, 127480], "Emoji_Flag_Sequence", "flag: Bahamas"),
+  (&[127463, 127481], "Emoji_Flag_Sequence", "flag: Bhutan"),
+  (&[127463, 127483], "Emoji_Flag_Sequence", "flag: Bouvet Island"),
+  (&[127463, 127484], "Emoji_Flag_Sequence", "flag: Botswana"),
+  (&[127463, 127486], "Emoji_Flag_Sequence", "flag: Belarus"),
+  (&[127463, 127487], "Emoji_Flag_Sequence", "flag: Belize"),
+  (&[127464, 127462], "Emoji_Flag_Sequence", "flag: Canada"),
+  (&[127464, 127464], "Emoji_Flag_Sequence", "flag: Cocos (Keeling) Islands"),
+  (&[127464, 127465], "Emoji_Flag_Sequence", "flag: Congo - Kinshasa"),
+  (&[127464, 127467], "Emoji_Flag_Sequence", "flag: Central African Republic"),
+  (&[127464, 127468], "Emoji_Flag_Sequence", "flag: Congo - Brazzaville"),
+  (&[127464, 127469], "Emoji_Flag_Sequence", "flag: Switzerland"),
+  (&[127464, 127470], "Emoji_Flag_Sequence", "flag: Côte d’Ivoire"),
+  (&[127464, 127472], "Emoji_Flag_Sequence", "flag: Cook Islands"),
+  (&[127464, 127473], "Emoji_Flag_Sequence", "flag: Chile"),
+  (&[127464, 127474], "Emoji_Flag_Sequence", "flag: Cameroon"),
+  (&[127464, 127475], "Emoji_Flag_Sequence", "flag: China"),
+  (&[127464, 127476], "Emoji_Flag_Sequence", "flag: Colombia"),
+  (&[127464, 127477], "Emoji_Flag_Sequence", "flag: Clipperton Island"),
+  (&[127464, 127479], "Emoji_Flag_Sequence", "flag: Costa Rica"),
+  (&[127464, 127482], "Emoji_Flag_Sequence", "flag: Cuba"),
+  (&[127464, 127483], "Emoji_Flag_Sequence", "flag: Cape Verde"),
+  (&[127464, 127484], "Emoji_Flag_Sequence", "flag: Curaçao"),
+  (&[127464, 127485], "Emoji_Flag_Sequence", "flag: Christmas Island"),
+  (&[127464, 127486], "Emoji_Flag_Sequence", "flag: Cyprus"),
+  (&[127464, 127487], "Emoji_Flag_Sequence", "flag: Czechia"),
+  (&[127465, 127466], "Emoji_Flag_Sequence", "flag: Germany"),
+  (&[127465, 127468], "Emoji_Flag_Sequence", "flag: Diego Garcia"),
+  (&[127465, 127471], "Emoji_Flag_Sequence", "flag: Djibouti"),
+  (&[127465, 127472], "Emoji_Flag_Sequence", "flag: Denmark"),
+  (&[127465, 127474], "Emoji_Flag_Sequence", "flag: Dominica"),
+  (&[127465, 127476], "Emoji_Flag_Sequence", "flag: Dominican Republic"),
+  (&[127465, 127487], "Emoji_Flag_Sequence", "flag: Algeria"),
+  (&[127466, 127462], "Emoji_Flag_Sequence", "flag: Ceuta & Melilla"),
+  (&[127466, 127464], "Emoji_Flag_Sequence", "flag: Ecuador"),
+  (&[127466, 127466], "Emoji_Flag_Sequence", "flag: Estonia"),
+  (&[127466, 127468], "Emoji_Flag_Sequence", "flag: Egypt"),
+  (&[127466, 127469], "Emoji_Flag_Sequence", "flag: Western Sahara"),
+  (&[127466, 127479], "Emoji_Flag_Sequence", "flag: Eritrea"),
+  (&[127466, 127480], "Emoji_Flag_Sequence", "flag: Spain"),
+  (&[127466, 127481], "Emoji_Flag_Sequence", "flag: Ethiopia"),
+  (&[127466, 127482], "Emoji_Flag_Sequence", "flag: European Union"),
+  (&[127467, 127470], "Emoji_Flag_Sequence", "flag: Finland"),
+  (&[127467, 127471], "Emoji_Flag_Sequence", "flag: Fiji"),
+  (&[127467, 127472], "Emoji_Flag_Sequence", "flag: Falkland Islands"),
+  (&[127467, 127474], "Emoji_Flag_Sequence", "flag: Micronesia"),
+  (&[127467, 127476], "Emoji_Flag_Sequence", "flag: Faroe Islands"),
+  (&[127467, 127479], "Emoji_Flag_Sequence", "flag: France"),
+  (&[127468, 127462], "Emoji_Flag_Sequence", "flag: Gabon"),
+  (&[127468, 127463], "Emoji_Flag_Sequence", "flag: United Kingdom"),
+  (&[127468, 127465], "Emoji_Flag_Sequence", "flag: Grenada"),
+  (&[127468, 127466], "Emoji_Flag_Sequence", "flag: Georgia"),
+  (&[127468, 127467], "Emoji_Flag_Sequence", "flag: French Guiana"),
+  (&[127468, 127468], "Emoji_Flag_Sequence", "flag: Guernsey"),
+  (&[127468, 127469], "Emoji_Flag_Sequence", "flag: Ghana"),
+  (&[127468, 127470], "Emoji_Flag_Sequence", "flag: Gibraltar"),
+  (&[127468, 127473], "Emoji_Flag_Sequence", "flag: Greenland"),
+  (&[127468, 127474], "Emoji_Flag_Sequence", "flag: Gambia"),
+  (&[127468, 127475], "Emoji_Flag_Sequence", "flag: Guinea"),
+  (&[127468, 127477], "Emoji_Flag_Sequence", "flag: Guadeloupe"),
+  (&[127468, 127478], "Emoji_Flag_Sequence", "flag: Equatorial Guinea"),
+  (&[127468, 127479], "Emoji_Flag_Sequence", "flag: Greece"),
+  (&[127468, 127480], "Emoji_Flag_Sequence", "flag: South Georgia & South Sandwich Islands"),
+  (&[127468, 127481], "Emoji_Flag_Sequence", "flag: Guatemala"),
+  (&[127468, 127482], "Emoji_Flag_Sequence", "flag: Guam"),
+  (&[127468, 127484], "Emoji_Flag_Sequence", "flag: Guinea-Bissau"),
+  (&[127468, 127486], "Emoji_Flag_Sequence", "flag: Guyana"),
+  (&[127469, 127472], "Emoji_Flag_Sequence", "flag: Hong Kong SAR China"),
+  (&[127469, 127474], "Emoji_Flag_Sequence", "flag: Heard & McDonald Islands"),
+  (&[127469, 127475], "Emoji_Flag_Sequence", "flag: Honduras"),
+  (&[127469, 127479], "Emoji_Flag_Sequence", "flag: Croatia"),
+  (&[127469, 127481], "Emoji_Flag_Sequence", "flag: Haiti"),
+  (&[127469, 127482], "Emoji_Flag_Sequence", "flag: Hungary"),
+  (&[127470, 127464], "Emoji_Flag_Sequence", "flag: Canary Islands"),
+  (&[127470, 127465], "Emoji_Flag_Sequence", "flag: Indonesia"),
+  (&[127470, 127466], "Emoji_Flag_Sequence", "flag: Ireland"),
+  (&[127470, 127473], "Emoji_Flag_Sequence", "flag: Israel"),
+  (&[127470, 127474], "Emoji_Flag_Sequence", "flag: Isle of Man"),
+  (&[127470, 127475], "Emoji_Flag_Sequence", "flag: India"),
+  (&[127470, 127476], "Emoji_Flag_Sequence", "flag: British Indian Ocean Territory"),
+  (&[127470, 127478], "Emoji_Flag_Sequence", "flag: Iraq"),
+  (&[127470, 127479], "Emoji_Flag_Sequence", "flag: Iran"),
+  (&[127470, 127480], "Emoji_Flag_Sequence", "flag: Iceland"),
+  (&[127470, 127481], "Emoji_Flag_Sequence", "flag: Italy"),
+  (&[127471, 127466], "Emoji_Flag_Sequence", "flag: Jersey"),
+  (&[127471, 127474], "Emoji_Flag_Sequence", "flag: Jamaica"),
+  (&[127471, 127476], "Emoji_Flag_Sequence", "flag: Jordan"),
+  (&[127471, 127477], "Emoji_Flag_Sequence", "flag: Japan"),
+  (&[127472, 127466], "Emoji_Flag_Sequence", "flag: Kenya"),
+  (&[127472, 127468], "Emoji_Flag_Sequence", "flag: Kyrgyzstan"),
+  (&[127472, 127469], "Emoji_Flag_Sequence", "flag: Cambodia"),
+  (&[127472, 127470], "Emoji_Flag_Sequence", "flag: Kiribati"),
+  (&[127472, 127474], "Emoji_Flag_Sequence", "flag: Comoros"),
+  (&[127472, 127475], "Emoji_Flag_Sequence", "flag: St. Kitts & Nevis"),
+  (&[127472, 127477], "Emoji_Flag_Sequence", "flag: North Korea"),
+  (&[127472, 127479], "Emoji_Flag_Sequence", "flag: South Korea"),
+  (&[127472, 127484], "Emoji_Flag_Sequence", "flag: Kuwait"),
+  (&[127472, 127486], "Emoji_Flag_Sequence", "flag: Cayman Islands"),
+  (&[127472, 127487], "Emoji_Flag_Sequence", "flag: Kazakhstan"),
+  (&[127473, 127462], "Emoji_Flag_Sequence", "flag: Laos"),
+  (&[127473, 127463], "Emoji_Flag_Sequence", "flag: Lebanon"),
+  (&[127473, 127464], "Emoji_Flag_Sequence", "flag: St. Lucia"),
+  (&[127473, 127470], "Emoji_Flag_Sequence", "flag: Liechtenstein"),
+  (&[127473, 127472], "Emoji_Flag_Sequence", "flag: Sri Lanka"),
+  (&[127473, 127479], "Emoji_Flag_Sequence", "flag: Liberia"),
+  (&[127473, 127480], "Emoji_Flag_Sequence", "flag: Lesotho"),
+  (&[127473, 127481], "Emoji_Flag_Sequence", "flag: Lithuania"),
+  (&[127473, 127482], "Emoji_Flag_Sequence", "flag: Luxembourg"),
+  (&[127473, 127483], "Emoji_Flag_Sequence", "flag: Latvia"),
+  (&[127473, 127486], "Emoji_Flag_Sequence", "flag: Libya"),
+  (&[127474, 127462], "Emoji_Flag_Sequence", "flag: Morocco"),
+  (&[127474, 127464], "Emoji_Flag_Sequence", "flag: Monaco"),
+  (&[127474, 127465], "Emoji_Flag_Sequence", "flag: Moldova"),
+  (&[127474, 127466], "Emoji_Flag_Sequence", "flag: Montenegro"),
+  (&[127474, 127467], "Emoji_Flag_Sequence", "flag: St. Martin"),
+  (&[127474, 127468], "Emoji_Flag_Sequence", "flag: Madagascar"),
+  (&[127474, 127469], "Emoji_Flag_Sequence", "flag: Marshall Islands"),
+  (&[127474, 127472], "Emoji_Flag_Sequence", "flag: North Macedonia"),
+  (&[127474, 127473], "Emoji_Flag_Sequence", "flag: Mali"),
+  (&[127474, 127474], "Emoji_Flag_Sequence", "flag: Myanmar (Burma)"),
+  (&[127474, 127475], "Emoji_Flag_Sequence", "flag: Mongolia"),
+  (&[127474, 127476], "Emoji_Flag_Sequence", "flag: Macao SAR China"),
+  (&[127474, 127477], "Emoji_Flag_Sequence", "flag: Northern Mariana Islands"),
+  (&[127474, 127478], "Emoji_Flag_Sequence", "flag: Martinique"),
+  (&[127474, 127479], "Emoji_Flag_Sequence", "flag: Mauritania"),
+  (&[127474, 127480], "Emoji_Flag_Sequence", "flag: Montserrat"),
+  (&[127474, 127481], "Emoji_Flag_Sequence", "flag: Malta"),
+  (&[127474, 127482], "Emoji_Flag_Sequence", "flag: Mauritius"),
+  (&[127474, 127483], "Emoji_Flag_Sequence", "flag: Maldives"),
+  (&[127474, 127484], "Emoji_Flag_Sequence", "flag: Malawi"),
+  (&[127474, 127485], "Emoji_Flag_Sequence", "flag: Mexico"),
+  (&[127474, 127486], "Emoji_Flag_Sequence", "flag: Malaysia"),
+  (&[127474, 127487], "Emoji_Flag_Sequence", "flag: Mozambique"),
+  (&[127475, 127462], "Emoji_Flag_Sequence", "flag: Namibia"),
+  (&[127475, 127464], "Emoji_Flag_Sequence", "flag: New Caledonia"),
+  (&[127475, 127466], "Emoji_Flag_Sequence", "flag: Niger"),
+  (&[127475, 127467], "Emoji_Flag_Sequence", "flag: Norfolk Island"),
+  (&[127475, 127468], "Emoji_Flag_Sequence", "flag: Nigeria"),
+  (&[127475, 127470], "Emoji_Flag_Sequence", "flag: Nicaragua"),
+  (&[127475, 127473], "Emoji_Flag_Sequence", "flag: Netherlands"),
+  (&[127475, 127476], "Emoji_Flag_Sequence", "flag: Norway"),
+  (&[127475, 127477], "Emoji_Flag_Sequence", "flag: Nepal"),
+  (&[127475, 127479], "Emoji_Flag_Sequence", "flag: Nauru"),
+  (&[127475, 127482], "Emoji_Flag_Sequence", "flag: Niue"),
+  (&[127475, 127487], "Emoji_Flag_Sequence", "flag: New Zealand"),
+  (&[127476, 127474], "Emoji_Flag_Sequence", "flag: Oman"),
+  (&[127477, 127462], "Emoji_Flag_Sequence", "flag: Panama"),
+  (&[127477, 127466], "Emoji_Flag_Sequence", "flag: Peru"),
+  (&[127477, 127467], "Emoji_Flag_Sequence", "flag: French Polynesia"),
+  (&[127477, 127468], "Emoji_Flag_Sequence", "flag: Papua New Guinea"),
+  (&[127477, 127469], "Emoji_Flag_Sequence", "flag: Philippines"),
+  (&[127477, 127472], "Emoji_Flag_Sequence", "flag: Pakistan"),
+  (&[127477, 127473], "Emoji_Flag_Sequence", "flag: Poland"),
+  (&[127477, 127474], "Emoji_Flag_Sequence", "flag: St. Pierre & Miquelon"),
+  (&[127477, 127475], "Emoji_Flag_Sequence", "flag: Pitcairn Islands"),
+  (&[127477, 127479], "Emoji_Flag_Sequence", "flag: Puerto Rico"),
+  (&[127477, 127480], "Emoji_Flag_Sequence", "flag: Palestinian Territories"),
+  (&[127477, 127481], "Emoji_Flag_Sequence", "flag: Portugal"),
+  (&[127477, 127484], "Emoji_Flag_Sequence", "flag: Palau"),
+  (&[127477, 127486], "Emoji_Flag_Sequence", "flag: Paraguay"),
+  (&[127478, 127462], "Emoji_Flag_Sequence", "flag: Qatar"),
+  (&[127479, 127466], "Emoji_Flag_Sequence", "flag: Réunion"),
+  (&[127479, 127476], "Emoji_Flag_Sequence", "flag: Romania"),
+  (&[127479, 127480], "Emoji_Flag_Sequence", "flag: Serbia"),
+  (&[127479, 127482], "Emoji_Flag_Sequence", "flag: Russia"),
+  (&[127479, 127484], "Emoji_Flag_Sequence", "flag: Rwanda"),
+  (&[127480, 127462], "Emoji_Flag_Sequence", "flag: Saudi Arabia"),
+  (&[127480, 127463], "Emoji_Flag_Sequence", "flag: Solomon Islands"),
+  (&[127480, 127464], "Emoji_Flag_Sequence", "flag: Seychelles"),
+  (&[127480, 127465], "Emoji_Flag_Sequence", "flag: Sudan"),
+  (&[127480, 127466], "Emoji_Flag_Sequence", "flag: Sweden"),
+  (&[127480, 127468], "Emoji_Flag_Sequence", "flag: Singapore"),
+  (&[127480, 127469], "Emoji_Flag_Sequence", "flag: St. Helena"),
+  (&[127480, 127470], "Emoji_Flag_Sequence", "flag: Slovenia"),
+  (&[127480, 127471], "Emoji_Flag_Sequence", "flag: Svalbard & Jan Mayen"),
+  (&[127480, 127472], "Emoji_Flag_Sequence", "flag: Slovakia"),
+  (&[127480, 127473], "Emoji_Flag_Sequence", "flag: Sierra Leone"),
+  (&[127480, 127474], "Emoji_Flag_Sequence", "flag: San Marino"),
+  (&[127480, 127475], "Emoji_Flag_Sequence", "flag: Senegal"),
+  (&[127480, 127476], "Emoji_Flag_Sequence", "flag: Somalia"),
+  (&[127480, 127479], "Emoji_Flag_Sequence", "flag: Suriname"),
+  (&[127480, 127480], "Emoji_Flag_Sequence", "flag: South Sudan"),
+  (&[127480, 127481], "Emoji_Flag_Sequence", "flag: São Tomé & Príncipe"),
+  (&[127480, 127483], "Emoji_Flag_Sequence", "flag: El Salvador"),
+  (&[127480, 127485], "Emoji_Flag_Sequence", "flag: Sint Maarten"),
+  (&[127480, 127486], "Emoji_Flag_Sequence", "flag: Syria"),
+  (&[127480, 127487], "Emoji_Flag_Sequence", "flag: Eswatini"),
+  (&[127481, 127462], "Emoji_Flag_Sequence", "flag: Tristan da Cunha"),
+  (&[127481, 127464], "Emoji_Flag_Sequence", "flag: Turks & Caicos Islands"),
+  (&[127481, 127465], "Emoji_Flag_Sequence", "flag: Chad"),
+  (&[127481, 127467], "Emoji_Flag_Sequence", "flag: French Southern Territories"),
+  (&[127481, 127468], "Emoji_Flag_Sequence", "flag: Togo"),
+  (&[127481, 127469], "Emoji_Flag_Sequence", "flag: Thailand"),
+  (&[127481, 127471], "Emoji_Flag_Sequence", "flag: Tajikistan"),
+  (&[127481, 127472], "Emoji_Flag_Sequence", "flag: Tokelau"),
+  (&[127481, 127473], "Emoji_Flag_Sequence", "flag: Timor-Leste"),
+  (&[127481, 127474], "Emoji_Flag_Sequence", "flag: Turkmenistan"),
+  (&[127481, 127475], "Emoji_Flag_Sequence", "flag: Tunisia"),
+  (&[127481, 127476], "Emoji_Flag_Sequence", "flag: Tonga"),
+  (&[127481, 127479], "Emoji_Flag_Sequence", "flag: Türkiye"),
+  (&[127481, 127481], "Emoji_Flag_Sequence", "flag: Trinidad & Tobago"),
+  (&[127481, 127483], "Emoji_Flag_Sequence", "flag: Tuvalu"),
+  (&[127481, 127484], "Emoji_Flag_Sequence", "flag: Taiwan"),
+  (&[127481, 127487], "Emoji_Flag_Sequence", "flag: Tanzania"),
+  (&[127482, 127462], "Emoji_Flag_Sequence", "flag: Ukraine"),
+  (&[127482, 127468], "Emoji_Flag_Sequence", "flag: Uganda"),
+  (&[127482, 127474], "Emoji_Flag_Sequence", "flag: U.S. Outlying Islands"),
+  (&[127482, 127475], "Emoji_Flag_Sequence", "flag: United Nations"),
+  (&[127482, 127480], "Emoji_Flag_Sequence", "flag: United States"),
+  (&[127482, 127486], "Emoji_Flag_Sequence", "flag: Uruguay"),
+  (&[127482, 127487], "Emoji_Flag_Sequence", "flag: Uzbekistan"),
+  (&[127483, 127462], "Emoji_Flag_Sequence", "flag: Vatican City"),
+  (&[127483, 127464], "Emoji_Flag_Sequence", "flag: St. Vincent & Grenadines"),
+  (&[127483, 127466], "Emoji_Flag_Sequence", "flag: Venezuela"),
+  (&[127483, 127468], "Emoji_Flag_Sequence", "flag: British Virgin Islands"),
+  (&[127483, 127470], "Emoji_Flag_Sequence", "flag: U.S. Virgin Islands"),
+  (&[127483, 127475], "Emoji_Flag_Sequence", "flag: Vietnam"),
+  (&[127483, 127482], "Emoji_Flag_Sequence", "flag: Vanuatu"),
+  (&[127484, 127467], "Emoji_Flag_Sequence", "flag: Wallis & Futuna"),
+  (&[127484, 127480], "Emoji_Flag_Sequence", "flag: Samoa"),
+  (&[127485, 127472], "Emoji_Flag_Sequence", "flag: Kosovo"),
+  (&[127486, 127466], "Emoji_Flag_Sequence", "flag: Yemen"),
+  (&[127486, 127481], "Emoji_Flag_Sequence", "flag: Mayotte"),
+  (&[127487, 127462], "Emoji_Flag_Sequence", "flag: South Africa"),
+  (&[127487, 127474], "Emoji_Flag_Sequence", "flag: Zambia"),
+  (&[127487, 127484], "Emoji_Flag_Sequence", "flag: Zimbabwe"),
+  (&[127877, 127995], "Emoji_Modifier_Sequence", "Santa Claus: light skin tone"),
+  (&[127877, 127996], "Emoji_Modifier_Sequence", "Santa Claus: medium-light skin tone"),
+  (&[127877, 127997], "Emoji_Modifier_Sequence", "Santa Claus: medium skin tone"),
+  (&[127877, 127998], "Emoji_Modifier_Sequence", "Santa Claus: medium-dark skin tone"),
+  (&[127877, 127999], "Emoji_Modifier_Sequence", "Santa Claus: dark skin tone"),
+  (&[127938, 127995], "Emoji_Modifier_Sequence", "snowboarder: light skin tone"),
+  (&[127938, 127996], "Emoji_Modifier_Sequence", "snowboarder: medium-light skin tone"),
+  (&[127938, 127997], "Emoji_Modifier_Sequence", "snowboarder: medium skin tone"),
+  (&[127938, 127998], "Emoji_Modifier_Sequence", "snowboarder: medium-dark skin tone"),
+  (&[127938, 127999], "Emoji_Modifier_Sequence", "snowboarder: dark skin tone"),
+  (&[127939, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman running"),
+  (&[127939, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man running"),
+  (&[127939, 127995], "Emoji_Modifier_Sequence", "person running: light skin tone"),
+  (&[127939, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman running: light skin tone"),
+  (&[127939, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man running: light skin tone"),
+  (&[127939, 127996], "Emoji_Modifier_Sequence", "person running: medium-light skin tone"),
+  (&[127939, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman running: medium-light skin tone"),
+  (&[127939, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man running: medium-light skin tone"),
+  (&[127939, 127997], "Emoji_Modifier_Sequence", "person running: medium skin tone"),
+  (&[127939, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman running: medium skin tone"),
+  (&[127939, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man running: medium skin tone"),
+  (&[127939, 127998], "Emoji_Modifier_Sequence", "person running: medium-dark skin tone"),
+  (&[127939, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman running: medium-dark skin tone"),
+  (&[127939, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man running: medium-dark skin tone"),
+  (&[127939, 127999], "Emoji_Modifier_Sequence", "person running: dark skin tone"),
+  (&[127939, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman running: dark skin tone"),
+  (&[127939, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man running: dark skin tone"),
+  (&[127940, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman surfing"),
+  (&[127940, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man surfing"),
+  (&[127940, 127995], "Emoji_Modifier_Sequence", "person surfing: light skin tone"),
+  (&[127940, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman surfing: light skin tone"),
+  (&[127940, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man surfing: light skin tone"),
+  (&[127940, 127996], "Emoji_Modifier_Sequence", "person surfing: medium-light skin tone"),
+  (&[127940, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman surfing: medium-light skin tone"),
+  (&[127940, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man surfing: medium-light skin tone"),
+  (&[127940, 127997], "Emoji_Modifier_Sequence", "person surfing: medium skin tone"),
+  (&[127940, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman surfing: medium skin tone"),
+  (&[127940, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man surfing: medium skin tone"),
+  (&[127940, 127998], "Emoji_Modifier_Sequence", "person surfing: medium-dark skin tone"),
+  (&[127940, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman surfing: medium-dark skin tone"),
+  (&[127940, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man surfing: medium-dark skin tone"),
+  (&[127940, 127999], "Emoji_Modifier_Sequence", "person surfing: dark skin tone"),
+  (&[127940, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman surfing: dark skin tone"),
+  (&[127940, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man surfing: dark skin tone"),
+  (&[127943, 127995], "Emoji_Modifier_Sequence", "horse racing: light skin tone"),
+  (&[127943, 127996], "Emoji_Modifier_Sequence", "horse racing: medium-light skin tone"),
+  (&[127943, 127997], "Emoji_Modifier_Sequence", "horse racing: medium skin tone"),
+  (&[127943, 127998], "Emoji_Modifier_Sequence", "horse racing: medium-dark skin tone"),
+  (&[127943, 127999], "Emoji_Modifier_Sequence", "horse racing: dark skin tone"),
+  (&[127946, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman swimming"),
+  (&[127946, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man swimming"),
+  (&[127946, 127995], "Emoji_Modifier_Sequence", "person swimming: light skin tone"),
+  (&[127946, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman swimming: light skin tone"),
+  (&[127946, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man swimming: light skin tone"),
+  (&[127946, 127996], "Emoji_Modifier_Sequence", "person swimming: medium-light skin tone"),
+  (&[127946, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman swimming: medium-light skin tone"),
+  (&[127946, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man swimming: medium-light skin tone"),
+  (&[127946, 127997], "Emoji_Modifier_Sequence", "person swimming: medium skin tone"),
+  (&[127946, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman swimming: medium skin tone"),
+  (&[127946, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man swimming: medium skin tone"),
+  (&[127946, 127998], "Emoji_Modifier_Sequence", "person swimming: medium-dark skin tone"),
+  (&[127946, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman swimming: medium-dark skin tone"),
+  (&[127946, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man swimming: medium-dark skin tone"),
+  (&[127946, 127999], "Emoji_Modifier_Sequence", "person swimming: dark skin tone"),
+  (&[127946, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman swimming: dark skin tone"),
+  (&[127946, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man swimming: dark skin tone"),
+  (&[127947, 65039, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman lifting weights"),
+  (&[127947, 65039, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man lifting weights"),
+  (&[127947, 127995], "Emoji_Modifier_Sequence", "person lifting weights: light skin tone"),
+  (&[127947, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman lifting weights: light skin tone"),
+  (&[127947, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man lifting weights: light skin tone"),
+  (&[127947, 127996], "Emoji_Modifier_Sequence", "person lifting weights: medium-light skin tone"),
+  (&[127947, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman lifting weights: medium-light skin tone"),
+  (&[127947, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man lifting weights: medium-light skin tone"),
+  (&[127947, 127997], "Emoji_Modifier_Sequence", "person lifting weights: medium skin tone"),
+  (&[127947, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman lifting weights: medium skin tone"),
+  (&[127947, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man lifting weights: medium skin tone"),
+  (&[127947, 127998], "Emoji_Modifier_Sequence", "person lifting weights: medium-dark skin tone"),
+  (&[127947, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman lifting weights: medium-dark skin tone"),
+  (&[127947, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man lifting weights: medium-dark skin tone"),
+  (&[127947, 127999], "Emoji_Modifier_Sequence", "person lifting weights: dark skin tone"),
+  (&[127947, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman lifting weights: dark skin tone"),
+  (&[127947, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man lifting weights: dark skin tone"),
+  (&[127948, 65039, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman golfing"),
+  (&[127948, 65039, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man golfing"),
+  (&[127948, 127995], "Emoji_Modifier_Sequence", "person golfing: light skin tone"),
+  (&[127948, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman golfing: light skin tone"),
+  (&[127948, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man golfing: light skin tone"),
+  (&[127948, 127996], "Emoji_Modifier_Sequence", "person golfing: medium-light skin tone"),
+  (&[127948, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman golfing: medium-light skin tone"),
+  (&[127948, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man golfing: medium-light skin tone"),
+  (&[127948, 127997], "Emoji_Modifier_Sequence", "person golfing: medium skin tone"),
+  (&[127948, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman golfing: medium skin tone"),
+  (&[127948, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man golfing: medium skin tone"),
+  (&[127948, 127998], "Emoji_Modifier_Sequence", "person golfing: medium-dark skin tone"),
+  (&[127948, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman golfing: medium-dark skin tone"),
+  (&[127948, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man golfing: medium-dark skin tone"),
+  (&[127948, 127999], "Emoji_Modifier_Sequence", "person golfing: dark skin tone"),
+  (&[127948, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman golfing: dark skin tone"),
+  (&[127948, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man golfing: dark skin tone"),
+  (&[127987, 65039, 8205, 127752], "Emoji_ZWJ_Sequence", "rainbow flag"),
+  (&[127988, 917607, 917602, 917605, 917614, 917607, 917631], "Emoji_Tag_Sequence", "flag: England"),
+  (&[127988, 917607, 917602, 917619, 917603, 917620, 917631], "Emoji_Tag_Sequence", "flag: Scotland"),
+  (&[127988, 917607, 917602, 917623, 917612, 917619, 917631], "Emoji_Tag_Sequence", "flag: Wales"),
+  (&[128065, 65039, 8205, 128488, 65039], "Emoji_ZWJ_Sequence", "eye in speech bubble"),
+  (&[128066, 127995], "Emoji_Modifier_Sequence", "ear: light skin tone"),
+  (&[128066, 127996], "Emoji_Modifier_Sequence", "ear: medium-light skin tone"),
+  (&[128066, 127997], "Emoji_Modifier_Sequence", "ear: medium skin tone"),
+  (&[128066, 127998], "Emoji_Modifier_Sequence", "ear: medium-dark skin tone"),
+  (&[128066, 127999], "Emoji_Modifier_Sequence", "ear: dark skin tone"),
+  (&[128067, 127995], "Emoji_Modifier_Sequence", "nose: light skin tone"),
+  (&[128067, 127996], "Emoji_Modifier_Sequence", "nose: medium-light skin tone"),
+  (&[128067, 127997], "Emoji_Modifier_Sequence", "nose: medium skin tone"),
+  (&[128067, 127998], "Emoji_Modifier_Sequence", "nose: medium-dark skin tone"),
+  (&[128067, 127999], "Emoji_Modifier_Sequence", "nose: dark skin tone"),
+  (&[128070, 127995], "Emoji_Modifier_Sequence", "backhand index pointing up: light skin tone"),
+  (&[128070, 127996], "Emoji_Modifier_Sequence", "backhand index pointing up: medium-light skin tone"),
+  (&[128070, 127997], "Emoji_Modifier_Sequence", "backhand index pointing up: medium skin tone"),
+  (&[128070, 127998], "Emoji_Modifier_Sequence", "backhand index pointing up: medium-dark skin tone"),
+  (&[128070, 127999], "Emoji_Modifier_Sequence", "backhand index pointing up: dark skin tone"),
+  (&[128071, 127995], "Emoji_Modifier_Sequence", "backhand index pointing down: light skin tone"),
+  (&[128071, 127996], "Emoji_Modifier_Sequence", "backhand index pointing down: medium-light skin tone"),
+  (&[128071, 127997], "Emoji_Modifier_Sequence", "backhand index pointing down: medium skin tone"),
+  (&[128071, 127998], "Emoji_Modifier_Sequence", "backhand index pointing down: medium-dark skin tone"),
+  (&[128071, 127999], "Emoji_Modifier_Sequence", "backhand index pointing down: dark skin tone"),
+  (&[128072, 127995], "Emoji_Modifier_Sequence", "backhand index pointing left: light skin tone"),
+  (&[128072, 127996], "Emoji_Modifier_Sequence", "backhand index pointing left: medium-light skin tone"),
+  (&[128072, 127997], "Emoji_Modifier_Sequence", "backhand index pointing left: medium skin tone"),
+  (&[128072, 127998], "Emoji_Modifier_Sequence", "backhand index pointing left: medium-dark skin tone"),
+  (&[128072, 127999], "Emoji_Modifier_Sequence", "backhand index pointing left: dark skin tone"),
+  (&[128073, 127995], "Emoji_Modifier_Sequence", "backhand index pointing right: light skin tone"),
+  (&[128073, 127996], "Emoji_Modifier_Sequence", "backhand index pointing right: medium-light skin tone"),
+  (&[128073, 127997], "Emoji_Modifier_Sequence", "backhand index pointing right: medium skin tone"),
+  (&[128073, 127998], "Emoji_Modifier_Sequence", "backhand index pointing right: medium-dark skin tone"),
+  (&[128073, 127999], "Emoji_Modifier_Sequence", "backhand index pointing right: dark skin tone"),
+  (&[128074, 127995], "Emoji_Modifier_Sequence", "oncoming fist: light skin tone"),
+  (&[128074, 127996], "Emoji_Modifier_Sequence", "oncoming fist: medium-light skin tone"),
+  (&[128074, 127997], "Emoji_Modifier_Sequence", "oncoming fist: medium skin tone"),
+  (&[128074, 127998], "Emoji_Modifier_Sequence", "oncoming fist: medium-dark skin tone"),
+  (&[128074, 127999], "Emoji_Modifier_Sequence", "oncoming fist: dark skin tone"),
+  (&[128075, 127995], "Emoji_Modifier_Sequence", "waving hand: light skin tone"),
+  (&[128075, 127996], "Emoji_Modifier_Sequence", "waving hand: medium-light skin tone"),
+  (&[128075, 127997], "Emoji_Modifier_Sequence", "waving hand: medium skin tone"),
+  (&[128075, 127998], "Emoji_Modifier_Sequence", "waving hand: medium-dark skin tone"),
+  (&[128075, 127999], "Emoji_Modifier_Sequence", "waving hand: dark skin tone"),
+  (&[128076, 127995], "Emoji_Modifier_Sequence", "OK hand: light skin tone"),
+  (&[128076, 127996], "Emoji_Modifier_Sequence", "OK hand: medium-light skin tone"),
+  (&[128076, 127997], "Emoji_Modifier_Sequence", "OK hand: medium skin tone"),
+  (&[128076, 127998], "Emoji_Modifier_Sequence", "OK hand: medium-dark skin tone"),
+  (&[128076, 127999], "Emoji_Modifier_Sequence", "OK hand: dark skin tone"),
+  (&[128077, 127995], "Emoji_Modifier_Sequence", "thumbs up: light skin tone"),
+  (&[128077, 127996], "Emoji_Modifier_Sequence", "thumbs up: medium-light skin tone"),
+  (&[128077, 127997], "Emoji_Modifier_Sequence", "thumbs up: medium skin tone"),
+  (&[128077, 127998], "Emoji_Modifier_Sequence", "thumbs up: medium-dark skin tone"),
+  (&[128077, 127999], "Emoji_Modifier_Sequence", "thumbs up: dark skin tone"),
+  (&[128078, 127995], "Emoji_Modifier_Sequence", "thumbs down: light skin tone"),
+  (&[128078, 127996], "Emoji_Modifier_Sequence", "thumbs down: medium-light skin tone"),
+  (&[128078, 127997], "Emoji_Modifier_Sequence", "thumbs down: medium skin tone"),
+  (&[128078, 127998], "Emoji_Modifier_Sequence", "thumbs down: medium-dark skin tone"),
+  (&[128078, 127999], "Emoji_Modifier_Sequence", "thumbs down: dark skin tone"),
+  (&[128079, 127995], "Emoji_Modifier_Sequence", "clapping hands: light skin tone"),
+  (&[128079, 127996], "Emoji_Modifier_Sequence", "clapping hands: medium-light skin tone"),
+  (&[128079, 127997], "Emoji_Modifier_Sequence", "clapping hands: medium skin tone"),
+  (&[128079, 127998], "Emoji_Modifier_Sequence", "clapping hands: medium-dark skin tone"),
+  (&[128079, 127999], "Emoji_Modifier_Sequence", "clapping hands: dark skin tone"),
+  (&[128080, 127995], "Emoji_Modifier_Sequence", "open hands: light skin tone"),
+  (&[128080, 127996], "Emoji_Modifier_Sequence", "open hands: medium-light skin tone"),
+  (&[128080, 127997], "Emoji_Modifier_Sequence", "open hands: medium skin tone"),
+  (&[128080, 127998], "Emoji_Modifier_Sequence", "open hands: medium-dark skin tone"),
+  (&[128080, 127999], "Emoji_Modifier_Sequence", "open hands: dark skin tone"),
+  (&[128102, 127995], "Emoji_Modifier_Sequence", "boy: light skin tone"),
+  (&[128102, 127996], "Emoji_Modifier_Sequence", "boy: medium-light skin tone"),
+  (&[128102, 127997], "Emoji_Modifier_Sequence", "boy: medium skin tone"),
+  (&[128102, 127998], "Emoji_Modifier_Sequence", "boy: medium-dark skin tone"),
+  (&[128102, 127999], "Emoji_Modifier_Sequence", "boy: dark skin tone"),
+  (&[128103, 127995], "Emoji_Modifier_Sequence", "girl: light skin tone"),
+  (&[128103, 127996], "Emoji_Modifier_Sequence", "girl: medium-light skin tone"),
+  (&[128103, 127997], "Emoji_Modifier_Sequence", "girl: medium skin tone"),
+  (&[128103, 127998], "Emoji_Modifier_Sequence", "girl: medium-dark skin tone"),
+  (&[128103, 127999], "Emoji_Modifier_Sequence", "girl: dark skin tone"),
+  (&[128104, 8205, 9877, 65039], "Emoji_ZWJ_Sequence", "man health worker"),
+  (&[128104, 8205, 9878, 65039], "Emoji_ZWJ_Sequence", "man judge"),
+  (&[128104, 8205, 9992, 65039], "Emoji_ZWJ_Sequence", "man pilot"),
+  (&[128104, 8205, 10084, 65039, 8205, 128104], "Emoji_ZWJ_Sequence", "couple with heart: man, man"),
+  (&[128104, 8205, 10084, 65039, 8205, 128139, 8205, 128104], "Emoji_ZWJ_Sequence", "kiss: man, man"),
+  (&[128104, 8205, 127806], "Emoji_ZWJ_Sequence", "man farmer"),
+  (&[128104, 8205, 127859], "Emoji_ZWJ_Sequence", "man cook"),
+  (&[128104, 8205, 127891], "Emoji_ZWJ_Sequence", "man student"),
+  (&[128104, 8205, 127908], "Emoji_ZWJ_Sequence", "man singer"),
+  (&[128104, 8205, 127912], "Emoji_ZWJ_Sequence", "man artist"),
+  (&[128104, 8205, 127979], "Emoji_ZWJ_Sequence", "man teacher"),
+  (&[128104, 8205, 127981], "Emoji_ZWJ_Sequence", "man factory worker"),
+  (&[128104, 8205, 128102], "Emoji_ZWJ_Sequence", "family: man, boy"),
+  (&[128104, 8205, 128102, 8205, 128102], "Emoji_ZWJ_Sequence", "family: man, boy, boy"),
+  (&[128104, 8205, 128103], "Emoji_ZWJ_Sequence", "family: man, girl"),
+  (&[128104, 8205, 128103, 8205, 128102], "Emoji_ZWJ_Sequence", "family: man, girl, boy"),
+  (&[128104, 8205, 128103, 8205, 128103], "Emoji_ZWJ_Sequence", "family: man, girl, girl"),
+  (&[128104, 8205, 128104, 8205, 128102], "Emoji_ZWJ_Sequence", "family: man, man, boy"),
+  (&[128104, 8205, 128104, 8205, 128102, 8205, 128102], "Emoji_ZWJ_Sequence", "family: man, man, boy, boy"),
+  (&[128104, 8205, 128104, 8205, 128103], "Emoji_ZWJ_Sequence", "family: man, man, girl"),
+  (&[128104, 8205, 128104, 8205, 128103, 8205, 128102], "Emoji_ZWJ_Sequence", "family: man, man, girl, boy"),
+  (&[128104, 8205, 128104, 8205, 128103, 8205, 128103], "Emoji_ZWJ_Sequence", "family: man, man, girl, girl"),
+  (&[128104, 8205, 128105, 8205, 128102], "Emoji_ZWJ_Sequence", "family: man, woman, boy"),
+  (&[128104, 8205, 128105, 8205, 128102, 8205, 128102], "Emoji_ZWJ_Sequence", "family: man, woman, boy, boy"),
+  (&[128104, 8205, 128105, 8205, 128103], "Emoji_ZWJ_Sequence", "family: man, woman, girl"),
+  (&[128104, 8205, 128105, 8205, 128103, 8205, 128102], "Emoji_ZWJ_Sequence", "family: man, woman, girl, boy"),
+  (&[128104, 8205, 128105, 8205, 128103, 8205, 128103], "Emoji_ZWJ_Sequence", "family: man, woman, girl, girl"),
+  (&[128104, 8205, 128187], "Emoji_ZWJ_Sequence", "man technologist"),
+  (&[128104, 8205, 128188], "Emoji_ZWJ_Sequence", "man office worker"),
+  (&[128104, 8205, 128295], "Emoji_ZWJ_Sequence", "man mechanic"),
+  (&[128104, 8205, 128300], "Emoji_ZWJ_Sequence", "man scientist"),
+  (&[128104, 8205, 128640], "Emoji_ZWJ_Sequence", "man astronaut"),
+  (&[128104, 8205, 128658], "Emoji_ZWJ_Sequence", "man firefighter"),
+  (&[128104, 127995], "Emoji_Modifier_Sequence", "man: light skin tone"),
+  (&[128104, 127995, 8205, 9877, 65039], "Emoji_ZWJ_Sequence", "man health worker: light skin tone"),
+  (&[128104, 127995, 8205, 9878, 65039], "Emoji_ZWJ_Sequence", "man judge: light skin tone"),
+  (&[128104, 127995, 8205, 9992, 65039], "Emoji_ZWJ_Sequence", "man pilot: light skin tone"),
+  (&[128104, 127995, 8205, 127806], "Emoji_ZWJ_Sequence", "man farmer: light skin tone"),
+  (&[128104, 127995, 8205, 127859], "Emoji_ZWJ_Sequence", "man cook: light skin tone"),
+  (&[128104, 127995, 8205, 127891], "Emoji_ZWJ_Sequence", "man student: light skin tone"),
+  (&[128104, 127995, 8205, 127908], "Emoji_ZWJ_Sequence", "man singer: light skin tone"),
+  (&[128104, 127995, 8205, 127912], "Emoji_ZWJ_Sequence", "man artist: light skin tone"),
+  (&[128104, 127995, 8205, 127979], "Emoji_ZWJ_Sequence", "man teacher: light skin tone"),
+  (&[128104, 127995, 8205, 127981], "Emoji_ZWJ_Sequence", "man factory worker: light skin tone"),
+  (&[128104, 127995, 8205, 128187], "Emoji_ZWJ_Sequence", "man technologist: light skin tone"),
+  (&[128104, 127995, 8205, 128188], "Emoji_ZWJ_Sequence", "man office worker: light skin tone"),
+  (&[128104, 127995, 8205, 128295], "Emoji_ZWJ_Sequence", "man mechanic: light skin tone"),
+  (&[128104, 127995, 8205, 128300], "Emoji_ZWJ_Sequence", "man scientist: light skin tone"),
+  (&[128104, 127995, 8205, 128640], "Emoji_ZWJ_Sequence", "man astronaut: light skin tone"),
+  (&[128104, 127995, 8205, 128658], "Emoji_ZWJ_Sequence", "man firefighter: light skin tone"),
+  (&[128104, 127996], "Emoji_Modifier_Sequence", "man: medium-light skin tone"),
+  (&[128104, 127996, 8205, 9877, 65039], "Emoji_ZWJ_Sequence", "man health worker: medium-light skin tone"),
+  (&[128104, 127996, 8205, 9878, 65039], "Emoji_ZWJ_Sequence", "man judge: medium-light skin tone"),
+  (&[128104, 127996, 8205, 9992, 65039], "Emoji_ZWJ_Sequence", "man pilot: medium-light skin tone"),
+  (&[128104, 127996, 8205, 127806], "Emoji_ZWJ_Sequence", "man farmer: medium-light skin tone"),
+  (&[128104, 127996, 8205, 127859], "Emoji_ZWJ_Sequence", "man cook: medium-light skin tone"),
+  (&[128104, 127996, 8205, 127891], "Emoji_ZWJ_Sequence", "man student: medium-light skin tone"),
+  (&[128104, 127996, 8205, 127908], "Emoji_ZWJ_Sequence", "man singer: medium-light skin tone"),
+  (&[128104, 127996, 8205, 127912], "Emoji_ZWJ_Sequence", "man artist: medium-light skin tone"),
+  (&[128104, 127996, 8205, 127979], "Emoji_ZWJ_Sequence", "man teacher: medium-light skin tone"),
+  (&[128104, 127996, 8205, 127981], "Emoji_ZWJ_Sequence", "man factory worker: medium-light skin tone"),
+  (&[128104, 127996, 8205, 128187], "Emoji_ZWJ_Sequence", "man technologist: medium-light skin tone"),
+  (&[128104, 127996, 8205, 128188], "Emoji_ZWJ_Sequence", "man office worker: medium-light skin tone"),
+  (&[128104, 127996, 8205, 128295], "Emoji_ZWJ_Sequence", "man mechanic: medium-light skin tone"),
+  (&[128104, 127996, 8205, 128300], "Emoji_ZWJ_Sequence", "man scientist: medium-light skin tone"),
+  (&[128104, 127996, 8205, 128640], "Emoji_ZWJ_Sequence", "man astronaut: medium-light skin tone"),
+  (&[128104, 127996, 8205, 128658], "Emoji_ZWJ_Sequence", "man firefighter: medium-light skin tone"),
+  (&[128104, 127997], "Emoji_Modifier_Sequence", "man: medium skin tone"),
+  (&[128104, 127997, 8205, 9877, 65039], "Emoji_ZWJ_Sequence", "man health worker: medium skin tone"),
+  (&[128104, 127997, 8205, 9878, 65039], "Emoji_ZWJ_Sequence", "man judge: medium skin tone"),
+  (&[128104, 127997, 8205, 9992, 65039], "Emoji_ZWJ_Sequence", "man pilot: medium skin tone"),
+  (&[128104, 127997, 8205, 127806], "Emoji_ZWJ_Sequence", "man farmer: medium skin tone"),
+  (&[128104, 127997, 8205, 127859], "Emoji_ZWJ_Sequence", "man cook: medium skin tone"),
+  (&[128104, 127997, 8205, 127891], "Emoji_ZWJ_Sequence", "man student: medium skin tone"),
+  (&[128104, 127997, 8205, 127908], "Emoji_ZWJ_Sequence", "man singer: medium skin tone"),
+  (&[128104, 127997, 8205, 127912], "Emoji_ZWJ_Sequence", "man artist: medium skin tone"),
+  (&[128104, 127997, 8205, 127979], "Emoji_ZWJ_Sequence", "man teacher: medium skin tone"),
+  (&[128104, 127997, 8205, 127981], "Emoji_ZWJ_Sequence", "man factory worker: medium skin tone"),
+  (&[128104, 127997, 8205, 128187], "Emoji_ZWJ_Sequence", "man technologist: medium skin tone"),
+  (&[128104, 127997, 8205, 128188], "Emoji_ZWJ_Sequence", "man office worker: medium skin tone"),
+  (&[128104, 127997, 8205, 128295], "Emoji_ZWJ_Sequence", "man mechanic: medium skin tone"),
+  (&[128104, 127997, 8205, 128300], "Emoji_ZWJ_Sequence", "man scientist: medium skin tone"),
+  (&[128104, 127997, 8205, 128640], "Emoji_ZWJ_Sequence", "man astronaut: medium skin tone"),
+  (&[128104, 127997, 8205, 128658], "Emoji_ZWJ_Sequence", "man firefighter: medium skin tone"),
+  (&[128104, 127998], "Emoji_Modifier_Sequence", "man: medium-dark skin tone"),
+  (&[128104, 127998, 8205, 9877, 65039], "Emoji_ZWJ_Sequence", "man health worker: medium-dark skin tone"),
+  (&[128104, 127998, 8205, 9878, 65039], "Emoji_ZWJ_Sequence", "man judge: medium-dark skin tone"),
+  (&[128104, 127998, 8205, 9992, 65039], "Emoji_ZWJ_Sequence", "man pilot: medium-dark skin tone"),
+  (&[128104, 127998, 8205, 127806], "Emoji_ZWJ_Sequence", "man farmer: medium-dark skin tone"),
+  (&[128104, 127998, 8205, 127859], "Emoji_ZWJ_Sequence", "man cook: medium-dark skin tone"),
+  (&[128104, 127998, 8205, 127891], "Emoji_ZWJ_Sequence", "man student: medium-dark skin tone"),
+  (&[128104, 127998, 8205, 127908], "Emoji_ZWJ_Sequence", "man singer: medium-dark skin tone"),
+  (&[128104, 127998, 8205, 127912], "Emoji_ZWJ_Sequence", "man artist: medium-dark skin tone"),
+  (&[128104, 127998, 8205, 127979], "Emoji_ZWJ_Sequence", "man teacher: medium-dark skin tone"),
+  (&[128104, 127998, 8205, 127981], "Emoji_ZWJ_Sequence", "man factory worker: medium-dark skin tone"),
+  (&[128104, 127998, 8205, 128187], "Emoji_ZWJ_Sequence", "man technologist: medium-dark skin tone"),
+  (&[128104, 127998, 8205, 128188], "Emoji_ZWJ_Sequence", "man office worker: medium-dark skin tone"),
+  (&[128104, 127998, 8205, 128295], "Emoji_ZWJ_Sequence", "man mechanic: medium-dark skin tone"),
+  (&[128104, 127998, 8205, 128300], "Emoji_ZWJ_Sequence", "man scientist: medium-dark skin tone"),
+  (&[128104, 127998, 8205, 128640], "Emoji_ZWJ_Sequence", "man astronaut: medium-dark skin tone"),
+  (&[128104, 127998, 8205, 128658], "Emoji_ZWJ_Sequence", "man firefighter: medium-dark skin tone"),
+  (&[128104, 127999], "Emoji_Modifier_Sequence", "man: dark skin tone"),
+  (&[128104, 127999, 8205, 9877, 65039], "Emoji_ZWJ_Sequence", "man health worker: dark skin tone"),
+  (&[128104, 127999, 8205, 9878, 65039], "Emoji_ZWJ_Sequence", "man judge: dark skin tone"),
+  (&[128104, 127999, 8205, 9992, 65039], "Emoji_ZWJ_Sequence", "man pilot: dark skin tone"),
+  (&[128104, 127999, 8205, 127806], "Emoji_ZWJ_Sequence", "man farmer: dark skin tone"),
+  (&[128104, 127999, 8205, 127859], "Emoji_ZWJ_Sequence", "man cook: dark skin tone"),
+  (&[128104, 127999, 8205, 127891], "Emoji_ZWJ_Sequence", "man student: dark skin tone"),
+  (&[128104, 127999, 8205, 127908], "Emoji_ZWJ_Sequence", "man singer: dark skin tone"),
+  (&[128104, 127999, 8205, 127912], "Emoji_ZWJ_Sequence", "man artist: dark skin tone"),
+  (&[128104, 127999, 8205, 127979], "Emoji_ZWJ_Sequence", "man teacher: dark skin tone"),
+  (&[128104, 127999, 8205, 127981], "Emoji_ZWJ_Sequence", "man factory worker: dark skin tone"),
+  (&[128104, 127999, 8205, 128187], "Emoji_ZWJ_Sequence", "man technologist: dark skin tone"),
+  (&[128104, 127999, 8205, 128188], "Emoji_ZWJ_Sequence", "man office worker: dark skin tone"),
+  (&[128104, 127999, 8205, 128295], "Emoji_ZWJ_Sequence", "man mechanic: dark skin tone"),
+  (&[128104, 127999, 8205, 128300], "Emoji_ZWJ_Sequence", "man scientist: dark skin tone"),
+  (&[128104, 127999, 8205, 128640], "Emoji_ZWJ_Sequence", "man astronaut: dark skin tone"),
+  (&[128104, 127999, 8205, 128658], "Emoji_ZWJ_Sequence", "man firefighter: dark skin tone"),
+  (&[128105, 8205, 9877, 65039], "Emoji_ZWJ_Sequence", "woman health worker"),
+  (&[128105, 8205, 9878, 65039], "Emoji_ZWJ_Sequence", "woman judge"),
+  (&[128105, 8205, 9992, 65039], "Emoji_ZWJ_Sequence", "woman pilot"),
+  (&[128105, 8205, 10084, 65039, 8205, 128104], "Emoji_ZWJ_Sequence", "couple with heart: woman, man"),
+  (&[128105, 8205, 10084, 65039, 8205, 128105], "Emoji_ZWJ_Sequence", "couple with heart: woman, woman"),
+  (&[128105, 8205, 10084, 65039, 8205, 128139, 8205, 128104], "Emoji_ZWJ_Sequence", "kiss: woman, man"),
+  (&[128105, 8205, 10084, 65039, 8205, 128139, 8205, 128105], "Emoji_ZWJ_Sequence", "kiss: woman, woman"),
+  (&[128105, 8205, 127806], "Emoji_ZWJ_Sequence", "woman farmer"),
+  (&[128105, 8205, 127859], "Emoji_ZWJ_Sequence", "woman cook"),
+  (&[128105, 8205, 127891], "Emoji_ZWJ_Sequence", "woman student"),
+  (&[128105, 8205, 127908], "Emoji_ZWJ_Sequence", "woman singer"),
+  (&[128105, 8205, 127912], "Emoji_ZWJ_Sequence", "woman artist"),
+  (&[128105, 8205, 127979], "Emoji_ZWJ_Sequence", "woman teacher"),
+  (&[128105, 8205, 127981], "Emoji_ZWJ_Sequence", "woman factory worker"),
+  (&[128105, 8205, 128102], "Emoji_ZWJ_Sequence", "family: woman, boy"),
+  (&[128105, 8205, 128102, 8205, 128102], "Emoji_ZWJ_Sequence", "family: woman, boy, boy"),
+  (&[128105, 8205, 128103], "Emoji_ZWJ_Sequence", "family: woman, girl"),
+  (&[128105, 8205, 128103, 8205, 128102], "Emoji_ZWJ_Sequence", "family: woman, girl, boy"),
+  (&[128105, 8205, 128103, 8205, 128103], "Emoji_ZWJ_Sequence", "family: woman, girl, girl"),
+  (&[128105, 8205, 128105, 8205, 128102], "Emoji_ZWJ_Sequence", "family: woman, woman, boy"),
+  (&[128105, 8205, 128105, 8205, 128102, 8205, 128102], "Emoji_ZWJ_Sequence", "family: woman, woman, boy, boy"),
+  (&[128105, 8205, 128105, 8205, 128103], "Emoji_ZWJ_Sequence", "family: woman, woman, girl"),
+  (&[128105, 8205, 128105, 8205, 128103, 8205, 128102], "Emoji_ZWJ_Sequence", "family: woman, woman, girl, boy"),
+  (&[128105, 8205, 128105, 8205, 128103, 8205, 128103], "Emoji_ZWJ_Sequence", "family: woman, woman, girl, girl"),
+  (&[128105, 8205, 128187], "Emoji_ZWJ_Sequence", "woman technologist"),
+  (&[128105, 8205, 128188], "Emoji_ZWJ_Sequence", "woman office worker"),
+  (&[128105, 8205, 128295], "Emoji_ZWJ_Sequence", "woman mechanic"),
+  (&[128105, 8205, 128300], "Emoji_ZWJ_Sequence", "woman scientist"),
+  (&[128105, 8205, 128640], "Emoji_ZWJ_Sequence", "woman astronaut"),
+  (&[128105, 8205, 128658], "Emoji_ZWJ_Sequence", "woman firefighter"),
+  (&[128105, 127995], "Emoji_Modifier_Sequence", "woman: light skin tone"),
+  (&[128105, 127995, 8205, 9877, 65039], "Emoji_ZWJ_Sequence", "woman health worker: light skin tone"),
+  (&[128105, 127995, 8205, 9878, 65039], "Emoji_ZWJ_Sequence", "woman judge: light skin tone"),
+  (&[128105, 127995, 8205, 9992, 65039], "Emoji_ZWJ_Sequence", "woman pilot: light skin tone"),
+  (&[128105, 127995, 8205, 127806], "Emoji_ZWJ_Sequence", "woman farmer: light skin tone"),
+  (&[128105, 127995, 8205, 127859], "Emoji_ZWJ_Sequence", "woman cook: light skin tone"),
+  (&[128105, 127995, 8205, 127891], "Emoji_ZWJ_Sequence", "woman student: light skin tone"),
+  (&[128105, 127995, 8205, 127908], "Emoji_ZWJ_Sequence", "woman singer: light skin tone"),
+  (&[128105, 127995, 8205, 127912], "Emoji_ZWJ_Sequence", "woman artist: light skin tone"),
+  (&[128105, 127995, 8205, 127979], "Emoji_ZWJ_Sequence", "woman teacher: light skin tone"),
+  (&[128105, 127995, 8205, 127981], "Emoji_ZWJ_Sequence", "woman factory worker: light skin tone"),
+  (&[128105, 127995, 8205, 128187], "Emoji_ZWJ_Sequence", "woman technologist: light skin tone"),
+  (&[128105, 127995, 8205, 128188], "Emoji_ZWJ_Sequence", "woman office worker: light skin tone"),
+  (&[128105, 127995, 8205, 128295], "Emoji_ZWJ_Sequence", "woman mechanic: light skin tone"),
+  (&[128105, 127995, 8205, 128300], "Emoji_ZWJ_Sequence", "woman scientist: light skin tone"),
+  (&[128105, 127995, 8205, 128640], "Emoji_ZWJ_Sequence", "woman astronaut: light skin tone"),
+  (&[128105, 127995, 8205, 128658], "Emoji_ZWJ_Sequence", "woman firefighter: light skin tone"),
+  (&[128105, 127996], "Emoji_Modifier_Sequence", "woman: medium-light skin tone"),
+  (&[128105, 127996, 8205, 9877, 65039], "Emoji_ZWJ_Sequence", "woman health worker: medium-light skin tone"),
+  (&[128105, 127996, 8205, 9878, 65039], "Emoji_ZWJ_Sequence", "woman judge: medium-light skin tone"),
+  (&[128105, 127996, 8205, 9992, 65039], "Emoji_ZWJ_Sequence", "woman pilot: medium-light skin tone"),
+  (&[128105, 127996, 8205, 127806], "Emoji_ZWJ_Sequence", "woman farmer: medium-light skin tone"),
+  (&[128105, 127996, 8205, 127859], "Emoji_ZWJ_Sequence", "woman cook: medium-light skin tone"),
+  (&[128105, 127996, 8205, 127891], "Emoji_ZWJ_Sequence", "woman student: medium-light skin tone"),
+  (&[128105, 127996, 8205, 127908], "Emoji_ZWJ_Sequence", "woman singer: medium-light skin tone"),
+  (&[128105, 127996, 8205, 127912], "Emoji_ZWJ_Sequence", "woman artist: medium-light skin tone"),
+  (&[128105, 127996, 8205, 127979], "Emoji_ZWJ_Sequence", "woman teacher: medium-light skin tone"),
+  (&[128105, 127996, 8205, 127981], "Emoji_ZWJ_Sequence", "woman factory worker: medium-light skin tone"),
+  (&[128105, 127996, 8205, 128187], "Emoji_ZWJ_Sequence", "woman technologist: medium-light skin tone"),
+  (&[128105, 127996, 8205, 128188], "Emoji_ZWJ_Sequence", "woman office worker: medium-light skin tone"),
+  (&[128105, 127996, 8205, 128295], "Emoji_ZWJ_Sequence", "woman mechanic: medium-light skin tone"),
+  (&[128105, 127996, 8205, 128300], "Emoji_ZWJ_Sequence", "woman scientist: medium-light skin tone"),
+  (&[128105, 127996, 8205, 128640], "Emoji_ZWJ_Sequence", "woman astronaut: medium-light skin tone"),
+  (&[128105, 127996, 8205, 128658], "Emoji_ZWJ_Sequence", "woman firefighter: medium-light skin tone"),
+  (&[128105, 127997], "Emoji_Modifier_Sequence", "woman: medium skin tone"),
+  (&[128105, 127997, 8205, 9877, 65039], "Emoji_ZWJ_Sequence", "woman health worker: medium skin tone"),
+  (&[128105, 127997, 8205, 9878, 65039], "Emoji_ZWJ_Sequence", "woman judge: medium skin tone"),
+  (&[128105, 127997, 8205, 9992, 65039], "Emoji_ZWJ_Sequence", "woman pilot: medium skin tone"),
+  (&[128105, 127997, 8205, 127806], "Emoji_ZWJ_Sequence", "woman farmer: medium skin tone"),
+  (&[128105, 127997, 8205, 127859], "Emoji_ZWJ_Sequence", "woman cook: medium skin tone"),
+  (&[128105, 127997, 8205, 127891], "Emoji_ZWJ_Sequence", "woman student: medium skin tone"),
+  (&[128105, 127997, 8205, 127908], "Emoji_ZWJ_Sequence", "woman singer: medium skin tone"),
+  (&[128105, 127997, 8205, 127912], "Emoji_ZWJ_Sequence", "woman artist: medium skin tone"),
+  (&[128105, 127997, 8205, 127979], "Emoji_ZWJ_Sequence", "woman teacher: medium skin tone"),
+  (&[128105, 127997, 8205, 127981], "Emoji_ZWJ_Sequence", "woman factory worker: medium skin tone"),
+  (&[128105, 127997, 8205, 128187], "Emoji_ZWJ_Sequence", "woman technologist: medium skin tone"),
+  (&[128105, 127997, 8205, 128188], "Emoji_ZWJ_Sequence", "woman office worker: medium skin tone"),
+  (&[128105, 127997, 8205, 128295], "Emoji_ZWJ_Sequence", "woman mechanic: medium skin tone"),
+  (&[128105, 127997, 8205, 128300], "Emoji_ZWJ_Sequence", "woman scientist: medium skin tone"),
+  (&[128105, 127997, 8205, 128640], "Emoji_ZWJ_Sequence", "woman astronaut: medium skin tone"),
+  (&[128105, 127997, 8205, 128658], "Emoji_ZWJ_Sequence", "woman firefighter: medium skin tone"),
+  (&[128105, 127998], "Emoji_Modifier_Sequence", "woman: medium-dark skin tone"),
+  (&[128105, 127998, 8205, 9877, 65039], "Emoji_ZWJ_Sequence", "woman health worker: medium-dark skin tone"),
+  (&[128105, 127998, 8205, 9878, 65039], "Emoji_ZWJ_Sequence", "woman judge: medium-dark skin tone"),
+  (&[128105, 127998, 8205, 9992, 65039], "Emoji_ZWJ_Sequence", "woman pilot: medium-dark skin tone"),
+  (&[128105, 127998, 8205, 127806], "Emoji_ZWJ_Sequence", "woman farmer: medium-dark skin tone"),
+  (&[128105, 127998, 8205, 127859], "Emoji_ZWJ_Sequence", "woman cook: medium-dark skin tone"),
+  (&[128105, 127998, 8205, 127891], "Emoji_ZWJ_Sequence", "woman student: medium-dark skin tone"),
+  (&[128105, 127998, 8205, 127908], "Emoji_ZWJ_Sequence", "woman singer: medium-dark skin tone"),
+  (&[128105, 127998, 8205, 127912], "Emoji_ZWJ_Sequence", "woman artist: medium-dark skin tone"),
+  (&[128105, 127998, 8205, 127979], "Emoji_ZWJ_Sequence", "woman teacher: medium-dark skin tone"),
+  (&[128105, 127998, 8205, 127981], "Emoji_ZWJ_Sequence", "woman factory worker: medium-dark skin tone"),
+  (&[128105, 127998, 8205, 128187], "Emoji_ZWJ_Sequence", "woman technologist: medium-dark skin tone"),
+  (&[128105, 127998, 8205, 128188], "Emoji_ZWJ_Sequence", "woman office worker: medium-dark skin tone"),
+  (&[128105, 127998, 8205, 128295], "Emoji_ZWJ_Sequence", "woman mechanic: medium-dark skin tone"),
+  (&[128105, 127998, 8205, 128300], "Emoji_ZWJ_Sequence", "woman scientist: medium-dark skin tone"),
+  (&[128105, 127998, 8205, 128640], "Emoji_ZWJ_Sequence", "woman astronaut: medium-dark skin tone"),
+  (&[128105, 127998, 8205, 128658], "Emoji_ZWJ_Sequence", "woman firefighter: medium-dark skin tone"),
+  (&[128105, 127999], "Emoji_Modifier_Sequence", "woman: dark skin tone"),
+  (&[128105, 127999, 8205, 9877, 65039], "Emoji_ZWJ_Sequence", "woman health worker: dark skin tone"),
+  (&[128105, 127999, 8205, 9878, 65039], "Emoji_ZWJ_Sequence", "woman judge: dark skin tone"),
+  (&[128105, 127999, 8205, 9992, 65039], "Emoji_ZWJ_Sequence", "woman pilot: dark skin tone"),
+  (&[128105, 127999, 8205, 127806], "Emoji_ZWJ_Sequence", "woman farmer: dark skin tone"),
+  (&[128105, 127999, 8205, 127859], "Emoji_ZWJ_Sequence", "woman cook: dark skin tone"),
+  (&[128105, 127999, 8205, 127891], "Emoji_ZWJ_Sequence", "woman student: dark skin tone"),
+  (&[128105, 127999, 8205, 127908], "Emoji_ZWJ_Sequence", "woman singer: dark skin tone"),
+  (&[128105, 127999, 8205, 127912], "Emoji_ZWJ_Sequence", "woman artist: dark skin tone"),
+  (&[128105, 127999, 8205, 127979], "Emoji_ZWJ_Sequence", "woman teacher: dark skin tone"),
+  (&[128105, 127999, 8205, 127981], "Emoji_ZWJ_Sequence", "woman factory worker: dark skin tone"),
+  (&[128105, 127999, 8205, 128187], "Emoji_ZWJ_Sequence", "woman technologist: dark skin tone"),
+  (&[128105, 127999, 8205, 128188], "Emoji_ZWJ_Sequence", "woman office worker: dark skin tone"),
+  (&[128105, 127999, 8205, 128295], "Emoji_ZWJ_Sequence", "woman mechanic: dark skin tone"),
+  (&[128105, 127999, 8205, 128300], "Emoji_ZWJ_Sequence", "woman scientist: dark skin tone"),
+  (&[128105, 127999, 8205, 128640], "Emoji_ZWJ_Sequence", "woman astronaut: dark skin tone"),
+  (&[128105, 127999, 8205, 128658], "Emoji_ZWJ_Sequence", "woman firefighter: dark skin tone"),
+  (&[128110, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman police officer"),
+  (&[128110, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man police officer"),
+  (&[128110, 127995], "Emoji_Modifier_Sequence", "police officer: light skin tone"),
+  (&[128110, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman police officer: light skin tone"),
+  (&[128110, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man police officer: light skin tone"),
+  (&[128110, 127996], "Emoji_Modifier_Sequence", "police officer: medium-light skin tone"),
+  (&[128110, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman police officer: medium-light skin tone"),
+  (&[128110, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man police officer: medium-light skin tone"),
+  (&[128110, 127997], "Emoji_Modifier_Sequence", "police officer: medium skin tone"),
+  (&[128110, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman police officer: medium skin tone"),
+  (&[128110, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man police officer: medium skin tone"),
+  (&[128110, 127998], "Emoji_Modifier_Sequence", "police officer: medium-dark skin tone"),
+  (&[128110, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman police officer: medium-dark skin tone"),
+  (&[128110, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man police officer: medium-dark skin tone"),
+  (&[128110, 127999], "Emoji_Modifier_Sequence", "police officer: dark skin tone"),
+  (&[128110, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman police officer: dark skin tone"),
+  (&[128110, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man police officer: dark skin tone"),
+  (&[128111, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "women with bunny ears"),
+  (&[128111, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "men with bunny ears"),
+  (&[128112, 127995], "Emoji_Modifier_Sequence", "person with veil: light skin tone"),
+  (&[128112, 127996], "Emoji_Modifier_Sequence", "person with veil: medium-light skin tone"),
+  (&[128112, 127997], "Emoji_Modifier_Sequence", "person with veil: medium skin tone"),
+  (&[128112, 127998], "Emoji_Modifier_Sequence", "person with veil: medium-dark skin tone"),
+  (&[128112, 127999], "Emoji_Modifier_Sequence", "person with veil: dark skin tone"),
+  (&[128113, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman: blond hair"),
+  (&[128113, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man: blond hair"),
+  (&[128113, 127995], "Emoji_Modifier_Sequence", "person: light skin tone, blond hair"),
+  (&[128113, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman: light skin tone, blond hair"),
+  (&[128113, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man: light skin tone, blond hair"),
+  (&[128113, 127996], "Emoji_Modifier_Sequence", "person: medium-light skin tone, blond hair"),
+  (&[128113, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman: medium-light skin tone, blond hair"),
+  (&[128113, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man: medium-light skin tone, blond hair"),
+  (&[128113, 127997], "Emoji_Modifier_Sequence", "person: medium skin tone, blond hair"),
+  (&[128113, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman: medium skin tone, blond hair"),
+  (&[128113, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man: medium skin tone, blond hair"),
+  (&[128113, 127998], "Emoji_Modifier_Sequence", "person: medium-dark skin tone, blond hair"),
+  (&[128113, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman: medium-dark skin tone, blond hair"),
+  (&[128113, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man: medium-dark skin tone, blond hair"),
+  (&[128113, 127999], "Emoji_Modifier_Sequence", "person: dark skin tone, blond hair"),
+  (&[128113, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman: dark skin tone, blond hair"),
+  (&[128113, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man: dark skin tone, blond hair"),
+  (&[128114, 127995], "Emoji_Modifier_Sequence", "person with skullcap: light skin tone"),
+  (&[128114, 127996], "Emoji_Modifier_Sequence", "person with skullcap: medium-light skin tone"),
+  (&[128114, 127997], "Emoji_Modifier_Sequence", "person with skullcap: medium skin tone"),
+  (&[128114, 127998], "Emoji_Modifier_Sequence", "person with skullcap: medium-dark skin tone"),
+  (&[128114, 127999], "Emoji_Modifier_Sequence", "person with skullcap: dark skin tone"),
+  (&[128115, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman wearing turban"),
+  (&[128115, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man wearing turban"),
+  (&[128115, 127995], "Emoji_Modifier_Sequence", "person wearing turban: light skin tone"),
+  (&[128115, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman wearing turban: light skin tone"),
+  (&[128115, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man wearing turban: light skin tone"),
+  (&[128115, 127996], "Emoji_Modifier_Sequence", "person wearing turban: medium-light skin tone"),
+  (&[128115, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman wearing turban: medium-light skin tone"),
+  (&[128115, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man wearing turban: medium-light skin tone"),
+  (&[128115, 127997], "Emoji_Modifier_Sequence", "person wearing turban: medium skin tone"),
+  (&[128115, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman wearing turban: medium skin tone"),
+  (&[128115, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man wearing turban: medium skin tone"),
+  (&[128115, 127998], "Emoji_Modifier_Sequence", "person wearing turban: medium-dark skin tone"),
+  (&[128115, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman wearing turban: medium-dark skin tone"),
+  (&[128115, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man wearing turban: medium-dark skin tone"),
+  (&[128115, 127999], "Emoji_Modifier_Sequence", "person wearing turban: dark skin tone"),
+  (&[128115, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman wearing turban: dark skin tone"),
+  (&[128115, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man wearing turban: dark skin tone"),
+  (&[128116, 127995], "Emoji_Modifier_Sequence", "old man: light skin tone"),
+  (&[128116, 127996], "Emoji_Modifier_Sequence", "old man: medium-light skin tone"),
+  (&[128116, 127997], "Emoji_Modifier_Sequence", "old man: medium skin tone"),
+  (&[128116, 127998], "Emoji_Modifier_Sequence", "old man: medium-dark skin tone"),
+  (&[128116, 127999], "Emoji_Modifier_Sequence", "old man: dark skin tone"),
+  (&[128117, 127995], "Emoji_Modifier_Sequence", "old woman: light skin tone"),
+  (&[128117, 127996], "Emoji_Modifier_Sequence", "old woman: medium-light skin tone"),
+  (&[128117, 127997], "Emoji_Modifier_Sequence", "old woman: medium skin tone"),
+  (&[128117, 127998], "Emoji_Modifier_Sequence", "old woman: medium-dark skin tone"),
+  (&[128117, 127999], "Emoji_Modifier_Sequence", "old woman: dark skin tone"),
+  (&[128118, 127995], "Emoji_Modifier_Sequence", "baby: light skin tone"),
+  (&[128118, 127996], "Emoji_Modifier_Sequence", "baby: medium-light skin tone"),
+  (&[128118, 127997], "Emoji_Modifier_Sequence", "baby: medium skin tone"),
+  (&[128118, 127998], "Emoji_Modifier_Sequence", "baby: medium-dark skin tone"),
+  (&[128118, 127999], "Emoji_Modifier_Sequence", "baby: dark skin tone"),
+  (&[128119, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman construction worker"),
+  (&[128119, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man construction worker"),
+  (&[128119, 127995], "Emoji_Modifier_Sequence", "construction worker: light skin tone"),
+  (&[128119, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman construction worker: light skin tone"),
+  (&[128119, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man construction worker: light skin tone"),
+  (&[128119, 127996], "Emoji_Modifier_Sequence", "construction worker: medium-light skin tone"),
+  (&[128119, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman construction worker: medium-light skin tone"),
+  (&[128119, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man construction worker: medium-light skin tone"),
+  (&[128119, 127997], "Emoji_Modifier_Sequence", "construction worker: medium skin tone"),
+  (&[128119, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman construction worker: medium skin tone"),
+  (&[128119, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man construction worker: medium skin tone"),
+  (&[128119, 127998], "Emoji_Modifier_Sequence", "construction worker: medium-dark skin tone"),
+  (&[128119, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman construction worker: medium-dark skin tone"),
+  (&[128119, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man construction worker: medium-dark skin tone"),
+  (&[128119, 127999], "Emoji_Modifier_Sequence", "construction worker: dark skin tone"),
+  (&[128119, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman construction worker: dark skin tone"),
+  (&[128119, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man construction worker: dark skin tone"),
+  (&[128120, 127995], "Emoji_Modifier_Sequence", "princess: light skin tone"),
+  (&[128120, 127996], "Emoji_Modifier_Sequence", "princess: medium-light skin tone"),
+  (&[128120, 127997], "Emoji_Modifier_Sequence", "princess: medium skin tone"),
+  (&[128120, 127998], "Emoji_Modifier_Sequence", "princess: medium-dark skin tone"),
+  (&[128120, 127999], "Emoji_Modifier_Sequence", "princess: dark skin tone"),
+  (&[128124, 127995], "Emoji_Modifier_Sequence", "baby angel: light skin tone"),
+  (&[128124, 127996], "Emoji_Modifier_Sequence", "baby angel: medium-light skin tone"),
+  (&[128124, 127997], "Emoji_Modifier_Sequence", "baby angel: medium skin tone"),
+  (&[128124, 127998], "Emoji_Modifier_Sequence", "baby angel: medium-dark skin tone"),
+  (&[128124, 127999], "Emoji_Modifier_Sequence", "baby angel: dark skin tone"),
+  (&[128129, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman tipping hand"),
+  (&[128129, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man tipping hand"),
+  (&[128129, 127995], "Emoji_Modifier_Sequence", "person tipping hand: light skin tone"),
+  (&[128129, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman tipping hand: light skin tone"),
+  (&[128129, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man tipping hand: light skin tone"),
+  (&[128129, 127996], "Emoji_Modifier_Sequence", "person tipping hand: medium-light skin tone"),
+  (&[128129, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman tipping hand: medium-light skin tone"),
+  (&[128129, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man tipping hand: medium-light skin tone"),
+  (&[128129, 127997], "Emoji_Modifier_Sequence", "person tipping hand: medium skin tone"),
+  (&[128129, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman tipping hand: medium skin tone"),
+  (&[128129, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man tipping hand: medium skin tone"),
+  (&[128129, 127998], "Emoji_Modifier_Sequence", "person tipping hand: medium-dark skin tone"),
+  (&[128129, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman tipping hand: medium-dark skin tone"),
+  (&[128129, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man tipping hand: medium-dark skin tone"),
+  (&[128129, 127999], "Emoji_Modifier_Sequence", "person tipping hand: dark skin tone"),
+  (&[128129, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman tipping hand: dark skin tone"),
+  (&[128129, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man tipping hand: dark skin tone"),
+  (&[128130, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman guard"),
+  (&[128130, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man guard"),
+  (&[128130, 127995], "Emoji_Modifier_Sequence", "guard: light skin tone"),
+  (&[128130, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman guard: light skin tone"),
+  (&[128130, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man guard: light skin tone"),
+  (&[128130, 127996], "Emoji_Modifier_Sequence", "guard: medium-light skin tone"),
+  (&[128130, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman guard: medium-light skin tone"),
+  (&[128130, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man guard: medium-light skin tone"),
+  (&[128130, 127997], "Emoji_Modifier_Sequence", "guard: medium skin tone"),
+  (&[128130, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman guard: medium skin tone"),
+  (&[128130, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man guard: medium skin tone"),
+  (&[128130, 127998], "Emoji_Modifier_Sequence", "guard: medium-dark skin tone"),
+  (&[128130, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman guard: medium-dark skin tone"),
+  (&[128130, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man guard: medium-dark skin tone"),
+  (&[128130, 127999], "Emoji_Modifier_Sequence", "guard: dark skin tone"),
+  (&[128130, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman guard: dark skin tone"),
+  (&[128130, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man guard: dark skin tone"),
+  (&[128131, 127995], "Emoji_Modifier_Sequence", "woman dancing: light skin tone"),
+  (&[128131, 127996], "Emoji_Modifier_Sequence", "woman dancing: medium-light skin tone"),
+  (&[128131, 127997], "Emoji_Modifier_Sequence", "woman dancing: medium skin tone"),
+  (&[128131, 127998], "Emoji_Modifier_Sequence", "woman dancing: medium-dark skin tone"),
+  (&[128131, 127999], "Emoji_Modifier_Sequence", "woman dancing: dark skin tone"),
+  (&[128133, 127995], "Emoji_Modifier_Sequence", "nail polish: light skin tone"),
+  (&[128133, 127996], "Emoji_Modifier_Sequence", "nail polish: medium-light skin tone"),
+  (&[128133, 127997], "Emoji_Modifier_Sequence", "nail polish: medium skin tone"),
+  (&[128133, 127998], "Emoji_Modifier_Sequence", "nail polish: medium-dark skin tone"),
+  (&[128133, 127999], "Emoji_Modifier_Sequence", "nail polish: dark skin tone"),
+  (&[128134, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman getting massage"),
+  (&[128134, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man getting massage"),
+  (&[128134, 127995], "Emoji_Modifier_Sequence", "person getting massage: light skin tone"),
+  (&[128134, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman getting massage: light skin tone"),
+  (&[128134, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man getting massage: light skin tone"),
+  (&[128134, 127996], "Emoji_Modifier_Sequence", "person getting massage: medium-light skin tone"),
+  (&[128134, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman getting massage: medium-light skin tone"),
+  (&[128134, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man getting massage: medium-light skin tone"),
+  (&[128134, 127997], "Emoji_Modifier_Sequence", "person getting massage: medium skin tone"),
+  (&[128134, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman getting massage: medium skin tone"),
+  (&[128134, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man getting massage: medium skin tone"),
+  (&[128134, 127998], "Emoji_Modifier_Sequence", "person getting massage: medium-dark skin tone"),
+  (&[128134, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman getting massage: medium-dark skin tone"),
+  (&[128134, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man getting massage: medium-dark skin tone"),
+  (&[128134, 127999], "Emoji_Modifier_Sequence", "person getting massage: dark skin tone"),
+  (&[128134, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman getting massage: dark skin tone"),
+  (&[128134, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man getting massage: dark skin tone"),
+  (&[128135, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman getting haircut"),
+  (&[128135, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man getting haircut"),
+  (&[128135, 127995], "Emoji_Modifier_Sequence", "person getting haircut: light skin tone"),
+  (&[128135, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman getting haircut: light skin tone"),
+  (&[128135, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man getting haircut: light skin tone"),
+  (&[128135, 127996], "Emoji_Modifier_Sequence", "person getting haircut: medium-light skin tone"),
+  (&[128135, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman getting haircut: medium-light skin tone"),
+  (&[128135, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man getting haircut: medium-light skin tone"),
+  (&[128135, 127997], "Emoji_Modifier_Sequence", "person getting haircut: medium skin tone"),
+  (&[128135, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman getting haircut: medium skin tone"),
+  (&[128135, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man getting haircut: medium skin tone"),
+  (&[128135, 127998], "Emoji_Modifier_Sequence", "person getting haircut: medium-dark skin tone"),
+  (&[128135, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman getting haircut: medium-dark skin tone"),
+  (&[128135, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man getting haircut: medium-dark skin tone"),
+  (&[128135, 127999], "Emoji_Modifier_Sequence", "person getting haircut: dark skin tone"),
+  (&[128135, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman getting haircut: dark skin tone"),
+  (&[128135, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man getting haircut: dark skin tone"),
+  (&[128170, 127995], "Emoji_Modifier_Sequence", "flexed biceps: light skin tone"),
+  (&[128170, 127996], "Emoji_Modifier_Sequence", "flexed biceps: medium-light skin tone"),
+  (&[128170, 127997], "Emoji_Modifier_Sequence", "flexed biceps: medium skin tone"),
+  (&[128170, 127998], "Emoji_Modifier_Sequence", "flexed biceps: medium-dark skin tone"),
+  (&[128170, 127999], "Emoji_Modifier_Sequence", "flexed biceps: dark skin tone"),
+  (&[128372, 127995], "Emoji_Modifier_Sequence", "person in suit levitating: light skin tone"),
+  (&[128372, 127996], "Emoji_Modifier_Sequence", "person in suit levitating: medium-light skin tone"),
+  (&[128372, 127997], "Emoji_Modifier_Sequence", "person in suit levitating: medium skin tone"),
+  (&[128372, 127998], "Emoji_Modifier_Sequence", "person in suit levitating: medium-dark skin tone"),
+  (&[128372, 127999], "Emoji_Modifier_Sequence", "person in suit levitating: dark skin tone"),
+  (&[128373, 65039, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman detective"),
+  (&[128373, 65039, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man detective"),
+  (&[128373, 127995], "Emoji_Modifier_Sequence", "detective: light skin tone"),
+  (&[128373, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman detective: light skin tone"),
+  (&[128373, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man detective: light skin tone"),
+  (&[128373, 127996], "Emoji_Modifier_Sequence", "detective: medium-light skin tone"),
+  (&[128373, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman detective: medium-light skin tone"),
+  (&[128373, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man detective: medium-light skin tone"),
+  (&[128373, 127997], "Emoji_Modifier_Sequence", "detective: medium skin tone"),
+  (&[128373, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman detective: medium skin tone"),
+  (&[128373, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man detective: medium skin tone"),
+  (&[128373, 127998], "Emoji_Modifier_Sequence", "detective: medium-dark skin tone"),
+  (&[128373, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman detective: medium-dark skin tone"),
+  (&[128373, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man detective: medium-dark skin tone"),
+  (&[128373, 127999], "Emoji_Modifier_Sequence", "detective: dark skin tone"),
+  (&[128373, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman detective: dark skin tone"),
+  (&[128373, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man detective: dark skin tone"),
+  (&[128378, 127995], "Emoji_Modifier_Sequence", "man dancing: light skin tone"),
+  (&[128378, 127996], "Emoji_Modifier_Sequence", "man dancing: medium-light skin tone"),
+  (&[128378, 127997], "Emoji_Modifier_Sequence", "man dancing: medium skin tone"),
+  (&[128378, 127998], "Emoji_Modifier_Sequence", "man dancing: medium-dark skin tone"),
+  (&[128378, 127999], "Emoji_Modifier_Sequence", "man dancing: dark skin tone"),
+  (&[128400, 127995], "Emoji_Modifier_Sequence", "hand with fingers splayed: light skin tone"),
+  (&[128400, 127996], "Emoji_Modifier_Sequence", "hand with fingers splayed: medium-light skin tone"),
+  (&[128400, 127997], "Emoji_Modifier_Sequence", "hand with fingers splayed: medium skin tone"),
+  (&[128400, 127998], "Emoji_Modifier_Sequence", "hand with fingers splayed: medium-dark skin tone"),
+  (&[128400, 127999], "Emoji_Modifier_Sequence", "hand with fingers splayed: dark skin tone"),
+  (&[128405, 127995], "Emoji_Modifier_Sequence", "middle finger: light skin tone"),
+  (&[128405, 127996], "Emoji_Modifier_Sequence", "middle finger: medium-light skin tone"),
+  (&[128405, 127997], "Emoji_Modifier_Sequence", "middle finger: medium skin tone"),
+  (&[128405, 127998], "Emoji_Modifier_Sequence", "middle finger: medium-dark skin tone"),
+  (&[128405, 127999], "Emoji_Modifier_Sequence", "middle finger: dark skin tone"),
+  (&[128406, 127995], "Emoji_Modifier_Sequence", "vulcan salute: light skin tone"),
+  (&[128406, 127996], "Emoji_Modifier_Sequence", "vulcan salute: medium-light skin tone"),
+  (&[128406, 127997], "Emoji_Modifier_Sequence", "vulcan salute: medium skin tone"),
+  (&[128406, 127998], "Emoji_Modifier_Sequence", "vulcan salute: medium-dark skin tone"),
+  (&[128406, 127999], "Emoji_Modifier_Sequence", "vulcan salute: dark skin tone"),
+  (&[128581, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman gesturing NO"),
+  (&[128581, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man gesturing NO"),
+  (&[128581, 127995], "Emoji_Modifier_Sequence", "person gesturing NO: light skin tone"),
+  (&[128581, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman gesturing NO: light skin tone"),
+  (&[128581, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man gesturing NO: light skin tone"),
+  (&[128581, 127996], "Emoji_Modifier_Sequence", "person gesturing NO: medium-light skin tone"),
+  (&[128581, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman gesturing NO: medium-light skin tone"),
+  (&[128581, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man gesturing NO: medium-light skin tone"),
+  (&[128581, 127997], "Emoji_Modifier_Sequence", "person gesturing NO: medium skin tone"),
+  (&[128581, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman gesturing NO: medium skin tone"),
+  (&[128581, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man gesturing NO: medium skin tone"),
+  (&[128581, 127998], "Emoji_Modifier_Sequence", "person gesturing NO: medium-dark skin tone"),
+  (&[128581, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman gesturing NO: medium-dark skin tone"),
+  (&[128581, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man gesturing NO: medium-dark skin tone"),
+  (&[128581, 127999], "Emoji_Modifier_Sequence", "person gesturing NO: dark skin tone"),
+  (&[128581, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman gesturing NO: dark skin tone"),
+  (&[128581, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man gesturing NO: dark skin tone"),
+  (&[128582, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman gesturing OK"),
+  (&[128582, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man gesturing OK"),
+  (&[128582, 127995], "Emoji_Modifier_Sequence", "person gesturing OK: light skin tone"),
+  (&[128582, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman gesturing OK: light skin tone"),
+  (&[128582, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man gesturing OK: light skin tone"),
+  (&[128582, 127996], "Emoji_Modifier_Sequence", "person gesturing OK: medium-light skin tone"),
+  (&[128582, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman gesturing OK: medium-light skin tone"),
+  (&[128582, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man gesturing OK: medium-light skin tone"),
+  (&[128582, 127997], "Emoji_Modifier_Sequence", "person gesturing OK: medium skin tone"),
+  (&[128582, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman gesturing OK: medium skin tone"),
+  (&[128582, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man gesturing OK: medium skin tone"),
+  (&[128582, 127998], "Emoji_Modifier_Sequence", "person gesturing OK: medium-dark skin tone"),
+  (&[128582, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman gesturing OK: medium-dark skin tone"),
+  (&[128582, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man gesturing OK: medium-dark skin tone"),
+  (&[128582, 127999], "Emoji_Modifier_Sequence", "person gesturing OK: dark skin tone"),
+  (&[128582, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman gesturing OK: dark skin tone"),
+  (&[128582, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man gesturing OK: dark skin tone"),
+  (&[128583, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman bowing"),
+  (&[128583, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man bowing"),
+  (&[128583, 127995], "Emoji_Modifier_Sequence", "person bowing: light skin tone"),
+  (&[128583, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman bowing: light skin tone"),
+  (&[128583, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man bowing: light skin tone"),
+  (&[128583, 127996], "Emoji_Modifier_Sequence", "person bowing: medium-light skin tone"),
+  (&[128583, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman bowing: medium-light skin tone"),
+  (&[128583, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man bowing: medium-light skin tone"),
+  (&[128583, 127997], "Emoji_Modifier_Sequence", "person bowing: medium skin tone"),
+  (&[128583, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman bowing: medium skin tone"),
+  (&[128583, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man bowing: medium skin tone"),
+  (&[128583, 127998], "Emoji_Modifier_Sequence", "person bowing: medium-dark skin tone"),
+  (&[128583, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman bowing: medium-dark skin tone"),
+  (&[128583, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man bowing: medium-dark skin tone"),
+  (&[128583, 127999], "Emoji_Modifier_Sequence", "person bowing: dark skin tone"),
+  (&[128583, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman bowing: dark skin tone"),
+  (&[128583, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man bowing: dark skin tone"),
+  (&[128587, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman raising hand"),
+  (&[128587, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man raising hand"),
+  (&[128587, 127995], "Emoji_Modifier_Sequence", "person raising hand: light skin tone"),
+  (&[128587, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman raising hand: light skin tone"),
+  (&[128587, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man raising hand: light skin tone"),
+  (&[128587, 127996], "Emoji_Modifier_Sequence", "person raising hand: medium-light skin tone"),
+  (&[128587, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman raising hand: medium-light skin tone"),
+  (&[128587, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man raising hand: medium-light skin tone"),
+  (&[128587, 127997], "Emoji_Modifier_Sequence", "person raising hand: medium skin tone"),
+  (&[128587, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman raising hand: medium skin tone"),
+  (&[128587, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man raising hand: medium skin tone"),
+  (&[128587, 127998], "Emoji_Modifier_Sequence", "person raising hand: medium-dark skin tone"),
+  (&[128587, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman raising hand: medium-dark skin tone"),
+  (&[128587, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man raising hand: medium-dark skin tone"),
+  (&[128587, 127999], "Emoji_Modifier_Sequence", "person raising hand: dark skin tone"),
+  (&[128587, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman raising hand: dark skin tone"),
+  (&[128587, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man raising hand: dark skin tone"),
+  (&[128588, 127995], "Emoji_Modifier_Sequence", "raising hands: light skin tone"),
+  (&[128588, 127996], "Emoji_Modifier_Sequence", "raising hands: medium-light skin tone"),
+  (&[128588, 127997], "Emoji_Modifier_Sequence", "raising hands: medium skin tone"),
+  (&[128588, 127998], "Emoji_Modifier_Sequence", "raising hands: medium-dark skin tone"),
+  (&[128588, 127999], "Emoji_Modifier_Sequence", "raising hands: dark skin tone"),
+  (&[128589, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman frowning"),
+  (&[128589, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man frowning"),
+  (&[128589, 127995], "Emoji_Modifier_Sequence", "person frowning: light skin tone"),
+  (&[128589, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman frowning: light skin tone"),
+  (&[128589, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man frowning: light skin tone"),
+  (&[128589, 127996], "Emoji_Modifier_Sequence", "person frowning: medium-light skin tone"),
+  (&[128589, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman frowning: medium-light skin tone"),
+  (&[128589, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man frowning: medium-light skin tone"),
+  (&[128589, 127997], "Emoji_Modifier_Sequence", "person frowning: medium skin tone"),
+  (&[128589, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman frowning: medium skin tone"),
+  (&[128589, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man frowning: medium skin tone"),
+  (&[128589, 127998], "Emoji_Modifier_Sequence", "person frowning: medium-dark skin tone"),
+  (&[128589, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman frowning: medium-dark skin tone"),
+  (&[128589, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man frowning: medium-dark skin tone"),
+  (&[128589, 127999], "Emoji_Modifier_Sequence", "person frowning: dark skin tone"),
+  (&[128589, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman frowning: dark skin tone"),
+  (&[128589, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man frowning: dark skin tone"),
+  (&[128590, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman pouting"),
+  (&[128590, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man pouting"),
+  (&[128590, 127995], "Emoji_Modifier_Sequence", "person pouting: light skin tone"),
+  (&[128590, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman pouting: light skin tone"),
+  (&[128590, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man pouting: light skin tone"),
+  (&[128590, 127996], "Emoji_Modifier_Sequence", "person pouting: medium-light skin tone"),
+  (&[128590, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman pouting: medium-light skin tone"),
+  (&[128590, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man pouting: medium-light skin tone"),
+  (&[128590, 127997], "Emoji_Modifier_Sequence", "person pouting: medium skin tone"),
+  (&[128590, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman pouting: medium skin tone"),
+  (&[128590, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man pouting: medium skin tone"),
+  (&[128590, 127998], "Emoji_Modifier_Sequence", "person pouting: medium-dark skin tone"),
+  (&[128590, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman pouting: medium-dark skin tone"),
+  (&[128590, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man pouting: medium-dark skin tone"),
+  (&[128590, 127999], "Emoji_Modifier_Sequence", "person pouting: dark skin tone"),
+  (&[128590, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman pouting: dark skin tone"),
+  (&[128590, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man pouting: dark skin tone"),
+  (&[128591, 127995], "Emoji_Modifier_Sequence", "folded hands: light skin tone"),
+  (&[128591, 127996], "Emoji_Modifier_Sequence", "folded hands: medium-light skin tone"),
+  (&[128591, 127997], "Emoji_Modifier_Sequence", "folded hands: medium skin tone"),
+  (&[128591, 127998], "Emoji_Modifier_Sequence", "folded hands: medium-dark skin tone"),
+  (&[128591, 127999], "Emoji_Modifier_Sequence", "folded hands: dark skin tone"),
+  (&[128675, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman rowing boat"),
+  (&[128675, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man rowing boat"),
+  (&[128675, 127995], "Emoji_Modifier_Sequence", "person rowing boat: light skin tone"),
+  (&[128675, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman rowing boat: light skin tone"),
+  (&[128675, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man rowing boat: light skin tone"),
+  (&[128675, 127996], "Emoji_Modifier_Sequence", "person rowing boat: medium-light skin tone"),
+  (&[128675, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman rowing boat: medium-light skin tone"),
+  (&[128675, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man rowing boat: medium-light skin tone"),
+  (&[128675, 127997], "Emoji_Modifier_Sequence", "person rowing boat: medium skin tone"),
+  (&[128675, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman rowing boat: medium skin tone"),
+  (&[128675, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man rowing boat: medium skin tone"),
+  (&[128675, 127998], "Emoji_Modifier_Sequence", "person rowing boat: medium-dark skin tone"),
+  (&[128675, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman rowing boat: medium-dark skin tone"),
+  (&[128675, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man rowing boat: medium-dark skin tone"),
+  (&[128675, 127999], "Emoji_Modifier_Sequence", "person rowing boat: dark skin tone"),
+  (&[128675, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman rowing boat: dark skin tone"),
+  (&[128675, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man rowing boat: dark skin tone"),
+  (&[128692, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman biking"),
+  (&[128692, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man biking"),
+  (&[128692, 127995], "Emoji_Modifier_Sequence", "person biking: light skin tone"),
+  (&[128692, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman biking: light skin tone"),
+  (&[128692, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man biking: light skin tone"),
+  (&[128692, 127996], "Emoji_Modifier_Sequence", "person biking: medium-light skin tone"),
+  (&[128692, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman biking: medium-light skin tone"),
+  (&[128692, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man biking: medium-light skin tone"),
+  (&[128692, 127997], "Emoji_Modifier_Sequence", "person biking: medium skin tone"),
+  (&[128692, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman biking: medium skin tone"),
+  (&[128692, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man biking: medium skin tone"),
+  (&[128692, 127998], "Emoji_Modifier_Sequence", "person biking: medium-dark skin tone"),
+  (&[128692, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman biking: medium-dark skin tone"),
+  (&[128692, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man biking: medium-dark skin tone"),
+  (&[128692, 127999], "Emoji_Modifier_Sequence", "person biking: dark skin tone"),
+  (&[128692, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman biking: dark skin tone"),
+  (&[128692, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man biking: dark skin tone"),
+  (&[128693, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman mountain biking"),
+  (&[128693, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man mountain biking"),
+  (&[128693, 127995], "Emoji_Modifier_Sequence", "person mountain biking: light skin tone"),
+  (&[128693, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman mountain biking: light skin tone"),
+  (&[128693, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man mountain biking: light skin tone"),
+  (&[128693, 127996], "Emoji_Modifier_Sequence", "person mountain biking: medium-light skin tone"),
+  (&[128693, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman mountain biking: medium-light skin tone"),
+  (&[128693, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man mountain biking: medium-light skin tone"),
+  (&[128693, 127997], "Emoji_Modifier_Sequence", "person mountain biking: medium skin tone"),
+  (&[128693, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman mountain biking: medium skin tone"),
+  (&[128693, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man mountain biking: medium skin tone"),
+  (&[128693, 127998], "Emoji_Modifier_Sequence", "person mountain biking: medium-dark skin tone"),
+  (&[128693, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman mountain biking: medium-dark skin tone"),
+  (&[128693, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man mountain biking: medium-dark skin tone"),
+  (&[128693, 127999], "Emoji_Modifier_Sequence", "person mountain biking: dark skin tone"),
+  (&[128693, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman mountain biking: dark skin tone"),
+  (&[128693, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man mountain biking: dark skin tone"),
+  (&[128694, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman walking"),
+  (&[128694, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man walking"),
+  (&[128694, 127995], "Emoji_Modifier_Sequence", "person walking: light skin tone"),
+  (&[128694, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman walking: light skin tone"),
+  (&[128694, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man walking: light skin tone"),
+  (&[128694, 127996], "Emoji_Modifier_Sequence", "person walking: medium-light skin tone"),
+  (&[128694, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman walking: medium-light skin tone"),
+  (&[128694, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man walking: medium-light skin tone"),
+  (&[128694, 127997], "Emoji_Modifier_Sequence", "person walking: medium skin tone"),
+  (&[128694, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman walking: medium skin tone"),
+  (&[128694, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man walking: medium skin tone"),
+  (&[128694, 127998], "Emoji_Modifier_Sequence", "person walking: medium-dark skin tone"),
+  (&[128694, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman walking: medium-dark skin tone"),
+  (&[128694, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man walking: medium-dark skin tone"),
+  (&[128694, 127999], "Emoji_Modifier_Sequence", "person walking: dark skin tone"),
+  (&[128694, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman walking: dark skin tone"),
+  (&[128694, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man walking: dark skin tone"),
+  (&[128704, 127995], "Emoji_Modifier_Sequence", "person taking bath: light skin tone"),
+  (&[128704, 127996], "Emoji_Modifier_Sequence", "person taking bath: medium-light skin tone"),
+  (&[128704, 127997], "Emoji_Modifier_Sequence", "person taking bath: medium skin tone"),
+  (&[128704, 127998], "Emoji_Modifier_Sequence", "person taking bath: medium-dark skin tone"),
+  (&[128704, 127999], "Emoji_Modifier_Sequence", "person taking bath: dark skin tone"),
+  (&[128716, 127995], "Emoji_Modifier_Sequence", "person in bed: light skin tone"),
+  (&[128716, 127996], "Emoji_Modifier_Sequence", "person in bed: medium-light skin tone"),
+  (&[128716, 127997], "Emoji_Modifier_Sequence", "person in bed: medium skin tone"),
+  (&[128716, 127998], "Emoji_Modifier_Sequence", "person in bed: medium-dark skin tone"),
+  (&[128716, 127999], "Emoji_Modifier_Sequence", "person in bed: dark skin tone"),
+  (&[129304, 127995], "Emoji_Modifier_Sequence", "sign of the horns: light skin tone"),
+  (&[129304, 127996], "Emoji_Modifier_Sequence", "sign of the horns: medium-light skin tone"),
+  (&[129304, 127997], "Emoji_Modifier_Sequence", "sign of the horns: medium skin tone"),
+  (&[129304, 127998], "Emoji_Modifier_Sequence", "sign of the horns: medium-dark skin tone"),
+  (&[129304, 127999], "Emoji_Modifier_Sequence", "sign of the horns: dark skin tone"),
+  (&[129305, 127995], "Emoji_Modifier_Sequence", "call me hand: light skin tone"),
+  (&[129305, 127996], "Emoji_Modifier_Sequence", "call me hand: medium-light skin tone"),
+  (&[129305, 127997], "Emoji_Modifier_Sequence", "call me hand: medium skin tone"),
+  (&[129305, 127998], "Emoji_Modifier_Sequence", "call me hand: medium-dark skin tone"),
+  (&[129305, 127999], "Emoji_Modifier_Sequence", "call me hand: dark skin tone"),
+  (&[129306, 127995], "Emoji_Modifier_Sequence", "raised back of hand: light skin tone"),
+  (&[129306, 127996], "Emoji_Modifier_Sequence", "raised back of hand: medium-light skin tone"),
+  (&[129306, 127997], "Emoji_Modifier_Sequence", "raised back of hand: medium skin tone"),
+  (&[129306, 127998], "Emoji_Modifier_Sequence", "raised back of hand: medium-dark skin tone"),
+  (&[129306, 127999], "Emoji_Modifier_Sequence", "raised back of hand: dark skin tone"),
+  (&[129307, 127995], "Emoji_Modifier_Sequence", "left-facing fist: light skin tone"),
+  (&[129307, 127996], "Emoji_Modifier_Sequence", "left-facing fist: medium-light skin tone"),
+  (&[129307, 127997], "Emoji_Modifier_Sequence", "left-facing fist: medium skin tone"),
+  (&[129307, 127998], "Emoji_Modifier_Sequence", "left-facing fist: medium-dark skin tone"),
+  (&[129307, 127999], "Emoji_Modifier_Sequence", "left-facing fist: dark skin tone"),
+  (&[129308, 127995], "Emoji_Modifier_Sequence", "right-facing fist: light skin tone"),
+  (&[129308, 127996], "Emoji_Modifier_Sequence", "right-facing fist: medium-light skin tone"),
+  (&[129308, 127997], "Emoji_Modifier_Sequence", "right-facing fist: medium skin tone"),
+  (&[129308, 127998], "Emoji_Modifier_Sequence", "right-facing fist: medium-dark skin tone"),
+  (&[129308, 127999], "Emoji_Modifier_Sequence", "right-facing fist: dark skin tone"),
+  (&[129310, 127995], "Emoji_Modifier_Sequence", "crossed fingers: light skin tone"),
+  (&[129310, 127996], "Emoji_Modifier_Sequence", "crossed fingers: medium-light skin tone"),
+  (&[129310, 127997], "Emoji_Modifier_Sequence", "crossed fingers: medium skin tone"),
+  (&[129310, 127998], "Emoji_Modifier_Sequence", "crossed fingers: medium-dark skin tone"),
+  (&[129310, 127999], "Emoji_Modifier_Sequence", "crossed fingers: dark skin tone"),
+  (&[129311, 127995], "Emoji_Modifier_Sequence", "love-you gesture: light skin tone"),
+  (&[129311, 127996], "Emoji_Modifier_Sequence", "love-you gesture: medium-light skin tone"),
+  (&[129311, 127997], "Emoji_Modifier_Sequence", "love-you gesture: medium skin tone"),
+  (&[129311, 127998], "Emoji_Modifier_Sequence", "love-you gesture: medium-dark skin tone"),
+  (&[129311, 127999], "Emoji_Modifier_Sequence", "love-you gesture: dark skin tone"),
+  (&[129318, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman facepalming"),
+  (&[129318, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man facepalming"),
+  (&[129318, 127995], "Emoji_Modifier_Sequence", "person facepalming: light skin tone"),
+  (&[129318, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman facepalming: light skin tone"),
+  (&[129318, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man facepalming: light skin tone"),
+  (&[129318, 127996], "Emoji_Modifier_Sequence", "person facepalming: medium-light skin tone"),
+  (&[129318, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman facepalming: medium-light skin tone"),
+  (&[129318, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man facepalming: medium-light skin tone"),
+  (&[129318, 127997], "Emoji_Modifier_Sequence", "person facepalming: medium skin tone"),
+  (&[129318, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman facepalming: medium skin tone"),
+  (&[129318, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man facepalming: medium skin tone"),
+  (&[129318, 127998], "Emoji_Modifier_Sequence", "person facepalming: medium-dark skin tone"),
+  (&[129318, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman facepalming: medium-dark skin tone"),
+  (&[129318, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man facepalming: medium-dark skin tone"),
+  (&[129318, 127999], "Emoji_Modifier_Sequence", "person facepalming: dark skin tone"),
+  (&[129318, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman facepalming: dark skin tone"),
+  (&[129318, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man facepalming: dark skin tone"),
+  (&[129328, 127995], "Emoji_Modifier_Sequence", "pregnant woman: light skin tone"),
+  (&[129328, 127996], "Emoji_Modifier_Sequence", "pregnant woman: medium-light skin tone"),
+  (&[129328, 127997], "Emoji_Modifier_Sequence", "pregnant woman: medium skin tone"),
+  (&[129328, 127998], "Emoji_Modifier_Sequence", "pregnant woman: medium-dark skin tone"),
+  (&[129328, 127999], "Emoji_Modifier_Sequence", "pregnant woman: dark skin tone"),
+  (&[129329, 127995], "Emoji_Modifier_Sequence", "breast-feeding: light skin tone"),
+  (&[129329, 127996], "Emoji_Modifier_Sequence", "breast-feeding: medium-light skin tone"),
+  (&[129329, 127997], "Emoji_Modifier_Sequence", "breast-feeding: medium skin tone"),
+  (&[129329, 127998], "Emoji_Modifier_Sequence", "breast-feeding: medium-dark skin tone"),
+  (&[129329, 127999], "Emoji_Modifier_Sequence", "breast-feeding: dark skin tone"),
+  (&[129330, 127995], "Emoji_Modifier_Sequence", "palms up together: light skin tone"),
+  (&[129330, 127996], "Emoji_Modifier_Sequence", "palms up together: medium-light skin tone"),
+  (&[129330, 127997], "Emoji_Modifier_Sequence", "palms up together: medium skin tone"),
+  (&[129330, 127998], "Emoji_Modifier_Sequence", "palms up together: medium-dark skin tone"),
+  (&[129330, 127999], "Emoji_Modifier_Sequence", "palms up together: dark skin tone"),
+  (&[129331, 127995], "Emoji_Modifier_Sequence", "selfie: light skin tone"),
+  (&[129331, 127996], "Emoji_Modifier_Sequence", "selfie: medium-light skin tone"),
+  (&[129331, 127997], "Emoji_Modifier_Sequence", "selfie: medium skin tone"),
+  (&[129331, 127998], "Emoji_Modifier_Sequence", "selfie: medium-dark skin tone"),
+  (&[129331, 127999], "Emoji_Modifier_Sequence", "selfie: dark skin tone"),
+  (&[129332, 127995], "Emoji_Modifier_Sequence", "prince: light skin tone"),
+  (&[129332, 127996], "Emoji_Modifier_Sequence", "prince: medium-light skin tone"),
+  (&[129332, 127997], "Emoji_Modifier_Sequence", "prince: medium skin tone"),
+  (&[129332, 127998], "Emoji_Modifier_Sequence", "prince: medium-dark skin tone"),
+  (&[129332, 127999], "Emoji_Modifier_Sequence", "prince: dark skin tone"),
+  (&[129333, 127995], "Emoji_Modifier_Sequence", "person in tuxedo: light skin tone"),
+  (&[129333, 127996], "Emoji_Modifier_Sequence", "person in tuxedo: medium-light skin tone"),
+  (&[129333, 127997], "Emoji_Modifier_Sequence", "person in tuxedo: medium skin tone"),
+  (&[129333, 127998], "Emoji_Modifier_Sequence", "person in tuxedo: medium-dark skin tone"),
+  (&[129333, 127999], "Emoji_Modifier_Sequence", "person in tuxedo: dark skin tone"),
+  (&[129334, 127995], "Emoji_Modifier_Sequence", "Mrs. Claus: light skin tone"),
+  (&[129334, 127996], "Emoji_Modifier_Sequence", "Mrs. Claus: medium-light skin tone"),
+  (&[129334, 127997], "Emoji_Modifier_Sequence", "Mrs. Claus: medium skin tone"),
+  (&[129334, 127998], "Emoji_Modifier_Sequence", "Mrs. Claus: medium-dark skin tone"),
+  (&[129334, 127999], "Emoji_Modifier_Sequence", "Mrs. Claus: dark skin tone"),
+  (&[129335, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman shrugging"),
+  (&[129335, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man shrugging"),
+  (&[129335, 127995], "Emoji_Modifier_Sequence", "person shrugging: light skin tone"),
+  (&[129335, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman shrugging: light skin tone"),
+  (&[129335, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man shrugging: light skin tone"),
+  (&[129335, 127996], "Emoji_Modifier_Sequence", "person shrugging: medium-light skin tone"),
+  (&[129335, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman shrugging: medium-light skin tone"),
+  (&[129335, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man shrugging: medium-light skin tone"),
+  (&[129335, 127997], "Emoji_Modifier_Sequence", "person shrugging: medium skin tone"),
+  (&[129335, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman shrugging: medium skin tone"),
+  (&[129335, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man shrugging: medium skin tone"),
+  (&[129335, 127998], "Emoji_Modifier_Sequence", "person shrugging: medium-dark skin tone"),
+  (&[129335, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman shrugging: medium-dark skin tone"),
+  (&[129335, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man shrugging: medium-dark skin tone"),
+  (&[129335, 127999], "Emoji_Modifier_Sequence", "person shrugging: dark skin tone"),
+  (&[129335, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman shrugging: dark skin tone"),
+  (&[129335, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man shrugging: dark skin tone"),
+  (&[129336, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman cartwheeling"),
+  (&[129336, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man cartwheeling"),
+  (&[129336, 127995], "Emoji_Modifier_Sequence", "person cartwheeling: light skin tone"),
+  (&[129336, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman cartwheeling: light skin tone"),
+  (&[129336, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man cartwheeling: light skin tone"),
+  (&[129336, 127996], "Emoji_Modifier_Sequence", "person cartwheeling: medium-light skin tone"),
+  (&[129336, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman cartwheeling: medium-light skin tone"),
+  (&[129336, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man cartwheeling: medium-light skin tone"),
+  (&[129336, 127997], "Emoji_Modifier_Sequence", "person cartwheeling: medium skin tone"),
+  (&[129336, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman cartwheeling: medium skin tone"),
+  (&[129336, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man cartwheeling: medium skin tone"),
+  (&[129336, 127998], "Emoji_Modifier_Sequence", "person cartwheeling: medium-dark skin tone"),
+  (&[129336, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman cartwheeling: medium-dark skin tone"),
+  (&[129336, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man cartwheeling: medium-dark skin tone"),
+  (&[129336, 127999], "Emoji_Modifier_Sequence", "person cartwheeling: dark skin tone"),
+  (&[129336, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman cartwheeling: dark skin tone"),
+  (&[129336, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man cartwheeling: dark skin tone"),
+  (&[129337, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman juggling"),
+  (&[129337, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man juggling"),
+  (&[129337, 127995], "Emoji_Modifier_Sequence", "person juggling: light skin tone"),
+  (&[129337, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman juggling: light skin tone"),
+  (&[129337, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man juggling: light skin tone"),
+  (&[129337, 127996], "Emoji_Modifier_Sequence", "person juggling: medium-light skin tone"),
+  (&[129337, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman juggling: medium-light skin tone"),
+  (&[129337, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man juggling: medium-light skin tone"),
+  (&[129337, 127997], "Emoji_Modifier_Sequence", "person juggling: medium skin tone"),
+  (&[129337, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman juggling: medium skin tone"),
+  (&[129337, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man juggling: medium skin tone"),
+  (&[129337, 127998], "Emoji_Modifier_Sequence", "person juggling: medium-dark skin tone"),
+  (&[129337, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman juggling: medium-dark skin tone"),
+  (&[129337, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man juggling: medium-dark skin tone"),
+  (&[129337, 127999], "Emoji_Modifier_Sequence", "person juggling: dark skin tone"),
+  (&[129337, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman juggling: dark skin tone"),
+  (&[129337, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man juggling: dark skin tone"),
+  (&[129340, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "women wrestling"),
+  (&[129340, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "men wrestling"),
+  (&[129341, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman playing water polo"),
+  (&[129341, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man playing water polo"),
+  (&[129341, 127995], "Emoji_Modifier_Sequence", "person playing water polo: light skin tone"),
+  (&[129341, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman playing water polo: light skin tone"),
+  (&[129341, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man playing water polo: light skin tone"),
+  (&[129341, 127996], "Emoji_Modifier_Sequence", "person playing water polo: medium-light skin tone"),
+  (&[129341, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman playing water polo: medium-light skin tone"),
+  (&[129341, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man playing water polo: medium-light skin tone"),
+  (&[129341, 127997], "Emoji_Modifier_Sequence", "person playing water polo: medium skin tone"),
+  (&[129341, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman playing water polo: medium skin tone"),
+  (&[129341, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man playing water polo: medium skin tone"),
+  (&[129341, 127998], "Emoji_Modifier_Sequence", "person playing water polo: medium-dark skin tone"),
+  (&[129341, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman playing water polo: medium-dark skin tone"),
+  (&[129341, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man playing water polo: medium-dark skin tone"),
+  (&[129341, 127999], "Emoji_Modifier_Sequence", "person playing water polo: dark skin tone"),
+  (&[129341, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman playing water polo: dark skin tone"),
+  (&[129341, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man playing water polo: dark skin tone"),
+  (&[129342, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman playing handball"),
+  (&[129342, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man playing handball"),
+  (&[129342, 127995], "Emoji_Modifier_Sequence", "person playing handball: light skin tone"),
+  (&[129342, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman playing handball: light skin tone"),
+  (&[129342, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man playing handball: light skin tone"),
+  (&[129342, 127996], "Emoji_Modifier_Sequence", "person playing handball: medium-light skin tone"),
+  (&[129342, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman playing handball: medium-light skin tone"),
+  (&[129342, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man playing handball: medium-light skin tone"),
+  (&[129342, 127997], "Emoji_Modifier_Sequence", "person playing handball: medium skin tone"),
+  (&[129342, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman playing handball: medium skin tone"),
+  (&[129342, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man playing handball: medium skin tone"),
+  (&[129342, 127998], "Emoji_Modifier_Sequence", "person playing handball: medium-dark skin tone"),
+  (&[129342, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman playing handball: medium-dark skin tone"),
+  (&[129342, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man playing handball: medium-dark skin tone"),
+  (&[129342, 127999], "Emoji_Modifier_Sequence", "person playing handball: dark skin tone"),
+  (&[129342, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman playing handball: dark skin tone"),
+  (&[129342, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man playing handball: dark skin tone"),
+  (&[129489, 127995], "Emoji_Modifier_Sequence", "person: light skin tone"),
+  (&[129489, 127996], "Emoji_Modifier_Sequence", "person: medium-light skin tone"),
+  (&[129489, 127997], "Emoji_Modifier_Sequence", "person: medium skin tone"),
+  (&[129489, 127998], "Emoji_Modifier_Sequence", "person: medium-dark skin tone"),
+  (&[129489, 127999], "Emoji_Modifier_Sequence", "person: dark skin tone"),
+  (&[129490, 127995], "Emoji_Modifier_Sequence", "child: light skin tone"),
+  (&[129490, 127996], "Emoji_Modifier_Sequence", "child: medium-light skin tone"),
+  (&[129490, 127997], "Emoji_Modifier_Sequence", "child: medium skin tone"),
+  (&[129490, 127998], "Emoji_Modifier_Sequence", "child: medium-dark skin tone"),
+  (&[129490, 127999], "Emoji_Modifier_Sequence", "child: dark skin tone"),
+  (&[129491, 127995], "Emoji_Modifier_Sequence", "older person: light skin tone"),
+  (&[129491, 127996], "Emoji_Modifier_Sequence", "older person: medium-light skin tone"),
+  (&[129491, 127997], "Emoji_Modifier_Sequence", "older person: medium skin tone"),
+  (&[129491, 127998], "Emoji_Modifier_Sequence", "older person: medium-dark skin tone"),
+  (&[129491, 127999], "Emoji_Modifier_Sequence", "older person: dark skin tone"),
+  (&[129492, 127995], "Emoji_Modifier_Sequence", "person: light skin tone, beard"),
+  (&[129492, 127996], "Emoji_Modifier_Sequence", "person: medium-light skin tone, beard"),
+  (&[129492, 127997], "Emoji_Modifier_Sequence", "person: medium skin tone, beard"),
+  (&[129492, 127998], "Emoji_Modifier_Sequence", "person: medium-dark skin tone, beard"),
+  (&[129492, 127999], "Emoji_Modifier_Sequence", "person: dark skin tone, beard"),
+  (&[129493, 127995], "Emoji_Modifier_Sequence", "woman with headscarf: light skin tone"),
+  (&[129493, 127996], "Emoji_Modifier_Sequence", "woman with headscarf: medium-light skin tone"),
+  (&[129493, 127997], "Emoji_Modifier_Sequence", "woman with headscarf: medium skin tone"),
+  (&[129493, 127998], "Emoji_Modifier_Sequence", "woman with headscarf: medium-dark skin tone"),
+  (&[129493, 127999], "Emoji_Modifier_Sequence", "woman with headscarf: dark skin tone"),
+  (&[129494, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman in steamy room"),
+  (&[129494, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man in steamy room"),
+  (&[129494, 127995], "Emoji_Modifier_Sequence", "person in steamy room: light skin tone"),
+  (&[129494, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman in steamy room: light skin tone"),
+  (&[129494, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man in steamy room: light skin tone"),
+  (&[129494, 127996], "Emoji_Modifier_Sequence", "person in steamy room: medium-light skin tone"),
+  (&[129494, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman in steamy room: medium-light skin tone"),
+  (&[129494, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man in steamy room: medium-light skin tone"),
+  (&[129494, 127997], "Emoji_Modifier_Sequence", "person in steamy room: medium skin tone"),
+  (&[129494, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman in steamy room: medium skin tone"),
+  (&[129494, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man in steamy room: medium skin tone"),
+  (&[129494, 127998], "Emoji_Modifier_Sequence", "person in steamy room: medium-dark skin tone"),
+  (&[129494, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman in steamy room: medium-dark skin tone"),
+  (&[129494, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man in steamy room: medium-dark skin tone"),
+  (&[129494, 127999], "Emoji_Modifier_Sequence", "person in steamy room: dark skin tone"),
+  (&[129494, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman in steamy room: dark skin tone"),
+  (&[129494, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man in steamy room: dark skin tone"),
+  (&[129495, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman climbing"),
+  (&[129495, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man climbing"),
+  (&[129495, 127995], "Emoji_Modifier_Sequence", "person climbing: light skin tone"),
+  (&[129495, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman climbing: light skin tone"),
+  (&[129495, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man climbing: light skin tone"),
+  (&[129495, 127996], "Emoji_Modifier_Sequence", "person climbing: medium-light skin tone"),
+  (&[129495, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman climbing: medium-light skin tone"),
+  (&[129495, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man climbing: medium-light skin tone"),
+  (&[129495, 127997], "Emoji_Modifier_Sequence", "person climbing: medium skin tone"),
+  (&[129495, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman climbing: medium skin tone"),
+  (&[129495, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man climbing: medium skin tone"),
+  (&[129495, 127998], "Emoji_Modifier_Sequence", "person climbing: medium-dark skin tone"),
+  (&[129495, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman climbing: medium-dark skin tone"),
+  (&[129495, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man climbing: medium-dark skin tone"),
+  (&[129495, 127999], "Emoji_Modifier_Sequence", "person climbing: dark skin tone"),
+  (&[129495, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman climbing: dark skin tone"),
+  (&[129495, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man climbing: dark skin tone"),
+  (&[129496, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman in lotus position"),
+  (&[129496, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man in lotus position"),
+  (&[129496, 127995], "Emoji_Modifier_Sequence", "person in lotus position: light skin tone"),
+  (&[129496, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman in lotus position: light skin tone"),
+  (&[129496, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man in lotus position: light skin tone"),
+  (&[129496, 127996], "Emoji_Modifier_Sequence", "person in lotus position: medium-light skin tone"),
+  (&[129496, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman in lotus position: medium-light skin tone"),
+  (&[129496, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man in lotus position: medium-light skin tone"),
+  (&[129496, 127997], "Emoji_Modifier_Sequence", "person in lotus position: medium skin tone"),
+  (&[129496, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman in lotus position: medium skin tone"),
+  (&[129496, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man in lotus position: medium skin tone"),
+  (&[129496, 127998], "Emoji_Modifier_Sequence", "person in lotus position: medium-dark skin tone"),
+  (&[129496, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman in lotus position: medium-dark skin tone"),
+  (&[129496, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man in lotus position: medium-dark skin tone"),
+  (&[129496, 127999], "Emoji_Modifier_Sequence", "person in lotus position: dark skin tone"),
+  (&[129496, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman in lotus position: dark skin tone"),
+  (&[129496, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man in lotus position: dark skin tone"),
+  (&[129497, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman mage"),
+  (&[129497, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man mage"),
+  (&[129497, 127995], "Emoji_Modifier_Sequence", "mage: light skin tone"),
+  (&[129497, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman mage: light skin tone"),
+  (&[129497, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man mage: light skin tone"),
+  (&[129497, 127996], "Emoji_Modifier_Sequence", "mage: medium-light skin tone"),
+  (&[129497, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman mage: medium-light skin tone"),
+  (&[129497, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man mage: medium-light skin tone"),
+  (&[129497, 127997], "Emoji_Modifier_Sequence", "mage: medium skin tone"),
+  (&[129497, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman mage: medium skin tone"),
+  (&[129497, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man mage: medium skin tone"),
+  (&[129497, 127998], "Emoji_Modifier_Sequence", "mage: medium-dark skin tone"),
+  (&[129497, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman mage: medium-dark skin tone"),
+  (&[129497, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man mage: medium-dark skin tone"),
+  (&[129497, 127999], "Emoji_Modifier_Sequence", "mage: dark skin tone"),
+  (&[129497, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman mage: dark skin tone"),
+  (&[129497, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man mage: dark skin tone"),
+  (&[129498, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman fairy"),
+  (&[129498, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man fairy"),
+  (&[129498, 127995], "Emoji_Modifier_Sequence", "fairy: light skin tone"),
+  (&[129498, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman fairy: light skin tone"),
+  (&[129498, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man fairy: light skin tone"),
+  (&[129498, 127996], "Emoji_Modifier_Sequence", "fairy: medium-light skin tone"),
+  (&[129498, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman fairy: medium-light skin tone"),
+  (&[129498, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man fairy: medium-light skin tone"),
+  (&[129498, 127997], "Emoji_Modifier_Sequence", "fairy: medium skin tone"),
+  (&[129498, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman fairy: medium skin tone"),
+  (&[129498, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man fairy: medium skin tone"),
+  (&[129498, 127998], "Emoji_Modifier_Sequence", "fairy: medium-dark skin tone"),
+  (&[129498, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman fairy: medium-dark skin tone"),
+  (&[129498, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man fairy: medium-dark skin tone"),
+  (&[129498, 127999], "Emoji_Modifier_Sequence", "fairy: dark skin tone"),
+  (&[129498, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman fairy: dark skin tone"),
+  (&[129498, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man fairy: dark skin tone"),
+  (&[129499, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman vampire"),
+  (&[129499, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man vampire"),
+  (&[129499, 127995], "Emoji_Modifier_Sequence", "vampire: light skin tone"),
+  (&[129499, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman vampire: light skin tone"),
+  (&[129499, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man vampire: light skin tone"),
+  (&[129499, 127996], "Emoji_Modifier_Sequence", "vampire: medium-light skin tone"),
+  (&[129499, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman vampire: medium-light skin tone"),
+  (&[129499, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man vampire: medium-light skin tone"),
+  (&[129499, 127997], "Emoji_Modifier_Sequence", "vampire: medium skin tone"),
+  (&[129499, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman vampire: medium skin tone"),
+  (&[129499, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man vampire: medium skin tone"),
+  (&[129499, 127998], "Emoji_Modifier_Sequence", "vampire: medium-dark skin tone"),
+  (&[129499, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman vampire: medium-dark skin tone"),
+  (&[129499, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man vampire: medium-dark skin tone"),
+  (&[129499, 127999], "Emoji_Modifier_Sequence", "vampire: dark skin tone"),
+  (&[129499, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman vampire: dark skin tone"),
+  (&[129499, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man vampire: dark skin tone"),
+  (&[129500, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "mermaid"),
+  (&[129500, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "merman"),
+  (&[129500, 127995], "Emoji_Modifier_Sequence", "merperson: light skin tone"),
+  (&[129500, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "mermaid: light skin tone"),
+  (&[129500, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "merman: light skin tone"),
+  (&[129500, 127996], "Emoji_Modifier_Sequence", "merperson: medium-light skin tone"),
+  (&[129500, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "mermaid: medium-light skin tone"),
+  (&[129500, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "merman: medium-light skin tone"),
+  (&[129500, 127997], "Emoji_Modifier_Sequence", "merperson: medium skin tone"),
+  (&[129500, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "mermaid: medium skin tone"),
+  (&[129500, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "merman: medium skin tone"),
+  (&[129500, 127998], "Emoji_Modifier_Sequence", "merperson: medium-dark skin tone"),
+  (&[129500, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "mermaid: medium-dark skin tone"),
+  (&[129500, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "merman: medium-dark skin tone"),
+  (&[129500, 127999], "Emoji_Modifier_Sequence", "merperson: dark skin tone"),
+  (&[129500, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "mermaid: dark skin tone"),
+  (&[129500, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "merman: dark skin tone"),
+  (&[129501, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman elf"),
+  (&[129501, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man elf"),
+  (&[129501, 127995], "Emoji_Modifier_Sequence", "elf: light skin tone"),
+  (&[129501, 127995, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman elf: light skin tone"),
+  (&[129501, 127995, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man elf: light skin tone"),
+  (&[129501, 127996], "Emoji_Modifier_Sequence", "elf: medium-light skin tone"),
+  (&[129501, 127996, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman elf: medium-light skin tone"),
+  (&[129501, 127996, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man elf: medium-light skin tone"),
+  (&[129501, 127997], "Emoji_Modifier_Sequence", "elf: medium skin tone"),
+  (&[129501, 127997, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman elf: medium skin tone"),
+  (&[129501, 127997, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man elf: medium skin tone"),
+  (&[129501, 127998], "Emoji_Modifier_Sequence", "elf: medium-dark skin tone"),
+  (&[129501, 127998, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman elf: medium-dark skin tone"),
+  (&[129501, 127998, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man elf: medium-dark skin tone"),
+  (&[129501, 127999], "Emoji_Modifier_Sequence", "elf: dark skin tone"),
+  (&[129501, 127999, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman elf: dark skin tone"),
+  (&[129501, 127999, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man elf: dark skin tone"),
+  (&[129502, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman genie"),
+  (&[129502, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man genie"),
+  (&[129503, 8205, 9792, 65039], "Emoji_ZWJ_Sequence", "woman zombie"),
+  (&[129503, 8205, 9794, 65039], "Emoji_ZWJ_Sequence", "man zombie"),
+];
@@ -0,0 +1,362 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//  ucd-generate emoji-variation-sequences /home/andrew/tmp/emoji-5.0/
+//
+// ucd-generate is available on crates.io.
+
+pub const EMOJI_VARIATION_SEQUENCES: &'static [(u32, u32, &'static str)] = &[
+  (35, 65038, "text style"), (35, 65039, "emoji style"),
+  (42, 65038, "text style"), (42, 65039, "emoji style"),
+  (48, 65038, "text style"), (48, 65039, "emoji style"),
+  (49, 65038, "text style"), (49, 65039, "emoji style"),
+  (50, 65038, "text style"), (50, 65039, "emoji style"),
+  (51, 65038, "text style"), (51, 65039, "emoji style"),
+  (52, 65038, "text style"), (52, 65039, "emoji style"),
+  (53, 65038, "text style"), (53, 65039, "emoji style"),
+  (54, 65038, "text style"), (54, 65039, "emoji style"),
+  (55, 65038, "text style"), (55, 65039, "emoji style"),
+  (56, 65038, "text style"), (56, 65039, "emoji style"),
+  (57, 65038, "text style"), (57, 65039, "emoji style"),
+  (169, 65038, "text style"), (169, 65039, "emoji style"),
+  (174, 65038, "text style"), (174, 65039, "emoji style"),
+  (8252, 65038, "text style"), (8252, 65039, "emoji style"),
+  (8265, 65038, "text style"), (8265, 65039, "emoji style"),
+  (8482, 65038, "text style"), (8482, 65039, "emoji style"),
+  (8505, 65038, "text style"), (8505, 65039, "emoji style"),
+  (8596, 65038, "text style"), (8596, 65039, "emoji style"),
+  (8597, 65038, "text style"), (8597, 65039, "emoji style"),
+  (8598, 65038, "text style"), (8598, 65039, "emoji style"),
+  (8599, 65038, "text style"), (8599, 65039, "emoji style"),
+  (8600, 65038, "text style"), (8600, 65039, "emoji style"),
+  (8601, 65038, "text style"), (8601, 65039, "emoji style"),
+  (8617, 65038, "text style"), (8617, 65039, "emoji style"),
+  (8618, 65038, "text style"), (8618, 65039, "emoji style"),
+  (8986, 65038, "text style"), (8986, 65039, "emoji style"),
+  (8987, 65038, "text style"), (8987, 65039, "emoji style"),
+  (9000, 65038, "text style"), (9000, 65039, "emoji style"),
+  (9167, 65038, "text style"), (9167, 65039, "emoji style"),
+  (9193, 65038, "text style"), (9193, 65039, "emoji style"),
+  (9194, 65038, "text style"), (9194, 65039, "emoji style"),
+  (9197, 65038, "text style"), (9197, 65039, "emoji style"),
+  (9198, 65038, "text style"), (9198, 65039, "emoji style"),
+  (9199, 65038, "text style"), (9199, 65039, "emoji style"),
+  (9201, 65038, "text style"), (9201, 65039, "emoji style"),
+  (9202, 65038, "text style"), (9202, 65039, "emoji style"),
+  (9203, 65038, "text style"), (9203, 65039, "emoji style"),
+  (9208, 65038, "text style"), (9208, 65039, "emoji style"),
+  (9209, 65038, "text style"), (9209, 65039, "emoji style"),
+  (9210, 65038, "text style"), (9210, 65039, "emoji style"),
+  (9410, 65038, "text style"), (9410, 65039, "emoji style"),
+  (9642, 65038, "text style"), (9642, 65039, "emoji style"),
+  (9643, 65038, "text style"), (9643, 65039, "emoji style"),
+  (9654, 65038, "text style"), (9654, 65039, "emoji style"),
+  (9664, 65038, "text style"), (9664, 65039, "emoji style"),
+  (9723, 65038, "text style"), (9723, 65039, "emoji style"),
+  (9724, 65038, "text style"), (9724, 65039, "emoji style"),
+  (9725, 65038, "text style"), (9725, 65039, "emoji style"),
+  (9726, 65038, "text style"), (9726, 65039, "emoji style"),
+  (9728, 65038, "text style"), (9728, 65039, "emoji style"),
+  (9729, 65038, "text style"), (9729, 65039, "emoji style"),
+  (9730, 65038, "text style"), (9730, 65039, "emoji style"),
+  (9731, 65038, "text style"), (9731, 65039, "emoji style"),
+  (9732, 65038, "text style"), (9732, 65039, "emoji style"),
+  (9742, 65038, "text style"), (9742, 65039, "emoji style"),
+  (9745, 65038, "text style"), (9745, 65039, "emoji style"),
+  (9748, 65038, "text style"), (9748, 65039, "emoji style"),
+  (9749, 65038, "text style"), (9749, 65039, "emoji style"),
+  (9752, 65038, "text style"), (9752, 65039, "emoji style"),
+  (9757, 65038, "text style"), (9757, 65039, "emoji style"),
+  (9760, 65038, "text style"), (9760, 65039, "emoji style"),
+  (9762, 65038, "text style"), (9762, 65039, "emoji style"),
+  (9763, 65038, "text style"), (9763, 65039, "emoji style"),
+  (9766, 65038, "text style"), (9766, 65039, "emoji style"),
+  (9770, 65038, "text style"), (9770, 65039, "emoji style"),
+  (9774, 65038, "text style"), (9774, 65039, "emoji style"),
+  (9775, 65038, "text style"), (9775, 65039, "emoji style"),
+  (9784, 65038, "text style"), (9784, 65039, "emoji style"),
+  (9785, 65038, "text style"), (9785, 65039, "emoji style"),
+  (9786, 65038, "text style"), (9786, 65039, "emoji style"),
+  (9792, 65038, "text style"), (9792, 65039, "emoji style"),
+  (9794, 65038, "text style"), (9794, 65039, "emoji style"),
+  (9800, 65038, "text style"), (9800, 65039, "emoji style"),
+  (9801, 65038, "text style"), (9801, 65039, "emoji style"),
+  (9802, 65038, "text style"), (9802, 65039, "emoji style"),
+  (9803, 65038, "text style"), (9803, 65039, "emoji style"),
+  (9804, 65038, "text style"), (9804, 65039, "emoji style"),
+  (9805, 65038, "text style"), (9805, 65039, "emoji style"),
+  (9806, 65038, "text style"), (9806, 65039, "emoji style"),
+  (9807, 65038, "text style"), (9807, 65039, "emoji style"),
+  (9808, 65038, "text style"), (9808, 65039, "emoji style"),
+  (9809, 65038, "text style"), (9809, 65039, "emoji style"),
+  (9810, 65038, "text style"), (9810, 65039, "emoji style"),
+  (9811, 65038, "text style"), (9811, 65039, "emoji style"),
+  (9823, 65038, "text style"), (9823, 65039, "emoji style"),
+  (9824, 65038, "text style"), (9824, 65039, "emoji style"),
+  (9827, 65038, "text style"), (9827, 65039, "emoji style"),
+  (9829, 65038, "text style"), (9829, 65039, "emoji style"),
+  (9830, 65038, "text style"), (9830, 65039, "emoji style"),
+  (9832, 65038, "text style"), (9832, 65039, "emoji style"),
+  (9851, 65038, "text style"), (9851, 65039, "emoji style"),
+  (9854, 65038, "text style"), (9854, 65039, "emoji style"),
+  (9855, 65038, "text style"), (9855, 65039, "emoji style"),
+  (9874, 65038, "text style"), (9874, 65039, "emoji style"),
+  (9875, 65038, "text style"), (9875, 65039, "emoji style"),
+  (9876, 65038, "text style"), (9876, 65039, "emoji style"),
+  (9877, 65038, "text style"), (9877, 65039, "emoji style"),
+  (9878, 65038, "text style"), (9878, 65039, "emoji style"),
+  (9879, 65038, "text style"), (9879, 65039, "emoji style"),
+  (9881, 65038, "text style"), (9881, 65039, "emoji style"),
+  (9883, 65038, "text style"), (9883, 65039, "emoji style"),
+  (9884, 65038, "text style"), (9884, 65039, "emoji style"),
+  (9888, 65038, "text style"), (9888, 65039, "emoji style"),
+  (9889, 65038, "text style"), (9889, 65039, "emoji style"),
+  (9895, 65038, "text style"), (9895, 65039, "emoji style"),
+  (9898, 65038, "text style"), (9898, 65039, "emoji style"),
+  (9899, 65038, "text style"), (9899, 65039, "emoji style"),
+  (9904, 65038, "text style"), (9904, 65039, "emoji style"),
+  (9905, 65038, "text style"), (9905, 65039, "emoji style"),
+  (9917, 65038, "text style"), (9917, 65039, "emoji style"),
+  (9918, 65038, "text style"), (9918, 65039, "emoji style"),
+  (9924, 65038, "text style"), (9924, 65039, "emoji style"),
+  (9925, 65038, "text style"), (9925, 65039, "emoji style"),
+  (9928, 65038, "text style"), (9928, 65039, "emoji style"),
+  (9935, 65038, "text style"), (9935, 65039, "emoji style"),
+  (9937, 65038, "text style"), (9937, 65039, "emoji style"),
+  (9939, 65038, "text style"), (9939, 65039, "emoji style"),
+  (9940, 65038, "text style"), (9940, 65039, "emoji style"),
+  (9961, 65038, "text style"), (9961, 65039, "emoji style"),
+  (9962, 65038, "text style"), (9962, 65039, "emoji style"),
+  (9968, 65038, "text style"), (9968, 65039, "emoji style"),
+  (9969, 65038, "text style"), (9969, 65039, "emoji style"),
+  (9970, 65038, "text style"), (9970, 65039, "emoji style"),
+  (9971, 65038, "text style"), (9971, 65039, "emoji style"),
+  (9972, 65038, "text style"), (9972, 65039, "emoji style"),
+  (9973, 65038, "text style"), (9973, 65039, "emoji style"),
+  (9975, 65038, "text style"), (9975, 65039, "emoji style"),
+  (9976, 65038, "text style"), (9976, 65039, "emoji style"),
+  (9977, 65038, "text style"), (9977, 65039, "emoji style"),
+  (9978, 65038, "text style"), (9978, 65039, "emoji style"),
+  (9981, 65038, "text style"), (9981, 65039, "emoji style"),
+  (9986, 65038, "text style"), (9986, 65039, "emoji style"),
+  (9992, 65038, "text style"), (9992, 65039, "emoji style"),
+  (9993, 65038, "text style"), (9993, 65039, "emoji style"),
+  (9996, 65038, "text style"), (9996, 65039, "emoji style"),
+  (9997, 65038, "text style"), (9997, 65039, "emoji style"),
+  (9999, 65038, "text style"), (9999, 65039, "emoji style"),
+  (10002, 65038, "text style"), (10002, 65039, "emoji style"),
+  (10004, 65038, "text style"), (10004, 65039, "emoji style"),
+  (10006, 65038, "text style"), (10006, 65039, "emoji style"),
+  (10013, 65038, "text style"), (10013, 65039, "emoji style"),
+  (10017, 65038, "text style"), (10017, 65039, "emoji style"),
+  (10035, 65038, "text style"), (10035, 65039, "emoji style"),
+  (10036, 65038, "text style"), (10036, 65039, "emoji style"),
+  (10052, 65038, "text style"), (10052, 65039, "emoji style"),
+  (10055, 65038, "text style"), (10055, 65039, "emoji style"),
+  (10067, 65038, "text style"), (10067, 65039, "emoji style"),
+  (10071, 65038, "text style"), (10071, 65039, "emoji style"),
+  (10083, 65038, "text style"), (10083, 65039, "emoji style"),
+  (10084, 65038, "text style"), (10084, 65039, "emoji style"),
+  (10145, 65038, "text style"), (10145, 65039, "emoji style"),
+  (10548, 65038, "text style"), (10548, 65039, "emoji style"),
+  (10549, 65038, "text style"), (10549, 65039, "emoji style"),
+  (11013, 65038, "text style"), (11013, 65039, "emoji style"),
+  (11014, 65038, "text style"), (11014, 65039, "emoji style"),
+  (11015, 65038, "text style"), (11015, 65039, "emoji style"),
+  (11035, 65038, "text style"), (11035, 65039, "emoji style"),
+  (11036, 65038, "text style"), (11036, 65039, "emoji style"),
+  (11088, 65038, "text style"), (11088, 65039, "emoji style"),
+  (11093, 65038, "text style"), (11093, 65039, "emoji style"),
+  (12336, 65038, "text style"), (12336, 65039, "emoji style"),
+  (12349, 65038, "text style"), (12349, 65039, "emoji style"),
+  (12951, 65038, "text style"), (12951, 65039, "emoji style"),
+  (12953, 65038, "text style"), (12953, 65039, "emoji style"),
+  (126980, 65038, "text style"), (126980, 65039, "emoji style"),
+  (127344, 65038, "text style"), (127344, 65039, "emoji style"),
+  (127345, 65038, "text style"), (127345, 65039, "emoji style"),
+  (127358, 65038, "text style"), (127358, 65039, "emoji style"),
+  (127359, 65038, "text style"), (127359, 65039, "emoji style"),
+  (127490, 65038, "text style"), (127490, 65039, "emoji style"),
+  (127514, 65038, "text style"), (127514, 65039, "emoji style"),
+  (127535, 65038, "text style"), (127535, 65039, "emoji style"),
+  (127543, 65038, "text style"), (127543, 65039, "emoji style"),
+  (127757, 65038, "text style"), (127757, 65039, "emoji style"),
+  (127758, 65038, "text style"), (127758, 65039, "emoji style"),
+  (127759, 65038, "text style"), (127759, 65039, "emoji style"),
+  (127765, 65038, "text style"), (127765, 65039, "emoji style"),
+  (127772, 65038, "text style"), (127772, 65039, "emoji style"),
+  (127777, 65038, "text style"), (127777, 65039, "emoji style"),
+  (127780, 65038, "text style"), (127780, 65039, "emoji style"),
+  (127781, 65038, "text style"), (127781, 65039, "emoji style"),
+  (127782, 65038, "text style"), (127782, 65039, "emoji style"),
+  (127783, 65038, "text style"), (127783, 65039, "emoji style"),
+  (127784, 65038, "text style"), (127784, 65039, "emoji style"),
+  (127785, 65038, "text style"), (127785, 65039, "emoji style"),
+  (127786, 65038, "text style"), (127786, 65039, "emoji style"),
+  (127787, 65038, "text style"), (127787, 65039, "emoji style"),
+  (127788, 65038, "text style"), (127788, 65039, "emoji style"),
+  (127798, 65038, "text style"), (127798, 65039, "emoji style"),
+  (127864, 65038, "text style"), (127864, 65039, "emoji style"),
+  (127869, 65038, "text style"), (127869, 65039, "emoji style"),
+  (127891, 65038, "text style"), (127891, 65039, "emoji style"),
+  (127894, 65038, "text style"), (127894, 65039, "emoji style"),
+  (127895, 65038, "text style"), (127895, 65039, "emoji style"),
+  (127897, 65038, "text style"), (127897, 65039, "emoji style"),
+  (127898, 65038, "text style"), (127898, 65039, "emoji style"),
+  (127899, 65038, "text style"), (127899, 65039, "emoji style"),
+  (127902, 65038, "text style"), (127902, 65039, "emoji style"),
+  (127903, 65038, "text style"), (127903, 65039, "emoji style"),
+  (127911, 65038, "text style"), (127911, 65039, "emoji style"),
+  (127916, 65038, "text style"), (127916, 65039, "emoji style"),
+  (127917, 65038, "text style"), (127917, 65039, "emoji style"),
+  (127918, 65038, "text style"), (127918, 65039, "emoji style"),
+  (127938, 65038, "text style"), (127938, 65039, "emoji style"),
+  (127940, 65038, "text style"), (127940, 65039, "emoji style"),
+  (127942, 65038, "text style"), (127942, 65039, "emoji style"),
+  (127946, 65038, "text style"), (127946, 65039, "emoji style"),
+  (127947, 65038, "text style"), (127947, 65039, "emoji style"),
+  (127948, 65038, "text style"), (127948, 65039, "emoji style"),
+  (127949, 65038, "text style"), (127949, 65039, "emoji style"),
+  (127950, 65038, "text style"), (127950, 65039, "emoji style"),
+  (127956, 65038, "text style"), (127956, 65039, "emoji style"),
+  (127957, 65038, "text style"), (127957, 65039, "emoji style"),
+  (127958, 65038, "text style"), (127958, 65039, "emoji style"),
+  (127959, 65038, "text style"), (127959, 65039, "emoji style"),
+  (127960, 65038, "text style"), (127960, 65039, "emoji style"),
+  (127961, 65038, "text style"), (127961, 65039, "emoji style"),
+  (127962, 65038, "text style"), (127962, 65039, "emoji style"),
+  (127963, 65038, "text style"), (127963, 65039, "emoji style"),
+  (127964, 65038, "text style"), (127964, 65039, "emoji style"),
+  (127965, 65038, "text style"), (127965, 65039, "emoji style"),
+  (127966, 65038, "text style"), (127966, 65039, "emoji style"),
+  (127967, 65038, "text style"), (127967, 65039, "emoji style"),
+  (127968, 65038, "text style"), (127968, 65039, "emoji style"),
+  (127981, 65038, "text style"), (127981, 65039, "emoji style"),
+  (127987, 65038, "text style"), (127987, 65039, "emoji style"),
+  (127989, 65038, "text style"), (127989, 65039, "emoji style"),
+  (127991, 65038, "text style"), (127991, 65039, "emoji style"),
+  (128008, 65038, "text style"), (128008, 65039, "emoji style"),
+  (128021, 65038, "text style"), (128021, 65039, "emoji style"),
+  (128031, 65038, "text style"), (128031, 65039, "emoji style"),
+  (128038, 65038, "text style"), (128038, 65039, "emoji style"),
+  (128063, 65038, "text style"), (128063, 65039, "emoji style"),
+  (128065, 65038, "text style"), (128065, 65039, "emoji style"),
+  (128066, 65038, "text style"), (128066, 65039, "emoji style"),
+  (128070, 65038, "text style"), (128070, 65039, "emoji style"),
+  (128071, 65038, "text style"), (128071, 65039, "emoji style"),
+  (128072, 65038, "text style"), (128072, 65039, "emoji style"),
+  (128073, 65038, "text style"), (128073, 65039, "emoji style"),
+  (128077, 65038, "text style"), (128077, 65039, "emoji style"),
+  (128078, 65038, "text style"), (128078, 65039, "emoji style"),
+  (128083, 65038, "text style"), (128083, 65039, "emoji style"),
+  (128106, 65038, "text style"), (128106, 65039, "emoji style"),
+  (128125, 65038, "text style"), (128125, 65039, "emoji style"),
+  (128163, 65038, "text style"), (128163, 65039, "emoji style"),
+  (128176, 65038, "text style"), (128176, 65039, "emoji style"),
+  (128179, 65038, "text style"), (128179, 65039, "emoji style"),
+  (128187, 65038, "text style"), (128187, 65039, "emoji style"),
+  (128191, 65038, "text style"), (128191, 65039, "emoji style"),
+  (128203, 65038, "text style"), (128203, 65039, "emoji style"),
+  (128218, 65038, "text style"), (128218, 65039, "emoji style"),
+  (128223, 65038, "text style"), (128223, 65039, "emoji style"),
+  (128228, 65038, "text style"), (128228, 65039, "emoji style"),
+  (128229, 65038, "text style"), (128229, 65039, "emoji style"),
+  (128230, 65038, "text style"), (128230, 65039, "emoji style"),
+  (128234, 65038, "text style"), (128234, 65039, "emoji style"),
+  (128235, 65038, "text style"), (128235, 65039, "emoji style"),
+  (128236, 65038, "text style"), (128236, 65039, "emoji style"),
+  (128237, 65038, "text style"), (128237, 65039, "emoji style"),
+  (128247, 65038, "text style"), (128247, 65039, "emoji style"),
+  (128249, 65038, "text style"), (128249, 65039, "emoji style"),
+  (128250, 65038, "text style"), (128250, 65039, "emoji style"),
+  (128251, 65038, "text style"), (128251, 65039, "emoji style"),
+  (128253, 65038, "text style"), (128253, 65039, "emoji style"),
+  (128264, 65038, "text style"), (128264, 65039, "emoji style"),
+  (128269, 65038, "text style"), (128269, 65039, "emoji style"),
+  (128274, 65038, "text style"), (128274, 65039, "emoji style"),
+  (128275, 65038, "text style"), (128275, 65039, "emoji style"),
+  (128329, 65038, "text style"), (128329, 65039, "emoji style"),
+  (128330, 65038, "text style"), (128330, 65039, "emoji style"),
+  (128336, 65038, "text style"), (128336, 65039, "emoji style"),
+  (128337, 65038, "text style"), (128337, 65039, "emoji style"),
+  (128338, 65038, "text style"), (128338, 65039, "emoji style"),
+  (128339, 65038, "text style"), (128339, 65039, "emoji style"),
+  (128340, 65038, "text style"), (128340, 65039, "emoji style"),
+  (128341, 65038, "text style"), (128341, 65039, "emoji style"),
+  (128342, 65038, "text style"), (128342, 65039, "emoji style"),
+  (128343, 65038, "text style"), (128343, 65039, "emoji style"),
+  (128344, 65038, "text style"), (128344, 65039, "emoji style"),
+  (128345, 65038, "text style"), (128345, 65039, "emoji style"),
+  (128346, 65038, "text style"), (128346, 65039, "emoji style"),
+  (128347, 65038, "text style"), (128347, 65039, "emoji style"),
+  (128348, 65038, "text style"), (128348, 65039, "emoji style"),
+  (128349, 65038, "text style"), (128349, 65039, "emoji style"),
+  (128350, 65038, "text style"), (128350, 65039, "emoji style"),
+  (128351, 65038, "text style"), (128351, 65039, "emoji style"),
+  (128352, 65038, "text style"), (128352, 65039, "emoji style"),
+  (128353, 65038, "text style"), (128353, 65039, "emoji style"),
+  (128354, 65038, "text style"), (128354, 65039, "emoji style"),
+  (128355, 65038, "text style"), (128355, 65039, "emoji style"),
+  (128356, 65038, "text style"), (128356, 65039, "emoji style"),
+  (128357, 65038, "text style"), (128357, 65039, "emoji style"),
+  (128358, 65038, "text style"), (128358, 65039, "emoji style"),
+  (128359, 65038, "text style"), (128359, 65039, "emoji style"),
+  (128367, 65038, "text style"), (128367, 65039, "emoji style"),
+  (128368, 65038, "text style"), (128368, 65039, "emoji style"),
+  (128371, 65038, "text style"), (128371, 65039, "emoji style"),
+  (128372, 65038, "text style"), (128372, 65039, "emoji style"),
+  (128373, 65038, "text style"), (128373, 65039, "emoji style"),
+  (128374, 65038, "text style"), (128374, 65039, "emoji style"),
+  (128375, 65038, "text style"), (128375, 65039, "emoji style"),
+  (128376, 65038, "text style"), (128376, 65039, "emoji style"),
+  (128377, 65038, "text style"), (128377, 65039, "emoji style"),
+  (128391, 65038, "text style"), (128391, 65039, "emoji style"),
+  (128394, 65038, "text style"), (128394, 65039, "emoji style"),
+  (128395, 65038, "text style"), (128395, 65039, "emoji style"),
+  (128396, 65038, "text style"), (128396, 65039, "emoji style"),
+  (128397, 65038, "text style"), (128397, 65039, "emoji style"),
+  (128400, 65038, "text style"), (128400, 65039, "emoji style"),
+  (128421, 65038, "text style"), (128421, 65039, "emoji style"),
+  (128424, 65038, "text style"), (128424, 65039, "emoji style"),
+  (128433, 65038, "text style"), (128433, 65039, "emoji style"),
+  (128434, 65038, "text style"), (128434, 65039, "emoji style"),
+  (128444, 65038, "text style"), (128444, 65039, "emoji style"),
+  (128450, 65038, "text style"), (128450, 65039, "emoji style"),
+  (128451, 65038, "text style"), (128451, 65039, "emoji style"),
+  (128452, 65038, "text style"), (128452, 65039, "emoji style"),
+  (128465, 65038, "text style"), (128465, 65039, "emoji style"),
+  (128466, 65038, "text style"), (128466, 65039, "emoji style"),
+  (128467, 65038, "text style"), (128467, 65039, "emoji style"),
+  (128476, 65038, "text style"), (128476, 65039, "emoji style"),
+  (128477, 65038, "text style"), (128477, 65039, "emoji style"),
+  (128478, 65038, "text style"), (128478, 65039, "emoji style"),
+  (128481, 65038, "text style"), (128481, 65039, "emoji style"),
+  (128483, 65038, "text style"), (128483, 65039, "emoji style"),
+  (128488, 65038, "text style"), (128488, 65039, "emoji style"),
+  (128495, 65038, "text style"), (128495, 65039, "emoji style"),
+  (128499, 65038, "text style"), (128499, 65039, "emoji style"),
+  (128506, 65038, "text style"), (128506, 65039, "emoji style"),
+  (128528, 65038, "text style"), (128528, 65039, "emoji style"),
+  (128647, 65038, "text style"), (128647, 65039, "emoji style"),
+  (128653, 65038, "text style"), (128653, 65039, "emoji style"),
+  (128657, 65038, "text style"), (128657, 65039, "emoji style"),
+  (128660, 65038, "text style"), (128660, 65039, "emoji style"),
+  (128664, 65038, "text style"), (128664, 65039, "emoji style"),
+  (128685, 65038, "text style"), (128685, 65039, "emoji style"),
+  (128690, 65038, "text style"), (128690, 65039, "emoji style"),
+  (128697, 65038, "text style"), (128697, 65039, "emoji style"),
+  (128698, 65038, "text style"), (128698, 65039, "emoji style"),
+  (128700, 65038, "text style"), (128700, 65039, "emoji style"),
+  (128715, 65038, "text style"), (128715, 65039, "emoji style"),
+  (128717, 65038, "text style"), (128717, 65039, "emoji style"),
+  (128718, 65038, "text style"), (128718, 65039, "emoji style"),
+  (128719, 65038, "text style"), (128719, 65039, "emoji style"),
+  (128736, 65038, "text style"), (128736, 65039, "emoji style"),
+  (128737, 65038, "text style"), (128737, 65039, "emoji style"),
+  (128738, 65038, "text style"), (128738, 65039, "emoji style"),
+  (128739, 65038, "text style"), (128739, 65039, "emoji style"),
+  (128740, 65038, "text style"), (128740, 65039, "emoji style"),
+  (128741, 65038, "text style"), (128741, 65039, "emoji style"),
+  (128745, 65038, "text style"), (128745, 65039, "emoji style"),
+  (128752, 65038, "text style"), (128752, 65039, "emoji style"),
+  (128755, 65038, "text style"), (128755, 65039, "emoji style"),
+];
@@ -0,0 +1,35 @@
+// Extended_Pictographic was first published in Emoji 11.0, so this table is
+// generated from Emoji 13.0, while the other emoji tables are generated from
+// Emoji 5.0.
+//
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//  ucd-generate property-bool /home/andrew/tmp/emoji-13.0/ --include Extended_Pictographic
+//
+// ucd-generate is available on crates.io.
+
+pub const BY_NAME: &'static [(&'static str, &'static [(u32, u32)])] = &[
+  ("Extended_Pictographic", EXTENDED_PICTOGRAPHIC),
+];
+
+pub const EXTENDED_PICTOGRAPHIC: &'static [(u32, u32)] = &[
+  (169, 169), (174, 174), (8252, 8252), (8265, 8265), (8482, 8482),
+  (8505, 8505), (8596, 8601), (8617, 8618), (8986, 8987), (9000, 9000),
+  (9096, 9096), (9167, 9167), (9193, 9203), (9208, 9210), (9410, 9410),
+  (9642, 9643), (9654, 9654), (9664, 9664), (9723, 9726), (9728, 9733),
+  (9735, 9746), (9748, 9861), (9872, 9989), (9992, 10002), (10004, 10004),
+  (10006, 10006), (10013, 10013), (10017, 10017), (10024, 10024),
+  (10035, 10036), (10052, 10052), (10055, 10055), (10060, 10060),
+  (10062, 10062), (10067, 10069), (10071, 10071), (10083, 10087),
+  (10133, 10135), (10145, 10145), (10160, 10160), (10175, 10175),
+  (10548, 10549), (11013, 11015), (11035, 11036), (11088, 11088),
+  (11093, 11093), (12336, 12336), (12349, 12349), (12951, 12951),
+  (12953, 12953), (126976, 127231), (127245, 127247), (127279, 127279),
+  (127340, 127345), (127358, 127359), (127374, 127374), (127377, 127386),
+  (127405, 127461), (127489, 127503), (127514, 127514), (127535, 127535),
+  (127538, 127546), (127548, 127551), (127561, 127994), (128000, 128317),
+  (128326, 128591), (128640, 128767), (128884, 128895), (128981, 129023),
+  (129036, 129039), (129096, 129103), (129114, 129119), (129160, 129167),
+  (129198, 129279), (129292, 129338), (129340, 129349), (129351, 129791),
+  (130048, 131069),
+];
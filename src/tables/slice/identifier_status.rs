@@ -0,0 +1,140 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//  ucd-generate identifier-status /home/andrew/tmp/security-10.0.0/
+//
+// ucd-generate is available on crates.io.
+
+pub const IDENTIFIER_STATUS: &'static [(u32, u32, &'static str)] = &[
+  (39, 39, "Allowed"), (45, 46, "Allowed"), (48, 58, "Allowed"),
+  (65, 90, "Allowed"), (95, 95, "Allowed"), (97, 122, "Allowed"),
+  (183, 183, "Allowed"), (192, 214, "Allowed"), (216, 246, "Allowed"),
+  (248, 305, "Allowed"), (308, 318, "Allowed"), (321, 328, "Allowed"),
+  (330, 382, "Allowed"), (399, 399, "Allowed"), (416, 417, "Allowed"),
+  (431, 432, "Allowed"), (461, 476, "Allowed"), (478, 483, "Allowed"),
+  (486, 496, "Allowed"), (500, 501, "Allowed"), (504, 539, "Allowed"),
+  (542, 543, "Allowed"), (550, 563, "Allowed"), (601, 601, "Allowed"),
+  (699, 700, "Allowed"), (748, 748, "Allowed"), (768, 772, "Allowed"),
+  (774, 780, "Allowed"), (783, 785, "Allowed"), (787, 788, "Allowed"),
+  (795, 795, "Allowed"), (803, 808, "Allowed"), (813, 814, "Allowed"),
+  (816, 817, "Allowed"), (821, 821, "Allowed"), (824, 825, "Allowed"),
+  (834, 834, "Allowed"), (837, 837, "Allowed"), (885, 885, "Allowed"),
+  (891, 893, "Allowed"), (902, 902, "Allowed"), (904, 906, "Allowed"),
+  (908, 908, "Allowed"), (910, 929, "Allowed"), (931, 974, "Allowed"),
+  (1020, 1119, "Allowed"), (1162, 1279, "Allowed"), (1296, 1321, "Allowed"),
+  (1326, 1327, "Allowed"), (1329, 1366, "Allowed"), (1369, 1369, "Allowed"),
+  (1377, 1414, "Allowed"), (1418, 1418, "Allowed"), (1460, 1460, "Allowed"),
+  (1488, 1514, "Allowed"), (1520, 1524, "Allowed"), (1568, 1599, "Allowed"),
+  (1601, 1621, "Allowed"), (1632, 1641, "Allowed"), (1648, 1650, "Allowed"),
+  (1652, 1652, "Allowed"), (1657, 1677, "Allowed"), (1679, 1696, "Allowed"),
+  (1698, 1747, "Allowed"), (1749, 1749, "Allowed"), (1765, 1766, "Allowed"),
+  (1774, 1791, "Allowed"), (1872, 1969, "Allowed"), (2208, 2220, "Allowed"),
+  (2226, 2226, "Allowed"), (2230, 2237, "Allowed"), (2305, 2381, "Allowed"),
+  (2383, 2384, "Allowed"), (2390, 2391, "Allowed"), (2400, 2403, "Allowed"),
+  (2406, 2415, "Allowed"), (2417, 2423, "Allowed"), (2425, 2431, "Allowed"),
+  (2433, 2435, "Allowed"), (2437, 2444, "Allowed"), (2447, 2448, "Allowed"),
+  (2451, 2472, "Allowed"), (2474, 2480, "Allowed"), (2482, 2482, "Allowed"),
+  (2486, 2489, "Allowed"), (2492, 2500, "Allowed"), (2503, 2504, "Allowed"),
+  (2507, 2510, "Allowed"), (2519, 2519, "Allowed"), (2528, 2531, "Allowed"),
+  (2534, 2545, "Allowed"), (2561, 2563, "Allowed"), (2565, 2570, "Allowed"),
+  (2575, 2576, "Allowed"), (2579, 2600, "Allowed"), (2602, 2608, "Allowed"),
+  (2610, 2610, "Allowed"), (2613, 2613, "Allowed"), (2616, 2617, "Allowed"),
+  (2620, 2620, "Allowed"), (2622, 2626, "Allowed"), (2631, 2632, "Allowed"),
+  (2635, 2637, "Allowed"), (2652, 2652, "Allowed"), (2662, 2676, "Allowed"),
+  (2689, 2691, "Allowed"), (2693, 2701, "Allowed"), (2703, 2705, "Allowed"),
+  (2707, 2728, "Allowed"), (2730, 2736, "Allowed"), (2738, 2739, "Allowed"),
+  (2741, 2745, "Allowed"), (2748, 2757, "Allowed"), (2759, 2761, "Allowed"),
+  (2763, 2765, "Allowed"), (2768, 2768, "Allowed"), (2784, 2787, "Allowed"),
+  (2790, 2799, "Allowed"), (2810, 2815, "Allowed"), (2817, 2819, "Allowed"),
+  (2821, 2828, "Allowed"), (2831, 2832, "Allowed"), (2835, 2856, "Allowed"),
+  (2858, 2864, "Allowed"), (2866, 2867, "Allowed"), (2869, 2873, "Allowed"),
+  (2876, 2883, "Allowed"), (2887, 2888, "Allowed"), (2891, 2893, "Allowed"),
+  (2902, 2903, "Allowed"), (2911, 2913, "Allowed"), (2918, 2927, "Allowed"),
+  (2929, 2929, "Allowed"), (2946, 2947, "Allowed"), (2949, 2954, "Allowed"),
+  (2958, 2960, "Allowed"), (2962, 2965, "Allowed"), (2969, 2970, "Allowed"),
+  (2972, 2972, "Allowed"), (2974, 2975, "Allowed"), (2979, 2980, "Allowed"),
+  (2984, 2986, "Allowed"), (2990, 3001, "Allowed"), (3006, 3010, "Allowed"),
+  (3014, 3016, "Allowed"), (3018, 3021, "Allowed"), (3024, 3024, "Allowed"),
+  (3031, 3031, "Allowed"), (3046, 3055, "Allowed"), (3073, 3075, "Allowed"),
+  (3077, 3084, "Allowed"), (3086, 3088, "Allowed"), (3090, 3112, "Allowed"),
+  (3114, 3123, "Allowed"), (3125, 3129, "Allowed"), (3133, 3140, "Allowed"),
+  (3142, 3144, "Allowed"), (3146, 3149, "Allowed"), (3157, 3158, "Allowed"),
+  (3168, 3169, "Allowed"), (3174, 3183, "Allowed"), (3200, 3200, "Allowed"),
+  (3202, 3203, "Allowed"), (3205, 3212, "Allowed"), (3214, 3216, "Allowed"),
+  (3218, 3240, "Allowed"), (3242, 3251, "Allowed"), (3253, 3257, "Allowed"),
+  (3260, 3268, "Allowed"), (3270, 3272, "Allowed"), (3274, 3277, "Allowed"),
+  (3285, 3286, "Allowed"), (3296, 3299, "Allowed"), (3302, 3311, "Allowed"),
+  (3313, 3314, "Allowed"), (3328, 3328, "Allowed"), (3330, 3331, "Allowed"),
+  (3333, 3340, "Allowed"), (3342, 3344, "Allowed"), (3346, 3386, "Allowed"),
+  (3389, 3395, "Allowed"), (3398, 3400, "Allowed"), (3402, 3406, "Allowed"),
+  (3412, 3415, "Allowed"), (3424, 3425, "Allowed"), (3430, 3439, "Allowed"),
+  (3450, 3455, "Allowed"), (3458, 3459, "Allowed"), (3461, 3470, "Allowed"),
+  (3473, 3478, "Allowed"), (3482, 3493, "Allowed"), (3495, 3505, "Allowed"),
+  (3507, 3515, "Allowed"), (3517, 3517, "Allowed"), (3520, 3526, "Allowed"),
+  (3530, 3530, "Allowed"), (3535, 3540, "Allowed"), (3542, 3542, "Allowed"),
+  (3544, 3550, "Allowed"), (3570, 3570, "Allowed"), (3585, 3634, "Allowed"),
+  (3636, 3642, "Allowed"), (3648, 3662, "Allowed"), (3664, 3673, "Allowed"),
+  (3713, 3714, "Allowed"), (3716, 3716, "Allowed"), (3719, 3720, "Allowed"),
+  (3722, 3722, "Allowed"), (3725, 3725, "Allowed"), (3732, 3735, "Allowed"),
+  (3737, 3743, "Allowed"), (3745, 3747, "Allowed"), (3749, 3749, "Allowed"),
+  (3751, 3751, "Allowed"), (3754, 3755, "Allowed"), (3757, 3762, "Allowed"),
+  (3764, 3769, "Allowed"), (3771, 3773, "Allowed"), (3776, 3780, "Allowed"),
+  (3782, 3782, "Allowed"), (3784, 3789, "Allowed"), (3792, 3801, "Allowed"),
+  (3806, 3807, "Allowed"), (3840, 3840, "Allowed"), (3851, 3851, "Allowed"),
+  (3872, 3881, "Allowed"), (3893, 3893, "Allowed"), (3895, 3895, "Allowed"),
+  (3902, 3906, "Allowed"), (3908, 3911, "Allowed"), (3913, 3916, "Allowed"),
+  (3918, 3921, "Allowed"), (3923, 3926, "Allowed"), (3928, 3931, "Allowed"),
+  (3933, 3944, "Allowed"), (3946, 3948, "Allowed"), (3953, 3954, "Allowed"),
+  (3956, 3956, "Allowed"), (3962, 3968, "Allowed"), (3970, 3972, "Allowed"),
+  (3974, 3986, "Allowed"), (3988, 3991, "Allowed"), (3993, 3996, "Allowed"),
+  (3998, 4001, "Allowed"), (4003, 4006, "Allowed"), (4008, 4011, "Allowed"),
+  (4013, 4024, "Allowed"), (4026, 4028, "Allowed"), (4038, 4038, "Allowed"),
+  (4096, 4169, "Allowed"), (4176, 4253, "Allowed"), (4295, 4295, "Allowed"),
+  (4301, 4301, "Allowed"), (4304, 4336, "Allowed"), (4343, 4346, "Allowed"),
+  (4349, 4351, "Allowed"), (4608, 4680, "Allowed"), (4682, 4685, "Allowed"),
+  (4688, 4694, "Allowed"), (4696, 4696, "Allowed"), (4698, 4701, "Allowed"),
+  (4704, 4744, "Allowed"), (4746, 4749, "Allowed"), (4752, 4784, "Allowed"),
+  (4786, 4789, "Allowed"), (4792, 4798, "Allowed"), (4800, 4800, "Allowed"),
+  (4802, 4805, "Allowed"), (4808, 4822, "Allowed"), (4824, 4880, "Allowed"),
+  (4882, 4885, "Allowed"), (4888, 4954, "Allowed"), (4957, 4959, "Allowed"),
+  (4992, 5007, "Allowed"), (6016, 6050, "Allowed"), (6053, 6055, "Allowed"),
+  (6057, 6067, "Allowed"), (6070, 6093, "Allowed"), (6096, 6096, "Allowed"),
+  (6098, 6098, "Allowed"), (6103, 6103, "Allowed"), (6108, 6108, "Allowed"),
+  (6112, 6121, "Allowed"), (7680, 7833, "Allowed"), (7838, 7838, "Allowed"),
+  (7840, 7929, "Allowed"), (7936, 7957, "Allowed"), (7960, 7965, "Allowed"),
+  (7968, 8005, "Allowed"), (8008, 8013, "Allowed"), (8016, 8023, "Allowed"),
+  (8025, 8025, "Allowed"), (8027, 8027, "Allowed"), (8029, 8029, "Allowed"),
+  (8031, 8048, "Allowed"), (8050, 8050, "Allowed"), (8052, 8052, "Allowed"),
+  (8054, 8054, "Allowed"), (8056, 8056, "Allowed"), (8058, 8058, "Allowed"),
+  (8060, 8060, "Allowed"), (8064, 8116, "Allowed"), (8118, 8122, "Allowed"),
+  (8124, 8124, "Allowed"), (8130, 8132, "Allowed"), (8134, 8136, "Allowed"),
+  (8138, 8138, "Allowed"), (8140, 8140, "Allowed"), (8144, 8146, "Allowed"),
+  (8150, 8154, "Allowed"), (8160, 8162, "Allowed"), (8164, 8170, "Allowed"),
+  (8172, 8172, "Allowed"), (8178, 8180, "Allowed"), (8182, 8184, "Allowed"),
+  (8186, 8186, "Allowed"), (8188, 8188, "Allowed"), (8208, 8208, "Allowed"),
+  (8217, 8217, "Allowed"), (8231, 8231, "Allowed"), (11559, 11559, "Allowed"),
+  (11565, 11565, "Allowed"), (11648, 11670, "Allowed"),
+  (11680, 11686, "Allowed"), (11688, 11694, "Allowed"),
+  (11696, 11702, "Allowed"), (11704, 11710, "Allowed"),
+  (11712, 11718, "Allowed"), (11720, 11726, "Allowed"),
+  (11728, 11734, "Allowed"), (11736, 11742, "Allowed"),
+  (12293, 12295, "Allowed"), (12353, 12438, "Allowed"),
+  (12441, 12442, "Allowed"), (12445, 12446, "Allowed"),
+  (12448, 12542, "Allowed"), (12549, 12589, "Allowed"),
+  (12704, 12730, "Allowed"), (13312, 19893, "Allowed"),
+  (19968, 40938, "Allowed"), (42623, 42623, "Allowed"),
+  (42775, 42783, "Allowed"), (42888, 42888, "Allowed"),
+  (42893, 42893, "Allowed"), (42898, 42899, "Allowed"),
+  (42922, 42922, "Allowed"), (43495, 43518, "Allowed"),
+  (43616, 43638, "Allowed"), (43642, 43647, "Allowed"),
+  (43777, 43782, "Allowed"), (43785, 43790, "Allowed"),
+  (43793, 43798, "Allowed"), (43808, 43814, "Allowed"),
+  (43816, 43822, "Allowed"), (44032, 55203, "Allowed"),
+  (64014, 64015, "Allowed"), (64017, 64017, "Allowed"),
+  (64019, 64020, "Allowed"), (64031, 64031, "Allowed"),
+  (64033, 64033, "Allowed"), (64035, 64036, "Allowed"),
+  (64039, 64041, "Allowed"), (70401, 70401, "Allowed"),
+  (70403, 70403, "Allowed"), (70460, 70460, "Allowed"),
+  (131072, 173782, "Allowed"), (173824, 177972, "Allowed"),
+  (177984, 178205, "Allowed"), (178208, 183969, "Allowed"),
+  (183984, 191456, "Allowed"),
+];
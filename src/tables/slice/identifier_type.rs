@@ -0,0 +1,649 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//  ucd-generate identifier-type /home/andrew/tmp/security-10.0.0/
+//
+// ucd-generate is available on crates.io.
+
+pub const IDENTIFIER_TYPE: &'static [(u32, u32, &'static str)] = &[
+  (9, 13, "Not_XID"), (32, 38, "Not_XID"), (39, 39, "Inclusion"),
+  (40, 44, "Not_XID"), (45, 46, "Inclusion"), (47, 47, "Not_XID"),
+  (48, 57, "Recommended"), (58, 58, "Inclusion"), (59, 64, "Not_XID"),
+  (65, 90, "Recommended"), (91, 94, "Not_XID"), (95, 95, "Recommended"),
+  (96, 96, "Not_XID"), (97, 122, "Recommended"), (123, 126, "Not_XID"),
+  (133, 133, "Not_XID"), (160, 160, "Not_NFKC"), (161, 167, "Not_XID"),
+  (168, 168, "Not_NFKC"), (169, 169, "Not_XID"), (170, 170, "Not_NFKC"),
+  (171, 172, "Not_XID"), (173, 173, "Default_Ignorable"),
+  (174, 174, "Not_XID"), (175, 175, "Not_NFKC"), (176, 177, "Not_XID"),
+  (178, 181, "Not_NFKC"), (182, 182, "Not_XID"), (183, 183, "Inclusion"),
+  (184, 186, "Not_NFKC"), (187, 187, "Not_XID"), (188, 190, "Not_NFKC"),
+  (191, 191, "Not_XID"), (192, 214, "Recommended"), (215, 215, "Not_XID"),
+  (216, 246, "Recommended"), (247, 247, "Not_XID"), (248, 305, "Recommended"),
+  (306, 307, "Not_NFKC"), (308, 318, "Recommended"), (319, 320, "Not_NFKC"),
+  (321, 328, "Recommended"), (329, 329, "Deprecated"),
+  (330, 382, "Recommended"), (383, 383, "Not_NFKC"), (384, 384, "Technical"),
+  (385, 396, "Uncommon_Use"), (397, 397, "Technical"),
+  (398, 398, "Uncommon_Use"), (399, 399, "Recommended"),
+  (400, 415, "Uncommon_Use"), (416, 417, "Recommended"),
+  (418, 425, "Uncommon_Use"), (426, 427, "Technical"),
+  (428, 430, "Uncommon_Use"), (431, 432, "Recommended"),
+  (433, 440, "Uncommon_Use"), (441, 441, "Obsolete"), (442, 443, "Technical"),
+  (444, 445, "Uncommon_Use"), (446, 446, "Technical"), (447, 447, "Obsolete"),
+  (448, 451, "Technical"), (452, 460, "Not_NFKC"), (461, 476, "Recommended"),
+  (477, 477, "Uncommon_Use"), (478, 483, "Recommended"),
+  (484, 485, "Uncommon_Use"), (486, 496, "Recommended"),
+  (497, 499, "Not_NFKC"), (500, 501, "Recommended"), (502, 503, "Obsolete"),
+  (504, 539, "Recommended"), (540, 541, "Obsolete"), (542, 543, "Recommended"),
+  (544, 549, "Uncommon_Use"), (550, 563, "Recommended"),
+  (564, 566, "Technical"), (567, 591, "Uncommon_Use"), (592, 594, "Technical"),
+  (595, 596, "Uncommon_Use"), (597, 597, "Technical"),
+  (598, 599, "Uncommon_Use"), (600, 600, "Technical"),
+  (601, 601, "Recommended"), (602, 602, "Technical"),
+  (603, 603, "Uncommon_Use"), (604, 610, "Technical"),
+  (611, 611, "Uncommon_Use"), (612, 615, "Technical"),
+  (616, 617, "Uncommon_Use"), (618, 625, "Technical"),
+  (626, 626, "Uncommon_Use"), (627, 648, "Technical"),
+  (649, 649, "Uncommon_Use"), (650, 657, "Technical"),
+  (658, 658, "Uncommon_Use"), (659, 687, "Technical"), (688, 696, "Not_NFKC"),
+  (697, 698, "Technical"), (699, 700, "Recommended"), (701, 705, "Technical"),
+  (706, 709, "Not_XID"), (710, 721, "Technical"), (722, 727, "Not_XID"),
+  (728, 733, "Not_NFKC"), (734, 735, "Not_XID"), (736, 740, "Not_NFKC"),
+  (741, 747, "Not_XID"), (748, 748, "Recommended"), (749, 749, "Not_XID"),
+  (750, 750, "Technical"), (751, 767, "Not_XID"), (768, 772, "Recommended"),
+  (773, 773, "Uncommon_Use"), (774, 780, "Recommended"),
+  (781, 781, "Uncommon_Use"), (782, 782, "Technical"),
+  (783, 785, "Recommended"), (786, 786, "Technical"),
+  (787, 788, "Recommended"), (789, 789, "Technical"),
+  (790, 790, "Uncommon_Use"), (791, 794, "Technical"),
+  (795, 795, "Recommended"), (796, 800, "Technical"),
+  (801, 802, "Uncommon_Use"), (803, 808, "Recommended"),
+  (809, 812, "Technical"), (813, 814, "Recommended"), (815, 815, "Technical"),
+  (816, 817, "Recommended"), (818, 818, "Uncommon_Use"),
+  (819, 819, "Technical"), (820, 820, "Uncommon_Use"),
+  (821, 821, "Recommended"), (822, 822, "Uncommon_Use"),
+  (823, 823, "Technical"), (824, 825, "Recommended"), (826, 831, "Technical"),
+  (832, 833, "Not_NFKC"), (834, 834, "Recommended"), (835, 836, "Not_NFKC"),
+  (837, 837, "Recommended"), (838, 846, "Technical"),
+  (847, 847, "Default_Ignorable"), (848, 855, "Technical"),
+  (856, 856, "Uncommon_Use"), (857, 866, "Technical"), (867, 883, "Obsolete"),
+  (884, 884, "Not_NFKC"), (885, 885, "Inclusion"), (886, 887, "Obsolete"),
+  (890, 890, "Not_NFKC"), (891, 893, "Recommended"), (894, 894, "Not_NFKC"),
+  (895, 895, "Obsolete"), (900, 901, "Not_NFKC"), (902, 902, "Recommended"),
+  (903, 903, "Not_NFKC"), (904, 906, "Recommended"), (908, 908, "Recommended"),
+  (910, 929, "Recommended"), (931, 974, "Recommended"),
+  (975, 975, "Technical"), (976, 982, "Not_NFKC"), (983, 983, "Technical"),
+  (984, 993, "Obsolete"), (994, 1007, "Exclusion"), (1008, 1010, "Not_NFKC"),
+  (1011, 1011, "Technical"), (1012, 1013, "Not_NFKC"), (1014, 1014, "Not_XID"),
+  (1015, 1016, "Obsolete"), (1017, 1017, "Not_NFKC"), (1018, 1019, "Obsolete"),
+  (1020, 1119, "Recommended"), (1120, 1155, "Obsolete"),
+  (1156, 1159, "Technical"), (1160, 1161, "Obsolete"),
+  (1162, 1279, "Recommended"), (1280, 1295, "Obsolete"),
+  (1296, 1321, "Recommended"), (1322, 1325, "Obsolete"),
+  (1326, 1327, "Recommended"), (1329, 1366, "Recommended"),
+  (1369, 1369, "Recommended"), (1370, 1375, "Not_XID"),
+  (1377, 1414, "Recommended"), (1415, 1415, "Not_NFKC"),
+  (1417, 1417, "Not_XID"), (1418, 1418, "Inclusion"), (1421, 1423, "Not_XID"),
+  (1425, 1459, "Uncommon_Use"), (1460, 1460, "Recommended"),
+  (1461, 1469, "Uncommon_Use"), (1470, 1470, "Not_XID"),
+  (1471, 1471, "Uncommon_Use"), (1472, 1472, "Not_XID"),
+  (1473, 1474, "Uncommon_Use"), (1475, 1475, "Not_XID"),
+  (1476, 1477, "Uncommon_Use"), (1478, 1478, "Obsolete"),
+  (1479, 1479, "Uncommon_Use"), (1488, 1514, "Recommended"),
+  (1520, 1522, "Recommended"), (1523, 1524, "Inclusion"),
+  (1536, 1551, "Not_XID"), (1552, 1562, "Uncommon_Use"),
+  (1563, 1563, "Not_XID"), (1564, 1564, "Default_Ignorable"),
+  (1566, 1567, "Not_XID"), (1568, 1599, "Recommended"),
+  (1600, 1600, "Obsolete"), (1601, 1621, "Recommended"),
+  (1622, 1631, "Uncommon_Use"), (1632, 1641, "Recommended"),
+  (1642, 1645, "Not_XID"), (1646, 1647, "Obsolete"),
+  (1648, 1650, "Recommended"), (1651, 1651, "Deprecated"),
+  (1652, 1652, "Recommended"), (1653, 1656, "Not_NFKC"),
+  (1657, 1677, "Recommended"), (1678, 1678, "Obsolete"),
+  (1679, 1696, "Recommended"), (1697, 1697, "Obsolete"),
+  (1698, 1747, "Recommended"), (1748, 1748, "Not_XID"),
+  (1749, 1749, "Recommended"), (1750, 1756, "Uncommon_Use"),
+  (1757, 1758, "Not_XID"), (1759, 1764, "Uncommon_Use"),
+  (1765, 1766, "Recommended"), (1767, 1768, "Uncommon_Use"),
+  (1769, 1769, "Not_XID"), (1770, 1773, "Uncommon_Use"),
+  (1774, 1788, "Recommended"), (1789, 1790, "Inclusion"),
+  (1791, 1791, "Recommended"), (1792, 1805, "Limited_Use"),
+  (1807, 1866, "Limited_Use"), (1869, 1871, "Limited_Use"),
+  (1872, 1969, "Recommended"), (1984, 2042, "Limited_Use"),
+  (2048, 2093, "Exclusion"), (2096, 2110, "Exclusion"),
+  (2112, 2139, "Limited_Use"), (2142, 2142, "Limited_Use"),
+  (2144, 2154, "Limited_Use"), (2208, 2220, "Recommended"),
+  (2221, 2225, "Obsolete"), (2226, 2226, "Recommended"),
+  (2227, 2228, "Uncommon_Use"), (2230, 2237, "Recommended"),
+  (2260, 2273, "Uncommon_Use"), (2274, 2274, "Not_XID"),
+  (2275, 2304, "Uncommon_Use"), (2305, 2381, "Recommended"),
+  (2382, 2382, "Obsolete"), (2383, 2384, "Recommended"),
+  (2385, 2386, "Obsolete"), (2387, 2388, "Technical"),
+  (2389, 2389, "Uncommon_Use"), (2390, 2391, "Recommended"),
+  (2392, 2399, "Not_NFKC"), (2400, 2403, "Recommended"),
+  (2404, 2405, "Not_XID"), (2406, 2415, "Recommended"),
+  (2416, 2416, "Not_XID"), (2417, 2423, "Recommended"),
+  (2424, 2424, "Obsolete"), (2425, 2431, "Recommended"),
+  (2432, 2432, "Obsolete"), (2433, 2435, "Recommended"),
+  (2437, 2444, "Recommended"), (2447, 2448, "Recommended"),
+  (2451, 2472, "Recommended"), (2474, 2480, "Recommended"),
+  (2482, 2482, "Recommended"), (2486, 2489, "Recommended"),
+  (2492, 2500, "Recommended"), (2503, 2504, "Recommended"),
+  (2507, 2510, "Recommended"), (2519, 2519, "Recommended"),
+  (2524, 2525, "Not_NFKC"), (2527, 2527, "Not_NFKC"),
+  (2528, 2531, "Recommended"), (2534, 2545, "Recommended"),
+  (2546, 2555, "Not_XID"), (2556, 2556, "Obsolete"), (2557, 2557, "Not_XID"),
+  (2561, 2563, "Recommended"), (2565, 2570, "Recommended"),
+  (2575, 2576, "Recommended"), (2579, 2600, "Recommended"),
+  (2602, 2608, "Recommended"), (2610, 2610, "Recommended"),
+  (2611, 2611, "Not_NFKC"), (2613, 2613, "Recommended"),
+  (2614, 2614, "Not_NFKC"), (2616, 2617, "Recommended"),
+  (2620, 2620, "Recommended"), (2622, 2626, "Recommended"),
+  (2631, 2632, "Recommended"), (2635, 2637, "Recommended"),
+  (2641, 2641, "Uncommon_Use"), (2649, 2651, "Not_NFKC"),
+  (2652, 2652, "Recommended"), (2654, 2654, "Not_NFKC"),
+  (2662, 2676, "Recommended"), (2677, 2677, "Uncommon_Use"),
+  (2689, 2691, "Recommended"), (2693, 2701, "Recommended"),
+  (2703, 2705, "Recommended"), (2707, 2728, "Recommended"),
+  (2730, 2736, "Recommended"), (2738, 2739, "Recommended"),
+  (2741, 2745, "Recommended"), (2748, 2757, "Recommended"),
+  (2759, 2761, "Recommended"), (2763, 2765, "Recommended"),
+  (2768, 2768, "Recommended"), (2784, 2787, "Recommended"),
+  (2790, 2799, "Recommended"), (2800, 2801, "Not_XID"),
+  (2809, 2809, "Uncommon_Use"), (2810, 2815, "Recommended"),
+  (2817, 2819, "Recommended"), (2821, 2828, "Recommended"),
+  (2831, 2832, "Recommended"), (2835, 2856, "Recommended"),
+  (2858, 2864, "Recommended"), (2866, 2867, "Recommended"),
+  (2869, 2873, "Recommended"), (2876, 2883, "Recommended"),
+  (2884, 2884, "Uncommon_Use"), (2887, 2888, "Recommended"),
+  (2891, 2893, "Recommended"), (2902, 2903, "Recommended"),
+  (2908, 2909, "Not_NFKC"), (2911, 2913, "Recommended"),
+  (2914, 2915, "Uncommon_Use"), (2918, 2927, "Recommended"),
+  (2928, 2928, "Not_XID"), (2929, 2929, "Recommended"),
+  (2930, 2935, "Not_XID"), (2946, 2947, "Recommended"),
+  (2949, 2954, "Recommended"), (2958, 2960, "Recommended"),
+  (2962, 2965, "Recommended"), (2969, 2970, "Recommended"),
+  (2972, 2972, "Recommended"), (2974, 2975, "Recommended"),
+  (2979, 2980, "Recommended"), (2984, 2986, "Recommended"),
+  (2990, 3001, "Recommended"), (3006, 3010, "Recommended"),
+  (3014, 3016, "Recommended"), (3018, 3021, "Recommended"),
+  (3024, 3024, "Recommended"), (3031, 3031, "Recommended"),
+  (3046, 3055, "Recommended"), (3056, 3066, "Not_XID"),
+  (3072, 3072, "Obsolete"), (3073, 3075, "Recommended"),
+  (3077, 3084, "Recommended"), (3086, 3088, "Recommended"),
+  (3090, 3112, "Recommended"), (3114, 3123, "Recommended"),
+  (3124, 3124, "Obsolete"), (3125, 3129, "Recommended"),
+  (3133, 3140, "Recommended"), (3142, 3144, "Recommended"),
+  (3146, 3149, "Recommended"), (3157, 3158, "Recommended"),
+  (3160, 3161, "Obsolete"), (3162, 3162, "Uncommon_Use"),
+  (3168, 3169, "Recommended"), (3170, 3171, "Uncommon_Use"),
+  (3174, 3183, "Recommended"), (3192, 3199, "Not_XID"),
+  (3200, 3200, "Recommended"), (3201, 3201, "Obsolete"),
+  (3202, 3203, "Recommended"), (3205, 3212, "Recommended"),
+  (3214, 3216, "Recommended"), (3218, 3240, "Recommended"),
+  (3242, 3251, "Recommended"), (3253, 3257, "Recommended"),
+  (3260, 3268, "Recommended"), (3270, 3272, "Recommended"),
+  (3274, 3277, "Recommended"), (3285, 3286, "Recommended"),
+  (3294, 3294, "Obsolete"), (3296, 3299, "Recommended"),
+  (3302, 3311, "Recommended"), (3313, 3314, "Recommended"),
+  (3328, 3328, "Recommended"), (3329, 3329, "Obsolete"),
+  (3330, 3331, "Recommended"), (3333, 3340, "Recommended"),
+  (3342, 3344, "Recommended"), (3346, 3386, "Recommended"),
+  (3387, 3388, "Obsolete"), (3389, 3395, "Recommended"),
+  (3396, 3396, "Uncommon_Use"), (3398, 3400, "Recommended"),
+  (3402, 3406, "Recommended"), (3407, 3407, "Not_XID"),
+  (3412, 3415, "Recommended"), (3416, 3422, "Not_XID"),
+  (3423, 3423, "Obsolete"), (3424, 3425, "Recommended"),
+  (3426, 3427, "Uncommon_Use"), (3430, 3439, "Recommended"),
+  (3440, 3449, "Not_XID"), (3450, 3455, "Recommended"),
+  (3458, 3459, "Recommended"), (3461, 3470, "Recommended"),
+  (3471, 3472, "Uncommon_Use"), (3473, 3478, "Recommended"),
+  (3482, 3493, "Recommended"), (3494, 3494, "Uncommon_Use"),
+  (3495, 3505, "Recommended"), (3507, 3515, "Recommended"),
+  (3517, 3517, "Recommended"), (3520, 3526, "Recommended"),
+  (3530, 3530, "Recommended"), (3535, 3540, "Recommended"),
+  (3542, 3542, "Recommended"), (3544, 3550, "Recommended"),
+  (3551, 3551, "Uncommon_Use"), (3558, 3567, "Obsolete"),
+  (3570, 3570, "Recommended"), (3571, 3571, "Uncommon_Use"),
+  (3572, 3572, "Not_XID"), (3585, 3634, "Recommended"),
+  (3635, 3635, "Not_NFKC"), (3636, 3642, "Recommended"),
+  (3647, 3647, "Not_XID"), (3648, 3662, "Recommended"),
+  (3663, 3663, "Not_XID"), (3664, 3673, "Recommended"),
+  (3674, 3675, "Not_XID"), (3713, 3714, "Recommended"),
+  (3716, 3716, "Recommended"), (3719, 3720, "Recommended"),
+  (3722, 3722, "Recommended"), (3725, 3725, "Recommended"),
+  (3732, 3735, "Recommended"), (3737, 3743, "Recommended"),
+  (3745, 3747, "Recommended"), (3749, 3749, "Recommended"),
+  (3751, 3751, "Recommended"), (3754, 3755, "Recommended"),
+  (3757, 3762, "Recommended"), (3763, 3763, "Not_NFKC"),
+  (3764, 3769, "Recommended"), (3771, 3773, "Recommended"),
+  (3776, 3780, "Recommended"), (3782, 3782, "Recommended"),
+  (3784, 3789, "Recommended"), (3792, 3801, "Recommended"),
+  (3804, 3805, "Not_NFKC"), (3806, 3807, "Recommended"),
+  (3840, 3840, "Recommended"), (3841, 3850, "Not_XID"),
+  (3851, 3851, "Inclusion"), (3852, 3852, "Not_NFKC"), (3853, 3863, "Not_XID"),
+  (3864, 3865, "Technical"), (3866, 3871, "Not_XID"),
+  (3872, 3881, "Recommended"), (3882, 3892, "Not_XID"),
+  (3893, 3893, "Recommended"), (3894, 3894, "Not_XID"),
+  (3895, 3895, "Recommended"), (3896, 3896, "Not_XID"),
+  (3897, 3897, "Uncommon_Use"), (3898, 3901, "Not_XID"),
+  (3902, 3906, "Recommended"), (3907, 3907, "Not_NFKC"),
+  (3908, 3911, "Recommended"), (3913, 3916, "Recommended"),
+  (3917, 3917, "Not_NFKC"), (3918, 3921, "Recommended"),
+  (3922, 3922, "Not_NFKC"), (3923, 3926, "Recommended"),
+  (3927, 3927, "Not_NFKC"), (3928, 3931, "Recommended"),
+  (3932, 3932, "Not_NFKC"), (3933, 3944, "Recommended"),
+  (3945, 3945, "Not_NFKC"), (3946, 3948, "Recommended"),
+  (3953, 3954, "Recommended"), (3955, 3955, "Not_NFKC"),
+  (3956, 3956, "Recommended"), (3957, 3958, "Not_NFKC"),
+  (3959, 3959, "Deprecated"), (3960, 3960, "Not_NFKC"),
+  (3961, 3961, "Deprecated"), (3962, 3968, "Recommended"),
+  (3969, 3969, "Not_NFKC"), (3970, 3972, "Recommended"),
+  (3973, 3973, "Not_XID"), (3974, 3986, "Recommended"),
+  (3987, 3987, "Not_NFKC"), (3988, 3991, "Recommended"),
+  (3993, 3996, "Recommended"), (3997, 3997, "Not_NFKC"),
+  (3998, 4001, "Recommended"), (4002, 4002, "Not_NFKC"),
+  (4003, 4006, "Recommended"), (4007, 4007, "Not_NFKC"),
+  (4008, 4011, "Recommended"), (4012, 4012, "Not_NFKC"),
+  (4013, 4024, "Recommended"), (4025, 4025, "Not_NFKC"),
+  (4026, 4028, "Recommended"), (4030, 4037, "Not_XID"),
+  (4038, 4038, "Recommended"), (4039, 4044, "Not_XID"),
+  (4046, 4058, "Not_XID"), (4096, 4169, "Recommended"),
+  (4170, 4175, "Not_XID"), (4176, 4253, "Recommended"),
+  (4254, 4255, "Not_XID"), (4256, 4293, "Obsolete"),
+  (4295, 4295, "Recommended"), (4301, 4301, "Recommended"),
+  (4304, 4336, "Recommended"), (4337, 4342, "Obsolete"),
+  (4343, 4346, "Recommended"), (4347, 4347, "Not_XID"),
+  (4348, 4348, "Not_NFKC"), (4349, 4351, "Recommended"),
+  (4352, 4446, "Obsolete"), (4447, 4448, "Default_Ignorable"),
+  (4449, 4607, "Obsolete"), (4608, 4680, "Recommended"),
+  (4682, 4685, "Recommended"), (4688, 4694, "Recommended"),
+  (4696, 4696, "Recommended"), (4698, 4701, "Recommended"),
+  (4704, 4744, "Recommended"), (4746, 4749, "Recommended"),
+  (4752, 4784, "Recommended"), (4786, 4789, "Recommended"),
+  (4792, 4798, "Recommended"), (4800, 4800, "Recommended"),
+  (4802, 4805, "Recommended"), (4808, 4822, "Recommended"),
+  (4824, 4880, "Recommended"), (4882, 4885, "Recommended"),
+  (4888, 4954, "Recommended"), (4957, 4959, "Recommended"),
+  (4960, 4968, "Not_XID"), (4969, 4977, "Obsolete"), (4978, 4988, "Not_XID"),
+  (4992, 5007, "Recommended"), (5008, 5017, "Not_XID"),
+  (5024, 5109, "Limited_Use"), (5112, 5117, "Limited_Use"),
+  (5120, 5759, "Limited_Use"), (5760, 5788, "Exclusion"),
+  (5792, 5880, "Exclusion"), (5888, 5900, "Exclusion"),
+  (5902, 5908, "Exclusion"), (5920, 5942, "Exclusion"),
+  (5952, 5971, "Exclusion"), (5984, 5996, "Exclusion"),
+  (5998, 6000, "Exclusion"), (6002, 6003, "Exclusion"),
+  (6016, 6050, "Recommended"), (6051, 6052, "Deprecated"),
+  (6053, 6055, "Recommended"), (6056, 6056, "Obsolete"),
+  (6057, 6067, "Recommended"), (6068, 6069, "Default_Ignorable"),
+  (6070, 6093, "Recommended"), (6094, 6095, "Technical"),
+  (6096, 6096, "Recommended"), (6097, 6097, "Technical"),
+  (6098, 6098, "Recommended"), (6099, 6099, "Obsolete"),
+  (6100, 6102, "Not_XID"), (6103, 6103, "Recommended"),
+  (6104, 6104, "Obsolete"), (6105, 6107, "Not_XID"),
+  (6108, 6108, "Recommended"), (6109, 6109, "Technical"),
+  (6112, 6121, "Recommended"), (6128, 6137, "Not_XID"),
+  (6144, 6154, "Exclusion"), (6155, 6158, "Default_Ignorable"),
+  (6160, 6169, "Exclusion"), (6176, 6263, "Exclusion"),
+  (6272, 6312, "Exclusion"), (6313, 6313, "Uncommon_Use"),
+  (6314, 6314, "Exclusion"), (6320, 6389, "Limited_Use"),
+  (6400, 6430, "Limited_Use"), (6432, 6443, "Limited_Use"),
+  (6448, 6459, "Limited_Use"), (6464, 6464, "Limited_Use"),
+  (6468, 6509, "Limited_Use"), (6512, 6516, "Limited_Use"),
+  (6528, 6571, "Limited_Use"), (6576, 6601, "Limited_Use"),
+  (6608, 6618, "Limited_Use"), (6622, 6623, "Limited_Use"),
+  (6624, 6655, "Not_XID"), (6656, 6683, "Exclusion"),
+  (6686, 6687, "Exclusion"), (6688, 6750, "Limited_Use"),
+  (6752, 6780, "Limited_Use"), (6783, 6793, "Limited_Use"),
+  (6800, 6809, "Limited_Use"), (6816, 6829, "Limited_Use"),
+  (6832, 6845, "Obsolete"), (6846, 6846, "Not_XID"),
+  (6912, 6987, "Limited_Use"), (6992, 7036, "Limited_Use"),
+  (7040, 7155, "Limited_Use"), (7164, 7223, "Limited_Use"),
+  (7227, 7241, "Limited_Use"), (7245, 7295, "Limited_Use"),
+  (7296, 7304, "Obsolete"), (7360, 7367, "Limited_Use"),
+  (7376, 7417, "Obsolete"), (7424, 7467, "Technical"),
+  (7468, 7470, "Not_NFKC"), (7471, 7471, "Technical"),
+  (7472, 7482, "Not_NFKC"), (7483, 7483, "Technical"),
+  (7484, 7501, "Not_NFKC"), (7502, 7502, "Technical"),
+  (7503, 7530, "Not_NFKC"), (7531, 7543, "Technical"),
+  (7544, 7544, "Not_NFKC"), (7545, 7578, "Technical"),
+  (7579, 7615, "Not_NFKC"), (7616, 7673, "Technical"),
+  (7675, 7679, "Technical"), (7680, 7833, "Recommended"),
+  (7834, 7835, "Not_NFKC"), (7836, 7837, "Technical"),
+  (7838, 7838, "Recommended"), (7839, 7839, "Technical"),
+  (7840, 7929, "Recommended"), (7930, 7935, "Technical"),
+  (7936, 7957, "Recommended"), (7960, 7965, "Recommended"),
+  (7968, 8005, "Recommended"), (8008, 8013, "Recommended"),
+  (8016, 8023, "Recommended"), (8025, 8025, "Recommended"),
+  (8027, 8027, "Recommended"), (8029, 8029, "Recommended"),
+  (8031, 8048, "Recommended"), (8049, 8049, "Not_NFKC"),
+  (8050, 8050, "Recommended"), (8051, 8051, "Not_NFKC"),
+  (8052, 8052, "Recommended"), (8053, 8053, "Not_NFKC"),
+  (8054, 8054, "Recommended"), (8055, 8055, "Not_NFKC"),
+  (8056, 8056, "Recommended"), (8057, 8057, "Not_NFKC"),
+  (8058, 8058, "Recommended"), (8059, 8059, "Not_NFKC"),
+  (8060, 8060, "Recommended"), (8061, 8061, "Not_NFKC"),
+  (8064, 8116, "Recommended"), (8118, 8122, "Recommended"),
+  (8123, 8123, "Not_NFKC"), (8124, 8124, "Recommended"),
+  (8125, 8129, "Not_NFKC"), (8130, 8132, "Recommended"),
+  (8134, 8136, "Recommended"), (8137, 8137, "Not_NFKC"),
+  (8138, 8138, "Recommended"), (8139, 8139, "Not_NFKC"),
+  (8140, 8140, "Recommended"), (8141, 8143, "Not_NFKC"),
+  (8144, 8146, "Recommended"), (8147, 8147, "Not_NFKC"),
+  (8150, 8154, "Recommended"), (8155, 8155, "Not_NFKC"),
+  (8157, 8159, "Not_NFKC"), (8160, 8162, "Recommended"),
+  (8163, 8163, "Not_NFKC"), (8164, 8170, "Recommended"),
+  (8171, 8171, "Not_NFKC"), (8172, 8172, "Recommended"),
+  (8173, 8175, "Not_NFKC"), (8178, 8180, "Recommended"),
+  (8182, 8184, "Recommended"), (8185, 8185, "Not_NFKC"),
+  (8186, 8186, "Recommended"), (8187, 8187, "Not_NFKC"),
+  (8188, 8188, "Recommended"), (8189, 8190, "Not_NFKC"),
+  (8192, 8202, "Not_NFKC"), (8203, 8207, "Default_Ignorable"),
+  (8208, 8208, "Inclusion"), (8209, 8209, "Not_NFKC"), (8210, 8214, "Not_XID"),
+  (8215, 8215, "Not_NFKC"), (8216, 8216, "Not_XID"), (8217, 8217, "Inclusion"),
+  (8218, 8227, "Not_XID"), (8228, 8230, "Not_NFKC"), (8231, 8231, "Inclusion"),
+  (8232, 8233, "Not_XID"), (8234, 8238, "Default_Ignorable"),
+  (8239, 8239, "Not_NFKC"), (8240, 8242, "Not_XID"), (8243, 8244, "Not_NFKC"),
+  (8245, 8245, "Not_XID"), (8246, 8247, "Not_NFKC"), (8248, 8251, "Not_XID"),
+  (8252, 8252, "Not_NFKC"), (8253, 8253, "Not_XID"), (8254, 8254, "Not_NFKC"),
+  (8255, 8256, "Technical"), (8257, 8262, "Not_XID"), (8263, 8265, "Not_NFKC"),
+  (8266, 8275, "Not_XID"), (8276, 8276, "Uncommon_Use"),
+  (8277, 8277, "Not_XID"), (8278, 8278, "Obsolete"), (8279, 8279, "Not_NFKC"),
+  (8280, 8286, "Obsolete"), (8287, 8287, "Not_NFKC"),
+  (8288, 8292, "Default_Ignorable"), (8294, 8297, "Default_Ignorable"),
+  (8298, 8303, "Deprecated"), (8304, 8305, "Not_NFKC"),
+  (8308, 8334, "Not_NFKC"), (8336, 8348, "Not_NFKC"), (8352, 8359, "Not_XID"),
+  (8360, 8360, "Not_NFKC"), (8361, 8383, "Not_XID"), (8400, 8432, "Technical"),
+  (8448, 8451, "Not_NFKC"), (8452, 8452, "Not_XID"), (8453, 8455, "Not_NFKC"),
+  (8456, 8456, "Not_XID"), (8457, 8467, "Not_NFKC"), (8468, 8468, "Not_XID"),
+  (8469, 8470, "Not_NFKC"), (8471, 8471, "Not_XID"), (8472, 8472, "Technical"),
+  (8473, 8477, "Not_NFKC"), (8478, 8479, "Not_XID"), (8480, 8482, "Not_NFKC"),
+  (8483, 8483, "Not_XID"), (8484, 8484, "Not_NFKC"), (8485, 8485, "Not_XID"),
+  (8486, 8486, "Not_NFKC"), (8487, 8487, "Obsolete"), (8488, 8488, "Not_NFKC"),
+  (8489, 8489, "Not_XID"), (8490, 8493, "Not_NFKC"), (8494, 8494, "Technical"),
+  (8495, 8497, "Not_NFKC"), (8498, 8498, "Obsolete"), (8499, 8505, "Not_NFKC"),
+  (8506, 8506, "Not_XID"), (8507, 8512, "Not_NFKC"), (8513, 8516, "Not_XID"),
+  (8517, 8521, "Not_NFKC"), (8522, 8525, "Not_XID"), (8526, 8527, "Obsolete"),
+  (8528, 8575, "Not_NFKC"), (8576, 8579, "Technical"),
+  (8580, 8584, "Obsolete"), (8585, 8585, "Not_NFKC"),
+  (8586, 8587, "Uncommon_Use"), (8592, 8747, "Not_XID"),
+  (8748, 8749, "Not_NFKC"), (8750, 8750, "Not_XID"), (8751, 8752, "Not_NFKC"),
+  (8753, 9000, "Not_XID"), (9001, 9002, "Deprecated"), (9003, 9254, "Not_XID"),
+  (9280, 9290, "Not_XID"), (9312, 9450, "Not_NFKC"), (9451, 9471, "Technical"),
+  (9472, 10239, "Not_XID"), (10240, 10495, "Technical"),
+  (10496, 10763, "Not_XID"), (10764, 10764, "Not_NFKC"),
+  (10765, 10867, "Not_XID"), (10868, 10870, "Not_NFKC"),
+  (10871, 10971, "Not_XID"), (10972, 10972, "Not_NFKC"),
+  (10973, 11123, "Not_XID"), (11126, 11157, "Not_XID"),
+  (11160, 11193, "Not_XID"), (11197, 11208, "Not_XID"),
+  (11210, 11218, "Not_XID"), (11244, 11247, "Uncommon_Use"),
+  (11264, 11310, "Exclusion"), (11312, 11358, "Exclusion"),
+  (11360, 11367, "Technical"), (11368, 11372, "Uncommon_Use"),
+  (11373, 11382, "Obsolete"), (11383, 11387, "Technical"),
+  (11388, 11389, "Not_NFKC"), (11390, 11391, "Obsolete"),
+  (11392, 11503, "Exclusion"), (11504, 11505, "Technical"),
+  (11506, 11507, "Exclusion"), (11513, 11519, "Exclusion"),
+  (11520, 11557, "Obsolete"), (11559, 11559, "Recommended"),
+  (11565, 11565, "Recommended"), (11568, 11623, "Limited_Use"),
+  (11631, 11631, "Not_NFKC"), (11632, 11632, "Limited_Use"),
+  (11647, 11647, "Limited_Use"), (11648, 11670, "Recommended"),
+  (11680, 11686, "Recommended"), (11688, 11694, "Recommended"),
+  (11696, 11702, "Recommended"), (11704, 11710, "Recommended"),
+  (11712, 11718, "Recommended"), (11720, 11726, "Recommended"),
+  (11728, 11734, "Recommended"), (11736, 11742, "Recommended"),
+  (11744, 11775, "Obsolete"), (11776, 11789, "Technical"),
+  (11790, 11798, "Obsolete"), (11799, 11817, "Not_XID"),
+  (11818, 11823, "Obsolete"), (11824, 11824, "Exclusion"),
+  (11825, 11826, "Obsolete"), (11827, 11828, "Not_XID"),
+  (11829, 11829, "Obsolete"), (11830, 11832, "Not_XID"),
+  (11833, 11833, "Obsolete"), (11834, 11835, "Not_XID"),
+  (11836, 11836, "Exclusion"), (11837, 11849, "Not_XID"),
+  (11904, 11929, "Not_XID"), (11931, 11934, "Not_XID"),
+  (11935, 11935, "Not_NFKC"), (11936, 12018, "Not_XID"),
+  (12019, 12019, "Not_NFKC"), (12032, 12245, "Not_NFKC"),
+  (12272, 12283, "Not_XID"), (12288, 12288, "Not_NFKC"),
+  (12289, 12292, "Not_XID"), (12293, 12295, "Recommended"),
+  (12296, 12317, "Not_XID"), (12318, 12318, "Obsolete"),
+  (12319, 12320, "Not_XID"), (12321, 12335, "Technical"),
+  (12336, 12336, "Not_XID"), (12337, 12341, "Technical"),
+  (12342, 12342, "Not_NFKC"), (12343, 12343, "Not_XID"),
+  (12344, 12346, "Not_NFKC"), (12347, 12348, "Technical"),
+  (12349, 12351, "Not_XID"), (12353, 12438, "Recommended"),
+  (12441, 12442, "Recommended"), (12443, 12444, "Not_NFKC"),
+  (12445, 12446, "Recommended"), (12447, 12447, "Not_NFKC"),
+  (12448, 12448, "Inclusion"), (12449, 12538, "Recommended"),
+  (12539, 12539, "Inclusion"), (12540, 12542, "Recommended"),
+  (12543, 12543, "Not_NFKC"), (12549, 12589, "Recommended"),
+  (12590, 12590, "Obsolete"), (12593, 12643, "Not_NFKC"),
+  (12644, 12644, "Default_Ignorable"), (12645, 12686, "Not_NFKC"),
+  (12688, 12689, "Not_XID"), (12690, 12703, "Not_NFKC"),
+  (12704, 12730, "Recommended"), (12736, 12771, "Not_XID"),
+  (12784, 12799, "Obsolete"), (12800, 12830, "Not_NFKC"),
+  (12832, 12871, "Not_NFKC"), (12872, 12879, "Not_XID"),
+  (12880, 12926, "Not_NFKC"), (12927, 12927, "Technical"),
+  (12928, 13054, "Not_NFKC"), (13056, 13311, "Not_NFKC"),
+  (13312, 19893, "Recommended"), (19904, 19967, "Technical"),
+  (19968, 40938, "Recommended"), (40960, 42124, "Limited_Use"),
+  (42128, 42182, "Limited_Use"), (42192, 42539, "Limited_Use"),
+  (42560, 42606, "Obsolete"), (42607, 42607, "Uncommon_Use"),
+  (42608, 42619, "Obsolete"), (42620, 42621, "Uncommon_Use"),
+  (42622, 42622, "Not_XID"), (42623, 42623, "Recommended"),
+  (42624, 42651, "Obsolete"), (42652, 42653, "Not_NFKC"),
+  (42654, 42654, "Uncommon_Use"), (42655, 42655, "Obsolete"),
+  (42656, 42743, "Limited_Use"), (42752, 42759, "Obsolete"),
+  (42760, 42774, "Technical"), (42775, 42783, "Recommended"),
+  (42784, 42785, "Not_XID"), (42786, 42799, "Technical"),
+  (42800, 42863, "Obsolete"), (42864, 42864, "Not_NFKC"),
+  (42865, 42887, "Obsolete"), (42888, 42888, "Recommended"),
+  (42889, 42890, "Not_XID"), (42891, 42892, "Uncommon_Use"),
+  (42893, 42893, "Recommended"), (42894, 42894, "Technical"),
+  (42895, 42895, "Uncommon_Use"), (42896, 42897, "Obsolete"),
+  (42898, 42899, "Recommended"), (42900, 42921, "Obsolete"),
+  (42922, 42922, "Recommended"), (42923, 42925, "Obsolete"),
+  (42926, 42926, "Technical"), (42928, 42929, "Obsolete"),
+  (42930, 42935, "Uncommon_Use"), (42999, 42999, "Obsolete"),
+  (43000, 43001, "Not_NFKC"), (43002, 43002, "Technical"),
+  (43003, 43007, "Obsolete"), (43008, 43051, "Limited_Use"),
+  (43056, 43065, "Not_XID"), (43072, 43127, "Exclusion"),
+  (43136, 43205, "Limited_Use"), (43214, 43225, "Limited_Use"),
+  (43232, 43259, "Obsolete"), (43260, 43261, "Uncommon_Use"),
+  (43264, 43309, "Limited_Use"), (43310, 43310, "Not_XID"),
+  (43311, 43311, "Limited_Use"), (43312, 43347, "Exclusion"),
+  (43359, 43359, "Exclusion"), (43360, 43388, "Obsolete"),
+  (43392, 43469, "Limited_Use"), (43471, 43481, "Limited_Use"),
+  (43486, 43487, "Limited_Use"), (43488, 43494, "Obsolete"),
+  (43495, 43518, "Recommended"), (43520, 43574, "Limited_Use"),
+  (43584, 43597, "Limited_Use"), (43600, 43609, "Limited_Use"),
+  (43612, 43615, "Limited_Use"), (43616, 43638, "Recommended"),
+  (43639, 43641, "Not_XID"), (43642, 43647, "Recommended"),
+  (43648, 43714, "Limited_Use"), (43739, 43766, "Limited_Use"),
+  (43777, 43782, "Recommended"), (43785, 43790, "Recommended"),
+  (43793, 43798, "Recommended"), (43808, 43814, "Recommended"),
+  (43816, 43822, "Recommended"), (43824, 43866, "Obsolete"),
+  (43867, 43867, "Not_XID"), (43868, 43871, "Not_NFKC"),
+  (43872, 43875, "Uncommon_Use"), (43876, 43877, "Obsolete"),
+  (43888, 44013, "Limited_Use"), (44016, 44025, "Limited_Use"),
+  (44032, 55203, "Recommended"), (55216, 55238, "Obsolete"),
+  (55243, 55291, "Obsolete"), (63744, 64013, "Not_NFKC"),
+  (64014, 64015, "Recommended"), (64016, 64016, "Not_NFKC"),
+  (64017, 64017, "Recommended"), (64018, 64018, "Not_NFKC"),
+  (64019, 64020, "Recommended"), (64021, 64030, "Not_NFKC"),
+  (64031, 64031, "Recommended"), (64032, 64032, "Not_NFKC"),
+  (64033, 64033, "Recommended"), (64034, 64034, "Not_NFKC"),
+  (64035, 64036, "Recommended"), (64037, 64038, "Not_NFKC"),
+  (64039, 64041, "Recommended"), (64042, 64109, "Not_NFKC"),
+  (64112, 64217, "Not_NFKC"), (64256, 64262, "Not_NFKC"),
+  (64275, 64279, "Not_NFKC"), (64285, 64285, "Not_NFKC"),
+  (64286, 64286, "Uncommon_Use"), (64287, 64310, "Not_NFKC"),
+  (64312, 64316, "Not_NFKC"), (64318, 64318, "Not_NFKC"),
+  (64320, 64321, "Not_NFKC"), (64323, 64324, "Not_NFKC"),
+  (64326, 64433, "Not_NFKC"), (64434, 64449, "Technical"),
+  (64467, 64829, "Not_NFKC"), (64830, 64831, "Technical"),
+  (64848, 64911, "Not_NFKC"), (64914, 64967, "Not_NFKC"),
+  (65008, 65020, "Not_NFKC"), (65021, 65021, "Technical"),
+  (65024, 65039, "Default_Ignorable"), (65040, 65049, "Not_NFKC"),
+  (65056, 65069, "Technical"), (65070, 65071, "Uncommon_Use"),
+  (65072, 65092, "Not_NFKC"), (65093, 65094, "Technical"),
+  (65095, 65106, "Not_NFKC"), (65108, 65126, "Not_NFKC"),
+  (65128, 65131, "Not_NFKC"), (65136, 65138, "Not_NFKC"),
+  (65139, 65139, "Technical"), (65140, 65140, "Not_NFKC"),
+  (65142, 65276, "Not_NFKC"), (65279, 65279, "Default_Ignorable"),
+  (65281, 65439, "Not_NFKC"), (65440, 65440, "Default_Ignorable"),
+  (65441, 65470, "Not_NFKC"), (65474, 65479, "Not_NFKC"),
+  (65482, 65487, "Not_NFKC"), (65490, 65495, "Not_NFKC"),
+  (65498, 65500, "Not_NFKC"), (65504, 65510, "Not_NFKC"),
+  (65512, 65518, "Not_NFKC"), (65529, 65533, "Not_XID"),
+  (65536, 65547, "Exclusion"), (65549, 65574, "Exclusion"),
+  (65576, 65594, "Exclusion"), (65596, 65597, "Exclusion"),
+  (65599, 65613, "Exclusion"), (65616, 65629, "Exclusion"),
+  (65664, 65786, "Exclusion"), (65792, 65794, "Exclusion"),
+  (65799, 65843, "Exclusion"), (65847, 65855, "Exclusion"),
+  (65856, 65908, "Obsolete"), (65909, 65934, "Not_XID"),
+  (65936, 65947, "Not_XID"), (65952, 65952, "Not_XID"),
+  (66000, 66045, "Obsolete"), (66176, 66204, "Exclusion"),
+  (66208, 66256, "Exclusion"), (66272, 66299, "Obsolete"),
+  (66304, 66339, "Exclusion"), (66349, 66378, "Exclusion"),
+  (66384, 66426, "Exclusion"), (66432, 66461, "Exclusion"),
+  (66463, 66499, "Exclusion"), (66504, 66517, "Exclusion"),
+  (66560, 66717, "Exclusion"), (66720, 66729, "Exclusion"),
+  (66736, 66771, "Limited_Use"), (66776, 66811, "Limited_Use"),
+  (66816, 66855, "Exclusion"), (66864, 66915, "Exclusion"),
+  (66927, 66927, "Exclusion"), (67072, 67382, "Exclusion"),
+  (67392, 67413, "Exclusion"), (67424, 67431, "Exclusion"),
+  (67584, 67589, "Exclusion"), (67592, 67592, "Exclusion"),
+  (67594, 67637, "Exclusion"), (67639, 67640, "Exclusion"),
+  (67644, 67644, "Exclusion"), (67647, 67669, "Exclusion"),
+  (67671, 67742, "Exclusion"), (67751, 67759, "Exclusion"),
+  (67808, 67826, "Exclusion"), (67828, 67829, "Exclusion"),
+  (67835, 67867, "Exclusion"), (67871, 67897, "Exclusion"),
+  (67903, 67903, "Exclusion"), (67968, 68023, "Exclusion"),
+  (68028, 68047, "Exclusion"), (68050, 68099, "Exclusion"),
+  (68101, 68102, "Exclusion"), (68108, 68115, "Exclusion"),
+  (68117, 68119, "Exclusion"), (68121, 68147, "Exclusion"),
+  (68152, 68154, "Exclusion"), (68159, 68167, "Exclusion"),
+  (68176, 68184, "Exclusion"), (68192, 68255, "Exclusion"),
+  (68288, 68326, "Exclusion"), (68331, 68342, "Exclusion"),
+  (68352, 68405, "Exclusion"), (68409, 68437, "Exclusion"),
+  (68440, 68466, "Exclusion"), (68472, 68497, "Exclusion"),
+  (68505, 68508, "Exclusion"), (68521, 68527, "Exclusion"),
+  (68608, 68680, "Exclusion"), (68736, 68786, "Exclusion"),
+  (68800, 68850, "Exclusion"), (68858, 68863, "Exclusion"),
+  (69216, 69246, "Not_XID"), (69632, 69709, "Exclusion"),
+  (69714, 69743, "Exclusion"), (69759, 69825, "Exclusion"),
+  (69840, 69864, "Exclusion"), (69872, 69881, "Exclusion"),
+  (69888, 69940, "Limited_Use"), (69942, 69955, "Limited_Use"),
+  (69968, 70006, "Exclusion"), (70016, 70093, "Exclusion"),
+  (70096, 70111, "Exclusion"), (70113, 70132, "Not_XID"),
+  (70144, 70161, "Exclusion"), (70163, 70206, "Exclusion"),
+  (70272, 70278, "Exclusion"), (70280, 70280, "Exclusion"),
+  (70282, 70285, "Exclusion"), (70287, 70301, "Exclusion"),
+  (70303, 70313, "Exclusion"), (70320, 70378, "Exclusion"),
+  (70384, 70393, "Exclusion"), (70400, 70400, "Exclusion"),
+  (70401, 70401, "Recommended"), (70402, 70402, "Exclusion"),
+  (70403, 70403, "Recommended"), (70405, 70412, "Exclusion"),
+  (70415, 70416, "Exclusion"), (70419, 70440, "Exclusion"),
+  (70442, 70448, "Exclusion"), (70450, 70451, "Exclusion"),
+  (70453, 70457, "Exclusion"), (70460, 70460, "Recommended"),
+  (70461, 70468, "Exclusion"), (70471, 70472, "Exclusion"),
+  (70475, 70477, "Exclusion"), (70480, 70480, "Exclusion"),
+  (70487, 70487, "Exclusion"), (70493, 70499, "Exclusion"),
+  (70502, 70508, "Exclusion"), (70512, 70516, "Exclusion"),
+  (70656, 70745, "Limited_Use"), (70747, 70747, "Limited_Use"),
+  (70749, 70749, "Limited_Use"), (70784, 70855, "Exclusion"),
+  (70864, 70873, "Exclusion"), (71040, 71093, "Exclusion"),
+  (71096, 71133, "Exclusion"), (71168, 71236, "Exclusion"),
+  (71248, 71257, "Exclusion"), (71264, 71276, "Exclusion"),
+  (71296, 71351, "Exclusion"), (71360, 71369, "Exclusion"),
+  (71424, 71449, "Exclusion"), (71453, 71467, "Exclusion"),
+  (71472, 71487, "Exclusion"), (71840, 71922, "Exclusion"),
+  (71935, 71935, "Exclusion"), (72192, 72263, "Exclusion"),
+  (72272, 72323, "Exclusion"), (72326, 72348, "Exclusion"),
+  (72350, 72354, "Exclusion"), (72384, 72440, "Exclusion"),
+  (72704, 72712, "Exclusion"), (72714, 72758, "Exclusion"),
+  (72760, 72773, "Exclusion"), (72784, 72812, "Exclusion"),
+  (72816, 72847, "Exclusion"), (72850, 72871, "Exclusion"),
+  (72873, 72886, "Exclusion"), (72960, 72966, "Exclusion"),
+  (72968, 72969, "Exclusion"), (72971, 73014, "Exclusion"),
+  (73018, 73018, "Exclusion"), (73020, 73021, "Exclusion"),
+  (73023, 73031, "Exclusion"), (73040, 73049, "Exclusion"),
+  (73728, 74649, "Exclusion"), (74752, 74862, "Exclusion"),
+  (74864, 74868, "Exclusion"), (74880, 75075, "Exclusion"),
+  (77824, 78894, "Exclusion"), (82944, 83526, "Exclusion"),
+  (92160, 92728, "Limited_Use"), (92736, 92766, "Uncommon_Use"),
+  (92768, 92777, "Uncommon_Use"), (92782, 92783, "Exclusion"),
+  (92880, 92909, "Exclusion"), (92912, 92917, "Exclusion"),
+  (92928, 92997, "Exclusion"), (93008, 93017, "Exclusion"),
+  (93019, 93025, "Exclusion"), (93027, 93047, "Exclusion"),
+  (93053, 93071, "Exclusion"), (93952, 94020, "Limited_Use"),
+  (94032, 94078, "Limited_Use"), (94095, 94111, "Limited_Use"),
+  (94176, 94177, "Exclusion"), (94208, 100332, "Exclusion"),
+  (100352, 101106, "Exclusion"), (110592, 110878, "Obsolete"),
+  (110960, 111355, "Exclusion"), (113664, 113770, "Exclusion"),
+  (113776, 113788, "Exclusion"), (113792, 113800, "Exclusion"),
+  (113808, 113817, "Exclusion"), (113820, 113823, "Exclusion"),
+  (113824, 113827, "Default_Ignorable"), (118784, 119029, "Technical"),
+  (119040, 119078, "Technical"), (119081, 119133, "Technical"),
+  (119134, 119140, "Not_NFKC"), (119141, 119154, "Technical"),
+  (119155, 119162, "Default_Ignorable"), (119163, 119226, "Technical"),
+  (119227, 119232, "Not_NFKC"), (119233, 119261, "Technical"),
+  (119262, 119272, "Uncommon_Use"), (119296, 119361, "Obsolete"),
+  (119362, 119364, "Technical"), (119365, 119365, "Obsolete"),
+  (119552, 119638, "Technical"), (119648, 119665, "Not_XID"),
+  (119808, 119892, "Not_NFKC"), (119894, 119964, "Not_NFKC"),
+  (119966, 119967, "Not_NFKC"), (119970, 119970, "Not_NFKC"),
+  (119973, 119974, "Not_NFKC"), (119977, 119980, "Not_NFKC"),
+  (119982, 119993, "Not_NFKC"), (119995, 119995, "Not_NFKC"),
+  (119997, 120003, "Not_NFKC"), (120005, 120069, "Not_NFKC"),
+  (120071, 120074, "Not_NFKC"), (120077, 120084, "Not_NFKC"),
+  (120086, 120092, "Not_NFKC"), (120094, 120121, "Not_NFKC"),
+  (120123, 120126, "Not_NFKC"), (120128, 120132, "Not_NFKC"),
+  (120134, 120134, "Not_NFKC"), (120138, 120144, "Not_NFKC"),
+  (120146, 120485, "Not_NFKC"), (120488, 120779, "Not_NFKC"),
+  (120782, 120831, "Not_NFKC"), (120832, 121483, "Exclusion"),
+  (121499, 121503, "Exclusion"), (121505, 121519, "Exclusion"),
+  (122880, 122886, "Exclusion"), (122888, 122904, "Exclusion"),
+  (122907, 122913, "Exclusion"), (122915, 122916, "Exclusion"),
+  (122918, 122922, "Exclusion"), (124928, 125124, "Exclusion"),
+  (125127, 125142, "Exclusion"), (125184, 125258, "Limited_Use"),
+  (125264, 125273, "Limited_Use"), (125278, 125279, "Limited_Use"),
+  (126464, 126467, "Not_NFKC"), (126469, 126495, "Not_NFKC"),
+  (126497, 126498, "Not_NFKC"), (126500, 126500, "Not_NFKC"),
+  (126503, 126503, "Not_NFKC"), (126505, 126514, "Not_NFKC"),
+  (126516, 126519, "Not_NFKC"), (126521, 126521, "Not_NFKC"),
+  (126523, 126523, "Not_NFKC"), (126530, 126530, "Not_NFKC"),
+  (126535, 126535, "Not_NFKC"), (126537, 126537, "Not_NFKC"),
+  (126539, 126539, "Not_NFKC"), (126541, 126543, "Not_NFKC"),
+  (126545, 126546, "Not_NFKC"), (126548, 126548, "Not_NFKC"),
+  (126551, 126551, "Not_NFKC"), (126553, 126553, "Not_NFKC"),
+  (126555, 126555, "Not_NFKC"), (126557, 126557, "Not_NFKC"),
+  (126559, 126559, "Not_NFKC"), (126561, 126562, "Not_NFKC"),
+  (126564, 126564, "Not_NFKC"), (126567, 126570, "Not_NFKC"),
+  (126572, 126578, "Not_NFKC"), (126580, 126583, "Not_NFKC"),
+  (126585, 126588, "Not_NFKC"), (126590, 126590, "Not_NFKC"),
+  (126592, 126601, "Not_NFKC"), (126603, 126619, "Not_NFKC"),
+  (126625, 126627, "Not_NFKC"), (126629, 126633, "Not_NFKC"),
+  (126635, 126651, "Not_NFKC"), (126704, 126705, "Not_XID"),
+  (126976, 127019, "Not_XID"), (127024, 127123, "Not_XID"),
+  (127136, 127150, "Not_XID"), (127153, 127167, "Not_XID"),
+  (127169, 127183, "Not_XID"), (127185, 127221, "Not_XID"),
+  (127232, 127242, "Not_NFKC"), (127243, 127244, "Not_XID"),
+  (127248, 127278, "Not_NFKC"), (127280, 127311, "Not_NFKC"),
+  (127312, 127337, "Not_XID"), (127338, 127339, "Not_NFKC"),
+  (127344, 127375, "Not_XID"), (127376, 127376, "Not_NFKC"),
+  (127377, 127404, "Not_XID"), (127462, 127487, "Not_XID"),
+  (127488, 127490, "Not_NFKC"), (127504, 127547, "Not_NFKC"),
+  (127552, 127560, "Not_NFKC"), (127568, 127569, "Not_NFKC"),
+  (127584, 127589, "Not_XID"), (127744, 128334, "Not_XID"),
+  (128335, 128335, "Uncommon_Use"), (128336, 128724, "Not_XID"),
+  (128736, 128748, "Not_XID"), (128752, 128760, "Not_XID"),
+  (128768, 128883, "Not_XID"), (128896, 128980, "Not_XID"),
+  (129024, 129035, "Not_XID"), (129040, 129095, "Not_XID"),
+  (129104, 129113, "Not_XID"), (129120, 129159, "Not_XID"),
+  (129168, 129197, "Not_XID"), (129280, 129291, "Not_XID"),
+  (129296, 129342, "Not_XID"), (129344, 129356, "Not_XID"),
+  (129360, 129387, "Not_XID"), (129408, 129431, "Not_XID"),
+  (129472, 129472, "Not_XID"), (129488, 129510, "Not_XID"),
+  (131072, 173782, "Recommended"), (173824, 177972, "Recommended"),
+  (177984, 178205, "Recommended"), (178208, 183969, "Recommended"),
+  (183984, 191456, "Recommended"), (194560, 195101, "Not_NFKC"),
+  (917505, 917505, "Deprecated"), (917536, 917631, "Default_Ignorable"),
+  (917760, 917999, "Default_Ignorable"),
+];
@@ -1,2 +1,32 @@
+pub mod age;
+pub mod bidi_brackets;
+pub mod bidi_class;
+pub mod bidi_mirrored;
+pub mod bidi_mirroring_glyph;
+pub mod block;
+pub mod canonical_combining_class;
+pub mod composition;
+pub mod confusables;
+pub mod decomposition;
+pub mod default_ignorable_code_point;
+pub mod east_asian_width;
+pub mod emoji_properties;
+pub mod emoji_sequences;
+pub mod emoji_variation_sequences;
+pub mod extended_pictographic;
+pub mod general_category;
+pub mod identifier_properties;
+pub mod identifier_status;
+pub mod identifier_type;
+pub mod grapheme_cluster_break;
+pub mod line_break;
+pub mod normalization_quick_check;
+pub mod numeric_type;
+pub mod numeric_value;
 pub mod property_names;
 pub mod property_values;
+pub mod script;
+pub mod script_extension;
+pub mod sentence_break;
+pub mod standardized_variants;
+pub mod word_break;
@@ -0,0 +1,144 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//  ucd-generate normalization-quick-check /home/andrew/tmp/ucd-10.0.0/
+//
+// ucd-generate is available on crates.io.
+
+pub const NFC_QUICK_CHECK: &'static [(u32, u32, &'static str)] = &[
+  (768, 772, "Maybe"), (774, 780, "Maybe"), (783, 783, "Maybe"),
+  (785, 785, "Maybe"), (787, 788, "Maybe"), (795, 795, "Maybe"),
+  (803, 808, "Maybe"), (813, 814, "Maybe"), (816, 817, "Maybe"),
+  (824, 824, "Maybe"), (832, 833, "No"), (834, 834, "Maybe"), (835, 836, "No"),
+  (837, 837, "Maybe"), (884, 884, "No"), (894, 894, "No"), (903, 903, "No"),
+  (1619, 1621, "Maybe"), (2364, 2364, "Maybe"), (2392, 2399, "No"),
+  (2494, 2494, "Maybe"), (2519, 2519, "Maybe"), (2524, 2525, "No"),
+  (2527, 2527, "No"), (2611, 2611, "No"), (2614, 2614, "No"),
+  (2649, 2651, "No"), (2654, 2654, "No"), (2878, 2878, "Maybe"),
+  (2902, 2903, "Maybe"), (2908, 2909, "No"), (3006, 3006, "Maybe"),
+  (3031, 3031, "Maybe"), (3158, 3158, "Maybe"), (3266, 3266, "Maybe"),
+  (3285, 3286, "Maybe"), (3390, 3390, "Maybe"), (3415, 3415, "Maybe"),
+  (3530, 3530, "Maybe"), (3535, 3535, "Maybe"), (3551, 3551, "Maybe"),
+  (3907, 3907, "No"), (3917, 3917, "No"), (3922, 3922, "No"),
+  (3927, 3927, "No"), (3932, 3932, "No"), (3945, 3945, "No"),
+  (3955, 3955, "No"), (3957, 3958, "No"), (3960, 3960, "No"),
+  (3969, 3969, "No"), (3987, 3987, "No"), (3997, 3997, "No"),
+  (4002, 4002, "No"), (4007, 4007, "No"), (4012, 4012, "No"),
+  (4025, 4025, "No"), (4142, 4142, "Maybe"), (4449, 4469, "Maybe"),
+  (4520, 4546, "Maybe"), (6965, 6965, "Maybe"), (8049, 8049, "No"),
+  (8051, 8051, "No"), (8053, 8053, "No"), (8055, 8055, "No"),
+  (8057, 8057, "No"), (8059, 8059, "No"), (8061, 8061, "No"),
+  (8123, 8123, "No"), (8126, 8126, "No"), (8137, 8137, "No"),
+  (8139, 8139, "No"), (8147, 8147, "No"), (8155, 8155, "No"),
+  (8163, 8163, "No"), (8171, 8171, "No"), (8174, 8175, "No"),
+  (8185, 8185, "No"), (8187, 8187, "No"), (8189, 8189, "No"),
+  (8192, 8193, "No"), (8486, 8486, "No"), (8490, 8491, "No"),
+  (9001, 9002, "No"), (10972, 10972, "No"), (12441, 12442, "Maybe"),
+  (63744, 64013, "No"), (64016, 64016, "No"), (64018, 64018, "No"),
+  (64021, 64030, "No"), (64032, 64032, "No"), (64034, 64034, "No"),
+  (64037, 64038, "No"), (64042, 64109, "No"), (64112, 64217, "No"),
+  (64285, 64285, "No"), (64287, 64287, "No"), (64298, 64310, "No"),
+  (64312, 64316, "No"), (64318, 64318, "No"), (64320, 64321, "No"),
+  (64323, 64324, "No"), (64326, 64334, "No"), (69818, 69818, "Maybe"),
+  (69927, 69927, "Maybe"), (70462, 70462, "Maybe"), (70487, 70487, "Maybe"),
+  (70832, 70832, "Maybe"), (70842, 70842, "Maybe"), (70845, 70845, "Maybe"),
+  (71087, 71087, "Maybe"), (119134, 119140, "No"), (119227, 119232, "No"),
+  (194560, 195101, "No"),
+];
+
+pub const NFKC_QUICK_CHECK: &'static [(u32, u32, &'static str)] = &[
+  (160, 160, "No"), (168, 168, "No"), (170, 170, "No"), (175, 175, "No"),
+  (178, 181, "No"), (184, 186, "No"), (188, 190, "No"), (306, 307, "No"),
+  (319, 320, "No"), (329, 329, "No"), (383, 383, "No"), (452, 460, "No"),
+  (497, 499, "No"), (688, 696, "No"), (728, 733, "No"), (736, 740, "No"),
+  (768, 772, "Maybe"), (774, 780, "Maybe"), (783, 783, "Maybe"),
+  (785, 785, "Maybe"), (787, 788, "Maybe"), (795, 795, "Maybe"),
+  (803, 808, "Maybe"), (813, 814, "Maybe"), (816, 817, "Maybe"),
+  (824, 824, "Maybe"), (832, 833, "No"), (834, 834, "Maybe"), (835, 836, "No"),
+  (837, 837, "Maybe"), (884, 884, "No"), (890, 890, "No"), (894, 894, "No"),
+  (900, 901, "No"), (903, 903, "No"), (976, 982, "No"), (1008, 1010, "No"),
+  (1012, 1013, "No"), (1017, 1017, "No"), (1415, 1415, "No"),
+  (1619, 1621, "Maybe"), (1653, 1656, "No"), (2364, 2364, "Maybe"),
+  (2392, 2399, "No"), (2494, 2494, "Maybe"), (2519, 2519, "Maybe"),
+  (2524, 2525, "No"), (2527, 2527, "No"), (2611, 2611, "No"),
+  (2614, 2614, "No"), (2649, 2651, "No"), (2654, 2654, "No"),
+  (2878, 2878, "Maybe"), (2902, 2903, "Maybe"), (2908, 2909, "No"),
+  (3006, 3006, "Maybe"), (3031, 3031, "Maybe"), (3158, 3158, "Maybe"),
+  (3266, 3266, "Maybe"), (3285, 3286, "Maybe"), (3390, 3390, "Maybe"),
+  (3415, 3415, "Maybe"), (3530, 3530, "Maybe"), (3535, 3535, "Maybe"),
+  (3551, 3551, "Maybe"), (3635, 3635, "No"), (3763, 3763, "No"),
+  (3804, 3805, "No"), (3852, 3852, "No"), (3907, 3907, "No"),
+  (3917, 3917, "No"), (3922, 3922, "No"), (3927, 3927, "No"),
+  (3932, 3932, "No"), (3945, 3945, "No"), (3955, 3955, "No"),
+  (3957, 3961, "No"), (3969, 3969, "No"), (3987, 3987, "No"),
+  (3997, 3997, "No"), (4002, 4002, "No"), (4007, 4007, "No"),
+  (4012, 4012, "No"), (4025, 4025, "No"), (4142, 4142, "Maybe"),
+  (4348, 4348, "No"), (4449, 4469, "Maybe"), (4520, 4546, "Maybe"),
+  (6965, 6965, "Maybe"), (7468, 7470, "No"), (7472, 7482, "No"),
+  (7484, 7501, "No"), (7503, 7530, "No"), (7544, 7544, "No"),
+  (7579, 7615, "No"), (7834, 7835, "No"), (8049, 8049, "No"),
+  (8051, 8051, "No"), (8053, 8053, "No"), (8055, 8055, "No"),
+  (8057, 8057, "No"), (8059, 8059, "No"), (8061, 8061, "No"),
+  (8123, 8123, "No"), (8125, 8129, "No"), (8137, 8137, "No"),
+  (8139, 8139, "No"), (8141, 8143, "No"), (8147, 8147, "No"),
+  (8155, 8155, "No"), (8157, 8159, "No"), (8163, 8163, "No"),
+  (8171, 8171, "No"), (8173, 8175, "No"), (8185, 8185, "No"),
+  (8187, 8187, "No"), (8189, 8190, "No"), (8192, 8202, "No"),
+  (8209, 8209, "No"), (8215, 8215, "No"), (8228, 8230, "No"),
+  (8239, 8239, "No"), (8243, 8244, "No"), (8246, 8247, "No"),
+  (8252, 8252, "No"), (8254, 8254, "No"), (8263, 8265, "No"),
+  (8279, 8279, "No"), (8287, 8287, "No"), (8304, 8305, "No"),
+  (8308, 8334, "No"), (8336, 8348, "No"), (8360, 8360, "No"),
+  (8448, 8451, "No"), (8453, 8455, "No"), (8457, 8467, "No"),
+  (8469, 8470, "No"), (8473, 8477, "No"), (8480, 8482, "No"),
+  (8484, 8484, "No"), (8486, 8486, "No"), (8488, 8488, "No"),
+  (8490, 8493, "No"), (8495, 8497, "No"), (8499, 8505, "No"),
+  (8507, 8512, "No"), (8517, 8521, "No"), (8528, 8575, "No"),
+  (8585, 8585, "No"), (8748, 8749, "No"), (8751, 8752, "No"),
+  (9001, 9002, "No"), (9312, 9450, "No"), (10764, 10764, "No"),
+  (10868, 10870, "No"), (10972, 10972, "No"), (11388, 11389, "No"),
+  (11631, 11631, "No"), (11935, 11935, "No"), (12019, 12019, "No"),
+  (12032, 12245, "No"), (12288, 12288, "No"), (12342, 12342, "No"),
+  (12344, 12346, "No"), (12441, 12442, "Maybe"), (12443, 12444, "No"),
+  (12447, 12447, "No"), (12543, 12543, "No"), (12593, 12686, "No"),
+  (12690, 12703, "No"), (12800, 12830, "No"), (12832, 12871, "No"),
+  (12880, 12926, "No"), (12928, 13054, "No"), (13056, 13311, "No"),
+  (42652, 42653, "No"), (42864, 42864, "No"), (43000, 43001, "No"),
+  (43868, 43871, "No"), (63744, 64013, "No"), (64016, 64016, "No"),
+  (64018, 64018, "No"), (64021, 64030, "No"), (64032, 64032, "No"),
+  (64034, 64034, "No"), (64037, 64038, "No"), (64042, 64109, "No"),
+  (64112, 64217, "No"), (64256, 64262, "No"), (64275, 64279, "No"),
+  (64285, 64285, "No"), (64287, 64310, "No"), (64312, 64316, "No"),
+  (64318, 64318, "No"), (64320, 64321, "No"), (64323, 64324, "No"),
+  (64326, 64433, "No"), (64467, 64829, "No"), (64848, 64911, "No"),
+  (64914, 64967, "No"), (65008, 65020, "No"), (65040, 65049, "No"),
+  (65072, 65092, "No"), (65095, 65106, "No"), (65108, 65126, "No"),
+  (65128, 65131, "No"), (65136, 65138, "No"), (65140, 65140, "No"),
+  (65142, 65276, "No"), (65281, 65470, "No"), (65474, 65479, "No"),
+  (65482, 65487, "No"), (65490, 65495, "No"), (65498, 65500, "No"),
+  (65504, 65510, "No"), (65512, 65518, "No"), (69818, 69818, "Maybe"),
+  (69927, 69927, "Maybe"), (70462, 70462, "Maybe"), (70487, 70487, "Maybe"),
+  (70832, 70832, "Maybe"), (70842, 70842, "Maybe"), (70845, 70845, "Maybe"),
+  (71087, 71087, "Maybe"), (119134, 119140, "No"), (119227, 119232, "No"),
+  (119808, 119892, "No"), (119894, 119964, "No"), (119966, 119967, "No"),
+  (119970, 119970, "No"), (119973, 119974, "No"), (119977, 119980, "No"),
+  (119982, 119993, "No"), (119995, 119995, "No"), (119997, 120003, "No"),
+  (120005, 120069, "No"), (120071, 120074, "No"), (120077, 120084, "No"),
+  (120086, 120092, "No"), (120094, 120121, "No"), (120123, 120126, "No"),
+  (120128, 120132, "No"), (120134, 120134, "No"), (120138, 120144, "No"),
+  (120146, 120485, "No"), (120488, 120779, "No"), (120782, 120831, "No"),
+  (126464, 126467, "No"), (126469, 126495, "No"), (126497, 126498, "No"),
+  (126500, 126500, "No"), (126503, 126503, "No"), (126505, 126514, "No"),
+  (126516, 126519, "No"), (126521, 126521, "No"), (126523, 126523, "No"),
+  (126530, 126530, "No"), (126535, 126535, "No"), (126537, 126537, "No"),
+  (126539, 126539, "No"), (126541, 126543, "No"), (126545, 126546, "No"),
+  (126548, 126548, "No"), (126551, 126551, "No"), (126553, 126553, "No"),
+  (126555, 126555, "No"), (126557, 126557, "No"), (126559, 126559, "No"),
+  (126561, 126562, "No"), (126564, 126564, "No"), (126567, 126570, "No"),
+  (126572, 126578, "No"), (126580, 126583, "No"), (126585, 126588, "No"),
+  (126590, 126590, "No"), (126592, 126601, "No"), (126603, 126619, "No"),
+  (126625, 126627, "No"), (126629, 126633, "No"), (126635, 126651, "No"),
+  (127232, 127242, "No"), (127248, 127278, "No"), (127280, 127311, "No"),
+  (127338, 127339, "No"), (127376, 127376, "No"), (127488, 127490, "No"),
+  (127504, 127547, "No"), (127552, 127560, "No"), (127568, 127569, "No"),
+  (194560, 195101, "No"),
+];
@@ -0,0 +1,105 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//  ucd-generate numeric-type /home/andrew/tmp/ucd-10.0.0/ --enum
+//
+// ucd-generate is available on crates.io.
+
+pub const NUMERIC_TYPE: &'static [(u32, u32, &'static str)] = &[
+  (48, 57, "Decimal"), (178, 179, "Digit"), (185, 185, "Digit"),
+  (188, 190, "Numeric"), (1632, 1641, "Decimal"), (1776, 1785, "Decimal"),
+  (1984, 1993, "Decimal"), (2406, 2415, "Decimal"), (2534, 2543, "Decimal"),
+  (2548, 2553, "Numeric"), (2662, 2671, "Decimal"), (2790, 2799, "Decimal"),
+  (2918, 2927, "Decimal"), (2930, 2935, "Numeric"), (3046, 3055, "Decimal"),
+  (3056, 3058, "Numeric"), (3174, 3183, "Decimal"), (3192, 3198, "Numeric"),
+  (3302, 3311, "Decimal"), (3416, 3422, "Numeric"), (3430, 3439, "Decimal"),
+  (3440, 3448, "Numeric"), (3558, 3567, "Decimal"), (3664, 3673, "Decimal"),
+  (3792, 3801, "Decimal"), (3872, 3881, "Decimal"), (3882, 3891, "Numeric"),
+  (4160, 4169, "Decimal"), (4240, 4249, "Decimal"), (4969, 4977, "Digit"),
+  (4978, 4988, "Numeric"), (5870, 5872, "Numeric"), (6112, 6121, "Decimal"),
+  (6128, 6137, "Numeric"), (6160, 6169, "Decimal"), (6470, 6479, "Decimal"),
+  (6608, 6617, "Decimal"), (6618, 6618, "Digit"), (6784, 6793, "Decimal"),
+  (6800, 6809, "Decimal"), (6992, 7001, "Decimal"), (7088, 7097, "Decimal"),
+  (7232, 7241, "Decimal"), (7248, 7257, "Decimal"), (8304, 8304, "Digit"),
+  (8308, 8313, "Digit"), (8320, 8329, "Digit"), (8528, 8578, "Numeric"),
+  (8581, 8585, "Numeric"), (9312, 9320, "Digit"), (9321, 9331, "Numeric"),
+  (9332, 9340, "Digit"), (9341, 9351, "Numeric"), (9352, 9360, "Digit"),
+  (9361, 9371, "Numeric"), (9450, 9450, "Digit"), (9451, 9460, "Numeric"),
+  (9461, 9469, "Digit"), (9470, 9470, "Numeric"), (9471, 9471, "Digit"),
+  (10102, 10110, "Digit"), (10111, 10111, "Numeric"), (10112, 10120, "Digit"),
+  (10121, 10121, "Numeric"), (10122, 10130, "Digit"),
+  (10131, 10131, "Numeric"), (11517, 11517, "Numeric"),
+  (12295, 12295, "Numeric"), (12321, 12329, "Numeric"),
+  (12344, 12346, "Numeric"), (12690, 12693, "Numeric"),
+  (12832, 12841, "Numeric"), (12872, 12879, "Numeric"),
+  (12881, 12895, "Numeric"), (12928, 12937, "Numeric"),
+  (12977, 12991, "Numeric"), (13317, 13317, "Numeric"),
+  (13443, 13443, "Numeric"), (14378, 14378, "Numeric"),
+  (15181, 15181, "Numeric"), (19968, 19968, "Numeric"),
+  (19971, 19971, "Numeric"), (19975, 19975, "Numeric"),
+  (19977, 19977, "Numeric"), (20061, 20061, "Numeric"),
+  (20108, 20108, "Numeric"), (20116, 20116, "Numeric"),
+  (20118, 20118, "Numeric"), (20159, 20160, "Numeric"),
+  (20191, 20191, "Numeric"), (20200, 20200, "Numeric"),
+  (20237, 20237, "Numeric"), (20336, 20336, "Numeric"),
+  (20740, 20740, "Numeric"), (20806, 20806, "Numeric"),
+  (20841, 20841, "Numeric"), (20843, 20843, "Numeric"),
+  (20845, 20845, "Numeric"), (21313, 21313, "Numeric"),
+  (21315, 21317, "Numeric"), (21324, 21324, "Numeric"),
+  (21441, 21444, "Numeric"), (22235, 22235, "Numeric"),
+  (22769, 22769, "Numeric"), (22777, 22777, "Numeric"),
+  (24186, 24186, "Numeric"), (24318, 24319, "Numeric"),
+  (24332, 24334, "Numeric"), (24336, 24336, "Numeric"),
+  (25342, 25342, "Numeric"), (25420, 25420, "Numeric"),
+  (26578, 26578, "Numeric"), (28422, 28422, "Numeric"),
+  (29590, 29590, "Numeric"), (30334, 30334, "Numeric"),
+  (32902, 32902, "Numeric"), (33836, 33836, "Numeric"),
+  (36014, 36014, "Numeric"), (36019, 36019, "Numeric"),
+  (36144, 36144, "Numeric"), (38433, 38433, "Numeric"),
+  (38470, 38470, "Numeric"), (38476, 38476, "Numeric"),
+  (38520, 38520, "Numeric"), (38646, 38646, "Numeric"),
+  (42528, 42537, "Decimal"), (42726, 42735, "Numeric"),
+  (43056, 43061, "Numeric"), (43216, 43225, "Decimal"),
+  (43264, 43273, "Decimal"), (43472, 43481, "Decimal"),
+  (43504, 43513, "Decimal"), (43600, 43609, "Decimal"),
+  (44016, 44025, "Decimal"), (63851, 63851, "Numeric"),
+  (63859, 63859, "Numeric"), (63864, 63864, "Numeric"),
+  (63922, 63922, "Numeric"), (63953, 63953, "Numeric"),
+  (63955, 63955, "Numeric"), (63997, 63997, "Numeric"),
+  (65296, 65305, "Decimal"), (65799, 65843, "Numeric"),
+  (65856, 65912, "Numeric"), (65930, 65931, "Numeric"),
+  (66273, 66299, "Numeric"), (66336, 66339, "Numeric"),
+  (66369, 66369, "Numeric"), (66378, 66378, "Numeric"),
+  (66513, 66517, "Numeric"), (66720, 66729, "Decimal"),
+  (67672, 67679, "Numeric"), (67705, 67711, "Numeric"),
+  (67751, 67759, "Numeric"), (67835, 67839, "Numeric"),
+  (67862, 67867, "Numeric"), (68028, 68029, "Numeric"),
+  (68032, 68047, "Numeric"), (68050, 68095, "Numeric"),
+  (68160, 68163, "Digit"), (68164, 68167, "Numeric"),
+  (68221, 68222, "Numeric"), (68253, 68255, "Numeric"),
+  (68331, 68335, "Numeric"), (68440, 68447, "Numeric"),
+  (68472, 68479, "Numeric"), (68521, 68527, "Numeric"),
+  (68858, 68863, "Numeric"), (69216, 69224, "Digit"),
+  (69225, 69246, "Numeric"), (69714, 69722, "Digit"),
+  (69723, 69733, "Numeric"), (69734, 69743, "Decimal"),
+  (69872, 69881, "Decimal"), (69942, 69951, "Decimal"),
+  (70096, 70105, "Decimal"), (70113, 70132, "Numeric"),
+  (70384, 70393, "Decimal"), (70736, 70745, "Decimal"),
+  (70864, 70873, "Decimal"), (71248, 71257, "Decimal"),
+  (71360, 71369, "Decimal"), (71472, 71481, "Decimal"),
+  (71482, 71483, "Numeric"), (71904, 71913, "Decimal"),
+  (71914, 71922, "Numeric"), (72784, 72793, "Decimal"),
+  (72794, 72812, "Numeric"), (73040, 73049, "Decimal"),
+  (74752, 74862, "Numeric"), (92768, 92777, "Decimal"),
+  (93008, 93017, "Decimal"), (93019, 93025, "Numeric"),
+  (119648, 119665, "Numeric"), (120782, 120831, "Decimal"),
+  (125127, 125135, "Numeric"), (125264, 125273, "Decimal"),
+  (127232, 127242, "Digit"), (127243, 127244, "Numeric"),
+  (131073, 131073, "Numeric"), (131172, 131172, "Numeric"),
+  (131298, 131298, "Numeric"), (131361, 131361, "Numeric"),
+  (133418, 133418, "Numeric"), (133507, 133507, "Numeric"),
+  (133516, 133516, "Numeric"), (133532, 133532, "Numeric"),
+  (133866, 133866, "Numeric"), (133885, 133885, "Numeric"),
+  (133913, 133913, "Numeric"), (140176, 140176, "Numeric"),
+  (141720, 141720, "Numeric"), (146203, 146203, "Numeric"),
+  (156269, 156269, "Numeric"), (194704, 194704, "Numeric"),
+];
@@ -0,0 +1,769 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//  ucd-generate script-extension /home/andrew/tmp/ucd-10.0.0/
+//
+// ucd-generate is available on crates.io.
+
+pub const BY_NAME: &'static [(&'static str, &'static [(u32, u32)])] = &[
+  ("Adlam", ADLAM), ("Ahom", AHOM),
+  ("Anatolian_Hieroglyphs", ANATOLIAN_HIEROGLYPHS), ("Arabic", ARABIC),
+  ("Armenian", ARMENIAN), ("Avestan", AVESTAN), ("Balinese", BALINESE),
+  ("Bamum", BAMUM), ("Bassa_Vah", BASSA_VAH), ("Batak", BATAK),
+  ("Bengali", BENGALI), ("Bhaiksuki", BHAIKSUKI), ("Bopomofo", BOPOMOFO),
+  ("Brahmi", BRAHMI), ("Braille", BRAILLE), ("Buginese", BUGINESE),
+  ("Buhid", BUHID), ("Canadian_Aboriginal", CANADIAN_ABORIGINAL),
+  ("Carian", CARIAN), ("Caucasian_Albanian", CAUCASIAN_ALBANIAN),
+  ("Chakma", CHAKMA), ("Cham", CHAM), ("Cherokee", CHEROKEE),
+  ("Common", COMMON), ("Coptic", COPTIC), ("Cuneiform", CUNEIFORM),
+  ("Cypriot", CYPRIOT), ("Cyrillic", CYRILLIC), ("Deseret", DESERET),
+  ("Devanagari", DEVANAGARI), ("Duployan", DUPLOYAN),
+  ("Egyptian_Hieroglyphs", EGYPTIAN_HIEROGLYPHS), ("Elbasan", ELBASAN),
+  ("Ethiopic", ETHIOPIC), ("Georgian", GEORGIAN), ("Glagolitic", GLAGOLITIC),
+  ("Gothic", GOTHIC), ("Grantha", GRANTHA), ("Greek", GREEK),
+  ("Gujarati", GUJARATI), ("Gurmukhi", GURMUKHI), ("Han", HAN),
+  ("Hangul", HANGUL), ("Hanunoo", HANUNOO), ("Hatran", HATRAN),
+  ("Hebrew", HEBREW), ("Hiragana", HIRAGANA),
+  ("Imperial_Aramaic", IMPERIAL_ARAMAIC), ("Inherited", INHERITED),
+  ("Inscriptional_Pahlavi", INSCRIPTIONAL_PAHLAVI),
+  ("Inscriptional_Parthian", INSCRIPTIONAL_PARTHIAN), ("Javanese", JAVANESE),
+  ("Kaithi", KAITHI), ("Kannada", KANNADA), ("Katakana", KATAKANA),
+  ("Kayah_Li", KAYAH_LI), ("Kharoshthi", KHAROSHTHI), ("Khmer", KHMER),
+  ("Khojki", KHOJKI), ("Khudawadi", KHUDAWADI), ("Lao", LAO), ("Latin", LATIN),
+  ("Lepcha", LEPCHA), ("Limbu", LIMBU), ("Linear_A", LINEAR_A),
+  ("Linear_B", LINEAR_B), ("Lisu", LISU), ("Lycian", LYCIAN),
+  ("Lydian", LYDIAN), ("Mahajani", MAHAJANI), ("Malayalam", MALAYALAM),
+  ("Mandaic", MANDAIC), ("Manichaean", MANICHAEAN), ("Marchen", MARCHEN),
+  ("Masaram_Gondi", MASARAM_GONDI), ("Meetei_Mayek", MEETEI_MAYEK),
+  ("Mende_Kikakui", MENDE_KIKAKUI), ("Meroitic_Cursive", MEROITIC_CURSIVE),
+  ("Meroitic_Hieroglyphs", MEROITIC_HIEROGLYPHS), ("Miao", MIAO),
+  ("Modi", MODI), ("Mongolian", MONGOLIAN), ("Mro", MRO), ("Multani", MULTANI),
+  ("Myanmar", MYANMAR), ("Nabataean", NABATAEAN), ("New_Tai_Lue", NEW_TAI_LUE),
+  ("Newa", NEWA), ("Nko", NKO), ("Nushu", NUSHU), ("Ogham", OGHAM),
+  ("Ol_Chiki", OL_CHIKI), ("Old_Hungarian", OLD_HUNGARIAN),
+  ("Old_Italic", OLD_ITALIC), ("Old_North_Arabian", OLD_NORTH_ARABIAN),
+  ("Old_Permic", OLD_PERMIC), ("Old_Persian", OLD_PERSIAN),
+  ("Old_South_Arabian", OLD_SOUTH_ARABIAN), ("Old_Turkic", OLD_TURKIC),
+  ("Oriya", ORIYA), ("Osage", OSAGE), ("Osmanya", OSMANYA),
+  ("Pahawh_Hmong", PAHAWH_HMONG), ("Palmyrene", PALMYRENE),
+  ("Pau_Cin_Hau", PAU_CIN_HAU), ("Phags_Pa", PHAGS_PA),
+  ("Phoenician", PHOENICIAN), ("Psalter_Pahlavi", PSALTER_PAHLAVI),
+  ("Rejang", REJANG), ("Runic", RUNIC), ("Samaritan", SAMARITAN),
+  ("Saurashtra", SAURASHTRA), ("Sharada", SHARADA), ("Shavian", SHAVIAN),
+  ("Siddham", SIDDHAM), ("SignWriting", SIGNWRITING), ("Sinhala", SINHALA),
+  ("Sora_Sompeng", SORA_SOMPENG), ("Soyombo", SOYOMBO),
+  ("Sundanese", SUNDANESE), ("Syloti_Nagri", SYLOTI_NAGRI), ("Syriac", SYRIAC),
+  ("Tagalog", TAGALOG), ("Tagbanwa", TAGBANWA), ("Tai_Le", TAI_LE),
+  ("Tai_Tham", TAI_THAM), ("Tai_Viet", TAI_VIET), ("Takri", TAKRI),
+  ("Tamil", TAMIL), ("Tangut", TANGUT), ("Telugu", TELUGU), ("Thaana", THAANA),
+  ("Thai", THAI), ("Tibetan", TIBETAN), ("Tifinagh", TIFINAGH),
+  ("Tirhuta", TIRHUTA), ("Ugaritic", UGARITIC), ("Vai", VAI),
+  ("Warang_Citi", WARANG_CITI), ("Yi", YI),
+  ("Zanabazar_Square", ZANABAZAR_SQUARE),
+];
+
+pub const ADLAM: &'static [(u32, u32)] = &[
+  (1600, 1600), (125184, 125258), (125264, 125273), (125278, 125279),
+];
+
+pub const AHOM: &'static [(u32, u32)] = &[
+  (71424, 71449), (71453, 71467), (71472, 71487),
+];
+
+pub const ANATOLIAN_HIEROGLYPHS: &'static [(u32, u32)] = &[
+  (82944, 83526),
+];
+
+pub const ARABIC: &'static [(u32, u32)] = &[
+  (1536, 1540), (1542, 1563), (1566, 1756), (1758, 1791), (1872, 1919),
+  (2208, 2228), (2230, 2237), (2260, 2273), (2275, 2303), (64336, 64449),
+  (64467, 64829), (64848, 64911), (64914, 64967), (65008, 65021),
+  (65136, 65140), (65142, 65276), (66272, 66299), (69216, 69246),
+  (126464, 126467), (126469, 126495), (126497, 126498), (126500, 126500),
+  (126503, 126503), (126505, 126514), (126516, 126519), (126521, 126521),
+  (126523, 126523), (126530, 126530), (126535, 126535), (126537, 126537),
+  (126539, 126539), (126541, 126543), (126545, 126546), (126548, 126548),
+  (126551, 126551), (126553, 126553), (126555, 126555), (126557, 126557),
+  (126559, 126559), (126561, 126562), (126564, 126564), (126567, 126570),
+  (126572, 126578), (126580, 126583), (126585, 126588), (126590, 126590),
+  (126592, 126601), (126603, 126619), (126625, 126627), (126629, 126633),
+  (126635, 126651), (126704, 126705),
+];
+
+pub const ARMENIAN: &'static [(u32, u32)] = &[
+  (1329, 1366), (1369, 1375), (1377, 1415), (1417, 1418), (1421, 1423),
+  (64275, 64279),
+];
+
+pub const AVESTAN: &'static [(u32, u32)] = &[
+  (68352, 68405), (68409, 68415),
+];
+
+pub const BALINESE: &'static [(u32, u32)] = &[
+  (6912, 6987), (6992, 7036),
+];
+
+pub const BAMUM: &'static [(u32, u32)] = &[
+  (42656, 42743), (92160, 92728),
+];
+
+pub const BASSA_VAH: &'static [(u32, u32)] = &[
+  (92880, 92909), (92912, 92917),
+];
+
+pub const BATAK: &'static [(u32, u32)] = &[
+  (7104, 7155), (7164, 7167),
+];
+
+pub const BENGALI: &'static [(u32, u32)] = &[
+  (2385, 2386), (2404, 2405), (2432, 2435), (2437, 2444), (2447, 2448),
+  (2451, 2472), (2474, 2480), (2482, 2482), (2486, 2489), (2492, 2500),
+  (2503, 2504), (2507, 2510), (2519, 2519), (2524, 2525), (2527, 2531),
+  (2534, 2557), (7415, 7415), (43249, 43249),
+];
+
+pub const BHAIKSUKI: &'static [(u32, u32)] = &[
+  (72704, 72712), (72714, 72758), (72760, 72773), (72784, 72812),
+];
+
+pub const BOPOMOFO: &'static [(u32, u32)] = &[
+  (746, 747), (12289, 12291), (12296, 12305), (12307, 12319), (12330, 12333),
+  (12336, 12336), (12343, 12343), (12539, 12539), (12549, 12590),
+  (12704, 12730), (65093, 65094), (65377, 65381),
+];
+
+pub const BRAHMI: &'static [(u32, u32)] = &[
+  (69632, 69709), (69714, 69743), (69759, 69759),
+];
+
+pub const BRAILLE: &'static [(u32, u32)] = &[
+  (10240, 10495),
+];
+
+pub const BUGINESE: &'static [(u32, u32)] = &[
+  (6656, 6683), (6686, 6687), (43471, 43471),
+];
+
+pub const BUHID: &'static [(u32, u32)] = &[
+  (5941, 5942), (5952, 5971),
+];
+
+pub const CANADIAN_ABORIGINAL: &'static [(u32, u32)] = &[
+  (5120, 5759), (6320, 6389),
+];
+
+pub const CARIAN: &'static [(u32, u32)] = &[
+  (66208, 66256),
+];
+
+pub const CAUCASIAN_ALBANIAN: &'static [(u32, u32)] = &[
+  (66864, 66915), (66927, 66927),
+];
+
+pub const CHAKMA: &'static [(u32, u32)] = &[
+  (2534, 2543), (4160, 4169), (69888, 69940), (69942, 69955),
+];
+
+pub const CHAM: &'static [(u32, u32)] = &[
+  (43520, 43574), (43584, 43597), (43600, 43609), (43612, 43615),
+];
+
+pub const CHEROKEE: &'static [(u32, u32)] = &[
+  (5024, 5109), (5112, 5117), (43888, 43967),
+];
+
+pub const COMMON: &'static [(u32, u32)] = &[
+  (0, 64), (91, 96), (123, 169), (171, 185), (187, 191), (215, 215),
+  (247, 247), (697, 735), (741, 745), (748, 767), (884, 884), (894, 894),
+  (901, 901), (903, 903), (1541, 1541), (1564, 1564), (1757, 1757),
+  (2274, 2274), (3647, 3647), (4053, 4056), (5867, 5869), (8192, 8203),
+  (8206, 8292), (8294, 8304), (8308, 8318), (8320, 8334), (8352, 8383),
+  (8448, 8485), (8487, 8489), (8492, 8497), (8499, 8525), (8527, 8543),
+  (8585, 8587), (8592, 9254), (9280, 9290), (9312, 10239), (10496, 11123),
+  (11126, 11157), (11160, 11193), (11197, 11208), (11210, 11218),
+  (11244, 11247), (11776, 11842), (11844, 11849), (12272, 12283),
+  (12288, 12288), (12292, 12292), (12306, 12306), (12320, 12320),
+  (12342, 12342), (12872, 12895), (12927, 12927), (12977, 12991),
+  (13004, 13007), (13169, 13178), (13184, 13279), (13311, 13311),
+  (19904, 19967), (42752, 42785), (42888, 42890), (43867, 43867),
+  (64830, 64831), (65040, 65049), (65072, 65092), (65095, 65106),
+  (65108, 65126), (65128, 65131), (65279, 65279), (65281, 65312),
+  (65339, 65344), (65371, 65376), (65504, 65510), (65512, 65518),
+  (65529, 65533), (65936, 65947), (66000, 66044), (118784, 119029),
+  (119040, 119078), (119081, 119142), (119146, 119162), (119171, 119172),
+  (119180, 119209), (119214, 119272), (119552, 119638), (119808, 119892),
+  (119894, 119964), (119966, 119967), (119970, 119970), (119973, 119974),
+  (119977, 119980), (119982, 119993), (119995, 119995), (119997, 120003),
+  (120005, 120069), (120071, 120074), (120077, 120084), (120086, 120092),
+  (120094, 120121), (120123, 120126), (120128, 120132), (120134, 120134),
+  (120138, 120144), (120146, 120485), (120488, 120779), (120782, 120831),
+  (126976, 127019), (127024, 127123), (127136, 127150), (127153, 127167),
+  (127169, 127183), (127185, 127221), (127232, 127244), (127248, 127278),
+  (127280, 127339), (127344, 127404), (127462, 127487), (127489, 127490),
+  (127504, 127547), (127552, 127560), (127584, 127589), (127744, 128724),
+  (128736, 128748), (128752, 128760), (128768, 128883), (128896, 128980),
+  (129024, 129035), (129040, 129095), (129104, 129113), (129120, 129159),
+  (129168, 129197), (129280, 129291), (129296, 129342), (129344, 129356),
+  (129360, 129387), (129408, 129431), (129472, 129472), (129488, 129510),
+  (917505, 917505), (917536, 917631),
+];
+
+pub const COPTIC: &'static [(u32, u32)] = &[
+  (994, 1007), (11392, 11507), (11513, 11519), (66272, 66299),
+];
+
+pub const CUNEIFORM: &'static [(u32, u32)] = &[
+  (73728, 74649), (74752, 74862), (74864, 74868), (74880, 75075),
+];
+
+pub const CYPRIOT: &'static [(u32, u32)] = &[
+  (65792, 65794), (65799, 65843), (65847, 65855), (67584, 67589),
+  (67592, 67592), (67594, 67637), (67639, 67640), (67644, 67644),
+  (67647, 67647),
+];
+
+pub const CYRILLIC: &'static [(u32, u32)] = &[
+  (1024, 1327), (7296, 7304), (7467, 7467), (7544, 7544), (7672, 7672),
+  (11744, 11775), (11843, 11843), (42560, 42655), (65070, 65071),
+];
+
+pub const DESERET: &'static [(u32, u32)] = &[
+  (66560, 66639),
+];
+
+pub const DEVANAGARI: &'static [(u32, u32)] = &[
+  (2304, 2431), (7376, 7414), (7416, 7417), (8432, 8432), (43056, 43065),
+  (43232, 43261),
+];
+
+pub const DUPLOYAN: &'static [(u32, u32)] = &[
+  (113664, 113770), (113776, 113788), (113792, 113800), (113808, 113817),
+  (113820, 113827),
+];
+
+pub const EGYPTIAN_HIEROGLYPHS: &'static [(u32, u32)] = &[
+  (77824, 78894),
+];
+
+pub const ELBASAN: &'static [(u32, u32)] = &[
+  (66816, 66855),
+];
+
+pub const ETHIOPIC: &'static [(u32, u32)] = &[
+  (4608, 4680), (4682, 4685), (4688, 4694), (4696, 4696), (4698, 4701),
+  (4704, 4744), (4746, 4749), (4752, 4784), (4786, 4789), (4792, 4798),
+  (4800, 4800), (4802, 4805), (4808, 4822), (4824, 4880), (4882, 4885),
+  (4888, 4954), (4957, 4988), (4992, 5017), (11648, 11670), (11680, 11686),
+  (11688, 11694), (11696, 11702), (11704, 11710), (11712, 11718),
+  (11720, 11726), (11728, 11734), (11736, 11742), (43777, 43782),
+  (43785, 43790), (43793, 43798), (43808, 43814), (43816, 43822),
+];
+
+pub const GEORGIAN: &'static [(u32, u32)] = &[
+  (1417, 1417), (4256, 4293), (4295, 4295), (4301, 4301), (4304, 4351),
+  (11520, 11557), (11559, 11559), (11565, 11565),
+];
+
+pub const GLAGOLITIC: &'static [(u32, u32)] = &[
+  (1156, 1156), (1159, 1159), (11264, 11310), (11312, 11358), (11843, 11843),
+  (42607, 42607), (122880, 122886), (122888, 122904), (122907, 122913),
+  (122915, 122916), (122918, 122922),
+];
+
+pub const GOTHIC: &'static [(u32, u32)] = &[
+  (66352, 66378),
+];
+
+pub const GRANTHA: &'static [(u32, u32)] = &[
+  (2385, 2386), (2404, 2405), (2986, 2986), (2997, 2997), (3046, 3058),
+  (7376, 7376), (7378, 7379), (7410, 7412), (7416, 7417), (8432, 8432),
+  (70400, 70403), (70405, 70412), (70415, 70416), (70419, 70440),
+  (70442, 70448), (70450, 70451), (70453, 70457), (70460, 70468),
+  (70471, 70472), (70475, 70477), (70480, 70480), (70487, 70487),
+  (70493, 70499), (70502, 70508), (70512, 70516),
+];
+
+pub const GREEK: &'static [(u32, u32)] = &[
+  (834, 834), (837, 837), (880, 883), (885, 887), (890, 893), (895, 895),
+  (900, 900), (902, 902), (904, 906), (908, 908), (910, 929), (931, 993),
+  (1008, 1023), (7462, 7466), (7517, 7521), (7526, 7530), (7615, 7617),
+  (7936, 7957), (7960, 7965), (7968, 8005), (8008, 8013), (8016, 8023),
+  (8025, 8025), (8027, 8027), (8029, 8029), (8031, 8061), (8064, 8116),
+  (8118, 8132), (8134, 8147), (8150, 8155), (8157, 8175), (8178, 8180),
+  (8182, 8190), (8486, 8486), (43877, 43877), (65856, 65934), (65952, 65952),
+  (119296, 119365),
+];
+
+pub const GUJARATI: &'static [(u32, u32)] = &[
+  (2385, 2386), (2404, 2405), (2689, 2691), (2693, 2701), (2703, 2705),
+  (2707, 2728), (2730, 2736), (2738, 2739), (2741, 2745), (2748, 2757),
+  (2759, 2761), (2763, 2765), (2768, 2768), (2784, 2787), (2790, 2801),
+  (2809, 2815), (43056, 43065),
+];
+
+pub const GURMUKHI: &'static [(u32, u32)] = &[
+  (2385, 2386), (2404, 2405), (2561, 2563), (2565, 2570), (2575, 2576),
+  (2579, 2600), (2602, 2608), (2610, 2611), (2613, 2614), (2616, 2617),
+  (2620, 2620), (2622, 2626), (2631, 2632), (2635, 2637), (2641, 2641),
+  (2649, 2652), (2654, 2654), (2662, 2677), (43056, 43065),
+];
+
+pub const HAN: &'static [(u32, u32)] = &[
+  (11904, 11929), (11931, 12019), (12032, 12245), (12289, 12291),
+  (12293, 12305), (12307, 12319), (12321, 12333), (12336, 12336),
+  (12343, 12351), (12539, 12539), (12688, 12703), (12736, 12771),
+  (12832, 12871), (12928, 12976), (12992, 13003), (13144, 13168),
+  (13179, 13183), (13280, 13310), (13312, 19893), (19968, 40938),
+  (63744, 64109), (64112, 64217), (65093, 65094), (65377, 65381),
+  (119648, 119665), (127568, 127569), (131072, 173782), (173824, 177972),
+  (177984, 178205), (178208, 183969), (183984, 191456), (194560, 195101),
+];
+
+pub const HANGUL: &'static [(u32, u32)] = &[
+  (4352, 4607), (12289, 12291), (12296, 12305), (12307, 12319), (12334, 12336),
+  (12343, 12343), (12539, 12539), (12593, 12686), (12800, 12830),
+  (12896, 12926), (43360, 43388), (44032, 55203), (55216, 55238),
+  (55243, 55291), (65093, 65094), (65377, 65381), (65440, 65470),
+  (65474, 65479), (65482, 65487), (65490, 65495), (65498, 65500),
+];
+
+pub const HANUNOO: &'static [(u32, u32)] = &[
+  (5920, 5942),
+];
+
+pub const HATRAN: &'static [(u32, u32)] = &[
+  (67808, 67826), (67828, 67829), (67835, 67839),
+];
+
+pub const HEBREW: &'static [(u32, u32)] = &[
+  (1425, 1479), (1488, 1514), (1520, 1524), (64285, 64310), (64312, 64316),
+  (64318, 64318), (64320, 64321), (64323, 64324), (64326, 64335),
+];
+
+pub const HIRAGANA: &'static [(u32, u32)] = &[
+  (12289, 12291), (12296, 12305), (12307, 12319), (12336, 12341),
+  (12343, 12343), (12348, 12349), (12353, 12438), (12441, 12448),
+  (12539, 12540), (65093, 65094), (65377, 65381), (65392, 65392),
+  (65438, 65439), (110593, 110878), (127488, 127488),
+];
+
+pub const IMPERIAL_ARAMAIC: &'static [(u32, u32)] = &[
+  (67648, 67669), (67671, 67679),
+];
+
+pub const INHERITED: &'static [(u32, u32)] = &[
+  (768, 833), (835, 836), (838, 866), (6832, 6846), (7618, 7671), (7673, 7673),
+  (7675, 7679), (8204, 8205), (8400, 8431), (65024, 65039), (65056, 65069),
+  (66045, 66045), (119143, 119145), (119163, 119170), (119173, 119179),
+  (119210, 119213), (917760, 917999),
+];
+
+pub const INSCRIPTIONAL_PAHLAVI: &'static [(u32, u32)] = &[
+  (68448, 68466), (68472, 68479),
+];
+
+pub const INSCRIPTIONAL_PARTHIAN: &'static [(u32, u32)] = &[
+  (68416, 68437), (68440, 68447),
+];
+
+pub const JAVANESE: &'static [(u32, u32)] = &[
+  (43392, 43469), (43471, 43481), (43486, 43487),
+];
+
+pub const KAITHI: &'static [(u32, u32)] = &[
+  (2406, 2415), (43056, 43065), (69760, 69825),
+];
+
+pub const KANNADA: &'static [(u32, u32)] = &[
+  (2385, 2386), (2404, 2405), (3200, 3203), (3205, 3212), (3214, 3216),
+  (3218, 3240), (3242, 3251), (3253, 3257), (3260, 3268), (3270, 3272),
+  (3274, 3277), (3285, 3286), (3294, 3294), (3296, 3299), (3302, 3311),
+  (3313, 3314), (7386, 7386), (7413, 7413), (43056, 43061),
+];
+
+pub const KATAKANA: &'static [(u32, u32)] = &[
+  (12289, 12291), (12296, 12305), (12307, 12319), (12336, 12341),
+  (12343, 12343), (12348, 12349), (12441, 12444), (12448, 12543),
+  (12784, 12799), (13008, 13054), (13056, 13143), (65093, 65094),
+  (65377, 65439), (110592, 110592),
+];
+
+pub const KAYAH_LI: &'static [(u32, u32)] = &[
+  (43264, 43311),
+];
+
+pub const KHAROSHTHI: &'static [(u32, u32)] = &[
+  (68096, 68099), (68101, 68102), (68108, 68115), (68117, 68119),
+  (68121, 68147), (68152, 68154), (68159, 68167), (68176, 68184),
+];
+
+pub const KHMER: &'static [(u32, u32)] = &[
+  (6016, 6109), (6112, 6121), (6128, 6137), (6624, 6655),
+];
+
+pub const KHOJKI: &'static [(u32, u32)] = &[
+  (2790, 2799), (70144, 70161), (70163, 70206),
+];
+
+pub const KHUDAWADI: &'static [(u32, u32)] = &[
+  (2404, 2405), (43056, 43065), (70320, 70378), (70384, 70393),
+];
+
+pub const LAO: &'static [(u32, u32)] = &[
+  (3713, 3714), (3716, 3716), (3719, 3720), (3722, 3722), (3725, 3725),
+  (3732, 3735), (3737, 3743), (3745, 3747), (3749, 3749), (3751, 3751),
+  (3754, 3755), (3757, 3769), (3771, 3773), (3776, 3780), (3782, 3782),
+  (3784, 3789), (3792, 3801), (3804, 3807),
+];
+
+pub const LATIN: &'static [(u32, u32)] = &[
+  (65, 90), (97, 122), (170, 170), (186, 186), (192, 214), (216, 246),
+  (248, 696), (736, 740), (867, 879), (1157, 1158), (2385, 2386), (4347, 4347),
+  (7424, 7461), (7468, 7516), (7522, 7525), (7531, 7543), (7545, 7614),
+  (7680, 7935), (8305, 8305), (8319, 8319), (8336, 8348), (8432, 8432),
+  (8490, 8491), (8498, 8498), (8526, 8526), (8544, 8584), (11360, 11391),
+  (42786, 42887), (42891, 42926), (42928, 42935), (42999, 43007),
+  (43310, 43310), (43824, 43866), (43868, 43876), (64256, 64262),
+  (65313, 65338), (65345, 65370),
+];
+
+pub const LEPCHA: &'static [(u32, u32)] = &[
+  (7168, 7223), (7227, 7241), (7245, 7247),
+];
+
+pub const LIMBU: &'static [(u32, u32)] = &[
+  (2405, 2405), (6400, 6430), (6432, 6443), (6448, 6459), (6464, 6464),
+  (6468, 6479),
+];
+
+pub const LINEAR_A: &'static [(u32, u32)] = &[
+  (65799, 65843), (67072, 67382), (67392, 67413), (67424, 67431),
+];
+
+pub const LINEAR_B: &'static [(u32, u32)] = &[
+  (65536, 65547), (65549, 65574), (65576, 65594), (65596, 65597),
+  (65599, 65613), (65616, 65629), (65664, 65786), (65792, 65794),
+  (65799, 65843), (65847, 65855),
+];
+
+pub const LISU: &'static [(u32, u32)] = &[
+  (42192, 42239),
+];
+
+pub const LYCIAN: &'static [(u32, u32)] = &[
+  (66176, 66204),
+];
+
+pub const LYDIAN: &'static [(u32, u32)] = &[
+  (67872, 67897), (67903, 67903),
+];
+
+pub const MAHAJANI: &'static [(u32, u32)] = &[
+  (2404, 2415), (43056, 43065), (69968, 70006),
+];
+
+pub const MALAYALAM: &'static [(u32, u32)] = &[
+  (2385, 2386), (2404, 2405), (3328, 3331), (3333, 3340), (3342, 3344),
+  (3346, 3396), (3398, 3400), (3402, 3407), (3412, 3427), (3430, 3455),
+  (7386, 7386),
+];
+
+pub const MANDAIC: &'static [(u32, u32)] = &[
+  (1600, 1600), (2112, 2139), (2142, 2142),
+];
+
+pub const MANICHAEAN: &'static [(u32, u32)] = &[
+  (1600, 1600), (68288, 68326), (68331, 68342),
+];
+
+pub const MARCHEN: &'static [(u32, u32)] = &[
+  (72816, 72847), (72850, 72871), (72873, 72886),
+];
+
+pub const MASARAM_GONDI: &'static [(u32, u32)] = &[
+  (72960, 72966), (72968, 72969), (72971, 73014), (73018, 73018),
+  (73020, 73021), (73023, 73031), (73040, 73049),
+];
+
+pub const MEETEI_MAYEK: &'static [(u32, u32)] = &[
+  (43744, 43766), (43968, 44013), (44016, 44025),
+];
+
+pub const MENDE_KIKAKUI: &'static [(u32, u32)] = &[
+  (124928, 125124), (125127, 125142),
+];
+
+pub const MEROITIC_CURSIVE: &'static [(u32, u32)] = &[
+  (68000, 68023), (68028, 68047), (68050, 68095),
+];
+
+pub const MEROITIC_HIEROGLYPHS: &'static [(u32, u32)] = &[
+  (67968, 67999),
+];
+
+pub const MIAO: &'static [(u32, u32)] = &[
+  (93952, 94020), (94032, 94078), (94095, 94111),
+];
+
+pub const MODI: &'static [(u32, u32)] = &[
+  (43056, 43065), (71168, 71236), (71248, 71257),
+];
+
+pub const MONGOLIAN: &'static [(u32, u32)] = &[
+  (6144, 6158), (6160, 6169), (6176, 6263), (6272, 6314), (71264, 71276),
+];
+
+pub const MRO: &'static [(u32, u32)] = &[
+  (92736, 92766), (92768, 92777), (92782, 92783),
+];
+
+pub const MULTANI: &'static [(u32, u32)] = &[
+  (2662, 2671), (70272, 70278), (70280, 70280), (70282, 70285), (70287, 70301),
+  (70303, 70313),
+];
+
+pub const MYANMAR: &'static [(u32, u32)] = &[
+  (4096, 4255), (43310, 43310), (43488, 43518), (43616, 43647),
+];
+
+pub const NABATAEAN: &'static [(u32, u32)] = &[
+  (67712, 67742), (67751, 67759),
+];
+
+pub const NEW_TAI_LUE: &'static [(u32, u32)] = &[
+  (6528, 6571), (6576, 6601), (6608, 6618), (6622, 6623),
+];
+
+pub const NEWA: &'static [(u32, u32)] = &[
+  (70656, 70745), (70747, 70747), (70749, 70749),
+];
+
+pub const NKO: &'static [(u32, u32)] = &[
+  (1984, 2042),
+];
+
+pub const NUSHU: &'static [(u32, u32)] = &[
+  (94177, 94177), (110960, 111355),
+];
+
+pub const OGHAM: &'static [(u32, u32)] = &[
+  (5760, 5788),
+];
+
+pub const OL_CHIKI: &'static [(u32, u32)] = &[
+  (7248, 7295),
+];
+
+pub const OLD_HUNGARIAN: &'static [(u32, u32)] = &[
+  (68736, 68786), (68800, 68850), (68858, 68863),
+];
+
+pub const OLD_ITALIC: &'static [(u32, u32)] = &[
+  (66304, 66339), (66349, 66351),
+];
+
+pub const OLD_NORTH_ARABIAN: &'static [(u32, u32)] = &[
+  (68224, 68255),
+];
+
+pub const OLD_PERMIC: &'static [(u32, u32)] = &[
+  (1155, 1155), (66384, 66426),
+];
+
+pub const OLD_PERSIAN: &'static [(u32, u32)] = &[
+  (66464, 66499), (66504, 66517),
+];
+
+pub const OLD_SOUTH_ARABIAN: &'static [(u32, u32)] = &[
+  (68192, 68223),
+];
+
+pub const OLD_TURKIC: &'static [(u32, u32)] = &[
+  (68608, 68680),
+];
+
+pub const ORIYA: &'static [(u32, u32)] = &[
+  (2385, 2386), (2404, 2405), (2817, 2819), (2821, 2828), (2831, 2832),
+  (2835, 2856), (2858, 2864), (2866, 2867), (2869, 2873), (2876, 2884),
+  (2887, 2888), (2891, 2893), (2902, 2903), (2908, 2909), (2911, 2915),
+  (2918, 2935),
+];
+
+pub const OSAGE: &'static [(u32, u32)] = &[
+  (66736, 66771), (66776, 66811),
+];
+
+pub const OSMANYA: &'static [(u32, u32)] = &[
+  (66688, 66717), (66720, 66729),
+];
+
+pub const PAHAWH_HMONG: &'static [(u32, u32)] = &[
+  (92928, 92997), (93008, 93017), (93019, 93025), (93027, 93047),
+  (93053, 93071),
+];
+
+pub const PALMYRENE: &'static [(u32, u32)] = &[
+  (67680, 67711),
+];
+
+pub const PAU_CIN_HAU: &'static [(u32, u32)] = &[
+  (72384, 72440),
+];
+
+pub const PHAGS_PA: &'static [(u32, u32)] = &[
+  (6146, 6147), (6149, 6149), (43072, 43127),
+];
+
+pub const PHOENICIAN: &'static [(u32, u32)] = &[
+  (67840, 67867), (67871, 67871),
+];
+
+pub const PSALTER_PAHLAVI: &'static [(u32, u32)] = &[
+  (1600, 1600), (68480, 68497), (68505, 68508), (68521, 68527),
+];
+
+pub const REJANG: &'static [(u32, u32)] = &[
+  (43312, 43347), (43359, 43359),
+];
+
+pub const RUNIC: &'static [(u32, u32)] = &[
+  (5792, 5866), (5870, 5880),
+];
+
+pub const SAMARITAN: &'static [(u32, u32)] = &[
+  (2048, 2093), (2096, 2110),
+];
+
+pub const SAURASHTRA: &'static [(u32, u32)] = &[
+  (43136, 43205), (43214, 43225),
+];
+
+pub const SHARADA: &'static [(u32, u32)] = &[
+  (2385, 2385), (7383, 7383), (7385, 7385), (7388, 7389), (7392, 7392),
+  (70016, 70093), (70096, 70111),
+];
+
+pub const SHAVIAN: &'static [(u32, u32)] = &[
+  (66640, 66687),
+];
+
+pub const SIDDHAM: &'static [(u32, u32)] = &[
+  (71040, 71093), (71096, 71133),
+];
+
+pub const SIGNWRITING: &'static [(u32, u32)] = &[
+  (120832, 121483), (121499, 121503), (121505, 121519),
+];
+
+pub const SINHALA: &'static [(u32, u32)] = &[
+  (2404, 2405), (3458, 3459), (3461, 3478), (3482, 3505), (3507, 3515),
+  (3517, 3517), (3520, 3526), (3530, 3530), (3535, 3540), (3542, 3542),
+  (3544, 3551), (3558, 3567), (3570, 3572), (70113, 70132),
+];
+
+pub const SORA_SOMPENG: &'static [(u32, u32)] = &[
+  (69840, 69864), (69872, 69881),
+];
+
+pub const SOYOMBO: &'static [(u32, u32)] = &[
+  (72272, 72323), (72326, 72348), (72350, 72354),
+];
+
+pub const SUNDANESE: &'static [(u32, u32)] = &[
+  (7040, 7103), (7360, 7367),
+];
+
+pub const SYLOTI_NAGRI: &'static [(u32, u32)] = &[
+  (2404, 2405), (2534, 2543), (43008, 43051),
+];
+
+pub const SYRIAC: &'static [(u32, u32)] = &[
+  (1548, 1548), (1563, 1563), (1567, 1567), (1600, 1600), (1611, 1621),
+  (1648, 1648), (1792, 1805), (1807, 1866), (1869, 1871), (2144, 2154),
+  (7672, 7672),
+];
+
+pub const TAGALOG: &'static [(u32, u32)] = &[
+  (5888, 5900), (5902, 5908), (5941, 5942),
+];
+
+pub const TAGBANWA: &'static [(u32, u32)] = &[
+  (5941, 5942), (5984, 5996), (5998, 6000), (6002, 6003),
+];
+
+pub const TAI_LE: &'static [(u32, u32)] = &[
+  (4160, 4169), (6480, 6509), (6512, 6516),
+];
+
+pub const TAI_THAM: &'static [(u32, u32)] = &[
+  (6688, 6750), (6752, 6780), (6783, 6793), (6800, 6809), (6816, 6829),
+];
+
+pub const TAI_VIET: &'static [(u32, u32)] = &[
+  (43648, 43714), (43739, 43743),
+];
+
+pub const TAKRI: &'static [(u32, u32)] = &[
+  (2404, 2405), (43056, 43065), (71296, 71351), (71360, 71369),
+];
+
+pub const TAMIL: &'static [(u32, u32)] = &[
+  (2385, 2386), (2404, 2405), (2946, 2947), (2949, 2954), (2958, 2960),
+  (2962, 2965), (2969, 2970), (2972, 2972), (2974, 2975), (2979, 2980),
+  (2984, 2986), (2990, 3001), (3006, 3010), (3014, 3016), (3018, 3021),
+  (3024, 3024), (3031, 3031), (3046, 3066), (7386, 7386), (43251, 43251),
+  (70403, 70403), (70460, 70460),
+];
+
+pub const TANGUT: &'static [(u32, u32)] = &[
+  (94176, 94176), (94208, 100332), (100352, 101106),
+];
+
+pub const TELUGU: &'static [(u32, u32)] = &[
+  (2385, 2386), (2404, 2405), (3072, 3075), (3077, 3084), (3086, 3088),
+  (3090, 3112), (3114, 3129), (3133, 3140), (3142, 3144), (3146, 3149),
+  (3157, 3158), (3160, 3162), (3168, 3171), (3174, 3183), (3192, 3199),
+  (7386, 7386),
+];
+
+pub const THAANA: &'static [(u32, u32)] = &[
+  (1548, 1548), (1563, 1563), (1567, 1567), (1632, 1641), (1920, 1969),
+  (65010, 65010), (65021, 65021),
+];
+
+pub const THAI: &'static [(u32, u32)] = &[
+  (3585, 3642), (3648, 3675),
+];
+
+pub const TIBETAN: &'static [(u32, u32)] = &[
+  (3840, 3911), (3913, 3948), (3953, 3991), (3993, 4028), (4030, 4044),
+  (4046, 4052), (4057, 4058),
+];
+
+pub const TIFINAGH: &'static [(u32, u32)] = &[
+  (11568, 11623), (11631, 11632), (11647, 11647),
+];
+
+pub const TIRHUTA: &'static [(u32, u32)] = &[
+  (2404, 2405), (43056, 43065), (70784, 70855), (70864, 70873),
+];
+
+pub const UGARITIC: &'static [(u32, u32)] = &[
+  (66432, 66461), (66463, 66463),
+];
+
+pub const VAI: &'static [(u32, u32)] = &[
+  (42240, 42539),
+];
+
+pub const WARANG_CITI: &'static [(u32, u32)] = &[
+  (71840, 71922), (71935, 71935),
+];
+
+pub const YI: &'static [(u32, u32)] = &[
+  (12289, 12290), (12296, 12305), (12308, 12315), (12539, 12539),
+  (40960, 42124), (42128, 42182), (65377, 65381),
+];
+
+pub const ZANABAZAR_SQUARE: &'static [(u32, u32)] = &[
+  (72192, 72263),
+];
@@ -0,0 +1,1053 @@
+// This file was NOT generated. It was assembled by hand from the Unicode
+// 10.0.0 StandardizedVariants.txt, in the format of ucd-generate, and is
+// missing the Mongolian and Manichaean sequences. The CJK compatibility
+// ideograph, math symbol, Myanmar and Phags-pa sequences are included.
+//
+// scripts/generate-ucd-tables replaces it with the complete output of:
+//
+//  ucd-generate standardized-variants /home/andrew/tmp/ucd-10.0.0/
+
+pub const STANDARDIZED_VARIANTS: &'static [(u32, u32, &'static str)] = &[
+  (4096, 65024, "dotted form"), (4098, 65024, "dotted form"),
+  (4100, 65024, "dotted form"), (4112, 65024, "dotted form"),
+  (4113, 65024, "dotted form"), (4117, 65024, "dotted form"),
+  (4121, 65024, "dotted form"), (4122, 65024, "dotted form"),
+  (4124, 65024, "dotted form"), (4125, 65024, "dotted form"),
+  (4130, 65024, "dotted form"), (4145, 65024, "dotted form"),
+  (4161, 65024, "dotted form"), (4171, 65024, "dotted form"),
+  (4206, 65024, "dotted form"), (4207, 65024, "dotted form"),
+  (4208, 65024, "dotted form"), (4210, 65024, "dotted form"),
+  (4213, 65024, "dotted form"), (4216, 65024, "dotted form"),
+  (4218, 65024, "dotted form"), (4224, 65024, "dotted form"),
+  (8745, 65024, "with serifs"), (8746, 65024, "with serifs"),
+  (8808, 65024, "with vertical stroke"), (8809, 65024, "with vertical stroke"),
+  (8818, 65024, "following the slant of the lower leg"),
+  (8819, 65024, "following the slant of the lower leg"),
+  (8842, 65024, "with stroke through bottom members"),
+  (8843, 65024, "with stroke through bottom members"),
+  (8851, 65024, "with serifs"), (8852, 65024, "with serifs"),
+  (8853, 65024, "with white rim"), (8855, 65024, "with white rim"),
+  (8860, 65024, "with equal sign touching the circle"),
+  (8922, 65024, "with slanted equal"), (8923, 65024, "with slanted equal"),
+  (10812, 65024, "tall variant with narrow foot"),
+  (10813, 65024, "tall variant with narrow foot"),
+  (10909, 65024, "with similar following the slant of the upper leg"),
+  (10910, 65024, "with similar following the slant of the upper leg"),
+  (10924, 65024, "with slanted equal"), (10925, 65024, "with slanted equal"),
+  (10955, 65024, "with stroke through bottom members"),
+  (10956, 65024, "with stroke through bottom members"),
+  (13470, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F80C"),
+  (13497, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F813"),
+  (13499, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9CA"),
+  (13535, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F81F"),
+  (13589, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F824"),
+  (14062, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F867"),
+  (14076, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F868"),
+  (14209, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F876"),
+  (14383, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F883"),
+  (14434, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F888"),
+  (14460, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F88A"),
+  (14535, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F896"),
+  (14563, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F89B"),
+  (14620, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8A2"),
+  (14650, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8A1"),
+  (14894, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8C2"),
+  (14956, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8C7"),
+  (15076, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8D1"),
+  (15112, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8D0"),
+  (15129, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8CE"),
+  (15177, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8DE"),
+  (15261, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAD2"),
+  (15261, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F8E7"),
+  (15384, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8EE"),
+  (15438, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8F2"),
+  (15667, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F90A"),
+  (15766, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F916"),
+  (16044, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F92A"),
+  (16056, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F92C"),
+  (16056, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F92D"),
+  (16155, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F933"),
+  (16380, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F93E"),
+  (16392, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F93F"),
+  (16408, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAD3"),
+  (16441, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAD4"),
+  (16441, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F949"),
+  (16454, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F94B"),
+  (16534, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F94C"),
+  (16611, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F951"),
+  (16687, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F958"),
+  (16898, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F960"),
+  (16935, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F964"),
+  (17056, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F967"),
+  (17153, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F96D"),
+  (17204, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F971"),
+  (17241, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F974"),
+  (17365, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F981"),
+  (17369, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8D7"),
+  (17419, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F984"),
+  (17515, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F98E"),
+  (17707, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9A7"),
+  (17757, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9AE"),
+  (17761, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9AF"),
+  (17771, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9B2"),
+  (17879, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9BF"),
+  (17913, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9C2"),
+  (17973, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9C8"),
+  (18110, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9CD"),
+  (18119, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9CE"),
+  (18837, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9EF"),
+  (18918, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9F2"),
+  (19054, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9F8"),
+  (19062, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9F9"),
+  (19122, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9FC"),
+  (19251, 65024, "CJK COMPATIBILITY IDEOGRAPH-2FA03"),
+  (19406, 65024, "CJK COMPATIBILITY IDEOGRAPH-2FA08"),
+  (19662, 65024, "CJK COMPATIBILITY IDEOGRAPH-2FA0D"),
+  (19693, 65024, "CJK COMPATIBILITY IDEOGRAPH-2FA0E"),
+  (19704, 65024, "CJK COMPATIBILITY IDEOGRAPH-2FA11"),
+  (19798, 65024, "CJK COMPATIBILITY IDEOGRAPH-2FA16"),
+  (19981, 65024, "CJK COMPATIBILITY IDEOGRAPH-F967"),
+  (20006, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA70"),
+  (20018, 65024, "CJK COMPATIBILITY IDEOGRAPH-F905"),
+  (20024, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F801"),
+  (20025, 65024, "CJK COMPATIBILITY IDEOGRAPH-F95E"),
+  (20029, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F800"),
+  (20033, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F802"),
+  (20098, 65024, "CJK COMPATIBILITY IDEOGRAPH-F91B"),
+  (20102, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9BA"),
+  (20142, 65024, "CJK COMPATIBILITY IDEOGRAPH-F977"),
+  (20160, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9FD"),
+  (20172, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F819"),
+  (20196, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9A8"),
+  (20320, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F804"),
+  (20352, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA73"),
+  (20358, 65024, "CJK COMPATIBILITY IDEOGRAPH-F92D"),
+  (20363, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9B5"),
+  (20398, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA30"),
+  (20398, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F805"),
+  (20411, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F806"),
+  (20415, 65024, "CJK COMPATIBILITY IDEOGRAPH-F965"),
+  (20482, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F807"),
+  (20523, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9D4"),
+  (20602, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F808"),
+  (20633, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F809"),
+  (20687, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F80B"),
+  (20698, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9BB"),
+  (20711, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA31"),
+  (20711, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F80A"),
+  (20800, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA0C"),
+  (20805, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA74"),
+  (20813, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA32"),
+  (20813, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F80E"),
+  (20820, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F80F"),
+  (20836, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F810"),
+  (20839, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F814"),
+  (20840, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA72"),
+  (20841, 65024, "CJK COMPATIBILITY IDEOGRAPH-F978"),
+  (20845, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9D1"),
+  (20855, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F811"),
+  (20864, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA75"),
+  (20877, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F815"),
+  (20882, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8D2"),
+  (20885, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8D3"),
+  (20887, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F817"),
+  (20900, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F818"),
+  (20908, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F81A"),
+  (20917, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA71"),
+  (20917, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F81B"),
+  (20919, 65024, "CJK COMPATIBILITY IDEOGRAPH-F92E"),
+  (20937, 65024, "CJK COMPATIBILITY IDEOGRAPH-F979"),
+  (20940, 65024, "CJK COMPATIBILITY IDEOGRAPH-F955"),
+  (20956, 65024, "CJK COMPATIBILITY IDEOGRAPH-F954"),
+  (20958, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA15"),
+  (20981, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F81D"),
+  (20995, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F81E"),
+  (20999, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA00"),
+  (20999, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F850"),
+  (21015, 65024, "CJK COMPATIBILITY IDEOGRAPH-F99C"),
+  (21033, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9DD"),
+  (21050, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9FF"),
+  (21051, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F820"),
+  (21062, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F821"),
+  (21106, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F822"),
+  (21111, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F823"),
+  (21129, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9C7"),
+  (21147, 65024, "CJK COMPATIBILITY IDEOGRAPH-F98A"),
+  (21155, 65024, "CJK COMPATIBILITY IDEOGRAPH-F99D"),
+  (21171, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F992"),
+  (21191, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA76"),
+  (21191, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F825"),
+  (21193, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA33"),
+  (21193, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F826"),
+  (21202, 65024, "CJK COMPATIBILITY IDEOGRAPH-F952"),
+  (21214, 65024, "CJK COMPATIBILITY IDEOGRAPH-F92F"),
+  (21220, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA34"),
+  (21220, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F827"),
+  (21237, 65024, "CJK COMPATIBILITY IDEOGRAPH-F97F"),
+  (21242, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA77"),
+  (21242, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F828"),
+  (21253, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F829"),
+  (21254, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F82A"),
+  (21271, 65024, "CJK COMPATIBILITY IDEOGRAPH-F963"),
+  (21271, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F82B"),
+  (21311, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9EB"),
+  (21321, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F82C"),
+  (21329, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA35"),
+  (21329, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F82D"),
+  (21338, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F82E"),
+  (21363, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F82F"),
+  (21365, 65024, "CJK COMPATIBILITY IDEOGRAPH-F91C"),
+  (21373, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F830"),
+  (21375, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F831"),
+  (21375, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F832"),
+  (21375, 65026, "CJK COMPATIBILITY IDEOGRAPH-2F833"),
+  (21443, 65024, "CJK COMPATIBILITY IDEOGRAPH-F96B"),
+  (21450, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F836"),
+  (21471, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F837"),
+  (21477, 65024, "CJK COMPATIBILITY IDEOGRAPH-F906"),
+  (21483, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F839"),
+  (21489, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F83A"),
+  (21510, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F83B"),
+  (21519, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9DE"),
+  (21533, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9ED"),
+  (21560, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F83D"),
+  (21570, 65024, "CJK COMPATIBILITY IDEOGRAPH-F980"),
+  (21576, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F83E"),
+  (21608, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F83F"),
+  (21662, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F83C"),
+  (21666, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F840"),
+  (21693, 65024, "CJK COMPATIBILITY IDEOGRAPH-F99E"),
+  (21750, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F841"),
+  (21776, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F842"),
+  (21843, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F843"),
+  (21845, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA79"),
+  (21859, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F844"),
+  (21892, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F845"),
+  (21892, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F846"),
+  (21895, 65024, "CJK COMPATIBILITY IDEOGRAPH-F90B"),
+  (21913, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA7A"),
+  (21913, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F847"),
+  (21917, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA36"),
+  (21917, 65025, "CJK COMPATIBILITY IDEOGRAPH-FA78"),
+  (21931, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F848"),
+  (21939, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F849"),
+  (21952, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA0D"),
+  (21954, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F84A"),
+  (21986, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA7B"),
+  (22022, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA37"),
+  (22022, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F84C"),
+  (22097, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F84E"),
+  (22120, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA38"),
+  (22132, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F84F"),
+  (22265, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9A9"),
+  (22294, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F84B"),
+  (22295, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F84D"),
+  (22411, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F855"),
+  (22478, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F852"),
+  (22516, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F853"),
+  (22541, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F854"),
+  (22577, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F857"),
+  (22578, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F856"),
+  (22592, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA39"),
+  (22618, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA10"),
+  (22618, 65025, "CJK COMPATIBILITY IDEOGRAPH-FA7C"),
+  (22622, 65024, "CJK COMPATIBILITY IDEOGRAPH-F96C"),
+  (22696, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA3A"),
+  (22700, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F858"),
+  (22707, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA7D"),
+  (22744, 65024, "CJK COMPATIBILITY IDEOGRAPH-F94A"),
+  (22751, 65024, "CJK COMPATIBILITY IDEOGRAPH-F942"),
+  (22766, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F851"),
+  (22770, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F85A"),
+  (22775, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F85B"),
+  (22790, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F85C"),
+  (22810, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F85D"),
+  (22818, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F85E"),
+  (22852, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA7E"),
+  (22856, 65024, "CJK COMPATIBILITY IDEOGRAPH-F90C"),
+  (22865, 65024, "CJK COMPATIBILITY IDEOGRAPH-F909"),
+  (22868, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA7F"),
+  (22882, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F85F"),
+  (22899, 65024, "CJK COMPATIBILITY IDEOGRAPH-F981"),
+  (23000, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F865"),
+  (23020, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F862"),
+  (23067, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F863"),
+  (23079, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F864"),
+  (23138, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA80"),
+  (23142, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F866"),
+  (23221, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F986"),
+  (23304, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F869"),
+  (23336, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA81"),
+  (23358, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F86A"),
+  (23358, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F86B"),
+  (23429, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA04"),
+  (23491, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F86D"),
+  (23512, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F86E"),
+  (23527, 65024, "CJK COMPATIBILITY IDEOGRAPH-F95F"),
+  (23527, 65025, "CJK COMPATIBILITY IDEOGRAPH-F9AA"),
+  (23527, 65026, "CJK COMPATIBILITY IDEOGRAPH-2F86F"),
+  (23534, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9BC"),
+  (23539, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F870"),
+  (23551, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F872"),
+  (23558, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F873"),
+  (23586, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F875"),
+  (23615, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9BD"),
+  (23648, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F877"),
+  (23650, 65024, "CJK COMPATIBILITY IDEOGRAPH-F94B"),
+  (23652, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA3B"),
+  (23653, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9DF"),
+  (23662, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA3C"),
+  (23662, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F878"),
+  (23693, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F87A"),
+  (23744, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F879"),
+  (23833, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9D5"),
+  (23875, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F87C"),
+  (23888, 65024, "CJK COMPATIBILITY IDEOGRAPH-F921"),
+  (23915, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F87F"),
+  (23918, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F87E"),
+  (23932, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F880"),
+  (23986, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9F4"),
+  (23994, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9AB"),
+  (24033, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F881"),
+  (24034, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F882"),
+  (24061, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F884"),
+  (24104, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F885"),
+  (24125, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F886"),
+  (24169, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F887"),
+  (24180, 65024, "CJK COMPATIBILITY IDEOGRAPH-F98E"),
+  (24230, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA01"),
+  (24240, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F88B"),
+  (24243, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F88C"),
+  (24246, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F88D"),
+  (24265, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9A2"),
+  (24266, 65024, "CJK COMPATIBILITY IDEOGRAPH-F928"),
+  (24266, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F88E"),
+  (24274, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA82"),
+  (24275, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA0B"),
+  (24281, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA83"),
+  (24300, 65024, "CJK COMPATIBILITY IDEOGRAPH-F982"),
+  (24318, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F890"),
+  (24324, 65024, "CJK COMPATIBILITY IDEOGRAPH-F943"),
+  (24354, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F894"),
+  (24354, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F895"),
+  (24403, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F874"),
+  (24418, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F899"),
+  (24425, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA84"),
+  (24427, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F89A"),
+  (24459, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9D8"),
+  (24474, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F89C"),
+  (24489, 65024, "CJK COMPATIBILITY IDEOGRAPH-F966"),
+  (24493, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA85"),
+  (24525, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F89D"),
+  (24535, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F89E"),
+  (24565, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9A3"),
+  (24569, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F89F"),
+  (24594, 65024, "CJK COMPATIBILITY IDEOGRAPH-F960"),
+  (24604, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9AC"),
+  (24693, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA6B"),
+  (24705, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8A0"),
+  (24724, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA3D"),
+  (24724, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F8A3"),
+  (24775, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8A5"),
+  (24792, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA86"),
+  (24801, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9B9"),
+  (24840, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA88"),
+  (24900, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9D9"),
+  (24904, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8A6"),
+  (24908, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8A7"),
+  (24908, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F8A9"),
+  (24910, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA87"),
+  (24910, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F8A8"),
+  (24928, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA8A"),
+  (24936, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA3E"),
+  (24954, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8AA"),
+  (24974, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA3F"),
+  (24974, 65025, "CJK COMPATIBILITY IDEOGRAPH-FA89"),
+  (24974, 65026, "CJK COMPATIBILITY IDEOGRAPH-2F8AB"),
+  (24976, 65024, "CJK COMPATIBILITY IDEOGRAPH-F98F"),
+  (24996, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8AD"),
+  (25007, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8AE"),
+  (25010, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8AC"),
+  (25054, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8AF"),
+  (25074, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA40"),
+  (25074, 65025, "CJK COMPATIBILITY IDEOGRAPH-FA8B"),
+  (25074, 65026, "CJK COMPATIBILITY IDEOGRAPH-2F8B0"),
+  (25078, 65024, "CJK COMPATIBILITY IDEOGRAPH-F90D"),
+  (25078, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F8B1"),
+  (25088, 65024, "CJK COMPATIBILITY IDEOGRAPH-F990"),
+  (25104, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8B2"),
+  (25115, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8B3"),
+  (25134, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9D2"),
+  (25140, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA8C"),
+  (25181, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8B4"),
+  (25265, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8B5"),
+  (25289, 65024, "CJK COMPATIBILITY IDEOGRAPH-F925"),
+  (25295, 65024, "CJK COMPATIBILITY IDEOGRAPH-F95B"),
+  (25299, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA02"),
+  (25300, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8B6"),
+  (25340, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8BA"),
+  (25342, 65024, "CJK COMPATIBILITY IDEOGRAPH-F973"),
+  (25405, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8B9"),
+  (25424, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8B7"),
+  (25448, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8BB"),
+  (25467, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9A4"),
+  (25475, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8BC"),
+  (25504, 65024, "CJK COMPATIBILITY IDEOGRAPH-F975"),
+  (25513, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8C1"),
+  (25540, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA8D"),
+  (25541, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8C0"),
+  (25572, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8BD"),
+  (25628, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA8E"),
+  (25634, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8BF"),
+  (25682, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA8F"),
+  (25705, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8C3"),
+  (25719, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8C6"),
+  (25726, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8C4"),
+  (25754, 65024, "CJK COMPATIBILITY IDEOGRAPH-F991"),
+  (25757, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8C5"),
+  (25796, 65024, "CJK COMPATIBILITY IDEOGRAPH-F930"),
+  (25935, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA41"),
+  (25935, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F8C8"),
+  (25942, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA90"),
+  (25964, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8C9"),
+  (25976, 65024, "CJK COMPATIBILITY IDEOGRAPH-F969"),
+  (26009, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9BE"),
+  (26053, 65024, "CJK COMPATIBILITY IDEOGRAPH-F983"),
+  (26082, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA42"),
+  (26083, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8CB"),
+  (26131, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9E0"),
+  (26185, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8CD"),
+  (26228, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA12"),
+  (26228, 65025, "CJK COMPATIBILITY IDEOGRAPH-FA91"),
+  (26248, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9C5"),
+  (26257, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA43"),
+  (26257, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F8CF"),
+  (26268, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8D5"),
+  (26292, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA06"),
+  (26310, 65024, "CJK COMPATIBILITY IDEOGRAPH-F98B"),
+  (26356, 65024, "CJK COMPATIBILITY IDEOGRAPH-F901"),
+  (26360, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8CC"),
+  (26368, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8D4"),
+  (26391, 65024, "CJK COMPATIBILITY IDEOGRAPH-F929"),
+  (26391, 65025, "CJK COMPATIBILITY IDEOGRAPH-FA92"),
+  (26391, 65026, "CJK COMPATIBILITY IDEOGRAPH-2F8D8"),
+  (26395, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA93"),
+  (26395, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F8D9"),
+  (26401, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8DA"),
+  (26446, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9E1"),
+  (26451, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8DC"),
+  (26454, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA94"),
+  (26462, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8DB"),
+  (26491, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9C8"),
+  (26501, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8E0"),
+  (26519, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9F4"),
+  (26611, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9C9"),
+  (26618, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8DF"),
+  (26647, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9DA"),
+  (26655, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8E5"),
+  (26706, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8E1"),
+  (26753, 65024, "CJK COMPATIBILITY IDEOGRAPH-F97A"),
+  (26757, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA44"),
+  (26757, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F8E2"),
+  (26766, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8E4"),
+  (26792, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9E2"),
+  (26900, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8E6"),
+  (26946, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8E8"),
+  (27043, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8E9"),
+  (27114, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8EA"),
+  (27138, 65024, "CJK COMPATIBILITY IDEOGRAPH-F914"),
+  (27138, 65025, "CJK COMPATIBILITY IDEOGRAPH-F95C"),
+  (27138, 65026, "CJK COMPATIBILITY IDEOGRAPH-F9BF"),
+  (27155, 65024, "CJK COMPATIBILITY IDEOGRAPH-F94C"),
+  (27304, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8EB"),
+  (27347, 65024, "CJK COMPATIBILITY IDEOGRAPH-F931"),
+  (27355, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8ED"),
+  (27396, 65024, "CJK COMPATIBILITY IDEOGRAPH-F91D"),
+  (27425, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8EF"),
+  (27476, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8F1"),
+  (27506, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8F3"),
+  (27511, 65024, "CJK COMPATIBILITY IDEOGRAPH-F98C"),
+  (27513, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA95"),
+  (27551, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8F4"),
+  (27566, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9A5"),
+  (27578, 65024, "CJK COMPATIBILITY IDEOGRAPH-F970"),
+  (27578, 65025, "CJK COMPATIBILITY IDEOGRAPH-FA96"),
+  (27578, 65026, "CJK COMPATIBILITY IDEOGRAPH-2F8F5"),
+  (27579, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8F6"),
+  (27726, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8FA"),
+  (27751, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8FE"),
+  (27784, 65024, "CJK COMPATIBILITY IDEOGRAPH-F972"),
+  (27839, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8FC"),
+  (27852, 65024, "CJK COMPATIBILITY IDEOGRAPH-F968"),
+  (27853, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8FD"),
+  (27877, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9E3"),
+  (27926, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8FF"),
+  (27931, 65024, "CJK COMPATIBILITY IDEOGRAPH-F915"),
+  (27934, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA05"),
+  (27956, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F907"),
+  (27966, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F900"),
+  (27969, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9CA"),
+  (27969, 65025, "CJK COMPATIBILITY IDEOGRAPH-FA97"),
+  (27969, 65026, "CJK COMPATIBILITY IDEOGRAPH-2F902"),
+  (28009, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F903"),
+  (28010, 65024, "CJK COMPATIBILITY IDEOGRAPH-F92A"),
+  (28023, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA45"),
+  (28023, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F901"),
+  (28024, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F904"),
+  (28037, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F905"),
+  (28107, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9F5"),
+  (28122, 65024, "CJK COMPATIBILITY IDEOGRAPH-F94D"),
+  (28138, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9D6"),
+  (28153, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F90E"),
+  (28186, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA46"),
+  (28207, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F908"),
+  (28270, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F909"),
+  (28316, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9CB"),
+  (28346, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9EC"),
+  (28359, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F90C"),
+  (28363, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA99"),
+  (28363, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F90B"),
+  (28369, 65024, "CJK COMPATIBILITY IDEOGRAPH-F904"),
+  (28379, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA98"),
+  (28431, 65024, "CJK COMPATIBILITY IDEOGRAPH-F94E"),
+  (28450, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA47"),
+  (28450, 65025, "CJK COMPATIBILITY IDEOGRAPH-FA9A"),
+  (28451, 65024, "CJK COMPATIBILITY IDEOGRAPH-F992"),
+  (28526, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F90F"),
+  (28614, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F912"),
+  (28651, 65024, "CJK COMPATIBILITY IDEOGRAPH-F922"),
+  (28670, 65024, "CJK COMPATIBILITY IDEOGRAPH-F984"),
+  (28699, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F915"),
+  (28702, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA9B"),
+  (28702, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F914"),
+  (28729, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F913"),
+  (28746, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F917"),
+  (28784, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F835"),
+  (28791, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F919"),
+  (28797, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F918"),
+  (28825, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9FB"),
+  (28845, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F91A"),
+  (28872, 65024, "CJK COMPATIBILITY IDEOGRAPH-F99F"),
+  (28889, 65024, "CJK COMPATIBILITY IDEOGRAPH-F916"),
+  (28997, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F91C"),
+  (29001, 65024, "CJK COMPATIBILITY IDEOGRAPH-F993"),
+  (29038, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA48"),
+  (29038, 65025, "CJK COMPATIBILITY IDEOGRAPH-FA9C"),
+  (29084, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F91E"),
+  (29134, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9C0"),
+  (29136, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9EE"),
+  (29200, 65024, "CJK COMPATIBILITY IDEOGRAPH-F932"),
+  (29211, 65024, "CJK COMPATIBILITY IDEOGRAPH-F91E"),
+  (29224, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F920"),
+  (29227, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA49"),
+  (29237, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA9E"),
+  (29237, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F921"),
+  (29264, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F922"),
+  (29282, 65024, "CJK COMPATIBILITY IDEOGRAPH-F946"),
+  (29312, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F924"),
+  (29333, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F925"),
+  (29359, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA9F"),
+  (29376, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9FA"),
+  (29436, 65024, "CJK COMPATIBILITY IDEOGRAPH-F92B"),
+  (29482, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA16"),
+  (29482, 65025, "CJK COMPATIBILITY IDEOGRAPH-FAA0"),
+  (29557, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9A7"),
+  (29562, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F928"),
+  (29575, 65024, "CJK COMPATIBILITY IDEOGRAPH-F961"),
+  (29575, 65025, "CJK COMPATIBILITY IDEOGRAPH-F9DB"),
+  (29579, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F929"),
+  (29605, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F92B"),
+  (29618, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9AD"),
+  (29662, 65024, "CJK COMPATIBILITY IDEOGRAPH-F917"),
+  (29702, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9E4"),
+  (29705, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9CC"),
+  (29730, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA4A"),
+  (29767, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F92E"),
+  (29788, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F92F"),
+  (29801, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9AE"),
+  (29809, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAA1"),
+  (29809, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F930"),
+  (29829, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F931"),
+  (29833, 65024, "CJK COMPATIBILITY IDEOGRAPH-F994"),
+  (29848, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9EF"),
+  (29898, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F932"),
+  (29958, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAA2"),
+  (29988, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F934"),
+  (30011, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAA3"),
+  (30014, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F936"),
+  (30041, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9CD"),
+  (30053, 65024, "CJK COMPATIBILITY IDEOGRAPH-F976"),
+  (30064, 65024, "CJK COMPATIBILITY IDEOGRAPH-F962"),
+  (30064, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F938"),
+  (30178, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9E5"),
+  (30224, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F93A"),
+  (30237, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAA4"),
+  (30239, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAA5"),
+  (30274, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9C1"),
+  (30313, 65024, "CJK COMPATIBILITY IDEOGRAPH-F90E"),
+  (30410, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA17"),
+  (30410, 65025, "CJK COMPATIBILITY IDEOGRAPH-FAA6"),
+  (30427, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAA7"),
+  (30439, 65024, "CJK COMPATIBILITY IDEOGRAPH-F933"),
+  (30452, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAA8"),
+  (30452, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F940"),
+  (30465, 65024, "CJK COMPATIBILITY IDEOGRAPH-F96D"),
+  (30494, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F945"),
+  (30495, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F946"),
+  (30495, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F947"),
+  (30528, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAAA"),
+  (30538, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAA9"),
+  (30538, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F948"),
+  (30603, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F94A"),
+  (30631, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA9D"),
+  (30798, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F94E"),
+  (30827, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9CE"),
+  (30860, 65024, "CJK COMPATIBILITY IDEOGRAPH-F93B"),
+  (30860, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F94F"),
+  (30865, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA4B"),
+  (30922, 65024, "CJK COMPATIBILITY IDEOGRAPH-F947"),
+  (30924, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAAB"),
+  (30924, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F950"),
+  (30971, 65024, "CJK COMPATIBILITY IDEOGRAPH-F964"),
+  (31018, 65024, "CJK COMPATIBILITY IDEOGRAPH-F985"),
+  (31036, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA18"),
+  (31038, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA4C"),
+  (31048, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA4E"),
+  (31049, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA4D"),
+  (31056, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA4F"),
+  (31062, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA50"),
+  (31062, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F953"),
+  (31069, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA51"),
+  (31070, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA19"),
+  (31077, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA1A"),
+  (31103, 65024, "CJK COMPATIBILITY IDEOGRAPH-F93C"),
+  (31117, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA52"),
+  (31118, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA53"),
+  (31119, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA1B"),
+  (31119, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F956"),
+  (31150, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9B6"),
+  (31178, 65024, "CJK COMPATIBILITY IDEOGRAPH-F995"),
+  (31211, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F957"),
+  (31260, 65024, "CJK COMPATIBILITY IDEOGRAPH-F956"),
+  (31296, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA54"),
+  (31296, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F959"),
+  (31306, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F95A"),
+  (31311, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F95B"),
+  (31361, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA55"),
+  (31409, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAAC"),
+  (31435, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9F7"),
+  (31470, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F95F"),
+  (31520, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9F8"),
+  (31680, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA56"),
+  (31680, 65025, "CJK COMPATIBILITY IDEOGRAPH-FAAD"),
+  (31686, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F962"),
+  (31689, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F963"),
+  (31806, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9A6"),
+  (31840, 65024, "CJK COMPATIBILITY IDEOGRAPH-F944"),
+  (31867, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAAE"),
+  (31890, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9F9"),
+  (31934, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA1D"),
+  (31954, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F966"),
+  (31958, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA03"),
+  (31971, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F969"),
+  (31975, 65024, "CJK COMPATIBILITY IDEOGRAPH-F97B"),
+  (31976, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F968"),
+  (32000, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F96A"),
+  (32016, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9CF"),
+  (32034, 65024, "CJK COMPATIBILITY IDEOGRAPH-F96A"),
+  (32047, 65024, "CJK COMPATIBILITY IDEOGRAPH-F94F"),
+  (32091, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAAF"),
+  (32099, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F96C"),
+  (32160, 65024, "CJK COMPATIBILITY IDEOGRAPH-F93D"),
+  (32190, 65024, "CJK COMPATIBILITY IDEOGRAPH-F957"),
+  (32199, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F96E"),
+  (32244, 65024, "CJK COMPATIBILITY IDEOGRAPH-F996"),
+  (32244, 65025, "CJK COMPATIBILITY IDEOGRAPH-FA57"),
+  (32244, 65026, "CJK COMPATIBILITY IDEOGRAPH-FAB0"),
+  (32258, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F96F"),
+  (32265, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA58"),
+  (32311, 65024, "CJK COMPATIBILITY IDEOGRAPH-F950"),
+  (32321, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA59"),
+  (32325, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F970"),
+  (32574, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAB1"),
+  (32626, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA5A"),
+  (32633, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9E6"),
+  (32634, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F976"),
+  (32645, 65024, "CJK COMPATIBILITY IDEOGRAPH-F90F"),
+  (32661, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F978"),
+  (32666, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9AF"),
+  (32701, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA1E"),
+  (32762, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F979"),
+  (32769, 65024, "CJK COMPATIBILITY IDEOGRAPH-F934"),
+  (32773, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA5B"),
+  (32773, 65025, "CJK COMPATIBILITY IDEOGRAPH-FAB2"),
+  (32773, 65026, "CJK COMPATIBILITY IDEOGRAPH-2F97A"),
+  (32838, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9B0"),
+  (32864, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F97D"),
+  (32879, 65024, "CJK COMPATIBILITY IDEOGRAPH-F997"),
+  (32880, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F97F"),
+  (32894, 65024, "CJK COMPATIBILITY IDEOGRAPH-F945"),
+  (32907, 65024, "CJK COMPATIBILITY IDEOGRAPH-F953"),
+  (32941, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8D6"),
+  (32946, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F982"),
+  (33027, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F983"),
+  (33086, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F985"),
+  (33240, 65024, "CJK COMPATIBILITY IDEOGRAPH-F926"),
+  (33256, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9F6"),
+  (33261, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA5C"),
+  (33281, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F893"),
+  (33281, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F98B"),
+  (33284, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F98C"),
+  (33304, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA6D"),
+  (33391, 65024, "CJK COMPATIBILITY IDEOGRAPH-F97C"),
+  (33401, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA5D"),
+  (33401, 65025, "CJK COMPATIBILITY IDEOGRAPH-FA5E"),
+  (33419, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F990"),
+  (33425, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F98F"),
+  (33437, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F991"),
+  (33457, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F993"),
+  (33459, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F994"),
+  (33469, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F995"),
+  (33509, 65024, "CJK COMPATIBILITY IDEOGRAPH-F974"),
+  (33509, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F998"),
+  (33510, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F996"),
+  (33565, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F999"),
+  (33571, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F99C"),
+  (33590, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9FE"),
+  (33618, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAB3"),
+  (33619, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9A0"),
+  (33635, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F99A"),
+  (33709, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F99B"),
+  (33725, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F99D"),
+  (33737, 65024, "CJK COMPATIBILITY IDEOGRAPH-F93E"),
+  (33738, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9A1"),
+  (33740, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9A2"),
+  (33756, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9A3"),
+  (33767, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F99E"),
+  (33775, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAB4"),
+  (33777, 65024, "CJK COMPATIBILITY IDEOGRAPH-F958"),
+  (33853, 65024, "CJK COMPATIBILITY IDEOGRAPH-F918"),
+  (33865, 65024, "CJK COMPATIBILITY IDEOGRAPH-F96E"),
+  (33879, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA5F"),
+  (33879, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F99F"),
+  (34030, 65024, "CJK COMPATIBILITY IDEOGRAPH-F999"),
+  (34033, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9A8"),
+  (34035, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9A9"),
+  (34044, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9C2"),
+  (34070, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9AA"),
+  (34148, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9AC"),
+  (34253, 65024, "CJK COMPATIBILITY IDEOGRAPH-F923"),
+  (34298, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9F0"),
+  (34310, 65024, "CJK COMPATIBILITY IDEOGRAPH-F935"),
+  (34322, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA20"),
+  (34349, 65024, "CJK COMPATIBILITY IDEOGRAPH-F91F"),
+  (34367, 65024, "CJK COMPATIBILITY IDEOGRAPH-F910"),
+  (34384, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9B3"),
+  (34396, 65024, "CJK COMPATIBILITY IDEOGRAPH-F936"),
+  (34396, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F9B4"),
+  (34407, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9B5"),
+  (34409, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9B6"),
+  (34440, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9B8"),
+  (34473, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9B7"),
+  (34530, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9BA"),
+  (34574, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9B9"),
+  (34600, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9BC"),
+  (34667, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9BD"),
+  (34681, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAB5"),
+  (34681, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F9BB"),
+  (34694, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9BE"),
+  (34746, 65024, "CJK COMPATIBILITY IDEOGRAPH-F911"),
+  (34785, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9C0"),
+  (34817, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9C1"),
+  (34847, 65024, "CJK COMPATIBILITY IDEOGRAPH-F927"),
+  (34892, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA08"),
+  (34912, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9C3"),
+  (34915, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9C4"),
+  (35010, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9A0"),
+  (35023, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9E7"),
+  (35031, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9C6"),
+  (35038, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9C7"),
+  (35041, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9E8"),
+  (35064, 65024, "CJK COMPATIBILITY IDEOGRAPH-F912"),
+  (35066, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9C9"),
+  (35088, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA60"),
+  (35137, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAB6"),
+  (35172, 65024, "CJK COMPATIBILITY IDEOGRAPH-F924"),
+  (35206, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAB7"),
+  (35211, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA0A"),
+  (35222, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA61"),
+  (35222, 65025, "CJK COMPATIBILITY IDEOGRAPH-FAB8"),
+  (35488, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9CF"),
+  (35498, 65024, "CJK COMPATIBILITY IDEOGRAPH-F96F"),
+  (35498, 65025, "CJK COMPATIBILITY IDEOGRAPH-F9A1"),
+  (35519, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAB9"),
+  (35531, 65024, "CJK COMPATIBILITY IDEOGRAPH-FABB"),
+  (35538, 65024, "CJK COMPATIBILITY IDEOGRAPH-F97D"),
+  (35542, 65024, "CJK COMPATIBILITY IDEOGRAPH-F941"),
+  (35565, 65024, "CJK COMPATIBILITY IDEOGRAPH-FABE"),
+  (35565, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F9D0"),
+  (35576, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA22"),
+  (35576, 65025, "CJK COMPATIBILITY IDEOGRAPH-FABA"),
+  (35582, 65024, "CJK COMPATIBILITY IDEOGRAPH-F95D"),
+  (35582, 65025, "CJK COMPATIBILITY IDEOGRAPH-FABD"),
+  (35585, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA62"),
+  (35585, 65025, "CJK COMPATIBILITY IDEOGRAPH-FABC"),
+  (35641, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA63"),
+  (35641, 65025, "CJK COMPATIBILITY IDEOGRAPH-FABF"),
+  (35672, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9FC"),
+  (35712, 65024, "CJK COMPATIBILITY IDEOGRAPH-F95A"),
+  (35722, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAC0"),
+  (35722, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F9D1"),
+  (35912, 65024, "CJK COMPATIBILITY IDEOGRAPH-F900"),
+  (35925, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9D2"),
+  (36011, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9D4"),
+  (36033, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9D5"),
+  (36034, 65024, "CJK COMPATIBILITY IDEOGRAPH-F948"),
+  (36040, 65024, "CJK COMPATIBILITY IDEOGRAPH-F903"),
+  (36051, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA64"),
+  (36104, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA65"),
+  (36104, 65025, "CJK COMPATIBILITY IDEOGRAPH-FAC1"),
+  (36123, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9D6"),
+  (36215, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9D7"),
+  (36284, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9DB"),
+  (36299, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9DA"),
+  (36335, 65024, "CJK COMPATIBILITY IDEOGRAPH-F937"),
+  (36336, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9DC"),
+  (36554, 65024, "CJK COMPATIBILITY IDEOGRAPH-F902"),
+  (36564, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9DE"),
+  (36646, 65024, "CJK COMPATIBILITY IDEOGRAPH-F998"),
+  (36650, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9D7"),
+  (36664, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAC2"),
+  (36664, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F9DF"),
+  (36667, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA07"),
+  (36706, 65024, "CJK COMPATIBILITY IDEOGRAPH-F98D"),
+  (36766, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F98D"),
+  (36784, 65024, "CJK COMPATIBILITY IDEOGRAPH-F971"),
+  (36790, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA66"),
+  (36899, 65024, "CJK COMPATIBILITY IDEOGRAPH-F99A"),
+  (36920, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA25"),
+  (36920, 65025, "CJK COMPATIBILITY IDEOGRAPH-FA67"),
+  (36978, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAC3"),
+  (36988, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9C3"),
+  (37007, 65024, "CJK COMPATIBILITY IDEOGRAPH-F913"),
+  (37012, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9E2"),
+  (37070, 65024, "CJK COMPATIBILITY IDEOGRAPH-F92C"),
+  (37086, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA2E"),
+  (37105, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9E3"),
+  (37117, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA26"),
+  (37137, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9E4"),
+  (37147, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9E6"),
+  (37226, 65024, "CJK COMPATIBILITY IDEOGRAPH-F919"),
+  (37273, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAC4"),
+  (37300, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9B7"),
+  (37324, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9E9"),
+  (37327, 65024, "CJK COMPATIBILITY IDEOGRAPH-F97E"),
+  (37329, 65024, "CJK COMPATIBILITY IDEOGRAPH-F90A"),
+  (37428, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9B1"),
+  (37432, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9E7"),
+  (37494, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAC5"),
+  (37500, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9EA"),
+  (37591, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9E8"),
+  (37592, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9E9"),
+  (37636, 65024, "CJK COMPATIBILITY IDEOGRAPH-F93F"),
+  (37706, 65024, "CJK COMPATIBILITY IDEOGRAPH-F99B"),
+  (37881, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9EB"),
+  (37909, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9EC"),
+  (38283, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9EE"),
+  (38317, 65024, "CJK COMPATIBILITY IDEOGRAPH-F986"),
+  (38327, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9F0"),
+  (38446, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9C6"),
+  (38475, 65024, "CJK COMPATIBILITY IDEOGRAPH-F951"),
+  (38477, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA09"),
+  (38517, 65024, "CJK COMPATIBILITY IDEOGRAPH-F959"),
+  (38520, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9D3"),
+  (38524, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAC6"),
+  (38534, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9DC"),
+  (38563, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9F1"),
+  (38583, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA2F"),
+  (38584, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9B8"),
+  (38595, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9F3"),
+  (38626, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9EA"),
+  (38627, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA68"),
+  (38627, 65025, "CJK COMPATIBILITY IDEOGRAPH-FAC7"),
+  (38646, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9B2"),
+  (38647, 65024, "CJK COMPATIBILITY IDEOGRAPH-F949"),
+  (38691, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9F5"),
+  (38706, 65024, "CJK COMPATIBILITY IDEOGRAPH-F938"),
+  (38728, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9B3"),
+  (38742, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA1C"),
+  (38742, 65025, "CJK COMPATIBILITY IDEOGRAPH-FAC8"),
+  (38875, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAC9"),
+  (38880, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9FA"),
+  (38911, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA69"),
+  (38911, 65025, "CJK COMPATIBILITY IDEOGRAPH-FACA"),
+  (38923, 65024, "CJK COMPATIBILITY IDEOGRAPH-FACB"),
+  (38923, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F9FE"),
+  (38923, 65026, "CJK COMPATIBILITY IDEOGRAPH-2F9FF"),
+  (38936, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9B4"),
+  (38953, 65024, "CJK COMPATIBILITY IDEOGRAPH-2FA00"),
+  (38971, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA6A"),
+  (38971, 65025, "CJK COMPATIBILITY IDEOGRAPH-FACC"),
+  (39006, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9D0"),
+  (39138, 65024, "CJK COMPATIBILITY IDEOGRAPH-2FA02"),
+  (39151, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA2A"),
+  (39164, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA2B"),
+  (39208, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA2C"),
+  (39209, 65024, "CJK COMPATIBILITY IDEOGRAPH-2FA04"),
+  (39335, 65024, "CJK COMPATIBILITY IDEOGRAPH-2FA05"),
+  (39362, 65024, "CJK COMPATIBILITY IDEOGRAPH-2FA06"),
+  (39409, 65024, "CJK COMPATIBILITY IDEOGRAPH-F91A"),
+  (39422, 65024, "CJK COMPATIBILITY IDEOGRAPH-2FA07"),
+  (39530, 65024, "CJK COMPATIBILITY IDEOGRAPH-F987"),
+  (39698, 65024, "CJK COMPATIBILITY IDEOGRAPH-FACD"),
+  (39698, 65025, "CJK COMPATIBILITY IDEOGRAPH-2FA0A"),
+  (39791, 65024, "CJK COMPATIBILITY IDEOGRAPH-F939"),
+  (40000, 65024, "CJK COMPATIBILITY IDEOGRAPH-2FA0B"),
+  (40023, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9F2"),
+  (40189, 65024, "CJK COMPATIBILITY IDEOGRAPH-2FA0C"),
+  (40295, 65024, "CJK COMPATIBILITY IDEOGRAPH-2FA0F"),
+  (40372, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA2D"),
+  (40442, 65024, "CJK COMPATIBILITY IDEOGRAPH-F93A"),
+  (40478, 65024, "CJK COMPATIBILITY IDEOGRAPH-F920"),
+  (40575, 65024, "CJK COMPATIBILITY IDEOGRAPH-F940"),
+  (40599, 65024, "CJK COMPATIBILITY IDEOGRAPH-F988"),
+  (40607, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9F3"),
+  (40635, 65024, "CJK COMPATIBILITY IDEOGRAPH-2FA15"),
+  (40654, 65024, "CJK COMPATIBILITY IDEOGRAPH-F989"),
+  (40697, 65024, "CJK COMPATIBILITY IDEOGRAPH-2FA17"),
+  (40702, 65024, "CJK COMPATIBILITY IDEOGRAPH-2FA18"),
+  (40709, 65024, "CJK COMPATIBILITY IDEOGRAPH-2FA19"),
+  (40719, 65024, "CJK COMPATIBILITY IDEOGRAPH-2FA1A"),
+  (40726, 65024, "CJK COMPATIBILITY IDEOGRAPH-2FA1B"),
+  (40763, 65024, "CJK COMPATIBILITY IDEOGRAPH-2FA1C"),
+  (40771, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAD8"),
+  (40845, 65024, "CJK COMPATIBILITY IDEOGRAPH-F9C4"),
+  (40846, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAD9"),
+  (40860, 65024, "CJK COMPATIBILITY IDEOGRAPH-F907"),
+  (40860, 65025, "CJK COMPATIBILITY IDEOGRAPH-F908"),
+  (40860, 65026, "CJK COMPATIBILITY IDEOGRAPH-FACE"),
+  (43094, 65024, "phags-pa letter reversed shaping small a"),
+  (43100, 65024, "phags-pa letter reversed shaping ha"),
+  (43102, 65024, "phags-pa letter reversed shaping i"),
+  (43103, 65024, "phags-pa letter reversed shaping u"),
+  (43104, 65024, "phags-pa letter reversed shaping e"),
+  (43112, 65024, "phags-pa letter reversed shaping subjoined ya"),
+  (43616, 65024, "dotted form"), (43617, 65024, "dotted form"),
+  (43618, 65024, "dotted form"), (43619, 65024, "dotted form"),
+  (43620, 65024, "dotted form"), (43621, 65024, "dotted form"),
+  (43622, 65024, "dotted form"), (43627, 65024, "dotted form"),
+  (43628, 65024, "dotted form"), (43631, 65024, "dotted form"),
+  (43642, 65024, "dotted form"),
+  (131362, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F803"),
+  (132380, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F812"),
+  (132389, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F91B"),
+  (132427, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F816"),
+  (132666, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F80D"),
+  (133124, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9D9"),
+  (133342, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9DD"),
+  (133676, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F834"),
+  (133987, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F838"),
+  (136420, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F859"),
+  (136872, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F860"),
+  (136938, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F861"),
+  (137672, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F86C"),
+  (138008, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F871"),
+  (138507, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8F8"),
+  (138724, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F87B"),
+  (138726, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F87D"),
+  (139651, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F889"),
+  (139679, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F939"),
+  (140081, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F891"),
+  (140081, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F892"),
+  (141012, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8A4"),
+  (141380, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAD0"),
+  (141386, 65024, "CJK COMPATIBILITY IDEOGRAPH-FACF"),
+  (142092, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8B8"),
+  (142321, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8BE"),
+  (143370, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8CA"),
+  (144056, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F897"),
+  (144223, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F980"),
+  (144275, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F989"),
+  (144284, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F98A"),
+  (144323, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8DD"),
+  (144341, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAD1"),
+  (144493, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8E3"),
+  (145059, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8EC"),
+  (145575, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8F0"),
+  (146061, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8F7"),
+  (146170, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8F9"),
+  (146620, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F8FB"),
+  (146718, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F906"),
+  (147153, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F90D"),
+  (147294, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F910"),
+  (147342, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F911"),
+  (148067, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F91D"),
+  (148206, 65024, "CJK COMPATIBILITY IDEOGRAPH-FA6C"),
+  (148395, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F91F"),
+  (149000, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F923"),
+  (149301, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F926"),
+  (149524, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F927"),
+  (150582, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F935"),
+  (150674, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F937"),
+  (151457, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F93B"),
+  (151480, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F93C"),
+  (151620, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F93D"),
+  (151794, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F942"),
+  (151795, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F941"),
+  (151833, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F943"),
+  (151859, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F944"),
+  (152137, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAD5"),
+  (152605, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F94D"),
+  (153126, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F952"),
+  (153242, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F954"),
+  (153285, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F955"),
+  (153980, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F95C"),
+  (154279, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F95D"),
+  (154279, 65025, "CJK COMPATIBILITY IDEOGRAPH-2F95E"),
+  (154539, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F961"),
+  (154752, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F965"),
+  (154832, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAD6"),
+  (155526, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F96B"),
+  (156122, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F898"),
+  (156200, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F972"),
+  (156231, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F973"),
+  (156377, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F975"),
+  (156478, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F977"),
+  (156890, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F97B"),
+  (156963, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F97C"),
+  (157096, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F97E"),
+  (157607, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F987"),
+  (157621, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F988"),
+  (158524, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F997"),
+  (158774, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9A4"),
+  (158933, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9A6"),
+  (159083, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9A5"),
+  (159532, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9AD"),
+  (159665, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9B0"),
+  (159954, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9B1"),
+  (160714, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9AB"),
+  (161383, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9C5"),
+  (161966, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9CB"),
+  (162150, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9CC"),
+  (162984, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9D3"),
+  (163539, 65024, "CJK COMPATIBILITY IDEOGRAPH-FAD7"),
+  (163631, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9D8"),
+  (165330, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9E0"),
+  (165357, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9E1"),
+  (165678, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9E5"),
+  (166906, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9ED"),
+  (167287, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9F1"),
+  (168261, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9F6"),
+  (168415, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F81C"),
+  (168474, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9F7"),
+  (168970, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9FB"),
+  (169110, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F9FD"),
+  (169398, 65024, "CJK COMPATIBILITY IDEOGRAPH-2FA01"),
+  (170800, 65024, "CJK COMPATIBILITY IDEOGRAPH-2FA09"),
+  (172238, 65024, "CJK COMPATIBILITY IDEOGRAPH-2FA10"),
+  (172293, 65024, "CJK COMPATIBILITY IDEOGRAPH-2FA12"),
+  (172558, 65024, "CJK COMPATIBILITY IDEOGRAPH-2FA13"),
+  (172689, 65024, "CJK COMPATIBILITY IDEOGRAPH-2FA14"),
+  (172946, 65024, "CJK COMPATIBILITY IDEOGRAPH-2F88F"),
+  (173568, 65024, "CJK COMPATIBILITY IDEOGRAPH-2FA1D"),
+];
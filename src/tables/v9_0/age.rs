@@ -0,0 +1,462 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//  ucd-generate age /home/andrew/tmp/ucd-9.0.0/
+//
+// ucd-generate is available on crates.io.
+
+pub const AGE: &'static [(u32, u32, &'static str)] = &[
+  (0, 501, "V1_1"), (502, 505, "V3_0"), (506, 535, "V1_1"), (536, 543, "V3_0"),
+  (544, 544, "V3_2"), (545, 545, "V4_0"), (546, 563, "V3_0"),
+  (564, 566, "V4_0"), (567, 577, "V4_1"), (578, 591, "V5_0"),
+  (592, 680, "V1_1"), (681, 685, "V3_0"), (686, 687, "V4_0"),
+  (688, 734, "V1_1"), (735, 735, "V3_0"), (736, 745, "V1_1"),
+  (746, 750, "V3_0"), (751, 767, "V4_0"), (768, 837, "V1_1"),
+  (838, 846, "V3_0"), (847, 847, "V3_2"), (848, 855, "V4_0"),
+  (856, 860, "V4_1"), (861, 863, "V4_0"), (864, 865, "V1_1"),
+  (866, 866, "V3_0"), (867, 879, "V3_2"), (880, 883, "V5_1"),
+  (884, 885, "V1_1"), (886, 887, "V5_1"), (890, 890, "V1_1"),
+  (891, 893, "V5_0"), (894, 894, "V1_1"), (895, 895, "V7_0"),
+  (900, 906, "V1_1"), (908, 908, "V1_1"), (910, 929, "V1_1"),
+  (931, 974, "V1_1"), (975, 975, "V5_1"), (976, 982, "V1_1"),
+  (983, 983, "V3_0"), (984, 985, "V3_2"), (986, 986, "V1_1"),
+  (987, 987, "V3_0"), (988, 988, "V1_1"), (989, 989, "V3_0"),
+  (990, 990, "V1_1"), (991, 991, "V3_0"), (992, 992, "V1_1"),
+  (993, 993, "V3_0"), (994, 1011, "V1_1"), (1012, 1013, "V3_1"),
+  (1014, 1014, "V3_2"), (1015, 1019, "V4_0"), (1020, 1023, "V4_1"),
+  (1024, 1024, "V3_0"), (1025, 1036, "V1_1"), (1037, 1037, "V3_0"),
+  (1038, 1103, "V1_1"), (1104, 1104, "V3_0"), (1105, 1116, "V1_1"),
+  (1117, 1117, "V3_0"), (1118, 1158, "V1_1"), (1159, 1159, "V5_1"),
+  (1160, 1161, "V3_0"), (1162, 1163, "V3_2"), (1164, 1167, "V3_0"),
+  (1168, 1220, "V1_1"), (1221, 1222, "V3_2"), (1223, 1224, "V1_1"),
+  (1225, 1226, "V3_2"), (1227, 1228, "V1_1"), (1229, 1230, "V3_2"),
+  (1231, 1231, "V5_0"), (1232, 1259, "V1_1"), (1260, 1261, "V3_0"),
+  (1262, 1269, "V1_1"), (1270, 1271, "V4_1"), (1272, 1273, "V1_1"),
+  (1274, 1279, "V5_0"), (1280, 1295, "V3_2"), (1296, 1299, "V5_0"),
+  (1300, 1315, "V5_1"), (1316, 1317, "V5_2"), (1318, 1319, "V6_0"),
+  (1320, 1327, "V7_0"), (1329, 1366, "V1_1"), (1369, 1375, "V1_1"),
+  (1377, 1415, "V1_1"), (1417, 1417, "V1_1"), (1418, 1418, "V3_0"),
+  (1421, 1422, "V7_0"), (1423, 1423, "V6_1"), (1425, 1441, "V2_0"),
+  (1442, 1442, "V4_1"), (1443, 1455, "V2_0"), (1456, 1465, "V1_1"),
+  (1466, 1466, "V5_0"), (1467, 1475, "V1_1"), (1476, 1476, "V2_0"),
+  (1477, 1479, "V4_1"), (1488, 1514, "V1_1"), (1520, 1524, "V1_1"),
+  (1536, 1539, "V4_0"), (1540, 1540, "V6_1"), (1541, 1541, "V7_0"),
+  (1542, 1546, "V5_1"), (1547, 1547, "V4_1"), (1548, 1548, "V1_1"),
+  (1549, 1557, "V4_0"), (1558, 1562, "V5_1"), (1563, 1563, "V1_1"),
+  (1564, 1564, "V6_3"), (1566, 1566, "V4_1"), (1567, 1567, "V1_1"),
+  (1568, 1568, "V6_0"), (1569, 1594, "V1_1"), (1595, 1599, "V5_1"),
+  (1600, 1618, "V1_1"), (1619, 1621, "V3_0"), (1622, 1624, "V4_0"),
+  (1625, 1630, "V4_1"), (1631, 1631, "V6_0"), (1632, 1645, "V1_1"),
+  (1646, 1647, "V3_2"), (1648, 1719, "V1_1"), (1720, 1721, "V3_0"),
+  (1722, 1726, "V1_1"), (1727, 1727, "V3_0"), (1728, 1742, "V1_1"),
+  (1743, 1743, "V3_0"), (1744, 1773, "V1_1"), (1774, 1775, "V4_0"),
+  (1776, 1785, "V1_1"), (1786, 1790, "V3_0"), (1791, 1791, "V4_0"),
+  (1792, 1805, "V3_0"), (1807, 1836, "V3_0"), (1837, 1839, "V4_0"),
+  (1840, 1866, "V3_0"), (1869, 1871, "V4_0"), (1872, 1901, "V4_1"),
+  (1902, 1919, "V5_1"), (1920, 1968, "V3_0"), (1969, 1969, "V3_2"),
+  (1984, 2042, "V5_0"), (2048, 2093, "V5_2"), (2096, 2110, "V5_2"),
+  (2112, 2139, "V6_0"), (2142, 2142, "V6_0"), (2208, 2208, "V6_1"),
+  (2209, 2209, "V7_0"), (2210, 2220, "V6_1"), (2221, 2226, "V7_0"),
+  (2227, 2228, "V8_0"), (2230, 2237, "V9_0"), (2260, 2274, "V9_0"),
+  (2275, 2275, "V8_0"), (2276, 2302, "V6_1"), (2303, 2303, "V7_0"),
+  (2304, 2304, "V5_2"), (2305, 2307, "V1_1"), (2308, 2308, "V4_0"),
+  (2309, 2361, "V1_1"), (2362, 2363, "V6_0"), (2364, 2381, "V1_1"),
+  (2382, 2382, "V5_2"), (2383, 2383, "V6_0"), (2384, 2388, "V1_1"),
+  (2389, 2389, "V5_2"), (2390, 2391, "V6_0"), (2392, 2416, "V1_1"),
+  (2417, 2418, "V5_1"), (2419, 2423, "V6_0"), (2424, 2424, "V7_0"),
+  (2425, 2426, "V5_2"), (2427, 2428, "V5_0"), (2429, 2429, "V4_1"),
+  (2430, 2431, "V5_0"), (2432, 2432, "V7_0"), (2433, 2435, "V1_1"),
+  (2437, 2444, "V1_1"), (2447, 2448, "V1_1"), (2451, 2472, "V1_1"),
+  (2474, 2480, "V1_1"), (2482, 2482, "V1_1"), (2486, 2489, "V1_1"),
+  (2492, 2492, "V1_1"), (2493, 2493, "V4_0"), (2494, 2500, "V1_1"),
+  (2503, 2504, "V1_1"), (2507, 2509, "V1_1"), (2510, 2510, "V4_1"),
+  (2519, 2519, "V1_1"), (2524, 2525, "V1_1"), (2527, 2531, "V1_1"),
+  (2534, 2554, "V1_1"), (2555, 2555, "V5_2"), (2561, 2561, "V4_0"),
+  (2562, 2562, "V1_1"), (2563, 2563, "V4_0"), (2565, 2570, "V1_1"),
+  (2575, 2576, "V1_1"), (2579, 2600, "V1_1"), (2602, 2608, "V1_1"),
+  (2610, 2611, "V1_1"), (2613, 2614, "V1_1"), (2616, 2617, "V1_1"),
+  (2620, 2620, "V1_1"), (2622, 2626, "V1_1"), (2631, 2632, "V1_1"),
+  (2635, 2637, "V1_1"), (2641, 2641, "V5_1"), (2649, 2652, "V1_1"),
+  (2654, 2654, "V1_1"), (2662, 2676, "V1_1"), (2677, 2677, "V5_1"),
+  (2689, 2691, "V1_1"), (2693, 2699, "V1_1"), (2700, 2700, "V4_0"),
+  (2701, 2701, "V1_1"), (2703, 2705, "V1_1"), (2707, 2728, "V1_1"),
+  (2730, 2736, "V1_1"), (2738, 2739, "V1_1"), (2741, 2745, "V1_1"),
+  (2748, 2757, "V1_1"), (2759, 2761, "V1_1"), (2763, 2765, "V1_1"),
+  (2768, 2768, "V1_1"), (2784, 2784, "V1_1"), (2785, 2787, "V4_0"),
+  (2790, 2799, "V1_1"), (2800, 2800, "V6_1"), (2801, 2801, "V4_0"),
+  (2809, 2809, "V8_0"), (2817, 2819, "V1_1"), (2821, 2828, "V1_1"),
+  (2831, 2832, "V1_1"), (2835, 2856, "V1_1"), (2858, 2864, "V1_1"),
+  (2866, 2867, "V1_1"), (2869, 2869, "V4_0"), (2870, 2873, "V1_1"),
+  (2876, 2883, "V1_1"), (2884, 2884, "V5_1"), (2887, 2888, "V1_1"),
+  (2891, 2893, "V1_1"), (2902, 2903, "V1_1"), (2908, 2909, "V1_1"),
+  (2911, 2913, "V1_1"), (2914, 2915, "V5_1"), (2918, 2928, "V1_1"),
+  (2929, 2929, "V4_0"), (2930, 2935, "V6_0"), (2946, 2947, "V1_1"),
+  (2949, 2954, "V1_1"), (2958, 2960, "V1_1"), (2962, 2965, "V1_1"),
+  (2969, 2970, "V1_1"), (2972, 2972, "V1_1"), (2974, 2975, "V1_1"),
+  (2979, 2980, "V1_1"), (2984, 2986, "V1_1"), (2990, 2997, "V1_1"),
+  (2998, 2998, "V4_1"), (2999, 3001, "V1_1"), (3006, 3010, "V1_1"),
+  (3014, 3016, "V1_1"), (3018, 3021, "V1_1"), (3024, 3024, "V5_1"),
+  (3031, 3031, "V1_1"), (3046, 3046, "V4_1"), (3047, 3058, "V1_1"),
+  (3059, 3066, "V4_0"), (3072, 3072, "V7_0"), (3073, 3075, "V1_1"),
+  (3077, 3084, "V1_1"), (3086, 3088, "V1_1"), (3090, 3112, "V1_1"),
+  (3114, 3123, "V1_1"), (3124, 3124, "V7_0"), (3125, 3129, "V1_1"),
+  (3133, 3133, "V5_1"), (3134, 3140, "V1_1"), (3142, 3144, "V1_1"),
+  (3146, 3149, "V1_1"), (3157, 3158, "V1_1"), (3160, 3161, "V5_1"),
+  (3162, 3162, "V8_0"), (3168, 3169, "V1_1"), (3170, 3171, "V5_1"),
+  (3174, 3183, "V1_1"), (3192, 3199, "V5_1"), (3200, 3200, "V9_0"),
+  (3201, 3201, "V7_0"), (3202, 3203, "V1_1"), (3205, 3212, "V1_1"),
+  (3214, 3216, "V1_1"), (3218, 3240, "V1_1"), (3242, 3251, "V1_1"),
+  (3253, 3257, "V1_1"), (3260, 3261, "V4_0"), (3262, 3268, "V1_1"),
+  (3270, 3272, "V1_1"), (3274, 3277, "V1_1"), (3285, 3286, "V1_1"),
+  (3294, 3294, "V1_1"), (3296, 3297, "V1_1"), (3298, 3299, "V5_0"),
+  (3302, 3311, "V1_1"), (3313, 3314, "V5_0"), (3329, 3329, "V7_0"),
+  (3330, 3331, "V1_1"), (3333, 3340, "V1_1"), (3342, 3344, "V1_1"),
+  (3346, 3368, "V1_1"), (3369, 3369, "V6_0"), (3370, 3385, "V1_1"),
+  (3386, 3386, "V6_0"), (3389, 3389, "V5_1"), (3390, 3395, "V1_1"),
+  (3396, 3396, "V5_1"), (3398, 3400, "V1_1"), (3402, 3405, "V1_1"),
+  (3406, 3406, "V6_0"), (3407, 3407, "V9_0"), (3412, 3414, "V9_0"),
+  (3415, 3415, "V1_1"), (3416, 3422, "V9_0"), (3423, 3423, "V8_0"),
+  (3424, 3425, "V1_1"), (3426, 3427, "V5_1"), (3430, 3439, "V1_1"),
+  (3440, 3445, "V5_1"), (3446, 3448, "V9_0"), (3449, 3455, "V5_1"),
+  (3458, 3459, "V3_0"), (3461, 3478, "V3_0"), (3482, 3505, "V3_0"),
+  (3507, 3515, "V3_0"), (3517, 3517, "V3_0"), (3520, 3526, "V3_0"),
+  (3530, 3530, "V3_0"), (3535, 3540, "V3_0"), (3542, 3542, "V3_0"),
+  (3544, 3551, "V3_0"), (3558, 3567, "V7_0"), (3570, 3572, "V3_0"),
+  (3585, 3642, "V1_1"), (3647, 3675, "V1_1"), (3713, 3714, "V1_1"),
+  (3716, 3716, "V1_1"), (3719, 3720, "V1_1"), (3722, 3722, "V1_1"),
+  (3725, 3725, "V1_1"), (3732, 3735, "V1_1"), (3737, 3743, "V1_1"),
+  (3745, 3747, "V1_1"), (3749, 3749, "V1_1"), (3751, 3751, "V1_1"),
+  (3754, 3755, "V1_1"), (3757, 3769, "V1_1"), (3771, 3773, "V1_1"),
+  (3776, 3780, "V1_1"), (3782, 3782, "V1_1"), (3784, 3789, "V1_1"),
+  (3792, 3801, "V1_1"), (3804, 3805, "V1_1"), (3806, 3807, "V6_1"),
+  (3840, 3911, "V2_0"), (3913, 3945, "V2_0"), (3946, 3946, "V3_0"),
+  (3947, 3948, "V5_1"), (3953, 3979, "V2_0"), (3980, 3983, "V6_0"),
+  (3984, 3989, "V2_0"), (3990, 3990, "V3_0"), (3991, 3991, "V2_0"),
+  (3993, 4013, "V2_0"), (4014, 4016, "V3_0"), (4017, 4023, "V2_0"),
+  (4024, 4024, "V3_0"), (4025, 4025, "V2_0"), (4026, 4028, "V3_0"),
+  (4030, 4044, "V3_0"), (4046, 4046, "V5_1"), (4047, 4047, "V3_0"),
+  (4048, 4049, "V4_1"), (4050, 4052, "V5_1"), (4053, 4056, "V5_2"),
+  (4057, 4058, "V6_0"), (4096, 4129, "V3_0"), (4130, 4130, "V5_1"),
+  (4131, 4135, "V3_0"), (4136, 4136, "V5_1"), (4137, 4138, "V3_0"),
+  (4139, 4139, "V5_1"), (4140, 4146, "V3_0"), (4147, 4149, "V5_1"),
+  (4150, 4153, "V3_0"), (4154, 4159, "V5_1"), (4160, 4185, "V3_0"),
+  (4186, 4249, "V5_1"), (4250, 4253, "V5_2"), (4254, 4255, "V5_1"),
+  (4256, 4293, "V1_1"), (4295, 4295, "V6_1"), (4301, 4301, "V6_1"),
+  (4304, 4342, "V1_1"), (4343, 4344, "V3_2"), (4345, 4346, "V4_1"),
+  (4347, 4347, "V1_1"), (4348, 4348, "V4_1"), (4349, 4351, "V6_1"),
+  (4352, 4441, "V1_1"), (4442, 4446, "V5_2"), (4447, 4514, "V1_1"),
+  (4515, 4519, "V5_2"), (4520, 4601, "V1_1"), (4602, 4607, "V5_2"),
+  (4608, 4614, "V3_0"), (4615, 4615, "V4_1"), (4616, 4678, "V3_0"),
+  (4679, 4679, "V4_1"), (4680, 4680, "V3_0"), (4682, 4685, "V3_0"),
+  (4688, 4694, "V3_0"), (4696, 4696, "V3_0"), (4698, 4701, "V3_0"),
+  (4704, 4742, "V3_0"), (4743, 4743, "V4_1"), (4744, 4744, "V3_0"),
+  (4746, 4749, "V3_0"), (4752, 4782, "V3_0"), (4783, 4783, "V4_1"),
+  (4784, 4784, "V3_0"), (4786, 4789, "V3_0"), (4792, 4798, "V3_0"),
+  (4800, 4800, "V3_0"), (4802, 4805, "V3_0"), (4808, 4814, "V3_0"),
+  (4815, 4815, "V4_1"), (4816, 4822, "V3_0"), (4824, 4846, "V3_0"),
+  (4847, 4847, "V4_1"), (4848, 4878, "V3_0"), (4879, 4879, "V4_1"),
+  (4880, 4880, "V3_0"), (4882, 4885, "V3_0"), (4888, 4894, "V3_0"),
+  (4895, 4895, "V4_1"), (4896, 4934, "V3_0"), (4935, 4935, "V4_1"),
+  (4936, 4954, "V3_0"), (4957, 4958, "V6_0"), (4959, 4960, "V4_1"),
+  (4961, 4988, "V3_0"), (4992, 5017, "V4_1"), (5024, 5108, "V3_0"),
+  (5109, 5109, "V8_0"), (5112, 5117, "V8_0"), (5120, 5120, "V5_2"),
+  (5121, 5750, "V3_0"), (5751, 5759, "V5_2"), (5760, 5788, "V3_0"),
+  (5792, 5872, "V3_0"), (5873, 5880, "V7_0"), (5888, 5900, "V3_2"),
+  (5902, 5908, "V3_2"), (5920, 5942, "V3_2"), (5952, 5971, "V3_2"),
+  (5984, 5996, "V3_2"), (5998, 6000, "V3_2"), (6002, 6003, "V3_2"),
+  (6016, 6108, "V3_0"), (6109, 6109, "V4_0"), (6112, 6121, "V3_0"),
+  (6128, 6137, "V4_0"), (6144, 6158, "V3_0"), (6160, 6169, "V3_0"),
+  (6176, 6263, "V3_0"), (6272, 6313, "V3_0"), (6314, 6314, "V5_1"),
+  (6320, 6389, "V5_2"), (6400, 6428, "V4_0"), (6429, 6430, "V7_0"),
+  (6432, 6443, "V4_0"), (6448, 6459, "V4_0"), (6464, 6464, "V4_0"),
+  (6468, 6509, "V4_0"), (6512, 6516, "V4_0"), (6528, 6569, "V4_1"),
+  (6570, 6571, "V5_2"), (6576, 6601, "V4_1"), (6608, 6617, "V4_1"),
+  (6618, 6618, "V5_2"), (6622, 6623, "V4_1"), (6624, 6655, "V4_0"),
+  (6656, 6683, "V4_1"), (6686, 6687, "V4_1"), (6688, 6750, "V5_2"),
+  (6752, 6780, "V5_2"), (6783, 6793, "V5_2"), (6800, 6809, "V5_2"),
+  (6816, 6829, "V5_2"), (6832, 6846, "V7_0"), (6912, 6987, "V5_0"),
+  (6992, 7036, "V5_0"), (7040, 7082, "V5_1"), (7083, 7085, "V6_1"),
+  (7086, 7097, "V5_1"), (7098, 7103, "V6_1"), (7104, 7155, "V6_0"),
+  (7164, 7167, "V6_0"), (7168, 7223, "V5_1"), (7227, 7241, "V5_1"),
+  (7245, 7295, "V5_1"), (7296, 7304, "V9_0"), (7360, 7367, "V6_1"),
+  (7376, 7410, "V5_2"), (7411, 7414, "V6_1"), (7416, 7417, "V7_0"),
+  (7424, 7531, "V4_0"), (7532, 7619, "V4_1"), (7620, 7626, "V5_0"),
+  (7627, 7654, "V5_1"), (7655, 7669, "V7_0"), (7675, 7675, "V9_0"),
+  (7676, 7676, "V6_0"), (7677, 7677, "V5_2"), (7678, 7679, "V5_0"),
+  (7680, 7834, "V1_1"), (7835, 7835, "V2_0"), (7836, 7839, "V5_1"),
+  (7840, 7929, "V1_1"), (7930, 7935, "V5_1"), (7936, 7957, "V1_1"),
+  (7960, 7965, "V1_1"), (7968, 8005, "V1_1"), (8008, 8013, "V1_1"),
+  (8016, 8023, "V1_1"), (8025, 8025, "V1_1"), (8027, 8027, "V1_1"),
+  (8029, 8029, "V1_1"), (8031, 8061, "V1_1"), (8064, 8116, "V1_1"),
+  (8118, 8132, "V1_1"), (8134, 8147, "V1_1"), (8150, 8155, "V1_1"),
+  (8157, 8175, "V1_1"), (8178, 8180, "V1_1"), (8182, 8190, "V1_1"),
+  (8192, 8238, "V1_1"), (8239, 8239, "V3_0"), (8240, 8262, "V1_1"),
+  (8263, 8263, "V3_2"), (8264, 8269, "V3_0"), (8270, 8274, "V3_2"),
+  (8275, 8276, "V4_0"), (8277, 8278, "V4_1"), (8279, 8279, "V3_2"),
+  (8280, 8286, "V4_1"), (8287, 8291, "V3_2"), (8292, 8292, "V5_1"),
+  (8294, 8297, "V6_3"), (8298, 8304, "V1_1"), (8305, 8305, "V3_2"),
+  (8308, 8334, "V1_1"), (8336, 8340, "V4_1"), (8341, 8348, "V6_0"),
+  (8352, 8362, "V1_1"), (8363, 8363, "V2_0"), (8364, 8364, "V2_1"),
+  (8365, 8367, "V3_0"), (8368, 8369, "V3_2"), (8370, 8373, "V4_1"),
+  (8374, 8376, "V5_2"), (8377, 8377, "V6_0"), (8378, 8378, "V6_2"),
+  (8379, 8381, "V7_0"), (8382, 8382, "V8_0"), (8400, 8417, "V1_1"),
+  (8418, 8419, "V3_0"), (8420, 8426, "V3_2"), (8427, 8427, "V4_1"),
+  (8428, 8431, "V5_0"), (8432, 8432, "V5_1"), (8448, 8504, "V1_1"),
+  (8505, 8506, "V3_0"), (8507, 8507, "V4_0"), (8508, 8508, "V4_1"),
+  (8509, 8523, "V3_2"), (8524, 8524, "V4_1"), (8525, 8526, "V5_0"),
+  (8527, 8527, "V5_1"), (8528, 8530, "V5_2"), (8531, 8578, "V1_1"),
+  (8579, 8579, "V3_0"), (8580, 8580, "V5_0"), (8581, 8584, "V5_1"),
+  (8585, 8585, "V5_2"), (8586, 8587, "V8_0"), (8592, 8682, "V1_1"),
+  (8683, 8691, "V3_0"), (8692, 8703, "V3_2"), (8704, 8945, "V1_1"),
+  (8946, 8959, "V3_2"), (8960, 8960, "V1_1"), (8961, 8961, "V3_0"),
+  (8962, 9082, "V1_1"), (9083, 9083, "V3_0"), (9084, 9084, "V3_2"),
+  (9085, 9114, "V3_0"), (9115, 9166, "V3_2"), (9167, 9168, "V4_0"),
+  (9169, 9179, "V4_1"), (9180, 9191, "V5_0"), (9192, 9192, "V5_2"),
+  (9193, 9203, "V6_0"), (9204, 9210, "V7_0"), (9211, 9214, "V9_0"),
+  (9216, 9252, "V1_1"), (9253, 9254, "V3_0"), (9280, 9290, "V1_1"),
+  (9312, 9450, "V1_1"), (9451, 9470, "V3_2"), (9471, 9471, "V4_0"),
+  (9472, 9621, "V1_1"), (9622, 9631, "V3_2"), (9632, 9711, "V1_1"),
+  (9712, 9719, "V3_0"), (9720, 9727, "V3_2"), (9728, 9747, "V1_1"),
+  (9748, 9749, "V4_0"), (9750, 9751, "V3_2"), (9752, 9752, "V4_1"),
+  (9753, 9753, "V3_0"), (9754, 9839, "V1_1"), (9840, 9841, "V3_0"),
+  (9842, 9853, "V3_2"), (9854, 9855, "V4_1"), (9856, 9865, "V3_2"),
+  (9866, 9873, "V4_0"), (9874, 9884, "V4_1"), (9885, 9885, "V5_1"),
+  (9886, 9887, "V5_2"), (9888, 9889, "V4_0"), (9890, 9905, "V4_1"),
+  (9906, 9906, "V5_0"), (9907, 9916, "V5_1"), (9917, 9919, "V5_2"),
+  (9920, 9923, "V5_1"), (9924, 9933, "V5_2"), (9934, 9934, "V6_0"),
+  (9935, 9953, "V5_2"), (9954, 9954, "V6_0"), (9955, 9955, "V5_2"),
+  (9956, 9959, "V6_0"), (9960, 9983, "V5_2"), (9984, 9984, "V7_0"),
+  (9985, 9988, "V1_1"), (9989, 9989, "V6_0"), (9990, 9993, "V1_1"),
+  (9994, 9995, "V6_0"), (9996, 10023, "V1_1"), (10024, 10024, "V6_0"),
+  (10025, 10059, "V1_1"), (10060, 10060, "V6_0"), (10061, 10061, "V1_1"),
+  (10062, 10062, "V6_0"), (10063, 10066, "V1_1"), (10067, 10069, "V6_0"),
+  (10070, 10070, "V1_1"), (10071, 10071, "V5_2"), (10072, 10078, "V1_1"),
+  (10079, 10080, "V6_0"), (10081, 10087, "V1_1"), (10088, 10101, "V3_2"),
+  (10102, 10132, "V1_1"), (10133, 10135, "V6_0"), (10136, 10159, "V1_1"),
+  (10160, 10160, "V6_0"), (10161, 10174, "V1_1"), (10175, 10175, "V6_0"),
+  (10176, 10182, "V4_1"), (10183, 10186, "V5_0"), (10187, 10187, "V6_1"),
+  (10188, 10188, "V5_1"), (10189, 10189, "V6_1"), (10190, 10191, "V6_0"),
+  (10192, 10219, "V3_2"), (10220, 10223, "V5_1"), (10224, 10239, "V3_2"),
+  (10240, 10495, "V3_0"), (10496, 11007, "V3_2"), (11008, 11021, "V4_0"),
+  (11022, 11027, "V4_1"), (11028, 11034, "V5_0"), (11035, 11039, "V5_1"),
+  (11040, 11043, "V5_0"), (11044, 11084, "V5_1"), (11085, 11087, "V7_0"),
+  (11088, 11092, "V5_1"), (11093, 11097, "V5_2"), (11098, 11123, "V7_0"),
+  (11126, 11157, "V7_0"), (11160, 11193, "V7_0"), (11197, 11208, "V7_0"),
+  (11210, 11217, "V7_0"), (11244, 11247, "V8_0"), (11264, 11310, "V4_1"),
+  (11312, 11358, "V4_1"), (11360, 11372, "V5_0"), (11373, 11375, "V5_1"),
+  (11376, 11376, "V5_2"), (11377, 11379, "V5_1"), (11380, 11383, "V5_0"),
+  (11384, 11389, "V5_1"), (11390, 11391, "V5_2"), (11392, 11498, "V4_1"),
+  (11499, 11505, "V5_2"), (11506, 11507, "V6_1"), (11513, 11557, "V4_1"),
+  (11559, 11559, "V6_1"), (11565, 11565, "V6_1"), (11568, 11621, "V4_1"),
+  (11622, 11623, "V6_1"), (11631, 11631, "V4_1"), (11632, 11632, "V6_0"),
+  (11647, 11647, "V6_0"), (11648, 11670, "V4_1"), (11680, 11686, "V4_1"),
+  (11688, 11694, "V4_1"), (11696, 11702, "V4_1"), (11704, 11710, "V4_1"),
+  (11712, 11718, "V4_1"), (11720, 11726, "V4_1"), (11728, 11734, "V4_1"),
+  (11736, 11742, "V4_1"), (11744, 11775, "V5_1"), (11776, 11799, "V4_1"),
+  (11800, 11803, "V5_1"), (11804, 11805, "V4_1"), (11806, 11824, "V5_1"),
+  (11825, 11825, "V5_2"), (11826, 11835, "V6_1"), (11836, 11842, "V7_0"),
+  (11843, 11844, "V9_0"), (11904, 11929, "V3_0"), (11931, 12019, "V3_0"),
+  (12032, 12245, "V3_0"), (12272, 12283, "V3_0"), (12288, 12343, "V1_1"),
+  (12344, 12346, "V3_0"), (12347, 12349, "V3_2"), (12350, 12350, "V3_0"),
+  (12351, 12351, "V1_1"), (12353, 12436, "V1_1"), (12437, 12438, "V3_2"),
+  (12441, 12446, "V1_1"), (12447, 12448, "V3_2"), (12449, 12542, "V1_1"),
+  (12543, 12543, "V3_2"), (12549, 12588, "V1_1"), (12589, 12589, "V5_1"),
+  (12593, 12686, "V1_1"), (12688, 12703, "V1_1"), (12704, 12727, "V3_0"),
+  (12728, 12730, "V6_0"), (12736, 12751, "V4_1"), (12752, 12771, "V5_1"),
+  (12784, 12799, "V3_2"), (12800, 12828, "V1_1"), (12829, 12830, "V4_0"),
+  (12832, 12867, "V1_1"), (12868, 12879, "V5_2"), (12880, 12880, "V4_0"),
+  (12881, 12895, "V3_2"), (12896, 12923, "V1_1"), (12924, 12925, "V4_0"),
+  (12926, 12926, "V4_1"), (12927, 12976, "V1_1"), (12977, 12991, "V3_2"),
+  (12992, 13003, "V1_1"), (13004, 13007, "V4_0"), (13008, 13054, "V1_1"),
+  (13056, 13174, "V1_1"), (13175, 13178, "V4_0"), (13179, 13277, "V1_1"),
+  (13278, 13279, "V4_0"), (13280, 13310, "V1_1"), (13311, 13311, "V4_0"),
+  (13312, 19893, "V3_0"), (19904, 19967, "V4_0"), (19968, 40869, "V1_1"),
+  (40870, 40891, "V4_1"), (40892, 40899, "V5_1"), (40900, 40907, "V5_2"),
+  (40908, 40908, "V6_1"), (40909, 40917, "V8_0"), (40960, 42124, "V3_0"),
+  (42128, 42145, "V3_0"), (42146, 42147, "V3_2"), (42148, 42163, "V3_0"),
+  (42164, 42164, "V3_2"), (42165, 42176, "V3_0"), (42177, 42177, "V3_2"),
+  (42178, 42180, "V3_0"), (42181, 42181, "V3_2"), (42182, 42182, "V3_0"),
+  (42192, 42239, "V5_2"), (42240, 42539, "V5_1"), (42560, 42591, "V5_1"),
+  (42592, 42593, "V6_0"), (42594, 42611, "V5_1"), (42612, 42619, "V6_1"),
+  (42620, 42647, "V5_1"), (42648, 42653, "V7_0"), (42654, 42654, "V8_0"),
+  (42655, 42655, "V6_1"), (42656, 42743, "V5_2"), (42752, 42774, "V4_1"),
+  (42775, 42778, "V5_0"), (42779, 42783, "V5_1"), (42784, 42785, "V5_0"),
+  (42786, 42892, "V5_1"), (42893, 42894, "V6_0"), (42895, 42895, "V8_0"),
+  (42896, 42897, "V6_0"), (42898, 42899, "V6_1"), (42900, 42911, "V7_0"),
+  (42912, 42921, "V6_0"), (42922, 42922, "V6_1"), (42923, 42925, "V7_0"),
+  (42926, 42926, "V9_0"), (42928, 42929, "V7_0"), (42930, 42935, "V8_0"),
+  (42999, 42999, "V7_0"), (43000, 43001, "V6_1"), (43002, 43002, "V6_0"),
+  (43003, 43007, "V5_1"), (43008, 43051, "V4_1"), (43056, 43065, "V5_2"),
+  (43072, 43127, "V5_0"), (43136, 43204, "V5_1"), (43205, 43205, "V9_0"),
+  (43214, 43225, "V5_1"), (43232, 43259, "V5_2"), (43260, 43261, "V8_0"),
+  (43264, 43347, "V5_1"), (43359, 43359, "V5_1"), (43360, 43388, "V5_2"),
+  (43392, 43469, "V5_2"), (43471, 43481, "V5_2"), (43486, 43487, "V5_2"),
+  (43488, 43518, "V7_0"), (43520, 43574, "V5_1"), (43584, 43597, "V5_1"),
+  (43600, 43609, "V5_1"), (43612, 43615, "V5_1"), (43616, 43643, "V5_2"),
+  (43644, 43647, "V7_0"), (43648, 43714, "V5_2"), (43739, 43743, "V5_2"),
+  (43744, 43766, "V6_1"), (43777, 43782, "V6_0"), (43785, 43790, "V6_0"),
+  (43793, 43798, "V6_0"), (43808, 43814, "V6_0"), (43816, 43822, "V6_0"),
+  (43824, 43871, "V7_0"), (43872, 43875, "V8_0"), (43876, 43877, "V7_0"),
+  (43888, 43967, "V8_0"), (43968, 44013, "V5_2"), (44016, 44025, "V5_2"),
+  (44032, 55203, "V2_0"), (55216, 55238, "V5_2"), (55243, 55291, "V5_2"),
+  (57344, 64045, "V1_1"), (64046, 64047, "V6_1"), (64048, 64106, "V3_2"),
+  (64107, 64109, "V5_2"), (64112, 64217, "V4_1"), (64256, 64262, "V1_1"),
+  (64275, 64279, "V1_1"), (64285, 64285, "V3_0"), (64286, 64310, "V1_1"),
+  (64312, 64316, "V1_1"), (64318, 64318, "V1_1"), (64320, 64321, "V1_1"),
+  (64323, 64324, "V1_1"), (64326, 64433, "V1_1"), (64434, 64449, "V6_0"),
+  (64467, 64831, "V1_1"), (64848, 64911, "V1_1"), (64914, 64967, "V1_1"),
+  (64976, 65007, "V3_1"), (65008, 65019, "V1_1"), (65020, 65020, "V3_2"),
+  (65021, 65021, "V4_0"), (65024, 65039, "V3_2"), (65040, 65049, "V4_1"),
+  (65056, 65059, "V1_1"), (65060, 65062, "V5_1"), (65063, 65069, "V7_0"),
+  (65070, 65071, "V8_0"), (65072, 65092, "V1_1"), (65093, 65094, "V3_2"),
+  (65095, 65096, "V4_0"), (65097, 65106, "V1_1"), (65108, 65126, "V1_1"),
+  (65128, 65131, "V1_1"), (65136, 65138, "V1_1"), (65139, 65139, "V3_2"),
+  (65140, 65140, "V1_1"), (65142, 65276, "V1_1"), (65279, 65279, "V1_1"),
+  (65281, 65374, "V1_1"), (65375, 65376, "V3_2"), (65377, 65470, "V1_1"),
+  (65474, 65479, "V1_1"), (65482, 65487, "V1_1"), (65490, 65495, "V1_1"),
+  (65498, 65500, "V1_1"), (65504, 65510, "V1_1"), (65512, 65518, "V1_1"),
+  (65529, 65531, "V3_0"), (65532, 65532, "V2_1"), (65533, 65535, "V1_1"),
+  (65536, 65547, "V4_0"), (65549, 65574, "V4_0"), (65576, 65594, "V4_0"),
+  (65596, 65597, "V4_0"), (65599, 65613, "V4_0"), (65616, 65629, "V4_0"),
+  (65664, 65786, "V4_0"), (65792, 65794, "V4_0"), (65799, 65843, "V4_0"),
+  (65847, 65855, "V4_0"), (65856, 65930, "V4_1"), (65931, 65932, "V7_0"),
+  (65933, 65934, "V9_0"), (65936, 65947, "V5_1"), (65952, 65952, "V7_0"),
+  (66000, 66045, "V5_1"), (66176, 66204, "V5_1"), (66208, 66256, "V5_1"),
+  (66272, 66299, "V7_0"), (66304, 66334, "V3_1"), (66335, 66335, "V7_0"),
+  (66336, 66339, "V3_1"), (66352, 66378, "V3_1"), (66384, 66426, "V7_0"),
+  (66432, 66461, "V4_0"), (66463, 66463, "V4_0"), (66464, 66499, "V4_1"),
+  (66504, 66517, "V4_1"), (66560, 66597, "V3_1"), (66598, 66599, "V4_0"),
+  (66600, 66637, "V3_1"), (66638, 66717, "V4_0"), (66720, 66729, "V4_0"),
+  (66736, 66771, "V9_0"), (66776, 66811, "V9_0"), (66816, 66855, "V7_0"),
+  (66864, 66915, "V7_0"), (66927, 66927, "V7_0"), (67072, 67382, "V7_0"),
+  (67392, 67413, "V7_0"), (67424, 67431, "V7_0"), (67584, 67589, "V4_0"),
+  (67592, 67592, "V4_0"), (67594, 67637, "V4_0"), (67639, 67640, "V4_0"),
+  (67644, 67644, "V4_0"), (67647, 67647, "V4_0"), (67648, 67669, "V5_2"),
+  (67671, 67679, "V5_2"), (67680, 67742, "V7_0"), (67751, 67759, "V7_0"),
+  (67808, 67826, "V8_0"), (67828, 67829, "V8_0"), (67835, 67839, "V8_0"),
+  (67840, 67865, "V5_0"), (67866, 67867, "V5_2"), (67871, 67871, "V5_0"),
+  (67872, 67897, "V5_1"), (67903, 67903, "V5_1"), (67968, 68023, "V6_1"),
+  (68028, 68029, "V8_0"), (68030, 68031, "V6_1"), (68032, 68047, "V8_0"),
+  (68050, 68095, "V8_0"), (68096, 68099, "V4_1"), (68101, 68102, "V4_1"),
+  (68108, 68115, "V4_1"), (68117, 68119, "V4_1"), (68121, 68147, "V4_1"),
+  (68152, 68154, "V4_1"), (68159, 68167, "V4_1"), (68176, 68184, "V4_1"),
+  (68192, 68223, "V5_2"), (68224, 68255, "V7_0"), (68288, 68326, "V7_0"),
+  (68331, 68342, "V7_0"), (68352, 68405, "V5_2"), (68409, 68437, "V5_2"),
+  (68440, 68466, "V5_2"), (68472, 68479, "V5_2"), (68480, 68497, "V7_0"),
+  (68505, 68508, "V7_0"), (68521, 68527, "V7_0"), (68608, 68680, "V5_2"),
+  (68736, 68786, "V8_0"), (68800, 68850, "V8_0"), (68858, 68863, "V8_0"),
+  (69216, 69246, "V5_2"), (69632, 69709, "V6_0"), (69714, 69743, "V6_0"),
+  (69759, 69759, "V7_0"), (69760, 69825, "V5_2"), (69840, 69864, "V6_1"),
+  (69872, 69881, "V6_1"), (69888, 69940, "V6_1"), (69942, 69955, "V6_1"),
+  (69968, 70006, "V7_0"), (70016, 70088, "V6_1"), (70089, 70092, "V8_0"),
+  (70093, 70093, "V7_0"), (70096, 70105, "V6_1"), (70106, 70106, "V7_0"),
+  (70107, 70111, "V8_0"), (70113, 70132, "V7_0"), (70144, 70161, "V7_0"),
+  (70163, 70205, "V7_0"), (70206, 70206, "V9_0"), (70272, 70278, "V8_0"),
+  (70280, 70280, "V8_0"), (70282, 70285, "V8_0"), (70287, 70301, "V8_0"),
+  (70303, 70313, "V8_0"), (70320, 70378, "V7_0"), (70384, 70393, "V7_0"),
+  (70400, 70400, "V8_0"), (70401, 70403, "V7_0"), (70405, 70412, "V7_0"),
+  (70415, 70416, "V7_0"), (70419, 70440, "V7_0"), (70442, 70448, "V7_0"),
+  (70450, 70451, "V7_0"), (70453, 70457, "V7_0"), (70460, 70468, "V7_0"),
+  (70471, 70472, "V7_0"), (70475, 70477, "V7_0"), (70480, 70480, "V8_0"),
+  (70487, 70487, "V7_0"), (70493, 70499, "V7_0"), (70502, 70508, "V7_0"),
+  (70512, 70516, "V7_0"), (70656, 70745, "V9_0"), (70747, 70747, "V9_0"),
+  (70749, 70749, "V9_0"), (70784, 70855, "V7_0"), (70864, 70873, "V7_0"),
+  (71040, 71093, "V7_0"), (71096, 71113, "V7_0"), (71114, 71133, "V8_0"),
+  (71168, 71236, "V7_0"), (71248, 71257, "V7_0"), (71264, 71276, "V9_0"),
+  (71296, 71351, "V6_1"), (71360, 71369, "V6_1"), (71424, 71449, "V8_0"),
+  (71453, 71467, "V8_0"), (71472, 71487, "V8_0"), (71840, 71922, "V7_0"),
+  (71935, 71935, "V7_0"), (72384, 72440, "V7_0"), (72704, 72712, "V9_0"),
+  (72714, 72758, "V9_0"), (72760, 72773, "V9_0"), (72784, 72812, "V9_0"),
+  (72816, 72847, "V9_0"), (72850, 72871, "V9_0"), (72873, 72886, "V9_0"),
+  (73728, 74606, "V5_0"), (74607, 74648, "V7_0"), (74649, 74649, "V8_0"),
+  (74752, 74850, "V5_0"), (74851, 74862, "V7_0"), (74864, 74867, "V5_0"),
+  (74868, 74868, "V7_0"), (74880, 75075, "V8_0"), (77824, 78894, "V5_2"),
+  (82944, 83526, "V8_0"), (92160, 92728, "V6_0"), (92736, 92766, "V7_0"),
+  (92768, 92777, "V7_0"), (92782, 92783, "V7_0"), (92880, 92909, "V7_0"),
+  (92912, 92917, "V7_0"), (92928, 92997, "V7_0"), (93008, 93017, "V7_0"),
+  (93019, 93025, "V7_0"), (93027, 93047, "V7_0"), (93053, 93071, "V7_0"),
+  (93952, 94020, "V6_1"), (94032, 94078, "V6_1"), (94095, 94111, "V6_1"),
+  (94176, 94176, "V9_0"), (94208, 100332, "V9_0"), (100352, 101106, "V9_0"),
+  (110592, 110593, "V6_0"), (113664, 113770, "V7_0"), (113776, 113788, "V7_0"),
+  (113792, 113800, "V7_0"), (113808, 113817, "V7_0"), (113820, 113827, "V7_0"),
+  (118784, 119029, "V3_1"), (119040, 119078, "V3_1"), (119081, 119081, "V5_1"),
+  (119082, 119261, "V3_1"), (119262, 119272, "V8_0"), (119296, 119365, "V4_1"),
+  (119552, 119638, "V4_0"), (119648, 119665, "V5_0"), (119808, 119892, "V3_1"),
+  (119894, 119964, "V3_1"), (119966, 119967, "V3_1"), (119970, 119970, "V3_1"),
+  (119973, 119974, "V3_1"), (119977, 119980, "V3_1"), (119982, 119993, "V3_1"),
+  (119995, 119995, "V3_1"), (119997, 120000, "V3_1"), (120001, 120001, "V4_0"),
+  (120002, 120003, "V3_1"), (120005, 120069, "V3_1"), (120071, 120074, "V3_1"),
+  (120077, 120084, "V3_1"), (120086, 120092, "V3_1"), (120094, 120121, "V3_1"),
+  (120123, 120126, "V3_1"), (120128, 120132, "V3_1"), (120134, 120134, "V3_1"),
+  (120138, 120144, "V3_1"), (120146, 120483, "V3_1"), (120484, 120485, "V4_1"),
+  (120488, 120777, "V3_1"), (120778, 120779, "V5_0"), (120782, 120831, "V3_1"),
+  (120832, 121483, "V8_0"), (121499, 121503, "V8_0"), (121505, 121519, "V8_0"),
+  (122880, 122886, "V9_0"), (122888, 122904, "V9_0"), (122907, 122913, "V9_0"),
+  (122915, 122916, "V9_0"), (122918, 122922, "V9_0"), (124928, 125124, "V7_0"),
+  (125127, 125142, "V7_0"), (125184, 125258, "V9_0"), (125264, 125273, "V9_0"),
+  (125278, 125279, "V9_0"), (126464, 126467, "V6_1"), (126469, 126495, "V6_1"),
+  (126497, 126498, "V6_1"), (126500, 126500, "V6_1"), (126503, 126503, "V6_1"),
+  (126505, 126514, "V6_1"), (126516, 126519, "V6_1"), (126521, 126521, "V6_1"),
+  (126523, 126523, "V6_1"), (126530, 126530, "V6_1"), (126535, 126535, "V6_1"),
+  (126537, 126537, "V6_1"), (126539, 126539, "V6_1"), (126541, 126543, "V6_1"),
+  (126545, 126546, "V6_1"), (126548, 126548, "V6_1"), (126551, 126551, "V6_1"),
+  (126553, 126553, "V6_1"), (126555, 126555, "V6_1"), (126557, 126557, "V6_1"),
+  (126559, 126559, "V6_1"), (126561, 126562, "V6_1"), (126564, 126564, "V6_1"),
+  (126567, 126570, "V6_1"), (126572, 126578, "V6_1"), (126580, 126583, "V6_1"),
+  (126585, 126588, "V6_1"), (126590, 126590, "V6_1"), (126592, 126601, "V6_1"),
+  (126603, 126619, "V6_1"), (126625, 126627, "V6_1"), (126629, 126633, "V6_1"),
+  (126635, 126651, "V6_1"), (126704, 126705, "V6_1"), (126976, 127019, "V5_1"),
+  (127024, 127123, "V5_1"), (127136, 127150, "V6_0"), (127153, 127166, "V6_0"),
+  (127167, 127167, "V7_0"), (127169, 127183, "V6_0"), (127185, 127199, "V6_0"),
+  (127200, 127221, "V7_0"), (127232, 127242, "V5_2"), (127243, 127244, "V7_0"),
+  (127248, 127278, "V5_2"), (127280, 127280, "V6_0"), (127281, 127281, "V5_2"),
+  (127282, 127292, "V6_0"), (127293, 127293, "V5_2"), (127294, 127294, "V6_0"),
+  (127295, 127295, "V5_2"), (127296, 127297, "V6_0"), (127298, 127298, "V5_2"),
+  (127299, 127301, "V6_0"), (127302, 127302, "V5_2"), (127303, 127305, "V6_0"),
+  (127306, 127310, "V5_2"), (127311, 127318, "V6_0"), (127319, 127319, "V5_2"),
+  (127320, 127326, "V6_0"), (127327, 127327, "V5_2"), (127328, 127337, "V6_0"),
+  (127338, 127339, "V6_1"), (127344, 127352, "V6_0"), (127353, 127353, "V5_2"),
+  (127354, 127354, "V6_0"), (127355, 127356, "V5_2"), (127357, 127358, "V6_0"),
+  (127359, 127359, "V5_2"), (127360, 127369, "V6_0"), (127370, 127373, "V5_2"),
+  (127374, 127375, "V6_0"), (127376, 127376, "V5_2"), (127377, 127386, "V6_0"),
+  (127387, 127404, "V9_0"), (127462, 127487, "V6_0"), (127488, 127488, "V5_2"),
+  (127489, 127490, "V6_0"), (127504, 127537, "V5_2"), (127538, 127546, "V6_0"),
+  (127547, 127547, "V9_0"), (127552, 127560, "V5_2"), (127568, 127569, "V6_0"),
+  (127744, 127776, "V6_0"), (127777, 127788, "V7_0"), (127789, 127791, "V8_0"),
+  (127792, 127797, "V6_0"), (127798, 127798, "V7_0"), (127799, 127868, "V6_0"),
+  (127869, 127869, "V7_0"), (127870, 127871, "V8_0"), (127872, 127891, "V6_0"),
+  (127892, 127903, "V7_0"), (127904, 127940, "V6_0"), (127941, 127941, "V7_0"),
+  (127942, 127946, "V6_0"), (127947, 127950, "V7_0"), (127951, 127955, "V8_0"),
+  (127956, 127967, "V7_0"), (127968, 127984, "V6_0"), (127985, 127991, "V7_0"),
+  (127992, 127999, "V8_0"), (128000, 128062, "V6_0"), (128063, 128063, "V7_0"),
+  (128064, 128064, "V6_0"), (128065, 128065, "V7_0"), (128066, 128247, "V6_0"),
+  (128248, 128248, "V7_0"), (128249, 128252, "V6_0"), (128253, 128254, "V7_0"),
+  (128255, 128255, "V8_0"), (128256, 128317, "V6_0"), (128318, 128319, "V7_0"),
+  (128320, 128323, "V6_1"), (128324, 128330, "V7_0"), (128331, 128335, "V8_0"),
+  (128336, 128359, "V6_0"), (128360, 128377, "V7_0"), (128378, 128378, "V9_0"),
+  (128379, 128419, "V7_0"), (128420, 128420, "V9_0"), (128421, 128506, "V7_0"),
+  (128507, 128511, "V6_0"), (128512, 128512, "V6_1"), (128513, 128528, "V6_0"),
+  (128529, 128529, "V6_1"), (128530, 128532, "V6_0"), (128533, 128533, "V6_1"),
+  (128534, 128534, "V6_0"), (128535, 128535, "V6_1"), (128536, 128536, "V6_0"),
+  (128537, 128537, "V6_1"), (128538, 128538, "V6_0"), (128539, 128539, "V6_1"),
+  (128540, 128542, "V6_0"), (128543, 128543, "V6_1"), (128544, 128549, "V6_0"),
+  (128550, 128551, "V6_1"), (128552, 128555, "V6_0"), (128556, 128556, "V6_1"),
+  (128557, 128557, "V6_0"), (128558, 128559, "V6_1"), (128560, 128563, "V6_0"),
+  (128564, 128564, "V6_1"), (128565, 128576, "V6_0"), (128577, 128578, "V7_0"),
+  (128579, 128580, "V8_0"), (128581, 128591, "V6_0"), (128592, 128639, "V7_0"),
+  (128640, 128709, "V6_0"), (128710, 128719, "V7_0"), (128720, 128720, "V8_0"),
+  (128721, 128722, "V9_0"), (128736, 128748, "V7_0"), (128752, 128755, "V7_0"),
+  (128756, 128758, "V9_0"), (128768, 128883, "V6_0"), (128896, 128980, "V7_0"),
+  (129024, 129035, "V7_0"), (129040, 129095, "V7_0"), (129104, 129113, "V7_0"),
+  (129120, 129159, "V7_0"), (129168, 129197, "V7_0"), (129296, 129304, "V8_0"),
+  (129305, 129310, "V9_0"), (129312, 129319, "V9_0"), (129328, 129328, "V9_0"),
+  (129331, 129342, "V9_0"), (129344, 129355, "V9_0"), (129360, 129374, "V9_0"),
+  (129408, 129412, "V8_0"), (129413, 129425, "V9_0"), (129472, 129472, "V8_0"),
+  (131070, 131071, "V2_0"), (131072, 173782, "V3_1"), (173824, 177972, "V5_2"),
+  (177984, 178205, "V6_0"), (178208, 183969, "V8_0"), (194560, 195101, "V3_1"),
+  (196606, 196607, "V2_0"), (262142, 262143, "V2_0"), (327678, 327679, "V2_0"),
+  (393214, 393215, "V2_0"), (458750, 458751, "V2_0"), (524286, 524287, "V2_0"),
+  (589822, 589823, "V2_0"), (655358, 655359, "V2_0"), (720894, 720895, "V2_0"),
+  (786430, 786431, "V2_0"), (851966, 851967, "V2_0"), (917502, 917503, "V2_0"),
+  (917505, 917505, "V3_1"), (917536, 917631, "V3_1"), (917760, 917999, "V4_0"),
+  (983038, 1114111, "V2_0"),
+];
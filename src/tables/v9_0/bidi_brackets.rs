@@ -0,0 +1,48 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//  ucd-generate bidi-brackets /home/andrew/tmp/ucd-9.0.0/
+//
+// ucd-generate is available on crates.io.
+
+pub const BIDI_BRACKETS: &'static [(u32, u32, &'static str)] = &[
+  (40, 41, "Open"), (41, 40, "Close"), (91, 93, "Open"), (93, 91, "Close"),
+  (123, 125, "Open"), (125, 123, "Close"), (3898, 3899, "Open"),
+  (3899, 3898, "Close"), (3900, 3901, "Open"), (3901, 3900, "Close"),
+  (5787, 5788, "Open"), (5788, 5787, "Close"), (8261, 8262, "Open"),
+  (8262, 8261, "Close"), (8317, 8318, "Open"), (8318, 8317, "Close"),
+  (8333, 8334, "Open"), (8334, 8333, "Close"), (8968, 8969, "Open"),
+  (8969, 8968, "Close"), (8970, 8971, "Open"), (8971, 8970, "Close"),
+  (9001, 9002, "Open"), (9002, 9001, "Close"), (10088, 10089, "Open"),
+  (10089, 10088, "Close"), (10090, 10091, "Open"), (10091, 10090, "Close"),
+  (10092, 10093, "Open"), (10093, 10092, "Close"), (10094, 10095, "Open"),
+  (10095, 10094, "Close"), (10096, 10097, "Open"), (10097, 10096, "Close"),
+  (10098, 10099, "Open"), (10099, 10098, "Close"), (10100, 10101, "Open"),
+  (10101, 10100, "Close"), (10181, 10182, "Open"), (10182, 10181, "Close"),
+  (10214, 10215, "Open"), (10215, 10214, "Close"), (10216, 10217, "Open"),
+  (10217, 10216, "Close"), (10218, 10219, "Open"), (10219, 10218, "Close"),
+  (10220, 10221, "Open"), (10221, 10220, "Close"), (10222, 10223, "Open"),
+  (10223, 10222, "Close"), (10627, 10628, "Open"), (10628, 10627, "Close"),
+  (10629, 10630, "Open"), (10630, 10629, "Close"), (10631, 10632, "Open"),
+  (10632, 10631, "Close"), (10633, 10634, "Open"), (10634, 10633, "Close"),
+  (10635, 10636, "Open"), (10636, 10635, "Close"), (10637, 10640, "Open"),
+  (10638, 10639, "Close"), (10639, 10638, "Open"), (10640, 10637, "Close"),
+  (10641, 10642, "Open"), (10642, 10641, "Close"), (10643, 10644, "Open"),
+  (10644, 10643, "Close"), (10645, 10646, "Open"), (10646, 10645, "Close"),
+  (10647, 10648, "Open"), (10648, 10647, "Close"), (10712, 10713, "Open"),
+  (10713, 10712, "Close"), (10714, 10715, "Open"), (10715, 10714, "Close"),
+  (10748, 10749, "Open"), (10749, 10748, "Close"), (11810, 11811, "Open"),
+  (11811, 11810, "Close"), (11812, 11813, "Open"), (11813, 11812, "Close"),
+  (11814, 11815, "Open"), (11815, 11814, "Close"), (11816, 11817, "Open"),
+  (11817, 11816, "Close"), (12296, 12297, "Open"), (12297, 12296, "Close"),
+  (12298, 12299, "Open"), (12299, 12298, "Close"), (12300, 12301, "Open"),
+  (12301, 12300, "Close"), (12302, 12303, "Open"), (12303, 12302, "Close"),
+  (12304, 12305, "Open"), (12305, 12304, "Close"), (12308, 12309, "Open"),
+  (12309, 12308, "Close"), (12310, 12311, "Open"), (12311, 12310, "Close"),
+  (12312, 12313, "Open"), (12313, 12312, "Close"), (12314, 12315, "Open"),
+  (12315, 12314, "Close"), (65113, 65114, "Open"), (65114, 65113, "Close"),
+  (65115, 65116, "Open"), (65116, 65115, "Close"), (65117, 65118, "Open"),
+  (65118, 65117, "Close"), (65288, 65289, "Open"), (65289, 65288, "Close"),
+  (65339, 65341, "Open"), (65341, 65339, "Close"), (65371, 65373, "Open"),
+  (65373, 65371, "Close"), (65375, 65376, "Open"), (65376, 65375, "Close"),
+  (65378, 65379, "Open"), (65379, 65378, "Close"),
+];
@@ -0,0 +1,863 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//  ucd-generate line-break /home/andrew/tmp/ucd-9.0.0/
+//
+// ucd-generate is available on crates.io.
+
+pub const LINE_BREAK: &'static [(u32, u32, &'static str)] = &[
+  (0, 8, "CM"), (9, 9, "BA"), (10, 10, "LF"), (11, 12, "BK"), (13, 13, "CR"),
+  (14, 31, "CM"), (32, 32, "SP"), (33, 33, "EX"), (34, 34, "QU"),
+  (35, 35, "AL"), (36, 36, "PR"), (37, 37, "PO"), (38, 38, "AL"),
+  (39, 39, "QU"), (40, 40, "OP"), (41, 41, "CP"), (42, 42, "AL"),
+  (43, 43, "PR"), (44, 44, "IS"), (45, 45, "HY"), (46, 46, "IS"),
+  (47, 47, "SY"), (48, 57, "NU"), (58, 59, "IS"), (60, 62, "AL"),
+  (63, 63, "EX"), (64, 90, "AL"), (91, 91, "OP"), (92, 92, "PR"),
+  (93, 93, "CP"), (94, 122, "AL"), (123, 123, "OP"), (124, 124, "BA"),
+  (125, 125, "CL"), (126, 126, "AL"), (127, 132, "CM"), (133, 133, "NL"),
+  (134, 159, "CM"), (160, 160, "GL"), (161, 161, "OP"), (162, 162, "PO"),
+  (163, 165, "PR"), (166, 166, "AL"), (167, 168, "AI"), (169, 169, "AL"),
+  (170, 170, "AI"), (171, 171, "QU"), (172, 172, "AL"), (173, 173, "BA"),
+  (174, 175, "AL"), (176, 176, "PO"), (177, 177, "PR"), (178, 179, "AI"),
+  (180, 180, "BB"), (181, 181, "AL"), (182, 186, "AI"), (187, 187, "QU"),
+  (188, 190, "AI"), (191, 191, "OP"), (192, 214, "AL"), (215, 215, "AI"),
+  (216, 246, "AL"), (247, 247, "AI"), (248, 710, "AL"), (711, 711, "AI"),
+  (712, 712, "BB"), (713, 715, "AI"), (716, 716, "BB"), (717, 717, "AI"),
+  (718, 719, "AL"), (720, 720, "AI"), (721, 727, "AL"), (728, 731, "AI"),
+  (732, 732, "AL"), (733, 733, "AI"), (734, 734, "AL"), (735, 735, "BB"),
+  (736, 767, "AL"), (768, 846, "CM"), (847, 847, "GL"), (848, 859, "CM"),
+  (860, 866, "GL"), (867, 879, "CM"), (880, 887, "AL"), (890, 893, "AL"),
+  (894, 894, "IS"), (895, 895, "AL"), (900, 906, "AL"), (908, 908, "AL"),
+  (910, 929, "AL"), (931, 1154, "AL"), (1155, 1161, "CM"), (1162, 1327, "AL"),
+  (1329, 1366, "AL"), (1369, 1375, "AL"), (1377, 1415, "AL"),
+  (1417, 1417, "IS"), (1418, 1418, "BA"), (1421, 1422, "AL"),
+  (1423, 1423, "PR"), (1425, 1469, "CM"), (1470, 1470, "BA"),
+  (1471, 1471, "CM"), (1472, 1472, "AL"), (1473, 1474, "CM"),
+  (1475, 1475, "AL"), (1476, 1477, "CM"), (1478, 1478, "EX"),
+  (1479, 1479, "CM"), (1488, 1514, "HL"), (1520, 1522, "HL"),
+  (1523, 1524, "AL"), (1536, 1544, "AL"), (1545, 1547, "PO"),
+  (1548, 1549, "IS"), (1550, 1551, "AL"), (1552, 1562, "CM"),
+  (1563, 1563, "EX"), (1564, 1564, "CM"), (1566, 1567, "EX"),
+  (1568, 1610, "AL"), (1611, 1631, "CM"), (1632, 1641, "NU"),
+  (1642, 1642, "PO"), (1643, 1644, "NU"), (1645, 1647, "AL"),
+  (1648, 1648, "CM"), (1649, 1747, "AL"), (1748, 1748, "EX"),
+  (1749, 1749, "AL"), (1750, 1756, "CM"), (1757, 1758, "AL"),
+  (1759, 1764, "CM"), (1765, 1766, "AL"), (1767, 1768, "CM"),
+  (1769, 1769, "AL"), (1770, 1773, "CM"), (1774, 1775, "AL"),
+  (1776, 1785, "NU"), (1786, 1805, "AL"), (1807, 1808, "AL"),
+  (1809, 1809, "CM"), (1810, 1839, "AL"), (1840, 1866, "CM"),
+  (1869, 1957, "AL"), (1958, 1968, "CM"), (1969, 1969, "AL"),
+  (1984, 1993, "NU"), (1994, 2026, "AL"), (2027, 2035, "CM"),
+  (2036, 2039, "AL"), (2040, 2040, "IS"), (2041, 2041, "EX"),
+  (2042, 2042, "AL"), (2048, 2069, "AL"), (2070, 2073, "CM"),
+  (2074, 2074, "AL"), (2075, 2083, "CM"), (2084, 2084, "AL"),
+  (2085, 2087, "CM"), (2088, 2088, "AL"), (2089, 2093, "CM"),
+  (2096, 2110, "AL"), (2112, 2136, "AL"), (2137, 2139, "CM"),
+  (2142, 2142, "AL"), (2208, 2228, "AL"), (2230, 2237, "AL"),
+  (2260, 2273, "CM"), (2274, 2274, "AL"), (2275, 2307, "CM"),
+  (2308, 2361, "AL"), (2362, 2364, "CM"), (2365, 2365, "AL"),
+  (2366, 2383, "CM"), (2384, 2384, "AL"), (2385, 2391, "CM"),
+  (2392, 2401, "AL"), (2402, 2403, "CM"), (2404, 2405, "BA"),
+  (2406, 2415, "NU"), (2416, 2432, "AL"), (2433, 2435, "CM"),
+  (2437, 2444, "AL"), (2447, 2448, "AL"), (2451, 2472, "AL"),
+  (2474, 2480, "AL"), (2482, 2482, "AL"), (2486, 2489, "AL"),
+  (2492, 2492, "CM"), (2493, 2493, "AL"), (2494, 2500, "CM"),
+  (2503, 2504, "CM"), (2507, 2509, "CM"), (2510, 2510, "AL"),
+  (2519, 2519, "CM"), (2524, 2525, "AL"), (2527, 2529, "AL"),
+  (2530, 2531, "CM"), (2534, 2543, "NU"), (2544, 2545, "AL"),
+  (2546, 2547, "PO"), (2548, 2552, "AL"), (2553, 2553, "PO"),
+  (2554, 2554, "AL"), (2555, 2555, "PR"), (2561, 2563, "CM"),
+  (2565, 2570, "AL"), (2575, 2576, "AL"), (2579, 2600, "AL"),
+  (2602, 2608, "AL"), (2610, 2611, "AL"), (2613, 2614, "AL"),
+  (2616, 2617, "AL"), (2620, 2620, "CM"), (2622, 2626, "CM"),
+  (2631, 2632, "CM"), (2635, 2637, "CM"), (2641, 2641, "CM"),
+  (2649, 2652, "AL"), (2654, 2654, "AL"), (2662, 2671, "NU"),
+  (2672, 2673, "CM"), (2674, 2676, "AL"), (2677, 2677, "CM"),
+  (2689, 2691, "CM"), (2693, 2701, "AL"), (2703, 2705, "AL"),
+  (2707, 2728, "AL"), (2730, 2736, "AL"), (2738, 2739, "AL"),
+  (2741, 2745, "AL"), (2748, 2748, "CM"), (2749, 2749, "AL"),
+  (2750, 2757, "CM"), (2759, 2761, "CM"), (2763, 2765, "CM"),
+  (2768, 2768, "AL"), (2784, 2785, "AL"), (2786, 2787, "CM"),
+  (2790, 2799, "NU"), (2800, 2800, "AL"), (2801, 2801, "PR"),
+  (2809, 2809, "AL"), (2817, 2819, "CM"), (2821, 2828, "AL"),
+  (2831, 2832, "AL"), (2835, 2856, "AL"), (2858, 2864, "AL"),
+  (2866, 2867, "AL"), (2869, 2873, "AL"), (2876, 2876, "CM"),
+  (2877, 2877, "AL"), (2878, 2884, "CM"), (2887, 2888, "CM"),
+  (2891, 2893, "CM"), (2902, 2903, "CM"), (2908, 2909, "AL"),
+  (2911, 2913, "AL"), (2914, 2915, "CM"), (2918, 2927, "NU"),
+  (2928, 2935, "AL"), (2946, 2946, "CM"), (2947, 2947, "AL"),
+  (2949, 2954, "AL"), (2958, 2960, "AL"), (2962, 2965, "AL"),
+  (2969, 2970, "AL"), (2972, 2972, "AL"), (2974, 2975, "AL"),
+  (2979, 2980, "AL"), (2984, 2986, "AL"), (2990, 3001, "AL"),
+  (3006, 3010, "CM"), (3014, 3016, "CM"), (3018, 3021, "CM"),
+  (3024, 3024, "AL"), (3031, 3031, "CM"), (3046, 3055, "NU"),
+  (3056, 3064, "AL"), (3065, 3065, "PR"), (3066, 3066, "AL"),
+  (3072, 3075, "CM"), (3077, 3084, "AL"), (3086, 3088, "AL"),
+  (3090, 3112, "AL"), (3114, 3129, "AL"), (3133, 3133, "AL"),
+  (3134, 3140, "CM"), (3142, 3144, "CM"), (3146, 3149, "CM"),
+  (3157, 3158, "CM"), (3160, 3162, "AL"), (3168, 3169, "AL"),
+  (3170, 3171, "CM"), (3174, 3183, "NU"), (3192, 3200, "AL"),
+  (3201, 3203, "CM"), (3205, 3212, "AL"), (3214, 3216, "AL"),
+  (3218, 3240, "AL"), (3242, 3251, "AL"), (3253, 3257, "AL"),
+  (3260, 3260, "CM"), (3261, 3261, "AL"), (3262, 3268, "CM"),
+  (3270, 3272, "CM"), (3274, 3277, "CM"), (3285, 3286, "CM"),
+  (3294, 3294, "AL"), (3296, 3297, "AL"), (3298, 3299, "CM"),
+  (3302, 3311, "NU"), (3313, 3314, "AL"), (3329, 3331, "CM"),
+  (3333, 3340, "AL"), (3342, 3344, "AL"), (3346, 3386, "AL"),
+  (3389, 3389, "AL"), (3390, 3396, "CM"), (3398, 3400, "CM"),
+  (3402, 3405, "CM"), (3406, 3407, "AL"), (3412, 3414, "AL"),
+  (3415, 3415, "CM"), (3416, 3425, "AL"), (3426, 3427, "CM"),
+  (3430, 3439, "NU"), (3440, 3448, "AL"), (3449, 3449, "PO"),
+  (3450, 3455, "AL"), (3458, 3459, "CM"), (3461, 3478, "AL"),
+  (3482, 3505, "AL"), (3507, 3515, "AL"), (3517, 3517, "AL"),
+  (3520, 3526, "AL"), (3530, 3530, "CM"), (3535, 3540, "CM"),
+  (3542, 3542, "CM"), (3544, 3551, "CM"), (3558, 3567, "NU"),
+  (3570, 3571, "CM"), (3572, 3572, "AL"), (3585, 3642, "SA"),
+  (3647, 3647, "PR"), (3648, 3662, "SA"), (3663, 3663, "AL"),
+  (3664, 3673, "NU"), (3674, 3675, "BA"), (3713, 3714, "SA"),
+  (3716, 3716, "SA"), (3719, 3720, "SA"), (3722, 3722, "SA"),
+  (3725, 3725, "SA"), (3732, 3735, "SA"), (3737, 3743, "SA"),
+  (3745, 3747, "SA"), (3749, 3749, "SA"), (3751, 3751, "SA"),
+  (3754, 3755, "SA"), (3757, 3769, "SA"), (3771, 3773, "SA"),
+  (3776, 3780, "SA"), (3782, 3782, "SA"), (3784, 3789, "SA"),
+  (3792, 3801, "NU"), (3804, 3807, "SA"), (3840, 3840, "AL"),
+  (3841, 3844, "BB"), (3845, 3845, "AL"), (3846, 3847, "BB"),
+  (3848, 3848, "GL"), (3849, 3850, "BB"), (3851, 3851, "BA"),
+  (3852, 3852, "GL"), (3853, 3857, "EX"), (3858, 3858, "GL"),
+  (3859, 3859, "AL"), (3860, 3860, "EX"), (3861, 3863, "AL"),
+  (3864, 3865, "CM"), (3866, 3871, "AL"), (3872, 3881, "NU"),
+  (3882, 3891, "AL"), (3892, 3892, "BA"), (3893, 3893, "CM"),
+  (3894, 3894, "AL"), (3895, 3895, "CM"), (3896, 3896, "AL"),
+  (3897, 3897, "CM"), (3898, 3898, "OP"), (3899, 3899, "CL"),
+  (3900, 3900, "OP"), (3901, 3901, "CL"), (3902, 3903, "CM"),
+  (3904, 3911, "AL"), (3913, 3948, "AL"), (3953, 3966, "CM"),
+  (3967, 3967, "BA"), (3968, 3972, "CM"), (3973, 3973, "BA"),
+  (3974, 3975, "CM"), (3976, 3980, "AL"), (3981, 3991, "CM"),
+  (3993, 4028, "CM"), (4030, 4031, "BA"), (4032, 4037, "AL"),
+  (4038, 4038, "CM"), (4039, 4044, "AL"), (4046, 4047, "AL"),
+  (4048, 4049, "BB"), (4050, 4050, "BA"), (4051, 4051, "BB"),
+  (4052, 4056, "AL"), (4057, 4058, "GL"), (4096, 4159, "SA"),
+  (4160, 4169, "NU"), (4170, 4171, "BA"), (4172, 4175, "AL"),
+  (4176, 4239, "SA"), (4240, 4249, "NU"), (4250, 4255, "SA"),
+  (4256, 4293, "AL"), (4295, 4295, "AL"), (4301, 4301, "AL"),
+  (4304, 4351, "AL"), (4352, 4447, "JL"), (4448, 4519, "JV"),
+  (4520, 4607, "JT"), (4608, 4680, "AL"), (4682, 4685, "AL"),
+  (4688, 4694, "AL"), (4696, 4696, "AL"), (4698, 4701, "AL"),
+  (4704, 4744, "AL"), (4746, 4749, "AL"), (4752, 4784, "AL"),
+  (4786, 4789, "AL"), (4792, 4798, "AL"), (4800, 4800, "AL"),
+  (4802, 4805, "AL"), (4808, 4822, "AL"), (4824, 4880, "AL"),
+  (4882, 4885, "AL"), (4888, 4954, "AL"), (4957, 4959, "CM"),
+  (4960, 4960, "AL"), (4961, 4961, "BA"), (4962, 4988, "AL"),
+  (4992, 5017, "AL"), (5024, 5109, "AL"), (5112, 5117, "AL"),
+  (5120, 5120, "BA"), (5121, 5759, "AL"), (5760, 5760, "BA"),
+  (5761, 5786, "AL"), (5787, 5787, "OP"), (5788, 5788, "CL"),
+  (5792, 5866, "AL"), (5867, 5869, "BA"), (5870, 5880, "AL"),
+  (5888, 5900, "AL"), (5902, 5905, "AL"), (5906, 5908, "CM"),
+  (5920, 5937, "AL"), (5938, 5940, "CM"), (5941, 5942, "BA"),
+  (5952, 5969, "AL"), (5970, 5971, "CM"), (5984, 5996, "AL"),
+  (5998, 6000, "AL"), (6002, 6003, "CM"), (6016, 6099, "SA"),
+  (6100, 6101, "BA"), (6102, 6102, "NS"), (6103, 6103, "SA"),
+  (6104, 6104, "BA"), (6105, 6105, "AL"), (6106, 6106, "BA"),
+  (6107, 6107, "PR"), (6108, 6109, "SA"), (6112, 6121, "NU"),
+  (6128, 6137, "AL"), (6144, 6145, "AL"), (6146, 6147, "EX"),
+  (6148, 6149, "BA"), (6150, 6150, "BB"), (6151, 6151, "AL"),
+  (6152, 6153, "EX"), (6154, 6154, "AL"), (6155, 6157, "CM"),
+  (6158, 6158, "GL"), (6160, 6169, "NU"), (6176, 6263, "AL"),
+  (6272, 6276, "AL"), (6277, 6278, "CM"), (6279, 6312, "AL"),
+  (6313, 6313, "CM"), (6314, 6314, "AL"), (6320, 6389, "AL"),
+  (6400, 6430, "AL"), (6432, 6443, "CM"), (6448, 6459, "CM"),
+  (6464, 6464, "AL"), (6468, 6469, "EX"), (6470, 6479, "NU"),
+  (6480, 6509, "SA"), (6512, 6516, "SA"), (6528, 6571, "SA"),
+  (6576, 6601, "SA"), (6608, 6617, "NU"), (6618, 6618, "SA"),
+  (6622, 6623, "SA"), (6624, 6678, "AL"), (6679, 6683, "CM"),
+  (6686, 6687, "AL"), (6688, 6750, "SA"), (6752, 6780, "SA"),
+  (6783, 6783, "CM"), (6784, 6793, "NU"), (6800, 6809, "NU"),
+  (6816, 6829, "SA"), (6832, 6846, "CM"), (6912, 6916, "CM"),
+  (6917, 6963, "AL"), (6964, 6980, "CM"), (6981, 6987, "AL"),
+  (6992, 7001, "NU"), (7002, 7003, "BA"), (7004, 7004, "AL"),
+  (7005, 7008, "BA"), (7009, 7018, "AL"), (7019, 7027, "CM"),
+  (7028, 7036, "AL"), (7040, 7042, "CM"), (7043, 7072, "AL"),
+  (7073, 7085, "CM"), (7086, 7087, "AL"), (7088, 7097, "NU"),
+  (7098, 7141, "AL"), (7142, 7155, "CM"), (7164, 7203, "AL"),
+  (7204, 7223, "CM"), (7227, 7231, "BA"), (7232, 7241, "NU"),
+  (7245, 7247, "AL"), (7248, 7257, "NU"), (7258, 7293, "AL"),
+  (7294, 7295, "BA"), (7296, 7304, "AL"), (7360, 7367, "AL"),
+  (7376, 7378, "CM"), (7379, 7379, "AL"), (7380, 7400, "CM"),
+  (7401, 7404, "AL"), (7405, 7405, "CM"), (7406, 7409, "AL"),
+  (7410, 7412, "CM"), (7413, 7414, "AL"), (7416, 7417, "CM"),
+  (7424, 7615, "AL"), (7616, 7669, "CM"), (7675, 7679, "CM"),
+  (7680, 7957, "AL"), (7960, 7965, "AL"), (7968, 8005, "AL"),
+  (8008, 8013, "AL"), (8016, 8023, "AL"), (8025, 8025, "AL"),
+  (8027, 8027, "AL"), (8029, 8029, "AL"), (8031, 8061, "AL"),
+  (8064, 8116, "AL"), (8118, 8132, "AL"), (8134, 8147, "AL"),
+  (8150, 8155, "AL"), (8157, 8175, "AL"), (8178, 8180, "AL"),
+  (8182, 8188, "AL"), (8189, 8189, "BB"), (8190, 8190, "AL"),
+  (8192, 8198, "BA"), (8199, 8199, "GL"), (8200, 8202, "BA"),
+  (8203, 8203, "ZW"), (8204, 8204, "CM"), (8205, 8205, "ZWJ"),
+  (8206, 8207, "CM"), (8208, 8208, "BA"), (8209, 8209, "GL"),
+  (8210, 8211, "BA"), (8212, 8212, "B2"), (8213, 8214, "AI"),
+  (8215, 8215, "AL"), (8216, 8217, "QU"), (8218, 8218, "OP"),
+  (8219, 8221, "QU"), (8222, 8222, "OP"), (8223, 8223, "QU"),
+  (8224, 8225, "AI"), (8226, 8227, "AL"), (8228, 8230, "IN"),
+  (8231, 8231, "BA"), (8232, 8233, "BK"), (8234, 8238, "CM"),
+  (8239, 8239, "GL"), (8240, 8247, "PO"), (8248, 8248, "AL"),
+  (8249, 8250, "QU"), (8251, 8251, "AI"), (8252, 8253, "NS"),
+  (8254, 8259, "AL"), (8260, 8260, "IS"), (8261, 8261, "OP"),
+  (8262, 8262, "CL"), (8263, 8265, "NS"), (8266, 8277, "AL"),
+  (8278, 8278, "BA"), (8279, 8279, "AL"), (8280, 8283, "BA"),
+  (8284, 8284, "AL"), (8285, 8287, "BA"), (8288, 8288, "WJ"),
+  (8289, 8292, "AL"), (8294, 8303, "CM"), (8304, 8305, "AL"),
+  (8308, 8308, "AI"), (8309, 8316, "AL"), (8317, 8317, "OP"),
+  (8318, 8318, "CL"), (8319, 8319, "AI"), (8320, 8320, "AL"),
+  (8321, 8324, "AI"), (8325, 8332, "AL"), (8333, 8333, "OP"),
+  (8334, 8334, "CL"), (8336, 8348, "AL"), (8352, 8358, "PR"),
+  (8359, 8359, "PO"), (8360, 8373, "PR"), (8374, 8374, "PO"),
+  (8375, 8378, "PR"), (8379, 8379, "PO"), (8380, 8381, "PR"),
+  (8382, 8382, "PO"), (8383, 8399, "PR"), (8400, 8432, "CM"),
+  (8448, 8450, "AL"), (8451, 8451, "PO"), (8452, 8452, "AL"),
+  (8453, 8453, "AI"), (8454, 8456, "AL"), (8457, 8457, "PO"),
+  (8458, 8466, "AL"), (8467, 8467, "AI"), (8468, 8469, "AL"),
+  (8470, 8470, "PR"), (8471, 8480, "AL"), (8481, 8482, "AI"),
+  (8483, 8490, "AL"), (8491, 8491, "AI"), (8492, 8531, "AL"),
+  (8532, 8533, "AI"), (8534, 8538, "AL"), (8539, 8539, "AI"),
+  (8540, 8541, "AL"), (8542, 8542, "AI"), (8543, 8543, "AL"),
+  (8544, 8555, "AI"), (8556, 8559, "AL"), (8560, 8569, "AI"),
+  (8570, 8584, "AL"), (8585, 8585, "AI"), (8586, 8587, "AL"),
+  (8592, 8601, "AI"), (8602, 8657, "AL"), (8658, 8658, "AI"),
+  (8659, 8659, "AL"), (8660, 8660, "AI"), (8661, 8703, "AL"),
+  (8704, 8704, "AI"), (8705, 8705, "AL"), (8706, 8707, "AI"),
+  (8708, 8710, "AL"), (8711, 8712, "AI"), (8713, 8714, "AL"),
+  (8715, 8715, "AI"), (8716, 8718, "AL"), (8719, 8719, "AI"),
+  (8720, 8720, "AL"), (8721, 8721, "AI"), (8722, 8723, "PR"),
+  (8724, 8724, "AL"), (8725, 8725, "AI"), (8726, 8729, "AL"),
+  (8730, 8730, "AI"), (8731, 8732, "AL"), (8733, 8736, "AI"),
+  (8737, 8738, "AL"), (8739, 8739, "AI"), (8740, 8740, "AL"),
+  (8741, 8741, "AI"), (8742, 8742, "AL"), (8743, 8748, "AI"),
+  (8749, 8749, "AL"), (8750, 8750, "AI"), (8751, 8755, "AL"),
+  (8756, 8759, "AI"), (8760, 8763, "AL"), (8764, 8765, "AI"),
+  (8766, 8775, "AL"), (8776, 8776, "AI"), (8777, 8779, "AL"),
+  (8780, 8780, "AI"), (8781, 8785, "AL"), (8786, 8786, "AI"),
+  (8787, 8799, "AL"), (8800, 8801, "AI"), (8802, 8803, "AL"),
+  (8804, 8807, "AI"), (8808, 8809, "AL"), (8810, 8811, "AI"),
+  (8812, 8813, "AL"), (8814, 8815, "AI"), (8816, 8833, "AL"),
+  (8834, 8835, "AI"), (8836, 8837, "AL"), (8838, 8839, "AI"),
+  (8840, 8852, "AL"), (8853, 8853, "AI"), (8854, 8856, "AL"),
+  (8857, 8857, "AI"), (8858, 8868, "AL"), (8869, 8869, "AI"),
+  (8870, 8894, "AL"), (8895, 8895, "AI"), (8896, 8942, "AL"),
+  (8943, 8943, "IN"), (8944, 8967, "AL"), (8968, 8968, "OP"),
+  (8969, 8969, "CL"), (8970, 8970, "OP"), (8971, 8971, "CL"),
+  (8972, 8977, "AL"), (8978, 8978, "AI"), (8979, 8985, "AL"),
+  (8986, 8987, "ID"), (8988, 9000, "AL"), (9001, 9001, "OP"),
+  (9002, 9002, "CL"), (9003, 9199, "AL"), (9200, 9203, "ID"),
+  (9204, 9214, "AL"), (9216, 9254, "AL"), (9280, 9290, "AL"),
+  (9312, 9470, "AI"), (9471, 9471, "AL"), (9472, 9547, "AI"),
+  (9548, 9551, "AL"), (9552, 9588, "AI"), (9589, 9599, "AL"),
+  (9600, 9615, "AI"), (9616, 9617, "AL"), (9618, 9621, "AI"),
+  (9622, 9631, "AL"), (9632, 9633, "AI"), (9634, 9634, "AL"),
+  (9635, 9641, "AI"), (9642, 9649, "AL"), (9650, 9651, "AI"),
+  (9652, 9653, "AL"), (9654, 9655, "AI"), (9656, 9659, "AL"),
+  (9660, 9661, "AI"), (9662, 9663, "AL"), (9664, 9665, "AI"),
+  (9666, 9669, "AL"), (9670, 9672, "AI"), (9673, 9674, "AL"),
+  (9675, 9675, "AI"), (9676, 9677, "AL"), (9678, 9681, "AI"),
+  (9682, 9697, "AL"), (9698, 9701, "AI"), (9702, 9710, "AL"),
+  (9711, 9711, "AI"), (9712, 9727, "AL"), (9728, 9731, "ID"),
+  (9732, 9732, "AL"), (9733, 9734, "AI"), (9735, 9736, "AL"),
+  (9737, 9737, "AI"), (9738, 9741, "AL"), (9742, 9743, "AI"),
+  (9744, 9747, "AL"), (9748, 9749, "ID"), (9750, 9751, "AI"),
+  (9752, 9752, "ID"), (9753, 9753, "AL"), (9754, 9756, "ID"),
+  (9757, 9757, "EB"), (9758, 9759, "ID"), (9760, 9784, "AL"),
+  (9785, 9787, "ID"), (9788, 9791, "AL"), (9792, 9792, "AI"),
+  (9793, 9793, "AL"), (9794, 9794, "AI"), (9795, 9823, "AL"),
+  (9824, 9825, "AI"), (9826, 9826, "AL"), (9827, 9829, "AI"),
+  (9830, 9830, "AL"), (9831, 9831, "AI"), (9832, 9832, "ID"),
+  (9833, 9834, "AI"), (9835, 9835, "AL"), (9836, 9837, "AI"),
+  (9838, 9838, "AL"), (9839, 9839, "AI"), (9840, 9854, "AL"),
+  (9855, 9855, "ID"), (9856, 9885, "AL"), (9886, 9887, "AI"),
+  (9888, 9916, "AL"), (9917, 9928, "ID"), (9929, 9932, "AI"),
+  (9933, 9933, "ID"), (9934, 9934, "AL"), (9935, 9937, "ID"),
+  (9938, 9938, "AI"), (9939, 9940, "ID"), (9941, 9943, "AI"),
+  (9944, 9945, "ID"), (9946, 9947, "AI"), (9948, 9948, "ID"),
+  (9949, 9950, "AI"), (9951, 9953, "ID"), (9954, 9954, "AL"),
+  (9955, 9955, "AI"), (9956, 9959, "AL"), (9960, 9961, "AI"),
+  (9962, 9962, "ID"), (9963, 9968, "AI"), (9969, 9973, "ID"),
+  (9974, 9974, "AI"), (9975, 9976, "ID"), (9977, 9977, "EB"),
+  (9978, 9978, "ID"), (9979, 9980, "AI"), (9981, 9988, "ID"),
+  (9989, 9991, "AL"), (9992, 9993, "ID"), (9994, 9997, "EB"),
+  (9998, 10070, "AL"), (10071, 10071, "AI"), (10072, 10074, "AL"),
+  (10075, 10080, "QU"), (10081, 10081, "AL"), (10082, 10083, "EX"),
+  (10084, 10084, "ID"), (10085, 10087, "AL"), (10088, 10088, "OP"),
+  (10089, 10089, "CL"), (10090, 10090, "OP"), (10091, 10091, "CL"),
+  (10092, 10092, "OP"), (10093, 10093, "CL"), (10094, 10094, "OP"),
+  (10095, 10095, "CL"), (10096, 10096, "OP"), (10097, 10097, "CL"),
+  (10098, 10098, "OP"), (10099, 10099, "CL"), (10100, 10100, "OP"),
+  (10101, 10101, "CL"), (10102, 10131, "AI"), (10132, 10180, "AL"),
+  (10181, 10181, "OP"), (10182, 10182, "CL"), (10183, 10213, "AL"),
+  (10214, 10214, "OP"), (10215, 10215, "CL"), (10216, 10216, "OP"),
+  (10217, 10217, "CL"), (10218, 10218, "OP"), (10219, 10219, "CL"),
+  (10220, 10220, "OP"), (10221, 10221, "CL"), (10222, 10222, "OP"),
+  (10223, 10223, "CL"), (10224, 10626, "AL"), (10627, 10627, "OP"),
+  (10628, 10628, "CL"), (10629, 10629, "OP"), (10630, 10630, "CL"),
+  (10631, 10631, "OP"), (10632, 10632, "CL"), (10633, 10633, "OP"),
+  (10634, 10634, "CL"), (10635, 10635, "OP"), (10636, 10636, "CL"),
+  (10637, 10637, "OP"), (10638, 10638, "CL"), (10639, 10639, "OP"),
+  (10640, 10640, "CL"), (10641, 10641, "OP"), (10642, 10642, "CL"),
+  (10643, 10643, "OP"), (10644, 10644, "CL"), (10645, 10645, "OP"),
+  (10646, 10646, "CL"), (10647, 10647, "OP"), (10648, 10648, "CL"),
+  (10649, 10711, "AL"), (10712, 10712, "OP"), (10713, 10713, "CL"),
+  (10714, 10714, "OP"), (10715, 10715, "CL"), (10716, 10747, "AL"),
+  (10748, 10748, "OP"), (10749, 10749, "CL"), (10750, 11092, "AL"),
+  (11093, 11097, "AI"), (11098, 11123, "AL"), (11126, 11157, "AL"),
+  (11160, 11193, "AL"), (11197, 11208, "AL"), (11210, 11217, "AL"),
+  (11244, 11247, "AL"), (11264, 11310, "AL"), (11312, 11358, "AL"),
+  (11360, 11502, "AL"), (11503, 11505, "CM"), (11506, 11507, "AL"),
+  (11513, 11513, "EX"), (11514, 11516, "BA"), (11517, 11517, "AL"),
+  (11518, 11518, "EX"), (11519, 11519, "BA"), (11520, 11557, "AL"),
+  (11559, 11559, "AL"), (11565, 11565, "AL"), (11568, 11623, "AL"),
+  (11631, 11631, "AL"), (11632, 11632, "BA"), (11647, 11647, "CM"),
+  (11648, 11670, "AL"), (11680, 11686, "AL"), (11688, 11694, "AL"),
+  (11696, 11702, "AL"), (11704, 11710, "AL"), (11712, 11718, "AL"),
+  (11720, 11726, "AL"), (11728, 11734, "AL"), (11736, 11742, "AL"),
+  (11744, 11775, "CM"), (11776, 11789, "QU"), (11790, 11797, "BA"),
+  (11798, 11798, "AL"), (11799, 11799, "BA"), (11800, 11800, "OP"),
+  (11801, 11801, "BA"), (11802, 11803, "AL"), (11804, 11805, "QU"),
+  (11806, 11807, "AL"), (11808, 11809, "QU"), (11810, 11810, "OP"),
+  (11811, 11811, "CL"), (11812, 11812, "OP"), (11813, 11813, "CL"),
+  (11814, 11814, "OP"), (11815, 11815, "CL"), (11816, 11816, "OP"),
+  (11817, 11817, "CL"), (11818, 11821, "BA"), (11822, 11822, "EX"),
+  (11823, 11823, "AL"), (11824, 11825, "BA"), (11826, 11826, "AL"),
+  (11827, 11828, "BA"), (11829, 11833, "AL"), (11834, 11835, "B2"),
+  (11836, 11838, "BA"), (11839, 11839, "AL"), (11840, 11841, "BA"),
+  (11842, 11842, "OP"), (11843, 11844, "BA"), (11904, 11929, "ID"),
+  (11931, 12019, "ID"), (12032, 12245, "ID"), (12272, 12283, "ID"),
+  (12288, 12288, "BA"), (12289, 12290, "CL"), (12291, 12292, "ID"),
+  (12293, 12293, "NS"), (12294, 12295, "ID"), (12296, 12296, "OP"),
+  (12297, 12297, "CL"), (12298, 12298, "OP"), (12299, 12299, "CL"),
+  (12300, 12300, "OP"), (12301, 12301, "CL"), (12302, 12302, "OP"),
+  (12303, 12303, "CL"), (12304, 12304, "OP"), (12305, 12305, "CL"),
+  (12306, 12307, "ID"), (12308, 12308, "OP"), (12309, 12309, "CL"),
+  (12310, 12310, "OP"), (12311, 12311, "CL"), (12312, 12312, "OP"),
+  (12313, 12313, "CL"), (12314, 12314, "OP"), (12315, 12315, "CL"),
+  (12316, 12316, "NS"), (12317, 12317, "OP"), (12318, 12319, "CL"),
+  (12320, 12329, "ID"), (12330, 12335, "CM"), (12336, 12340, "ID"),
+  (12341, 12341, "CM"), (12342, 12346, "ID"), (12347, 12348, "NS"),
+  (12349, 12351, "ID"), (12353, 12353, "CJ"), (12354, 12354, "ID"),
+  (12355, 12355, "CJ"), (12356, 12356, "ID"), (12357, 12357, "CJ"),
+  (12358, 12358, "ID"), (12359, 12359, "CJ"), (12360, 12360, "ID"),
+  (12361, 12361, "CJ"), (12362, 12386, "ID"), (12387, 12387, "CJ"),
+  (12388, 12418, "ID"), (12419, 12419, "CJ"), (12420, 12420, "ID"),
+  (12421, 12421, "CJ"), (12422, 12422, "ID"), (12423, 12423, "CJ"),
+  (12424, 12429, "ID"), (12430, 12430, "CJ"), (12431, 12436, "ID"),
+  (12437, 12438, "CJ"), (12441, 12442, "CM"), (12443, 12446, "NS"),
+  (12447, 12447, "ID"), (12448, 12448, "NS"), (12449, 12449, "CJ"),
+  (12450, 12450, "ID"), (12451, 12451, "CJ"), (12452, 12452, "ID"),
+  (12453, 12453, "CJ"), (12454, 12454, "ID"), (12455, 12455, "CJ"),
+  (12456, 12456, "ID"), (12457, 12457, "CJ"), (12458, 12482, "ID"),
+  (12483, 12483, "CJ"), (12484, 12514, "ID"), (12515, 12515, "CJ"),
+  (12516, 12516, "ID"), (12517, 12517, "CJ"), (12518, 12518, "ID"),
+  (12519, 12519, "CJ"), (12520, 12525, "ID"), (12526, 12526, "CJ"),
+  (12527, 12532, "ID"), (12533, 12534, "CJ"), (12535, 12538, "ID"),
+  (12539, 12539, "NS"), (12540, 12540, "CJ"), (12541, 12542, "NS"),
+  (12543, 12543, "ID"), (12549, 12589, "ID"), (12593, 12686, "ID"),
+  (12688, 12730, "ID"), (12736, 12771, "ID"), (12784, 12799, "CJ"),
+  (12800, 12830, "ID"), (12832, 12871, "ID"), (12872, 12879, "AI"),
+  (12880, 13054, "ID"), (13056, 19903, "ID"), (19904, 19967, "AL"),
+  (19968, 40980, "ID"), (40981, 40981, "NS"), (40982, 42124, "ID"),
+  (42128, 42182, "ID"), (42192, 42237, "AL"), (42238, 42239, "BA"),
+  (42240, 42508, "AL"), (42509, 42509, "BA"), (42510, 42510, "EX"),
+  (42511, 42511, "BA"), (42512, 42527, "AL"), (42528, 42537, "NU"),
+  (42538, 42539, "AL"), (42560, 42606, "AL"), (42607, 42610, "CM"),
+  (42611, 42611, "AL"), (42612, 42621, "CM"), (42622, 42653, "AL"),
+  (42654, 42655, "CM"), (42656, 42735, "AL"), (42736, 42737, "CM"),
+  (42738, 42738, "AL"), (42739, 42743, "BA"), (42752, 42926, "AL"),
+  (42928, 42935, "AL"), (42999, 43009, "AL"), (43010, 43010, "CM"),
+  (43011, 43013, "AL"), (43014, 43014, "CM"), (43015, 43018, "AL"),
+  (43019, 43019, "CM"), (43020, 43042, "AL"), (43043, 43047, "CM"),
+  (43048, 43051, "AL"), (43056, 43063, "AL"), (43064, 43064, "PO"),
+  (43065, 43065, "AL"), (43072, 43123, "AL"), (43124, 43125, "BB"),
+  (43126, 43127, "EX"), (43136, 43137, "CM"), (43138, 43187, "AL"),
+  (43188, 43205, "CM"), (43214, 43215, "BA"), (43216, 43225, "NU"),
+  (43232, 43249, "CM"), (43250, 43259, "AL"), (43260, 43260, "BB"),
+  (43261, 43261, "AL"), (43264, 43273, "NU"), (43274, 43301, "AL"),
+  (43302, 43309, "CM"), (43310, 43311, "BA"), (43312, 43334, "AL"),
+  (43335, 43347, "CM"), (43359, 43359, "AL"), (43360, 43388, "JL"),
+  (43392, 43395, "CM"), (43396, 43442, "AL"), (43443, 43456, "CM"),
+  (43457, 43462, "AL"), (43463, 43465, "BA"), (43466, 43469, "AL"),
+  (43471, 43471, "AL"), (43472, 43481, "NU"), (43486, 43487, "AL"),
+  (43488, 43503, "SA"), (43504, 43513, "NU"), (43514, 43518, "SA"),
+  (43520, 43560, "AL"), (43561, 43574, "CM"), (43584, 43586, "AL"),
+  (43587, 43587, "CM"), (43588, 43595, "AL"), (43596, 43597, "CM"),
+  (43600, 43609, "NU"), (43612, 43612, "AL"), (43613, 43615, "BA"),
+  (43616, 43714, "SA"), (43739, 43743, "SA"), (43744, 43754, "AL"),
+  (43755, 43759, "CM"), (43760, 43761, "BA"), (43762, 43764, "AL"),
+  (43765, 43766, "CM"), (43777, 43782, "AL"), (43785, 43790, "AL"),
+  (43793, 43798, "AL"), (43808, 43814, "AL"), (43816, 43822, "AL"),
+  (43824, 43877, "AL"), (43888, 44002, "AL"), (44003, 44010, "CM"),
+  (44011, 44011, "BA"), (44012, 44013, "CM"), (44016, 44025, "NU"),
+  (44032, 44032, "H2"), (44033, 44059, "H3"), (44060, 44060, "H2"),
+  (44061, 44087, "H3"), (44088, 44088, "H2"), (44089, 44115, "H3"),
+  (44116, 44116, "H2"), (44117, 44143, "H3"), (44144, 44144, "H2"),
+  (44145, 44171, "H3"), (44172, 44172, "H2"), (44173, 44199, "H3"),
+  (44200, 44200, "H2"), (44201, 44227, "H3"), (44228, 44228, "H2"),
+  (44229, 44255, "H3"), (44256, 44256, "H2"), (44257, 44283, "H3"),
+  (44284, 44284, "H2"), (44285, 44311, "H3"), (44312, 44312, "H2"),
+  (44313, 44339, "H3"), (44340, 44340, "H2"), (44341, 44367, "H3"),
+  (44368, 44368, "H2"), (44369, 44395, "H3"), (44396, 44396, "H2"),
+  (44397, 44423, "H3"), (44424, 44424, "H2"), (44425, 44451, "H3"),
+  (44452, 44452, "H2"), (44453, 44479, "H3"), (44480, 44480, "H2"),
+  (44481, 44507, "H3"), (44508, 44508, "H2"), (44509, 44535, "H3"),
+  (44536, 44536, "H2"), (44537, 44563, "H3"), (44564, 44564, "H2"),
+  (44565, 44591, "H3"), (44592, 44592, "H2"), (44593, 44619, "H3"),
+  (44620, 44620, "H2"), (44621, 44647, "H3"), (44648, 44648, "H2"),
+  (44649, 44675, "H3"), (44676, 44676, "H2"), (44677, 44703, "H3"),
+  (44704, 44704, "H2"), (44705, 44731, "H3"), (44732, 44732, "H2"),
+  (44733, 44759, "H3"), (44760, 44760, "H2"), (44761, 44787, "H3"),
+  (44788, 44788, "H2"), (44789, 44815, "H3"), (44816, 44816, "H2"),
+  (44817, 44843, "H3"), (44844, 44844, "H2"), (44845, 44871, "H3"),
+  (44872, 44872, "H2"), (44873, 44899, "H3"), (44900, 44900, "H2"),
+  (44901, 44927, "H3"), (44928, 44928, "H2"), (44929, 44955, "H3"),
+  (44956, 44956, "H2"), (44957, 44983, "H3"), (44984, 44984, "H2"),
+  (44985, 45011, "H3"), (45012, 45012, "H2"), (45013, 45039, "H3"),
+  (45040, 45040, "H2"), (45041, 45067, "H3"), (45068, 45068, "H2"),
+  (45069, 45095, "H3"), (45096, 45096, "H2"), (45097, 45123, "H3"),
+  (45124, 45124, "H2"), (45125, 45151, "H3"), (45152, 45152, "H2"),
+  (45153, 45179, "H3"), (45180, 45180, "H2"), (45181, 45207, "H3"),
+  (45208, 45208, "H2"), (45209, 45235, "H3"), (45236, 45236, "H2"),
+  (45237, 45263, "H3"), (45264, 45264, "H2"), (45265, 45291, "H3"),
+  (45292, 45292, "H2"), (45293, 45319, "H3"), (45320, 45320, "H2"),
+  (45321, 45347, "H3"), (45348, 45348, "H2"), (45349, 45375, "H3"),
+  (45376, 45376, "H2"), (45377, 45403, "H3"), (45404, 45404, "H2"),
+  (45405, 45431, "H3"), (45432, 45432, "H2"), (45433, 45459, "H3"),
+  (45460, 45460, "H2"), (45461, 45487, "H3"), (45488, 45488, "H2"),
+  (45489, 45515, "H3"), (45516, 45516, "H2"), (45517, 45543, "H3"),
+  (45544, 45544, "H2"), (45545, 45571, "H3"), (45572, 45572, "H2"),
+  (45573, 45599, "H3"), (45600, 45600, "H2"), (45601, 45627, "H3"),
+  (45628, 45628, "H2"), (45629, 45655, "H3"), (45656, 45656, "H2"),
+  (45657, 45683, "H3"), (45684, 45684, "H2"), (45685, 45711, "H3"),
+  (45712, 45712, "H2"), (45713, 45739, "H3"), (45740, 45740, "H2"),
+  (45741, 45767, "H3"), (45768, 45768, "H2"), (45769, 45795, "H3"),
+  (45796, 45796, "H2"), (45797, 45823, "H3"), (45824, 45824, "H2"),
+  (45825, 45851, "H3"), (45852, 45852, "H2"), (45853, 45879, "H3"),
+  (45880, 45880, "H2"), (45881, 45907, "H3"), (45908, 45908, "H2"),
+  (45909, 45935, "H3"), (45936, 45936, "H2"), (45937, 45963, "H3"),
+  (45964, 45964, "H2"), (45965, 45991, "H3"), (45992, 45992, "H2"),
+  (45993, 46019, "H3"), (46020, 46020, "H2"), (46021, 46047, "H3"),
+  (46048, 46048, "H2"), (46049, 46075, "H3"), (46076, 46076, "H2"),
+  (46077, 46103, "H3"), (46104, 46104, "H2"), (46105, 46131, "H3"),
+  (46132, 46132, "H2"), (46133, 46159, "H3"), (46160, 46160, "H2"),
+  (46161, 46187, "H3"), (46188, 46188, "H2"), (46189, 46215, "H3"),
+  (46216, 46216, "H2"), (46217, 46243, "H3"), (46244, 46244, "H2"),
+  (46245, 46271, "H3"), (46272, 46272, "H2"), (46273, 46299, "H3"),
+  (46300, 46300, "H2"), (46301, 46327, "H3"), (46328, 46328, "H2"),
+  (46329, 46355, "H3"), (46356, 46356, "H2"), (46357, 46383, "H3"),
+  (46384, 46384, "H2"), (46385, 46411, "H3"), (46412, 46412, "H2"),
+  (46413, 46439, "H3"), (46440, 46440, "H2"), (46441, 46467, "H3"),
+  (46468, 46468, "H2"), (46469, 46495, "H3"), (46496, 46496, "H2"),
+  (46497, 46523, "H3"), (46524, 46524, "H2"), (46525, 46551, "H3"),
+  (46552, 46552, "H2"), (46553, 46579, "H3"), (46580, 46580, "H2"),
+  (46581, 46607, "H3"), (46608, 46608, "H2"), (46609, 46635, "H3"),
+  (46636, 46636, "H2"), (46637, 46663, "H3"), (46664, 46664, "H2"),
+  (46665, 46691, "H3"), (46692, 46692, "H2"), (46693, 46719, "H3"),
+  (46720, 46720, "H2"), (46721, 46747, "H3"), (46748, 46748, "H2"),
+  (46749, 46775, "H3"), (46776, 46776, "H2"), (46777, 46803, "H3"),
+  (46804, 46804, "H2"), (46805, 46831, "H3"), (46832, 46832, "H2"),
+  (46833, 46859, "H3"), (46860, 46860, "H2"), (46861, 46887, "H3"),
+  (46888, 46888, "H2"), (46889, 46915, "H3"), (46916, 46916, "H2"),
+  (46917, 46943, "H3"), (46944, 46944, "H2"), (46945, 46971, "H3"),
+  (46972, 46972, "H2"), (46973, 46999, "H3"), (47000, 47000, "H2"),
+  (47001, 47027, "H3"), (47028, 47028, "H2"), (47029, 47055, "H3"),
+  (47056, 47056, "H2"), (47057, 47083, "H3"), (47084, 47084, "H2"),
+  (47085, 47111, "H3"), (47112, 47112, "H2"), (47113, 47139, "H3"),
+  (47140, 47140, "H2"), (47141, 47167, "H3"), (47168, 47168, "H2"),
+  (47169, 47195, "H3"), (47196, 47196, "H2"), (47197, 47223, "H3"),
+  (47224, 47224, "H2"), (47225, 47251, "H3"), (47252, 47252, "H2"),
+  (47253, 47279, "H3"), (47280, 47280, "H2"), (47281, 47307, "H3"),
+  (47308, 47308, "H2"), (47309, 47335, "H3"), (47336, 47336, "H2"),
+  (47337, 47363, "H3"), (47364, 47364, "H2"), (47365, 47391, "H3"),
+  (47392, 47392, "H2"), (47393, 47419, "H3"), (47420, 47420, "H2"),
+  (47421, 47447, "H3"), (47448, 47448, "H2"), (47449, 47475, "H3"),
+  (47476, 47476, "H2"), (47477, 47503, "H3"), (47504, 47504, "H2"),
+  (47505, 47531, "H3"), (47532, 47532, "H2"), (47533, 47559, "H3"),
+  (47560, 47560, "H2"), (47561, 47587, "H3"), (47588, 47588, "H2"),
+  (47589, 47615, "H3"), (47616, 47616, "H2"), (47617, 47643, "H3"),
+  (47644, 47644, "H2"), (47645, 47671, "H3"), (47672, 47672, "H2"),
+  (47673, 47699, "H3"), (47700, 47700, "H2"), (47701, 47727, "H3"),
+  (47728, 47728, "H2"), (47729, 47755, "H3"), (47756, 47756, "H2"),
+  (47757, 47783, "H3"), (47784, 47784, "H2"), (47785, 47811, "H3"),
+  (47812, 47812, "H2"), (47813, 47839, "H3"), (47840, 47840, "H2"),
+  (47841, 47867, "H3"), (47868, 47868, "H2"), (47869, 47895, "H3"),
+  (47896, 47896, "H2"), (47897, 47923, "H3"), (47924, 47924, "H2"),
+  (47925, 47951, "H3"), (47952, 47952, "H2"), (47953, 47979, "H3"),
+  (47980, 47980, "H2"), (47981, 48007, "H3"), (48008, 48008, "H2"),
+  (48009, 48035, "H3"), (48036, 48036, "H2"), (48037, 48063, "H3"),
+  (48064, 48064, "H2"), (48065, 48091, "H3"), (48092, 48092, "H2"),
+  (48093, 48119, "H3"), (48120, 48120, "H2"), (48121, 48147, "H3"),
+  (48148, 48148, "H2"), (48149, 48175, "H3"), (48176, 48176, "H2"),
+  (48177, 48203, "H3"), (48204, 48204, "H2"), (48205, 48231, "H3"),
+  (48232, 48232, "H2"), (48233, 48259, "H3"), (48260, 48260, "H2"),
+  (48261, 48287, "H3"), (48288, 48288, "H2"), (48289, 48315, "H3"),
+  (48316, 48316, "H2"), (48317, 48343, "H3"), (48344, 48344, "H2"),
+  (48345, 48371, "H3"), (48372, 48372, "H2"), (48373, 48399, "H3"),
+  (48400, 48400, "H2"), (48401, 48427, "H3"), (48428, 48428, "H2"),
+  (48429, 48455, "H3"), (48456, 48456, "H2"), (48457, 48483, "H3"),
+  (48484, 48484, "H2"), (48485, 48511, "H3"), (48512, 48512, "H2"),
+  (48513, 48539, "H3"), (48540, 48540, "H2"), (48541, 48567, "H3"),
+  (48568, 48568, "H2"), (48569, 48595, "H3"), (48596, 48596, "H2"),
+  (48597, 48623, "H3"), (48624, 48624, "H2"), (48625, 48651, "H3"),
+  (48652, 48652, "H2"), (48653, 48679, "H3"), (48680, 48680, "H2"),
+  (48681, 48707, "H3"), (48708, 48708, "H2"), (48709, 48735, "H3"),
+  (48736, 48736, "H2"), (48737, 48763, "H3"), (48764, 48764, "H2"),
+  (48765, 48791, "H3"), (48792, 48792, "H2"), (48793, 48819, "H3"),
+  (48820, 48820, "H2"), (48821, 48847, "H3"), (48848, 48848, "H2"),
+  (48849, 48875, "H3"), (48876, 48876, "H2"), (48877, 48903, "H3"),
+  (48904, 48904, "H2"), (48905, 48931, "H3"), (48932, 48932, "H2"),
+  (48933, 48959, "H3"), (48960, 48960, "H2"), (48961, 48987, "H3"),
+  (48988, 48988, "H2"), (48989, 49015, "H3"), (49016, 49016, "H2"),
+  (49017, 49043, "H3"), (49044, 49044, "H2"), (49045, 49071, "H3"),
+  (49072, 49072, "H2"), (49073, 49099, "H3"), (49100, 49100, "H2"),
+  (49101, 49127, "H3"), (49128, 49128, "H2"), (49129, 49155, "H3"),
+  (49156, 49156, "H2"), (49157, 49183, "H3"), (49184, 49184, "H2"),
+  (49185, 49211, "H3"), (49212, 49212, "H2"), (49213, 49239, "H3"),
+  (49240, 49240, "H2"), (49241, 49267, "H3"), (49268, 49268, "H2"),
+  (49269, 49295, "H3"), (49296, 49296, "H2"), (49297, 49323, "H3"),
+  (49324, 49324, "H2"), (49325, 49351, "H3"), (49352, 49352, "H2"),
+  (49353, 49379, "H3"), (49380, 49380, "H2"), (49381, 49407, "H3"),
+  (49408, 49408, "H2"), (49409, 49435, "H3"), (49436, 49436, "H2"),
+  (49437, 49463, "H3"), (49464, 49464, "H2"), (49465, 49491, "H3"),
+  (49492, 49492, "H2"), (49493, 49519, "H3"), (49520, 49520, "H2"),
+  (49521, 49547, "H3"), (49548, 49548, "H2"), (49549, 49575, "H3"),
+  (49576, 49576, "H2"), (49577, 49603, "H3"), (49604, 49604, "H2"),
+  (49605, 49631, "H3"), (49632, 49632, "H2"), (49633, 49659, "H3"),
+  (49660, 49660, "H2"), (49661, 49687, "H3"), (49688, 49688, "H2"),
+  (49689, 49715, "H3"), (49716, 49716, "H2"), (49717, 49743, "H3"),
+  (49744, 49744, "H2"), (49745, 49771, "H3"), (49772, 49772, "H2"),
+  (49773, 49799, "H3"), (49800, 49800, "H2"), (49801, 49827, "H3"),
+  (49828, 49828, "H2"), (49829, 49855, "H3"), (49856, 49856, "H2"),
+  (49857, 49883, "H3"), (49884, 49884, "H2"), (49885, 49911, "H3"),
+  (49912, 49912, "H2"), (49913, 49939, "H3"), (49940, 49940, "H2"),
+  (49941, 49967, "H3"), (49968, 49968, "H2"), (49969, 49995, "H3"),
+  (49996, 49996, "H2"), (49997, 50023, "H3"), (50024, 50024, "H2"),
+  (50025, 50051, "H3"), (50052, 50052, "H2"), (50053, 50079, "H3"),
+  (50080, 50080, "H2"), (50081, 50107, "H3"), (50108, 50108, "H2"),
+  (50109, 50135, "H3"), (50136, 50136, "H2"), (50137, 50163, "H3"),
+  (50164, 50164, "H2"), (50165, 50191, "H3"), (50192, 50192, "H2"),
+  (50193, 50219, "H3"), (50220, 50220, "H2"), (50221, 50247, "H3"),
+  (50248, 50248, "H2"), (50249, 50275, "H3"), (50276, 50276, "H2"),
+  (50277, 50303, "H3"), (50304, 50304, "H2"), (50305, 50331, "H3"),
+  (50332, 50332, "H2"), (50333, 50359, "H3"), (50360, 50360, "H2"),
+  (50361, 50387, "H3"), (50388, 50388, "H2"), (50389, 50415, "H3"),
+  (50416, 50416, "H2"), (50417, 50443, "H3"), (50444, 50444, "H2"),
+  (50445, 50471, "H3"), (50472, 50472, "H2"), (50473, 50499, "H3"),
+  (50500, 50500, "H2"), (50501, 50527, "H3"), (50528, 50528, "H2"),
+  (50529, 50555, "H3"), (50556, 50556, "H2"), (50557, 50583, "H3"),
+  (50584, 50584, "H2"), (50585, 50611, "H3"), (50612, 50612, "H2"),
+  (50613, 50639, "H3"), (50640, 50640, "H2"), (50641, 50667, "H3"),
+  (50668, 50668, "H2"), (50669, 50695, "H3"), (50696, 50696, "H2"),
+  (50697, 50723, "H3"), (50724, 50724, "H2"), (50725, 50751, "H3"),
+  (50752, 50752, "H2"), (50753, 50779, "H3"), (50780, 50780, "H2"),
+  (50781, 50807, "H3"), (50808, 50808, "H2"), (50809, 50835, "H3"),
+  (50836, 50836, "H2"), (50837, 50863, "H3"), (50864, 50864, "H2"),
+  (50865, 50891, "H3"), (50892, 50892, "H2"), (50893, 50919, "H3"),
+  (50920, 50920, "H2"), (50921, 50947, "H3"), (50948, 50948, "H2"),
+  (50949, 50975, "H3"), (50976, 50976, "H2"), (50977, 51003, "H3"),
+  (51004, 51004, "H2"), (51005, 51031, "H3"), (51032, 51032, "H2"),
+  (51033, 51059, "H3"), (51060, 51060, "H2"), (51061, 51087, "H3"),
+  (51088, 51088, "H2"), (51089, 51115, "H3"), (51116, 51116, "H2"),
+  (51117, 51143, "H3"), (51144, 51144, "H2"), (51145, 51171, "H3"),
+  (51172, 51172, "H2"), (51173, 51199, "H3"), (51200, 51200, "H2"),
+  (51201, 51227, "H3"), (51228, 51228, "H2"), (51229, 51255, "H3"),
+  (51256, 51256, "H2"), (51257, 51283, "H3"), (51284, 51284, "H2"),
+  (51285, 51311, "H3"), (51312, 51312, "H2"), (51313, 51339, "H3"),
+  (51340, 51340, "H2"), (51341, 51367, "H3"), (51368, 51368, "H2"),
+  (51369, 51395, "H3"), (51396, 51396, "H2"), (51397, 51423, "H3"),
+  (51424, 51424, "H2"), (51425, 51451, "H3"), (51452, 51452, "H2"),
+  (51453, 51479, "H3"), (51480, 51480, "H2"), (51481, 51507, "H3"),
+  (51508, 51508, "H2"), (51509, 51535, "H3"), (51536, 51536, "H2"),
+  (51537, 51563, "H3"), (51564, 51564, "H2"), (51565, 51591, "H3"),
+  (51592, 51592, "H2"), (51593, 51619, "H3"), (51620, 51620, "H2"),
+  (51621, 51647, "H3"), (51648, 51648, "H2"), (51649, 51675, "H3"),
+  (51676, 51676, "H2"), (51677, 51703, "H3"), (51704, 51704, "H2"),
+  (51705, 51731, "H3"), (51732, 51732, "H2"), (51733, 51759, "H3"),
+  (51760, 51760, "H2"), (51761, 51787, "H3"), (51788, 51788, "H2"),
+  (51789, 51815, "H3"), (51816, 51816, "H2"), (51817, 51843, "H3"),
+  (51844, 51844, "H2"), (51845, 51871, "H3"), (51872, 51872, "H2"),
+  (51873, 51899, "H3"), (51900, 51900, "H2"), (51901, 51927, "H3"),
+  (51928, 51928, "H2"), (51929, 51955, "H3"), (51956, 51956, "H2"),
+  (51957, 51983, "H3"), (51984, 51984, "H2"), (51985, 52011, "H3"),
+  (52012, 52012, "H2"), (52013, 52039, "H3"), (52040, 52040, "H2"),
+  (52041, 52067, "H3"), (52068, 52068, "H2"), (52069, 52095, "H3"),
+  (52096, 52096, "H2"), (52097, 52123, "H3"), (52124, 52124, "H2"),
+  (52125, 52151, "H3"), (52152, 52152, "H2"), (52153, 52179, "H3"),
+  (52180, 52180, "H2"), (52181, 52207, "H3"), (52208, 52208, "H2"),
+  (52209, 52235, "H3"), (52236, 52236, "H2"), (52237, 52263, "H3"),
+  (52264, 52264, "H2"), (52265, 52291, "H3"), (52292, 52292, "H2"),
+  (52293, 52319, "H3"), (52320, 52320, "H2"), (52321, 52347, "H3"),
+  (52348, 52348, "H2"), (52349, 52375, "H3"), (52376, 52376, "H2"),
+  (52377, 52403, "H3"), (52404, 52404, "H2"), (52405, 52431, "H3"),
+  (52432, 52432, "H2"), (52433, 52459, "H3"), (52460, 52460, "H2"),
+  (52461, 52487, "H3"), (52488, 52488, "H2"), (52489, 52515, "H3"),
+  (52516, 52516, "H2"), (52517, 52543, "H3"), (52544, 52544, "H2"),
+  (52545, 52571, "H3"), (52572, 52572, "H2"), (52573, 52599, "H3"),
+  (52600, 52600, "H2"), (52601, 52627, "H3"), (52628, 52628, "H2"),
+  (52629, 52655, "H3"), (52656, 52656, "H2"), (52657, 52683, "H3"),
+  (52684, 52684, "H2"), (52685, 52711, "H3"), (52712, 52712, "H2"),
+  (52713, 52739, "H3"), (52740, 52740, "H2"), (52741, 52767, "H3"),
+  (52768, 52768, "H2"), (52769, 52795, "H3"), (52796, 52796, "H2"),
+  (52797, 52823, "H3"), (52824, 52824, "H2"), (52825, 52851, "H3"),
+  (52852, 52852, "H2"), (52853, 52879, "H3"), (52880, 52880, "H2"),
+  (52881, 52907, "H3"), (52908, 52908, "H2"), (52909, 52935, "H3"),
+  (52936, 52936, "H2"), (52937, 52963, "H3"), (52964, 52964, "H2"),
+  (52965, 52991, "H3"), (52992, 52992, "H2"), (52993, 53019, "H3"),
+  (53020, 53020, "H2"), (53021, 53047, "H3"), (53048, 53048, "H2"),
+  (53049, 53075, "H3"), (53076, 53076, "H2"), (53077, 53103, "H3"),
+  (53104, 53104, "H2"), (53105, 53131, "H3"), (53132, 53132, "H2"),
+  (53133, 53159, "H3"), (53160, 53160, "H2"), (53161, 53187, "H3"),
+  (53188, 53188, "H2"), (53189, 53215, "H3"), (53216, 53216, "H2"),
+  (53217, 53243, "H3"), (53244, 53244, "H2"), (53245, 53271, "H3"),
+  (53272, 53272, "H2"), (53273, 53299, "H3"), (53300, 53300, "H2"),
+  (53301, 53327, "H3"), (53328, 53328, "H2"), (53329, 53355, "H3"),
+  (53356, 53356, "H2"), (53357, 53383, "H3"), (53384, 53384, "H2"),
+  (53385, 53411, "H3"), (53412, 53412, "H2"), (53413, 53439, "H3"),
+  (53440, 53440, "H2"), (53441, 53467, "H3"), (53468, 53468, "H2"),
+  (53469, 53495, "H3"), (53496, 53496, "H2"), (53497, 53523, "H3"),
+  (53524, 53524, "H2"), (53525, 53551, "H3"), (53552, 53552, "H2"),
+  (53553, 53579, "H3"), (53580, 53580, "H2"), (53581, 53607, "H3"),
+  (53608, 53608, "H2"), (53609, 53635, "H3"), (53636, 53636, "H2"),
+  (53637, 53663, "H3"), (53664, 53664, "H2"), (53665, 53691, "H3"),
+  (53692, 53692, "H2"), (53693, 53719, "H3"), (53720, 53720, "H2"),
+  (53721, 53747, "H3"), (53748, 53748, "H2"), (53749, 53775, "H3"),
+  (53776, 53776, "H2"), (53777, 53803, "H3"), (53804, 53804, "H2"),
+  (53805, 53831, "H3"), (53832, 53832, "H2"), (53833, 53859, "H3"),
+  (53860, 53860, "H2"), (53861, 53887, "H3"), (53888, 53888, "H2"),
+  (53889, 53915, "H3"), (53916, 53916, "H2"), (53917, 53943, "H3"),
+  (53944, 53944, "H2"), (53945, 53971, "H3"), (53972, 53972, "H2"),
+  (53973, 53999, "H3"), (54000, 54000, "H2"), (54001, 54027, "H3"),
+  (54028, 54028, "H2"), (54029, 54055, "H3"), (54056, 54056, "H2"),
+  (54057, 54083, "H3"), (54084, 54084, "H2"), (54085, 54111, "H3"),
+  (54112, 54112, "H2"), (54113, 54139, "H3"), (54140, 54140, "H2"),
+  (54141, 54167, "H3"), (54168, 54168, "H2"), (54169, 54195, "H3"),
+  (54196, 54196, "H2"), (54197, 54223, "H3"), (54224, 54224, "H2"),
+  (54225, 54251, "H3"), (54252, 54252, "H2"), (54253, 54279, "H3"),
+  (54280, 54280, "H2"), (54281, 54307, "H3"), (54308, 54308, "H2"),
+  (54309, 54335, "H3"), (54336, 54336, "H2"), (54337, 54363, "H3"),
+  (54364, 54364, "H2"), (54365, 54391, "H3"), (54392, 54392, "H2"),
+  (54393, 54419, "H3"), (54420, 54420, "H2"), (54421, 54447, "H3"),
+  (54448, 54448, "H2"), (54449, 54475, "H3"), (54476, 54476, "H2"),
+  (54477, 54503, "H3"), (54504, 54504, "H2"), (54505, 54531, "H3"),
+  (54532, 54532, "H2"), (54533, 54559, "H3"), (54560, 54560, "H2"),
+  (54561, 54587, "H3"), (54588, 54588, "H2"), (54589, 54615, "H3"),
+  (54616, 54616, "H2"), (54617, 54643, "H3"), (54644, 54644, "H2"),
+  (54645, 54671, "H3"), (54672, 54672, "H2"), (54673, 54699, "H3"),
+  (54700, 54700, "H2"), (54701, 54727, "H3"), (54728, 54728, "H2"),
+  (54729, 54755, "H3"), (54756, 54756, "H2"), (54757, 54783, "H3"),
+  (54784, 54784, "H2"), (54785, 54811, "H3"), (54812, 54812, "H2"),
+  (54813, 54839, "H3"), (54840, 54840, "H2"), (54841, 54867, "H3"),
+  (54868, 54868, "H2"), (54869, 54895, "H3"), (54896, 54896, "H2"),
+  (54897, 54923, "H3"), (54924, 54924, "H2"), (54925, 54951, "H3"),
+  (54952, 54952, "H2"), (54953, 54979, "H3"), (54980, 54980, "H2"),
+  (54981, 55007, "H3"), (55008, 55008, "H2"), (55009, 55035, "H3"),
+  (55036, 55036, "H2"), (55037, 55063, "H3"), (55064, 55064, "H2"),
+  (55065, 55091, "H3"), (55092, 55092, "H2"), (55093, 55119, "H3"),
+  (55120, 55120, "H2"), (55121, 55147, "H3"), (55148, 55148, "H2"),
+  (55149, 55175, "H3"), (55176, 55176, "H2"), (55177, 55203, "H3"),
+  (55216, 55238, "JV"), (55243, 55291, "JT"), (55296, 57343, "SG"),
+  (63744, 64255, "ID"), (64256, 64262, "AL"), (64275, 64279, "AL"),
+  (64285, 64285, "HL"), (64286, 64286, "CM"), (64287, 64296, "HL"),
+  (64297, 64297, "AL"), (64298, 64310, "HL"), (64312, 64316, "HL"),
+  (64318, 64318, "HL"), (64320, 64321, "HL"), (64323, 64324, "HL"),
+  (64326, 64335, "HL"), (64336, 64449, "AL"), (64467, 64829, "AL"),
+  (64830, 64830, "CL"), (64831, 64831, "OP"), (64848, 64911, "AL"),
+  (64914, 64967, "AL"), (65008, 65019, "AL"), (65020, 65020, "PO"),
+  (65021, 65021, "AL"), (65024, 65039, "CM"), (65040, 65040, "IS"),
+  (65041, 65042, "CL"), (65043, 65044, "IS"), (65045, 65046, "EX"),
+  (65047, 65047, "OP"), (65048, 65048, "CL"), (65049, 65049, "IN"),
+  (65056, 65071, "CM"), (65072, 65076, "ID"), (65077, 65077, "OP"),
+  (65078, 65078, "CL"), (65079, 65079, "OP"), (65080, 65080, "CL"),
+  (65081, 65081, "OP"), (65082, 65082, "CL"), (65083, 65083, "OP"),
+  (65084, 65084, "CL"), (65085, 65085, "OP"), (65086, 65086, "CL"),
+  (65087, 65087, "OP"), (65088, 65088, "CL"), (65089, 65089, "OP"),
+  (65090, 65090, "CL"), (65091, 65091, "OP"), (65092, 65092, "CL"),
+  (65093, 65094, "ID"), (65095, 65095, "OP"), (65096, 65096, "CL"),
+  (65097, 65103, "ID"), (65104, 65104, "CL"), (65105, 65105, "ID"),
+  (65106, 65106, "CL"), (65108, 65109, "NS"), (65110, 65111, "EX"),
+  (65112, 65112, "ID"), (65113, 65113, "OP"), (65114, 65114, "CL"),
+  (65115, 65115, "OP"), (65116, 65116, "CL"), (65117, 65117, "OP"),
+  (65118, 65118, "CL"), (65119, 65126, "ID"), (65128, 65128, "ID"),
+  (65129, 65129, "PR"), (65130, 65130, "PO"), (65131, 65131, "ID"),
+  (65136, 65140, "AL"), (65142, 65276, "AL"), (65279, 65279, "WJ"),
+  (65281, 65281, "EX"), (65282, 65283, "ID"), (65284, 65284, "PR"),
+  (65285, 65285, "PO"), (65286, 65287, "ID"), (65288, 65288, "OP"),
+  (65289, 65289, "CL"), (65290, 65291, "ID"), (65292, 65292, "CL"),
+  (65293, 65293, "ID"), (65294, 65294, "CL"), (65295, 65305, "ID"),
+  (65306, 65307, "NS"), (65308, 65310, "ID"), (65311, 65311, "EX"),
+  (65312, 65338, "ID"), (65339, 65339, "OP"), (65340, 65340, "ID"),
+  (65341, 65341, "CL"), (65342, 65370, "ID"), (65371, 65371, "OP"),
+  (65372, 65372, "ID"), (65373, 65373, "CL"), (65374, 65374, "ID"),
+  (65375, 65375, "OP"), (65376, 65377, "CL"), (65378, 65378, "OP"),
+  (65379, 65380, "CL"), (65381, 65381, "NS"), (65382, 65382, "ID"),
+  (65383, 65392, "CJ"), (65393, 65437, "ID"), (65438, 65439, "NS"),
+  (65440, 65470, "ID"), (65474, 65479, "ID"), (65482, 65487, "ID"),
+  (65490, 65495, "ID"), (65498, 65500, "ID"), (65504, 65504, "PO"),
+  (65505, 65505, "PR"), (65506, 65508, "ID"), (65509, 65510, "PR"),
+  (65512, 65518, "AL"), (65529, 65531, "CM"), (65532, 65532, "CB"),
+  (65533, 65533, "AI"), (65536, 65547, "AL"), (65549, 65574, "AL"),
+  (65576, 65594, "AL"), (65596, 65597, "AL"), (65599, 65613, "AL"),
+  (65616, 65629, "AL"), (65664, 65786, "AL"), (65792, 65794, "BA"),
+  (65799, 65843, "AL"), (65847, 65934, "AL"), (65936, 65947, "AL"),
+  (65952, 65952, "AL"), (66000, 66044, "AL"), (66045, 66045, "CM"),
+  (66176, 66204, "AL"), (66208, 66256, "AL"), (66272, 66272, "CM"),
+  (66273, 66299, "AL"), (66304, 66339, "AL"), (66352, 66378, "AL"),
+  (66384, 66421, "AL"), (66422, 66426, "CM"), (66432, 66461, "AL"),
+  (66463, 66463, "BA"), (66464, 66499, "AL"), (66504, 66511, "AL"),
+  (66512, 66512, "BA"), (66513, 66517, "AL"), (66560, 66717, "AL"),
+  (66720, 66729, "NU"), (66736, 66771, "AL"), (66776, 66811, "AL"),
+  (66816, 66855, "AL"), (66864, 66915, "AL"), (66927, 66927, "AL"),
+  (67072, 67382, "AL"), (67392, 67413, "AL"), (67424, 67431, "AL"),
+  (67584, 67589, "AL"), (67592, 67592, "AL"), (67594, 67637, "AL"),
+  (67639, 67640, "AL"), (67644, 67644, "AL"), (67647, 67669, "AL"),
+  (67671, 67671, "BA"), (67672, 67742, "AL"), (67751, 67759, "AL"),
+  (67808, 67826, "AL"), (67828, 67829, "AL"), (67835, 67867, "AL"),
+  (67871, 67871, "BA"), (67872, 67897, "AL"), (67903, 67903, "AL"),
+  (67968, 68023, "AL"), (68028, 68047, "AL"), (68050, 68096, "AL"),
+  (68097, 68099, "CM"), (68101, 68102, "CM"), (68108, 68111, "CM"),
+  (68112, 68115, "AL"), (68117, 68119, "AL"), (68121, 68147, "AL"),
+  (68152, 68154, "CM"), (68159, 68159, "CM"), (68160, 68167, "AL"),
+  (68176, 68183, "BA"), (68184, 68184, "AL"), (68192, 68255, "AL"),
+  (68288, 68324, "AL"), (68325, 68326, "CM"), (68331, 68335, "AL"),
+  (68336, 68341, "BA"), (68342, 68342, "IN"), (68352, 68405, "AL"),
+  (68409, 68415, "BA"), (68416, 68437, "AL"), (68440, 68466, "AL"),
+  (68472, 68497, "AL"), (68505, 68508, "AL"), (68521, 68527, "AL"),
+  (68608, 68680, "AL"), (68736, 68786, "AL"), (68800, 68850, "AL"),
+  (68858, 68863, "AL"), (69216, 69246, "AL"), (69632, 69634, "CM"),
+  (69635, 69687, "AL"), (69688, 69702, "CM"), (69703, 69704, "BA"),
+  (69705, 69709, "AL"), (69714, 69733, "AL"), (69734, 69743, "NU"),
+  (69759, 69762, "CM"), (69763, 69807, "AL"), (69808, 69818, "CM"),
+  (69819, 69821, "AL"), (69822, 69825, "BA"), (69840, 69864, "AL"),
+  (69872, 69881, "NU"), (69888, 69890, "CM"), (69891, 69926, "AL"),
+  (69927, 69940, "CM"), (69942, 69951, "NU"), (69952, 69955, "BA"),
+  (69968, 70002, "AL"), (70003, 70003, "CM"), (70004, 70004, "AL"),
+  (70005, 70005, "BB"), (70006, 70006, "AL"), (70016, 70018, "CM"),
+  (70019, 70066, "AL"), (70067, 70080, "CM"), (70081, 70084, "AL"),
+  (70085, 70086, "BA"), (70087, 70087, "AL"), (70088, 70088, "BA"),
+  (70089, 70089, "AL"), (70090, 70092, "CM"), (70093, 70093, "AL"),
+  (70096, 70105, "NU"), (70106, 70106, "AL"), (70107, 70107, "BB"),
+  (70108, 70108, "AL"), (70109, 70111, "BA"), (70113, 70132, "AL"),
+  (70144, 70161, "AL"), (70163, 70187, "AL"), (70188, 70199, "CM"),
+  (70200, 70201, "BA"), (70202, 70202, "AL"), (70203, 70204, "BA"),
+  (70205, 70205, "AL"), (70206, 70206, "CM"), (70272, 70278, "AL"),
+  (70280, 70280, "AL"), (70282, 70285, "AL"), (70287, 70301, "AL"),
+  (70303, 70312, "AL"), (70313, 70313, "BA"), (70320, 70366, "AL"),
+  (70367, 70378, "CM"), (70384, 70393, "NU"), (70400, 70403, "CM"),
+  (70405, 70412, "AL"), (70415, 70416, "AL"), (70419, 70440, "AL"),
+  (70442, 70448, "AL"), (70450, 70451, "AL"), (70453, 70457, "AL"),
+  (70460, 70460, "CM"), (70461, 70461, "AL"), (70462, 70468, "CM"),
+  (70471, 70472, "CM"), (70475, 70477, "CM"), (70480, 70480, "AL"),
+  (70487, 70487, "CM"), (70493, 70497, "AL"), (70498, 70499, "CM"),
+  (70502, 70508, "CM"), (70512, 70516, "CM"), (70656, 70708, "AL"),
+  (70709, 70726, "CM"), (70727, 70730, "AL"), (70731, 70734, "BA"),
+  (70735, 70735, "AL"), (70736, 70745, "NU"), (70747, 70747, "BA"),
+  (70749, 70749, "AL"), (70784, 70831, "AL"), (70832, 70851, "CM"),
+  (70852, 70855, "AL"), (70864, 70873, "NU"), (71040, 71086, "AL"),
+  (71087, 71093, "CM"), (71096, 71104, "CM"), (71105, 71105, "BB"),
+  (71106, 71107, "BA"), (71108, 71109, "EX"), (71110, 71112, "AL"),
+  (71113, 71127, "BA"), (71128, 71131, "AL"), (71132, 71133, "CM"),
+  (71168, 71215, "AL"), (71216, 71232, "CM"), (71233, 71234, "BA"),
+  (71235, 71236, "AL"), (71248, 71257, "NU"), (71264, 71276, "BB"),
+  (71296, 71338, "AL"), (71339, 71351, "CM"), (71360, 71369, "NU"),
+  (71424, 71449, "SA"), (71453, 71467, "SA"), (71472, 71481, "NU"),
+  (71482, 71483, "SA"), (71484, 71486, "BA"), (71487, 71487, "SA"),
+  (71840, 71903, "AL"), (71904, 71913, "NU"), (71914, 71922, "AL"),
+  (71935, 71935, "AL"), (72384, 72440, "AL"), (72704, 72712, "AL"),
+  (72714, 72750, "AL"), (72751, 72758, "CM"), (72760, 72767, "CM"),
+  (72768, 72768, "AL"), (72769, 72773, "BA"), (72784, 72793, "NU"),
+  (72794, 72812, "AL"), (72816, 72816, "BB"), (72817, 72817, "EX"),
+  (72818, 72847, "AL"), (72850, 72871, "CM"), (72873, 72886, "CM"),
+  (73728, 74649, "AL"), (74752, 74862, "AL"), (74864, 74868, "BA"),
+  (74880, 75075, "AL"), (77824, 78423, "AL"), (78424, 78426, "OP"),
+  (78427, 78429, "CL"), (78430, 78465, "AL"), (78466, 78466, "CL"),
+  (78467, 78469, "AL"), (78470, 78470, "OP"), (78471, 78471, "CL"),
+  (78472, 78472, "OP"), (78473, 78473, "CL"), (78474, 78712, "AL"),
+  (78713, 78713, "OP"), (78714, 78715, "CL"), (78716, 78894, "AL"),
+  (82944, 83405, "AL"), (83406, 83406, "OP"), (83407, 83407, "CL"),
+  (83408, 83526, "AL"), (92160, 92728, "AL"), (92736, 92766, "AL"),
+  (92768, 92777, "NU"), (92782, 92783, "BA"), (92880, 92909, "AL"),
+  (92912, 92916, "CM"), (92917, 92917, "BA"), (92928, 92975, "AL"),
+  (92976, 92982, "CM"), (92983, 92985, "BA"), (92986, 92995, "AL"),
+  (92996, 92996, "BA"), (92997, 92997, "AL"), (93008, 93017, "NU"),
+  (93019, 93025, "AL"), (93027, 93047, "AL"), (93053, 93071, "AL"),
+  (93952, 94020, "AL"), (94032, 94032, "AL"), (94033, 94078, "CM"),
+  (94095, 94098, "CM"), (94099, 94111, "AL"), (94176, 94176, "NS"),
+  (94208, 100332, "ID"), (100352, 101106, "ID"), (110592, 110593, "ID"),
+  (113664, 113770, "AL"), (113776, 113788, "AL"), (113792, 113800, "AL"),
+  (113808, 113817, "AL"), (113820, 113820, "AL"), (113821, 113822, "CM"),
+  (113823, 113823, "BA"), (113824, 113827, "CM"), (118784, 119029, "AL"),
+  (119040, 119078, "AL"), (119081, 119140, "AL"), (119141, 119145, "CM"),
+  (119146, 119148, "AL"), (119149, 119170, "CM"), (119171, 119172, "AL"),
+  (119173, 119179, "CM"), (119180, 119209, "AL"), (119210, 119213, "CM"),
+  (119214, 119272, "AL"), (119296, 119361, "AL"), (119362, 119364, "CM"),
+  (119365, 119365, "AL"), (119552, 119638, "AL"), (119648, 119665, "AL"),
+  (119808, 119892, "AL"), (119894, 119964, "AL"), (119966, 119967, "AL"),
+  (119970, 119970, "AL"), (119973, 119974, "AL"), (119977, 119980, "AL"),
+  (119982, 119993, "AL"), (119995, 119995, "AL"), (119997, 120003, "AL"),
+  (120005, 120069, "AL"), (120071, 120074, "AL"), (120077, 120084, "AL"),
+  (120086, 120092, "AL"), (120094, 120121, "AL"), (120123, 120126, "AL"),
+  (120128, 120132, "AL"), (120134, 120134, "AL"), (120138, 120144, "AL"),
+  (120146, 120485, "AL"), (120488, 120779, "AL"), (120782, 120831, "NU"),
+  (120832, 121343, "AL"), (121344, 121398, "CM"), (121399, 121402, "AL"),
+  (121403, 121452, "CM"), (121453, 121460, "AL"), (121461, 121461, "CM"),
+  (121462, 121475, "AL"), (121476, 121476, "CM"), (121477, 121478, "AL"),
+  (121479, 121482, "BA"), (121483, 121483, "AL"), (121499, 121503, "CM"),
+  (121505, 121519, "CM"), (122880, 122886, "CM"), (122888, 122904, "CM"),
+  (122907, 122913, "CM"), (122915, 122916, "CM"), (122918, 122922, "CM"),
+  (124928, 125124, "AL"), (125127, 125135, "AL"), (125136, 125142, "CM"),
+  (125184, 125251, "AL"), (125252, 125258, "CM"), (125264, 125273, "NU"),
+  (125278, 125279, "OP"), (126464, 126467, "AL"), (126469, 126495, "AL"),
+  (126497, 126498, "AL"), (126500, 126500, "AL"), (126503, 126503, "AL"),
+  (126505, 126514, "AL"), (126516, 126519, "AL"), (126521, 126521, "AL"),
+  (126523, 126523, "AL"), (126530, 126530, "AL"), (126535, 126535, "AL"),
+  (126537, 126537, "AL"), (126539, 126539, "AL"), (126541, 126543, "AL"),
+  (126545, 126546, "AL"), (126548, 126548, "AL"), (126551, 126551, "AL"),
+  (126553, 126553, "AL"), (126555, 126555, "AL"), (126557, 126557, "AL"),
+  (126559, 126559, "AL"), (126561, 126562, "AL"), (126564, 126564, "AL"),
+  (126567, 126570, "AL"), (126572, 126578, "AL"), (126580, 126583, "AL"),
+  (126585, 126588, "AL"), (126590, 126590, "AL"), (126592, 126601, "AL"),
+  (126603, 126619, "AL"), (126625, 126627, "AL"), (126629, 126633, "AL"),
+  (126635, 126651, "AL"), (126704, 126705, "AL"), (126976, 127231, "ID"),
+  (127232, 127244, "AI"), (127245, 127247, "ID"), (127248, 127277, "AI"),
+  (127278, 127278, "AL"), (127279, 127279, "ID"), (127280, 127337, "AI"),
+  (127338, 127339, "AL"), (127340, 127343, "ID"), (127344, 127404, "AI"),
+  (127405, 127461, "ID"), (127462, 127487, "RI"), (127488, 127876, "ID"),
+  (127877, 127877, "EB"), (127878, 127899, "ID"), (127900, 127901, "AL"),
+  (127902, 127924, "ID"), (127925, 127926, "AL"), (127927, 127931, "ID"),
+  (127932, 127932, "AL"), (127933, 127938, "ID"), (127939, 127940, "EB"),
+  (127941, 127945, "ID"), (127946, 127947, "EB"), (127948, 127994, "ID"),
+  (127995, 127999, "EM"), (128000, 128065, "ID"), (128066, 128067, "EB"),
+  (128068, 128069, "ID"), (128070, 128080, "EB"), (128081, 128101, "ID"),
+  (128102, 128105, "EB"), (128106, 128109, "ID"), (128110, 128110, "EB"),
+  (128111, 128111, "ID"), (128112, 128120, "EB"), (128121, 128123, "ID"),
+  (128124, 128124, "EB"), (128125, 128128, "ID"), (128129, 128131, "EB"),
+  (128132, 128132, "ID"), (128133, 128135, "EB"), (128136, 128159, "ID"),
+  (128160, 128160, "AL"), (128161, 128161, "ID"), (128162, 128162, "AL"),
+  (128163, 128163, "ID"), (128164, 128164, "AL"), (128165, 128169, "ID"),
+  (128170, 128170, "EB"), (128171, 128174, "ID"), (128175, 128175, "AL"),
+  (128176, 128176, "ID"), (128177, 128178, "AL"), (128179, 128255, "ID"),
+  (128256, 128262, "AL"), (128263, 128278, "ID"), (128279, 128292, "AL"),
+  (128293, 128305, "ID"), (128306, 128329, "AL"), (128330, 128372, "ID"),
+  (128373, 128373, "EB"), (128374, 128377, "ID"), (128378, 128378, "EB"),
+  (128379, 128399, "ID"), (128400, 128400, "EB"), (128401, 128404, "ID"),
+  (128405, 128406, "EB"), (128407, 128467, "ID"), (128468, 128475, "AL"),
+  (128476, 128499, "ID"), (128500, 128505, "AL"), (128506, 128580, "ID"),
+  (128581, 128583, "EB"), (128584, 128586, "ID"), (128587, 128591, "EB"),
+  (128592, 128629, "AL"), (128630, 128632, "QU"), (128633, 128635, "NS"),
+  (128636, 128639, "AL"), (128640, 128674, "ID"), (128675, 128675, "EB"),
+  (128676, 128691, "ID"), (128692, 128694, "EB"), (128695, 128703, "ID"),
+  (128704, 128704, "EB"), (128705, 128767, "ID"), (128768, 128883, "AL"),
+  (128884, 128895, "ID"), (128896, 128980, "AL"), (128981, 129023, "ID"),
+  (129024, 129035, "AL"), (129036, 129039, "ID"), (129040, 129095, "AL"),
+  (129096, 129103, "ID"), (129104, 129113, "AL"), (129114, 129119, "ID"),
+  (129120, 129159, "AL"), (129160, 129167, "ID"), (129168, 129197, "AL"),
+  (129198, 129303, "ID"), (129304, 129310, "EB"), (129311, 129317, "ID"),
+  (129318, 129318, "EB"), (129319, 129327, "ID"), (129328, 129328, "EB"),
+  (129329, 129330, "ID"), (129331, 129337, "EB"), (129338, 129339, "ID"),
+  (129340, 129342, "EB"), (129343, 131069, "ID"), (131072, 196605, "ID"),
+  (196608, 262141, "ID"), (917505, 917505, "CM"), (917536, 917631, "CM"),
+  (917760, 917999, "CM"),
+];
@@ -0,0 +1,23 @@
+pub mod age;
+pub mod bidi_brackets;
+pub mod bidi_class;
+pub mod bidi_mirrored;
+pub mod bidi_mirroring_glyph;
+pub mod block;
+pub mod canonical_combining_class;
+pub mod composition;
+pub mod decomposition;
+pub mod default_ignorable_code_point;
+pub mod east_asian_width;
+pub mod general_category;
+pub mod identifier_properties;
+pub mod grapheme_cluster_break;
+pub mod line_break;
+pub mod normalization_quick_check;
+pub mod numeric_type;
+pub mod numeric_value;
+pub mod property_values;
+pub mod script;
+pub mod script_extension;
+pub mod sentence_break;
+pub mod word_break;
@@ -0,0 +1,551 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//  ucd-generate numeric-value /home/andrew/tmp/ucd-9.0.0/
+//
+// ucd-generate is available on crates.io.
+
+pub const NUMERIC_VALUE: &'static [(u32, u32, (i64, u64))] = &[
+  (48, 48, (0, 1)), (49, 49, (1, 1)), (50, 50, (2, 1)), (51, 51, (3, 1)),
+  (52, 52, (4, 1)), (53, 53, (5, 1)), (54, 54, (6, 1)), (55, 55, (7, 1)),
+  (56, 56, (8, 1)), (57, 57, (9, 1)), (178, 178, (2, 1)), (179, 179, (3, 1)),
+  (185, 185, (1, 1)), (188, 188, (1, 4)), (189, 189, (1, 2)),
+  (190, 190, (3, 4)), (1632, 1632, (0, 1)), (1633, 1633, (1, 1)),
+  (1634, 1634, (2, 1)), (1635, 1635, (3, 1)), (1636, 1636, (4, 1)),
+  (1637, 1637, (5, 1)), (1638, 1638, (6, 1)), (1639, 1639, (7, 1)),
+  (1640, 1640, (8, 1)), (1641, 1641, (9, 1)), (1776, 1776, (0, 1)),
+  (1777, 1777, (1, 1)), (1778, 1778, (2, 1)), (1779, 1779, (3, 1)),
+  (1780, 1780, (4, 1)), (1781, 1781, (5, 1)), (1782, 1782, (6, 1)),
+  (1783, 1783, (7, 1)), (1784, 1784, (8, 1)), (1785, 1785, (9, 1)),
+  (1984, 1984, (0, 1)), (1985, 1985, (1, 1)), (1986, 1986, (2, 1)),
+  (1987, 1987, (3, 1)), (1988, 1988, (4, 1)), (1989, 1989, (5, 1)),
+  (1990, 1990, (6, 1)), (1991, 1991, (7, 1)), (1992, 1992, (8, 1)),
+  (1993, 1993, (9, 1)), (2406, 2406, (0, 1)), (2407, 2407, (1, 1)),
+  (2408, 2408, (2, 1)), (2409, 2409, (3, 1)), (2410, 2410, (4, 1)),
+  (2411, 2411, (5, 1)), (2412, 2412, (6, 1)), (2413, 2413, (7, 1)),
+  (2414, 2414, (8, 1)), (2415, 2415, (9, 1)), (2534, 2534, (0, 1)),
+  (2535, 2535, (1, 1)), (2536, 2536, (2, 1)), (2537, 2537, (3, 1)),
+  (2538, 2538, (4, 1)), (2539, 2539, (5, 1)), (2540, 2540, (6, 1)),
+  (2541, 2541, (7, 1)), (2542, 2542, (8, 1)), (2543, 2543, (9, 1)),
+  (2548, 2548, (1, 16)), (2549, 2549, (1, 8)), (2550, 2550, (3, 16)),
+  (2551, 2551, (1, 4)), (2552, 2552, (3, 4)), (2553, 2553, (16, 1)),
+  (2662, 2662, (0, 1)), (2663, 2663, (1, 1)), (2664, 2664, (2, 1)),
+  (2665, 2665, (3, 1)), (2666, 2666, (4, 1)), (2667, 2667, (5, 1)),
+  (2668, 2668, (6, 1)), (2669, 2669, (7, 1)), (2670, 2670, (8, 1)),
+  (2671, 2671, (9, 1)), (2790, 2790, (0, 1)), (2791, 2791, (1, 1)),
+  (2792, 2792, (2, 1)), (2793, 2793, (3, 1)), (2794, 2794, (4, 1)),
+  (2795, 2795, (5, 1)), (2796, 2796, (6, 1)), (2797, 2797, (7, 1)),
+  (2798, 2798, (8, 1)), (2799, 2799, (9, 1)), (2918, 2918, (0, 1)),
+  (2919, 2919, (1, 1)), (2920, 2920, (2, 1)), (2921, 2921, (3, 1)),
+  (2922, 2922, (4, 1)), (2923, 2923, (5, 1)), (2924, 2924, (6, 1)),
+  (2925, 2925, (7, 1)), (2926, 2926, (8, 1)), (2927, 2927, (9, 1)),
+  (2930, 2930, (1, 4)), (2931, 2931, (1, 2)), (2932, 2932, (3, 4)),
+  (2933, 2933, (1, 16)), (2934, 2934, (1, 8)), (2935, 2935, (3, 16)),
+  (3046, 3046, (0, 1)), (3047, 3047, (1, 1)), (3048, 3048, (2, 1)),
+  (3049, 3049, (3, 1)), (3050, 3050, (4, 1)), (3051, 3051, (5, 1)),
+  (3052, 3052, (6, 1)), (3053, 3053, (7, 1)), (3054, 3054, (8, 1)),
+  (3055, 3055, (9, 1)), (3056, 3056, (10, 1)), (3057, 3057, (100, 1)),
+  (3058, 3058, (1000, 1)), (3174, 3174, (0, 1)), (3175, 3175, (1, 1)),
+  (3176, 3176, (2, 1)), (3177, 3177, (3, 1)), (3178, 3178, (4, 1)),
+  (3179, 3179, (5, 1)), (3180, 3180, (6, 1)), (3181, 3181, (7, 1)),
+  (3182, 3182, (8, 1)), (3183, 3183, (9, 1)), (3192, 3192, (0, 1)),
+  (3193, 3193, (1, 1)), (3194, 3194, (2, 1)), (3195, 3195, (3, 1)),
+  (3196, 3196, (1, 1)), (3197, 3197, (2, 1)), (3198, 3198, (3, 1)),
+  (3302, 3302, (0, 1)), (3303, 3303, (1, 1)), (3304, 3304, (2, 1)),
+  (3305, 3305, (3, 1)), (3306, 3306, (4, 1)), (3307, 3307, (5, 1)),
+  (3308, 3308, (6, 1)), (3309, 3309, (7, 1)), (3310, 3310, (8, 1)),
+  (3311, 3311, (9, 1)), (3416, 3416, (1, 160)), (3417, 3417, (1, 40)),
+  (3418, 3418, (3, 80)), (3419, 3419, (1, 20)), (3420, 3420, (1, 10)),
+  (3421, 3421, (3, 20)), (3422, 3422, (1, 5)), (3430, 3430, (0, 1)),
+  (3431, 3431, (1, 1)), (3432, 3432, (2, 1)), (3433, 3433, (3, 1)),
+  (3434, 3434, (4, 1)), (3435, 3435, (5, 1)), (3436, 3436, (6, 1)),
+  (3437, 3437, (7, 1)), (3438, 3438, (8, 1)), (3439, 3439, (9, 1)),
+  (3440, 3440, (10, 1)), (3441, 3441, (100, 1)), (3442, 3442, (1000, 1)),
+  (3443, 3443, (1, 4)), (3444, 3444, (1, 2)), (3445, 3445, (3, 4)),
+  (3446, 3446, (1, 16)), (3447, 3447, (1, 8)), (3448, 3448, (3, 16)),
+  (3558, 3558, (0, 1)), (3559, 3559, (1, 1)), (3560, 3560, (2, 1)),
+  (3561, 3561, (3, 1)), (3562, 3562, (4, 1)), (3563, 3563, (5, 1)),
+  (3564, 3564, (6, 1)), (3565, 3565, (7, 1)), (3566, 3566, (8, 1)),
+  (3567, 3567, (9, 1)), (3664, 3664, (0, 1)), (3665, 3665, (1, 1)),
+  (3666, 3666, (2, 1)), (3667, 3667, (3, 1)), (3668, 3668, (4, 1)),
+  (3669, 3669, (5, 1)), (3670, 3670, (6, 1)), (3671, 3671, (7, 1)),
+  (3672, 3672, (8, 1)), (3673, 3673, (9, 1)), (3792, 3792, (0, 1)),
+  (3793, 3793, (1, 1)), (3794, 3794, (2, 1)), (3795, 3795, (3, 1)),
+  (3796, 3796, (4, 1)), (3797, 3797, (5, 1)), (3798, 3798, (6, 1)),
+  (3799, 3799, (7, 1)), (3800, 3800, (8, 1)), (3801, 3801, (9, 1)),
+  (3872, 3872, (0, 1)), (3873, 3873, (1, 1)), (3874, 3874, (2, 1)),
+  (3875, 3875, (3, 1)), (3876, 3876, (4, 1)), (3877, 3877, (5, 1)),
+  (3878, 3878, (6, 1)), (3879, 3879, (7, 1)), (3880, 3880, (8, 1)),
+  (3881, 3881, (9, 1)), (3882, 3882, (1, 2)), (3883, 3883, (3, 2)),
+  (3884, 3884, (5, 2)), (3885, 3885, (7, 2)), (3886, 3886, (9, 2)),
+  (3887, 3887, (11, 2)), (3888, 3888, (13, 2)), (3889, 3889, (15, 2)),
+  (3890, 3890, (17, 2)), (3891, 3891, (-1, 2)), (4160, 4160, (0, 1)),
+  (4161, 4161, (1, 1)), (4162, 4162, (2, 1)), (4163, 4163, (3, 1)),
+  (4164, 4164, (4, 1)), (4165, 4165, (5, 1)), (4166, 4166, (6, 1)),
+  (4167, 4167, (7, 1)), (4168, 4168, (8, 1)), (4169, 4169, (9, 1)),
+  (4240, 4240, (0, 1)), (4241, 4241, (1, 1)), (4242, 4242, (2, 1)),
+  (4243, 4243, (3, 1)), (4244, 4244, (4, 1)), (4245, 4245, (5, 1)),
+  (4246, 4246, (6, 1)), (4247, 4247, (7, 1)), (4248, 4248, (8, 1)),
+  (4249, 4249, (9, 1)), (4969, 4969, (1, 1)), (4970, 4970, (2, 1)),
+  (4971, 4971, (3, 1)), (4972, 4972, (4, 1)), (4973, 4973, (5, 1)),
+  (4974, 4974, (6, 1)), (4975, 4975, (7, 1)), (4976, 4976, (8, 1)),
+  (4977, 4977, (9, 1)), (4978, 4978, (10, 1)), (4979, 4979, (20, 1)),
+  (4980, 4980, (30, 1)), (4981, 4981, (40, 1)), (4982, 4982, (50, 1)),
+  (4983, 4983, (60, 1)), (4984, 4984, (70, 1)), (4985, 4985, (80, 1)),
+  (4986, 4986, (90, 1)), (4987, 4987, (100, 1)), (4988, 4988, (10000, 1)),
+  (5870, 5870, (17, 1)), (5871, 5871, (18, 1)), (5872, 5872, (19, 1)),
+  (6112, 6112, (0, 1)), (6113, 6113, (1, 1)), (6114, 6114, (2, 1)),
+  (6115, 6115, (3, 1)), (6116, 6116, (4, 1)), (6117, 6117, (5, 1)),
+  (6118, 6118, (6, 1)), (6119, 6119, (7, 1)), (6120, 6120, (8, 1)),
+  (6121, 6121, (9, 1)), (6128, 6128, (0, 1)), (6129, 6129, (1, 1)),
+  (6130, 6130, (2, 1)), (6131, 6131, (3, 1)), (6132, 6132, (4, 1)),
+  (6133, 6133, (5, 1)), (6134, 6134, (6, 1)), (6135, 6135, (7, 1)),
+  (6136, 6136, (8, 1)), (6137, 6137, (9, 1)), (6160, 6160, (0, 1)),
+  (6161, 6161, (1, 1)), (6162, 6162, (2, 1)), (6163, 6163, (3, 1)),
+  (6164, 6164, (4, 1)), (6165, 6165, (5, 1)), (6166, 6166, (6, 1)),
+  (6167, 6167, (7, 1)), (6168, 6168, (8, 1)), (6169, 6169, (9, 1)),
+  (6470, 6470, (0, 1)), (6471, 6471, (1, 1)), (6472, 6472, (2, 1)),
+  (6473, 6473, (3, 1)), (6474, 6474, (4, 1)), (6475, 6475, (5, 1)),
+  (6476, 6476, (6, 1)), (6477, 6477, (7, 1)), (6478, 6478, (8, 1)),
+  (6479, 6479, (9, 1)), (6608, 6608, (0, 1)), (6609, 6609, (1, 1)),
+  (6610, 6610, (2, 1)), (6611, 6611, (3, 1)), (6612, 6612, (4, 1)),
+  (6613, 6613, (5, 1)), (6614, 6614, (6, 1)), (6615, 6615, (7, 1)),
+  (6616, 6616, (8, 1)), (6617, 6617, (9, 1)), (6618, 6618, (1, 1)),
+  (6784, 6784, (0, 1)), (6785, 6785, (1, 1)), (6786, 6786, (2, 1)),
+  (6787, 6787, (3, 1)), (6788, 6788, (4, 1)), (6789, 6789, (5, 1)),
+  (6790, 6790, (6, 1)), (6791, 6791, (7, 1)), (6792, 6792, (8, 1)),
+  (6793, 6793, (9, 1)), (6800, 6800, (0, 1)), (6801, 6801, (1, 1)),
+  (6802, 6802, (2, 1)), (6803, 6803, (3, 1)), (6804, 6804, (4, 1)),
+  (6805, 6805, (5, 1)), (6806, 6806, (6, 1)), (6807, 6807, (7, 1)),
+  (6808, 6808, (8, 1)), (6809, 6809, (9, 1)), (6992, 6992, (0, 1)),
+  (6993, 6993, (1, 1)), (6994, 6994, (2, 1)), (6995, 6995, (3, 1)),
+  (6996, 6996, (4, 1)), (6997, 6997, (5, 1)), (6998, 6998, (6, 1)),
+  (6999, 6999, (7, 1)), (7000, 7000, (8, 1)), (7001, 7001, (9, 1)),
+  (7088, 7088, (0, 1)), (7089, 7089, (1, 1)), (7090, 7090, (2, 1)),
+  (7091, 7091, (3, 1)), (7092, 7092, (4, 1)), (7093, 7093, (5, 1)),
+  (7094, 7094, (6, 1)), (7095, 7095, (7, 1)), (7096, 7096, (8, 1)),
+  (7097, 7097, (9, 1)), (7232, 7232, (0, 1)), (7233, 7233, (1, 1)),
+  (7234, 7234, (2, 1)), (7235, 7235, (3, 1)), (7236, 7236, (4, 1)),
+  (7237, 7237, (5, 1)), (7238, 7238, (6, 1)), (7239, 7239, (7, 1)),
+  (7240, 7240, (8, 1)), (7241, 7241, (9, 1)), (7248, 7248, (0, 1)),
+  (7249, 7249, (1, 1)), (7250, 7250, (2, 1)), (7251, 7251, (3, 1)),
+  (7252, 7252, (4, 1)), (7253, 7253, (5, 1)), (7254, 7254, (6, 1)),
+  (7255, 7255, (7, 1)), (7256, 7256, (8, 1)), (7257, 7257, (9, 1)),
+  (8304, 8304, (0, 1)), (8308, 8308, (4, 1)), (8309, 8309, (5, 1)),
+  (8310, 8310, (6, 1)), (8311, 8311, (7, 1)), (8312, 8312, (8, 1)),
+  (8313, 8313, (9, 1)), (8320, 8320, (0, 1)), (8321, 8321, (1, 1)),
+  (8322, 8322, (2, 1)), (8323, 8323, (3, 1)), (8324, 8324, (4, 1)),
+  (8325, 8325, (5, 1)), (8326, 8326, (6, 1)), (8327, 8327, (7, 1)),
+  (8328, 8328, (8, 1)), (8329, 8329, (9, 1)), (8528, 8528, (1, 7)),
+  (8529, 8529, (1, 9)), (8530, 8530, (1, 10)), (8531, 8531, (1, 3)),
+  (8532, 8532, (2, 3)), (8533, 8533, (1, 5)), (8534, 8534, (2, 5)),
+  (8535, 8535, (3, 5)), (8536, 8536, (4, 5)), (8537, 8537, (1, 6)),
+  (8538, 8538, (5, 6)), (8539, 8539, (1, 8)), (8540, 8540, (3, 8)),
+  (8541, 8541, (5, 8)), (8542, 8542, (7, 8)), (8543, 8544, (1, 1)),
+  (8545, 8545, (2, 1)), (8546, 8546, (3, 1)), (8547, 8547, (4, 1)),
+  (8548, 8548, (5, 1)), (8549, 8549, (6, 1)), (8550, 8550, (7, 1)),
+  (8551, 8551, (8, 1)), (8552, 8552, (9, 1)), (8553, 8553, (10, 1)),
+  (8554, 8554, (11, 1)), (8555, 8555, (12, 1)), (8556, 8556, (50, 1)),
+  (8557, 8557, (100, 1)), (8558, 8558, (500, 1)), (8559, 8559, (1000, 1)),
+  (8560, 8560, (1, 1)), (8561, 8561, (2, 1)), (8562, 8562, (3, 1)),
+  (8563, 8563, (4, 1)), (8564, 8564, (5, 1)), (8565, 8565, (6, 1)),
+  (8566, 8566, (7, 1)), (8567, 8567, (8, 1)), (8568, 8568, (9, 1)),
+  (8569, 8569, (10, 1)), (8570, 8570, (11, 1)), (8571, 8571, (12, 1)),
+  (8572, 8572, (50, 1)), (8573, 8573, (100, 1)), (8574, 8574, (500, 1)),
+  (8575, 8576, (1000, 1)), (8577, 8577, (5000, 1)), (8578, 8578, (10000, 1)),
+  (8581, 8581, (6, 1)), (8582, 8582, (50, 1)), (8583, 8583, (50000, 1)),
+  (8584, 8584, (100000, 1)), (8585, 8585, (0, 1)), (9312, 9312, (1, 1)),
+  (9313, 9313, (2, 1)), (9314, 9314, (3, 1)), (9315, 9315, (4, 1)),
+  (9316, 9316, (5, 1)), (9317, 9317, (6, 1)), (9318, 9318, (7, 1)),
+  (9319, 9319, (8, 1)), (9320, 9320, (9, 1)), (9321, 9321, (10, 1)),
+  (9322, 9322, (11, 1)), (9323, 9323, (12, 1)), (9324, 9324, (13, 1)),
+  (9325, 9325, (14, 1)), (9326, 9326, (15, 1)), (9327, 9327, (16, 1)),
+  (9328, 9328, (17, 1)), (9329, 9329, (18, 1)), (9330, 9330, (19, 1)),
+  (9331, 9331, (20, 1)), (9332, 9332, (1, 1)), (9333, 9333, (2, 1)),
+  (9334, 9334, (3, 1)), (9335, 9335, (4, 1)), (9336, 9336, (5, 1)),
+  (9337, 9337, (6, 1)), (9338, 9338, (7, 1)), (9339, 9339, (8, 1)),
+  (9340, 9340, (9, 1)), (9341, 9341, (10, 1)), (9342, 9342, (11, 1)),
+  (9343, 9343, (12, 1)), (9344, 9344, (13, 1)), (9345, 9345, (14, 1)),
+  (9346, 9346, (15, 1)), (9347, 9347, (16, 1)), (9348, 9348, (17, 1)),
+  (9349, 9349, (18, 1)), (9350, 9350, (19, 1)), (9351, 9351, (20, 1)),
+  (9352, 9352, (1, 1)), (9353, 9353, (2, 1)), (9354, 9354, (3, 1)),
+  (9355, 9355, (4, 1)), (9356, 9356, (5, 1)), (9357, 9357, (6, 1)),
+  (9358, 9358, (7, 1)), (9359, 9359, (8, 1)), (9360, 9360, (9, 1)),
+  (9361, 9361, (10, 1)), (9362, 9362, (11, 1)), (9363, 9363, (12, 1)),
+  (9364, 9364, (13, 1)), (9365, 9365, (14, 1)), (9366, 9366, (15, 1)),
+  (9367, 9367, (16, 1)), (9368, 9368, (17, 1)), (9369, 9369, (18, 1)),
+  (9370, 9370, (19, 1)), (9371, 9371, (20, 1)), (9450, 9450, (0, 1)),
+  (9451, 9451, (11, 1)), (9452, 9452, (12, 1)), (9453, 9453, (13, 1)),
+  (9454, 9454, (14, 1)), (9455, 9455, (15, 1)), (9456, 9456, (16, 1)),
+  (9457, 9457, (17, 1)), (9458, 9458, (18, 1)), (9459, 9459, (19, 1)),
+  (9460, 9460, (20, 1)), (9461, 9461, (1, 1)), (9462, 9462, (2, 1)),
+  (9463, 9463, (3, 1)), (9464, 9464, (4, 1)), (9465, 9465, (5, 1)),
+  (9466, 9466, (6, 1)), (9467, 9467, (7, 1)), (9468, 9468, (8, 1)),
+  (9469, 9469, (9, 1)), (9470, 9470, (10, 1)), (9471, 9471, (0, 1)),
+  (10102, 10102, (1, 1)), (10103, 10103, (2, 1)), (10104, 10104, (3, 1)),
+  (10105, 10105, (4, 1)), (10106, 10106, (5, 1)), (10107, 10107, (6, 1)),
+  (10108, 10108, (7, 1)), (10109, 10109, (8, 1)), (10110, 10110, (9, 1)),
+  (10111, 10111, (10, 1)), (10112, 10112, (1, 1)), (10113, 10113, (2, 1)),
+  (10114, 10114, (3, 1)), (10115, 10115, (4, 1)), (10116, 10116, (5, 1)),
+  (10117, 10117, (6, 1)), (10118, 10118, (7, 1)), (10119, 10119, (8, 1)),
+  (10120, 10120, (9, 1)), (10121, 10121, (10, 1)), (10122, 10122, (1, 1)),
+  (10123, 10123, (2, 1)), (10124, 10124, (3, 1)), (10125, 10125, (4, 1)),
+  (10126, 10126, (5, 1)), (10127, 10127, (6, 1)), (10128, 10128, (7, 1)),
+  (10129, 10129, (8, 1)), (10130, 10130, (9, 1)), (10131, 10131, (10, 1)),
+  (11517, 11517, (1, 2)), (12295, 12295, (0, 1)), (12321, 12321, (1, 1)),
+  (12322, 12322, (2, 1)), (12323, 12323, (3, 1)), (12324, 12324, (4, 1)),
+  (12325, 12325, (5, 1)), (12326, 12326, (6, 1)), (12327, 12327, (7, 1)),
+  (12328, 12328, (8, 1)), (12329, 12329, (9, 1)), (12344, 12344, (10, 1)),
+  (12345, 12345, (20, 1)), (12346, 12346, (30, 1)), (12690, 12690, (1, 1)),
+  (12691, 12691, (2, 1)), (12692, 12692, (3, 1)), (12693, 12693, (4, 1)),
+  (12832, 12832, (1, 1)), (12833, 12833, (2, 1)), (12834, 12834, (3, 1)),
+  (12835, 12835, (4, 1)), (12836, 12836, (5, 1)), (12837, 12837, (6, 1)),
+  (12838, 12838, (7, 1)), (12839, 12839, (8, 1)), (12840, 12840, (9, 1)),
+  (12841, 12841, (10, 1)), (12872, 12872, (10, 1)), (12873, 12873, (20, 1)),
+  (12874, 12874, (30, 1)), (12875, 12875, (40, 1)), (12876, 12876, (50, 1)),
+  (12877, 12877, (60, 1)), (12878, 12878, (70, 1)), (12879, 12879, (80, 1)),
+  (12881, 12881, (21, 1)), (12882, 12882, (22, 1)), (12883, 12883, (23, 1)),
+  (12884, 12884, (24, 1)), (12885, 12885, (25, 1)), (12886, 12886, (26, 1)),
+  (12887, 12887, (27, 1)), (12888, 12888, (28, 1)), (12889, 12889, (29, 1)),
+  (12890, 12890, (30, 1)), (12891, 12891, (31, 1)), (12892, 12892, (32, 1)),
+  (12893, 12893, (33, 1)), (12894, 12894, (34, 1)), (12895, 12895, (35, 1)),
+  (12928, 12928, (1, 1)), (12929, 12929, (2, 1)), (12930, 12930, (3, 1)),
+  (12931, 12931, (4, 1)), (12932, 12932, (5, 1)), (12933, 12933, (6, 1)),
+  (12934, 12934, (7, 1)), (12935, 12935, (8, 1)), (12936, 12936, (9, 1)),
+  (12937, 12937, (10, 1)), (12977, 12977, (36, 1)), (12978, 12978, (37, 1)),
+  (12979, 12979, (38, 1)), (12980, 12980, (39, 1)), (12981, 12981, (40, 1)),
+  (12982, 12982, (41, 1)), (12983, 12983, (42, 1)), (12984, 12984, (43, 1)),
+  (12985, 12985, (44, 1)), (12986, 12986, (45, 1)), (12987, 12987, (46, 1)),
+  (12988, 12988, (47, 1)), (12989, 12989, (48, 1)), (12990, 12990, (49, 1)),
+  (12991, 12991, (50, 1)), (13317, 13317, (5, 1)), (13443, 13443, (2, 1)),
+  (14378, 14378, (5, 1)), (15181, 15181, (7, 1)), (19968, 19968, (1, 1)),
+  (19971, 19971, (7, 1)), (19975, 19975, (10000, 1)), (19977, 19977, (3, 1)),
+  (20061, 20061, (9, 1)), (20108, 20108, (2, 1)), (20116, 20116, (5, 1)),
+  (20118, 20118, (4, 1)), (20159, 20159, (100000000, 1)),
+  (20160, 20160, (10, 1)), (20191, 20191, (1000, 1)), (20200, 20200, (3, 1)),
+  (20237, 20237, (5, 1)), (20336, 20336, (100, 1)),
+  (20740, 20740, (100000000, 1)), (20806, 20806, (1000000000000, 1)),
+  (20841, 20841, (2, 1)), (20843, 20843, (8, 1)), (20845, 20845, (6, 1)),
+  (21313, 21313, (10, 1)), (21315, 21315, (1000, 1)), (21316, 21316, (20, 1)),
+  (21317, 21317, (30, 1)), (21324, 21324, (40, 1)), (21441, 21444, (3, 1)),
+  (22235, 22235, (4, 1)), (22769, 22769, (1, 1)), (22777, 22777, (1, 1)),
+  (24186, 24186, (1, 1)), (24318, 24318, (9, 1)), (24319, 24319, (20, 1)),
+  (24332, 24332, (1, 1)), (24333, 24333, (2, 1)), (24334, 24334, (3, 1)),
+  (24336, 24336, (2, 1)), (25342, 25342, (10, 1)), (25420, 25420, (8, 1)),
+  (26578, 26578, (7, 1)), (28422, 28422, (7, 1)), (29590, 29590, (9, 1)),
+  (30334, 30334, (100, 1)), (32902, 32902, (4, 1)), (33836, 33836, (10000, 1)),
+  (36014, 36014, (2, 1)), (36019, 36019, (2, 1)), (36144, 36144, (2, 1)),
+  (38433, 38433, (1000, 1)), (38470, 38470, (6, 1)), (38476, 38476, (100, 1)),
+  (38520, 38520, (6, 1)), (38646, 38646, (0, 1)), (42528, 42528, (0, 1)),
+  (42529, 42529, (1, 1)), (42530, 42530, (2, 1)), (42531, 42531, (3, 1)),
+  (42532, 42532, (4, 1)), (42533, 42533, (5, 1)), (42534, 42534, (6, 1)),
+  (42535, 42535, (7, 1)), (42536, 42536, (8, 1)), (42537, 42537, (9, 1)),
+  (42726, 42726, (1, 1)), (42727, 42727, (2, 1)), (42728, 42728, (3, 1)),
+  (42729, 42729, (4, 1)), (42730, 42730, (5, 1)), (42731, 42731, (6, 1)),
+  (42732, 42732, (7, 1)), (42733, 42733, (8, 1)), (42734, 42734, (9, 1)),
+  (42735, 42735, (0, 1)), (43056, 43056, (1, 4)), (43057, 43057, (1, 2)),
+  (43058, 43058, (3, 4)), (43059, 43059, (1, 16)), (43060, 43060, (1, 8)),
+  (43061, 43061, (3, 16)), (43216, 43216, (0, 1)), (43217, 43217, (1, 1)),
+  (43218, 43218, (2, 1)), (43219, 43219, (3, 1)), (43220, 43220, (4, 1)),
+  (43221, 43221, (5, 1)), (43222, 43222, (6, 1)), (43223, 43223, (7, 1)),
+  (43224, 43224, (8, 1)), (43225, 43225, (9, 1)), (43264, 43264, (0, 1)),
+  (43265, 43265, (1, 1)), (43266, 43266, (2, 1)), (43267, 43267, (3, 1)),
+  (43268, 43268, (4, 1)), (43269, 43269, (5, 1)), (43270, 43270, (6, 1)),
+  (43271, 43271, (7, 1)), (43272, 43272, (8, 1)), (43273, 43273, (9, 1)),
+  (43472, 43472, (0, 1)), (43473, 43473, (1, 1)), (43474, 43474, (2, 1)),
+  (43475, 43475, (3, 1)), (43476, 43476, (4, 1)), (43477, 43477, (5, 1)),
+  (43478, 43478, (6, 1)), (43479, 43479, (7, 1)), (43480, 43480, (8, 1)),
+  (43481, 43481, (9, 1)), (43504, 43504, (0, 1)), (43505, 43505, (1, 1)),
+  (43506, 43506, (2, 1)), (43507, 43507, (3, 1)), (43508, 43508, (4, 1)),
+  (43509, 43509, (5, 1)), (43510, 43510, (6, 1)), (43511, 43511, (7, 1)),
+  (43512, 43512, (8, 1)), (43513, 43513, (9, 1)), (43600, 43600, (0, 1)),
+  (43601, 43601, (1, 1)), (43602, 43602, (2, 1)), (43603, 43603, (3, 1)),
+  (43604, 43604, (4, 1)), (43605, 43605, (5, 1)), (43606, 43606, (6, 1)),
+  (43607, 43607, (7, 1)), (43608, 43608, (8, 1)), (43609, 43609, (9, 1)),
+  (44016, 44016, (0, 1)), (44017, 44017, (1, 1)), (44018, 44018, (2, 1)),
+  (44019, 44019, (3, 1)), (44020, 44020, (4, 1)), (44021, 44021, (5, 1)),
+  (44022, 44022, (6, 1)), (44023, 44023, (7, 1)), (44024, 44024, (8, 1)),
+  (44025, 44025, (9, 1)), (63851, 63851, (3, 1)), (63859, 63859, (10, 1)),
+  (63864, 63864, (2, 1)), (63922, 63922, (0, 1)), (63953, 63953, (6, 1)),
+  (63955, 63955, (6, 1)), (63997, 63997, (10, 1)), (65296, 65296, (0, 1)),
+  (65297, 65297, (1, 1)), (65298, 65298, (2, 1)), (65299, 65299, (3, 1)),
+  (65300, 65300, (4, 1)), (65301, 65301, (5, 1)), (65302, 65302, (6, 1)),
+  (65303, 65303, (7, 1)), (65304, 65304, (8, 1)), (65305, 65305, (9, 1)),
+  (65799, 65799, (1, 1)), (65800, 65800, (2, 1)), (65801, 65801, (3, 1)),
+  (65802, 65802, (4, 1)), (65803, 65803, (5, 1)), (65804, 65804, (6, 1)),
+  (65805, 65805, (7, 1)), (65806, 65806, (8, 1)), (65807, 65807, (9, 1)),
+  (65808, 65808, (10, 1)), (65809, 65809, (20, 1)), (65810, 65810, (30, 1)),
+  (65811, 65811, (40, 1)), (65812, 65812, (50, 1)), (65813, 65813, (60, 1)),
+  (65814, 65814, (70, 1)), (65815, 65815, (80, 1)), (65816, 65816, (90, 1)),
+  (65817, 65817, (100, 1)), (65818, 65818, (200, 1)), (65819, 65819, (300, 1)),
+  (65820, 65820, (400, 1)), (65821, 65821, (500, 1)), (65822, 65822, (600, 1)),
+  (65823, 65823, (700, 1)), (65824, 65824, (800, 1)), (65825, 65825, (900, 1)),
+  (65826, 65826, (1000, 1)), (65827, 65827, (2000, 1)),
+  (65828, 65828, (3000, 1)), (65829, 65829, (4000, 1)),
+  (65830, 65830, (5000, 1)), (65831, 65831, (6000, 1)),
+  (65832, 65832, (7000, 1)), (65833, 65833, (8000, 1)),
+  (65834, 65834, (9000, 1)), (65835, 65835, (10000, 1)),
+  (65836, 65836, (20000, 1)), (65837, 65837, (30000, 1)),
+  (65838, 65838, (40000, 1)), (65839, 65839, (50000, 1)),
+  (65840, 65840, (60000, 1)), (65841, 65841, (70000, 1)),
+  (65842, 65842, (80000, 1)), (65843, 65843, (90000, 1)),
+  (65856, 65856, (1, 4)), (65857, 65857, (1, 2)), (65858, 65858, (1, 1)),
+  (65859, 65859, (5, 1)), (65860, 65860, (50, 1)), (65861, 65861, (500, 1)),
+  (65862, 65862, (5000, 1)), (65863, 65863, (50000, 1)),
+  (65864, 65864, (5, 1)), (65865, 65865, (10, 1)), (65866, 65866, (50, 1)),
+  (65867, 65867, (100, 1)), (65868, 65868, (500, 1)),
+  (65869, 65869, (1000, 1)), (65870, 65870, (5000, 1)), (65871, 65871, (5, 1)),
+  (65872, 65872, (10, 1)), (65873, 65873, (50, 1)), (65874, 65874, (100, 1)),
+  (65875, 65875, (500, 1)), (65876, 65876, (1000, 1)),
+  (65877, 65877, (10000, 1)), (65878, 65878, (50000, 1)),
+  (65879, 65879, (10, 1)), (65880, 65882, (1, 1)), (65883, 65886, (2, 1)),
+  (65887, 65887, (5, 1)), (65888, 65892, (10, 1)), (65893, 65893, (30, 1)),
+  (65894, 65897, (50, 1)), (65898, 65898, (100, 1)), (65899, 65899, (300, 1)),
+  (65900, 65904, (500, 1)), (65905, 65905, (1000, 1)),
+  (65906, 65906, (5000, 1)), (65907, 65907, (5, 1)), (65908, 65908, (50, 1)),
+  (65909, 65910, (1, 2)), (65911, 65911, (2, 3)), (65912, 65912, (3, 4)),
+  (65930, 65930, (0, 1)), (65931, 65931, (1, 4)), (66273, 66273, (1, 1)),
+  (66274, 66274, (2, 1)), (66275, 66275, (3, 1)), (66276, 66276, (4, 1)),
+  (66277, 66277, (5, 1)), (66278, 66278, (6, 1)), (66279, 66279, (7, 1)),
+  (66280, 66280, (8, 1)), (66281, 66281, (9, 1)), (66282, 66282, (10, 1)),
+  (66283, 66283, (20, 1)), (66284, 66284, (30, 1)), (66285, 66285, (40, 1)),
+  (66286, 66286, (50, 1)), (66287, 66287, (60, 1)), (66288, 66288, (70, 1)),
+  (66289, 66289, (80, 1)), (66290, 66290, (90, 1)), (66291, 66291, (100, 1)),
+  (66292, 66292, (200, 1)), (66293, 66293, (300, 1)), (66294, 66294, (400, 1)),
+  (66295, 66295, (500, 1)), (66296, 66296, (600, 1)), (66297, 66297, (700, 1)),
+  (66298, 66298, (800, 1)), (66299, 66299, (900, 1)), (66336, 66336, (1, 1)),
+  (66337, 66337, (5, 1)), (66338, 66338, (10, 1)), (66339, 66339, (50, 1)),
+  (66369, 66369, (90, 1)), (66378, 66378, (900, 1)), (66513, 66513, (1, 1)),
+  (66514, 66514, (2, 1)), (66515, 66515, (10, 1)), (66516, 66516, (20, 1)),
+  (66517, 66517, (100, 1)), (66720, 66720, (0, 1)), (66721, 66721, (1, 1)),
+  (66722, 66722, (2, 1)), (66723, 66723, (3, 1)), (66724, 66724, (4, 1)),
+  (66725, 66725, (5, 1)), (66726, 66726, (6, 1)), (66727, 66727, (7, 1)),
+  (66728, 66728, (8, 1)), (66729, 66729, (9, 1)), (67672, 67672, (1, 1)),
+  (67673, 67673, (2, 1)), (67674, 67674, (3, 1)), (67675, 67675, (10, 1)),
+  (67676, 67676, (20, 1)), (67677, 67677, (100, 1)), (67678, 67678, (1000, 1)),
+  (67679, 67679, (10000, 1)), (67705, 67705, (1, 1)), (67706, 67706, (2, 1)),
+  (67707, 67707, (3, 1)), (67708, 67708, (4, 1)), (67709, 67709, (5, 1)),
+  (67710, 67710, (10, 1)), (67711, 67711, (20, 1)), (67751, 67751, (1, 1)),
+  (67752, 67752, (2, 1)), (67753, 67753, (3, 1)), (67754, 67755, (4, 1)),
+  (67756, 67756, (5, 1)), (67757, 67757, (10, 1)), (67758, 67758, (20, 1)),
+  (67759, 67759, (100, 1)), (67835, 67835, (1, 1)), (67836, 67836, (5, 1)),
+  (67837, 67837, (10, 1)), (67838, 67838, (20, 1)), (67839, 67839, (100, 1)),
+  (67862, 67862, (1, 1)), (67863, 67863, (10, 1)), (67864, 67864, (20, 1)),
+  (67865, 67865, (100, 1)), (67866, 67866, (2, 1)), (67867, 67867, (3, 1)),
+  (68028, 68028, (11, 12)), (68029, 68029, (1, 2)), (68032, 68032, (1, 1)),
+  (68033, 68033, (2, 1)), (68034, 68034, (3, 1)), (68035, 68035, (4, 1)),
+  (68036, 68036, (5, 1)), (68037, 68037, (6, 1)), (68038, 68038, (7, 1)),
+  (68039, 68039, (8, 1)), (68040, 68040, (9, 1)), (68041, 68041, (10, 1)),
+  (68042, 68042, (20, 1)), (68043, 68043, (30, 1)), (68044, 68044, (40, 1)),
+  (68045, 68045, (50, 1)), (68046, 68046, (60, 1)), (68047, 68047, (70, 1)),
+  (68050, 68050, (100, 1)), (68051, 68051, (200, 1)), (68052, 68052, (300, 1)),
+  (68053, 68053, (400, 1)), (68054, 68054, (500, 1)), (68055, 68055, (600, 1)),
+  (68056, 68056, (700, 1)), (68057, 68057, (800, 1)), (68058, 68058, (900, 1)),
+  (68059, 68059, (1000, 1)), (68060, 68060, (2000, 1)),
+  (68061, 68061, (3000, 1)), (68062, 68062, (4000, 1)),
+  (68063, 68063, (5000, 1)), (68064, 68064, (6000, 1)),
+  (68065, 68065, (7000, 1)), (68066, 68066, (8000, 1)),
+  (68067, 68067, (9000, 1)), (68068, 68068, (10000, 1)),
+  (68069, 68069, (20000, 1)), (68070, 68070, (30000, 1)),
+  (68071, 68071, (40000, 1)), (68072, 68072, (50000, 1)),
+  (68073, 68073, (60000, 1)), (68074, 68074, (70000, 1)),
+  (68075, 68075, (80000, 1)), (68076, 68076, (90000, 1)),
+  (68077, 68077, (100000, 1)), (68078, 68078, (200000, 1)),
+  (68079, 68079, (300000, 1)), (68080, 68080, (400000, 1)),
+  (68081, 68081, (500000, 1)), (68082, 68082, (600000, 1)),
+  (68083, 68083, (700000, 1)), (68084, 68084, (800000, 1)),
+  (68085, 68085, (900000, 1)), (68086, 68086, (1, 12)), (68087, 68087, (1, 6)),
+  (68088, 68088, (1, 4)), (68089, 68089, (1, 3)), (68090, 68090, (5, 12)),
+  (68091, 68091, (1, 2)), (68092, 68092, (7, 12)), (68093, 68093, (2, 3)),
+  (68094, 68094, (3, 4)), (68095, 68095, (5, 6)), (68160, 68160, (1, 1)),
+  (68161, 68161, (2, 1)), (68162, 68162, (3, 1)), (68163, 68163, (4, 1)),
+  (68164, 68164, (10, 1)), (68165, 68165, (20, 1)), (68166, 68166, (100, 1)),
+  (68167, 68167, (1000, 1)), (68221, 68221, (1, 1)), (68222, 68222, (50, 1)),
+  (68253, 68253, (1, 1)), (68254, 68254, (10, 1)), (68255, 68255, (20, 1)),
+  (68331, 68331, (1, 1)), (68332, 68332, (5, 1)), (68333, 68333, (10, 1)),
+  (68334, 68334, (20, 1)), (68335, 68335, (100, 1)), (68440, 68440, (1, 1)),
+  (68441, 68441, (2, 1)), (68442, 68442, (3, 1)), (68443, 68443, (4, 1)),
+  (68444, 68444, (10, 1)), (68445, 68445, (20, 1)), (68446, 68446, (100, 1)),
+  (68447, 68447, (1000, 1)), (68472, 68472, (1, 1)), (68473, 68473, (2, 1)),
+  (68474, 68474, (3, 1)), (68475, 68475, (4, 1)), (68476, 68476, (10, 1)),
+  (68477, 68477, (20, 1)), (68478, 68478, (100, 1)), (68479, 68479, (1000, 1)),
+  (68521, 68521, (1, 1)), (68522, 68522, (2, 1)), (68523, 68523, (3, 1)),
+  (68524, 68524, (4, 1)), (68525, 68525, (10, 1)), (68526, 68526, (20, 1)),
+  (68527, 68527, (100, 1)), (68858, 68858, (1, 1)), (68859, 68859, (5, 1)),
+  (68860, 68860, (10, 1)), (68861, 68861, (50, 1)), (68862, 68862, (100, 1)),
+  (68863, 68863, (1000, 1)), (69216, 69216, (1, 1)), (69217, 69217, (2, 1)),
+  (69218, 69218, (3, 1)), (69219, 69219, (4, 1)), (69220, 69220, (5, 1)),
+  (69221, 69221, (6, 1)), (69222, 69222, (7, 1)), (69223, 69223, (8, 1)),
+  (69224, 69224, (9, 1)), (69225, 69225, (10, 1)), (69226, 69226, (20, 1)),
+  (69227, 69227, (30, 1)), (69228, 69228, (40, 1)), (69229, 69229, (50, 1)),
+  (69230, 69230, (60, 1)), (69231, 69231, (70, 1)), (69232, 69232, (80, 1)),
+  (69233, 69233, (90, 1)), (69234, 69234, (100, 1)), (69235, 69235, (200, 1)),
+  (69236, 69236, (300, 1)), (69237, 69237, (400, 1)), (69238, 69238, (500, 1)),
+  (69239, 69239, (600, 1)), (69240, 69240, (700, 1)), (69241, 69241, (800, 1)),
+  (69242, 69242, (900, 1)), (69243, 69243, (1, 2)), (69244, 69244, (1, 4)),
+  (69245, 69245, (1, 3)), (69246, 69246, (2, 3)), (69714, 69714, (1, 1)),
+  (69715, 69715, (2, 1)), (69716, 69716, (3, 1)), (69717, 69717, (4, 1)),
+  (69718, 69718, (5, 1)), (69719, 69719, (6, 1)), (69720, 69720, (7, 1)),
+  (69721, 69721, (8, 1)), (69722, 69722, (9, 1)), (69723, 69723, (10, 1)),
+  (69724, 69724, (20, 1)), (69725, 69725, (30, 1)), (69726, 69726, (40, 1)),
+  (69727, 69727, (50, 1)), (69728, 69728, (60, 1)), (69729, 69729, (70, 1)),
+  (69730, 69730, (80, 1)), (69731, 69731, (90, 1)), (69732, 69732, (100, 1)),
+  (69733, 69733, (1000, 1)), (69734, 69734, (0, 1)), (69735, 69735, (1, 1)),
+  (69736, 69736, (2, 1)), (69737, 69737, (3, 1)), (69738, 69738, (4, 1)),
+  (69739, 69739, (5, 1)), (69740, 69740, (6, 1)), (69741, 69741, (7, 1)),
+  (69742, 69742, (8, 1)), (69743, 69743, (9, 1)), (69872, 69872, (0, 1)),
+  (69873, 69873, (1, 1)), (69874, 69874, (2, 1)), (69875, 69875, (3, 1)),
+  (69876, 69876, (4, 1)), (69877, 69877, (5, 1)), (69878, 69878, (6, 1)),
+  (69879, 69879, (7, 1)), (69880, 69880, (8, 1)), (69881, 69881, (9, 1)),
+  (69942, 69942, (0, 1)), (69943, 69943, (1, 1)), (69944, 69944, (2, 1)),
+  (69945, 69945, (3, 1)), (69946, 69946, (4, 1)), (69947, 69947, (5, 1)),
+  (69948, 69948, (6, 1)), (69949, 69949, (7, 1)), (69950, 69950, (8, 1)),
+  (69951, 69951, (9, 1)), (70096, 70096, (0, 1)), (70097, 70097, (1, 1)),
+  (70098, 70098, (2, 1)), (70099, 70099, (3, 1)), (70100, 70100, (4, 1)),
+  (70101, 70101, (5, 1)), (70102, 70102, (6, 1)), (70103, 70103, (7, 1)),
+  (70104, 70104, (8, 1)), (70105, 70105, (9, 1)), (70113, 70113, (1, 1)),
+  (70114, 70114, (2, 1)), (70115, 70115, (3, 1)), (70116, 70116, (4, 1)),
+  (70117, 70117, (5, 1)), (70118, 70118, (6, 1)), (70119, 70119, (7, 1)),
+  (70120, 70120, (8, 1)), (70121, 70121, (9, 1)), (70122, 70122, (10, 1)),
+  (70123, 70123, (20, 1)), (70124, 70124, (30, 1)), (70125, 70125, (40, 1)),
+  (70126, 70126, (50, 1)), (70127, 70127, (60, 1)), (70128, 70128, (70, 1)),
+  (70129, 70129, (80, 1)), (70130, 70130, (90, 1)), (70131, 70131, (100, 1)),
+  (70132, 70132, (1000, 1)), (70384, 70384, (0, 1)), (70385, 70385, (1, 1)),
+  (70386, 70386, (2, 1)), (70387, 70387, (3, 1)), (70388, 70388, (4, 1)),
+  (70389, 70389, (5, 1)), (70390, 70390, (6, 1)), (70391, 70391, (7, 1)),
+  (70392, 70392, (8, 1)), (70393, 70393, (9, 1)), (70736, 70736, (0, 1)),
+  (70737, 70737, (1, 1)), (70738, 70738, (2, 1)), (70739, 70739, (3, 1)),
+  (70740, 70740, (4, 1)), (70741, 70741, (5, 1)), (70742, 70742, (6, 1)),
+  (70743, 70743, (7, 1)), (70744, 70744, (8, 1)), (70745, 70745, (9, 1)),
+  (70864, 70864, (0, 1)), (70865, 70865, (1, 1)), (70866, 70866, (2, 1)),
+  (70867, 70867, (3, 1)), (70868, 70868, (4, 1)), (70869, 70869, (5, 1)),
+  (70870, 70870, (6, 1)), (70871, 70871, (7, 1)), (70872, 70872, (8, 1)),
+  (70873, 70873, (9, 1)), (71248, 71248, (0, 1)), (71249, 71249, (1, 1)),
+  (71250, 71250, (2, 1)), (71251, 71251, (3, 1)), (71252, 71252, (4, 1)),
+  (71253, 71253, (5, 1)), (71254, 71254, (6, 1)), (71255, 71255, (7, 1)),
+  (71256, 71256, (8, 1)), (71257, 71257, (9, 1)), (71360, 71360, (0, 1)),
+  (71361, 71361, (1, 1)), (71362, 71362, (2, 1)), (71363, 71363, (3, 1)),
+  (71364, 71364, (4, 1)), (71365, 71365, (5, 1)), (71366, 71366, (6, 1)),
+  (71367, 71367, (7, 1)), (71368, 71368, (8, 1)), (71369, 71369, (9, 1)),
+  (71472, 71472, (0, 1)), (71473, 71473, (1, 1)), (71474, 71474, (2, 1)),
+  (71475, 71475, (3, 1)), (71476, 71476, (4, 1)), (71477, 71477, (5, 1)),
+  (71478, 71478, (6, 1)), (71479, 71479, (7, 1)), (71480, 71480, (8, 1)),
+  (71481, 71481, (9, 1)), (71482, 71482, (10, 1)), (71483, 71483, (20, 1)),
+  (71904, 71904, (0, 1)), (71905, 71905, (1, 1)), (71906, 71906, (2, 1)),
+  (71907, 71907, (3, 1)), (71908, 71908, (4, 1)), (71909, 71909, (5, 1)),
+  (71910, 71910, (6, 1)), (71911, 71911, (7, 1)), (71912, 71912, (8, 1)),
+  (71913, 71913, (9, 1)), (71914, 71914, (10, 1)), (71915, 71915, (20, 1)),
+  (71916, 71916, (30, 1)), (71917, 71917, (40, 1)), (71918, 71918, (50, 1)),
+  (71919, 71919, (60, 1)), (71920, 71920, (70, 1)), (71921, 71921, (80, 1)),
+  (71922, 71922, (90, 1)), (72784, 72784, (0, 1)), (72785, 72785, (1, 1)),
+  (72786, 72786, (2, 1)), (72787, 72787, (3, 1)), (72788, 72788, (4, 1)),
+  (72789, 72789, (5, 1)), (72790, 72790, (6, 1)), (72791, 72791, (7, 1)),
+  (72792, 72792, (8, 1)), (72793, 72793, (9, 1)), (72794, 72794, (1, 1)),
+  (72795, 72795, (2, 1)), (72796, 72796, (3, 1)), (72797, 72797, (4, 1)),
+  (72798, 72798, (5, 1)), (72799, 72799, (6, 1)), (72800, 72800, (7, 1)),
+  (72801, 72801, (8, 1)), (72802, 72802, (9, 1)), (72803, 72803, (10, 1)),
+  (72804, 72804, (20, 1)), (72805, 72805, (30, 1)), (72806, 72806, (40, 1)),
+  (72807, 72807, (50, 1)), (72808, 72808, (60, 1)), (72809, 72809, (70, 1)),
+  (72810, 72810, (80, 1)), (72811, 72811, (90, 1)), (72812, 72812, (100, 1)),
+  (74752, 74752, (2, 1)), (74753, 74753, (3, 1)), (74754, 74754, (4, 1)),
+  (74755, 74755, (5, 1)), (74756, 74756, (6, 1)), (74757, 74757, (7, 1)),
+  (74758, 74758, (8, 1)), (74759, 74759, (9, 1)), (74760, 74760, (3, 1)),
+  (74761, 74761, (4, 1)), (74762, 74762, (5, 1)), (74763, 74763, (6, 1)),
+  (74764, 74764, (7, 1)), (74765, 74765, (8, 1)), (74766, 74766, (9, 1)),
+  (74767, 74767, (4, 1)), (74768, 74768, (5, 1)), (74769, 74769, (6, 1)),
+  (74770, 74770, (7, 1)), (74771, 74771, (8, 1)), (74772, 74772, (9, 1)),
+  (74773, 74773, (1, 1)), (74774, 74774, (2, 1)), (74775, 74775, (3, 1)),
+  (74776, 74776, (4, 1)), (74777, 74777, (5, 1)), (74778, 74778, (6, 1)),
+  (74779, 74779, (7, 1)), (74780, 74780, (8, 1)), (74781, 74781, (9, 1)),
+  (74782, 74782, (1, 1)), (74783, 74783, (2, 1)), (74784, 74784, (3, 1)),
+  (74785, 74785, (4, 1)), (74786, 74786, (5, 1)), (74787, 74787, (2, 1)),
+  (74788, 74789, (3, 1)), (74790, 74790, (4, 1)), (74791, 74791, (5, 1)),
+  (74792, 74792, (6, 1)), (74793, 74793, (7, 1)), (74794, 74794, (8, 1)),
+  (74795, 74795, (9, 1)), (74796, 74796, (1, 1)), (74797, 74797, (2, 1)),
+  (74798, 74799, (3, 1)), (74800, 74800, (4, 1)), (74801, 74801, (5, 1)),
+  (74802, 74802, (216000, 1)), (74803, 74803, (432000, 1)),
+  (74804, 74804, (1, 1)), (74805, 74805, (2, 1)), (74806, 74807, (3, 1)),
+  (74808, 74808, (4, 1)), (74809, 74809, (5, 1)), (74810, 74811, (3, 1)),
+  (74812, 74815, (4, 1)), (74816, 74816, (6, 1)), (74817, 74819, (7, 1)),
+  (74820, 74821, (8, 1)), (74822, 74825, (9, 1)), (74826, 74826, (2, 1)),
+  (74827, 74827, (3, 1)), (74828, 74828, (4, 1)), (74829, 74829, (5, 1)),
+  (74830, 74830, (6, 1)), (74831, 74831, (1, 1)), (74832, 74832, (2, 1)),
+  (74833, 74833, (3, 1)), (74834, 74835, (4, 1)), (74836, 74837, (5, 1)),
+  (74838, 74838, (2, 1)), (74839, 74839, (3, 1)), (74840, 74840, (1, 1)),
+  (74841, 74841, (2, 1)), (74842, 74842, (1, 3)), (74843, 74843, (2, 3)),
+  (74844, 74844, (5, 6)), (74845, 74845, (1, 3)), (74846, 74846, (2, 3)),
+  (74847, 74847, (1, 8)), (74848, 74848, (1, 4)), (74849, 74849, (1, 6)),
+  (74850, 74851, (1, 4)), (74852, 74852, (1, 2)), (74853, 74853, (1, 3)),
+  (74854, 74854, (2, 3)), (74855, 74855, (40, 1)), (74856, 74856, (50, 1)),
+  (74857, 74857, (4, 1)), (74858, 74858, (5, 1)), (74859, 74859, (6, 1)),
+  (74860, 74860, (7, 1)), (74861, 74861, (8, 1)), (74862, 74862, (9, 1)),
+  (92768, 92768, (0, 1)), (92769, 92769, (1, 1)), (92770, 92770, (2, 1)),
+  (92771, 92771, (3, 1)), (92772, 92772, (4, 1)), (92773, 92773, (5, 1)),
+  (92774, 92774, (6, 1)), (92775, 92775, (7, 1)), (92776, 92776, (8, 1)),
+  (92777, 92777, (9, 1)), (93008, 93008, (0, 1)), (93009, 93009, (1, 1)),
+  (93010, 93010, (2, 1)), (93011, 93011, (3, 1)), (93012, 93012, (4, 1)),
+  (93013, 93013, (5, 1)), (93014, 93014, (6, 1)), (93015, 93015, (7, 1)),
+  (93016, 93016, (8, 1)), (93017, 93017, (9, 1)), (93019, 93019, (10, 1)),
+  (93020, 93020, (100, 1)), (93021, 93021, (10000, 1)),
+  (93022, 93022, (1000000, 1)), (93023, 93023, (100000000, 1)),
+  (93024, 93024, (10000000000, 1)), (93025, 93025, (1000000000000, 1)),
+  (119648, 119648, (1, 1)), (119649, 119649, (2, 1)), (119650, 119650, (3, 1)),
+  (119651, 119651, (4, 1)), (119652, 119652, (5, 1)), (119653, 119653, (6, 1)),
+  (119654, 119654, (7, 1)), (119655, 119655, (8, 1)), (119656, 119656, (9, 1)),
+  (119657, 119657, (10, 1)), (119658, 119658, (20, 1)),
+  (119659, 119659, (30, 1)), (119660, 119660, (40, 1)),
+  (119661, 119661, (50, 1)), (119662, 119662, (60, 1)),
+  (119663, 119663, (70, 1)), (119664, 119664, (80, 1)),
+  (119665, 119665, (90, 1)), (120782, 120782, (0, 1)),
+  (120783, 120783, (1, 1)), (120784, 120784, (2, 1)), (120785, 120785, (3, 1)),
+  (120786, 120786, (4, 1)), (120787, 120787, (5, 1)), (120788, 120788, (6, 1)),
+  (120789, 120789, (7, 1)), (120790, 120790, (8, 1)), (120791, 120791, (9, 1)),
+  (120792, 120792, (0, 1)), (120793, 120793, (1, 1)), (120794, 120794, (2, 1)),
+  (120795, 120795, (3, 1)), (120796, 120796, (4, 1)), (120797, 120797, (5, 1)),
+  (120798, 120798, (6, 1)), (120799, 120799, (7, 1)), (120800, 120800, (8, 1)),
+  (120801, 120801, (9, 1)), (120802, 120802, (0, 1)), (120803, 120803, (1, 1)),
+  (120804, 120804, (2, 1)), (120805, 120805, (3, 1)), (120806, 120806, (4, 1)),
+  (120807, 120807, (5, 1)), (120808, 120808, (6, 1)), (120809, 120809, (7, 1)),
+  (120810, 120810, (8, 1)), (120811, 120811, (9, 1)), (120812, 120812, (0, 1)),
+  (120813, 120813, (1, 1)), (120814, 120814, (2, 1)), (120815, 120815, (3, 1)),
+  (120816, 120816, (4, 1)), (120817, 120817, (5, 1)), (120818, 120818, (6, 1)),
+  (120819, 120819, (7, 1)), (120820, 120820, (8, 1)), (120821, 120821, (9, 1)),
+  (120822, 120822, (0, 1)), (120823, 120823, (1, 1)), (120824, 120824, (2, 1)),
+  (120825, 120825, (3, 1)), (120826, 120826, (4, 1)), (120827, 120827, (5, 1)),
+  (120828, 120828, (6, 1)), (120829, 120829, (7, 1)), (120830, 120830, (8, 1)),
+  (120831, 120831, (9, 1)), (125127, 125127, (1, 1)), (125128, 125128, (2, 1)),
+  (125129, 125129, (3, 1)), (125130, 125130, (4, 1)), (125131, 125131, (5, 1)),
+  (125132, 125132, (6, 1)), (125133, 125133, (7, 1)), (125134, 125134, (8, 1)),
+  (125135, 125135, (9, 1)), (125264, 125264, (0, 1)), (125265, 125265, (1, 1)),
+  (125266, 125266, (2, 1)), (125267, 125267, (3, 1)), (125268, 125268, (4, 1)),
+  (125269, 125269, (5, 1)), (125270, 125270, (6, 1)), (125271, 125271, (7, 1)),
+  (125272, 125272, (8, 1)), (125273, 125273, (9, 1)), (127232, 127233, (0, 1)),
+  (127234, 127234, (1, 1)), (127235, 127235, (2, 1)), (127236, 127236, (3, 1)),
+  (127237, 127237, (4, 1)), (127238, 127238, (5, 1)), (127239, 127239, (6, 1)),
+  (127240, 127240, (7, 1)), (127241, 127241, (8, 1)), (127242, 127242, (9, 1)),
+  (127243, 127244, (0, 1)), (131073, 131073, (7, 1)), (131172, 131172, (4, 1)),
+  (131298, 131298, (4, 1)), (131361, 131361, (5, 1)), (133418, 133418, (1, 1)),
+  (133507, 133507, (30, 1)), (133516, 133516, (40, 1)),
+  (133532, 133532, (40, 1)), (133866, 133866, (6, 1)),
+  (133885, 133885, (3, 1)), (133913, 133913, (3, 1)), (140176, 140176, (2, 1)),
+  (141720, 141720, (3, 1)), (146203, 146203, (3, 1)), (156269, 156269, (4, 1)),
+  (194704, 194704, (9, 1)),
+];
+
+pub const UNIHAN_NUMERIC_VALUE: &'static [(u32, u32)] = &[
+  (13317, 13317), (13443, 13443), (14378, 14378), (15181, 15181),
+  (19968, 19968), (19971, 19971), (19975, 19975), (19977, 19977),
+  (20061, 20061), (20108, 20108), (20116, 20116), (20118, 20118),
+  (20159, 20160), (20191, 20191), (20200, 20200), (20237, 20237),
+  (20336, 20336), (20740, 20740), (20806, 20806), (20841, 20841),
+  (20843, 20843), (20845, 20845), (21313, 21313), (21315, 21317),
+  (21324, 21324), (21441, 21444), (22235, 22235), (22769, 22769),
+  (22777, 22777), (24186, 24186), (24318, 24319), (24332, 24334),
+  (24336, 24336), (25342, 25342), (25420, 25420), (26578, 26578),
+  (28422, 28422), (29590, 29590), (30334, 30334), (32902, 32902),
+  (33836, 33836), (36014, 36014), (36019, 36019), (36144, 36144),
+  (38433, 38433), (38470, 38470), (38476, 38476), (38520, 38520),
+  (38646, 38646), (131073, 131073), (131172, 131172), (131298, 131298),
+  (131361, 131361), (133418, 133418), (133507, 133507), (133516, 133516),
+  (133532, 133532), (133866, 133866), (133885, 133885), (133913, 133913),
+  (140176, 140176), (141720, 141720), (146203, 146203), (156269, 156269),
+];
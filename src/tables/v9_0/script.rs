@@ -0,0 +1,355 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//  ucd-generate script /home/andrew/tmp/ucd-9.0.0/
+//
+// ucd-generate is available on crates.io.
+
+pub const SCRIPT: &'static [(u32, u32, &'static str)] = &[
+  (0, 64, "Common"), (65, 90, "Latin"), (91, 96, "Common"), (97, 122, "Latin"),
+  (123, 169, "Common"), (170, 170, "Latin"), (171, 185, "Common"),
+  (186, 186, "Latin"), (187, 191, "Common"), (192, 214, "Latin"),
+  (215, 215, "Common"), (216, 246, "Latin"), (247, 247, "Common"),
+  (248, 696, "Latin"), (697, 735, "Common"), (736, 740, "Latin"),
+  (741, 745, "Common"), (746, 747, "Bopomofo"), (748, 767, "Common"),
+  (768, 879, "Inherited"), (880, 883, "Greek"), (884, 884, "Common"),
+  (885, 887, "Greek"), (890, 893, "Greek"), (894, 894, "Common"),
+  (895, 895, "Greek"), (900, 900, "Greek"), (901, 901, "Common"),
+  (902, 902, "Greek"), (903, 903, "Common"), (904, 906, "Greek"),
+  (908, 908, "Greek"), (910, 929, "Greek"), (931, 993, "Greek"),
+  (994, 1007, "Coptic"), (1008, 1023, "Greek"), (1024, 1156, "Cyrillic"),
+  (1157, 1158, "Inherited"), (1159, 1327, "Cyrillic"),
+  (1329, 1366, "Armenian"), (1369, 1375, "Armenian"), (1377, 1415, "Armenian"),
+  (1417, 1417, "Common"), (1418, 1418, "Armenian"), (1421, 1423, "Armenian"),
+  (1425, 1479, "Hebrew"), (1488, 1514, "Hebrew"), (1520, 1524, "Hebrew"),
+  (1536, 1540, "Arabic"), (1541, 1541, "Common"), (1542, 1547, "Arabic"),
+  (1548, 1548, "Common"), (1549, 1562, "Arabic"), (1563, 1564, "Common"),
+  (1566, 1566, "Arabic"), (1567, 1567, "Common"), (1568, 1599, "Arabic"),
+  (1600, 1600, "Common"), (1601, 1610, "Arabic"), (1611, 1621, "Inherited"),
+  (1622, 1647, "Arabic"), (1648, 1648, "Inherited"), (1649, 1756, "Arabic"),
+  (1757, 1757, "Common"), (1758, 1791, "Arabic"), (1792, 1805, "Syriac"),
+  (1807, 1866, "Syriac"), (1869, 1871, "Syriac"), (1872, 1919, "Arabic"),
+  (1920, 1969, "Thaana"), (1984, 2042, "Nko"), (2048, 2093, "Samaritan"),
+  (2096, 2110, "Samaritan"), (2112, 2139, "Mandaic"), (2142, 2142, "Mandaic"),
+  (2208, 2228, "Arabic"), (2230, 2237, "Arabic"), (2260, 2273, "Arabic"),
+  (2274, 2274, "Common"), (2275, 2303, "Arabic"), (2304, 2384, "Devanagari"),
+  (2385, 2386, "Inherited"), (2387, 2403, "Devanagari"),
+  (2404, 2405, "Common"), (2406, 2431, "Devanagari"), (2432, 2435, "Bengali"),
+  (2437, 2444, "Bengali"), (2447, 2448, "Bengali"), (2451, 2472, "Bengali"),
+  (2474, 2480, "Bengali"), (2482, 2482, "Bengali"), (2486, 2489, "Bengali"),
+  (2492, 2500, "Bengali"), (2503, 2504, "Bengali"), (2507, 2510, "Bengali"),
+  (2519, 2519, "Bengali"), (2524, 2525, "Bengali"), (2527, 2531, "Bengali"),
+  (2534, 2555, "Bengali"), (2561, 2563, "Gurmukhi"), (2565, 2570, "Gurmukhi"),
+  (2575, 2576, "Gurmukhi"), (2579, 2600, "Gurmukhi"), (2602, 2608, "Gurmukhi"),
+  (2610, 2611, "Gurmukhi"), (2613, 2614, "Gurmukhi"), (2616, 2617, "Gurmukhi"),
+  (2620, 2620, "Gurmukhi"), (2622, 2626, "Gurmukhi"), (2631, 2632, "Gurmukhi"),
+  (2635, 2637, "Gurmukhi"), (2641, 2641, "Gurmukhi"), (2649, 2652, "Gurmukhi"),
+  (2654, 2654, "Gurmukhi"), (2662, 2677, "Gurmukhi"), (2689, 2691, "Gujarati"),
+  (2693, 2701, "Gujarati"), (2703, 2705, "Gujarati"), (2707, 2728, "Gujarati"),
+  (2730, 2736, "Gujarati"), (2738, 2739, "Gujarati"), (2741, 2745, "Gujarati"),
+  (2748, 2757, "Gujarati"), (2759, 2761, "Gujarati"), (2763, 2765, "Gujarati"),
+  (2768, 2768, "Gujarati"), (2784, 2787, "Gujarati"), (2790, 2801, "Gujarati"),
+  (2809, 2809, "Gujarati"), (2817, 2819, "Oriya"), (2821, 2828, "Oriya"),
+  (2831, 2832, "Oriya"), (2835, 2856, "Oriya"), (2858, 2864, "Oriya"),
+  (2866, 2867, "Oriya"), (2869, 2873, "Oriya"), (2876, 2884, "Oriya"),
+  (2887, 2888, "Oriya"), (2891, 2893, "Oriya"), (2902, 2903, "Oriya"),
+  (2908, 2909, "Oriya"), (2911, 2915, "Oriya"), (2918, 2935, "Oriya"),
+  (2946, 2947, "Tamil"), (2949, 2954, "Tamil"), (2958, 2960, "Tamil"),
+  (2962, 2965, "Tamil"), (2969, 2970, "Tamil"), (2972, 2972, "Tamil"),
+  (2974, 2975, "Tamil"), (2979, 2980, "Tamil"), (2984, 2986, "Tamil"),
+  (2990, 3001, "Tamil"), (3006, 3010, "Tamil"), (3014, 3016, "Tamil"),
+  (3018, 3021, "Tamil"), (3024, 3024, "Tamil"), (3031, 3031, "Tamil"),
+  (3046, 3066, "Tamil"), (3072, 3075, "Telugu"), (3077, 3084, "Telugu"),
+  (3086, 3088, "Telugu"), (3090, 3112, "Telugu"), (3114, 3129, "Telugu"),
+  (3133, 3140, "Telugu"), (3142, 3144, "Telugu"), (3146, 3149, "Telugu"),
+  (3157, 3158, "Telugu"), (3160, 3162, "Telugu"), (3168, 3171, "Telugu"),
+  (3174, 3183, "Telugu"), (3192, 3199, "Telugu"), (3200, 3203, "Kannada"),
+  (3205, 3212, "Kannada"), (3214, 3216, "Kannada"), (3218, 3240, "Kannada"),
+  (3242, 3251, "Kannada"), (3253, 3257, "Kannada"), (3260, 3268, "Kannada"),
+  (3270, 3272, "Kannada"), (3274, 3277, "Kannada"), (3285, 3286, "Kannada"),
+  (3294, 3294, "Kannada"), (3296, 3299, "Kannada"), (3302, 3311, "Kannada"),
+  (3313, 3314, "Kannada"), (3329, 3331, "Malayalam"),
+  (3333, 3340, "Malayalam"), (3342, 3344, "Malayalam"),
+  (3346, 3386, "Malayalam"), (3389, 3396, "Malayalam"),
+  (3398, 3400, "Malayalam"), (3402, 3407, "Malayalam"),
+  (3412, 3427, "Malayalam"), (3430, 3455, "Malayalam"),
+  (3458, 3459, "Sinhala"), (3461, 3478, "Sinhala"), (3482, 3505, "Sinhala"),
+  (3507, 3515, "Sinhala"), (3517, 3517, "Sinhala"), (3520, 3526, "Sinhala"),
+  (3530, 3530, "Sinhala"), (3535, 3540, "Sinhala"), (3542, 3542, "Sinhala"),
+  (3544, 3551, "Sinhala"), (3558, 3567, "Sinhala"), (3570, 3572, "Sinhala"),
+  (3585, 3642, "Thai"), (3647, 3647, "Common"), (3648, 3675, "Thai"),
+  (3713, 3714, "Lao"), (3716, 3716, "Lao"), (3719, 3720, "Lao"),
+  (3722, 3722, "Lao"), (3725, 3725, "Lao"), (3732, 3735, "Lao"),
+  (3737, 3743, "Lao"), (3745, 3747, "Lao"), (3749, 3749, "Lao"),
+  (3751, 3751, "Lao"), (3754, 3755, "Lao"), (3757, 3769, "Lao"),
+  (3771, 3773, "Lao"), (3776, 3780, "Lao"), (3782, 3782, "Lao"),
+  (3784, 3789, "Lao"), (3792, 3801, "Lao"), (3804, 3807, "Lao"),
+  (3840, 3911, "Tibetan"), (3913, 3948, "Tibetan"), (3953, 3991, "Tibetan"),
+  (3993, 4028, "Tibetan"), (4030, 4044, "Tibetan"), (4046, 4052, "Tibetan"),
+  (4053, 4056, "Common"), (4057, 4058, "Tibetan"), (4096, 4255, "Myanmar"),
+  (4256, 4293, "Georgian"), (4295, 4295, "Georgian"), (4301, 4301, "Georgian"),
+  (4304, 4346, "Georgian"), (4347, 4347, "Common"), (4348, 4351, "Georgian"),
+  (4352, 4607, "Hangul"), (4608, 4680, "Ethiopic"), (4682, 4685, "Ethiopic"),
+  (4688, 4694, "Ethiopic"), (4696, 4696, "Ethiopic"), (4698, 4701, "Ethiopic"),
+  (4704, 4744, "Ethiopic"), (4746, 4749, "Ethiopic"), (4752, 4784, "Ethiopic"),
+  (4786, 4789, "Ethiopic"), (4792, 4798, "Ethiopic"), (4800, 4800, "Ethiopic"),
+  (4802, 4805, "Ethiopic"), (4808, 4822, "Ethiopic"), (4824, 4880, "Ethiopic"),
+  (4882, 4885, "Ethiopic"), (4888, 4954, "Ethiopic"), (4957, 4988, "Ethiopic"),
+  (4992, 5017, "Ethiopic"), (5024, 5109, "Cherokee"), (5112, 5117, "Cherokee"),
+  (5120, 5759, "Canadian_Aboriginal"), (5760, 5788, "Ogham"),
+  (5792, 5866, "Runic"), (5867, 5869, "Common"), (5870, 5880, "Runic"),
+  (5888, 5900, "Tagalog"), (5902, 5908, "Tagalog"), (5920, 5940, "Hanunoo"),
+  (5941, 5942, "Common"), (5952, 5971, "Buhid"), (5984, 5996, "Tagbanwa"),
+  (5998, 6000, "Tagbanwa"), (6002, 6003, "Tagbanwa"), (6016, 6109, "Khmer"),
+  (6112, 6121, "Khmer"), (6128, 6137, "Khmer"), (6144, 6145, "Mongolian"),
+  (6146, 6147, "Common"), (6148, 6148, "Mongolian"), (6149, 6149, "Common"),
+  (6150, 6158, "Mongolian"), (6160, 6169, "Mongolian"),
+  (6176, 6263, "Mongolian"), (6272, 6314, "Mongolian"),
+  (6320, 6389, "Canadian_Aboriginal"), (6400, 6430, "Limbu"),
+  (6432, 6443, "Limbu"), (6448, 6459, "Limbu"), (6464, 6464, "Limbu"),
+  (6468, 6479, "Limbu"), (6480, 6509, "Tai_Le"), (6512, 6516, "Tai_Le"),
+  (6528, 6571, "New_Tai_Lue"), (6576, 6601, "New_Tai_Lue"),
+  (6608, 6618, "New_Tai_Lue"), (6622, 6623, "New_Tai_Lue"),
+  (6624, 6655, "Khmer"), (6656, 6683, "Buginese"), (6686, 6687, "Buginese"),
+  (6688, 6750, "Tai_Tham"), (6752, 6780, "Tai_Tham"), (6783, 6793, "Tai_Tham"),
+  (6800, 6809, "Tai_Tham"), (6816, 6829, "Tai_Tham"),
+  (6832, 6846, "Inherited"), (6912, 6987, "Balinese"),
+  (6992, 7036, "Balinese"), (7040, 7103, "Sundanese"), (7104, 7155, "Batak"),
+  (7164, 7167, "Batak"), (7168, 7223, "Lepcha"), (7227, 7241, "Lepcha"),
+  (7245, 7247, "Lepcha"), (7248, 7295, "Ol_Chiki"), (7296, 7304, "Cyrillic"),
+  (7360, 7367, "Sundanese"), (7376, 7378, "Inherited"), (7379, 7379, "Common"),
+  (7380, 7392, "Inherited"), (7393, 7393, "Common"), (7394, 7400, "Inherited"),
+  (7401, 7404, "Common"), (7405, 7405, "Inherited"), (7406, 7411, "Common"),
+  (7412, 7412, "Inherited"), (7413, 7414, "Common"), (7416, 7417, "Inherited"),
+  (7424, 7461, "Latin"), (7462, 7466, "Greek"), (7467, 7467, "Cyrillic"),
+  (7468, 7516, "Latin"), (7517, 7521, "Greek"), (7522, 7525, "Latin"),
+  (7526, 7530, "Greek"), (7531, 7543, "Latin"), (7544, 7544, "Cyrillic"),
+  (7545, 7614, "Latin"), (7615, 7615, "Greek"), (7616, 7669, "Inherited"),
+  (7675, 7679, "Inherited"), (7680, 7935, "Latin"), (7936, 7957, "Greek"),
+  (7960, 7965, "Greek"), (7968, 8005, "Greek"), (8008, 8013, "Greek"),
+  (8016, 8023, "Greek"), (8025, 8025, "Greek"), (8027, 8027, "Greek"),
+  (8029, 8029, "Greek"), (8031, 8061, "Greek"), (8064, 8116, "Greek"),
+  (8118, 8132, "Greek"), (8134, 8147, "Greek"), (8150, 8155, "Greek"),
+  (8157, 8175, "Greek"), (8178, 8180, "Greek"), (8182, 8190, "Greek"),
+  (8192, 8203, "Common"), (8204, 8205, "Inherited"), (8206, 8292, "Common"),
+  (8294, 8304, "Common"), (8305, 8305, "Latin"), (8308, 8318, "Common"),
+  (8319, 8319, "Latin"), (8320, 8334, "Common"), (8336, 8348, "Latin"),
+  (8352, 8382, "Common"), (8400, 8432, "Inherited"), (8448, 8485, "Common"),
+  (8486, 8486, "Greek"), (8487, 8489, "Common"), (8490, 8491, "Latin"),
+  (8492, 8497, "Common"), (8498, 8498, "Latin"), (8499, 8525, "Common"),
+  (8526, 8526, "Latin"), (8527, 8543, "Common"), (8544, 8584, "Latin"),
+  (8585, 8587, "Common"), (8592, 9214, "Common"), (9216, 9254, "Common"),
+  (9280, 9290, "Common"), (9312, 10239, "Common"), (10240, 10495, "Braille"),
+  (10496, 11123, "Common"), (11126, 11157, "Common"), (11160, 11193, "Common"),
+  (11197, 11208, "Common"), (11210, 11217, "Common"), (11244, 11247, "Common"),
+  (11264, 11310, "Glagolitic"), (11312, 11358, "Glagolitic"),
+  (11360, 11391, "Latin"), (11392, 11507, "Coptic"), (11513, 11519, "Coptic"),
+  (11520, 11557, "Georgian"), (11559, 11559, "Georgian"),
+  (11565, 11565, "Georgian"), (11568, 11623, "Tifinagh"),
+  (11631, 11632, "Tifinagh"), (11647, 11647, "Tifinagh"),
+  (11648, 11670, "Ethiopic"), (11680, 11686, "Ethiopic"),
+  (11688, 11694, "Ethiopic"), (11696, 11702, "Ethiopic"),
+  (11704, 11710, "Ethiopic"), (11712, 11718, "Ethiopic"),
+  (11720, 11726, "Ethiopic"), (11728, 11734, "Ethiopic"),
+  (11736, 11742, "Ethiopic"), (11744, 11775, "Cyrillic"),
+  (11776, 11844, "Common"), (11904, 11929, "Han"), (11931, 12019, "Han"),
+  (12032, 12245, "Han"), (12272, 12283, "Common"), (12288, 12292, "Common"),
+  (12293, 12293, "Han"), (12294, 12294, "Common"), (12295, 12295, "Han"),
+  (12296, 12320, "Common"), (12321, 12329, "Han"), (12330, 12333, "Inherited"),
+  (12334, 12335, "Hangul"), (12336, 12343, "Common"), (12344, 12347, "Han"),
+  (12348, 12351, "Common"), (12353, 12438, "Hiragana"),
+  (12441, 12442, "Inherited"), (12443, 12444, "Common"),
+  (12445, 12447, "Hiragana"), (12448, 12448, "Common"),
+  (12449, 12538, "Katakana"), (12539, 12540, "Common"),
+  (12541, 12543, "Katakana"), (12549, 12589, "Bopomofo"),
+  (12593, 12686, "Hangul"), (12688, 12703, "Common"),
+  (12704, 12730, "Bopomofo"), (12736, 12771, "Common"),
+  (12784, 12799, "Katakana"), (12800, 12830, "Hangul"),
+  (12832, 12895, "Common"), (12896, 12926, "Hangul"), (12927, 13007, "Common"),
+  (13008, 13054, "Katakana"), (13056, 13143, "Katakana"),
+  (13144, 13311, "Common"), (13312, 19893, "Han"), (19904, 19967, "Common"),
+  (19968, 40917, "Han"), (40960, 42124, "Yi"), (42128, 42182, "Yi"),
+  (42192, 42239, "Lisu"), (42240, 42539, "Vai"), (42560, 42655, "Cyrillic"),
+  (42656, 42743, "Bamum"), (42752, 42785, "Common"), (42786, 42887, "Latin"),
+  (42888, 42890, "Common"), (42891, 42926, "Latin"), (42928, 42935, "Latin"),
+  (42999, 43007, "Latin"), (43008, 43051, "Syloti_Nagri"),
+  (43056, 43065, "Common"), (43072, 43127, "Phags_Pa"),
+  (43136, 43205, "Saurashtra"), (43214, 43225, "Saurashtra"),
+  (43232, 43261, "Devanagari"), (43264, 43309, "Kayah_Li"),
+  (43310, 43310, "Common"), (43311, 43311, "Kayah_Li"),
+  (43312, 43347, "Rejang"), (43359, 43359, "Rejang"), (43360, 43388, "Hangul"),
+  (43392, 43469, "Javanese"), (43471, 43471, "Common"),
+  (43472, 43481, "Javanese"), (43486, 43487, "Javanese"),
+  (43488, 43518, "Myanmar"), (43520, 43574, "Cham"), (43584, 43597, "Cham"),
+  (43600, 43609, "Cham"), (43612, 43615, "Cham"), (43616, 43647, "Myanmar"),
+  (43648, 43714, "Tai_Viet"), (43739, 43743, "Tai_Viet"),
+  (43744, 43766, "Meetei_Mayek"), (43777, 43782, "Ethiopic"),
+  (43785, 43790, "Ethiopic"), (43793, 43798, "Ethiopic"),
+  (43808, 43814, "Ethiopic"), (43816, 43822, "Ethiopic"),
+  (43824, 43866, "Latin"), (43867, 43867, "Common"), (43868, 43876, "Latin"),
+  (43877, 43877, "Greek"), (43888, 43967, "Cherokee"),
+  (43968, 44013, "Meetei_Mayek"), (44016, 44025, "Meetei_Mayek"),
+  (44032, 55203, "Hangul"), (55216, 55238, "Hangul"), (55243, 55291, "Hangul"),
+  (63744, 64109, "Han"), (64112, 64217, "Han"), (64256, 64262, "Latin"),
+  (64275, 64279, "Armenian"), (64285, 64310, "Hebrew"),
+  (64312, 64316, "Hebrew"), (64318, 64318, "Hebrew"), (64320, 64321, "Hebrew"),
+  (64323, 64324, "Hebrew"), (64326, 64335, "Hebrew"), (64336, 64449, "Arabic"),
+  (64467, 64829, "Arabic"), (64830, 64831, "Common"), (64848, 64911, "Arabic"),
+  (64914, 64967, "Arabic"), (65008, 65021, "Arabic"),
+  (65024, 65039, "Inherited"), (65040, 65049, "Common"),
+  (65056, 65069, "Inherited"), (65070, 65071, "Cyrillic"),
+  (65072, 65106, "Common"), (65108, 65126, "Common"), (65128, 65131, "Common"),
+  (65136, 65140, "Arabic"), (65142, 65276, "Arabic"), (65279, 65279, "Common"),
+  (65281, 65312, "Common"), (65313, 65338, "Latin"), (65339, 65344, "Common"),
+  (65345, 65370, "Latin"), (65371, 65381, "Common"),
+  (65382, 65391, "Katakana"), (65392, 65392, "Common"),
+  (65393, 65437, "Katakana"), (65438, 65439, "Common"),
+  (65440, 65470, "Hangul"), (65474, 65479, "Hangul"), (65482, 65487, "Hangul"),
+  (65490, 65495, "Hangul"), (65498, 65500, "Hangul"), (65504, 65510, "Common"),
+  (65512, 65518, "Common"), (65529, 65533, "Common"),
+  (65536, 65547, "Linear_B"), (65549, 65574, "Linear_B"),
+  (65576, 65594, "Linear_B"), (65596, 65597, "Linear_B"),
+  (65599, 65613, "Linear_B"), (65616, 65629, "Linear_B"),
+  (65664, 65786, "Linear_B"), (65792, 65794, "Common"),
+  (65799, 65843, "Common"), (65847, 65855, "Common"), (65856, 65934, "Greek"),
+  (65936, 65947, "Common"), (65952, 65952, "Greek"), (66000, 66044, "Common"),
+  (66045, 66045, "Inherited"), (66176, 66204, "Lycian"),
+  (66208, 66256, "Carian"), (66272, 66272, "Inherited"),
+  (66273, 66299, "Common"), (66304, 66339, "Old_Italic"),
+  (66352, 66378, "Gothic"), (66384, 66426, "Old_Permic"),
+  (66432, 66461, "Ugaritic"), (66463, 66463, "Ugaritic"),
+  (66464, 66499, "Old_Persian"), (66504, 66517, "Old_Persian"),
+  (66560, 66639, "Deseret"), (66640, 66687, "Shavian"),
+  (66688, 66717, "Osmanya"), (66720, 66729, "Osmanya"),
+  (66736, 66771, "Osage"), (66776, 66811, "Osage"), (66816, 66855, "Elbasan"),
+  (66864, 66915, "Caucasian_Albanian"), (66927, 66927, "Caucasian_Albanian"),
+  (67072, 67382, "Linear_A"), (67392, 67413, "Linear_A"),
+  (67424, 67431, "Linear_A"), (67584, 67589, "Cypriot"),
+  (67592, 67592, "Cypriot"), (67594, 67637, "Cypriot"),
+  (67639, 67640, "Cypriot"), (67644, 67644, "Cypriot"),
+  (67647, 67647, "Cypriot"), (67648, 67669, "Imperial_Aramaic"),
+  (67671, 67679, "Imperial_Aramaic"), (67680, 67711, "Palmyrene"),
+  (67712, 67742, "Nabataean"), (67751, 67759, "Nabataean"),
+  (67808, 67826, "Hatran"), (67828, 67829, "Hatran"), (67835, 67839, "Hatran"),
+  (67840, 67867, "Phoenician"), (67871, 67871, "Phoenician"),
+  (67872, 67897, "Lydian"), (67903, 67903, "Lydian"),
+  (67968, 67999, "Meroitic_Hieroglyphs"), (68000, 68023, "Meroitic_Cursive"),
+  (68028, 68047, "Meroitic_Cursive"), (68050, 68095, "Meroitic_Cursive"),
+  (68096, 68099, "Kharoshthi"), (68101, 68102, "Kharoshthi"),
+  (68108, 68115, "Kharoshthi"), (68117, 68119, "Kharoshthi"),
+  (68121, 68147, "Kharoshthi"), (68152, 68154, "Kharoshthi"),
+  (68159, 68167, "Kharoshthi"), (68176, 68184, "Kharoshthi"),
+  (68192, 68223, "Old_South_Arabian"), (68224, 68255, "Old_North_Arabian"),
+  (68288, 68326, "Manichaean"), (68331, 68342, "Manichaean"),
+  (68352, 68405, "Avestan"), (68409, 68415, "Avestan"),
+  (68416, 68437, "Inscriptional_Parthian"),
+  (68440, 68447, "Inscriptional_Parthian"),
+  (68448, 68466, "Inscriptional_Pahlavi"),
+  (68472, 68479, "Inscriptional_Pahlavi"), (68480, 68497, "Psalter_Pahlavi"),
+  (68505, 68508, "Psalter_Pahlavi"), (68521, 68527, "Psalter_Pahlavi"),
+  (68608, 68680, "Old_Turkic"), (68736, 68786, "Old_Hungarian"),
+  (68800, 68850, "Old_Hungarian"), (68858, 68863, "Old_Hungarian"),
+  (69216, 69246, "Arabic"), (69632, 69709, "Brahmi"), (69714, 69743, "Brahmi"),
+  (69759, 69759, "Brahmi"), (69760, 69825, "Kaithi"),
+  (69840, 69864, "Sora_Sompeng"), (69872, 69881, "Sora_Sompeng"),
+  (69888, 69940, "Chakma"), (69942, 69955, "Chakma"),
+  (69968, 70006, "Mahajani"), (70016, 70093, "Sharada"),
+  (70096, 70111, "Sharada"), (70113, 70132, "Sinhala"),
+  (70144, 70161, "Khojki"), (70163, 70206, "Khojki"),
+  (70272, 70278, "Multani"), (70280, 70280, "Multani"),
+  (70282, 70285, "Multani"), (70287, 70301, "Multani"),
+  (70303, 70313, "Multani"), (70320, 70378, "Khudawadi"),
+  (70384, 70393, "Khudawadi"), (70400, 70403, "Grantha"),
+  (70405, 70412, "Grantha"), (70415, 70416, "Grantha"),
+  (70419, 70440, "Grantha"), (70442, 70448, "Grantha"),
+  (70450, 70451, "Grantha"), (70453, 70457, "Grantha"),
+  (70460, 70468, "Grantha"), (70471, 70472, "Grantha"),
+  (70475, 70477, "Grantha"), (70480, 70480, "Grantha"),
+  (70487, 70487, "Grantha"), (70493, 70499, "Grantha"),
+  (70502, 70508, "Grantha"), (70512, 70516, "Grantha"), (70656, 70745, "Newa"),
+  (70747, 70747, "Newa"), (70749, 70749, "Newa"), (70784, 70855, "Tirhuta"),
+  (70864, 70873, "Tirhuta"), (71040, 71093, "Siddham"),
+  (71096, 71133, "Siddham"), (71168, 71236, "Modi"), (71248, 71257, "Modi"),
+  (71264, 71276, "Mongolian"), (71296, 71351, "Takri"),
+  (71360, 71369, "Takri"), (71424, 71449, "Ahom"), (71453, 71467, "Ahom"),
+  (71472, 71487, "Ahom"), (71840, 71922, "Warang_Citi"),
+  (71935, 71935, "Warang_Citi"), (72384, 72440, "Pau_Cin_Hau"),
+  (72704, 72712, "Bhaiksuki"), (72714, 72758, "Bhaiksuki"),
+  (72760, 72773, "Bhaiksuki"), (72784, 72812, "Bhaiksuki"),
+  (72816, 72847, "Marchen"), (72850, 72871, "Marchen"),
+  (72873, 72886, "Marchen"), (73728, 74649, "Cuneiform"),
+  (74752, 74862, "Cuneiform"), (74864, 74868, "Cuneiform"),
+  (74880, 75075, "Cuneiform"), (77824, 78894, "Egyptian_Hieroglyphs"),
+  (82944, 83526, "Anatolian_Hieroglyphs"), (92160, 92728, "Bamum"),
+  (92736, 92766, "Mro"), (92768, 92777, "Mro"), (92782, 92783, "Mro"),
+  (92880, 92909, "Bassa_Vah"), (92912, 92917, "Bassa_Vah"),
+  (92928, 92997, "Pahawh_Hmong"), (93008, 93017, "Pahawh_Hmong"),
+  (93019, 93025, "Pahawh_Hmong"), (93027, 93047, "Pahawh_Hmong"),
+  (93053, 93071, "Pahawh_Hmong"), (93952, 94020, "Miao"),
+  (94032, 94078, "Miao"), (94095, 94111, "Miao"), (94176, 94176, "Tangut"),
+  (94208, 100332, "Tangut"), (100352, 101106, "Tangut"),
+  (110592, 110592, "Katakana"), (110593, 110593, "Hiragana"),
+  (113664, 113770, "Duployan"), (113776, 113788, "Duployan"),
+  (113792, 113800, "Duployan"), (113808, 113817, "Duployan"),
+  (113820, 113823, "Duployan"), (113824, 113827, "Common"),
+  (118784, 119029, "Common"), (119040, 119078, "Common"),
+  (119081, 119142, "Common"), (119143, 119145, "Inherited"),
+  (119146, 119162, "Common"), (119163, 119170, "Inherited"),
+  (119171, 119172, "Common"), (119173, 119179, "Inherited"),
+  (119180, 119209, "Common"), (119210, 119213, "Inherited"),
+  (119214, 119272, "Common"), (119296, 119365, "Greek"),
+  (119552, 119638, "Common"), (119648, 119665, "Common"),
+  (119808, 119892, "Common"), (119894, 119964, "Common"),
+  (119966, 119967, "Common"), (119970, 119970, "Common"),
+  (119973, 119974, "Common"), (119977, 119980, "Common"),
+  (119982, 119993, "Common"), (119995, 119995, "Common"),
+  (119997, 120003, "Common"), (120005, 120069, "Common"),
+  (120071, 120074, "Common"), (120077, 120084, "Common"),
+  (120086, 120092, "Common"), (120094, 120121, "Common"),
+  (120123, 120126, "Common"), (120128, 120132, "Common"),
+  (120134, 120134, "Common"), (120138, 120144, "Common"),
+  (120146, 120485, "Common"), (120488, 120779, "Common"),
+  (120782, 120831, "Common"), (120832, 121483, "SignWriting"),
+  (121499, 121503, "SignWriting"), (121505, 121519, "SignWriting"),
+  (122880, 122886, "Glagolitic"), (122888, 122904, "Glagolitic"),
+  (122907, 122913, "Glagolitic"), (122915, 122916, "Glagolitic"),
+  (122918, 122922, "Glagolitic"), (124928, 125124, "Mende_Kikakui"),
+  (125127, 125142, "Mende_Kikakui"), (125184, 125258, "Adlam"),
+  (125264, 125273, "Adlam"), (125278, 125279, "Adlam"),
+  (126464, 126467, "Arabic"), (126469, 126495, "Arabic"),
+  (126497, 126498, "Arabic"), (126500, 126500, "Arabic"),
+  (126503, 126503, "Arabic"), (126505, 126514, "Arabic"),
+  (126516, 126519, "Arabic"), (126521, 126521, "Arabic"),
+  (126523, 126523, "Arabic"), (126530, 126530, "Arabic"),
+  (126535, 126535, "Arabic"), (126537, 126537, "Arabic"),
+  (126539, 126539, "Arabic"), (126541, 126543, "Arabic"),
+  (126545, 126546, "Arabic"), (126548, 126548, "Arabic"),
+  (126551, 126551, "Arabic"), (126553, 126553, "Arabic"),
+  (126555, 126555, "Arabic"), (126557, 126557, "Arabic"),
+  (126559, 126559, "Arabic"), (126561, 126562, "Arabic"),
+  (126564, 126564, "Arabic"), (126567, 126570, "Arabic"),
+  (126572, 126578, "Arabic"), (126580, 126583, "Arabic"),
+  (126585, 126588, "Arabic"), (126590, 126590, "Arabic"),
+  (126592, 126601, "Arabic"), (126603, 126619, "Arabic"),
+  (126625, 126627, "Arabic"), (126629, 126633, "Arabic"),
+  (126635, 126651, "Arabic"), (126704, 126705, "Arabic"),
+  (126976, 127019, "Common"), (127024, 127123, "Common"),
+  (127136, 127150, "Common"), (127153, 127167, "Common"),
+  (127169, 127183, "Common"), (127185, 127221, "Common"),
+  (127232, 127244, "Common"), (127248, 127278, "Common"),
+  (127280, 127339, "Common"), (127344, 127404, "Common"),
+  (127462, 127487, "Common"), (127488, 127488, "Hiragana"),
+  (127489, 127490, "Common"), (127504, 127547, "Common"),
+  (127552, 127560, "Common"), (127568, 127569, "Common"),
+  (127744, 128722, "Common"), (128736, 128748, "Common"),
+  (128752, 128758, "Common"), (128768, 128883, "Common"),
+  (128896, 128980, "Common"), (129024, 129035, "Common"),
+  (129040, 129095, "Common"), (129104, 129113, "Common"),
+  (129120, 129159, "Common"), (129168, 129197, "Common"),
+  (129296, 129310, "Common"), (129312, 129319, "Common"),
+  (129328, 129328, "Common"), (129331, 129342, "Common"),
+  (129344, 129355, "Common"), (129360, 129374, "Common"),
+  (129408, 129425, "Common"), (129472, 129472, "Common"),
+  (131072, 173782, "Han"), (173824, 177972, "Han"), (177984, 178205, "Han"),
+  (178208, 183969, "Han"), (194560, 195101, "Han"), (917505, 917505, "Common"),
+  (917536, 917631, "Common"), (917760, 917999, "Inherited"),
+];
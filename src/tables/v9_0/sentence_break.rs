@@ -0,0 +1,814 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//  ucd-generate sentence-break /home/andrew/tmp/ucd-9.0.0/
+//
+// ucd-generate is available on crates.io.
+
+pub const SENTENCE_BREAK: &'static [(u32, u32, &'static str)] = &[
+  (9, 9, "Sp"), (10, 10, "LF"), (11, 12, "Sp"), (13, 13, "CR"), (32, 32, "Sp"),
+  (33, 33, "STerm"), (34, 34, "Close"), (39, 41, "Close"),
+  (44, 45, "SContinue"), (46, 46, "ATerm"), (48, 57, "Numeric"),
+  (58, 58, "SContinue"), (63, 63, "STerm"), (65, 90, "Upper"),
+  (91, 91, "Close"), (93, 93, "Close"), (97, 122, "Lower"),
+  (123, 123, "Close"), (125, 125, "Close"), (133, 133, "Sep"),
+  (160, 160, "Sp"), (170, 170, "Lower"), (171, 171, "Close"),
+  (173, 173, "Format"), (181, 181, "Lower"), (186, 186, "Lower"),
+  (187, 187, "Close"), (192, 214, "Upper"), (216, 222, "Upper"),
+  (223, 246, "Lower"), (248, 255, "Lower"), (256, 256, "Upper"),
+  (257, 257, "Lower"), (258, 258, "Upper"), (259, 259, "Lower"),
+  (260, 260, "Upper"), (261, 261, "Lower"), (262, 262, "Upper"),
+  (263, 263, "Lower"), (264, 264, "Upper"), (265, 265, "Lower"),
+  (266, 266, "Upper"), (267, 267, "Lower"), (268, 268, "Upper"),
+  (269, 269, "Lower"), (270, 270, "Upper"), (271, 271, "Lower"),
+  (272, 272, "Upper"), (273, 273, "Lower"), (274, 274, "Upper"),
+  (275, 275, "Lower"), (276, 276, "Upper"), (277, 277, "Lower"),
+  (278, 278, "Upper"), (279, 279, "Lower"), (280, 280, "Upper"),
+  (281, 281, "Lower"), (282, 282, "Upper"), (283, 283, "Lower"),
+  (284, 284, "Upper"), (285, 285, "Lower"), (286, 286, "Upper"),
+  (287, 287, "Lower"), (288, 288, "Upper"), (289, 289, "Lower"),
+  (290, 290, "Upper"), (291, 291, "Lower"), (292, 292, "Upper"),
+  (293, 293, "Lower"), (294, 294, "Upper"), (295, 295, "Lower"),
+  (296, 296, "Upper"), (297, 297, "Lower"), (298, 298, "Upper"),
+  (299, 299, "Lower"), (300, 300, "Upper"), (301, 301, "Lower"),
+  (302, 302, "Upper"), (303, 303, "Lower"), (304, 304, "Upper"),
+  (305, 305, "Lower"), (306, 306, "Upper"), (307, 307, "Lower"),
+  (308, 308, "Upper"), (309, 309, "Lower"), (310, 310, "Upper"),
+  (311, 312, "Lower"), (313, 313, "Upper"), (314, 314, "Lower"),
+  (315, 315, "Upper"), (316, 316, "Lower"), (317, 317, "Upper"),
+  (318, 318, "Lower"), (319, 319, "Upper"), (320, 320, "Lower"),
+  (321, 321, "Upper"), (322, 322, "Lower"), (323, 323, "Upper"),
+  (324, 324, "Lower"), (325, 325, "Upper"), (326, 326, "Lower"),
+  (327, 327, "Upper"), (328, 329, "Lower"), (330, 330, "Upper"),
+  (331, 331, "Lower"), (332, 332, "Upper"), (333, 333, "Lower"),
+  (334, 334, "Upper"), (335, 335, "Lower"), (336, 336, "Upper"),
+  (337, 337, "Lower"), (338, 338, "Upper"), (339, 339, "Lower"),
+  (340, 340, "Upper"), (341, 341, "Lower"), (342, 342, "Upper"),
+  (343, 343, "Lower"), (344, 344, "Upper"), (345, 345, "Lower"),
+  (346, 346, "Upper"), (347, 347, "Lower"), (348, 348, "Upper"),
+  (349, 349, "Lower"), (350, 350, "Upper"), (351, 351, "Lower"),
+  (352, 352, "Upper"), (353, 353, "Lower"), (354, 354, "Upper"),
+  (355, 355, "Lower"), (356, 356, "Upper"), (357, 357, "Lower"),
+  (358, 358, "Upper"), (359, 359, "Lower"), (360, 360, "Upper"),
+  (361, 361, "Lower"), (362, 362, "Upper"), (363, 363, "Lower"),
+  (364, 364, "Upper"), (365, 365, "Lower"), (366, 366, "Upper"),
+  (367, 367, "Lower"), (368, 368, "Upper"), (369, 369, "Lower"),
+  (370, 370, "Upper"), (371, 371, "Lower"), (372, 372, "Upper"),
+  (373, 373, "Lower"), (374, 374, "Upper"), (375, 375, "Lower"),
+  (376, 377, "Upper"), (378, 378, "Lower"), (379, 379, "Upper"),
+  (380, 380, "Lower"), (381, 381, "Upper"), (382, 384, "Lower"),
+  (385, 386, "Upper"), (387, 387, "Lower"), (388, 388, "Upper"),
+  (389, 389, "Lower"), (390, 391, "Upper"), (392, 392, "Lower"),
+  (393, 395, "Upper"), (396, 397, "Lower"), (398, 401, "Upper"),
+  (402, 402, "Lower"), (403, 404, "Upper"), (405, 405, "Lower"),
+  (406, 408, "Upper"), (409, 411, "Lower"), (412, 413, "Upper"),
+  (414, 414, "Lower"), (415, 416, "Upper"), (417, 417, "Lower"),
+  (418, 418, "Upper"), (419, 419, "Lower"), (420, 420, "Upper"),
+  (421, 421, "Lower"), (422, 423, "Upper"), (424, 424, "Lower"),
+  (425, 425, "Upper"), (426, 427, "Lower"), (428, 428, "Upper"),
+  (429, 429, "Lower"), (430, 431, "Upper"), (432, 432, "Lower"),
+  (433, 435, "Upper"), (436, 436, "Lower"), (437, 437, "Upper"),
+  (438, 438, "Lower"), (439, 440, "Upper"), (441, 442, "Lower"),
+  (443, 443, "OLetter"), (444, 444, "Upper"), (445, 447, "Lower"),
+  (448, 451, "OLetter"), (452, 453, "Upper"), (454, 454, "Lower"),
+  (455, 456, "Upper"), (457, 457, "Lower"), (458, 459, "Upper"),
+  (460, 460, "Lower"), (461, 461, "Upper"), (462, 462, "Lower"),
+  (463, 463, "Upper"), (464, 464, "Lower"), (465, 465, "Upper"),
+  (466, 466, "Lower"), (467, 467, "Upper"), (468, 468, "Lower"),
+  (469, 469, "Upper"), (470, 470, "Lower"), (471, 471, "Upper"),
+  (472, 472, "Lower"), (473, 473, "Upper"), (474, 474, "Lower"),
+  (475, 475, "Upper"), (476, 477, "Lower"), (478, 478, "Upper"),
+  (479, 479, "Lower"), (480, 480, "Upper"), (481, 481, "Lower"),
+  (482, 482, "Upper"), (483, 483, "Lower"), (484, 484, "Upper"),
+  (485, 485, "Lower"), (486, 486, "Upper"), (487, 487, "Lower"),
+  (488, 488, "Upper"), (489, 489, "Lower"), (490, 490, "Upper"),
+  (491, 491, "Lower"), (492, 492, "Upper"), (493, 493, "Lower"),
+  (494, 494, "Upper"), (495, 496, "Lower"), (497, 498, "Upper"),
+  (499, 499, "Lower"), (500, 500, "Upper"), (501, 501, "Lower"),
+  (502, 504, "Upper"), (505, 505, "Lower"), (506, 506, "Upper"),
+  (507, 507, "Lower"), (508, 508, "Upper"), (509, 509, "Lower"),
+  (510, 510, "Upper"), (511, 511, "Lower"), (512, 512, "Upper"),
+  (513, 513, "Lower"), (514, 514, "Upper"), (515, 515, "Lower"),
+  (516, 516, "Upper"), (517, 517, "Lower"), (518, 518, "Upper"),
+  (519, 519, "Lower"), (520, 520, "Upper"), (521, 521, "Lower"),
+  (522, 522, "Upper"), (523, 523, "Lower"), (524, 524, "Upper"),
+  (525, 525, "Lower"), (526, 526, "Upper"), (527, 527, "Lower"),
+  (528, 528, "Upper"), (529, 529, "Lower"), (530, 530, "Upper"),
+  (531, 531, "Lower"), (532, 532, "Upper"), (533, 533, "Lower"),
+  (534, 534, "Upper"), (535, 535, "Lower"), (536, 536, "Upper"),
+  (537, 537, "Lower"), (538, 538, "Upper"), (539, 539, "Lower"),
+  (540, 540, "Upper"), (541, 541, "Lower"), (542, 542, "Upper"),
+  (543, 543, "Lower"), (544, 544, "Upper"), (545, 545, "Lower"),
+  (546, 546, "Upper"), (547, 547, "Lower"), (548, 548, "Upper"),
+  (549, 549, "Lower"), (550, 550, "Upper"), (551, 551, "Lower"),
+  (552, 552, "Upper"), (553, 553, "Lower"), (554, 554, "Upper"),
+  (555, 555, "Lower"), (556, 556, "Upper"), (557, 557, "Lower"),
+  (558, 558, "Upper"), (559, 559, "Lower"), (560, 560, "Upper"),
+  (561, 561, "Lower"), (562, 562, "Upper"), (563, 569, "Lower"),
+  (570, 571, "Upper"), (572, 572, "Lower"), (573, 574, "Upper"),
+  (575, 576, "Lower"), (577, 577, "Upper"), (578, 578, "Lower"),
+  (579, 582, "Upper"), (583, 583, "Lower"), (584, 584, "Upper"),
+  (585, 585, "Lower"), (586, 586, "Upper"), (587, 587, "Lower"),
+  (588, 588, "Upper"), (589, 589, "Lower"), (590, 590, "Upper"),
+  (591, 659, "Lower"), (660, 660, "OLetter"), (661, 696, "Lower"),
+  (697, 703, "OLetter"), (704, 705, "Lower"), (710, 721, "OLetter"),
+  (736, 740, "Lower"), (748, 748, "OLetter"), (750, 750, "OLetter"),
+  (768, 879, "Extend"), (880, 880, "Upper"), (881, 881, "Lower"),
+  (882, 882, "Upper"), (883, 883, "Lower"), (884, 884, "OLetter"),
+  (886, 886, "Upper"), (887, 887, "Lower"), (890, 893, "Lower"),
+  (895, 895, "Upper"), (902, 902, "Upper"), (904, 906, "Upper"),
+  (908, 908, "Upper"), (910, 911, "Upper"), (912, 912, "Lower"),
+  (913, 929, "Upper"), (931, 939, "Upper"), (940, 974, "Lower"),
+  (975, 975, "Upper"), (976, 977, "Lower"), (978, 980, "Upper"),
+  (981, 983, "Lower"), (984, 984, "Upper"), (985, 985, "Lower"),
+  (986, 986, "Upper"), (987, 987, "Lower"), (988, 988, "Upper"),
+  (989, 989, "Lower"), (990, 990, "Upper"), (991, 991, "Lower"),
+  (992, 992, "Upper"), (993, 993, "Lower"), (994, 994, "Upper"),
+  (995, 995, "Lower"), (996, 996, "Upper"), (997, 997, "Lower"),
+  (998, 998, "Upper"), (999, 999, "Lower"), (1000, 1000, "Upper"),
+  (1001, 1001, "Lower"), (1002, 1002, "Upper"), (1003, 1003, "Lower"),
+  (1004, 1004, "Upper"), (1005, 1005, "Lower"), (1006, 1006, "Upper"),
+  (1007, 1011, "Lower"), (1012, 1012, "Upper"), (1013, 1013, "Lower"),
+  (1015, 1015, "Upper"), (1016, 1016, "Lower"), (1017, 1018, "Upper"),
+  (1019, 1020, "Lower"), (1021, 1071, "Upper"), (1072, 1119, "Lower"),
+  (1120, 1120, "Upper"), (1121, 1121, "Lower"), (1122, 1122, "Upper"),
+  (1123, 1123, "Lower"), (1124, 1124, "Upper"), (1125, 1125, "Lower"),
+  (1126, 1126, "Upper"), (1127, 1127, "Lower"), (1128, 1128, "Upper"),
+  (1129, 1129, "Lower"), (1130, 1130, "Upper"), (1131, 1131, "Lower"),
+  (1132, 1132, "Upper"), (1133, 1133, "Lower"), (1134, 1134, "Upper"),
+  (1135, 1135, "Lower"), (1136, 1136, "Upper"), (1137, 1137, "Lower"),
+  (1138, 1138, "Upper"), (1139, 1139, "Lower"), (1140, 1140, "Upper"),
+  (1141, 1141, "Lower"), (1142, 1142, "Upper"), (1143, 1143, "Lower"),
+  (1144, 1144, "Upper"), (1145, 1145, "Lower"), (1146, 1146, "Upper"),
+  (1147, 1147, "Lower"), (1148, 1148, "Upper"), (1149, 1149, "Lower"),
+  (1150, 1150, "Upper"), (1151, 1151, "Lower"), (1152, 1152, "Upper"),
+  (1153, 1153, "Lower"), (1155, 1161, "Extend"), (1162, 1162, "Upper"),
+  (1163, 1163, "Lower"), (1164, 1164, "Upper"), (1165, 1165, "Lower"),
+  (1166, 1166, "Upper"), (1167, 1167, "Lower"), (1168, 1168, "Upper"),
+  (1169, 1169, "Lower"), (1170, 1170, "Upper"), (1171, 1171, "Lower"),
+  (1172, 1172, "Upper"), (1173, 1173, "Lower"), (1174, 1174, "Upper"),
+  (1175, 1175, "Lower"), (1176, 1176, "Upper"), (1177, 1177, "Lower"),
+  (1178, 1178, "Upper"), (1179, 1179, "Lower"), (1180, 1180, "Upper"),
+  (1181, 1181, "Lower"), (1182, 1182, "Upper"), (1183, 1183, "Lower"),
+  (1184, 1184, "Upper"), (1185, 1185, "Lower"), (1186, 1186, "Upper"),
+  (1187, 1187, "Lower"), (1188, 1188, "Upper"), (1189, 1189, "Lower"),
+  (1190, 1190, "Upper"), (1191, 1191, "Lower"), (1192, 1192, "Upper"),
+  (1193, 1193, "Lower"), (1194, 1194, "Upper"), (1195, 1195, "Lower"),
+  (1196, 1196, "Upper"), (1197, 1197, "Lower"), (1198, 1198, "Upper"),
+  (1199, 1199, "Lower"), (1200, 1200, "Upper"), (1201, 1201, "Lower"),
+  (1202, 1202, "Upper"), (1203, 1203, "Lower"), (1204, 1204, "Upper"),
+  (1205, 1205, "Lower"), (1206, 1206, "Upper"), (1207, 1207, "Lower"),
+  (1208, 1208, "Upper"), (1209, 1209, "Lower"), (1210, 1210, "Upper"),
+  (1211, 1211, "Lower"), (1212, 1212, "Upper"), (1213, 1213, "Lower"),
+  (1214, 1214, "Upper"), (1215, 1215, "Lower"), (1216, 1217, "Upper"),
+  (1218, 1218, "Lower"), (1219, 1219, "Upper"), (1220, 1220, "Lower"),
+  (1221, 1221, "Upper"), (1222, 1222, "Lower"), (1223, 1223, "Upper"),
+  (1224, 1224, "Lower"), (1225, 1225, "Upper"), (1226, 1226, "Lower"),
+  (1227, 1227, "Upper"), (1228, 1228, "Lower"), (1229, 1229, "Upper"),
+  (1230, 1231, "Lower"), (1232, 1232, "Upper"), (1233, 1233, "Lower"),
+  (1234, 1234, "Upper"), (1235, 1235, "Lower"), (1236, 1236, "Upper"),
+  (1237, 1237, "Lower"), (1238, 1238, "Upper"), (1239, 1239, "Lower"),
+  (1240, 1240, "Upper"), (1241, 1241, "Lower"), (1242, 1242, "Upper"),
+  (1243, 1243, "Lower"), (1244, 1244, "Upper"), (1245, 1245, "Lower"),
+  (1246, 1246, "Upper"), (1247, 1247, "Lower"), (1248, 1248, "Upper"),
+  (1249, 1249, "Lower"), (1250, 1250, "Upper"), (1251, 1251, "Lower"),
+  (1252, 1252, "Upper"), (1253, 1253, "Lower"), (1254, 1254, "Upper"),
+  (1255, 1255, "Lower"), (1256, 1256, "Upper"), (1257, 1257, "Lower"),
+  (1258, 1258, "Upper"), (1259, 1259, "Lower"), (1260, 1260, "Upper"),
+  (1261, 1261, "Lower"), (1262, 1262, "Upper"), (1263, 1263, "Lower"),
+  (1264, 1264, "Upper"), (1265, 1265, "Lower"), (1266, 1266, "Upper"),
+  (1267, 1267, "Lower"), (1268, 1268, "Upper"), (1269, 1269, "Lower"),
+  (1270, 1270, "Upper"), (1271, 1271, "Lower"), (1272, 1272, "Upper"),
+  (1273, 1273, "Lower"), (1274, 1274, "Upper"), (1275, 1275, "Lower"),
+  (1276, 1276, "Upper"), (1277, 1277, "Lower"), (1278, 1278, "Upper"),
+  (1279, 1279, "Lower"), (1280, 1280, "Upper"), (1281, 1281, "Lower"),
+  (1282, 1282, "Upper"), (1283, 1283, "Lower"), (1284, 1284, "Upper"),
+  (1285, 1285, "Lower"), (1286, 1286, "Upper"), (1287, 1287, "Lower"),
+  (1288, 1288, "Upper"), (1289, 1289, "Lower"), (1290, 1290, "Upper"),
+  (1291, 1291, "Lower"), (1292, 1292, "Upper"), (1293, 1293, "Lower"),
+  (1294, 1294, "Upper"), (1295, 1295, "Lower"), (1296, 1296, "Upper"),
+  (1297, 1297, "Lower"), (1298, 1298, "Upper"), (1299, 1299, "Lower"),
+  (1300, 1300, "Upper"), (1301, 1301, "Lower"), (1302, 1302, "Upper"),
+  (1303, 1303, "Lower"), (1304, 1304, "Upper"), (1305, 1305, "Lower"),
+  (1306, 1306, "Upper"), (1307, 1307, "Lower"), (1308, 1308, "Upper"),
+  (1309, 1309, "Lower"), (1310, 1310, "Upper"), (1311, 1311, "Lower"),
+  (1312, 1312, "Upper"), (1313, 1313, "Lower"), (1314, 1314, "Upper"),
+  (1315, 1315, "Lower"), (1316, 1316, "Upper"), (1317, 1317, "Lower"),
+  (1318, 1318, "Upper"), (1319, 1319, "Lower"), (1320, 1320, "Upper"),
+  (1321, 1321, "Lower"), (1322, 1322, "Upper"), (1323, 1323, "Lower"),
+  (1324, 1324, "Upper"), (1325, 1325, "Lower"), (1326, 1326, "Upper"),
+  (1327, 1327, "Lower"), (1329, 1366, "Upper"), (1369, 1369, "OLetter"),
+  (1373, 1373, "SContinue"), (1377, 1415, "Lower"), (1417, 1417, "STerm"),
+  (1425, 1469, "Extend"), (1471, 1471, "Extend"), (1473, 1474, "Extend"),
+  (1476, 1477, "Extend"), (1479, 1479, "Extend"), (1488, 1514, "OLetter"),
+  (1520, 1523, "OLetter"), (1536, 1541, "Format"), (1548, 1549, "SContinue"),
+  (1552, 1562, "Extend"), (1564, 1564, "Format"), (1567, 1567, "STerm"),
+  (1568, 1610, "OLetter"), (1611, 1631, "Extend"), (1632, 1641, "Numeric"),
+  (1643, 1644, "Numeric"), (1646, 1647, "OLetter"), (1648, 1648, "Extend"),
+  (1649, 1747, "OLetter"), (1748, 1748, "STerm"), (1749, 1749, "OLetter"),
+  (1750, 1756, "Extend"), (1757, 1757, "Format"), (1759, 1764, "Extend"),
+  (1765, 1766, "OLetter"), (1767, 1768, "Extend"), (1770, 1773, "Extend"),
+  (1774, 1775, "OLetter"), (1776, 1785, "Numeric"), (1786, 1788, "OLetter"),
+  (1791, 1791, "OLetter"), (1792, 1794, "STerm"), (1807, 1807, "Format"),
+  (1808, 1808, "OLetter"), (1809, 1809, "Extend"), (1810, 1839, "OLetter"),
+  (1840, 1866, "Extend"), (1869, 1957, "OLetter"), (1958, 1968, "Extend"),
+  (1969, 1969, "OLetter"), (1984, 1993, "Numeric"), (1994, 2026, "OLetter"),
+  (2027, 2035, "Extend"), (2036, 2037, "OLetter"), (2040, 2040, "SContinue"),
+  (2041, 2041, "STerm"), (2042, 2042, "OLetter"), (2048, 2069, "OLetter"),
+  (2070, 2073, "Extend"), (2074, 2074, "OLetter"), (2075, 2083, "Extend"),
+  (2084, 2084, "OLetter"), (2085, 2087, "Extend"), (2088, 2088, "OLetter"),
+  (2089, 2093, "Extend"), (2112, 2136, "OLetter"), (2137, 2139, "Extend"),
+  (2208, 2228, "OLetter"), (2230, 2237, "OLetter"), (2260, 2273, "Extend"),
+  (2274, 2274, "Format"), (2275, 2307, "Extend"), (2308, 2361, "OLetter"),
+  (2362, 2364, "Extend"), (2365, 2365, "OLetter"), (2366, 2383, "Extend"),
+  (2384, 2384, "OLetter"), (2385, 2391, "Extend"), (2392, 2401, "OLetter"),
+  (2402, 2403, "Extend"), (2404, 2405, "STerm"), (2406, 2415, "Numeric"),
+  (2417, 2432, "OLetter"), (2433, 2435, "Extend"), (2437, 2444, "OLetter"),
+  (2447, 2448, "OLetter"), (2451, 2472, "OLetter"), (2474, 2480, "OLetter"),
+  (2482, 2482, "OLetter"), (2486, 2489, "OLetter"), (2492, 2492, "Extend"),
+  (2493, 2493, "OLetter"), (2494, 2500, "Extend"), (2503, 2504, "Extend"),
+  (2507, 2509, "Extend"), (2510, 2510, "OLetter"), (2519, 2519, "Extend"),
+  (2524, 2525, "OLetter"), (2527, 2529, "OLetter"), (2530, 2531, "Extend"),
+  (2534, 2543, "Numeric"), (2544, 2545, "OLetter"), (2561, 2563, "Extend"),
+  (2565, 2570, "OLetter"), (2575, 2576, "OLetter"), (2579, 2600, "OLetter"),
+  (2602, 2608, "OLetter"), (2610, 2611, "OLetter"), (2613, 2614, "OLetter"),
+  (2616, 2617, "OLetter"), (2620, 2620, "Extend"), (2622, 2626, "Extend"),
+  (2631, 2632, "Extend"), (2635, 2637, "Extend"), (2641, 2641, "Extend"),
+  (2649, 2652, "OLetter"), (2654, 2654, "OLetter"), (2662, 2671, "Numeric"),
+  (2672, 2673, "Extend"), (2674, 2676, "OLetter"), (2677, 2677, "Extend"),
+  (2689, 2691, "Extend"), (2693, 2701, "OLetter"), (2703, 2705, "OLetter"),
+  (2707, 2728, "OLetter"), (2730, 2736, "OLetter"), (2738, 2739, "OLetter"),
+  (2741, 2745, "OLetter"), (2748, 2748, "Extend"), (2749, 2749, "OLetter"),
+  (2750, 2757, "Extend"), (2759, 2761, "Extend"), (2763, 2765, "Extend"),
+  (2768, 2768, "OLetter"), (2784, 2785, "OLetter"), (2786, 2787, "Extend"),
+  (2790, 2799, "Numeric"), (2809, 2809, "OLetter"), (2817, 2819, "Extend"),
+  (2821, 2828, "OLetter"), (2831, 2832, "OLetter"), (2835, 2856, "OLetter"),
+  (2858, 2864, "OLetter"), (2866, 2867, "OLetter"), (2869, 2873, "OLetter"),
+  (2876, 2876, "Extend"), (2877, 2877, "OLetter"), (2878, 2884, "Extend"),
+  (2887, 2888, "Extend"), (2891, 2893, "Extend"), (2902, 2903, "Extend"),
+  (2908, 2909, "OLetter"), (2911, 2913, "OLetter"), (2914, 2915, "Extend"),
+  (2918, 2927, "Numeric"), (2929, 2929, "OLetter"), (2946, 2946, "Extend"),
+  (2947, 2947, "OLetter"), (2949, 2954, "OLetter"), (2958, 2960, "OLetter"),
+  (2962, 2965, "OLetter"), (2969, 2970, "OLetter"), (2972, 2972, "OLetter"),
+  (2974, 2975, "OLetter"), (2979, 2980, "OLetter"), (2984, 2986, "OLetter"),
+  (2990, 3001, "OLetter"), (3006, 3010, "Extend"), (3014, 3016, "Extend"),
+  (3018, 3021, "Extend"), (3024, 3024, "OLetter"), (3031, 3031, "Extend"),
+  (3046, 3055, "Numeric"), (3072, 3075, "Extend"), (3077, 3084, "OLetter"),
+  (3086, 3088, "OLetter"), (3090, 3112, "OLetter"), (3114, 3129, "OLetter"),
+  (3133, 3133, "OLetter"), (3134, 3140, "Extend"), (3142, 3144, "Extend"),
+  (3146, 3149, "Extend"), (3157, 3158, "Extend"), (3160, 3162, "OLetter"),
+  (3168, 3169, "OLetter"), (3170, 3171, "Extend"), (3174, 3183, "Numeric"),
+  (3200, 3200, "OLetter"), (3201, 3203, "Extend"), (3205, 3212, "OLetter"),
+  (3214, 3216, "OLetter"), (3218, 3240, "OLetter"), (3242, 3251, "OLetter"),
+  (3253, 3257, "OLetter"), (3260, 3260, "Extend"), (3261, 3261, "OLetter"),
+  (3262, 3268, "Extend"), (3270, 3272, "Extend"), (3274, 3277, "Extend"),
+  (3285, 3286, "Extend"), (3294, 3294, "OLetter"), (3296, 3297, "OLetter"),
+  (3298, 3299, "Extend"), (3302, 3311, "Numeric"), (3313, 3314, "OLetter"),
+  (3329, 3331, "Extend"), (3333, 3340, "OLetter"), (3342, 3344, "OLetter"),
+  (3346, 3386, "OLetter"), (3389, 3389, "OLetter"), (3390, 3396, "Extend"),
+  (3398, 3400, "Extend"), (3402, 3405, "Extend"), (3406, 3406, "OLetter"),
+  (3412, 3414, "OLetter"), (3415, 3415, "Extend"), (3423, 3425, "OLetter"),
+  (3426, 3427, "Extend"), (3430, 3439, "Numeric"), (3450, 3455, "OLetter"),
+  (3458, 3459, "Extend"), (3461, 3478, "OLetter"), (3482, 3505, "OLetter"),
+  (3507, 3515, "OLetter"), (3517, 3517, "OLetter"), (3520, 3526, "OLetter"),
+  (3530, 3530, "Extend"), (3535, 3540, "Extend"), (3542, 3542, "Extend"),
+  (3544, 3551, "Extend"), (3558, 3567, "Numeric"), (3570, 3571, "Extend"),
+  (3585, 3632, "OLetter"), (3633, 3633, "Extend"), (3634, 3635, "OLetter"),
+  (3636, 3642, "Extend"), (3648, 3654, "OLetter"), (3655, 3662, "Extend"),
+  (3664, 3673, "Numeric"), (3713, 3714, "OLetter"), (3716, 3716, "OLetter"),
+  (3719, 3720, "OLetter"), (3722, 3722, "OLetter"), (3725, 3725, "OLetter"),
+  (3732, 3735, "OLetter"), (3737, 3743, "OLetter"), (3745, 3747, "OLetter"),
+  (3749, 3749, "OLetter"), (3751, 3751, "OLetter"), (3754, 3755, "OLetter"),
+  (3757, 3760, "OLetter"), (3761, 3761, "Extend"), (3762, 3763, "OLetter"),
+  (3764, 3769, "Extend"), (3771, 3772, "Extend"), (3773, 3773, "OLetter"),
+  (3776, 3780, "OLetter"), (3782, 3782, "OLetter"), (3784, 3789, "Extend"),
+  (3792, 3801, "Numeric"), (3804, 3807, "OLetter"), (3840, 3840, "OLetter"),
+  (3864, 3865, "Extend"), (3872, 3881, "Numeric"), (3893, 3893, "Extend"),
+  (3895, 3895, "Extend"), (3897, 3897, "Extend"), (3898, 3901, "Close"),
+  (3902, 3903, "Extend"), (3904, 3911, "OLetter"), (3913, 3948, "OLetter"),
+  (3953, 3972, "Extend"), (3974, 3975, "Extend"), (3976, 3980, "OLetter"),
+  (3981, 3991, "Extend"), (3993, 4028, "Extend"), (4038, 4038, "Extend"),
+  (4096, 4138, "OLetter"), (4139, 4158, "Extend"), (4159, 4159, "OLetter"),
+  (4160, 4169, "Numeric"), (4170, 4171, "STerm"), (4176, 4181, "OLetter"),
+  (4182, 4185, "Extend"), (4186, 4189, "OLetter"), (4190, 4192, "Extend"),
+  (4193, 4193, "OLetter"), (4194, 4196, "Extend"), (4197, 4198, "OLetter"),
+  (4199, 4205, "Extend"), (4206, 4208, "OLetter"), (4209, 4212, "Extend"),
+  (4213, 4225, "OLetter"), (4226, 4237, "Extend"), (4238, 4238, "OLetter"),
+  (4239, 4239, "Extend"), (4240, 4249, "Numeric"), (4250, 4253, "Extend"),
+  (4256, 4293, "Upper"), (4295, 4295, "Upper"), (4301, 4301, "Upper"),
+  (4304, 4346, "OLetter"), (4348, 4680, "OLetter"), (4682, 4685, "OLetter"),
+  (4688, 4694, "OLetter"), (4696, 4696, "OLetter"), (4698, 4701, "OLetter"),
+  (4704, 4744, "OLetter"), (4746, 4749, "OLetter"), (4752, 4784, "OLetter"),
+  (4786, 4789, "OLetter"), (4792, 4798, "OLetter"), (4800, 4800, "OLetter"),
+  (4802, 4805, "OLetter"), (4808, 4822, "OLetter"), (4824, 4880, "OLetter"),
+  (4882, 4885, "OLetter"), (4888, 4954, "OLetter"), (4957, 4959, "Extend"),
+  (4962, 4962, "STerm"), (4967, 4968, "STerm"), (4992, 5007, "OLetter"),
+  (5024, 5109, "Upper"), (5112, 5117, "Lower"), (5121, 5740, "OLetter"),
+  (5742, 5742, "STerm"), (5743, 5759, "OLetter"), (5760, 5760, "Sp"),
+  (5761, 5786, "OLetter"), (5787, 5788, "Close"), (5792, 5866, "OLetter"),
+  (5870, 5880, "OLetter"), (5888, 5900, "OLetter"), (5902, 5905, "OLetter"),
+  (5906, 5908, "Extend"), (5920, 5937, "OLetter"), (5938, 5940, "Extend"),
+  (5941, 5942, "STerm"), (5952, 5969, "OLetter"), (5970, 5971, "Extend"),
+  (5984, 5996, "OLetter"), (5998, 6000, "OLetter"), (6002, 6003, "Extend"),
+  (6016, 6067, "OLetter"), (6068, 6099, "Extend"), (6103, 6103, "OLetter"),
+  (6108, 6108, "OLetter"), (6109, 6109, "Extend"), (6112, 6121, "Numeric"),
+  (6146, 6146, "SContinue"), (6147, 6147, "STerm"), (6152, 6152, "SContinue"),
+  (6153, 6153, "STerm"), (6155, 6157, "Extend"), (6158, 6158, "Format"),
+  (6160, 6169, "Numeric"), (6176, 6263, "OLetter"), (6272, 6276, "OLetter"),
+  (6277, 6278, "Extend"), (6279, 6312, "OLetter"), (6313, 6313, "Extend"),
+  (6314, 6314, "OLetter"), (6320, 6389, "OLetter"), (6400, 6430, "OLetter"),
+  (6432, 6443, "Extend"), (6448, 6459, "Extend"), (6468, 6469, "STerm"),
+  (6470, 6479, "Numeric"), (6480, 6509, "OLetter"), (6512, 6516, "OLetter"),
+  (6528, 6571, "OLetter"), (6576, 6601, "OLetter"), (6608, 6617, "Numeric"),
+  (6656, 6678, "OLetter"), (6679, 6683, "Extend"), (6688, 6740, "OLetter"),
+  (6741, 6750, "Extend"), (6752, 6780, "Extend"), (6783, 6783, "Extend"),
+  (6784, 6793, "Numeric"), (6800, 6809, "Numeric"), (6823, 6823, "OLetter"),
+  (6824, 6827, "STerm"), (6832, 6846, "Extend"), (6912, 6916, "Extend"),
+  (6917, 6963, "OLetter"), (6964, 6980, "Extend"), (6981, 6987, "OLetter"),
+  (6992, 7001, "Numeric"), (7002, 7003, "STerm"), (7006, 7007, "STerm"),
+  (7019, 7027, "Extend"), (7040, 7042, "Extend"), (7043, 7072, "OLetter"),
+  (7073, 7085, "Extend"), (7086, 7087, "OLetter"), (7088, 7097, "Numeric"),
+  (7098, 7141, "OLetter"), (7142, 7155, "Extend"), (7168, 7203, "OLetter"),
+  (7204, 7223, "Extend"), (7227, 7228, "STerm"), (7232, 7241, "Numeric"),
+  (7245, 7247, "OLetter"), (7248, 7257, "Numeric"), (7258, 7293, "OLetter"),
+  (7294, 7295, "STerm"), (7296, 7304, "Lower"), (7376, 7378, "Extend"),
+  (7380, 7400, "Extend"), (7401, 7404, "OLetter"), (7405, 7405, "Extend"),
+  (7406, 7409, "OLetter"), (7410, 7412, "Extend"), (7413, 7414, "OLetter"),
+  (7416, 7417, "Extend"), (7424, 7615, "Lower"), (7616, 7669, "Extend"),
+  (7675, 7679, "Extend"), (7680, 7680, "Upper"), (7681, 7681, "Lower"),
+  (7682, 7682, "Upper"), (7683, 7683, "Lower"), (7684, 7684, "Upper"),
+  (7685, 7685, "Lower"), (7686, 7686, "Upper"), (7687, 7687, "Lower"),
+  (7688, 7688, "Upper"), (7689, 7689, "Lower"), (7690, 7690, "Upper"),
+  (7691, 7691, "Lower"), (7692, 7692, "Upper"), (7693, 7693, "Lower"),
+  (7694, 7694, "Upper"), (7695, 7695, "Lower"), (7696, 7696, "Upper"),
+  (7697, 7697, "Lower"), (7698, 7698, "Upper"), (7699, 7699, "Lower"),
+  (7700, 7700, "Upper"), (7701, 7701, "Lower"), (7702, 7702, "Upper"),
+  (7703, 7703, "Lower"), (7704, 7704, "Upper"), (7705, 7705, "Lower"),
+  (7706, 7706, "Upper"), (7707, 7707, "Lower"), (7708, 7708, "Upper"),
+  (7709, 7709, "Lower"), (7710, 7710, "Upper"), (7711, 7711, "Lower"),
+  (7712, 7712, "Upper"), (7713, 7713, "Lower"), (7714, 7714, "Upper"),
+  (7715, 7715, "Lower"), (7716, 7716, "Upper"), (7717, 7717, "Lower"),
+  (7718, 7718, "Upper"), (7719, 7719, "Lower"), (7720, 7720, "Upper"),
+  (7721, 7721, "Lower"), (7722, 7722, "Upper"), (7723, 7723, "Lower"),
+  (7724, 7724, "Upper"), (7725, 7725, "Lower"), (7726, 7726, "Upper"),
+  (7727, 7727, "Lower"), (7728, 7728, "Upper"), (7729, 7729, "Lower"),
+  (7730, 7730, "Upper"), (7731, 7731, "Lower"), (7732, 7732, "Upper"),
+  (7733, 7733, "Lower"), (7734, 7734, "Upper"), (7735, 7735, "Lower"),
+  (7736, 7736, "Upper"), (7737, 7737, "Lower"), (7738, 7738, "Upper"),
+  (7739, 7739, "Lower"), (7740, 7740, "Upper"), (7741, 7741, "Lower"),
+  (7742, 7742, "Upper"), (7743, 7743, "Lower"), (7744, 7744, "Upper"),
+  (7745, 7745, "Lower"), (7746, 7746, "Upper"), (7747, 7747, "Lower"),
+  (7748, 7748, "Upper"), (7749, 7749, "Lower"), (7750, 7750, "Upper"),
+  (7751, 7751, "Lower"), (7752, 7752, "Upper"), (7753, 7753, "Lower"),
+  (7754, 7754, "Upper"), (7755, 7755, "Lower"), (7756, 7756, "Upper"),
+  (7757, 7757, "Lower"), (7758, 7758, "Upper"), (7759, 7759, "Lower"),
+  (7760, 7760, "Upper"), (7761, 7761, "Lower"), (7762, 7762, "Upper"),
+  (7763, 7763, "Lower"), (7764, 7764, "Upper"), (7765, 7765, "Lower"),
+  (7766, 7766, "Upper"), (7767, 7767, "Lower"), (7768, 7768, "Upper"),
+  (7769, 7769, "Lower"), (7770, 7770, "Upper"), (7771, 7771, "Lower"),
+  (7772, 7772, "Upper"), (7773, 7773, "Lower"), (7774, 7774, "Upper"),
+  (7775, 7775, "Lower"), (7776, 7776, "Upper"), (7777, 7777, "Lower"),
+  (7778, 7778, "Upper"), (7779, 7779, "Lower"), (7780, 7780, "Upper"),
+  (7781, 7781, "Lower"), (7782, 7782, "Upper"), (7783, 7783, "Lower"),
+  (7784, 7784, "Upper"), (7785, 7785, "Lower"), (7786, 7786, "Upper"),
+  (7787, 7787, "Lower"), (7788, 7788, "Upper"), (7789, 7789, "Lower"),
+  (7790, 7790, "Upper"), (7791, 7791, "Lower"), (7792, 7792, "Upper"),
+  (7793, 7793, "Lower"), (7794, 7794, "Upper"), (7795, 7795, "Lower"),
+  (7796, 7796, "Upper"), (7797, 7797, "Lower"), (7798, 7798, "Upper"),
+  (7799, 7799, "Lower"), (7800, 7800, "Upper"), (7801, 7801, "Lower"),
+  (7802, 7802, "Upper"), (7803, 7803, "Lower"), (7804, 7804, "Upper"),
+  (7805, 7805, "Lower"), (7806, 7806, "Upper"), (7807, 7807, "Lower"),
+  (7808, 7808, "Upper"), (7809, 7809, "Lower"), (7810, 7810, "Upper"),
+  (7811, 7811, "Lower"), (7812, 7812, "Upper"), (7813, 7813, "Lower"),
+  (7814, 7814, "Upper"), (7815, 7815, "Lower"), (7816, 7816, "Upper"),
+  (7817, 7817, "Lower"), (7818, 7818, "Upper"), (7819, 7819, "Lower"),
+  (7820, 7820, "Upper"), (7821, 7821, "Lower"), (7822, 7822, "Upper"),
+  (7823, 7823, "Lower"), (7824, 7824, "Upper"), (7825, 7825, "Lower"),
+  (7826, 7826, "Upper"), (7827, 7827, "Lower"), (7828, 7828, "Upper"),
+  (7829, 7837, "Lower"), (7838, 7838, "Upper"), (7839, 7839, "Lower"),
+  (7840, 7840, "Upper"), (7841, 7841, "Lower"), (7842, 7842, "Upper"),
+  (7843, 7843, "Lower"), (7844, 7844, "Upper"), (7845, 7845, "Lower"),
+  (7846, 7846, "Upper"), (7847, 7847, "Lower"), (7848, 7848, "Upper"),
+  (7849, 7849, "Lower"), (7850, 7850, "Upper"), (7851, 7851, "Lower"),
+  (7852, 7852, "Upper"), (7853, 7853, "Lower"), (7854, 7854, "Upper"),
+  (7855, 7855, "Lower"), (7856, 7856, "Upper"), (7857, 7857, "Lower"),
+  (7858, 7858, "Upper"), (7859, 7859, "Lower"), (7860, 7860, "Upper"),
+  (7861, 7861, "Lower"), (7862, 7862, "Upper"), (7863, 7863, "Lower"),
+  (7864, 7864, "Upper"), (7865, 7865, "Lower"), (7866, 7866, "Upper"),
+  (7867, 7867, "Lower"), (7868, 7868, "Upper"), (7869, 7869, "Lower"),
+  (7870, 7870, "Upper"), (7871, 7871, "Lower"), (7872, 7872, "Upper"),
+  (7873, 7873, "Lower"), (7874, 7874, "Upper"), (7875, 7875, "Lower"),
+  (7876, 7876, "Upper"), (7877, 7877, "Lower"), (7878, 7878, "Upper"),
+  (7879, 7879, "Lower"), (7880, 7880, "Upper"), (7881, 7881, "Lower"),
+  (7882, 7882, "Upper"), (7883, 7883, "Lower"), (7884, 7884, "Upper"),
+  (7885, 7885, "Lower"), (7886, 7886, "Upper"), (7887, 7887, "Lower"),
+  (7888, 7888, "Upper"), (7889, 7889, "Lower"), (7890, 7890, "Upper"),
+  (7891, 7891, "Lower"), (7892, 7892, "Upper"), (7893, 7893, "Lower"),
+  (7894, 7894, "Upper"), (7895, 7895, "Lower"), (7896, 7896, "Upper"),
+  (7897, 7897, "Lower"), (7898, 7898, "Upper"), (7899, 7899, "Lower"),
+  (7900, 7900, "Upper"), (7901, 7901, "Lower"), (7902, 7902, "Upper"),
+  (7903, 7903, "Lower"), (7904, 7904, "Upper"), (7905, 7905, "Lower"),
+  (7906, 7906, "Upper"), (7907, 7907, "Lower"), (7908, 7908, "Upper"),
+  (7909, 7909, "Lower"), (7910, 7910, "Upper"), (7911, 7911, "Lower"),
+  (7912, 7912, "Upper"), (7913, 7913, "Lower"), (7914, 7914, "Upper"),
+  (7915, 7915, "Lower"), (7916, 7916, "Upper"), (7917, 7917, "Lower"),
+  (7918, 7918, "Upper"), (7919, 7919, "Lower"), (7920, 7920, "Upper"),
+  (7921, 7921, "Lower"), (7922, 7922, "Upper"), (7923, 7923, "Lower"),
+  (7924, 7924, "Upper"), (7925, 7925, "Lower"), (7926, 7926, "Upper"),
+  (7927, 7927, "Lower"), (7928, 7928, "Upper"), (7929, 7929, "Lower"),
+  (7930, 7930, "Upper"), (7931, 7931, "Lower"), (7932, 7932, "Upper"),
+  (7933, 7933, "Lower"), (7934, 7934, "Upper"), (7935, 7943, "Lower"),
+  (7944, 7951, "Upper"), (7952, 7957, "Lower"), (7960, 7965, "Upper"),
+  (7968, 7975, "Lower"), (7976, 7983, "Upper"), (7984, 7991, "Lower"),
+  (7992, 7999, "Upper"), (8000, 8005, "Lower"), (8008, 8013, "Upper"),
+  (8016, 8023, "Lower"), (8025, 8025, "Upper"), (8027, 8027, "Upper"),
+  (8029, 8029, "Upper"), (8031, 8031, "Upper"), (8032, 8039, "Lower"),
+  (8040, 8047, "Upper"), (8048, 8061, "Lower"), (8064, 8071, "Lower"),
+  (8072, 8079, "Upper"), (8080, 8087, "Lower"), (8088, 8095, "Upper"),
+  (8096, 8103, "Lower"), (8104, 8111, "Upper"), (8112, 8116, "Lower"),
+  (8118, 8119, "Lower"), (8120, 8124, "Upper"), (8126, 8126, "Lower"),
+  (8130, 8132, "Lower"), (8134, 8135, "Lower"), (8136, 8140, "Upper"),
+  (8144, 8147, "Lower"), (8150, 8151, "Lower"), (8152, 8155, "Upper"),
+  (8160, 8167, "Lower"), (8168, 8172, "Upper"), (8178, 8180, "Lower"),
+  (8182, 8183, "Lower"), (8184, 8188, "Upper"), (8192, 8202, "Sp"),
+  (8203, 8203, "Format"), (8204, 8205, "Extend"), (8206, 8207, "Format"),
+  (8211, 8212, "SContinue"), (8216, 8223, "Close"), (8228, 8228, "ATerm"),
+  (8232, 8233, "Sep"), (8234, 8238, "Format"), (8239, 8239, "Sp"),
+  (8249, 8250, "Close"), (8252, 8253, "STerm"), (8261, 8262, "Close"),
+  (8263, 8265, "STerm"), (8287, 8287, "Sp"), (8288, 8292, "Format"),
+  (8294, 8303, "Format"), (8305, 8305, "Lower"), (8317, 8318, "Close"),
+  (8319, 8319, "Lower"), (8333, 8334, "Close"), (8336, 8348, "Lower"),
+  (8400, 8432, "Extend"), (8450, 8450, "Upper"), (8455, 8455, "Upper"),
+  (8458, 8458, "Lower"), (8459, 8461, "Upper"), (8462, 8463, "Lower"),
+  (8464, 8466, "Upper"), (8467, 8467, "Lower"), (8469, 8469, "Upper"),
+  (8473, 8477, "Upper"), (8484, 8484, "Upper"), (8486, 8486, "Upper"),
+  (8488, 8488, "Upper"), (8490, 8493, "Upper"), (8495, 8495, "Lower"),
+  (8496, 8499, "Upper"), (8500, 8500, "Lower"), (8501, 8504, "OLetter"),
+  (8505, 8505, "Lower"), (8508, 8509, "Lower"), (8510, 8511, "Upper"),
+  (8517, 8517, "Upper"), (8518, 8521, "Lower"), (8526, 8526, "Lower"),
+  (8544, 8559, "Upper"), (8560, 8575, "Lower"), (8576, 8578, "OLetter"),
+  (8579, 8579, "Upper"), (8580, 8580, "Lower"), (8581, 8584, "OLetter"),
+  (8968, 8971, "Close"), (9001, 9002, "Close"), (9398, 9423, "Upper"),
+  (9424, 9449, "Lower"), (10075, 10080, "Close"), (10088, 10101, "Close"),
+  (10181, 10182, "Close"), (10214, 10223, "Close"), (10627, 10648, "Close"),
+  (10712, 10715, "Close"), (10748, 10749, "Close"), (11264, 11310, "Upper"),
+  (11312, 11358, "Lower"), (11360, 11360, "Upper"), (11361, 11361, "Lower"),
+  (11362, 11364, "Upper"), (11365, 11366, "Lower"), (11367, 11367, "Upper"),
+  (11368, 11368, "Lower"), (11369, 11369, "Upper"), (11370, 11370, "Lower"),
+  (11371, 11371, "Upper"), (11372, 11372, "Lower"), (11373, 11376, "Upper"),
+  (11377, 11377, "Lower"), (11378, 11378, "Upper"), (11379, 11380, "Lower"),
+  (11381, 11381, "Upper"), (11382, 11389, "Lower"), (11390, 11392, "Upper"),
+  (11393, 11393, "Lower"), (11394, 11394, "Upper"), (11395, 11395, "Lower"),
+  (11396, 11396, "Upper"), (11397, 11397, "Lower"), (11398, 11398, "Upper"),
+  (11399, 11399, "Lower"), (11400, 11400, "Upper"), (11401, 11401, "Lower"),
+  (11402, 11402, "Upper"), (11403, 11403, "Lower"), (11404, 11404, "Upper"),
+  (11405, 11405, "Lower"), (11406, 11406, "Upper"), (11407, 11407, "Lower"),
+  (11408, 11408, "Upper"), (11409, 11409, "Lower"), (11410, 11410, "Upper"),
+  (11411, 11411, "Lower"), (11412, 11412, "Upper"), (11413, 11413, "Lower"),
+  (11414, 11414, "Upper"), (11415, 11415, "Lower"), (11416, 11416, "Upper"),
+  (11417, 11417, "Lower"), (11418, 11418, "Upper"), (11419, 11419, "Lower"),
+  (11420, 11420, "Upper"), (11421, 11421, "Lower"), (11422, 11422, "Upper"),
+  (11423, 11423, "Lower"), (11424, 11424, "Upper"), (11425, 11425, "Lower"),
+  (11426, 11426, "Upper"), (11427, 11427, "Lower"), (11428, 11428, "Upper"),
+  (11429, 11429, "Lower"), (11430, 11430, "Upper"), (11431, 11431, "Lower"),
+  (11432, 11432, "Upper"), (11433, 11433, "Lower"), (11434, 11434, "Upper"),
+  (11435, 11435, "Lower"), (11436, 11436, "Upper"), (11437, 11437, "Lower"),
+  (11438, 11438, "Upper"), (11439, 11439, "Lower"), (11440, 11440, "Upper"),
+  (11441, 11441, "Lower"), (11442, 11442, "Upper"), (11443, 11443, "Lower"),
+  (11444, 11444, "Upper"), (11445, 11445, "Lower"), (11446, 11446, "Upper"),
+  (11447, 11447, "Lower"), (11448, 11448, "Upper"), (11449, 11449, "Lower"),
+  (11450, 11450, "Upper"), (11451, 11451, "Lower"), (11452, 11452, "Upper"),
+  (11453, 11453, "Lower"), (11454, 11454, "Upper"), (11455, 11455, "Lower"),
+  (11456, 11456, "Upper"), (11457, 11457, "Lower"), (11458, 11458, "Upper"),
+  (11459, 11459, "Lower"), (11460, 11460, "Upper"), (11461, 11461, "Lower"),
+  (11462, 11462, "Upper"), (11463, 11463, "Lower"), (11464, 11464, "Upper"),
+  (11465, 11465, "Lower"), (11466, 11466, "Upper"), (11467, 11467, "Lower"),
+  (11468, 11468, "Upper"), (11469, 11469, "Lower"), (11470, 11470, "Upper"),
+  (11471, 11471, "Lower"), (11472, 11472, "Upper"), (11473, 11473, "Lower"),
+  (11474, 11474, "Upper"), (11475, 11475, "Lower"), (11476, 11476, "Upper"),
+  (11477, 11477, "Lower"), (11478, 11478, "Upper"), (11479, 11479, "Lower"),
+  (11480, 11480, "Upper"), (11481, 11481, "Lower"), (11482, 11482, "Upper"),
+  (11483, 11483, "Lower"), (11484, 11484, "Upper"), (11485, 11485, "Lower"),
+  (11486, 11486, "Upper"), (11487, 11487, "Lower"), (11488, 11488, "Upper"),
+  (11489, 11489, "Lower"), (11490, 11490, "Upper"), (11491, 11492, "Lower"),
+  (11499, 11499, "Upper"), (11500, 11500, "Lower"), (11501, 11501, "Upper"),
+  (11502, 11502, "Lower"), (11503, 11505, "Extend"), (11506, 11506, "Upper"),
+  (11507, 11507, "Lower"), (11520, 11557, "Lower"), (11559, 11559, "Lower"),
+  (11565, 11565, "Lower"), (11568, 11623, "OLetter"),
+  (11631, 11631, "OLetter"), (11647, 11647, "Extend"),
+  (11648, 11670, "OLetter"), (11680, 11686, "OLetter"),
+  (11688, 11694, "OLetter"), (11696, 11702, "OLetter"),
+  (11704, 11710, "OLetter"), (11712, 11718, "OLetter"),
+  (11720, 11726, "OLetter"), (11728, 11734, "OLetter"),
+  (11736, 11742, "OLetter"), (11744, 11775, "Extend"), (11776, 11789, "Close"),
+  (11804, 11805, "Close"), (11808, 11817, "Close"), (11822, 11822, "STerm"),
+  (11823, 11823, "OLetter"), (11836, 11836, "STerm"), (11842, 11842, "Close"),
+  (12288, 12288, "Sp"), (12289, 12289, "SContinue"), (12290, 12290, "STerm"),
+  (12293, 12295, "OLetter"), (12296, 12305, "Close"), (12308, 12315, "Close"),
+  (12317, 12319, "Close"), (12321, 12329, "OLetter"), (12330, 12335, "Extend"),
+  (12337, 12341, "OLetter"), (12344, 12348, "OLetter"),
+  (12353, 12438, "OLetter"), (12441, 12442, "Extend"),
+  (12445, 12447, "OLetter"), (12449, 12538, "OLetter"),
+  (12540, 12543, "OLetter"), (12549, 12589, "OLetter"),
+  (12593, 12686, "OLetter"), (12704, 12730, "OLetter"),
+  (12784, 12799, "OLetter"), (13312, 19893, "OLetter"),
+  (19968, 40917, "OLetter"), (40960, 42124, "OLetter"),
+  (42192, 42237, "OLetter"), (42239, 42239, "STerm"),
+  (42240, 42508, "OLetter"), (42510, 42511, "STerm"),
+  (42512, 42527, "OLetter"), (42528, 42537, "Numeric"),
+  (42538, 42539, "OLetter"), (42560, 42560, "Upper"), (42561, 42561, "Lower"),
+  (42562, 42562, "Upper"), (42563, 42563, "Lower"), (42564, 42564, "Upper"),
+  (42565, 42565, "Lower"), (42566, 42566, "Upper"), (42567, 42567, "Lower"),
+  (42568, 42568, "Upper"), (42569, 42569, "Lower"), (42570, 42570, "Upper"),
+  (42571, 42571, "Lower"), (42572, 42572, "Upper"), (42573, 42573, "Lower"),
+  (42574, 42574, "Upper"), (42575, 42575, "Lower"), (42576, 42576, "Upper"),
+  (42577, 42577, "Lower"), (42578, 42578, "Upper"), (42579, 42579, "Lower"),
+  (42580, 42580, "Upper"), (42581, 42581, "Lower"), (42582, 42582, "Upper"),
+  (42583, 42583, "Lower"), (42584, 42584, "Upper"), (42585, 42585, "Lower"),
+  (42586, 42586, "Upper"), (42587, 42587, "Lower"), (42588, 42588, "Upper"),
+  (42589, 42589, "Lower"), (42590, 42590, "Upper"), (42591, 42591, "Lower"),
+  (42592, 42592, "Upper"), (42593, 42593, "Lower"), (42594, 42594, "Upper"),
+  (42595, 42595, "Lower"), (42596, 42596, "Upper"), (42597, 42597, "Lower"),
+  (42598, 42598, "Upper"), (42599, 42599, "Lower"), (42600, 42600, "Upper"),
+  (42601, 42601, "Lower"), (42602, 42602, "Upper"), (42603, 42603, "Lower"),
+  (42604, 42604, "Upper"), (42605, 42605, "Lower"), (42606, 42606, "OLetter"),
+  (42607, 42610, "Extend"), (42612, 42621, "Extend"),
+  (42623, 42623, "OLetter"), (42624, 42624, "Upper"), (42625, 42625, "Lower"),
+  (42626, 42626, "Upper"), (42627, 42627, "Lower"), (42628, 42628, "Upper"),
+  (42629, 42629, "Lower"), (42630, 42630, "Upper"), (42631, 42631, "Lower"),
+  (42632, 42632, "Upper"), (42633, 42633, "Lower"), (42634, 42634, "Upper"),
+  (42635, 42635, "Lower"), (42636, 42636, "Upper"), (42637, 42637, "Lower"),
+  (42638, 42638, "Upper"), (42639, 42639, "Lower"), (42640, 42640, "Upper"),
+  (42641, 42641, "Lower"), (42642, 42642, "Upper"), (42643, 42643, "Lower"),
+  (42644, 42644, "Upper"), (42645, 42645, "Lower"), (42646, 42646, "Upper"),
+  (42647, 42647, "Lower"), (42648, 42648, "Upper"), (42649, 42649, "Lower"),
+  (42650, 42650, "Upper"), (42651, 42653, "Lower"), (42654, 42655, "Extend"),
+  (42656, 42735, "OLetter"), (42736, 42737, "Extend"), (42739, 42739, "STerm"),
+  (42743, 42743, "STerm"), (42775, 42783, "OLetter"), (42786, 42786, "Upper"),
+  (42787, 42787, "Lower"), (42788, 42788, "Upper"), (42789, 42789, "Lower"),
+  (42790, 42790, "Upper"), (42791, 42791, "Lower"), (42792, 42792, "Upper"),
+  (42793, 42793, "Lower"), (42794, 42794, "Upper"), (42795, 42795, "Lower"),
+  (42796, 42796, "Upper"), (42797, 42797, "Lower"), (42798, 42798, "Upper"),
+  (42799, 42801, "Lower"), (42802, 42802, "Upper"), (42803, 42803, "Lower"),
+  (42804, 42804, "Upper"), (42805, 42805, "Lower"), (42806, 42806, "Upper"),
+  (42807, 42807, "Lower"), (42808, 42808, "Upper"), (42809, 42809, "Lower"),
+  (42810, 42810, "Upper"), (42811, 42811, "Lower"), (42812, 42812, "Upper"),
+  (42813, 42813, "Lower"), (42814, 42814, "Upper"), (42815, 42815, "Lower"),
+  (42816, 42816, "Upper"), (42817, 42817, "Lower"), (42818, 42818, "Upper"),
+  (42819, 42819, "Lower"), (42820, 42820, "Upper"), (42821, 42821, "Lower"),
+  (42822, 42822, "Upper"), (42823, 42823, "Lower"), (42824, 42824, "Upper"),
+  (42825, 42825, "Lower"), (42826, 42826, "Upper"), (42827, 42827, "Lower"),
+  (42828, 42828, "Upper"), (42829, 42829, "Lower"), (42830, 42830, "Upper"),
+  (42831, 42831, "Lower"), (42832, 42832, "Upper"), (42833, 42833, "Lower"),
+  (42834, 42834, "Upper"), (42835, 42835, "Lower"), (42836, 42836, "Upper"),
+  (42837, 42837, "Lower"), (42838, 42838, "Upper"), (42839, 42839, "Lower"),
+  (42840, 42840, "Upper"), (42841, 42841, "Lower"), (42842, 42842, "Upper"),
+  (42843, 42843, "Lower"), (42844, 42844, "Upper"), (42845, 42845, "Lower"),
+  (42846, 42846, "Upper"), (42847, 42847, "Lower"), (42848, 42848, "Upper"),
+  (42849, 42849, "Lower"), (42850, 42850, "Upper"), (42851, 42851, "Lower"),
+  (42852, 42852, "Upper"), (42853, 42853, "Lower"), (42854, 42854, "Upper"),
+  (42855, 42855, "Lower"), (42856, 42856, "Upper"), (42857, 42857, "Lower"),
+  (42858, 42858, "Upper"), (42859, 42859, "Lower"), (42860, 42860, "Upper"),
+  (42861, 42861, "Lower"), (42862, 42862, "Upper"), (42863, 42872, "Lower"),
+  (42873, 42873, "Upper"), (42874, 42874, "Lower"), (42875, 42875, "Upper"),
+  (42876, 42876, "Lower"), (42877, 42878, "Upper"), (42879, 42879, "Lower"),
+  (42880, 42880, "Upper"), (42881, 42881, "Lower"), (42882, 42882, "Upper"),
+  (42883, 42883, "Lower"), (42884, 42884, "Upper"), (42885, 42885, "Lower"),
+  (42886, 42886, "Upper"), (42887, 42887, "Lower"), (42888, 42888, "OLetter"),
+  (42891, 42891, "Upper"), (42892, 42892, "Lower"), (42893, 42893, "Upper"),
+  (42894, 42894, "Lower"), (42895, 42895, "OLetter"), (42896, 42896, "Upper"),
+  (42897, 42897, "Lower"), (42898, 42898, "Upper"), (42899, 42901, "Lower"),
+  (42902, 42902, "Upper"), (42903, 42903, "Lower"), (42904, 42904, "Upper"),
+  (42905, 42905, "Lower"), (42906, 42906, "Upper"), (42907, 42907, "Lower"),
+  (42908, 42908, "Upper"), (42909, 42909, "Lower"), (42910, 42910, "Upper"),
+  (42911, 42911, "Lower"), (42912, 42912, "Upper"), (42913, 42913, "Lower"),
+  (42914, 42914, "Upper"), (42915, 42915, "Lower"), (42916, 42916, "Upper"),
+  (42917, 42917, "Lower"), (42918, 42918, "Upper"), (42919, 42919, "Lower"),
+  (42920, 42920, "Upper"), (42921, 42921, "Lower"), (42922, 42926, "Upper"),
+  (42928, 42932, "Upper"), (42933, 42933, "Lower"), (42934, 42934, "Upper"),
+  (42935, 42935, "Lower"), (42999, 42999, "OLetter"), (43000, 43002, "Lower"),
+  (43003, 43009, "OLetter"), (43010, 43010, "Extend"),
+  (43011, 43013, "OLetter"), (43014, 43014, "Extend"),
+  (43015, 43018, "OLetter"), (43019, 43019, "Extend"),
+  (43020, 43042, "OLetter"), (43043, 43047, "Extend"),
+  (43072, 43123, "OLetter"), (43126, 43127, "STerm"), (43136, 43137, "Extend"),
+  (43138, 43187, "OLetter"), (43188, 43205, "Extend"), (43214, 43215, "STerm"),
+  (43216, 43225, "Numeric"), (43232, 43249, "Extend"),
+  (43250, 43255, "OLetter"), (43259, 43259, "OLetter"),
+  (43261, 43261, "OLetter"), (43264, 43273, "Numeric"),
+  (43274, 43301, "OLetter"), (43302, 43309, "Extend"), (43311, 43311, "STerm"),
+  (43312, 43334, "OLetter"), (43335, 43347, "Extend"),
+  (43360, 43388, "OLetter"), (43392, 43395, "Extend"),
+  (43396, 43442, "OLetter"), (43443, 43456, "Extend"), (43464, 43465, "STerm"),
+  (43471, 43471, "OLetter"), (43472, 43481, "Numeric"),
+  (43488, 43492, "OLetter"), (43493, 43493, "Extend"),
+  (43494, 43503, "OLetter"), (43504, 43513, "Numeric"),
+  (43514, 43518, "OLetter"), (43520, 43560, "OLetter"),
+  (43561, 43574, "Extend"), (43584, 43586, "OLetter"),
+  (43587, 43587, "Extend"), (43588, 43595, "OLetter"),
+  (43596, 43597, "Extend"), (43600, 43609, "Numeric"), (43613, 43615, "STerm"),
+  (43616, 43638, "OLetter"), (43642, 43642, "OLetter"),
+  (43643, 43645, "Extend"), (43646, 43695, "OLetter"),
+  (43696, 43696, "Extend"), (43697, 43697, "OLetter"),
+  (43698, 43700, "Extend"), (43701, 43702, "OLetter"),
+  (43703, 43704, "Extend"), (43705, 43709, "OLetter"),
+  (43710, 43711, "Extend"), (43712, 43712, "OLetter"),
+  (43713, 43713, "Extend"), (43714, 43714, "OLetter"),
+  (43739, 43741, "OLetter"), (43744, 43754, "OLetter"),
+  (43755, 43759, "Extend"), (43760, 43761, "STerm"), (43762, 43764, "OLetter"),
+  (43765, 43766, "Extend"), (43777, 43782, "OLetter"),
+  (43785, 43790, "OLetter"), (43793, 43798, "OLetter"),
+  (43808, 43814, "OLetter"), (43816, 43822, "OLetter"),
+  (43824, 43866, "Lower"), (43868, 43877, "Lower"), (43888, 43967, "Lower"),
+  (43968, 44002, "OLetter"), (44003, 44010, "Extend"), (44011, 44011, "STerm"),
+  (44012, 44013, "Extend"), (44016, 44025, "Numeric"),
+  (44032, 55203, "OLetter"), (55216, 55238, "OLetter"),
+  (55243, 55291, "OLetter"), (63744, 64109, "OLetter"),
+  (64112, 64217, "OLetter"), (64256, 64262, "Lower"), (64275, 64279, "Lower"),
+  (64285, 64285, "OLetter"), (64286, 64286, "Extend"),
+  (64287, 64296, "OLetter"), (64298, 64310, "OLetter"),
+  (64312, 64316, "OLetter"), (64318, 64318, "OLetter"),
+  (64320, 64321, "OLetter"), (64323, 64324, "OLetter"),
+  (64326, 64433, "OLetter"), (64467, 64829, "OLetter"),
+  (64830, 64831, "Close"), (64848, 64911, "OLetter"),
+  (64914, 64967, "OLetter"), (65008, 65019, "OLetter"),
+  (65024, 65039, "Extend"), (65040, 65041, "SContinue"),
+  (65043, 65043, "SContinue"), (65047, 65048, "Close"),
+  (65056, 65071, "Extend"), (65073, 65074, "SContinue"),
+  (65077, 65092, "Close"), (65095, 65096, "Close"),
+  (65104, 65105, "SContinue"), (65106, 65106, "ATerm"),
+  (65109, 65109, "SContinue"), (65110, 65111, "STerm"),
+  (65112, 65112, "SContinue"), (65113, 65118, "Close"),
+  (65123, 65123, "SContinue"), (65136, 65140, "OLetter"),
+  (65142, 65276, "OLetter"), (65279, 65279, "Format"), (65281, 65281, "STerm"),
+  (65288, 65289, "Close"), (65292, 65293, "SContinue"),
+  (65294, 65294, "ATerm"), (65306, 65306, "SContinue"),
+  (65311, 65311, "STerm"), (65313, 65338, "Upper"), (65339, 65339, "Close"),
+  (65341, 65341, "Close"), (65345, 65370, "Lower"), (65371, 65371, "Close"),
+  (65373, 65373, "Close"), (65375, 65376, "Close"), (65377, 65377, "STerm"),
+  (65378, 65379, "Close"), (65380, 65380, "SContinue"),
+  (65382, 65437, "OLetter"), (65438, 65439, "Extend"),
+  (65440, 65470, "OLetter"), (65474, 65479, "OLetter"),
+  (65482, 65487, "OLetter"), (65490, 65495, "OLetter"),
+  (65498, 65500, "OLetter"), (65529, 65531, "Format"),
+  (65536, 65547, "OLetter"), (65549, 65574, "OLetter"),
+  (65576, 65594, "OLetter"), (65596, 65597, "OLetter"),
+  (65599, 65613, "OLetter"), (65616, 65629, "OLetter"),
+  (65664, 65786, "OLetter"), (65856, 65908, "OLetter"),
+  (66045, 66045, "Extend"), (66176, 66204, "OLetter"),
+  (66208, 66256, "OLetter"), (66272, 66272, "Extend"),
+  (66304, 66335, "OLetter"), (66352, 66378, "OLetter"),
+  (66384, 66421, "OLetter"), (66422, 66426, "Extend"),
+  (66432, 66461, "OLetter"), (66464, 66499, "OLetter"),
+  (66504, 66511, "OLetter"), (66513, 66517, "OLetter"),
+  (66560, 66599, "Upper"), (66600, 66639, "Lower"), (66640, 66717, "OLetter"),
+  (66720, 66729, "Numeric"), (66736, 66771, "Upper"), (66776, 66811, "Lower"),
+  (66816, 66855, "OLetter"), (66864, 66915, "OLetter"),
+  (67072, 67382, "OLetter"), (67392, 67413, "OLetter"),
+  (67424, 67431, "OLetter"), (67584, 67589, "OLetter"),
+  (67592, 67592, "OLetter"), (67594, 67637, "OLetter"),
+  (67639, 67640, "OLetter"), (67644, 67644, "OLetter"),
+  (67647, 67669, "OLetter"), (67680, 67702, "OLetter"),
+  (67712, 67742, "OLetter"), (67808, 67826, "OLetter"),
+  (67828, 67829, "OLetter"), (67840, 67861, "OLetter"),
+  (67872, 67897, "OLetter"), (67968, 68023, "OLetter"),
+  (68030, 68031, "OLetter"), (68096, 68096, "OLetter"),
+  (68097, 68099, "Extend"), (68101, 68102, "Extend"), (68108, 68111, "Extend"),
+  (68112, 68115, "OLetter"), (68117, 68119, "OLetter"),
+  (68121, 68147, "OLetter"), (68152, 68154, "Extend"),
+  (68159, 68159, "Extend"), (68182, 68183, "STerm"), (68192, 68220, "OLetter"),
+  (68224, 68252, "OLetter"), (68288, 68295, "OLetter"),
+  (68297, 68324, "OLetter"), (68325, 68326, "Extend"),
+  (68352, 68405, "OLetter"), (68416, 68437, "OLetter"),
+  (68448, 68466, "OLetter"), (68480, 68497, "OLetter"),
+  (68608, 68680, "OLetter"), (68736, 68786, "Upper"), (68800, 68850, "Lower"),
+  (69632, 69634, "Extend"), (69635, 69687, "OLetter"),
+  (69688, 69702, "Extend"), (69703, 69704, "STerm"), (69734, 69743, "Numeric"),
+  (69759, 69762, "Extend"), (69763, 69807, "OLetter"),
+  (69808, 69818, "Extend"), (69821, 69821, "Format"), (69822, 69825, "STerm"),
+  (69840, 69864, "OLetter"), (69872, 69881, "Numeric"),
+  (69888, 69890, "Extend"), (69891, 69926, "OLetter"),
+  (69927, 69940, "Extend"), (69942, 69951, "Numeric"), (69953, 69955, "STerm"),
+  (69968, 70002, "OLetter"), (70003, 70003, "Extend"),
+  (70006, 70006, "OLetter"), (70016, 70018, "Extend"),
+  (70019, 70066, "OLetter"), (70067, 70080, "Extend"),
+  (70081, 70084, "OLetter"), (70085, 70086, "STerm"), (70090, 70092, "Extend"),
+  (70093, 70093, "STerm"), (70096, 70105, "Numeric"),
+  (70106, 70106, "OLetter"), (70108, 70108, "OLetter"),
+  (70110, 70111, "STerm"), (70144, 70161, "OLetter"),
+  (70163, 70187, "OLetter"), (70188, 70199, "Extend"), (70200, 70201, "STerm"),
+  (70203, 70204, "STerm"), (70206, 70206, "Extend"), (70272, 70278, "OLetter"),
+  (70280, 70280, "OLetter"), (70282, 70285, "OLetter"),
+  (70287, 70301, "OLetter"), (70303, 70312, "OLetter"),
+  (70313, 70313, "STerm"), (70320, 70366, "OLetter"), (70367, 70378, "Extend"),
+  (70384, 70393, "Numeric"), (70400, 70403, "Extend"),
+  (70405, 70412, "OLetter"), (70415, 70416, "OLetter"),
+  (70419, 70440, "OLetter"), (70442, 70448, "OLetter"),
+  (70450, 70451, "OLetter"), (70453, 70457, "OLetter"),
+  (70460, 70460, "Extend"), (70461, 70461, "OLetter"),
+  (70462, 70468, "Extend"), (70471, 70472, "Extend"), (70475, 70477, "Extend"),
+  (70480, 70480, "OLetter"), (70487, 70487, "Extend"),
+  (70493, 70497, "OLetter"), (70498, 70499, "Extend"),
+  (70502, 70508, "Extend"), (70512, 70516, "Extend"),
+  (70656, 70708, "OLetter"), (70709, 70726, "Extend"),
+  (70727, 70730, "OLetter"), (70731, 70732, "STerm"),
+  (70736, 70745, "Numeric"), (70784, 70831, "OLetter"),
+  (70832, 70851, "Extend"), (70852, 70853, "OLetter"),
+  (70855, 70855, "OLetter"), (70864, 70873, "Numeric"),
+  (71040, 71086, "OLetter"), (71087, 71093, "Extend"),
+  (71096, 71104, "Extend"), (71106, 71107, "STerm"), (71113, 71127, "STerm"),
+  (71128, 71131, "OLetter"), (71132, 71133, "Extend"),
+  (71168, 71215, "OLetter"), (71216, 71232, "Extend"), (71233, 71234, "STerm"),
+  (71236, 71236, "OLetter"), (71248, 71257, "Numeric"),
+  (71296, 71338, "OLetter"), (71339, 71351, "Extend"),
+  (71360, 71369, "Numeric"), (71424, 71449, "OLetter"),
+  (71453, 71467, "Extend"), (71472, 71481, "Numeric"), (71484, 71486, "STerm"),
+  (71840, 71871, "Upper"), (71872, 71903, "Lower"), (71904, 71913, "Numeric"),
+  (71935, 71935, "OLetter"), (72384, 72440, "OLetter"),
+  (72704, 72712, "OLetter"), (72714, 72750, "OLetter"),
+  (72751, 72758, "Extend"), (72760, 72767, "Extend"),
+  (72768, 72768, "OLetter"), (72769, 72770, "STerm"),
+  (72784, 72793, "Numeric"), (72818, 72847, "OLetter"),
+  (72850, 72871, "Extend"), (72873, 72886, "Extend"),
+  (73728, 74649, "OLetter"), (74752, 74862, "OLetter"),
+  (74880, 75075, "OLetter"), (77824, 78894, "OLetter"),
+  (82944, 83526, "OLetter"), (92160, 92728, "OLetter"),
+  (92736, 92766, "OLetter"), (92768, 92777, "Numeric"),
+  (92782, 92783, "STerm"), (92880, 92909, "OLetter"), (92912, 92916, "Extend"),
+  (92917, 92917, "STerm"), (92928, 92975, "OLetter"), (92976, 92982, "Extend"),
+  (92983, 92984, "STerm"), (92992, 92995, "OLetter"), (92996, 92996, "STerm"),
+  (93008, 93017, "Numeric"), (93027, 93047, "OLetter"),
+  (93053, 93071, "OLetter"), (93952, 94020, "OLetter"),
+  (94032, 94032, "OLetter"), (94033, 94078, "Extend"),
+  (94095, 94098, "Extend"), (94099, 94111, "OLetter"),
+  (94176, 94176, "OLetter"), (94208, 100332, "OLetter"),
+  (100352, 101106, "OLetter"), (110592, 110593, "OLetter"),
+  (113664, 113770, "OLetter"), (113776, 113788, "OLetter"),
+  (113792, 113800, "OLetter"), (113808, 113817, "OLetter"),
+  (113821, 113822, "Extend"), (113823, 113823, "STerm"),
+  (113824, 113827, "Format"), (119141, 119145, "Extend"),
+  (119149, 119154, "Extend"), (119155, 119162, "Format"),
+  (119163, 119170, "Extend"), (119173, 119179, "Extend"),
+  (119210, 119213, "Extend"), (119362, 119364, "Extend"),
+  (119808, 119833, "Upper"), (119834, 119859, "Lower"),
+  (119860, 119885, "Upper"), (119886, 119892, "Lower"),
+  (119894, 119911, "Lower"), (119912, 119937, "Upper"),
+  (119938, 119963, "Lower"), (119964, 119964, "Upper"),
+  (119966, 119967, "Upper"), (119970, 119970, "Upper"),
+  (119973, 119974, "Upper"), (119977, 119980, "Upper"),
+  (119982, 119989, "Upper"), (119990, 119993, "Lower"),
+  (119995, 119995, "Lower"), (119997, 120003, "Lower"),
+  (120005, 120015, "Lower"), (120016, 120041, "Upper"),
+  (120042, 120067, "Lower"), (120068, 120069, "Upper"),
+  (120071, 120074, "Upper"), (120077, 120084, "Upper"),
+  (120086, 120092, "Upper"), (120094, 120119, "Lower"),
+  (120120, 120121, "Upper"), (120123, 120126, "Upper"),
+  (120128, 120132, "Upper"), (120134, 120134, "Upper"),
+  (120138, 120144, "Upper"), (120146, 120171, "Lower"),
+  (120172, 120197, "Upper"), (120198, 120223, "Lower"),
+  (120224, 120249, "Upper"), (120250, 120275, "Lower"),
+  (120276, 120301, "Upper"), (120302, 120327, "Lower"),
+  (120328, 120353, "Upper"), (120354, 120379, "Lower"),
+  (120380, 120405, "Upper"), (120406, 120431, "Lower"),
+  (120432, 120457, "Upper"), (120458, 120485, "Lower"),
+  (120488, 120512, "Upper"), (120514, 120538, "Lower"),
+  (120540, 120545, "Lower"), (120546, 120570, "Upper"),
+  (120572, 120596, "Lower"), (120598, 120603, "Lower"),
+  (120604, 120628, "Upper"), (120630, 120654, "Lower"),
+  (120656, 120661, "Lower"), (120662, 120686, "Upper"),
+  (120688, 120712, "Lower"), (120714, 120719, "Lower"),
+  (120720, 120744, "Upper"), (120746, 120770, "Lower"),
+  (120772, 120777, "Lower"), (120778, 120778, "Upper"),
+  (120779, 120779, "Lower"), (120782, 120831, "Numeric"),
+  (121344, 121398, "Extend"), (121403, 121452, "Extend"),
+  (121461, 121461, "Extend"), (121476, 121476, "Extend"),
+  (121480, 121480, "STerm"), (121499, 121503, "Extend"),
+  (121505, 121519, "Extend"), (122880, 122886, "Extend"),
+  (122888, 122904, "Extend"), (122907, 122913, "Extend"),
+  (122915, 122916, "Extend"), (122918, 122922, "Extend"),
+  (124928, 125124, "OLetter"), (125136, 125142, "Extend"),
+  (125184, 125217, "Upper"), (125218, 125251, "Lower"),
+  (125252, 125258, "Extend"), (125264, 125273, "Numeric"),
+  (126464, 126467, "OLetter"), (126469, 126495, "OLetter"),
+  (126497, 126498, "OLetter"), (126500, 126500, "OLetter"),
+  (126503, 126503, "OLetter"), (126505, 126514, "OLetter"),
+  (126516, 126519, "OLetter"), (126521, 126521, "OLetter"),
+  (126523, 126523, "OLetter"), (126530, 126530, "OLetter"),
+  (126535, 126535, "OLetter"), (126537, 126537, "OLetter"),
+  (126539, 126539, "OLetter"), (126541, 126543, "OLetter"),
+  (126545, 126546, "OLetter"), (126548, 126548, "OLetter"),
+  (126551, 126551, "OLetter"), (126553, 126553, "OLetter"),
+  (126555, 126555, "OLetter"), (126557, 126557, "OLetter"),
+  (126559, 126559, "OLetter"), (126561, 126562, "OLetter"),
+  (126564, 126564, "OLetter"), (126567, 126570, "OLetter"),
+  (126572, 126578, "OLetter"), (126580, 126583, "OLetter"),
+  (126585, 126588, "OLetter"), (126590, 126590, "OLetter"),
+  (126592, 126601, "OLetter"), (126603, 126619, "OLetter"),
+  (126625, 126627, "OLetter"), (126629, 126633, "OLetter"),
+  (126635, 126651, "OLetter"), (127280, 127305, "Upper"),
+  (127312, 127337, "Upper"), (127344, 127369, "Upper"),
+  (128630, 128632, "Close"), (131072, 173782, "OLetter"),
+  (173824, 177972, "OLetter"), (177984, 178205, "OLetter"),
+  (178208, 183969, "OLetter"), (194560, 195101, "OLetter"),
+  (917505, 917505, "Format"), (917536, 917631, "Extend"),
+  (917760, 917999, "Extend"),
+];
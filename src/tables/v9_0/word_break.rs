@@ -0,0 +1,393 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//  ucd-generate word-break /home/andrew/tmp/ucd-9.0.0/
+//
+// ucd-generate is available on crates.io.
+
+pub const WORD_BREAK: &'static [(u32, u32, &'static str)] = &[
+  (10, 10, "LF"), (11, 12, "Newline"), (13, 13, "CR"),
+  (34, 34, "Double_Quote"), (39, 39, "Single_Quote"), (44, 44, "MidNum"),
+  (46, 46, "MidNumLet"), (48, 57, "Numeric"), (58, 58, "MidLetter"),
+  (59, 59, "MidNum"), (65, 90, "ALetter"), (95, 95, "ExtendNumLet"),
+  (97, 122, "ALetter"), (133, 133, "Newline"), (170, 170, "ALetter"),
+  (173, 173, "Format"), (181, 181, "ALetter"), (183, 183, "MidLetter"),
+  (186, 186, "ALetter"), (192, 214, "ALetter"), (216, 246, "ALetter"),
+  (248, 705, "ALetter"), (710, 721, "ALetter"), (727, 727, "MidLetter"),
+  (736, 740, "ALetter"), (748, 748, "ALetter"), (750, 750, "ALetter"),
+  (768, 879, "Extend"), (880, 884, "ALetter"), (886, 887, "ALetter"),
+  (890, 893, "ALetter"), (894, 894, "MidNum"), (895, 895, "ALetter"),
+  (902, 902, "ALetter"), (903, 903, "MidLetter"), (904, 906, "ALetter"),
+  (908, 908, "ALetter"), (910, 929, "ALetter"), (931, 1013, "ALetter"),
+  (1015, 1153, "ALetter"), (1155, 1161, "Extend"), (1162, 1327, "ALetter"),
+  (1329, 1366, "ALetter"), (1369, 1369, "ALetter"), (1377, 1415, "ALetter"),
+  (1417, 1417, "MidNum"), (1425, 1469, "Extend"), (1471, 1471, "Extend"),
+  (1473, 1474, "Extend"), (1476, 1477, "Extend"), (1479, 1479, "Extend"),
+  (1488, 1514, "Hebrew_Letter"), (1520, 1522, "Hebrew_Letter"),
+  (1523, 1523, "ALetter"), (1524, 1524, "MidLetter"), (1536, 1541, "Format"),
+  (1548, 1549, "MidNum"), (1552, 1562, "Extend"), (1564, 1564, "Format"),
+  (1568, 1610, "ALetter"), (1611, 1631, "Extend"), (1632, 1641, "Numeric"),
+  (1643, 1643, "Numeric"), (1644, 1644, "MidNum"), (1646, 1647, "ALetter"),
+  (1648, 1648, "Extend"), (1649, 1747, "ALetter"), (1749, 1749, "ALetter"),
+  (1750, 1756, "Extend"), (1757, 1757, "Format"), (1759, 1764, "Extend"),
+  (1765, 1766, "ALetter"), (1767, 1768, "Extend"), (1770, 1773, "Extend"),
+  (1774, 1775, "ALetter"), (1776, 1785, "Numeric"), (1786, 1788, "ALetter"),
+  (1791, 1791, "ALetter"), (1807, 1807, "Format"), (1808, 1808, "ALetter"),
+  (1809, 1809, "Extend"), (1810, 1839, "ALetter"), (1840, 1866, "Extend"),
+  (1869, 1957, "ALetter"), (1958, 1968, "Extend"), (1969, 1969, "ALetter"),
+  (1984, 1993, "Numeric"), (1994, 2026, "ALetter"), (2027, 2035, "Extend"),
+  (2036, 2037, "ALetter"), (2040, 2040, "MidNum"), (2042, 2042, "ALetter"),
+  (2048, 2069, "ALetter"), (2070, 2073, "Extend"), (2074, 2074, "ALetter"),
+  (2075, 2083, "Extend"), (2084, 2084, "ALetter"), (2085, 2087, "Extend"),
+  (2088, 2088, "ALetter"), (2089, 2093, "Extend"), (2112, 2136, "ALetter"),
+  (2137, 2139, "Extend"), (2208, 2228, "ALetter"), (2230, 2237, "ALetter"),
+  (2260, 2273, "Extend"), (2274, 2274, "Format"), (2275, 2307, "Extend"),
+  (2308, 2361, "ALetter"), (2362, 2364, "Extend"), (2365, 2365, "ALetter"),
+  (2366, 2383, "Extend"), (2384, 2384, "ALetter"), (2385, 2391, "Extend"),
+  (2392, 2401, "ALetter"), (2402, 2403, "Extend"), (2406, 2415, "Numeric"),
+  (2417, 2432, "ALetter"), (2433, 2435, "Extend"), (2437, 2444, "ALetter"),
+  (2447, 2448, "ALetter"), (2451, 2472, "ALetter"), (2474, 2480, "ALetter"),
+  (2482, 2482, "ALetter"), (2486, 2489, "ALetter"), (2492, 2492, "Extend"),
+  (2493, 2493, "ALetter"), (2494, 2500, "Extend"), (2503, 2504, "Extend"),
+  (2507, 2509, "Extend"), (2510, 2510, "ALetter"), (2519, 2519, "Extend"),
+  (2524, 2525, "ALetter"), (2527, 2529, "ALetter"), (2530, 2531, "Extend"),
+  (2534, 2543, "Numeric"), (2544, 2545, "ALetter"), (2561, 2563, "Extend"),
+  (2565, 2570, "ALetter"), (2575, 2576, "ALetter"), (2579, 2600, "ALetter"),
+  (2602, 2608, "ALetter"), (2610, 2611, "ALetter"), (2613, 2614, "ALetter"),
+  (2616, 2617, "ALetter"), (2620, 2620, "Extend"), (2622, 2626, "Extend"),
+  (2631, 2632, "Extend"), (2635, 2637, "Extend"), (2641, 2641, "Extend"),
+  (2649, 2652, "ALetter"), (2654, 2654, "ALetter"), (2662, 2671, "Numeric"),
+  (2672, 2673, "Extend"), (2674, 2676, "ALetter"), (2677, 2677, "Extend"),
+  (2689, 2691, "Extend"), (2693, 2701, "ALetter"), (2703, 2705, "ALetter"),
+  (2707, 2728, "ALetter"), (2730, 2736, "ALetter"), (2738, 2739, "ALetter"),
+  (2741, 2745, "ALetter"), (2748, 2748, "Extend"), (2749, 2749, "ALetter"),
+  (2750, 2757, "Extend"), (2759, 2761, "Extend"), (2763, 2765, "Extend"),
+  (2768, 2768, "ALetter"), (2784, 2785, "ALetter"), (2786, 2787, "Extend"),
+  (2790, 2799, "Numeric"), (2809, 2809, "ALetter"), (2817, 2819, "Extend"),
+  (2821, 2828, "ALetter"), (2831, 2832, "ALetter"), (2835, 2856, "ALetter"),
+  (2858, 2864, "ALetter"), (2866, 2867, "ALetter"), (2869, 2873, "ALetter"),
+  (2876, 2876, "Extend"), (2877, 2877, "ALetter"), (2878, 2884, "Extend"),
+  (2887, 2888, "Extend"), (2891, 2893, "Extend"), (2902, 2903, "Extend"),
+  (2908, 2909, "ALetter"), (2911, 2913, "ALetter"), (2914, 2915, "Extend"),
+  (2918, 2927, "Numeric"), (2929, 2929, "ALetter"), (2946, 2946, "Extend"),
+  (2947, 2947, "ALetter"), (2949, 2954, "ALetter"), (2958, 2960, "ALetter"),
+  (2962, 2965, "ALetter"), (2969, 2970, "ALetter"), (2972, 2972, "ALetter"),
+  (2974, 2975, "ALetter"), (2979, 2980, "ALetter"), (2984, 2986, "ALetter"),
+  (2990, 3001, "ALetter"), (3006, 3010, "Extend"), (3014, 3016, "Extend"),
+  (3018, 3021, "Extend"), (3024, 3024, "ALetter"), (3031, 3031, "Extend"),
+  (3046, 3055, "Numeric"), (3072, 3075, "Extend"), (3077, 3084, "ALetter"),
+  (3086, 3088, "ALetter"), (3090, 3112, "ALetter"), (3114, 3129, "ALetter"),
+  (3133, 3133, "ALetter"), (3134, 3140, "Extend"), (3142, 3144, "Extend"),
+  (3146, 3149, "Extend"), (3157, 3158, "Extend"), (3160, 3162, "ALetter"),
+  (3168, 3169, "ALetter"), (3170, 3171, "Extend"), (3174, 3183, "Numeric"),
+  (3200, 3200, "ALetter"), (3201, 3203, "Extend"), (3205, 3212, "ALetter"),
+  (3214, 3216, "ALetter"), (3218, 3240, "ALetter"), (3242, 3251, "ALetter"),
+  (3253, 3257, "ALetter"), (3260, 3260, "Extend"), (3261, 3261, "ALetter"),
+  (3262, 3268, "Extend"), (3270, 3272, "Extend"), (3274, 3277, "Extend"),
+  (3285, 3286, "Extend"), (3294, 3294, "ALetter"), (3296, 3297, "ALetter"),
+  (3298, 3299, "Extend"), (3302, 3311, "Numeric"), (3313, 3314, "ALetter"),
+  (3329, 3331, "Extend"), (3333, 3340, "ALetter"), (3342, 3344, "ALetter"),
+  (3346, 3386, "ALetter"), (3389, 3389, "ALetter"), (3390, 3396, "Extend"),
+  (3398, 3400, "Extend"), (3402, 3405, "Extend"), (3406, 3406, "ALetter"),
+  (3412, 3414, "ALetter"), (3415, 3415, "Extend"), (3423, 3425, "ALetter"),
+  (3426, 3427, "Extend"), (3430, 3439, "Numeric"), (3450, 3455, "ALetter"),
+  (3458, 3459, "Extend"), (3461, 3478, "ALetter"), (3482, 3505, "ALetter"),
+  (3507, 3515, "ALetter"), (3517, 3517, "ALetter"), (3520, 3526, "ALetter"),
+  (3530, 3530, "Extend"), (3535, 3540, "Extend"), (3542, 3542, "Extend"),
+  (3544, 3551, "Extend"), (3558, 3567, "Numeric"), (3570, 3571, "Extend"),
+  (3633, 3633, "Extend"), (3636, 3642, "Extend"), (3655, 3662, "Extend"),
+  (3664, 3673, "Numeric"), (3761, 3761, "Extend"), (3764, 3769, "Extend"),
+  (3771, 3772, "Extend"), (3784, 3789, "Extend"), (3792, 3801, "Numeric"),
+  (3840, 3840, "ALetter"), (3864, 3865, "Extend"), (3872, 3881, "Numeric"),
+  (3893, 3893, "Extend"), (3895, 3895, "Extend"), (3897, 3897, "Extend"),
+  (3902, 3903, "Extend"), (3904, 3911, "ALetter"), (3913, 3948, "ALetter"),
+  (3953, 3972, "Extend"), (3974, 3975, "Extend"), (3976, 3980, "ALetter"),
+  (3981, 3991, "Extend"), (3993, 4028, "Extend"), (4038, 4038, "Extend"),
+  (4139, 4158, "Extend"), (4160, 4169, "Numeric"), (4182, 4185, "Extend"),
+  (4190, 4192, "Extend"), (4194, 4196, "Extend"), (4199, 4205, "Extend"),
+  (4209, 4212, "Extend"), (4226, 4237, "Extend"), (4239, 4239, "Extend"),
+  (4240, 4249, "Numeric"), (4250, 4253, "Extend"), (4256, 4293, "ALetter"),
+  (4295, 4295, "ALetter"), (4301, 4301, "ALetter"), (4304, 4346, "ALetter"),
+  (4348, 4680, "ALetter"), (4682, 4685, "ALetter"), (4688, 4694, "ALetter"),
+  (4696, 4696, "ALetter"), (4698, 4701, "ALetter"), (4704, 4744, "ALetter"),
+  (4746, 4749, "ALetter"), (4752, 4784, "ALetter"), (4786, 4789, "ALetter"),
+  (4792, 4798, "ALetter"), (4800, 4800, "ALetter"), (4802, 4805, "ALetter"),
+  (4808, 4822, "ALetter"), (4824, 4880, "ALetter"), (4882, 4885, "ALetter"),
+  (4888, 4954, "ALetter"), (4957, 4959, "Extend"), (4992, 5007, "ALetter"),
+  (5024, 5109, "ALetter"), (5112, 5117, "ALetter"), (5121, 5740, "ALetter"),
+  (5743, 5759, "ALetter"), (5761, 5786, "ALetter"), (5792, 5866, "ALetter"),
+  (5870, 5880, "ALetter"), (5888, 5900, "ALetter"), (5902, 5905, "ALetter"),
+  (5906, 5908, "Extend"), (5920, 5937, "ALetter"), (5938, 5940, "Extend"),
+  (5952, 5969, "ALetter"), (5970, 5971, "Extend"), (5984, 5996, "ALetter"),
+  (5998, 6000, "ALetter"), (6002, 6003, "Extend"), (6068, 6099, "Extend"),
+  (6109, 6109, "Extend"), (6112, 6121, "Numeric"), (6155, 6157, "Extend"),
+  (6158, 6158, "Format"), (6160, 6169, "Numeric"), (6176, 6263, "ALetter"),
+  (6272, 6276, "ALetter"), (6277, 6278, "Extend"), (6279, 6312, "ALetter"),
+  (6313, 6313, "Extend"), (6314, 6314, "ALetter"), (6320, 6389, "ALetter"),
+  (6400, 6430, "ALetter"), (6432, 6443, "Extend"), (6448, 6459, "Extend"),
+  (6470, 6479, "Numeric"), (6608, 6617, "Numeric"), (6656, 6678, "ALetter"),
+  (6679, 6683, "Extend"), (6741, 6750, "Extend"), (6752, 6780, "Extend"),
+  (6783, 6783, "Extend"), (6784, 6793, "Numeric"), (6800, 6809, "Numeric"),
+  (6832, 6846, "Extend"), (6912, 6916, "Extend"), (6917, 6963, "ALetter"),
+  (6964, 6980, "Extend"), (6981, 6987, "ALetter"), (6992, 7001, "Numeric"),
+  (7019, 7027, "Extend"), (7040, 7042, "Extend"), (7043, 7072, "ALetter"),
+  (7073, 7085, "Extend"), (7086, 7087, "ALetter"), (7088, 7097, "Numeric"),
+  (7098, 7141, "ALetter"), (7142, 7155, "Extend"), (7168, 7203, "ALetter"),
+  (7204, 7223, "Extend"), (7232, 7241, "Numeric"), (7245, 7247, "ALetter"),
+  (7248, 7257, "Numeric"), (7258, 7293, "ALetter"), (7296, 7304, "ALetter"),
+  (7376, 7378, "Extend"), (7380, 7400, "Extend"), (7401, 7404, "ALetter"),
+  (7405, 7405, "Extend"), (7406, 7409, "ALetter"), (7410, 7412, "Extend"),
+  (7413, 7414, "ALetter"), (7416, 7417, "Extend"), (7424, 7615, "ALetter"),
+  (7616, 7669, "Extend"), (7675, 7679, "Extend"), (7680, 7957, "ALetter"),
+  (7960, 7965, "ALetter"), (7968, 8005, "ALetter"), (8008, 8013, "ALetter"),
+  (8016, 8023, "ALetter"), (8025, 8025, "ALetter"), (8027, 8027, "ALetter"),
+  (8029, 8029, "ALetter"), (8031, 8061, "ALetter"), (8064, 8116, "ALetter"),
+  (8118, 8124, "ALetter"), (8126, 8126, "ALetter"), (8130, 8132, "ALetter"),
+  (8134, 8140, "ALetter"), (8144, 8147, "ALetter"), (8150, 8155, "ALetter"),
+  (8160, 8172, "ALetter"), (8178, 8180, "ALetter"), (8182, 8188, "ALetter"),
+  (8204, 8204, "Extend"), (8205, 8205, "ZWJ"), (8206, 8207, "Format"),
+  (8216, 8217, "MidNumLet"), (8228, 8228, "MidNumLet"),
+  (8231, 8231, "MidLetter"), (8232, 8233, "Newline"), (8234, 8238, "Format"),
+  (8239, 8239, "ExtendNumLet"), (8255, 8256, "ExtendNumLet"),
+  (8260, 8260, "MidNum"), (8276, 8276, "ExtendNumLet"), (8288, 8292, "Format"),
+  (8294, 8303, "Format"), (8305, 8305, "ALetter"), (8319, 8319, "ALetter"),
+  (8336, 8348, "ALetter"), (8400, 8432, "Extend"), (8450, 8450, "ALetter"),
+  (8455, 8455, "ALetter"), (8458, 8467, "ALetter"), (8469, 8469, "ALetter"),
+  (8473, 8477, "ALetter"), (8484, 8484, "ALetter"), (8486, 8486, "ALetter"),
+  (8488, 8488, "ALetter"), (8490, 8493, "ALetter"), (8495, 8505, "ALetter"),
+  (8508, 8511, "ALetter"), (8517, 8521, "ALetter"), (8526, 8526, "ALetter"),
+  (8544, 8584, "ALetter"), (9398, 9449, "ALetter"), (9757, 9757, "E_Base"),
+  (9977, 9977, "E_Base"), (9994, 9997, "E_Base"),
+  (10084, 10084, "Glue_After_Zwj"), (11264, 11310, "ALetter"),
+  (11312, 11358, "ALetter"), (11360, 11492, "ALetter"),
+  (11499, 11502, "ALetter"), (11503, 11505, "Extend"),
+  (11506, 11507, "ALetter"), (11520, 11557, "ALetter"),
+  (11559, 11559, "ALetter"), (11565, 11565, "ALetter"),
+  (11568, 11623, "ALetter"), (11631, 11631, "ALetter"),
+  (11647, 11647, "Extend"), (11648, 11670, "ALetter"),
+  (11680, 11686, "ALetter"), (11688, 11694, "ALetter"),
+  (11696, 11702, "ALetter"), (11704, 11710, "ALetter"),
+  (11712, 11718, "ALetter"), (11720, 11726, "ALetter"),
+  (11728, 11734, "ALetter"), (11736, 11742, "ALetter"),
+  (11744, 11775, "Extend"), (11823, 11823, "ALetter"),
+  (12293, 12293, "ALetter"), (12330, 12335, "Extend"),
+  (12337, 12341, "Katakana"), (12347, 12348, "ALetter"),
+  (12441, 12442, "Extend"), (12443, 12444, "Katakana"),
+  (12448, 12538, "Katakana"), (12540, 12543, "Katakana"),
+  (12549, 12589, "ALetter"), (12593, 12686, "ALetter"),
+  (12704, 12730, "ALetter"), (12784, 12799, "Katakana"),
+  (13008, 13054, "Katakana"), (13056, 13143, "Katakana"),
+  (40960, 42124, "ALetter"), (42192, 42237, "ALetter"),
+  (42240, 42508, "ALetter"), (42512, 42527, "ALetter"),
+  (42528, 42537, "Numeric"), (42538, 42539, "ALetter"),
+  (42560, 42606, "ALetter"), (42607, 42610, "Extend"),
+  (42612, 42621, "Extend"), (42623, 42653, "ALetter"),
+  (42654, 42655, "Extend"), (42656, 42735, "ALetter"),
+  (42736, 42737, "Extend"), (42775, 42783, "ALetter"),
+  (42786, 42888, "ALetter"), (42891, 42926, "ALetter"),
+  (42928, 42935, "ALetter"), (42999, 43009, "ALetter"),
+  (43010, 43010, "Extend"), (43011, 43013, "ALetter"),
+  (43014, 43014, "Extend"), (43015, 43018, "ALetter"),
+  (43019, 43019, "Extend"), (43020, 43042, "ALetter"),
+  (43043, 43047, "Extend"), (43072, 43123, "ALetter"),
+  (43136, 43137, "Extend"), (43138, 43187, "ALetter"),
+  (43188, 43205, "Extend"), (43216, 43225, "Numeric"),
+  (43232, 43249, "Extend"), (43250, 43255, "ALetter"),
+  (43259, 43259, "ALetter"), (43261, 43261, "ALetter"),
+  (43264, 43273, "Numeric"), (43274, 43301, "ALetter"),
+  (43302, 43309, "Extend"), (43312, 43334, "ALetter"),
+  (43335, 43347, "Extend"), (43360, 43388, "ALetter"),
+  (43392, 43395, "Extend"), (43396, 43442, "ALetter"),
+  (43443, 43456, "Extend"), (43471, 43471, "ALetter"),
+  (43472, 43481, "Numeric"), (43493, 43493, "Extend"),
+  (43504, 43513, "Numeric"), (43520, 43560, "ALetter"),
+  (43561, 43574, "Extend"), (43584, 43586, "ALetter"),
+  (43587, 43587, "Extend"), (43588, 43595, "ALetter"),
+  (43596, 43597, "Extend"), (43600, 43609, "Numeric"),
+  (43643, 43645, "Extend"), (43696, 43696, "Extend"), (43698, 43700, "Extend"),
+  (43703, 43704, "Extend"), (43710, 43711, "Extend"), (43713, 43713, "Extend"),
+  (43744, 43754, "ALetter"), (43755, 43759, "Extend"),
+  (43762, 43764, "ALetter"), (43765, 43766, "Extend"),
+  (43777, 43782, "ALetter"), (43785, 43790, "ALetter"),
+  (43793, 43798, "ALetter"), (43808, 43814, "ALetter"),
+  (43816, 43822, "ALetter"), (43824, 43866, "ALetter"),
+  (43868, 43877, "ALetter"), (43888, 44002, "ALetter"),
+  (44003, 44010, "Extend"), (44012, 44013, "Extend"),
+  (44016, 44025, "Numeric"), (44032, 55203, "ALetter"),
+  (55216, 55238, "ALetter"), (55243, 55291, "ALetter"),
+  (64256, 64262, "ALetter"), (64275, 64279, "ALetter"),
+  (64285, 64285, "Hebrew_Letter"), (64286, 64286, "Extend"),
+  (64287, 64296, "Hebrew_Letter"), (64298, 64310, "Hebrew_Letter"),
+  (64312, 64316, "Hebrew_Letter"), (64318, 64318, "Hebrew_Letter"),
+  (64320, 64321, "Hebrew_Letter"), (64323, 64324, "Hebrew_Letter"),
+  (64326, 64335, "Hebrew_Letter"), (64336, 64433, "ALetter"),
+  (64467, 64829, "ALetter"), (64848, 64911, "ALetter"),
+  (64914, 64967, "ALetter"), (65008, 65019, "ALetter"),
+  (65024, 65039, "Extend"), (65040, 65040, "MidNum"),
+  (65043, 65043, "MidLetter"), (65044, 65044, "MidNum"),
+  (65056, 65071, "Extend"), (65075, 65076, "ExtendNumLet"),
+  (65101, 65103, "ExtendNumLet"), (65104, 65104, "MidNum"),
+  (65106, 65106, "MidNumLet"), (65108, 65108, "MidNum"),
+  (65109, 65109, "MidLetter"), (65136, 65140, "ALetter"),
+  (65142, 65276, "ALetter"), (65279, 65279, "Format"),
+  (65287, 65287, "MidNumLet"), (65292, 65292, "MidNum"),
+  (65294, 65294, "MidNumLet"), (65306, 65306, "MidLetter"),
+  (65307, 65307, "MidNum"), (65313, 65338, "ALetter"),
+  (65343, 65343, "ExtendNumLet"), (65345, 65370, "ALetter"),
+  (65382, 65437, "Katakana"), (65438, 65439, "Extend"),
+  (65440, 65470, "ALetter"), (65474, 65479, "ALetter"),
+  (65482, 65487, "ALetter"), (65490, 65495, "ALetter"),
+  (65498, 65500, "ALetter"), (65529, 65531, "Format"),
+  (65536, 65547, "ALetter"), (65549, 65574, "ALetter"),
+  (65576, 65594, "ALetter"), (65596, 65597, "ALetter"),
+  (65599, 65613, "ALetter"), (65616, 65629, "ALetter"),
+  (65664, 65786, "ALetter"), (65856, 65908, "ALetter"),
+  (66045, 66045, "Extend"), (66176, 66204, "ALetter"),
+  (66208, 66256, "ALetter"), (66272, 66272, "Extend"),
+  (66304, 66335, "ALetter"), (66352, 66378, "ALetter"),
+  (66384, 66421, "ALetter"), (66422, 66426, "Extend"),
+  (66432, 66461, "ALetter"), (66464, 66499, "ALetter"),
+  (66504, 66511, "ALetter"), (66513, 66517, "ALetter"),
+  (66560, 66717, "ALetter"), (66720, 66729, "Numeric"),
+  (66736, 66771, "ALetter"), (66776, 66811, "ALetter"),
+  (66816, 66855, "ALetter"), (66864, 66915, "ALetter"),
+  (67072, 67382, "ALetter"), (67392, 67413, "ALetter"),
+  (67424, 67431, "ALetter"), (67584, 67589, "ALetter"),
+  (67592, 67592, "ALetter"), (67594, 67637, "ALetter"),
+  (67639, 67640, "ALetter"), (67644, 67644, "ALetter"),
+  (67647, 67669, "ALetter"), (67680, 67702, "ALetter"),
+  (67712, 67742, "ALetter"), (67808, 67826, "ALetter"),
+  (67828, 67829, "ALetter"), (67840, 67861, "ALetter"),
+  (67872, 67897, "ALetter"), (67968, 68023, "ALetter"),
+  (68030, 68031, "ALetter"), (68096, 68096, "ALetter"),
+  (68097, 68099, "Extend"), (68101, 68102, "Extend"), (68108, 68111, "Extend"),
+  (68112, 68115, "ALetter"), (68117, 68119, "ALetter"),
+  (68121, 68147, "ALetter"), (68152, 68154, "Extend"),
+  (68159, 68159, "Extend"), (68192, 68220, "ALetter"),
+  (68224, 68252, "ALetter"), (68288, 68295, "ALetter"),
+  (68297, 68324, "ALetter"), (68325, 68326, "Extend"),
+  (68352, 68405, "ALetter"), (68416, 68437, "ALetter"),
+  (68448, 68466, "ALetter"), (68480, 68497, "ALetter"),
+  (68608, 68680, "ALetter"), (68736, 68786, "ALetter"),
+  (68800, 68850, "ALetter"), (69632, 69634, "Extend"),
+  (69635, 69687, "ALetter"), (69688, 69702, "Extend"),
+  (69734, 69743, "Numeric"), (69759, 69762, "Extend"),
+  (69763, 69807, "ALetter"), (69808, 69818, "Extend"),
+  (69821, 69821, "Format"), (69840, 69864, "ALetter"),
+  (69872, 69881, "Numeric"), (69888, 69890, "Extend"),
+  (69891, 69926, "ALetter"), (69927, 69940, "Extend"),
+  (69942, 69951, "Numeric"), (69968, 70002, "ALetter"),
+  (70003, 70003, "Extend"), (70006, 70006, "ALetter"),
+  (70016, 70018, "Extend"), (70019, 70066, "ALetter"),
+  (70067, 70080, "Extend"), (70081, 70084, "ALetter"),
+  (70090, 70092, "Extend"), (70096, 70105, "Numeric"),
+  (70106, 70106, "ALetter"), (70108, 70108, "ALetter"),
+  (70144, 70161, "ALetter"), (70163, 70187, "ALetter"),
+  (70188, 70199, "Extend"), (70206, 70206, "Extend"),
+  (70272, 70278, "ALetter"), (70280, 70280, "ALetter"),
+  (70282, 70285, "ALetter"), (70287, 70301, "ALetter"),
+  (70303, 70312, "ALetter"), (70320, 70366, "ALetter"),
+  (70367, 70378, "Extend"), (70384, 70393, "Numeric"),
+  (70400, 70403, "Extend"), (70405, 70412, "ALetter"),
+  (70415, 70416, "ALetter"), (70419, 70440, "ALetter"),
+  (70442, 70448, "ALetter"), (70450, 70451, "ALetter"),
+  (70453, 70457, "ALetter"), (70460, 70460, "Extend"),
+  (70461, 70461, "ALetter"), (70462, 70468, "Extend"),
+  (70471, 70472, "Extend"), (70475, 70477, "Extend"),
+  (70480, 70480, "ALetter"), (70487, 70487, "Extend"),
+  (70493, 70497, "ALetter"), (70498, 70499, "Extend"),
+  (70502, 70508, "Extend"), (70512, 70516, "Extend"),
+  (70656, 70708, "ALetter"), (70709, 70726, "Extend"),
+  (70727, 70730, "ALetter"), (70736, 70745, "Numeric"),
+  (70784, 70831, "ALetter"), (70832, 70851, "Extend"),
+  (70852, 70853, "ALetter"), (70855, 70855, "ALetter"),
+  (70864, 70873, "Numeric"), (71040, 71086, "ALetter"),
+  (71087, 71093, "Extend"), (71096, 71104, "Extend"),
+  (71128, 71131, "ALetter"), (71132, 71133, "Extend"),
+  (71168, 71215, "ALetter"), (71216, 71232, "Extend"),
+  (71236, 71236, "ALetter"), (71248, 71257, "Numeric"),
+  (71296, 71338, "ALetter"), (71339, 71351, "Extend"),
+  (71360, 71369, "Numeric"), (71453, 71467, "Extend"),
+  (71472, 71481, "Numeric"), (71840, 71903, "ALetter"),
+  (71904, 71913, "Numeric"), (71935, 71935, "ALetter"),
+  (72384, 72440, "ALetter"), (72704, 72712, "ALetter"),
+  (72714, 72750, "ALetter"), (72751, 72758, "Extend"),
+  (72760, 72767, "Extend"), (72768, 72768, "ALetter"),
+  (72784, 72793, "Numeric"), (72818, 72847, "ALetter"),
+  (72850, 72871, "Extend"), (72873, 72886, "Extend"),
+  (73728, 74649, "ALetter"), (74752, 74862, "ALetter"),
+  (74880, 75075, "ALetter"), (77824, 78894, "ALetter"),
+  (82944, 83526, "ALetter"), (92160, 92728, "ALetter"),
+  (92736, 92766, "ALetter"), (92768, 92777, "Numeric"),
+  (92880, 92909, "ALetter"), (92912, 92916, "Extend"),
+  (92928, 92975, "ALetter"), (92976, 92982, "Extend"),
+  (92992, 92995, "ALetter"), (93008, 93017, "Numeric"),
+  (93027, 93047, "ALetter"), (93053, 93071, "ALetter"),
+  (93952, 94020, "ALetter"), (94032, 94032, "ALetter"),
+  (94033, 94078, "Extend"), (94095, 94098, "Extend"),
+  (94099, 94111, "ALetter"), (94176, 94176, "ALetter"),
+  (110592, 110592, "Katakana"), (113664, 113770, "ALetter"),
+  (113776, 113788, "ALetter"), (113792, 113800, "ALetter"),
+  (113808, 113817, "ALetter"), (113821, 113822, "Extend"),
+  (113824, 113827, "Format"), (119141, 119145, "Extend"),
+  (119149, 119154, "Extend"), (119155, 119162, "Format"),
+  (119163, 119170, "Extend"), (119173, 119179, "Extend"),
+  (119210, 119213, "Extend"), (119362, 119364, "Extend"),
+  (119808, 119892, "ALetter"), (119894, 119964, "ALetter"),
+  (119966, 119967, "ALetter"), (119970, 119970, "ALetter"),
+  (119973, 119974, "ALetter"), (119977, 119980, "ALetter"),
+  (119982, 119993, "ALetter"), (119995, 119995, "ALetter"),
+  (119997, 120003, "ALetter"), (120005, 120069, "ALetter"),
+  (120071, 120074, "ALetter"), (120077, 120084, "ALetter"),
+  (120086, 120092, "ALetter"), (120094, 120121, "ALetter"),
+  (120123, 120126, "ALetter"), (120128, 120132, "ALetter"),
+  (120134, 120134, "ALetter"), (120138, 120144, "ALetter"),
+  (120146, 120485, "ALetter"), (120488, 120512, "ALetter"),
+  (120514, 120538, "ALetter"), (120540, 120570, "ALetter"),
+  (120572, 120596, "ALetter"), (120598, 120628, "ALetter"),
+  (120630, 120654, "ALetter"), (120656, 120686, "ALetter"),
+  (120688, 120712, "ALetter"), (120714, 120744, "ALetter"),
+  (120746, 120770, "ALetter"), (120772, 120779, "ALetter"),
+  (120782, 120831, "Numeric"), (121344, 121398, "Extend"),
+  (121403, 121452, "Extend"), (121461, 121461, "Extend"),
+  (121476, 121476, "Extend"), (121499, 121503, "Extend"),
+  (121505, 121519, "Extend"), (122880, 122886, "Extend"),
+  (122888, 122904, "Extend"), (122907, 122913, "Extend"),
+  (122915, 122916, "Extend"), (122918, 122922, "Extend"),
+  (124928, 125124, "ALetter"), (125136, 125142, "Extend"),
+  (125184, 125251, "ALetter"), (125252, 125258, "Extend"),
+  (125264, 125273, "Numeric"), (126464, 126467, "ALetter"),
+  (126469, 126495, "ALetter"), (126497, 126498, "ALetter"),
+  (126500, 126500, "ALetter"), (126503, 126503, "ALetter"),
+  (126505, 126514, "ALetter"), (126516, 126519, "ALetter"),
+  (126521, 126521, "ALetter"), (126523, 126523, "ALetter"),
+  (126530, 126530, "ALetter"), (126535, 126535, "ALetter"),
+  (126537, 126537, "ALetter"), (126539, 126539, "ALetter"),
+  (126541, 126543, "ALetter"), (126545, 126546, "ALetter"),
+  (126548, 126548, "ALetter"), (126551, 126551, "ALetter"),
+  (126553, 126553, "ALetter"), (126555, 126555, "ALetter"),
+  (126557, 126557, "ALetter"), (126559, 126559, "ALetter"),
+  (126561, 126562, "ALetter"), (126564, 126564, "ALetter"),
+  (126567, 126570, "ALetter"), (126572, 126578, "ALetter"),
+  (126580, 126583, "ALetter"), (126585, 126588, "ALetter"),
+  (126590, 126590, "ALetter"), (126592, 126601, "ALetter"),
+  (126603, 126619, "ALetter"), (126625, 126627, "ALetter"),
+  (126629, 126633, "ALetter"), (126635, 126651, "ALetter"),
+  (127280, 127305, "ALetter"), (127312, 127337, "ALetter"),
+  (127344, 127369, "ALetter"), (127462, 127487, "Regional_Indicator"),
+  (127877, 127877, "E_Base"), (127939, 127940, "E_Base"),
+  (127946, 127947, "E_Base"), (127995, 127999, "E_Modifier"),
+  (128066, 128067, "E_Base"), (128070, 128080, "E_Base"),
+  (128102, 128105, "E_Base_GAZ"), (128110, 128110, "E_Base"),
+  (128112, 128120, "E_Base"), (128124, 128124, "E_Base"),
+  (128129, 128131, "E_Base"), (128133, 128135, "E_Base"),
+  (128139, 128139, "Glue_After_Zwj"), (128170, 128170, "E_Base"),
+  (128373, 128373, "E_Base"), (128378, 128378, "E_Base"),
+  (128400, 128400, "E_Base"), (128405, 128406, "E_Base"),
+  (128488, 128488, "Glue_After_Zwj"), (128581, 128583, "E_Base"),
+  (128587, 128591, "E_Base"), (128675, 128675, "E_Base"),
+  (128692, 128694, "E_Base"), (128704, 128704, "E_Base"),
+  (129304, 129310, "E_Base"), (129318, 129318, "E_Base"),
+  (129328, 129328, "E_Base"), (129331, 129337, "E_Base"),
+  (129340, 129342, "E_Base"), (917505, 917505, "Format"),
+  (917536, 917631, "Extend"), (917760, 917999, "Extend"),
+];
@@ -0,0 +1,152 @@
+use std::io;
+
+use clap;
+
+use app::arg_to_codepoint;
+use codepoint::Codepoint;
+use display::{ColumnWriter, short_codepoint};
+use error::Result;
+use tables::{require, ucd};
+
+pub fn command(args: &clap::ArgMatches) -> Result<()> {
+    let cp = arg_to_codepoint("codepoint", args.value_of_os("codepoint"))?;
+    let all =
+        require(all_variation_sequences(), "variation sequence data")?;
+    // When given a variation selector, show every sequence that uses it.
+    let sequences: Vec<(Codepoint, Codepoint, &str)> = all
+        .into_iter()
+        .filter(|&(base, selector, _)| {
+            if is_variation_selector(cp) { selector == cp } else { base == cp }
+        })
+        .collect();
+    if sequences.is_empty() {
+        return err!("U+{} has no variation sequences", cp);
+    }
+
+    let pictures = args.is_present("pictures");
+    let mut wtr = ColumnWriter::new(io::stdout());
+    wtr.write_row(vec![
+        "sequence".to_string(),
+        "character".to_string(),
+        "selector".to_string(),
+        "description".to_string(),
+    ]);
+    for (base, selector, description) in sequences {
+        let text: String =
+            [base, selector].iter().filter_map(|cp| cp.scalar()).collect();
+        wtr.write_row(vec![
+            format!("U+{:04X} U+{:04X}", base.value(), selector.value()),
+            text,
+            short_codepoint(selector, pictures),
+            description.to_string(),
+        ]);
+    }
+    wtr.flush()?;
+    Ok(())
+}
+
+/// Returns true if the given codepoint is one of the variation selectors
+/// U+FE00..U+FE0F or U+E0100..U+E01EF.
+pub fn is_variation_selector(cp: Codepoint) -> bool {
+    let n = cp.value();
+    (0xFE00 <= n && n <= 0xFE0F) || (0xE0100 <= n && n <= 0xE01EF)
+}
+
+/// Return the variation selectors that may follow the given codepoint,
+/// along with a description of each variation sequence.
+///
+/// Sequences come from StandardizedVariants.txt, which includes the CJK
+/// compatibility ideograph variants and variants of math symbols and of
+/// Myanmar and Phags-pa letters, and from emoji-variation-sequences.txt,
+/// which selects between text and emoji style. If the selected version of
+/// Unicode has no variation sequence data, then `None` is returned.
+pub fn variation_sequences(
+    cp: Codepoint,
+) -> Option<Vec<(Codepoint, &'static str)>> {
+    all_variation_sequences().map(|all| {
+        all.into_iter()
+            .filter(|&(base, _, _)| base == cp)
+            .map(|(_, selector, description)| (selector, description))
+            .collect()
+    })
+}
+
+/// Return every variation sequence, in ascending order of base codepoint
+/// and then selector.
+fn all_variation_sequences(
+) -> Option<Vec<(Codepoint, Codepoint, &'static str)>> {
+    let ucd = ucd();
+    let (standardized, emoji) =
+        match (ucd.standardized_variants, ucd.emoji_variation_sequences) {
+            (Some(standardized), Some(emoji)) => (standardized, emoji),
+            _ => return None,
+        };
+    let mut sequences: Vec<(Codepoint, Codepoint, &'static str)> =
+        standardized
+            .iter()
+            .chain(emoji)
+            .map(|&(base, selector, description)| {
+                (
+                    Codepoint::from_u32(base).unwrap(),
+                    Codepoint::from_u32(selector).unwrap(),
+                    description,
+                )
+            })
+            .collect();
+    sequences.sort();
+    Some(sequences)
+}
+
+#[cfg(test)]
+mod tests {
+    use codepoint::Codepoint;
+    use tables::select_unicode_version;
+
+    use super::{is_variation_selector, variation_sequences};
+
+    fn cp(n: u32) -> Codepoint {
+        Codepoint::from_u32(n).unwrap()
+    }
+
+    fn sequences(cp: Codepoint) -> Vec<(Codepoint, &'static str)> {
+        variation_sequences(cp).unwrap()
+    }
+
+    #[test]
+    fn variation_selectors() {
+        assert!(is_variation_selector(cp(0xFE00)));
+        assert!(is_variation_selector(cp(0xFE0F)));
+        assert!(is_variation_selector(cp(0xE0100)));
+        assert!(is_variation_selector(cp(0xE01EF)));
+        assert!(!is_variation_selector(cp(0xFDFF)));
+        assert!(!is_variation_selector(cp(0xFE10)));
+        assert!(!is_variation_selector(cp(0xE01F0)));
+    }
+
+    #[test]
+    fn standardized_variants() {
+        let vs1 = cp(0xFE00);
+        assert_eq!(vec![(vs1, "with serifs")], sequences(cp(0x2229)));
+        assert_eq!(vec![(vs1, "dotted form")], sequences(cp(0x1000)));
+        assert_eq!(
+            vec![(vs1, "phags-pa letter reversed shaping small a")],
+            sequences(cp(0xA856)));
+        assert_eq!(
+            vec![(vs1, "CJK COMPATIBILITY IDEOGRAPH-F900")],
+            sequences(cp(0x8C48)));
+        assert!(sequences(cp(0x61)).is_empty());
+    }
+
+    #[test]
+    fn emoji_variation_sequences() {
+        assert_eq!(
+            vec![(cp(0xFE0E), "text style"), (cp(0xFE0F), "emoji style")],
+            sequences(cp(0x2764)));
+    }
+
+    #[test]
+    fn unavailable_in_unicode_9() {
+        select_unicode_version("9.0");
+        assert_eq!(None, variation_sequences(cp(0x2764)));
+    }
+}
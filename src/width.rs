@@ -0,0 +1,222 @@
+use std::io;
+
+use clap;
+
+use app::arg_to_str;
+use codepoint::Codepoint;
+use display::{ColumnWriter, cell_char};
+use error::Result;
+use graphemes::grapheme_clusters;
+use tables::{in_ranges, range_value, ucd};
+
+pub fn command(args: &clap::ArgMatches) -> Result<()> {
+    let text = arg_to_str("text", args.value_of_os("text"))?;
+    let ambiguous = match args.value_of("ambiguous").unwrap_or("narrow") {
+        "wide" => Ambiguous::Wide,
+        _ => Ambiguous::Narrow,
+    };
+
+    let pictures = args.is_present("pictures");
+    let mut wtr = ColumnWriter::new(io::stdout());
+    wtr.write_row(vec![
+        "codepoint".to_string(),
+        "character".to_string(),
+        "eaw".to_string(),
+        "category".to_string(),
+        "width".to_string(),
+        "reason".to_string(),
+    ]);
+    let mut total = 0;
+    for (cp, width, reason) in codepoint_widths(&text, ambiguous) {
+        total += width;
+        wtr.write_row(vec![
+            format!("U+{}", cp),
+            cell_char(cp, pictures),
+            east_asian_width(cp).to_string(),
+            general_category(cp).to_string(),
+            width.to_string(),
+            reason.to_string(),
+        ]);
+    }
+    wtr.flush()?;
+    println!("total width: {}", total);
+    Ok(())
+}
+
+/// How to treat codepoints whose `East_Asian_Width` is ambiguous.
+///
+/// Ambiguous codepoints are narrow in most contexts, but are wide in
+/// East Asian legacy encodings and in terminals configured to match them.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Ambiguous {
+    Narrow,
+    Wide,
+}
+
+/// Return the `East_Asian_Width` property value of the given codepoint,
+/// using the abbreviated value names from EastAsianWidth.txt.
+pub fn east_asian_width(cp: Codepoint) -> &'static str {
+    range_value(ucd().east_asian_width, cp.value()).unwrap_or("N")
+}
+
+/// Return the number of terminal columns the given text occupies.
+pub fn text_width(text: &str, ambiguous: Ambiguous) -> usize {
+    codepoint_widths(text, ambiguous)
+        .into_iter()
+        .map(|(_, width, _)| width)
+        .sum()
+}
+
+/// Return the number of terminal columns taken by every codepoint in the
+/// given text, along with a short description of why.
+///
+/// Widths are computed one grapheme cluster at a time. A cluster that is
+/// displayed as a single emoji, such as a flag, an emoji modifier sequence,
+/// an emoji ZWJ sequence or a codepoint followed by U+FE0F, takes two
+/// columns. Its first codepoint is given the width of the whole cluster and
+/// the rest are given no columns. Every other codepoint is given the width
+/// returned by `codepoint_width`.
+pub fn codepoint_widths(
+    text: &str,
+    ambiguous: Ambiguous,
+) -> Vec<(Codepoint, usize, &'static str)> {
+    let mut widths = vec![];
+    for cluster in grapheme_clusters(text) {
+        let cps: Vec<Codepoint> =
+            cluster.chars().map(Codepoint::from).collect();
+        match emoji_sequence_width(&cps) {
+            None => {
+                for &cp in &cps {
+                    let (width, reason) = codepoint_width(cp, ambiguous);
+                    widths.push((cp, width, reason));
+                }
+            }
+            Some((width, reason)) => {
+                widths.push((cps[0], width, reason));
+                for &cp in &cps[1..] {
+                    widths.push((cp, 0, "part of sequence"));
+                }
+            }
+        }
+    }
+    widths
+}
+
+/// Return the width of the given grapheme cluster if it is an emoji
+/// sequence whose presentation decides its width, along with the kind of
+/// sequence.
+fn emoji_sequence_width(cps: &[Codepoint]) -> Option<(usize, &'static str)> {
+    if cps.len() < 2 {
+        return None;
+    }
+    let ucd = ucd();
+    let has = |table: Option<&[(u32, u32)]>, cp: Codepoint| {
+        in_ranges(table.unwrap_or(&[]), cp.value())
+    };
+    let is_pictographic = |cp: Codepoint| {
+        has(ucd.extended_pictographic, cp) || has(ucd.emoji, cp)
+    };
+    let is_regional_indicator = |cp: &Codepoint| {
+        0x1F1E6 <= cp.value() && cp.value() <= 0x1F1FF
+    };
+    let zwj = cps
+        .windows(2)
+        .any(|w| w[0].value() == 0x200D && is_pictographic(w[1]));
+    if zwj && is_pictographic(cps[0]) {
+        return Some((2, "emoji ZWJ sequence"));
+    }
+    if cps.len() == 2 && cps.iter().all(is_regional_indicator) {
+        return Some((2, "emoji flag sequence"));
+    }
+    if has(ucd.emoji_modifier_base, cps[0])
+        && has(ucd.emoji_modifier, cps[1])
+    {
+        return Some((2, "emoji modifier sequence"));
+    }
+    if has(ucd.emoji, cps[0]) {
+        match cps[1].value() {
+            0xFE0F => return Some((2, "emoji presentation sequence")),
+            0xFE0E => return Some((1, "text presentation sequence")),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Return the number of terminal columns the given codepoint occupies,
+/// along with a short description of why.
+///
+/// Combining marks, format characters and controls take no columns.
+/// Codepoints with a wide or fullwidth `East_Asian_Width`, or that are
+/// displayed as emoji by default, take two columns. Everything else takes
+/// one column, except for ambiguous codepoints, which take one or two
+/// columns depending on `ambiguous`.
+pub fn codepoint_width(
+    cp: Codepoint,
+    ambiguous: Ambiguous,
+) -> (usize, &'static str) {
+    // The soft hyphen is a format character, but is traditionally shown
+    // as a hyphen by terminals.
+    if cp.value() == 0xAD {
+        return (1, "soft hyphen");
+    }
+    match general_category(cp) {
+        "Cc" => return (0, "control"),
+        "Mn" | "Me" => return (0, "combining mark"),
+        "Cf" => return (0, "format"),
+        _ => {}
+    }
+    // Medial vowels and final consonants of conjoining Hangul jamo combine
+    // with a preceding leading consonant into a single syllable.
+    let n = cp.value();
+    if (0x1160 <= n && n <= 0x11FF) || (0xD7B0 <= n && n <= 0xD7FF) {
+        return (0, "hangul jamo");
+    }
+    if in_ranges(ucd().emoji_presentation.unwrap_or(&[]), cp.value()) {
+        return (2, "emoji presentation");
+    }
+    match east_asian_width(cp) {
+        "W" => (2, "wide"),
+        "F" => (2, "fullwidth"),
+        "A" if ambiguous == Ambiguous::Wide => (2, "ambiguous"),
+        "A" => (1, "ambiguous"),
+        "H" => (1, "halfwidth"),
+        "Na" => (1, "narrow"),
+        _ => (1, "neutral"),
+    }
+}
+
+/// Return the `General_Category` property value of the given codepoint.
+fn general_category(cp: Codepoint) -> &'static str {
+    range_value(ucd().general_category, cp.value()).unwrap_or("Cn")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Ambiguous, text_width};
+
+    fn width(text: &str) -> usize {
+        text_width(text, Ambiguous::Narrow)
+    }
+
+    #[test]
+    fn codepoints() {
+        assert_eq!(5, width("hello"));
+        assert_eq!(4, width("日本"));
+        assert_eq!(1, width("e\u{301}"));
+        assert_eq!(2, width("\u{1100}\u{1161}\u{11A8}"));
+        assert_eq!(1, width("\u{B1}"));
+        assert_eq!(2, text_width("\u{B1}", Ambiguous::Wide));
+    }
+
+    #[test]
+    fn emoji_sequences() {
+        assert_eq!(2, width("\u{2764}\u{FE0F}"));
+        assert_eq!(1, width("\u{231A}\u{FE0E}"));
+        assert_eq!(2, width("\u{1F1FA}\u{1F1F8}"));
+        assert_eq!(2, width("\u{1F44D}\u{1F3FD}"));
+        assert_eq!(2, width("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}"));
+        assert_eq!(2, width("#\u{FE0F}\u{20E3}"));
+        assert_eq!(4, width("\u{1F1FA}\u{1F1F8}\u{1F1EC}\u{1F1E7}"));
+    }
+}